@@ -45,8 +45,11 @@ fn make_test_experience(cid: CollectiveId, seed: u64) -> pulsedb::Experience {
         related_files: vec![],
         source_agent: pulsedb::AgentId::new("bench"),
         source_task: None,
+        user_id: None,
         timestamp: Timestamp::now(),
+        expires_at: None,
         archived: false,
+        attribution: None,
     }
 }
 
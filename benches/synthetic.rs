@@ -0,0 +1,74 @@
+//! Synthetic-distribution benchmarks using the public `bench` generator.
+//!
+//! Run with: `cargo bench --features bench --bench synthetic`
+//!
+//! Compares search latency on uniform and clustered embedding spaces at
+//! the same size. Clustered data is closer to real embeddings and is
+//! harder for HNSW.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use pulsedb::bench::{SyntheticVectors, VectorDistribution};
+use pulsedb::{CollectiveId, Config, NewExperience, PulseDB};
+use tempfile::tempdir;
+
+/// Default embedding dimension (D384).
+const DIM: usize = 384;
+
+/// Experiences per benchmark collective.
+const SIZE: usize = 2_000;
+
+/// Sets up a database populated from the given distribution.
+fn setup_db(distribution: VectorDistribution) -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("bench.db"), Config::default()).unwrap();
+    let cid = db.create_collective("bench").unwrap();
+
+    for (i, embedding) in SyntheticVectors::new(DIM, distribution, 42)
+        .take(SIZE)
+        .enumerate()
+    {
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: format!("Experience {i}"),
+            embedding: Some(embedding),
+            ..Default::default()
+        })
+        .unwrap();
+    }
+
+    (db, cid, dir)
+}
+
+/// Benchmark: search_similar (k=10) per distribution.
+fn bench_search_by_distribution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("search_by_distribution");
+
+    let distributions = [
+        ("uniform", VectorDistribution::Uniform),
+        (
+            "clustered",
+            VectorDistribution::Clustered {
+                clusters: 16,
+                spread: 0.1,
+            },
+        ),
+    ];
+    for (name, distribution) in distributions {
+        let (db, cid, _dir) = setup_db(distribution);
+        let queries: Vec<_> = SyntheticVectors::new(DIM, distribution, 7)
+            .take(64)
+            .collect();
+        let mut i = 0;
+
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| {
+                i = (i + 1) % queries.len();
+                db.search_similar(cid, &queries[i], 10).unwrap()
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_search_by_distribution);
+criterion_main!(benches);
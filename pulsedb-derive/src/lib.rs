@@ -0,0 +1,319 @@
+//! Derive macros for PulseDB.
+//!
+//! `#[derive(ToExperience)]` implements `pulsedb::ToExperience` for a
+//! struct with named fields, so host applications can record their domain
+//! events as experiences without hand-written conversion code. Enable the
+//! `derive` feature of `pulsehive-db` to use it through `pulsedb`.
+//!
+//! # Attributes
+//!
+//! On the struct, `#[experience(...)]` accepts:
+//!
+//! | Attribute | Effect |
+//! |-----------|--------|
+//! | `content = "..."` | Required. Content template; `{field}` and `{field:spec}` format struct fields. |
+//! | `tags("a", "b")` | Static domain tags. |
+//! | `category = "..."` | Records a `Generic` experience with this category. |
+//! | `experience_type = path` | Calls `path(&self)` for the `ExperienceType`. |
+//! | `importance = 0.8` | Fixed importance. |
+//! | `confidence = 0.9` | Fixed confidence. |
+//! | `agent = "..."` | Fixed source agent. |
+//!
+//! On fields, `#[experience(...)]` accepts:
+//!
+//! | Attribute | Effect |
+//! |-----------|--------|
+//! | `tag` | Adds the field (any `Display`) as a domain tag. |
+//! | `tags` | Adds every item of the field (iterable by reference, `Display` items) as a domain tag. |
+//! | `importance` / `confidence` | Takes the value from the field (`f32`, or convertible with `f32::from`). |
+//! | `agent` | Takes the source agent from the field (any `Display`). |
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use pulsedb::ToExperience;
+//!
+//! #[derive(ToExperience)]
+//! #[experience(content = "Deploy of {service} failed: {reason}", tags("deploy"), importance = 0.8)]
+//! struct DeployFailed {
+//!     service: String,
+//!     reason: String,
+//!     #[experience(tag)]
+//!     environment: String,
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{parenthesized, parse_macro_input, Data, DeriveInput, Fields, LitFloat, LitStr, Token};
+
+/// Derives `pulsedb::ToExperience`. See the [crate docs](crate) for the
+/// attributes.
+#[proc_macro_derive(ToExperience, attributes(experience))]
+pub fn derive_to_experience(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Where a numeric or agent setting comes from.
+enum Source<T> {
+    Default,
+    Fixed(T),
+    Field(syn::Ident),
+}
+
+/// Parsed struct- and field-level attributes.
+struct Spec {
+    content: Option<LitStr>,
+    tags: Vec<LitStr>,
+    tag_fields: Vec<syn::Ident>,
+    tags_fields: Vec<syn::Ident>,
+    experience_type: Option<TokenStream2>,
+    importance: Source<LitFloat>,
+    confidence: Source<LitFloat>,
+    agent: Source<LitStr>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "ToExperience needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "ToExperience can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut spec = Spec {
+        content: None,
+        tags: Vec::new(),
+        tag_fields: Vec::new(),
+        tags_fields: Vec::new(),
+        experience_type: None,
+        importance: Source::Default,
+        confidence: Source::Default,
+        agent: Source::Default,
+    };
+    parse_struct_attrs(&input, &mut spec)?;
+    for field in fields {
+        parse_field_attrs(field, &mut spec)?;
+    }
+
+    let content = spec.content.as_ref().ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "missing #[experience(content = \"...\")] on the struct",
+        )
+    })?;
+    let field_names: Vec<String> = fields
+        .iter()
+        .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+        .collect();
+    let mut placeholders = Vec::new();
+    for name in template_placeholders(content)? {
+        if !field_names.contains(&name) {
+            return Err(syn::Error::new_spanned(
+                content,
+                format!("content template names unknown field `{}`", name),
+            ));
+        }
+        if !placeholders.contains(&name) {
+            placeholders.push(name);
+        }
+    }
+    let format_args = placeholders.iter().map(|name| {
+        let ident = syn::Ident::new(name, content.span());
+        quote! { #ident = self.#ident }
+    });
+
+    let static_tags = &spec.tags;
+    let tag_fields = &spec.tag_fields;
+    let tags_fields = &spec.tags_fields;
+    let experience_type = spec
+        .experience_type
+        .unwrap_or_else(|| quote! { ::pulsedb::ExperienceType::default() });
+    let importance = number(&spec.importance, quote! { 0.5 });
+    let confidence = number(&spec.confidence, quote! { 0.5 });
+    let agent = match &spec.agent {
+        Source::Default => quote! { ::pulsedb::AgentId::new("anonymous") },
+        Source::Fixed(lit) => quote! { ::pulsedb::AgentId::new(#lit) },
+        Source::Field(ident) => quote! { ::pulsedb::AgentId::new(self.#ident.to_string()) },
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::pulsedb::ToExperience for #name #ty_generics #where_clause {
+            fn to_experience(
+                &self,
+                collective_id: ::pulsedb::CollectiveId,
+            ) -> ::pulsedb::NewExperience {
+                #[allow(unused_mut)]
+                let mut domain: ::std::vec::Vec<::std::string::String> =
+                    ::std::vec![#(::std::string::String::from(#static_tags)),*];
+                #(domain.push(::std::string::ToString::to_string(&self.#tag_fields));)*
+                #(
+                    for tag in &self.#tags_fields {
+                        domain.push(::std::string::ToString::to_string(tag));
+                    }
+                )*
+                ::pulsedb::NewExperience {
+                    collective_id,
+                    content: ::std::format!(#content #(, #format_args)*),
+                    experience_type: #experience_type,
+                    importance: #importance,
+                    confidence: #confidence,
+                    domain,
+                    source_agent: #agent,
+                    ..::std::default::Default::default()
+                }
+            }
+        }
+    })
+}
+
+/// Parses `#[experience(...)]` on the struct.
+fn parse_struct_attrs(input: &DeriveInput, spec: &mut Spec) -> syn::Result<()> {
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("experience"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("content") {
+                spec.content = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("tags") {
+                let list;
+                parenthesized!(list in meta.input);
+                spec.tags
+                    .extend(Punctuated::<LitStr, Token![,]>::parse_terminated(&list)?);
+            } else if meta.path.is_ident("category") {
+                let category: LitStr = meta.value()?.parse()?;
+                set_type(
+                    spec,
+                    &meta,
+                    quote! {
+                        ::pulsedb::ExperienceType::Generic {
+                            category: ::std::option::Option::Some(
+                                ::std::string::String::from(#category),
+                            ),
+                        }
+                    },
+                )?;
+            } else if meta.path.is_ident("experience_type") {
+                let path: syn::Path = meta.value()?.parse()?;
+                set_type(spec, &meta, quote! { #path(self) })?;
+            } else if meta.path.is_ident("importance") {
+                spec.importance = Source::Fixed(meta.value()?.parse()?);
+            } else if meta.path.is_ident("confidence") {
+                spec.confidence = Source::Fixed(meta.value()?.parse()?);
+            } else if meta.path.is_ident("agent") {
+                spec.agent = Source::Fixed(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("unknown experience attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/// Parses `#[experience(...)]` on one field.
+fn parse_field_attrs(field: &syn::Field, spec: &mut Spec) -> syn::Result<()> {
+    let Some(ident) = field.ident.clone() else {
+        return Ok(());
+    };
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("experience"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                spec.tag_fields.push(ident.clone());
+            } else if meta.path.is_ident("tags") {
+                spec.tags_fields.push(ident.clone());
+            } else if meta.path.is_ident("importance") {
+                spec.importance = Source::Field(ident.clone());
+            } else if meta.path.is_ident("confidence") {
+                spec.confidence = Source::Field(ident.clone());
+            } else if meta.path.is_ident("agent") {
+                spec.agent = Source::Field(ident.clone());
+            } else {
+                return Err(meta.error("unknown experience field attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/// Records the experience type, rejecting a second source.
+fn set_type(
+    spec: &mut Spec,
+    meta: &syn::meta::ParseNestedMeta,
+    tokens: TokenStream2,
+) -> syn::Result<()> {
+    if spec.experience_type.is_some() {
+        return Err(meta.error("`category` and `experience_type` are mutually exclusive"));
+    }
+    spec.experience_type = Some(tokens);
+    Ok(())
+}
+
+/// Expands an importance or confidence setting.
+fn number(source: &Source<LitFloat>, default: TokenStream2) -> TokenStream2 {
+    match source {
+        Source::Default => default,
+        Source::Fixed(lit) => quote! { #lit },
+        Source::Field(ident) => quote! { ::std::convert::From::from(self.#ident) },
+    }
+}
+
+/// Returns the argument names of a format template's placeholders.
+///
+/// Positional placeholders (`{}` or `{0}`) are rejected: there are no
+/// positional arguments to fill them.
+fn template_placeholders(template: &LitStr) -> syn::Result<Vec<String>> {
+    let value = template.value();
+    let mut names = Vec::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '{' => {
+                let mut name = String::new();
+                for c in chars.by_ref() {
+                    if c == '}' || c == ':' {
+                        break;
+                    }
+                    name.push(c);
+                }
+                if name.is_empty() || name.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(syn::Error::new_spanned(
+                        template,
+                        "content template placeholders must name a field, e.g. `{service}`",
+                    ));
+                }
+                names.push(name);
+            }
+            _ => {}
+        }
+    }
+    Ok(names)
+}
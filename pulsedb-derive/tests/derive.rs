@@ -0,0 +1,98 @@
+//! Tests for `#[derive(ToExperience)]`.
+//!
+//! Expands the derive on sample structs and checks the resulting
+//! `NewExperience` values against the real `pulsedb` types.
+
+use pulsedb::{AgentId, CollectiveId, ExperienceType, Severity, ToExperience};
+use pulsedb_derive::ToExperience;
+
+#[derive(ToExperience)]
+#[experience(
+    content = "Deploy of {service} failed: {reason} (attempt {attempt:02})",
+    tags("deploy", "ops"),
+    category = "incident",
+    importance = 0.8
+)]
+struct DeployFailed {
+    service: String,
+    reason: String,
+    attempt: u32,
+    #[experience(tag)]
+    environment: &'static str,
+    #[experience(tags)]
+    labels: Vec<String>,
+    #[experience(agent)]
+    reporter: String,
+    #[allow(dead_code)]
+    unused: bool,
+}
+
+#[derive(ToExperience)]
+#[experience(content = "{message}", experience_type = Self::classify)]
+struct Alert<T: std::fmt::Display> {
+    message: T,
+    critical: bool,
+    #[experience(confidence)]
+    certainty: f32,
+}
+
+impl<T: std::fmt::Display> Alert<T> {
+    fn classify(&self) -> ExperienceType {
+        ExperienceType::Difficulty {
+            description: self.message.to_string(),
+            severity: if self.critical {
+                Severity::Critical
+            } else {
+                Severity::Low
+            },
+        }
+    }
+}
+
+#[test]
+fn test_derive_maps_template_tags_and_settings() {
+    let collective_id = CollectiveId::new();
+    let exp = DeployFailed {
+        service: "api".to_string(),
+        reason: "timeout".to_string(),
+        attempt: 3,
+        environment: "staging",
+        labels: vec!["p1".to_string()],
+        reporter: "deploy-bot".to_string(),
+        unused: false,
+    }
+    .to_experience(collective_id);
+
+    assert_eq!(exp.collective_id, collective_id);
+    assert_eq!(exp.content, "Deploy of api failed: timeout (attempt 03)");
+    assert_eq!(exp.domain, vec!["deploy", "ops", "staging", "p1"]);
+    assert!(matches!(
+        exp.experience_type,
+        ExperienceType::Generic { category: Some(ref c) } if c == "incident"
+    ));
+    assert_eq!(exp.importance, 0.8);
+    assert_eq!(exp.confidence, 0.5);
+    assert_eq!(exp.source_agent, AgentId::new("deploy-bot"));
+    assert!(exp.embedding.is_none());
+}
+
+#[test]
+fn test_derive_supports_generics_and_type_fn() {
+    let exp = Alert {
+        message: 42,
+        critical: true,
+        certainty: 0.9,
+    }
+    .to_experience(CollectiveId::new());
+
+    assert_eq!(exp.content, "42");
+    assert!(exp.domain.is_empty());
+    assert_eq!(exp.confidence, 0.9);
+    assert!(matches!(
+        exp.experience_type,
+        ExperienceType::Difficulty {
+            severity: Severity::Critical,
+            ..
+        }
+    ));
+}
@@ -11,11 +11,13 @@
 //! - [`update_heartbeat(agent_id, collective_id)`](crate::PulseDB::update_heartbeat)
 //! - [`end_activity(agent_id, collective_id)`](crate::PulseDB::end_activity)
 //! - [`get_active_agents(collective_id)`](crate::PulseDB::get_active_agents)
+//! - [`find_agents_with_capability(collective_id, capability)`](crate::PulseDB::find_agents_with_capability)
 //!
 //! # Constraints
 //!
 //! - Agent ID must be non-empty and ≤ 255 bytes
 //! - `current_task` and `context_summary` must each be ≤ 1KB
+//! - At most 32 capabilities, each non-empty and ≤ 64 bytes
 //! - One activity per `(collective_id, agent_id)` pair (upsert semantics)
 
 pub mod types;
@@ -23,7 +25,10 @@ pub mod types;
 pub use types::{Activity, NewActivity};
 
 use crate::error::{PulseDBError, ValidationError};
-use crate::storage::schema::{MAX_ACTIVITY_AGENT_ID_LENGTH, MAX_ACTIVITY_FIELD_SIZE};
+use crate::storage::schema::{
+    MAX_ACTIVITY_AGENT_ID_LENGTH, MAX_ACTIVITY_CAPABILITIES, MAX_ACTIVITY_FIELD_SIZE,
+    MAX_CAPABILITY_LENGTH,
+};
 
 /// Validates a new activity before storage.
 ///
@@ -32,43 +37,89 @@ use crate::storage::schema::{MAX_ACTIVITY_AGENT_ID_LENGTH, MAX_ACTIVITY_FIELD_SI
 /// - Agent ID doesn't exceed 255 bytes
 /// - `current_task` (if provided) doesn't exceed 1KB
 /// - `context_summary` (if provided) doesn't exceed 1KB
+/// - At most 32 capabilities, each non-empty and ≤ 64 bytes
 ///
 /// Does NOT check collective existence — that requires a storage lookup
 /// and is handled by the PulseDB facade.
 pub(crate) fn validate_new_activity(activity: &NewActivity) -> Result<(), PulseDBError> {
+    validate_agent_id(&activity.agent_id)?;
+
+    // current_task size limit
+    if let Some(ref task) = activity.current_task {
+        if task.len() > MAX_ACTIVITY_FIELD_SIZE {
+            return Err(
+                ValidationError::content_too_large(task.len(), MAX_ACTIVITY_FIELD_SIZE).into(),
+            );
+        }
+    }
+
+    // context_summary size limit
+    if let Some(ref summary) = activity.context_summary {
+        if summary.len() > MAX_ACTIVITY_FIELD_SIZE {
+            return Err(
+                ValidationError::content_too_large(summary.len(), MAX_ACTIVITY_FIELD_SIZE).into(),
+            );
+        }
+    }
+
+    validate_capabilities(&activity.capabilities)
+}
+
+/// Validates an agent ID: non-empty and at most 255 bytes.
+pub(crate) fn validate_agent_id(agent_id: &str) -> Result<(), PulseDBError> {
     // Agent ID must be non-empty
-    if activity.agent_id.is_empty() {
+    if agent_id.is_empty() {
         return Err(ValidationError::required_field("agent_id").into());
     }
 
     // Agent ID length limit
-    if activity.agent_id.len() > MAX_ACTIVITY_AGENT_ID_LENGTH {
+    if agent_id.len() > MAX_ACTIVITY_AGENT_ID_LENGTH {
         return Err(ValidationError::invalid_field(
             "agent_id",
             format!(
                 "must be at most {} bytes, got {}",
                 MAX_ACTIVITY_AGENT_ID_LENGTH,
-                activity.agent_id.len()
+                agent_id.len()
             ),
         )
         .into());
     }
 
-    // current_task size limit
-    if let Some(ref task) = activity.current_task {
-        if task.len() > MAX_ACTIVITY_FIELD_SIZE {
-            return Err(
-                ValidationError::content_too_large(task.len(), MAX_ACTIVITY_FIELD_SIZE).into(),
-            );
-        }
+    Ok(())
+}
+
+/// Validates an agent's advertised capabilities.
+fn validate_capabilities(capabilities: &[String]) -> Result<(), PulseDBError> {
+    if capabilities.len() > MAX_ACTIVITY_CAPABILITIES {
+        return Err(ValidationError::invalid_field(
+            "capabilities",
+            format!(
+                "must have at most {} entries, got {}",
+                MAX_ACTIVITY_CAPABILITIES,
+                capabilities.len()
+            ),
+        )
+        .into());
     }
 
-    // context_summary size limit
-    if let Some(ref summary) = activity.context_summary {
-        if summary.len() > MAX_ACTIVITY_FIELD_SIZE {
-            return Err(
-                ValidationError::content_too_large(summary.len(), MAX_ACTIVITY_FIELD_SIZE).into(),
-            );
+    for capability in capabilities {
+        if capability.is_empty() {
+            return Err(ValidationError::invalid_field(
+                "capabilities",
+                "must not contain empty entries",
+            )
+            .into());
+        }
+        if capability.len() > MAX_CAPABILITY_LENGTH {
+            return Err(ValidationError::invalid_field(
+                "capabilities",
+                format!(
+                    "entries must be at most {} bytes, got {}",
+                    MAX_CAPABILITY_LENGTH,
+                    capability.len()
+                ),
+            )
+            .into());
         }
     }
 
@@ -86,6 +137,7 @@ mod tests {
             collective_id: CollectiveId::new(),
             current_task: Some("Implementing feature X".to_string()),
             context_summary: Some("Working on module Y".to_string()),
+            capabilities: vec![],
         }
     }
 
@@ -102,6 +154,7 @@ mod tests {
             collective_id: CollectiveId::new(),
             current_task: None,
             context_summary: None,
+            capabilities: vec![],
         };
         assert!(validate_new_activity(&activity).is_ok());
     }
@@ -149,6 +202,27 @@ mod tests {
         assert!(err.to_string().contains("too large"));
     }
 
+    #[test]
+    fn test_capabilities_validated() {
+        let mut activity = valid_new_activity();
+        activity.capabilities = vec!["rust".to_string(); MAX_ACTIVITY_CAPABILITIES];
+        assert!(validate_new_activity(&activity).is_ok());
+
+        activity.capabilities = vec!["rust".to_string(); MAX_ACTIVITY_CAPABILITIES + 1];
+        let err = validate_new_activity(&activity).unwrap_err();
+        assert!(err.to_string().contains("capabilities"));
+
+        activity.capabilities = vec![String::new()];
+        assert!(validate_new_activity(&activity)
+            .unwrap_err()
+            .is_validation());
+
+        activity.capabilities = vec!["x".repeat(MAX_CAPABILITY_LENGTH + 1)];
+        assert!(validate_new_activity(&activity)
+            .unwrap_err()
+            .is_validation());
+    }
+
     #[test]
     fn test_fields_at_limit_passes() {
         let mut activity = valid_new_activity();
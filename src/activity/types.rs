@@ -36,11 +36,17 @@ pub struct Activity {
     pub collective_id: CollectiveId,
 
     /// What the agent is currently working on (max 1KB).
+    #[serde(default)]
     pub current_task: Option<String>,
 
     /// Summary of the agent's current context (max 1KB).
+    #[serde(default)]
     pub context_summary: Option<String>,
 
+    /// Capabilities the agent advertises (e.g., "rust", "code-review").
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+
     /// When this activity was first registered.
     pub started_at: Timestamp,
 
@@ -48,10 +54,28 @@ pub struct Activity {
     pub last_heartbeat: Timestamp,
 }
 
+impl Default for Activity {
+    /// An activity with no agent, the nil collective, and both timestamps
+    /// set to now.
+    fn default() -> Self {
+        let now = Timestamp::now();
+        Self {
+            agent_id: String::new(),
+            collective_id: CollectiveId::nil(),
+            current_task: None,
+            context_summary: None,
+            capabilities: Vec::new(),
+            started_at: now,
+            last_heartbeat: now,
+        }
+    }
+}
+
 /// Input for registering a new agent activity.
 ///
 /// The `started_at` and `last_heartbeat` timestamps are set automatically
-/// to `Timestamp::now()` when the activity is registered.
+/// to `Timestamp::now()` when the activity is registered. Optional fields
+/// can be left to [`Default`].
 ///
 /// # Example
 ///
@@ -67,11 +91,20 @@ pub struct Activity {
 ///     collective_id,
 ///     current_task: Some("Implementing error handling".to_string()),
 ///     context_summary: Some("Working on src/error.rs".to_string()),
+///     capabilities: vec!["rust".to_string(), "code-review".to_string()],
 /// };
 /// db.register_activity(activity)?;
+///
+/// // Fields added later never break callers that fill in the rest
+/// db.register_activity(NewActivity {
+///     agent_id: "agent-47".to_string(),
+///     collective_id,
+///     ..Default::default()
+/// })?;
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct NewActivity {
     /// The agent's identifier (non-empty, max 255 bytes).
     pub agent_id: String,
@@ -80,10 +113,20 @@ pub struct NewActivity {
     pub collective_id: CollectiveId,
 
     /// What the agent is currently working on (max 1KB).
+    #[serde(default)]
     pub current_task: Option<String>,
 
     /// Summary of the agent's current context (max 1KB).
+    #[serde(default)]
     pub context_summary: Option<String>,
+
+    /// Capabilities the agent advertises, used for discovery via
+    /// [`PulseDB::find_agents_with_capability()`](crate::PulseDB::find_agents_with_capability).
+    ///
+    /// At most 32 entries, each non-empty and ≤ 64 bytes. Duplicates are
+    /// collapsed on registration.
+    #[serde(default)]
+    pub capabilities: Vec<String>,
 }
 
 #[cfg(test)]
@@ -97,6 +140,7 @@ mod tests {
             collective_id: CollectiveId::new(),
             current_task: Some("Implementing feature X".to_string()),
             context_summary: Some("Working on module Y".to_string()),
+            capabilities: vec![],
             started_at: Timestamp::now(),
             last_heartbeat: Timestamp::now(),
         };
@@ -119,6 +163,7 @@ mod tests {
             collective_id: CollectiveId::new(),
             current_task: None,
             context_summary: None,
+            capabilities: vec![],
             started_at: Timestamp::from_millis(1000),
             last_heartbeat: Timestamp::from_millis(2000),
         };
@@ -132,4 +177,22 @@ mod tests {
         assert_eq!(activity.started_at, restored.started_at);
         assert_eq!(activity.last_heartbeat, restored.last_heartbeat);
     }
+
+    #[test]
+    fn test_new_activity_without_capabilities() {
+        let json = format!(
+            r#"{{"agent_id":"agent-1","collective_id":"{}"}}"#,
+            CollectiveId::nil()
+        );
+        let activity: NewActivity = serde_json::from_str(&json).unwrap();
+        assert_eq!(activity.agent_id, "agent-1");
+        assert!(activity.capabilities.is_empty());
+
+        let literal = NewActivity {
+            agent_id: "agent-1".to_string(),
+            ..Default::default()
+        };
+        assert!(literal.capabilities.is_empty());
+        assert!(literal.current_task.is_none());
+    }
 }
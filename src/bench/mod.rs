@@ -0,0 +1,310 @@
+//! Capacity-planning benchmarks on synthetic collectives.
+//!
+//! Requires the `bench` feature flag. [`run()`] generates a synthetic
+//! collective of the configured size, dimension, and [`VectorDistribution`]
+//! and measures, on the current hardware:
+//!
+//! - **record** — `record_experience` throughput while the collective fills
+//! - **search** — `search_similar` latency against the full collective
+//! - **open** — `PulseDB::open()` of the populated database, including the
+//!   HNSW rebuild from stored embeddings
+//! - **rebuild** — raw HNSW graph construction over the same vectors
+//!
+//! The `pulsedb bench` command-line mode is a thin wrapper over [`run()`]:
+//!
+//! ```text
+//! cargo run --release --features bench --bin pulsedb -- bench --size 100000 --dimension 768
+//! ```
+//!
+//! Criterion benchmarks for regression tracking live in `benches/` and use
+//! [`SyntheticVectors`] for their data.
+
+mod synthetic;
+
+#[cfg(feature = "testdata")]
+pub(crate) use synthetic::{normalize, SplitMix64};
+pub use synthetic::{SyntheticVectors, VectorDistribution};
+
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::config::{Config, EmbeddingDimension};
+use crate::db::PulseDB;
+use crate::error::{Result, ValidationError};
+use crate::experience::NewExperience;
+use crate::vector::HnswIndex;
+
+/// Seed offset for the query stream, so queries differ from stored vectors.
+const QUERY_SEED_OFFSET: u64 = 0xA5A5_A5A5_A5A5_A5A5;
+
+/// Parameters of a synthetic benchmark run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BenchConfig {
+    /// Number of experiences in the synthetic collective.
+    pub size: usize,
+
+    /// Embedding dimension (1–4096).
+    pub dimension: usize,
+
+    /// Shape of the embedding space.
+    pub distribution: VectorDistribution,
+
+    /// Number of search queries to time.
+    pub queries: usize,
+
+    /// Results per search query (1–1000).
+    pub k: usize,
+
+    /// Seed for vector generation.
+    pub seed: u64,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            size: 10_000,
+            dimension: 384,
+            distribution: VectorDistribution::Uniform,
+            queries: 100,
+            k: 10,
+            seed: 42,
+        }
+    }
+}
+
+/// Operation count and wall-clock time for one measured phase.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Throughput {
+    /// Number of operations performed.
+    pub operations: usize,
+
+    /// Total time spent.
+    pub elapsed: Duration,
+}
+
+impl Throughput {
+    /// Operations per second.
+    pub fn per_second(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs == 0.0 {
+            0.0
+        } else {
+            self.operations as f64 / secs
+        }
+    }
+
+    /// Mean time per operation.
+    pub fn mean_latency(&self) -> Duration {
+        if self.operations == 0 {
+            Duration::ZERO
+        } else {
+            self.elapsed / self.operations as u32
+        }
+    }
+}
+
+/// Measurements from one [`run()`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BenchReport {
+    /// The configuration that was measured.
+    pub config: BenchConfig,
+
+    /// `record_experience` calls for every synthetic experience.
+    pub record: Throughput,
+
+    /// `search_similar` calls for every query.
+    pub search: Throughput,
+
+    /// Time to reopen the populated database.
+    pub open: Duration,
+
+    /// HNSW construction over all vectors (operations = vectors inserted).
+    pub rebuild: Throughput,
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = &self.config;
+        writeln!(
+            f,
+            "size={} dimension={} distribution={:?} queries={} k={}",
+            c.size, c.dimension, c.distribution, c.queries, c.k
+        )?;
+        writeln!(
+            f,
+            "record   {:>12.1} ops/s  mean {:?}",
+            self.record.per_second(),
+            self.record.mean_latency()
+        )?;
+        writeln!(
+            f,
+            "search   {:>12.1} ops/s  mean {:?}",
+            self.search.per_second(),
+            self.search.mean_latency()
+        )?;
+        writeln!(f, "open     {:?}", self.open)?;
+        write!(
+            f,
+            "rebuild  {:>12.1} vectors/s  total {:?}",
+            self.rebuild.per_second(),
+            self.rebuild.elapsed
+        )
+    }
+}
+
+/// Runs a synthetic benchmark in `dir`.
+///
+/// Creates `bench.db` inside `dir` (which must exist and should be empty
+/// and on the storage being sized), populates it, and measures each phase.
+/// The database is left in place for the caller to remove.
+///
+/// # Errors
+///
+/// - Validation error if `size`, `queries`, or `k` is out of range, or the
+///   dimension is invalid
+/// - Any storage or index error raised while running the workload
+pub fn run(dir: &Path, config: &BenchConfig) -> Result<BenchReport> {
+    if config.size == 0 {
+        return Err(ValidationError::invalid_field("size", "must be at least 1").into());
+    }
+    if config.queries == 0 {
+        return Err(ValidationError::invalid_field("queries", "must be at least 1").into());
+    }
+    if config.k == 0 || config.k > 1000 {
+        return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
+    }
+
+    let db_config = Config {
+        embedding_dimension: EmbeddingDimension::Custom(config.dimension),
+        ..Config::default()
+    };
+    let path = dir.join("bench.db");
+
+    info!(
+        size = config.size,
+        dimension = config.dimension,
+        "Starting benchmark"
+    );
+
+    // Record phase
+    let db = PulseDB::open(&path, db_config.clone())?;
+    let cid = db.create_collective("bench")?;
+    let mut vectors = SyntheticVectors::new(config.dimension, config.distribution, config.seed);
+    let mut embeddings = Vec::with_capacity(config.size);
+    let start = Instant::now();
+    for i in 0..config.size {
+        let embedding = vectors.next_vector();
+        let id = db.record_experience(NewExperience {
+            collective_id: cid,
+            content: format!("synthetic experience {i}"),
+            embedding: Some(embedding.clone()),
+            ..Default::default()
+        })?;
+        embeddings.push((id, embedding));
+    }
+    let record = Throughput {
+        operations: config.size,
+        elapsed: start.elapsed(),
+    };
+    db.close()?;
+
+    // Open phase (includes the HNSW rebuild from stored embeddings)
+    let start = Instant::now();
+    let db = PulseDB::open(&path, db_config.clone())?;
+    let open = start.elapsed();
+
+    // Search phase
+    let queries: Vec<Vec<f32>> = SyntheticVectors::new(
+        config.dimension,
+        config.distribution,
+        config.seed.wrapping_add(QUERY_SEED_OFFSET),
+    )
+    .take(config.queries)
+    .collect();
+    let start = Instant::now();
+    for query in &queries {
+        db.search_similar(cid, query, config.k)?;
+    }
+    let search = Throughput {
+        operations: config.queries,
+        elapsed: start.elapsed(),
+    };
+    db.close()?;
+
+    // Rebuild phase (index only, no storage)
+    let start = Instant::now();
+    HnswIndex::rebuild_from_embeddings(config.dimension, &db_config.hnsw, embeddings)?;
+    let rebuild = Throughput {
+        operations: config.size,
+        elapsed: start.elapsed(),
+    };
+
+    Ok(BenchReport {
+        config: config.clone(),
+        record,
+        search,
+        open,
+        rebuild,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_small_collective() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = BenchConfig {
+            size: 50,
+            dimension: 16,
+            distribution: VectorDistribution::Clustered {
+                clusters: 4,
+                spread: 0.1,
+            },
+            queries: 5,
+            ..BenchConfig::default()
+        };
+
+        let report = run(dir.path(), &config).unwrap();
+        assert_eq!(report.record.operations, 50);
+        assert_eq!(report.search.operations, 5);
+        assert_eq!(report.rebuild.operations, 50);
+        assert!(report.to_string().contains("size=50"));
+    }
+
+    #[test]
+    fn test_run_validates_config() {
+        let dir = tempfile::tempdir().unwrap();
+        for config in [
+            BenchConfig {
+                size: 0,
+                ..BenchConfig::default()
+            },
+            BenchConfig {
+                queries: 0,
+                ..BenchConfig::default()
+            },
+            BenchConfig {
+                k: 0,
+                ..BenchConfig::default()
+            },
+        ] {
+            assert!(run(dir.path(), &config).unwrap_err().is_validation());
+        }
+    }
+
+    #[test]
+    fn test_throughput_rates() {
+        let t = Throughput {
+            operations: 4,
+            elapsed: Duration::from_secs(2),
+        };
+        assert_eq!(t.per_second(), 2.0);
+        assert_eq!(t.mean_latency(), Duration::from_millis(500));
+    }
+}
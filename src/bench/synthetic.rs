@@ -0,0 +1,180 @@
+//! Deterministic synthetic embedding generation.
+
+use serde::{Deserialize, Serialize};
+
+/// Shape of the synthetic embedding space.
+///
+/// Real embedding sets are rarely uniform: documents about the same topic
+/// cluster tightly, which changes HNSW recall and latency. Benchmark both
+/// to bracket what production data will do.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum VectorDistribution {
+    /// Components drawn uniformly from `[-1, 1]`, then normalized.
+    #[default]
+    Uniform,
+
+    /// Gaussian noise around `clusters` random centers, then normalized.
+    ///
+    /// `spread` is the noise standard deviation relative to the center;
+    /// smaller values produce tighter clusters.
+    Clustered {
+        /// Number of cluster centers (at least 1).
+        clusters: usize,
+        /// Per-component noise standard deviation.
+        spread: f32,
+    },
+}
+
+/// Seeded generator of unit-length synthetic embeddings.
+///
+/// The same `(dimension, distribution, seed)` always yields the same
+/// sequence, so benchmark runs are comparable across machines.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::bench::{SyntheticVectors, VectorDistribution};
+///
+/// let mut vectors = SyntheticVectors::new(384, VectorDistribution::Uniform, 42);
+/// let v = vectors.next_vector();
+/// assert_eq!(v.len(), 384);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SyntheticVectors {
+    dimension: usize,
+    distribution: VectorDistribution,
+    centers: Vec<Vec<f32>>,
+    rng: SplitMix64,
+}
+
+impl SyntheticVectors {
+    /// Creates a generator for `dimension`-sized vectors.
+    pub fn new(dimension: usize, distribution: VectorDistribution, seed: u64) -> Self {
+        let mut generator = Self {
+            dimension,
+            distribution,
+            centers: Vec::new(),
+            rng: SplitMix64::new(seed),
+        };
+        if let VectorDistribution::Clustered { clusters, .. } = distribution {
+            generator.centers = (0..clusters.max(1))
+                .map(|_| generator.uniform_vector())
+                .collect();
+        }
+        generator
+    }
+
+    /// Returns the next vector in the sequence.
+    pub fn next_vector(&mut self) -> Vec<f32> {
+        match self.distribution {
+            VectorDistribution::Uniform => self.uniform_vector(),
+            VectorDistribution::Clustered { spread, .. } => {
+                let center = self.rng.below(self.centers.len());
+                let mut v = self.centers[center].clone();
+                for x in &mut v {
+                    *x += self.rng.next_gaussian() * spread;
+                }
+                normalize(&mut v);
+                v
+            }
+        }
+    }
+
+    fn uniform_vector(&mut self) -> Vec<f32> {
+        let mut v: Vec<f32> = (0..self.dimension)
+            .map(|_| self.rng.next_f32() * 2.0 - 1.0)
+            .collect();
+        normalize(&mut v);
+        v
+    }
+}
+
+/// Small seeded PRNG shared by the synthetic data generators.
+#[derive(Clone, Debug)]
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// SplitMix64 step.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`; `n` must be non-zero.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub(crate) fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Standard normal via Box-Muller.
+    pub(crate) fn next_gaussian(&mut self) -> f32 {
+        let u1 = self.next_f32().max(f32::MIN_POSITIVE);
+        let u2 = self.next_f32();
+        (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+    }
+}
+
+impl Iterator for SyntheticVectors {
+    type Item = Vec<f32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_vector())
+    }
+}
+
+pub(crate) fn normalize(v: &mut [f32]) {
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for x in v {
+            *x /= norm;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let clustered = VectorDistribution::Clustered {
+            clusters: 4,
+            spread: 0.1,
+        };
+        let a: Vec<_> = SyntheticVectors::new(16, clustered, 7).take(10).collect();
+        let b: Vec<_> = SyntheticVectors::new(16, clustered, 7).take(10).collect();
+        let c: Vec<_> = SyntheticVectors::new(16, clustered, 8).take(10).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_vectors_are_unit_length() {
+        for distribution in [
+            VectorDistribution::Uniform,
+            VectorDistribution::Clustered {
+                clusters: 3,
+                spread: 0.5,
+            },
+        ] {
+            for v in SyntheticVectors::new(32, distribution, 1).take(20) {
+                assert_eq!(v.len(), 32);
+                let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+                assert!((norm - 1.0).abs() < 1e-4);
+            }
+        }
+    }
+}
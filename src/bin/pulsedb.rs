@@ -0,0 +1,116 @@
+//! `pulsedb` command-line tool.
+//!
+//! Currently provides one mode, `pulsedb bench`, which measures record,
+//! search, open, and rebuild throughput on a synthetic collective. Requires
+//! the `bench` feature:
+//!
+//! ```text
+//! cargo run --release --features bench --bin pulsedb -- bench --help
+//! ```
+
+use std::process::ExitCode;
+
+use pulsedb::bench::{self, BenchConfig, VectorDistribution};
+
+const USAGE: &str = "\
+Usage: pulsedb bench [OPTIONS]
+
+Generates a synthetic collective and measures record/search/open/rebuild
+throughput on this machine.
+
+Options:
+  --size <N>              Experiences in the collective [default: 10000]
+  --dimension <D>         Embedding dimension [default: 384]
+  --distribution <DIST>   uniform | clustered:<clusters>[:<spread>] [default: uniform]
+  --queries <N>           Search queries to time [default: 100]
+  --k <K>                 Results per query [default: 10]
+  --seed <SEED>           Vector generator seed [default: 42]
+  --dir <PATH>            Directory for the database [default: a temporary directory]
+  -h, --help              Print this help";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("bench") => match run_bench(&args[1..]) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(message) => {
+                eprintln!("error: {message}\n\n{USAGE}");
+                ExitCode::FAILURE
+            }
+        },
+        Some("-h") | Some("--help") => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_bench(args: &[String]) -> Result<(), String> {
+    let mut config = BenchConfig::default();
+    let mut dir = None;
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        if flag == "-h" || flag == "--help" {
+            println!("{USAGE}");
+            return Ok(());
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {flag}"))?;
+        match flag.as_str() {
+            "--size" => config.size = parse(flag, value)?,
+            "--dimension" => config.dimension = parse(flag, value)?,
+            "--distribution" => config.distribution = parse_distribution(value)?,
+            "--queries" => config.queries = parse(flag, value)?,
+            "--k" => config.k = parse(flag, value)?,
+            "--seed" => config.seed = parse(flag, value)?,
+            "--dir" => dir = Some(std::path::PathBuf::from(value)),
+            _ => return Err(format!("unknown option {flag}")),
+        }
+    }
+
+    // Default to a fresh directory under the system temp dir, removed afterwards
+    let (dir, cleanup) = match dir {
+        Some(dir) => (dir, false),
+        None => (
+            std::env::temp_dir().join(format!("pulsedb-bench-{}", std::process::id())),
+            true,
+        ),
+    };
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let result = bench::run(&dir, &config);
+    if cleanup {
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+    println!("{}", result.map_err(|e| e.to_string())?);
+    Ok(())
+}
+
+fn parse<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{value}' for {flag}"))
+}
+
+fn parse_distribution(value: &str) -> Result<VectorDistribution, String> {
+    let mut parts = value.split(':');
+    match parts.next() {
+        Some("uniform") => Ok(VectorDistribution::Uniform),
+        Some("clustered") => {
+            let clusters = parts
+                .next()
+                .map_or(Ok(16), |c| parse("--distribution", c))?;
+            let spread = parts
+                .next()
+                .map_or(Ok(0.1), |s| parse("--distribution", s))?;
+            Ok(VectorDistribution::Clustered { clusters, spread })
+        }
+        _ => Err(format!("unknown distribution '{value}'")),
+    }
+}
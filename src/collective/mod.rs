@@ -4,6 +4,7 @@
 //! Each collective has:
 //! - Unique ID (UUID v7)
 //! - Name and optional owner
+//! - Optional description and key/value settings
 //! - Fixed embedding dimension (set at creation)
 //! - Its own vector index
 //!
@@ -16,6 +17,7 @@
 //! - [`get_collective(id)`](crate::PulseDB::get_collective)
 //! - [`list_collectives()`](crate::PulseDB::list_collectives)
 //! - [`list_collectives_by_owner(owner_id)`](crate::PulseDB::list_collectives_by_owner)
+//! - [`update_collective(id, update)`](crate::PulseDB::update_collective)
 //! - [`get_collective_stats(id)`](crate::PulseDB::get_collective_stats)
 //! - [`delete_collective(id)`](crate::PulseDB::delete_collective)
 //!
@@ -52,14 +54,30 @@
 //! ```
 
 pub mod types;
+mod wire;
 
-pub use types::{Collective, CollectiveStats};
+pub use types::{
+    Collective, CollectiveQuota, CollectiveStats, CollectiveUpdate, EmbeddingStats, OwnerStats,
+};
+pub(crate) use wire::CollectiveDetails;
 
 use crate::error::{PulseDBError, ValidationError};
 
 /// Maximum length for a collective name in characters.
 pub const MAX_COLLECTIVE_NAME_LENGTH: usize = 255;
 
+/// Maximum length for a collective description in characters.
+pub const MAX_COLLECTIVE_DESCRIPTION_LENGTH: usize = 4096;
+
+/// Maximum number of settings per collective.
+pub const MAX_COLLECTIVE_SETTINGS: usize = 64;
+
+/// Maximum length for a setting key in characters.
+pub const MAX_SETTING_KEY_LENGTH: usize = 255;
+
+/// Maximum length for a setting value in characters.
+pub const MAX_SETTING_VALUE_LENGTH: usize = 4096;
+
 /// Validates a collective name.
 ///
 /// # Rules
@@ -96,6 +114,81 @@ pub(crate) fn validate_collective_name(name: &str) -> Result<(), PulseDBError> {
     Ok(())
 }
 
+/// Validates a collective update.
+///
+/// # Rules
+///
+/// - Description must not exceed 4096 characters
+/// - At most 64 settings
+/// - Setting keys must not be empty or whitespace-only, nor exceed 255
+///   characters
+/// - Setting values must not exceed 4096 characters
+///
+/// # Errors
+///
+/// Returns [`ValidationError::InvalidField`] naming the offending field.
+pub(crate) fn validate_collective_update(update: &CollectiveUpdate) -> Result<(), PulseDBError> {
+    if let Some(description) = &update.description {
+        if description.len() > MAX_COLLECTIVE_DESCRIPTION_LENGTH {
+            return Err(ValidationError::invalid_field(
+                "description",
+                format!(
+                    "must not exceed {} characters (got {})",
+                    MAX_COLLECTIVE_DESCRIPTION_LENGTH,
+                    description.len()
+                ),
+            )
+            .into());
+        }
+    }
+
+    if let Some(settings) = &update.settings {
+        if settings.len() > MAX_COLLECTIVE_SETTINGS {
+            return Err(ValidationError::invalid_field(
+                "settings",
+                format!(
+                    "must not exceed {} entries (got {})",
+                    MAX_COLLECTIVE_SETTINGS,
+                    settings.len()
+                ),
+            )
+            .into());
+        }
+        for (key, value) in settings {
+            if key.trim().is_empty() {
+                return Err(
+                    ValidationError::invalid_field("settings", "keys must not be empty").into(),
+                );
+            }
+            if key.len() > MAX_SETTING_KEY_LENGTH {
+                return Err(ValidationError::invalid_field(
+                    "settings",
+                    format!(
+                        "keys must not exceed {} characters (got {})",
+                        MAX_SETTING_KEY_LENGTH,
+                        key.len()
+                    ),
+                )
+                .into());
+            }
+            if value.len() > MAX_SETTING_VALUE_LENGTH {
+                return Err(ValidationError::invalid_field(
+                    "settings",
+                    format!(
+                        "value of {:?} must not exceed {} characters (got {})",
+                        key,
+                        MAX_SETTING_VALUE_LENGTH,
+                        value.len()
+                    ),
+                )
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,4 +224,45 @@ mod tests {
         let name = "x".repeat(MAX_COLLECTIVE_NAME_LENGTH);
         assert!(validate_collective_name(&name).is_ok());
     }
+
+    #[test]
+    fn test_validate_collective_update() {
+        let settings = |pairs: &[(&str, &str)]| {
+            Some(
+                pairs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            )
+        };
+        assert!(validate_collective_update(&CollectiveUpdate {
+            description: Some("x".repeat(MAX_COLLECTIVE_DESCRIPTION_LENGTH)),
+            settings: settings(&[("repo", "https://example.com/r")]),
+        })
+        .is_ok());
+
+        let invalid = [
+            CollectiveUpdate {
+                description: Some("x".repeat(MAX_COLLECTIVE_DESCRIPTION_LENGTH + 1)),
+                ..Default::default()
+            },
+            CollectiveUpdate {
+                settings: settings(&[(" ", "v")]),
+                ..Default::default()
+            },
+            CollectiveUpdate {
+                settings: settings(&[(&"k".repeat(MAX_SETTING_KEY_LENGTH + 1), "v")]),
+                ..Default::default()
+            },
+            CollectiveUpdate {
+                settings: settings(&[("k", &"v".repeat(MAX_SETTING_VALUE_LENGTH + 1))]),
+                ..Default::default()
+            },
+        ];
+        for update in &invalid {
+            assert!(validate_collective_update(update)
+                .unwrap_err()
+                .is_validation());
+        }
+    }
 }
@@ -3,6 +3,8 @@
 //! A **collective** is an isolated namespace for experiences, typically one per project.
 //! Each collective has its own embedding dimension and vector index.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::{CollectiveId, Timestamp};
@@ -17,14 +19,18 @@ use crate::types::{CollectiveId, Timestamp};
 /// - `id` — Unique identifier (UUID v7, time-ordered)
 /// - `name` — Human-readable name (e.g., "my-project")
 /// - `owner_id` — Optional owner for multi-tenant filtering
+/// - `description` / `settings` — Free-form description and key/value
+///   settings (e.g., repository URL, team), changed with
+///   [`update_collective()`](crate::PulseDB::update_collective)
 /// - `embedding_dimension` — Vector dimension locked at creation (e.g., 384, 768)
 /// - `created_at` / `updated_at` — Lifecycle timestamps
 ///
 /// # Serialization
 ///
 /// Collectives are serialized with bincode for compact storage in redb.
-/// The `Serialize`/`Deserialize` derives enable this automatically.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Binary formats use that storage layout, which leaves out `description`
+/// and `settings` (stored alongside); JSON carries every field.
+#[derive(Clone, Debug)]
 pub struct Collective {
     /// Unique identifier (UUID v7).
     pub id: CollectiveId,
@@ -38,6 +44,12 @@ pub struct Collective {
     /// `list_collectives_by_owner()`.
     pub owner_id: Option<String>,
 
+    /// Optional free-form description.
+    pub description: Option<String>,
+
+    /// Application-defined key/value settings.
+    pub settings: HashMap<String, String>,
+
     /// Embedding vector dimension for this collective.
     ///
     /// All experiences in this collective must have embeddings
@@ -55,13 +67,16 @@ impl Collective {
     /// Creates a new collective with the given name and embedding dimension.
     ///
     /// Sets `created_at` and `updated_at` to the current time.
-    /// The `owner_id` defaults to `None`.
+    /// The `owner_id` and `description` default to `None`, `settings` to
+    /// empty.
     pub fn new(name: impl Into<String>, embedding_dimension: u16) -> Self {
         let now = Timestamp::now();
         Self {
             id: CollectiveId::new(),
             name: name.into(),
             owner_id: None,
+            description: None,
+            settings: HashMap::new(),
             embedding_dimension,
             created_at: now,
             updated_at: now,
@@ -80,20 +95,99 @@ impl Collective {
     }
 }
 
+/// Partial update for a collective's mutable metadata.
+///
+/// Only fields set to `Some(...)` will be updated. Applied with
+/// [`PulseDB::update_collective()`](crate::PulseDB::update_collective).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CollectiveUpdate {
+    /// New description. An empty string clears it.
+    pub description: Option<String>,
+
+    /// Replace settings entirely.
+    pub settings: Option<HashMap<String, String>>,
+}
+
+/// Limits on a collective and everything below it in the hierarchy.
+///
+/// Set with [`PulseDB::set_collective_quota()`](crate::PulseDB::set_collective_quota).
+/// Limits roll up the hierarchy: a write to a sub-collective counts
+/// against the quota of every ancestor. Unset limits don't apply.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CollectiveQuota {
+    /// Most experiences the collective and its descendants may hold.
+    pub max_experiences: Option<u64>,
+}
+
 /// Statistics for a collective.
 ///
 /// Returned by [`PulseDB::get_collective_stats()`](crate::PulseDB::get_collective_stats).
 /// These values are computed on-the-fly from the storage layer, not cached.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CollectiveStats {
     /// Number of experiences in this collective.
     pub experience_count: u64,
-    /// Estimated storage size in bytes for this collective's data.
+    /// Estimated storage size in bytes for this collective's data:
+    /// encoded experience records, embeddings and insights.
     pub storage_bytes: u64,
     /// Timestamp of the oldest experience, if any.
     pub oldest_experience: Option<Timestamp>,
     /// Timestamp of the newest experience, if any.
     pub newest_experience: Option<Timestamp>,
+    /// Health of the collective's experience HNSW index.
+    ///
+    /// `None` for IVF-backed collectives, for collectives whose index is
+    /// not resident (evicted or failed to load), and for rollups.
+    #[serde(default)]
+    pub vector_index: Option<crate::vector::HnswStats>,
+}
+
+/// Aggregate statistics for every collective belonging to one owner.
+///
+/// Returned by [`PulseDB::stats_by_owner()`](crate::PulseDB::stats_by_owner).
+/// Computed on-the-fly from the owner index, not cached.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct OwnerStats {
+    /// Number of collectives owned.
+    pub collective_count: u64,
+    /// Total experiences across the owner's collectives.
+    pub experience_count: u64,
+    /// Total insights across the owner's collectives.
+    pub insight_count: u64,
+}
+
+/// Shape of a collective's embedding space.
+///
+/// Returned by [`PulseDB::embedding_stats()`](crate::PulseDB::embedding_stats).
+/// Computed on-the-fly from stored embeddings and the vector index, not
+/// cached. All fields are zero or empty for a collective without embeddings.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EmbeddingStats {
+    /// Number of embeddings measured.
+    pub embedding_count: u64,
+    /// Smallest L2 norm.
+    pub norm_min: f32,
+    /// Largest L2 norm.
+    pub norm_max: f32,
+    /// Mean L2 norm.
+    pub norm_mean: f32,
+    /// Standard deviation of the L2 norms.
+    pub norm_std_dev: f32,
+    /// Component-wise mean of all embeddings.
+    ///
+    /// A mean with a large norm relative to `norm_mean` means the vectors
+    /// crowd into a narrow cone, which compresses similarity scores.
+    pub mean_vector: Vec<f32>,
+    /// TwoNN estimate of the intrinsic dimensionality.
+    ///
+    /// `None` with fewer than three embeddings or when every nearest
+    /// neighbor is an exact duplicate.
+    pub intrinsic_dimension: Option<f32>,
+    /// Fraction of embeddings whose nearest neighbor has cosine similarity
+    /// of at least 0.98.
+    pub near_duplicate_rate: f32,
 }
 
 #[cfg(test)]
@@ -0,0 +1,235 @@
+//! Serde representation of [`Collective`].
+//!
+//! Like [`Experience`](crate::Experience), a collective has two serialized
+//! forms, chosen by the format's
+//! [`is_human_readable()`](serde::Serializer::is_human_readable):
+//!
+//! - **Storage** (bincode and other binary formats) — the record layout
+//!   of `COLLECTIVES_TABLE`, also used by export records and sync
+//!   payloads. `description` and `settings` live in
+//!   `COLLECTIVE_DETAILS_TABLE` and are left out. This layout must never
+//!   change.
+//! - **Wire** (JSON and other human-readable formats) — every field.
+//!   `owner_id`, `description`, and `settings` may be omitted on input and
+//!   default to empty; unknown fields are ignored.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::types::Collective;
+use crate::types::{CollectiveId, Timestamp};
+
+/// Storage layout, borrowed for serialization. Field order is the
+/// on-disk order.
+#[derive(Serialize)]
+struct StoredRef<'a> {
+    id: CollectiveId,
+    name: &'a str,
+    owner_id: &'a Option<String>,
+    embedding_dimension: u16,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+/// Storage layout, owned for deserialization.
+#[derive(Deserialize)]
+struct Stored {
+    id: CollectiveId,
+    name: String,
+    owner_id: Option<String>,
+    embedding_dimension: u16,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+/// Wire layout, borrowed for serialization.
+#[derive(Serialize)]
+struct WireRef<'a> {
+    id: CollectiveId,
+    name: &'a str,
+    owner_id: &'a Option<String>,
+    description: &'a Option<String>,
+    settings: &'a HashMap<String, String>,
+    embedding_dimension: u16,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+/// Wire layout, owned for deserialization.
+#[derive(Deserialize)]
+struct Wire {
+    id: CollectiveId,
+    name: String,
+    #[serde(default)]
+    owner_id: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    settings: HashMap<String, String>,
+    embedding_dimension: u16,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+/// The fields of a collective kept outside its storage layout.
+///
+/// Stored in `COLLECTIVE_DETAILS_TABLE` and carried next to the collective
+/// in its export record. Collectives without a description or settings
+/// have no entry.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CollectiveDetails {
+    pub description: Option<String>,
+    pub settings: HashMap<String, String>,
+}
+
+impl CollectiveDetails {
+    /// Returns the details of `collective`, or `None` if it has none.
+    pub(crate) fn of(collective: &Collective) -> Option<Self> {
+        if collective.description.is_none() && collective.settings.is_empty() {
+            return None;
+        }
+        Some(Self {
+            description: collective.description.clone(),
+            settings: collective.settings.clone(),
+        })
+    }
+
+    /// Fills in the fields a storage-layout decode left empty.
+    pub(crate) fn apply_to(self, collective: &mut Collective) {
+        collective.description = self.description;
+        collective.settings = self.settings;
+    }
+}
+
+impl Serialize for Collective {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            WireRef {
+                id: self.id,
+                name: &self.name,
+                owner_id: &self.owner_id,
+                description: &self.description,
+                settings: &self.settings,
+                embedding_dimension: self.embedding_dimension,
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+            }
+            .serialize(serializer)
+        } else {
+            StoredRef {
+                id: self.id,
+                name: &self.name,
+                owner_id: &self.owner_id,
+                embedding_dimension: self.embedding_dimension,
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Collective {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let w = Wire::deserialize(deserializer)?;
+            Ok(Self {
+                id: w.id,
+                name: w.name,
+                owner_id: w.owner_id,
+                description: w.description,
+                settings: w.settings,
+                embedding_dimension: w.embedding_dimension,
+                created_at: w.created_at,
+                updated_at: w.updated_at,
+            })
+        } else {
+            let s = Stored::deserialize(deserializer)?;
+            Ok(Self {
+                id: s.id,
+                name: s.name,
+                owner_id: s.owner_id,
+                description: None,
+                settings: HashMap::new(),
+                embedding_dimension: s.embedding_dimension,
+                created_at: s.created_at,
+                updated_at: s.updated_at,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collective() -> Collective {
+        let mut collective = Collective::with_owner("billing", "team-payments", 384);
+        collective.description = Some("Billing service".to_string());
+        collective.settings.insert(
+            "repo".to_string(),
+            "https://example.com/billing".to_string(),
+        );
+        collective
+    }
+
+    #[test]
+    fn test_storage_layout_matches_original_derive() {
+        // The layout the plain derive produced before details existed
+        #[derive(Serialize)]
+        struct Original<'a> {
+            id: CollectiveId,
+            name: &'a String,
+            owner_id: &'a Option<String>,
+            embedding_dimension: u16,
+            created_at: Timestamp,
+            updated_at: Timestamp,
+        }
+
+        let c = collective();
+        let original = bincode::serialize(&Original {
+            id: c.id,
+            name: &c.name,
+            owner_id: &c.owner_id,
+            embedding_dimension: c.embedding_dimension,
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+        })
+        .unwrap();
+        assert_eq!(bincode::serialize(&c).unwrap(), original);
+
+        let decoded: Collective = bincode::deserialize(&original).unwrap();
+        assert_eq!(decoded.name, "billing");
+        assert!(decoded.description.is_none());
+        assert!(decoded.settings.is_empty());
+    }
+
+    #[test]
+    fn test_wire_roundtrip_and_defaults() {
+        let c = collective();
+        let json = serde_json::to_string(&c).unwrap();
+        let decoded: Collective = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.description, c.description);
+        assert_eq!(decoded.settings, c.settings);
+
+        let minimal = format!(
+            r#"{{"id":"{}","name":"n","embedding_dimension":4,"created_at":1,"updated_at":1}}"#,
+            c.id
+        );
+        let decoded: Collective = serde_json::from_str(&minimal).unwrap();
+        assert!(decoded.owner_id.is_none());
+        assert!(decoded.description.is_none());
+        assert!(decoded.settings.is_empty());
+    }
+
+    #[test]
+    fn test_details_of_empty_collective() {
+        assert!(CollectiveDetails::of(&Collective::new("c", 4)).is_none());
+
+        let details = CollectiveDetails::of(&collective()).unwrap();
+        let mut restored = Collective::new("c", 4);
+        details.clone().apply_to(&mut restored);
+        assert_eq!(CollectiveDetails::of(&restored), Some(details));
+    }
+}
@@ -21,13 +21,16 @@
 //! };
 //! ```
 
+use std::borrow::Cow;
 use std::path::PathBuf;
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
+use crate::embedding::TextNormalization;
 use crate::error::ValidationError;
 use crate::types::CollectiveId;
+use crate::vector::VectorBackend;
 
 /// Database configuration options.
 ///
@@ -50,6 +53,49 @@ pub struct Config {
     /// Embedding vector dimension (must match provider output).
     pub embedding_dimension: EmbeddingDimension,
 
+    /// How embedding vectors are encoded on disk.
+    ///
+    /// See [`EmbeddingStorage`] for the size and accuracy of each option.
+    /// Changing it for an existing database re-encodes every stored
+    /// embedding the next time the database is opened.
+    ///
+    /// Default: [`EmbeddingStorage::F32`]
+    pub embedding_storage: EmbeddingStorage,
+
+    /// Worker threads running builtin ONNX embedding inference.
+    ///
+    /// Workers take requests from a bounded queue, so concurrent
+    /// `record_experience()` calls embed in parallel rather than taking
+    /// turns on one session. Ignored for [`EmbeddingProvider::External`].
+    ///
+    /// Default: 2
+    pub embedding_threads: usize,
+
+    /// ONNX model sessions shared round-robin by the embedding workers.
+    ///
+    /// Each session holds a copy of the model in memory. `None` loads one
+    /// per worker; fewer sessions than [`embedding_threads`](Self::embedding_threads)
+    /// caps memory at the cost of workers waiting for a free session.
+    ///
+    /// Default: `None`
+    pub onnx_sessions: Option<usize>,
+
+    /// Where and how the builtin model files are downloaded on first use.
+    ///
+    /// See [`ModelDownloadConfig`] for mirrors, offline mode, and retries.
+    pub model_download: ModelDownloadConfig,
+
+    /// Clean-up applied to text before PulseDB embeds it.
+    ///
+    /// Used both for record content and for
+    /// [`embed_query()`](crate::PulseDB::embed_query), so the two stay
+    /// consistent. Collectives can override it with
+    /// [`set_text_normalization()`](crate::PulseDB::set_text_normalization).
+    /// See [`TextNormalization`] for the steps.
+    ///
+    /// Default: no normalization
+    pub text_normalization: TextNormalization,
+
     /// Default collective for operations when none specified.
     pub default_collective: Option<CollectiveId>,
 
@@ -68,6 +114,23 @@ pub struct Config {
     /// See [`HnswConfig`] for tuning guidelines.
     pub hnsw: HnswConfig,
 
+    /// Disk-backed IVF vector index parameters.
+    ///
+    /// Applies to collectives switched to [`VectorIndexKind::Ivf`].
+    /// See [`IvfConfig`] for details.
+    pub ivf: IvfConfig,
+
+    /// Builds every collective's experience and insight indexes instead of
+    /// the built-in HNSW and IVF indexes.
+    ///
+    /// Custom indexes are rebuilt from stored embeddings whenever a
+    /// collective is loaded, so the backend can change between opens.
+    /// While set, [`VectorIndexKind`] choices are recorded but not
+    /// applied, and index snapshots are unavailable.
+    ///
+    /// Default: `None`
+    pub vector_backend: Option<VectorBackend>,
+
     /// Agent activity tracking parameters.
     ///
     /// Controls staleness detection for agent heartbeats.
@@ -90,6 +153,135 @@ pub struct Config {
     ///
     /// Default: false
     pub read_only: bool,
+
+    /// How long a write waits for the write lock before failing as busy.
+    ///
+    /// See [`WriteRetryConfig`].
+    pub write_retry: WriteRetryConfig,
+
+    /// Time limits for searches, write transactions, and index rebuilds.
+    ///
+    /// See [`TimeoutConfig`] for what each limit covers. Operations that
+    /// run past their limit fail with
+    /// [`PulseDBError::Timeout`](crate::PulseDBError::Timeout).
+    ///
+    /// Default: no limits
+    pub timeouts: TimeoutConfig,
+
+    /// Idle time after which a collective's in-memory vector indexes are
+    /// persisted and evicted.
+    ///
+    /// Evicted indexes are rebuilt from redb transparently on the next
+    /// search against that collective. `None` keeps every index resident
+    /// for the lifetime of the handle.
+    ///
+    /// Default: None
+    pub idle_eviction: Option<Duration>,
+
+    /// Interval between automatic sweeps of expired experiences.
+    ///
+    /// When set, [`PulseDB::open()`](crate::PulseDB::open) runs
+    /// [`purge_expired()`](crate::PulseDB::purge_expired) once. There is no
+    /// background thread: later sweeps run inline, on the calling thread of
+    /// the first experience write after each interval elapses, so that one
+    /// write also pays for the purge. Call `purge_expired()` yourself to keep
+    /// sweeps off the write path. `None` leaves expired experiences in place
+    /// until `purge_expired()` is called. Ignored in read-only mode.
+    ///
+    /// Default: None
+    pub expiry_sweep: Option<Duration>,
+
+    /// Recording of searches the collective couldn't answer.
+    ///
+    /// When set, similarity searches whose best result scores below
+    /// [`KnowledgeGapConfig::min_score`] are recorded for
+    /// [`PulseDB::list_knowledge_gaps()`](crate::PulseDB::list_knowledge_gaps).
+    /// Ignored in read-only mode.
+    ///
+    /// Default: None
+    pub knowledge_gaps: Option<KnowledgeGapConfig>,
+
+    /// Suggestion of relations for newly recorded experiences.
+    ///
+    /// When set, each published experience is compared with its nearest
+    /// neighbors and the close ones are queued as
+    /// [`RelationSuggestion`](crate::RelationSuggestion)s for agents to
+    /// confirm with
+    /// [`accept_relation_suggestion()`](crate::PulseDB::accept_relation_suggestion).
+    ///
+    /// Default: None
+    pub relation_suggestions: Option<RelationSuggestionConfig>,
+
+    /// Enforce referential integrity of insight sources.
+    ///
+    /// When `true`, `store_insight` rejects insights whose
+    /// `source_experience_ids` don't all exist in the target collective.
+    /// Disable only for bulk imports where sources may arrive after the
+    /// insights that cite them; run
+    /// [`repair_insight_sources`](crate::PulseDB::repair_insight_sources)
+    /// afterwards.
+    ///
+    /// Default: true
+    pub strict_insight_sources: bool,
+
+    /// What `delete_experience` does to insights that cite the experience.
+    ///
+    /// See [`InsightSourceCascade`] for the options.
+    ///
+    /// Default: [`InsightSourceCascade::Detach`]
+    pub insight_source_cascade: InsightSourceCascade,
+
+    /// Where experience content is kept.
+    ///
+    /// See [`ContentStorage`] for the options. The mode is recorded in
+    /// the database on first open; reopening with a different mode fails
+    /// with [`PulseDBError::Config`](crate::PulseDBError::Config).
+    ///
+    /// Default: [`ContentStorage::Inline`]
+    pub content_storage: ContentStorage,
+
+    /// How `record_experience` assigns IDs when the caller doesn't supply one.
+    ///
+    /// See [`IdStrategy`] for the options.
+    ///
+    /// Default: [`IdStrategy::TimeOrdered`]
+    pub id_strategy: IdStrategy,
+
+    /// Number of hydrated experiences kept in an in-memory LRU cache.
+    ///
+    /// Hot records read by consecutive searches are served from the cache
+    /// instead of redb. Entries are dropped on every write to the
+    /// experience. `0` disables the cache; read-only handles never cache,
+    /// since another process may be writing.
+    ///
+    /// Default: 1024
+    pub experience_cache_capacity: usize,
+
+    /// Worker threads that fetch full experiences after a vector search.
+    ///
+    /// Candidates are split into small morsels that idle workers claim in
+    /// turn, and results are reassembled in ranking order. Only searches
+    /// with more candidates than one morsel use more than one thread, so
+    /// small `k` is unaffected. `1` hydrates serially.
+    ///
+    /// Default: 4
+    pub hydration_threads: usize,
+
+    /// How `similarity` scores in search results are computed.
+    ///
+    /// Applies to [`SearchResult`](crate::SearchResult) and insight search
+    /// scores. See [`ScoreKind`] for the options.
+    ///
+    /// Default: [`ScoreKind::Cosine`]
+    pub score_kind: ScoreKind,
+
+    /// How much experience and insight content tracing output may show.
+    ///
+    /// Content is never recorded in spans; this only controls the
+    /// `content` field of write events such as "Experience recorded".
+    ///
+    /// Default: [`LogContentPolicy::Never`]
+    pub log_content_policy: LogContentPolicy,
 }
 
 impl Default for Config {
@@ -99,13 +291,34 @@ impl Default for Config {
             embedding_provider: EmbeddingProvider::External,
             // 384 matches all-MiniLM-L6-v2, the default builtin model
             embedding_dimension: EmbeddingDimension::D384,
+            embedding_storage: EmbeddingStorage::default(),
+            embedding_threads: 2,
+            onnx_sessions: None,
+            model_download: ModelDownloadConfig::default(),
+            write_retry: WriteRetryConfig::default(),
+            timeouts: TimeoutConfig::default(),
+            text_normalization: TextNormalization::default(),
             default_collective: None,
             cache_size_mb: 64,
             sync_mode: SyncMode::Normal,
             hnsw: HnswConfig::default(),
+            ivf: IvfConfig::default(),
+            vector_backend: None,
             activity: ActivityConfig::default(),
             watch: WatchConfig::default(),
             read_only: false,
+            idle_eviction: None,
+            expiry_sweep: None,
+            knowledge_gaps: None,
+            relation_suggestions: None,
+            strict_insight_sources: true,
+            insight_source_cascade: InsightSourceCascade::default(),
+            content_storage: ContentStorage::default(),
+            id_strategy: IdStrategy::default(),
+            experience_cache_capacity: 1024,
+            hydration_threads: 4,
+            score_kind: ScoreKind::default(),
+            log_content_policy: LogContentPolicy::default(),
         }
     }
 }
@@ -148,7 +361,30 @@ impl Config {
     /// ```
     pub fn with_builtin_embeddings() -> Self {
         Self {
-            embedding_provider: EmbeddingProvider::Builtin { model_path: None },
+            embedding_provider: EmbeddingProvider::Builtin {
+                model_path: None,
+                execution_provider: ExecutionProvider::Cpu,
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Creates a Config that generates hash-based embeddings.
+    ///
+    /// Exercises the same paths as builtin embeddings — experiences and
+    /// insights recorded without an embedding get one generated — without
+    /// a model download.
+    ///
+    /// # Example
+    /// ```rust
+    /// use pulsedb::{Config, EmbeddingDimension};
+    ///
+    /// let config = Config::with_hashing_embeddings(EmbeddingDimension::D384);
+    /// ```
+    pub fn with_hashing_embeddings(dimension: EmbeddingDimension) -> Self {
+        Self {
+            embedding_provider: EmbeddingProvider::Hashing,
+            embedding_dimension: dimension,
             ..Default::default()
         }
     }
@@ -210,6 +446,73 @@ impl Config {
                 "must be greater than 0",
             ));
         }
+        if self.hnsw.max_segment_size == 0 {
+            return Err(ValidationError::invalid_field(
+                "hnsw.max_segment_size",
+                "must be greater than 0",
+            ));
+        }
+
+        // Validate IVF parameters
+        if self.ivf.nlist == 0 {
+            return Err(ValidationError::invalid_field(
+                "ivf.nlist",
+                "must be greater than 0",
+            ));
+        }
+        if self.ivf.nprobe == 0 {
+            return Err(ValidationError::invalid_field(
+                "ivf.nprobe",
+                "must be greater than 0",
+            ));
+        }
+
+        if self.embedding_threads == 0 {
+            return Err(ValidationError::invalid_field(
+                "embedding_threads",
+                "must be greater than 0",
+            ));
+        }
+        match self.onnx_sessions {
+            Some(0) => {
+                return Err(ValidationError::invalid_field(
+                    "onnx_sessions",
+                    "must be greater than 0",
+                ));
+            }
+            Some(sessions) if sessions > self.embedding_threads => {
+                return Err(ValidationError::invalid_field(
+                    "onnx_sessions",
+                    "must not exceed embedding_threads",
+                ));
+            }
+            _ => {}
+        }
+
+        if !self.model_download.offline && self.model_download.mirrors.is_empty() {
+            return Err(ValidationError::invalid_field(
+                "model_download.mirrors",
+                "must not be empty unless offline",
+            ));
+        }
+        if self
+            .model_download
+            .mirrors
+            .iter()
+            .any(|m| !(m.starts_with("https://") || m.starts_with("http://")))
+        {
+            return Err(ValidationError::invalid_field(
+                "model_download.mirrors",
+                "must be http:// or https:// URLs",
+            ));
+        }
+
+        if self.hydration_threads == 0 {
+            return Err(ValidationError::invalid_field(
+                "hydration_threads",
+                "must be greater than 0",
+            ));
+        }
 
         // Validate watch buffer size
         if self.watch.buffer_size == 0 {
@@ -225,6 +528,68 @@ impl Config {
             ));
         }
 
+        // Idle eviction window must be positive when set
+        if self.idle_eviction.is_some_and(|d| d.is_zero()) {
+            return Err(ValidationError::invalid_field(
+                "idle_eviction",
+                "must be greater than 0",
+            ));
+        }
+
+        for (field, limit) in [
+            ("timeouts.search", self.timeouts.search),
+            (
+                "timeouts.write_transaction",
+                self.timeouts.write_transaction,
+            ),
+            ("timeouts.rebuild", self.timeouts.rebuild),
+        ] {
+            if limit.is_some_and(|d| d.is_zero()) {
+                return Err(ValidationError::invalid_field(
+                    field,
+                    "must be greater than 0",
+                ));
+            }
+        }
+
+        // Expiry sweep interval must be positive when set
+        if self.expiry_sweep.is_some_and(|d| d.is_zero()) {
+            return Err(ValidationError::invalid_field(
+                "expiry_sweep",
+                "must be greater than 0",
+            ));
+        }
+
+        if let Some(gaps) = &self.knowledge_gaps {
+            if !gaps.min_score.is_finite() {
+                return Err(ValidationError::invalid_field(
+                    "knowledge_gaps.min_score",
+                    "must be a finite number",
+                ));
+            }
+            if !(gaps.cluster_similarity > 0.0 && gaps.cluster_similarity <= 1.0) {
+                return Err(ValidationError::invalid_field(
+                    "knowledge_gaps.cluster_similarity",
+                    "must be greater than 0.0 and at most 1.0",
+                ));
+            }
+        }
+
+        if let Some(suggestions) = &self.relation_suggestions {
+            if !(0.0..=1.0).contains(&suggestions.min_similarity) {
+                return Err(ValidationError::invalid_field(
+                    "relation_suggestions.min_similarity",
+                    "must be between 0.0 and 1.0",
+                ));
+            }
+            if suggestions.max_suggestions == 0 || suggestions.max_suggestions > 100 {
+                return Err(ValidationError::invalid_field(
+                    "relation_suggestions.max_suggestions",
+                    "must be between 1 and 100",
+                ));
+            }
+        }
+
         // Validate custom dimension bounds
         if let EmbeddingDimension::Custom(dim) = self.embedding_dimension {
             if dim == 0 {
@@ -262,6 +627,10 @@ pub enum EmbeddingProvider {
     Builtin {
         /// Custom ONNX model path. If `None`, uses the bundled model.
         model_path: Option<PathBuf>,
+
+        /// Hardware ONNX Runtime runs the model on. Falls back to the CPU
+        /// when the provider isn't available on this host.
+        execution_provider: ExecutionProvider,
     },
 
     /// Caller provides pre-computed embedding vectors.
@@ -269,6 +638,35 @@ pub enum EmbeddingProvider {
     /// Use this when you have your own embedding service (OpenAI, Cohere, etc.)
     /// or want to use a model not bundled with PulseDB.
     External,
+
+    /// PulseDB generates deterministic word-hashing vectors with
+    /// [`HashingEmbedding`](crate::embedding::HashingEmbedding).
+    ///
+    /// Needs no feature or model files. Similarity reflects word overlap
+    /// only, so this is meant for tests, CI, and examples rather than
+    /// production search.
+    Hashing,
+}
+
+/// ONNX Runtime execution provider for builtin embeddings.
+///
+/// Accelerated providers need an ONNX Runtime build that includes them
+/// and the matching hardware and drivers. When either is missing, a
+/// warning is logged and inference runs on the CPU instead of failing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExecutionProvider {
+    /// CPU inference (always available).
+    #[default]
+    Cpu,
+
+    /// NVIDIA GPUs via CUDA.
+    Cuda,
+
+    /// Apple Neural Engine and GPUs via Core ML.
+    CoreMl,
+
+    /// DirectX 12 GPUs on Windows via DirectML.
+    DirectMl,
 }
 
 impl EmbeddingProvider {
@@ -323,6 +721,198 @@ impl EmbeddingDimension {
     }
 }
 
+/// Referential behavior when deleting an experience cited by insights.
+///
+/// Applied by [`PulseDB::delete_experience()`](crate::PulseDB::delete_experience)
+/// to every insight whose `source_experience_ids` contains the deleted ID.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InsightSourceCascade {
+    /// Refuse the deletion with a validation error while any insight
+    /// still cites the experience.
+    Block,
+
+    /// Remove the deleted ID from each citing insight's sources.
+    ///
+    /// An insight whose last source is removed is deleted with the
+    /// experience, since every insight must cite at least one source.
+    #[default]
+    Detach,
+
+    /// Keep the (now dangling) ID and flag the insight as degraded.
+    ///
+    /// Degraded insights can be listed with
+    /// [`PulseDB::list_degraded_insights()`](crate::PulseDB::list_degraded_insights)
+    /// and cleaned up with
+    /// [`PulseDB::repair_insight_sources()`](crate::PulseDB::repair_insight_sources).
+    MarkDegraded,
+}
+
+/// On-disk encoding of embedding vectors.
+///
+/// Quantized encodings shrink the embeddings table, usually the bulk of a
+/// database, at some cost in accuracy. Vector indexes are rebuilt from the
+/// stored vectors on open, so after a reopen similarity search runs on the
+/// dequantized values; records read back carry them too.
+///
+/// | Encoding | Bytes per vector | 1536 dims | Accuracy |
+/// |----------|------------------|-----------|----------|
+/// | [`F32`](Self::F32) | 4 × dim | 6 KiB | exact |
+/// | [`F16`](Self::F16) | 2 × dim | 3 KiB | cosine similarity within ~1e-5 of the original |
+/// | [`Int8`](Self::Int8) | dim + 4 | 1.5 KiB | cosine similarity within ~1e-3; near-ties may swap rank |
+///
+/// A million 1536-dimension vectors take about 6 GB as `F32`, 3 GB as
+/// `F16`, and 1.5 GB as `Int8`.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{Config, EmbeddingStorage};
+///
+/// let config = Config {
+///     embedding_storage: EmbeddingStorage::Int8,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmbeddingStorage {
+    /// 32-bit floats, stored as given.
+    #[default]
+    F32,
+
+    /// IEEE 754 half-precision floats.
+    ///
+    /// Halves embedding storage. Values keep about three significant
+    /// digits, well below the noise in model embeddings.
+    F16,
+
+    /// Signed 8-bit integers with one `f32` scale per vector.
+    ///
+    /// Quarters embedding storage. Each component is rounded to one of
+    /// 255 steps between minus and plus the vector's largest magnitude.
+    Int8,
+}
+
+/// Where experience content is kept.
+///
+/// Embeddings and metadata are always stored locally so search keeps
+/// working; this only controls the `content` text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContentStorage {
+    /// `content` is stored in the database as given.
+    #[default]
+    Inline,
+
+    /// `content` is an opaque handle (an external document key, or
+    /// ciphertext) and the raw text never enters the database.
+    ///
+    /// `record_experience` requires a caller-supplied embedding, since the
+    /// handle cannot be embedded. `get_experience` resolves the handle
+    /// through the registered
+    /// [`ContentResolver`](crate::ContentResolver); other reads (search,
+    /// listings, context candidates) return handles.
+    External,
+}
+
+/// How new experience IDs are assigned.
+///
+/// A caller-supplied [`NewExperience::id`](crate::NewExperience::id) always
+/// takes precedence. Either way, `record_experience` rejects an ID that
+/// already exists instead of overwriting.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IdStrategy {
+    /// Fresh UUID v7 per experience (time-ordered, unique).
+    #[default]
+    TimeOrdered,
+
+    /// UUID v5 derived from the collective and content with
+    /// [`ExperienceId::derive()`](crate::ExperienceId::derive).
+    ///
+    /// Mirrors of external stores get the same ID for the same record on
+    /// every run, and recording identical content twice in a collective is
+    /// rejected as a collision. Derived IDs are not time-ordered; listings
+    /// that return experiences oldest first (bookmarks, user and task
+    /// links) sort by timestamp instead.
+    ContentDerived,
+}
+
+/// How search scores are derived from the vector index's cosine distance.
+///
+/// Indexes rank by cosine distance, which runs from 0.0 (same direction)
+/// to 2.0 (opposite). The score kind only changes the reported number;
+/// ranking is identical. Results carry the kind and the raw distance, so
+/// thresholds can be checked against the convention in use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScoreKind {
+    /// `1.0 - distance`: cosine similarity in [-1.0, 1.0], with 0.0 for
+    /// orthogonal vectors.
+    #[default]
+    Cosine,
+
+    /// `1.0 - distance / 2.0`: the dot product of the unit-length vectors
+    /// rescaled to [0.0, 1.0], with 0.5 for orthogonal vectors.
+    NormalizedDot,
+}
+
+impl ScoreKind {
+    /// Converts a cosine distance to a score of this kind.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pulsedb::ScoreKind;
+    ///
+    /// assert_eq!(ScoreKind::Cosine.score(1.0), 0.0);
+    /// assert_eq!(ScoreKind::NormalizedDot.score(1.0), 0.5);
+    /// ```
+    pub fn score(self, distance: f32) -> f32 {
+        match self {
+            Self::Cosine => 1.0 - distance,
+            Self::NormalizedDot => 1.0 - distance / 2.0,
+        }
+    }
+}
+
+/// How much content tracing output may show.
+///
+/// Experience content is the memory of the agents using the database, so
+/// logs redact it unless an operator opts in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogContentPolicy {
+    /// Log only the content's length.
+    #[default]
+    Never,
+
+    /// Log at most this many characters, followed by `…` if cut.
+    Truncate(usize),
+
+    /// Log the content as stored.
+    Full,
+}
+
+impl LogContentPolicy {
+    /// Returns the form of `content` this policy allows in logs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pulsedb::LogContentPolicy;
+    ///
+    /// assert_eq!(LogContentPolicy::Never.redact("secret"), "<redacted 6 bytes>");
+    /// assert_eq!(LogContentPolicy::Truncate(3).redact("secret"), "sec…");
+    /// assert_eq!(LogContentPolicy::Full.redact("secret"), "secret");
+    /// ```
+    pub fn redact<'a>(&self, content: &'a str) -> Cow<'a, str> {
+        match *self {
+            Self::Never => Cow::Owned(format!("<redacted {} bytes>", content.len())),
+            Self::Truncate(max_chars) => match content.char_indices().nth(max_chars) {
+                Some((cut, _)) => Cow::Owned(format!("{}…", &content[..cut])),
+                None => Cow::Borrowed(content),
+            },
+            Self::Full => Cow::Borrowed(content),
+        }
+    }
+}
+
 /// Durability mode for write operations.
 ///
 /// Controls the trade-off between write performance and crash safety.
@@ -405,10 +995,32 @@ pub struct HnswConfig {
 
     /// Initial pre-allocated capacity (number of vectors).
     ///
-    /// The index grows beyond this automatically, but pre-allocation
-    /// avoids reallocations for known workloads.
+    /// This is a sizing hint, not a limit: inserts past it never fail and
+    /// the graph grows in place. Pre-allocation avoids reallocations for
+    /// known workloads, and indexes rebuilt on open are sized for the
+    /// larger of this and the collective's actual vector count.
     /// Default: 10_000
     pub max_elements: usize,
+
+    /// Number of vectors per graph segment.
+    ///
+    /// When the active segment of an index reaches this size it is sealed
+    /// and a new segment takes further inserts. Searches cover every
+    /// segment in parallel; [`PulseDB::merge_index_segments`](crate::PulseDB::merge_index_segments)
+    /// compacts sealed segments. Smaller segments rebuild and merge faster
+    /// at some cost in search latency.
+    /// Default: 1_000_000
+    pub max_segment_size: usize,
+
+    /// Warm each index right after it is loaded or rebuilt from redb.
+    ///
+    /// This happens on open and when an idle-evicted collective is
+    /// reloaded. Warming walks the graph once (see
+    /// [`PulseDB::warm_collective`](crate::PulseDB::warm_collective)) so
+    /// the first real search doesn't pay the cold-start cost, at the price
+    /// of a slower open/reload.
+    /// Default: false
+    pub warm_after_rebuild: bool,
 }
 
 impl Default for HnswConfig {
@@ -419,33 +1031,102 @@ impl Default for HnswConfig {
             ef_search: 50,
             max_layer: 16,
             max_elements: 10_000,
+            max_segment_size: 1_000_000,
+            warm_after_rebuild: false,
         }
     }
 }
 
-/// Configuration for agent activity tracking.
+/// Which vector index a collective's experiences are searched through.
 ///
-/// Controls how stale activities are detected and filtered.
+/// Chosen per collective with
+/// [`PulseDB::set_vector_index_kind()`](crate::PulseDB::set_vector_index_kind).
+/// Insight indexes are always HNSW.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VectorIndexKind {
+    /// In-memory HNSW graph, tuned by [`HnswConfig`].
+    ///
+    /// Fastest search, but every vector and its graph links are held in RAM.
+    #[default]
+    Hnsw,
+
+    /// Disk-backed inverted file, tuned by [`IvfConfig`].
+    ///
+    /// Vectors stay in a data file next to the database and only cluster
+    /// centroids and per-cluster record positions are held in RAM, so
+    /// collectives larger than memory stay searchable. Each search reads
+    /// the probed clusters from disk, which makes it slower than HNSW.
+    Ivf,
+}
+
+/// Configuration for the disk-backed IVF vector index.
+///
+/// Vectors are partitioned into `nlist` clusters; a search reads the
+/// `nprobe` clusters whose centroids are closest to the query.
 ///
 /// # Example
 /// ```rust
-/// use std::time::Duration;
-/// use pulsedb::Config;
+/// use pulsedb::{Config, IvfConfig};
 ///
 /// let config = Config {
-///     activity: pulsedb::ActivityConfig {
-///         stale_threshold: Duration::from_secs(120), // 2 minutes
+///     ivf: IvfConfig {
+///         nlist: 1024,
+///         nprobe: 32,
 ///     },
 ///     ..Default::default()
 /// };
 /// ```
 #[derive(Clone, Debug)]
-pub struct ActivityConfig {
-    /// Duration after which an activity with no heartbeat is considered stale.
+pub struct IvfConfig {
+    /// Number of clusters.
     ///
-    /// Activities whose `last_heartbeat` is older than `now - stale_threshold`
-    /// are excluded from `get_active_agents()` results. They remain in storage
-    /// until explicitly ended or the collective is deleted.
+    /// Clusters are trained once an index holds 16 vectors per cluster;
+    /// until then every search scans all vectors. More clusters make each
+    /// probe read less data. A good starting point is about the square
+    /// root of the collective's size.
+    /// Default: 256
+    pub nlist: usize,
+
+    /// Number of clusters read per search.
+    ///
+    /// Higher values improve recall at the cost of more disk reads.
+    /// `nprobe >= nlist` makes search exact.
+    /// Default: 16
+    pub nprobe: usize,
+}
+
+impl Default for IvfConfig {
+    fn default() -> Self {
+        Self {
+            nlist: 256,
+            nprobe: 16,
+        }
+    }
+}
+
+/// Configuration for agent activity tracking.
+///
+/// Controls how stale activities are detected and filtered.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use pulsedb::Config;
+///
+/// let config = Config {
+///     activity: pulsedb::ActivityConfig {
+///         stale_threshold: Duration::from_secs(120), // 2 minutes
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct ActivityConfig {
+    /// Duration after which an activity with no heartbeat is considered stale.
+    ///
+    /// Activities whose `last_heartbeat` is older than `now - stale_threshold`
+    /// are excluded from `get_active_agents()` results. They remain in storage
+    /// until explicitly ended or the collective is deleted.
     ///
     /// Default: 5 minutes (300 seconds)
     pub stale_threshold: Duration,
@@ -459,6 +1140,267 @@ impl Default for ActivityConfig {
     }
 }
 
+/// Download settings for the builtin embedding models.
+///
+/// Model files are fetched from the first mirror that serves them, as
+/// `{mirror}/{repo}/resolve/main/{file}` — the HuggingFace Hub layout, which
+/// most Hub mirrors and caching proxies also serve. Interrupted downloads
+/// resume from where they stopped with HTTP `Range` requests, both across
+/// retries and across runs.
+///
+/// # Example
+/// ```rust
+/// use pulsedb::Config;
+///
+/// let config = Config {
+///     model_download: pulsedb::ModelDownloadConfig {
+///         mirrors: vec![
+///             "https://hf-mirror.example.com".into(),
+///             "https://huggingface.co".into(),
+///         ],
+///         ..Default::default()
+///     },
+///     ..Config::with_builtin_embeddings()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct ModelDownloadConfig {
+    /// Base URLs tried in order until one serves the model.
+    ///
+    /// Default: `["https://huggingface.co"]`
+    pub mirrors: Vec<String>,
+
+    /// Never touch the network.
+    ///
+    /// Opening fails if the model files are not already in the cache
+    /// directory, instead of downloading them.
+    ///
+    /// Default: false
+    pub offline: bool,
+
+    /// Attempts per mirror after the first fails.
+    ///
+    /// An attempt that received data resets the count, so a slow but
+    /// progressing download is not abandoned.
+    ///
+    /// Default: 5
+    pub max_retries: u32,
+
+    /// Delay before the first retry, doubling for each further retry up to
+    /// one minute.
+    ///
+    /// Default: 1 second
+    pub retry_backoff: Duration,
+}
+
+impl Default for ModelDownloadConfig {
+    fn default() -> Self {
+        Self {
+            mirrors: vec!["https://huggingface.co".to_string()],
+            offline: false,
+            max_retries: 5,
+            retry_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Retry policy for starting write transactions.
+///
+/// redb admits one write transaction at a time. A write that finds the
+/// lock held waits for it in rounds: the first round lasts
+/// `retry_backoff` and each retry waits twice as long as the one before,
+/// up to one second. A waiting writer takes the lock as soon as it is
+/// released. Once the retries are exhausted the write fails with
+/// [`PulseDBError::Busy`](crate::PulseDBError::Busy), which callers can
+/// tell apart from storage failures and retry later. With the defaults a
+/// write gives up after waiting about five seconds.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use pulsedb::{Config, WriteRetryConfig};
+///
+/// let config = Config {
+///     write_retry: WriteRetryConfig {
+///         max_retries: 4,
+///         retry_backoff: Duration::from_millis(50),
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct WriteRetryConfig {
+    /// Rounds of waiting after the first. Zero gives up after one round.
+    ///
+    /// Default: 10
+    pub max_retries: u32,
+
+    /// Length of the first round of waiting, doubling for each retry up
+    /// to one second.
+    ///
+    /// Default: 10 milliseconds
+    pub retry_backoff: Duration,
+}
+
+impl Default for WriteRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 10,
+            retry_backoff: Duration::from_millis(10),
+        }
+    }
+}
+
+/// Per-operation time limits.
+///
+/// Limits are enforced cooperatively: long-running operations check their
+/// deadline between units of work (one collective's index probe, one
+/// hydrated candidate, a chunk of a rebuild) and give up with
+/// [`PulseDBError::Timeout`](crate::PulseDBError::Timeout) once it has
+/// passed. A single unit of work is never interrupted, so an operation
+/// can overrun its limit by that much. `None` means no limit.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use pulsedb::{Config, TimeoutConfig};
+///
+/// let config = Config {
+///     timeouts: TimeoutConfig {
+///         search: Some(Duration::from_millis(250)),
+///         write_transaction: Some(Duration::from_secs(5)),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TimeoutConfig {
+    /// Limit for one similarity search, from validation to the last
+    /// hydrated result. Searches that fan out over separately named
+    /// collectives, such as
+    /// [`search_across_collectives()`](crate::PulseDB::search_across_collectives),
+    /// apply it to each collective.
+    ///
+    /// Default: None
+    pub search: Option<Duration>,
+
+    /// Limit on waiting for a write transaction while another write holds
+    /// it. Whichever of this and [`Config::write_retry`] runs out first
+    /// ends the wait.
+    ///
+    /// The transaction's own work isn't limited: once started, a write
+    /// runs to commit so it is never left half-applied.
+    ///
+    /// Default: None
+    pub write_transaction: Option<Duration>,
+
+    /// Limit for loading or rebuilding one collective's experience or
+    /// insight index.
+    ///
+    /// A rebuild at open that times out leaves the collective unavailable
+    /// until the database is reopened (see
+    /// [`PulseDB::health()`](crate::PulseDB::health)); one triggered
+    /// later, such as the reload of an idle-evicted index, fails the call
+    /// that needed it and is retried by the next one.
+    ///
+    /// Default: None
+    pub rebuild: Option<Duration>,
+}
+
+/// Configuration for relation suggestions at record time.
+///
+/// Up to `max_suggestions` of a new experience's nearest active neighbors
+/// scoring at least `min_similarity` are suggested as
+/// relation targets. The relation type is guessed from the two experience
+/// types — a solution elaborates on a difficulty, an experience near an
+/// open question answers it, a newer decision supersedes an older one —
+/// and is [`RelatedTo`](crate::RelationType::RelatedTo) otherwise.
+///
+/// # Example
+/// ```rust
+/// use pulsedb::{Config, RelationSuggestionConfig};
+///
+/// let config = Config {
+///     relation_suggestions: Some(RelationSuggestionConfig {
+///         min_similarity: 0.9,
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct RelationSuggestionConfig {
+    /// Minimum similarity for a neighbor to be suggested, on the scale of
+    /// [`Config::score_kind`].
+    ///
+    /// Default: 0.8
+    pub min_similarity: f32,
+
+    /// Maximum suggestions per experience (1-100).
+    ///
+    /// Default: 5
+    pub max_suggestions: usize,
+}
+
+impl Default for RelationSuggestionConfig {
+    fn default() -> Self {
+        Self {
+            min_similarity: 0.8,
+            max_suggestions: 5,
+        }
+    }
+}
+
+/// Configuration for knowledge gap recording.
+///
+/// A search whose nearest indexed experience scores below `min_score` —
+/// or that finds nothing — suggests the collective is missing knowledge.
+/// The nearest experience is taken before the search's filters and
+/// [`SearchFilter::min_similarity`](crate::SearchFilter::min_similarity)
+/// cutoff, which narrow a search without revealing a gap. Such searches
+/// are recorded per collective, written in batches rather than one
+/// transaction per search, and
+/// [`PulseDB::list_knowledge_gaps()`](crate::PulseDB::list_knowledge_gaps)
+/// groups repeats of the same question by embedding similarity.
+///
+/// Scores follow [`Config::score_kind`].
+///
+/// # Example
+/// ```rust
+/// use pulsedb::{Config, KnowledgeGapConfig};
+///
+/// let config = Config {
+///     knowledge_gaps: Some(KnowledgeGapConfig {
+///         min_score: 0.6,
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct KnowledgeGapConfig {
+    /// Searches whose nearest experience scores below this are recorded.
+    ///
+    /// Default: 0.5
+    pub min_score: f32,
+
+    /// Cosine similarity to a gap's centroid at which a recorded search
+    /// joins that gap, in (0.0, 1.0].
+    ///
+    /// Default: 0.85
+    pub cluster_similarity: f32,
+}
+
+impl Default for KnowledgeGapConfig {
+    fn default() -> Self {
+        Self {
+            min_score: 0.5,
+            cluster_similarity: 0.85,
+        }
+    }
+}
+
 /// Configuration for the watch system (in-process and cross-process).
 ///
 /// Controls whether in-process channel subscriptions are enabled, the
@@ -527,6 +1469,9 @@ mod tests {
         assert!(config.embedding_provider.is_external());
         assert_eq!(config.embedding_dimension, EmbeddingDimension::D384);
         assert_eq!(config.cache_size_mb, 64);
+        assert_eq!(config.experience_cache_capacity, 1024);
+        assert_eq!(config.hydration_threads, 4);
+        assert_eq!(config.embedding_threads, 2);
         assert_eq!(config.sync_mode, SyncMode::Normal);
         assert!(config.default_collective.is_none());
     }
@@ -535,6 +1480,13 @@ mod tests {
     fn test_with_builtin_embeddings() {
         let config = Config::with_builtin_embeddings();
         assert!(config.embedding_provider.is_builtin());
+        assert!(matches!(
+            config.embedding_provider,
+            EmbeddingProvider::Builtin {
+                execution_provider: ExecutionProvider::Cpu,
+                ..
+            }
+        ));
     }
 
     #[test]
@@ -660,6 +1612,30 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_hnsw_zero_max_segment_size() {
+        let config = Config {
+            hnsw: HnswConfig {
+                max_segment_size: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_ivf_zero_nprobe() {
+        let config = Config {
+            ivf: IvfConfig {
+                nprobe: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_embedding_dimension_serialization() {
         let dim = EmbeddingDimension::D768;
@@ -707,4 +1683,197 @@ mod tests {
             ValidationError::InvalidField { field, .. } if field == "watch.poll_interval_ms"
         ));
     }
+
+    #[test]
+    fn test_insight_source_cascade_default_is_detach() {
+        let config = Config::default();
+        assert_eq!(config.insight_source_cascade, InsightSourceCascade::Detach);
+    }
+
+    #[test]
+    fn test_validate_embedding_threads_zero() {
+        let config = Config {
+            embedding_threads: 0,
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::InvalidField { field, .. } if field == "embedding_threads"
+        ));
+    }
+
+    #[test]
+    fn test_validate_onnx_sessions_range() {
+        for sessions in [0, 3] {
+            let config = Config {
+                embedding_threads: 2,
+                onnx_sessions: Some(sessions),
+                ..Default::default()
+            };
+            let err = config.validate().unwrap_err();
+            assert!(matches!(
+                err,
+                ValidationError::InvalidField { field, .. } if field == "onnx_sessions"
+            ));
+        }
+        let config = Config {
+            embedding_threads: 4,
+            onnx_sessions: Some(2),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_model_download_mirrors() {
+        let config = Config {
+            model_download: ModelDownloadConfig {
+                mirrors: vec![],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::InvalidField { field, .. } if field == "model_download.mirrors"
+        ));
+
+        let config = Config {
+            model_download: ModelDownloadConfig {
+                mirrors: vec!["ftp://mirror.example.com".into()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = Config {
+            model_download: ModelDownloadConfig {
+                mirrors: vec![],
+                offline: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_hydration_threads_zero() {
+        let config = Config {
+            hydration_threads: 0,
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::InvalidField { field, .. } if field == "hydration_threads"
+        ));
+    }
+
+    #[test]
+    fn test_validate_idle_eviction_zero() {
+        let config = Config {
+            idle_eviction: Some(Duration::ZERO),
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::InvalidField { field, .. } if field == "idle_eviction"
+        ));
+    }
+
+    #[test]
+    fn test_validate_timeouts_zero() {
+        let config = Config {
+            timeouts: TimeoutConfig {
+                rebuild: Some(Duration::ZERO),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::InvalidField { field, .. } if field == "timeouts.rebuild"
+        ));
+    }
+
+    #[test]
+    fn test_validate_knowledge_gaps() {
+        let config = Config {
+            knowledge_gaps: Some(KnowledgeGapConfig::default()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let config = Config {
+            knowledge_gaps: Some(KnowledgeGapConfig {
+                cluster_similarity: 0.0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidField { field, .. })
+                if field == "knowledge_gaps.cluster_similarity"
+        ));
+    }
+
+    #[test]
+    fn test_validate_relation_suggestions() {
+        let config = Config {
+            relation_suggestions: Some(RelationSuggestionConfig::default()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let config = Config {
+            relation_suggestions: Some(RelationSuggestionConfig {
+                max_suggestions: 0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidField { field, .. })
+                if field == "relation_suggestions.max_suggestions"
+        ));
+    }
+
+    #[test]
+    fn test_validate_expiry_sweep_zero() {
+        let config = Config {
+            expiry_sweep: Some(Duration::ZERO),
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::InvalidField { field, .. } if field == "expiry_sweep"
+        ));
+    }
+
+    #[test]
+    fn test_log_content_policy_redacts_by_default() {
+        let config = Config::default();
+        assert_eq!(config.log_content_policy, LogContentPolicy::Never);
+        assert_eq!(
+            config.log_content_policy.redact("api key is hunter2"),
+            "<redacted 18 bytes>"
+        );
+    }
+
+    #[test]
+    fn test_log_content_policy_truncates_on_char_boundary() {
+        let policy = LogContentPolicy::Truncate(2);
+        assert_eq!(policy.redact("héllo"), "hé…");
+        assert_eq!(policy.redact("hé"), "hé");
+        assert_eq!(LogContentPolicy::Truncate(0).redact("x"), "…");
+    }
 }
@@ -0,0 +1,149 @@
+//! Consolidation of similar low-importance experiences.
+//!
+//! Agents record many small observations that say nearly the same thing.
+//! [`consolidate(collective_id, policy)`](crate::PulseDB::consolidate)
+//! folds them into insights:
+//!
+//! 1. Active experiences below the policy's importance threshold are the
+//!    candidates. Oldest first, each unclustered candidate seeds a cluster
+//!    of its nearest unclustered candidates in the vector index that meet
+//!    the similarity threshold.
+//! 2. Each cluster large enough becomes a [`Synthesis`](crate::InsightType::Synthesis)
+//!    insight citing every member. The summary is extractive: the content
+//!    of the member closest to all others (the medoid), with the members'
+//!    centroid as the embedding.
+//! 3. The representative is linked to every other member with a
+//!    [`Summarizes`](crate::RelationType::Summarizes) relation, and all
+//!    members are archived — excluded from search but still readable and
+//!    restorable with [`unarchive_experience()`](crate::PulseDB::unarchive_experience).
+
+pub mod types;
+
+pub use types::{ConsolidatedCluster, ConsolidationPolicy, ConsolidationReport};
+
+use crate::error::{PulseDBError, ValidationError};
+use crate::search::normalized;
+use crate::storage::schema::MAX_INSIGHT_SOURCES;
+
+/// Validates a consolidation policy.
+pub(crate) fn validate_consolidation_policy(
+    policy: &ConsolidationPolicy,
+) -> Result<(), PulseDBError> {
+    if !(0.0..=1.0).contains(&policy.max_importance) {
+        return Err(ValidationError::invalid_field(
+            "max_importance",
+            "must be between 0.0 and 1.0",
+        )
+        .into());
+    }
+    if !(0.0..=1.0).contains(&policy.min_similarity) {
+        return Err(ValidationError::invalid_field(
+            "min_similarity",
+            "must be between 0.0 and 1.0",
+        )
+        .into());
+    }
+    // A cluster cites every member, so seed + neighbors must fit an insight
+    if policy.neighbors == 0 || policy.neighbors >= MAX_INSIGHT_SOURCES {
+        return Err(ValidationError::invalid_field(
+            "neighbors",
+            format!("must be between 1 and {}", MAX_INSIGHT_SOURCES - 1),
+        )
+        .into());
+    }
+    if policy.min_cluster_size < 2 || policy.min_cluster_size > policy.neighbors + 1 {
+        return Err(ValidationError::invalid_field(
+            "min_cluster_size",
+            "must be at least 2 and at most neighbors + 1",
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Unit-length mean of unit-length vectors.
+pub(crate) fn centroid(vectors: &[Vec<f32>]) -> Vec<f32> {
+    let dim = vectors.first().map_or(0, Vec::len);
+    let mut sum = vec![0.0; dim];
+    for vector in vectors {
+        for (total, v) in sum.iter_mut().zip(vector) {
+            *total += v;
+        }
+    }
+    normalized(&sum)
+}
+
+/// Index of the unit-length vector with the highest total similarity to
+/// the others (the first on ties).
+pub(crate) fn medoid(vectors: &[Vec<f32>]) -> usize {
+    let dot = |a: &[f32], b: &[f32]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+    let mut best = (0, f32::NEG_INFINITY);
+    for (i, a) in vectors.iter().enumerate() {
+        let total: f32 = vectors.iter().map(|b| dot(a, b)).sum();
+        if total > best.1 {
+            best = (i, total);
+        }
+    }
+    best.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_is_valid() {
+        assert!(validate_consolidation_policy(&ConsolidationPolicy::default()).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_policies_rejected() {
+        let cases = [
+            (
+                "max_importance",
+                ConsolidationPolicy {
+                    max_importance: 1.5,
+                    ..Default::default()
+                },
+            ),
+            (
+                "min_similarity",
+                ConsolidationPolicy {
+                    min_similarity: -0.1,
+                    ..Default::default()
+                },
+            ),
+            (
+                "neighbors",
+                ConsolidationPolicy {
+                    neighbors: MAX_INSIGHT_SOURCES,
+                    ..Default::default()
+                },
+            ),
+            (
+                "min_cluster_size",
+                ConsolidationPolicy {
+                    min_cluster_size: 1,
+                    ..Default::default()
+                },
+            ),
+        ];
+        for (field, policy) in cases {
+            let err = validate_consolidation_policy(&policy).unwrap_err();
+            assert!(err.is_validation());
+            assert!(err.to_string().contains(field), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_centroid_and_medoid() {
+        let diagonal = std::f32::consts::FRAC_1_SQRT_2;
+        let vectors = vec![vec![1.0, 0.0], vec![diagonal, diagonal], vec![0.0, 1.0]];
+        assert_eq!(medoid(&vectors), 1);
+
+        let mean = centroid(&vectors);
+        let norm = mean.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-6);
+        assert!((mean[0] - mean[1]).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,73 @@
+//! Data types for memory consolidation.
+
+use crate::types::{CollectiveId, ExperienceId, InsightId};
+
+/// Which experiences to consolidate, and how tightly they must cluster.
+#[derive(Clone, Debug)]
+pub struct ConsolidationPolicy {
+    /// Only experiences with importance strictly below this are
+    /// consolidated (default: `0.3`).
+    pub max_importance: f32,
+
+    /// Minimum similarity between a cluster's seed and each other member,
+    /// as scored by [`Config::score_kind`](crate::Config::score_kind)
+    /// (default: `0.85`).
+    pub min_similarity: f32,
+
+    /// Smallest cluster that is consolidated (default: `3`, at least `2`).
+    pub min_cluster_size: usize,
+
+    /// Nearest neighbors fetched from the vector index per seed
+    /// (default: `20`, at most `99`). A cluster holds at most this many
+    /// members besides its seed.
+    pub neighbors: usize,
+}
+
+impl Default for ConsolidationPolicy {
+    fn default() -> Self {
+        Self {
+            max_importance: 0.3,
+            min_similarity: 0.85,
+            min_cluster_size: 3,
+            neighbors: 20,
+        }
+    }
+}
+
+/// One cluster of experiences folded into an insight.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsolidatedCluster {
+    /// The insight summarizing the cluster.
+    pub insight_id: InsightId,
+
+    /// The member closest to all others, whose content became the
+    /// insight's content.
+    pub representative: ExperienceId,
+
+    /// Every member of the cluster, representative included, in the order
+    /// they were cited as the insight's sources.
+    pub members: Vec<ExperienceId>,
+
+    /// Mean cosine similarity of the members to the cluster centroid,
+    /// stored as the insight's confidence.
+    pub cohesion: f32,
+}
+
+/// Outcome of [`PulseDB::consolidate()`](crate::PulseDB::consolidate).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsolidationReport {
+    /// The collective that was consolidated.
+    pub collective_id: CollectiveId,
+
+    /// Active, low-importance experiences considered for clustering.
+    pub candidates: usize,
+
+    /// Clusters consolidated, in the order they were formed.
+    pub clusters: Vec<ConsolidatedCluster>,
+
+    /// Experiences archived.
+    pub archived: usize,
+
+    /// `Summarizes` relations recorded.
+    pub relations: usize,
+}
@@ -0,0 +1,266 @@
+//! Opaque pagination cursors for list APIs.
+
+use std::fmt;
+use std::str::FromStr;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::ValidationError;
+use crate::types::Timestamp;
+
+/// Current cursor layout version.
+const CURSOR_VERSION: u8 = 1;
+
+const FLAG_NEWEST_FIRST: u8 = 0b01;
+const FLAG_POSITION: u8 = 0b10;
+
+/// Order in which a cursor walks a list.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PageDirection {
+    /// Oldest items first.
+    #[default]
+    OldestFirst,
+
+    /// Newest items first.
+    NewestFirst,
+}
+
+/// Opaque, versioned position in a paginated list.
+///
+/// Offset pagination skips or repeats items when records are inserted or
+/// deleted between pages. A cursor instead names the last item a page
+/// returned, by its position in the list's index (timestamp, then ID), so
+/// the next page starts right after it no matter what changed in between:
+///
+/// - items inserted after the position appear on a later page
+/// - items deleted before the position never shift what comes next
+/// - an item is returned at most once per traversal
+///
+/// Start a traversal with [`Cursor::first()`] and pass each page's
+/// [`CursorPage::next`] to the following call until it is `None`.
+///
+/// # Wire format
+///
+/// Cursors cross process boundaries as strings (their
+/// [`Display`](fmt::Display) form, also used by `Serialize`), so clients
+/// can hand them back over HTTP or store them. The string is unpadded
+/// URL-safe base64 of:
+///
+/// ```text
+/// [version: u8 = 1][flags: u8][timestamp: i64 BE][id: 16 bytes]
+/// ```
+///
+/// `flags` bit 0 is the direction (0 oldest first, 1 newest first) and
+/// bit 1 is set when a position follows; a cursor without one (from
+/// [`Cursor::first()`]) is just the two leading bytes. The timestamp is
+/// the item's index timestamp in milliseconds and the ID its raw UUID
+/// bytes. Other bits and versions are rejected, so future layouts can be
+/// told apart. Treat the string as opaque: the layout is documented for
+/// compatibility, not for constructing cursors by hand.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{Cursor, PageDirection};
+///
+/// let cursor = Cursor::first(PageDirection::NewestFirst);
+/// let wire = cursor.to_string();
+/// assert_eq!(wire.parse::<Cursor>().unwrap(), cursor);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cursor {
+    direction: PageDirection,
+    position: Option<(Timestamp, [u8; 16])>,
+}
+
+impl Cursor {
+    /// A cursor before the first item, walking in `direction`.
+    pub fn first(direction: PageDirection) -> Self {
+        Self {
+            direction,
+            position: None,
+        }
+    }
+
+    /// A cursor just past the item at `(timestamp, id)`.
+    pub(crate) fn after(direction: PageDirection, timestamp: Timestamp, id: [u8; 16]) -> Self {
+        Self {
+            direction,
+            position: Some((timestamp, id)),
+        }
+    }
+
+    /// The direction this cursor walks in.
+    pub fn direction(&self) -> PageDirection {
+        self.direction
+    }
+
+    /// The index position of the last item returned, or `None` before the
+    /// first page.
+    pub(crate) fn position(&self) -> Option<(Timestamp, [u8; 16])> {
+        self.position
+    }
+
+    /// Whether items come newest first.
+    pub(crate) fn newest_first(&self) -> bool {
+        self.direction == PageDirection::NewestFirst
+    }
+
+    /// Encodes the cursor in its wire format.
+    pub fn encode(&self) -> String {
+        let mut bytes = Vec::with_capacity(26);
+        bytes.push(CURSOR_VERSION);
+        let mut flags = 0;
+        if self.newest_first() {
+            flags |= FLAG_NEWEST_FIRST;
+        }
+        if self.position.is_some() {
+            flags |= FLAG_POSITION;
+        }
+        bytes.push(flags);
+        if let Some((timestamp, id)) = self.position {
+            bytes.extend_from_slice(&timestamp.to_be_bytes());
+            bytes.extend_from_slice(&id);
+        }
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    /// Decodes a cursor from its wire format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::InvalidField`] if the string is not a
+    /// cursor this version of PulseDB can read.
+    pub fn decode(encoded: &str) -> Result<Self, ValidationError> {
+        let invalid = |reason: &str| ValidationError::invalid_field("cursor", reason);
+        let bytes = URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|_| invalid("not base64"))?;
+        let (&version, rest) = bytes.split_first().ok_or_else(|| invalid("empty"))?;
+        if version != CURSOR_VERSION {
+            return Err(invalid(&format!("unsupported cursor version {}", version)));
+        }
+        let (&flags, rest) = rest.split_first().ok_or_else(|| invalid("truncated"))?;
+        if flags & !(FLAG_NEWEST_FIRST | FLAG_POSITION) != 0 {
+            return Err(invalid("unknown cursor flags"));
+        }
+        let direction = if flags & FLAG_NEWEST_FIRST != 0 {
+            PageDirection::NewestFirst
+        } else {
+            PageDirection::OldestFirst
+        };
+        let position = if flags & FLAG_POSITION != 0 {
+            if rest.len() != 24 {
+                return Err(invalid("truncated"));
+            }
+            let mut timestamp = [0u8; 8];
+            timestamp.copy_from_slice(&rest[..8]);
+            let mut id = [0u8; 16];
+            id.copy_from_slice(&rest[8..]);
+            Some((Timestamp::from_millis(i64::from_be_bytes(timestamp)), id))
+        } else {
+            if !rest.is_empty() {
+                return Err(invalid("trailing bytes"));
+            }
+            None
+        };
+        Ok(Self {
+            direction,
+            position,
+        })
+    }
+}
+
+impl Default for Cursor {
+    /// The start of an oldest-first traversal.
+    fn default() -> Self {
+        Self::first(PageDirection::OldestFirst)
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::decode(s)
+    }
+}
+
+impl Serialize for Cursor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.encode())
+    }
+}
+
+impl<'de> Deserialize<'de> for Cursor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        Self::decode(&encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+/// One page of a cursor-paginated list.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CursorPage<T> {
+    /// The items on this page, in the cursor's direction.
+    pub items: Vec<T>,
+
+    /// Cursor for the following page, or `None` if this page reached the
+    /// end of the list.
+    pub next: Option<Cursor>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let cursor = Cursor::after(
+            PageDirection::NewestFirst,
+            Timestamp::from_millis(1_700_000_000_000),
+            [7; 16],
+        );
+        let wire = cursor.encode();
+        assert!(!wire.contains('='));
+        assert_eq!(Cursor::decode(&wire).unwrap(), cursor);
+        assert_eq!(
+            Cursor::decode(&Cursor::default().encode()).unwrap(),
+            Cursor::first(PageDirection::OldestFirst)
+        );
+
+        let json = serde_json::to_string(&cursor).unwrap();
+        assert_eq!(json, format!("\"{}\"", wire));
+        assert_eq!(serde_json::from_str::<Cursor>(&json).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_wire_layout_is_stable() {
+        // Version 1, newest first with a position
+        let cursor = Cursor::after(
+            PageDirection::NewestFirst,
+            Timestamp::from_millis(1),
+            [0; 16],
+        );
+        let bytes = URL_SAFE_NO_PAD.decode(cursor.encode()).unwrap();
+        assert_eq!(&bytes[..2], &[1, 0b11]);
+        assert_eq!(&bytes[2..10], &1i64.to_be_bytes());
+        assert_eq!(bytes.len(), 26);
+        assert_eq!(Cursor::first(PageDirection::OldestFirst).encode(), "AQA");
+    }
+
+    #[test]
+    fn test_invalid_cursors_are_rejected() {
+        for bad in ["", "!!!", "AgA", "AQQ", "AQI", "AQAA"] {
+            assert!(Cursor::decode(bad).is_err(), "accepted {:?}", bad);
+        }
+    }
+}
@@ -58,34 +58,129 @@
 //! # }
 //! ```
 
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
-
-#[cfg(feature = "sync")]
-use tracing::debug;
-use tracing::{info, instrument, warn};
-
-use crate::activity::{validate_new_activity, Activity, NewActivity};
-use crate::collective::types::CollectiveStats;
-use crate::collective::{validate_collective_name, Collective};
-use crate::config::{Config, EmbeddingProvider};
-use crate::embedding::{create_embedding_service, EmbeddingService};
-use crate::error::{NotFoundError, PulseDBError, Result, ValidationError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::time::{Duration, Instant};
+
+use tracing::{debug, info, instrument, warn};
+
+use crate::activity::{validate_agent_id, validate_new_activity, Activity, NewActivity};
+use crate::collective::types::{
+    CollectiveQuota, CollectiveStats, CollectiveUpdate, EmbeddingStats, OwnerStats,
+};
+use crate::collective::{validate_collective_name, validate_collective_update, Collective};
+use crate::config::{
+    Config, ContentStorage, EmbeddingProvider, EmbeddingStorage, IdStrategy, InsightSourceCascade,
+    RelationSuggestionConfig, VectorIndexKind,
+};
+use crate::consolidation::{
+    centroid, medoid, validate_consolidation_policy, ConsolidatedCluster, ConsolidationPolicy,
+    ConsolidationReport,
+};
+use crate::cursor::{Cursor, CursorPage};
+use crate::deadline::Deadline;
+use crate::embedding::{create_embedding_service, EmbeddingService, TextNormalization};
+use crate::erasure::ErasureReport;
+use crate::error::{
+    NotFoundError, PulseDBError, Result, StorageError, TimedOperation, ValidationError,
+};
+use crate::eval::{
+    latency_summary, ndcg_at_k, recall_at_k, validate_eval_set, validate_retrieval_config,
+    EvalReport, EvalSet, RetrievalConfig,
+};
 use crate::experience::{
-    validate_experience_update, validate_new_experience, Experience, ExperienceUpdate,
-    NewExperience,
+    validate_experience_update, validate_new_experience, BatchOutcome, BatchReport, ContentPolicy,
+    ContentResolver, Episode, Experience, ExperienceUpdate, NewExperience,
+};
+use crate::export::training::TrainingExample;
+use crate::export::{
+    export_experience, restore_experience, CollectiveExportReport, CollectiveImportReport,
+    ExportKind, ExportManifest, ExportReader, ExportRecord, ExportWriter, ImportReport,
+    TrainingExportReport, TrainingFormat,
+};
+use crate::fence::{FenceGuard, WriteFence};
+use crate::health::{HealthReport, UnavailableCollective};
+use crate::hook::{CommittedWrite, Hook, PendingWrite, ReadHook, ReadRecord};
+use crate::insight::{
+    validate_new_insight, DerivedInsight, InsightFilter, InsightType, NewDerivedInsight,
+};
+use crate::lock::{validate_lock_name, validate_lock_request, Lease};
+use crate::maintenance::{
+    validate_maintenance_policy, DuplicateGroup, MaintenancePlan, MaintenancePolicy,
+    MaintenanceReport,
+};
+use crate::metrics::{DatabaseMetrics, IndexLockStats, LockWaitStats, TimedRwLock};
+use crate::moderation::ModerationPolicy;
+use crate::pin::{IndexPins, PinGuard};
+use crate::relation::{
+    suggest_relation_type, ExperienceRelation, NewExperienceRelation, RelationFilter, RelationSort,
+    RelationSuggestion, RelationType,
+};
+use crate::scope::{Capabilities, ScopedDb};
+use crate::search::answer::{self, AnswerSupport, Evidence, Stance};
+use crate::search::changeset::{self, ChangesetKnowledge, FileExperience, FileInsight};
+use crate::search::{
+    bisector, cluster_gaps, normalized, select_diverse, ContextCandidates, ContextCost,
+    ContextItem, ContextRequest, ExperienceNeighbor, ItemCost, KnowledgeGap, KnowledgeGapRecord,
+    Query, QueryExplain, RepresentativeQuery, Reranker, SearchFilter, SearchResult, TokenCounter,
+    REPRESENTATIVE_QUERY_DEPTH, RERANK_OVER_FETCH,
+};
+use crate::storage::schema::{
+    agent_hash, EntityTypeTag, ExperienceTypeTag, MAX_INSIGHT_CONTENT_SIZE,
+};
+use crate::storage::{open_storage, DatabaseMetadata, ExperienceDeletion, StorageEngine};
+use crate::types::{
+    AgentId, CollectiveId, Embedding, EpisodeId, ExperienceId, InsightId, Page, RelationId, TaskId,
+    Timestamp, UserId,
+};
+use crate::vector::snapshot;
+use crate::vector::{
+    CollectiveIndex, HnswIndex, IndexMetadata, IndexRole, IndexSnapshotManifest, IvfIndex,
+    VectorBackend,
+};
+use crate::watch::{
+    Change, ChangeSubscription, Interest, WatchEvent, WatchEventType, WatchFilter, WatchService,
+    WatchStream,
 };
-use crate::insight::{validate_new_insight, DerivedInsight, NewDerivedInsight};
-#[cfg(feature = "sync")]
-use crate::relation::ExperienceRelation;
-use crate::search::{ContextCandidates, ContextRequest, SearchFilter, SearchResult};
-use crate::storage::{open_storage, DatabaseMetadata, StorageEngine};
-#[cfg(feature = "sync")]
-use crate::types::RelationId;
-use crate::types::{CollectiveId, ExperienceId, InsightId, Timestamp};
-use crate::vector::HnswIndex;
-use crate::watch::{WatchEvent, WatchEventType, WatchFilter, WatchService, WatchStream};
+
+/// Changelog events read per batch by [`PulseDB::backup_incremental`].
+const EXPORT_CHANGELOG_BATCH: usize = 1000;
+
+/// Most changes returned by one [`PulseDB::changes_since`] call.
+const CHANGES_PAGE_SIZE: usize = 1000;
+
+/// Database file name inside a [`PulseDB::backup_to`] directory.
+const BACKUP_DB_FILE: &str = "pulse.db";
+
+/// Number of most recent experience records read by [`PulseDB::warm_collective`].
+const WARM_PREFETCH_EXPERIENCES: usize = 256;
+
+/// Neighbor lists written per transaction by [`PulseDB::compute_neighbor_graph`].
+const NEIGHBOR_GRAPH_BATCH: usize = 1000;
+
+/// Nearest-neighbor similarity at which [`PulseDB::embedding_stats`] counts
+/// an embedding as a near duplicate.
+const NEAR_DUPLICATE_SIMILARITY: f32 = 0.98;
+
+/// Knowledge gaps queued by searches before one of them writes the batch.
+const KNOWLEDGE_GAP_BATCH: usize = 64;
+
+/// Search candidates per unit of work claimed by a hydration worker.
+const HYDRATION_MORSEL: usize = 16;
+
+/// Whether [`PulseDB::record_new_experience`] stored a new experience.
+enum Recorded {
+    /// Stored under this ID.
+    New(ExperienceId),
+
+    /// Nothing stored: the ID already exists.
+    Existing(ExperienceId),
+}
 
 /// The main PulseDB database handle.
 ///
@@ -104,27 +199,96 @@ pub struct PulseDB {
     /// Embedding service (external or ONNX).
     embedding: Box<dyn EmbeddingService>,
 
+    /// Whether `embedding` was supplied to
+    /// [`open_with_embedding()`](Self::open_with_embedding), in which case
+    /// it generates embeddings whatever the configured provider.
+    injected_embedding: bool,
+
     /// Configuration used to open this database.
     config: Config,
 
-    /// Per-collective HNSW vector indexes for experience semantic search.
+    /// Per-collective vector indexes for experience semantic search.
     ///
     /// Outer RwLock protects the HashMap (add/remove collectives).
-    /// Each HnswIndex has its own internal RwLock for concurrent search+insert.
-    vectors: RwLock<HashMap<CollectiveId, HnswIndex>>,
+    /// Each index has its own internal RwLock for concurrent search+insert.
+    /// HNSW unless the collective was switched with
+    /// [`set_vector_index_kind`](Self::set_vector_index_kind).
+    vectors: TimedRwLock<HashMap<CollectiveId, CollectiveIndex>>,
 
     /// Per-collective HNSW vector indexes for insight semantic search.
     ///
     /// Separate from `vectors` to prevent ID collisions between experiences
     /// and insights. Uses InsightId→ExperienceId byte conversion for the
     /// HNSW API (safe because indexes are isolated per collective).
-    insight_vectors: RwLock<HashMap<CollectiveId, HnswIndex>>,
+    insight_vectors: TimedRwLock<HashMap<CollectiveId, CollectiveIndex>>,
 
     /// Watch service for real-time experience change notifications.
     ///
     /// Arc-wrapped because [`WatchStream`] holds a weak reference for
     /// cleanup on drop.
     watch: Arc<WatchService>,
+
+    /// Collectives currently frozen for maintenance, and the writes in
+    /// flight against each.
+    ///
+    /// Writes targeting a frozen collective fail with
+    /// [`PulseDBError::Busy`]; reads are unaffected. In-memory only —
+    /// a reopened database starts with no frozen collectives.
+    fence: WriteFence,
+
+    /// Collectives whose indexes failed to load at open, with the reason.
+    ///
+    /// Reads and writes targeting them fail with
+    /// [`PulseDBError::Unavailable`]; see [`PulseDB::health`].
+    unavailable: RwLock<HashMap<CollectiveId, String>>,
+
+    /// Experiences written to redb whose vector index insert failed,
+    /// with their collective.
+    ///
+    /// Re-inserted on the next write or index search and counted by
+    /// [`PulseDB::health`] until then. In-memory only — a reopened
+    /// database rebuilds its indexes from redb anyway.
+    unindexed: Mutex<HashMap<ExperienceId, CollectiveId>>,
+
+    /// Knowledge gaps recorded by searches but not yet written.
+    ///
+    /// Written in one transaction by the next write, by the search that
+    /// queues the [`KNOWLEDGE_GAP_BATCH`]th, or on [`PulseDB::close`], so
+    /// searches don't each take the write lock. Listings include them.
+    queued_gaps: Mutex<Vec<KnowledgeGapRecord>>,
+
+    /// Last time each resident collective's indexes were used.
+    ///
+    /// Only maintained when [`Config::idle_eviction`] is set; drives
+    /// [`PulseDB::evict_idle_collectives`].
+    last_access: Mutex<HashMap<CollectiveId, Instant>>,
+
+    /// Collectives whose indexes a search or write is using right now.
+    ///
+    /// Idle eviction skips them.
+    pins: IndexPins,
+
+    /// When the last automatic idle sweep ran.
+    last_sweep: Mutex<Instant>,
+
+    /// Held from a quota check until the experiences it admitted are
+    /// written, so concurrent writers can't both take the last room.
+    quota_lock: Mutex<()>,
+
+    /// When the last automatic expiry sweep ran.
+    ///
+    /// Only consulted when [`Config::expiry_sweep`] is set.
+    last_expiry_sweep: Mutex<Instant>,
+
+    /// Resolves content handles when [`Config::content_storage`] is
+    /// [`ContentStorage::External`].
+    content_resolver: RwLock<Option<Arc<dyn ContentResolver>>>,
+
+    /// Write hooks, run in registration order.
+    hooks: RwLock<Vec<Arc<dyn Hook>>>,
+
+    /// Read hooks per collective, run in registration order.
+    read_hooks: RwLock<HashMap<CollectiveId, Vec<Arc<dyn ReadHook>>>>,
 }
 
 impl std::fmt::Debug for PulseDB {
@@ -182,25 +346,94 @@ impl PulseDB {
     /// ```
     #[instrument(skip(config), fields(path = %path.as_ref().display()))]
     pub fn open(path: impl AsRef<Path>, config: Config) -> Result<Self> {
+        Self::open_with_service(path.as_ref(), config, None)
+    }
+
+    /// Opens or creates a database that embeds text with `embedding`.
+    ///
+    /// Works like [`open()`](Self::open), but records without an embedding,
+    /// insights, and [`embed_query()`](Self::embed_query) use the given
+    /// service — an HTTP client for a local model server, say — instead of
+    /// the one [`Config::embedding_provider`] selects, which is ignored.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::DimensionMismatch`] if the service's dimension
+    ///   differs from [`Config::embedding_dimension`]
+    /// - Any error from [`open()`](Self::open)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// use pulsedb::embedding::HashingEmbedding;
+    /// use pulsedb::{Config, NewExperience, PulseDB};
+    ///
+    /// // Any EmbeddingService implementation can be supplied
+    /// let service = Box::new(HashingEmbedding::new(384));
+    /// let db = PulseDB::open_with_embedding(dir.path().join("test.db"), Config::default(), service)?;
+    ///
+    /// let cid = db.create_collective("example")?;
+    /// db.record_experience(NewExperience {
+    ///     collective_id: cid,
+    ///     content: "embedded by the injected service".into(),
+    ///     ..Default::default()
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(config, embedding), fields(path = %path.as_ref().display()))]
+    pub fn open_with_embedding(
+        path: impl AsRef<Path>,
+        config: Config,
+        embedding: Box<dyn EmbeddingService>,
+    ) -> Result<Self> {
+        let expected = config.embedding_dimension.size();
+        if embedding.dimension() != expected {
+            return Err(
+                ValidationError::dimension_mismatch(expected, embedding.dimension()).into(),
+            );
+        }
+        Self::open_with_service(path.as_ref(), config, Some(embedding))
+    }
+
+    /// Shared body of [`open()`](Self::open) and
+    /// [`open_with_embedding()`](Self::open_with_embedding); the service
+    /// comes from the config unless one is injected.
+    fn open_with_service(
+        path: &Path,
+        config: Config,
+        injected: Option<Box<dyn EmbeddingService>>,
+    ) -> Result<Self> {
         // Validate configuration first
         config.validate().map_err(PulseDBError::from)?;
 
         info!("Opening PulseDB");
 
         // Open storage engine
-        let storage = open_storage(&path, &config)?;
+        let storage = open_storage(path, &config)?;
 
         // Create embedding service
-        let embedding = create_embedding_service(&config)?;
+        let injected_embedding = injected.is_some();
+        let embedding = match injected {
+            Some(embedding) => embedding,
+            None => create_embedding_service(&config)?,
+        };
 
-        // Load or rebuild HNSW indexes for all existing collectives
-        let vectors = Self::load_all_indexes(&*storage, &config)?;
-        let insight_vectors = Self::load_all_insight_indexes(&*storage, &config)?;
+        // Load or rebuild HNSW indexes for all existing collectives. A
+        // collective that fails to load is taken out of service instead of
+        // failing the open.
+        let mut unavailable = HashMap::new();
+        let mut vectors = Self::load_all_indexes(&*storage, &config, &mut unavailable)?;
+        let insight_vectors = Self::load_all_insight_indexes(&*storage, &config, &mut unavailable)?;
+        vectors.retain(|id, _| !unavailable.contains_key(id));
 
         info!(
             dimension = config.embedding_dimension.size(),
             sync_mode = ?config.sync_mode,
             collectives = vectors.len(),
+            unavailable = unavailable.len(),
             "PulseDB opened successfully"
         );
 
@@ -208,15 +441,51 @@ impl PulseDB {
             config.watch.buffer_size,
             config.watch.in_process,
         ));
+        let listener_watch = Arc::downgrade(&watch);
+        storage.set_commit_listener(Arc::new(move |storage: &dyn StorageEngine| {
+            if let Some(watch) = listener_watch.upgrade() {
+                watch.publish_changes(storage);
+            }
+        }));
+
+        // Every index loaded at open counts as freshly used
+        let now = Instant::now();
+        let last_access = if config.idle_eviction.is_some() {
+            vectors.keys().map(|id| (*id, now)).collect()
+        } else {
+            HashMap::new()
+        };
 
-        Ok(Self {
+        let db = Self {
             storage,
             embedding,
+            injected_embedding,
             config,
-            vectors: RwLock::new(vectors),
-            insight_vectors: RwLock::new(insight_vectors),
+            vectors: TimedRwLock::new(vectors),
+            insight_vectors: TimedRwLock::new(insight_vectors),
             watch,
-        })
+            fence: WriteFence::default(),
+            unavailable: RwLock::new(unavailable),
+            unindexed: Mutex::new(HashMap::new()),
+            queued_gaps: Mutex::new(Vec::new()),
+            last_access: Mutex::new(last_access),
+            pins: IndexPins::default(),
+            last_sweep: Mutex::new(now),
+            quota_lock: Mutex::new(()),
+            last_expiry_sweep: Mutex::new(now),
+            content_resolver: RwLock::new(None),
+            hooks: RwLock::new(Vec::new()),
+            read_hooks: RwLock::new(HashMap::new()),
+        };
+
+        // Experiences that expired while the database was closed go first
+        if db.config.expiry_sweep.is_some() && !db.config.read_only {
+            if let Err(e) = db.purge_expired() {
+                warn!(error = %e, "Expiry sweep on open failed");
+            }
+        }
+
+        Ok(db)
     }
 
     /// Closes the database, flushing all pending writes.
@@ -248,42 +517,13 @@ impl PulseDB {
     #[instrument(skip(self))]
     pub fn close(self) -> Result<()> {
         info!("Closing PulseDB");
+        self.write_queued_gaps();
 
         // Persist HNSW indexes BEFORE closing storage.
         // If HNSW save fails, storage is still open for potential recovery.
         // On next open(), stale/missing HNSW files trigger a rebuild from redb.
         if let Some(hnsw_dir) = self.hnsw_dir() {
-            // Experience HNSW indexes
-            let vectors = self
-                .vectors
-                .read()
-                .map_err(|_| PulseDBError::vector("Vectors lock poisoned during close"))?;
-            for (collective_id, index) in vectors.iter() {
-                if let Err(e) = index.save_to_dir(&hnsw_dir, &collective_id.to_string()) {
-                    warn!(
-                        collective = %collective_id,
-                        error = %e,
-                        "Failed to save HNSW index (will rebuild on next open)"
-                    );
-                }
-            }
-            drop(vectors);
-
-            // Insight HNSW indexes (separate files with _insights suffix)
-            let insight_vectors = self
-                .insight_vectors
-                .read()
-                .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned during close"))?;
-            for (collective_id, index) in insight_vectors.iter() {
-                let name = format!("{}_insights", collective_id);
-                if let Err(e) = index.save_to_dir(&hnsw_dir, &name) {
-                    warn!(
-                        collective = %collective_id,
-                        error = %e,
-                        "Failed to save insight HNSW index (will rebuild on next open)"
-                    );
-                }
-            }
+            self.save_indexes(&hnsw_dir)?;
         }
 
         // Close storage (flushes pending writes)
@@ -321,6 +561,128 @@ impl PulseDB {
         self.config.embedding_dimension.size()
     }
 
+    /// Reports collectives taken out of service because they failed to
+    /// load at open, and experiences missing from their vector index.
+    ///
+    /// A damaged collective doesn't fail [`open()`](Self::open); it is
+    /// listed here instead, and reads and writes against it return
+    /// [`PulseDBError::Unavailable`]. An experience whose index insert
+    /// failed after it was stored is counted until a later write or
+    /// search re-inserts it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// let health = db.health()?;
+    /// for collective in &health.unavailable {
+    ///     eprintln!("{} unavailable: {}", collective.collective_id, collective.reason);
+    /// }
+    /// # assert!(!health.is_degraded());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn health(&self) -> Result<HealthReport> {
+        let unavailable = self
+            .unavailable
+            .read()
+            .map_err(|_| PulseDBError::internal("Unavailable set lock poisoned"))?;
+        let mut unavailable: Vec<UnavailableCollective> = unavailable
+            .iter()
+            .map(|(id, reason)| UnavailableCollective {
+                collective_id: *id,
+                reason: reason.clone(),
+            })
+            .collect();
+        unavailable.sort_by_key(|c| c.collective_id.0);
+        let unindexed_experiences = self
+            .unindexed
+            .lock()
+            .map_err(|_| PulseDBError::internal("Unindexed set lock poisoned"))?
+            .len();
+        Ok(HealthReport {
+            collective_count: self.storage.list_collectives()?.len(),
+            unavailable,
+            unindexed_experiences,
+        })
+    }
+
+    /// Returns lock contention metrics accumulated since open.
+    ///
+    /// Reports time spent waiting on the lock over the index map, on each
+    /// resident index's internal locks, and to begin storage write
+    /// transactions. See [`DatabaseMetrics`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// let metrics = db.metrics()?;
+    /// let writes = &metrics.write_transactions;
+    /// println!(
+    ///     "{} of {} write transactions queued, mean wait {:?}",
+    ///     writes.contended,
+    ///     writes.acquisitions,
+    ///     writes.mean_wait()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn metrics(&self) -> Result<DatabaseMetrics> {
+        let mut vectors_lock = self.vectors.waits();
+        vectors_lock.merge(&self.insight_vectors.waits());
+
+        let mut by_collective: HashMap<CollectiveId, LockWaitStats> = HashMap::new();
+        {
+            let vectors = self
+                .vectors
+                .read()
+                .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+            for (id, index) in vectors.iter() {
+                by_collective
+                    .entry(*id)
+                    .or_default()
+                    .merge(&index.lock_waits());
+            }
+        }
+        {
+            let insight_vectors = self
+                .insight_vectors
+                .read()
+                .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+            for (id, index) in insight_vectors.iter() {
+                by_collective
+                    .entry(*id)
+                    .or_default()
+                    .merge(&index.lock_waits());
+            }
+        }
+
+        let mut index_locks = LockWaitStats::default();
+        let mut indexes: Vec<IndexLockStats> = by_collective
+            .into_iter()
+            .map(|(collective_id, waits)| {
+                index_locks.merge(&waits);
+                IndexLockStats {
+                    collective_id,
+                    waits,
+                }
+            })
+            .collect();
+        indexes.sort_by_key(|i| i.collective_id.0);
+
+        Ok(DatabaseMetrics {
+            vectors_lock,
+            index_locks,
+            indexes,
+            write_transactions: self.storage.write_waits(),
+        })
+    }
+
     // =========================================================================
     // Internal Accessors (for use by feature modules)
     // =========================================================================
@@ -359,17 +721,55 @@ impl PulseDB {
         })
     }
 
-    /// Loads or rebuilds HNSW indexes for all existing collectives.
+    /// Saves every resident experience and insight index under `dir`.
     ///
-    /// For each collective in storage:
-    /// 1. Try loading metadata from `.hnsw.meta` file
-    /// 2. Rebuild the graph from redb embeddings (always, since we can't
-    ///    load the graph due to hnsw_rs lifetime constraints)
-    /// 3. Restore deleted set from metadata if available
+    /// Failures are logged rather than returned: a missing or stale index
+    /// file only means the index is rebuilt from redb on the next open.
+    fn save_indexes(&self, dir: &Path) -> Result<()> {
+        // Experience HNSW indexes
+        let vectors = self
+            .vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+        for (collective_id, index) in vectors.iter() {
+            if let Err(e) = index.save_to_dir(dir, &collective_id.to_string()) {
+                warn!(
+                    collective = %collective_id,
+                    error = %e,
+                    "Failed to save HNSW index (will rebuild on next open)"
+                );
+            }
+        }
+        drop(vectors);
+
+        // Insight HNSW indexes (separate files with _insights suffix)
+        let insight_vectors = self
+            .insight_vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+        for (collective_id, index) in insight_vectors.iter() {
+            let name = format!("{}_insights", collective_id);
+            if let Err(e) = index.save_to_dir(dir, &name) {
+                warn!(
+                    collective = %collective_id,
+                    error = %e,
+                    "Failed to save insight HNSW index (will rebuild on next open)"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads or rebuilds experience indexes for all existing collectives.
+    ///
+    /// See [`build_experience_index`](Self::build_experience_index) for the
+    /// per-collective procedure. Collectives that fail to load are recorded
+    /// in `unavailable` and skipped.
     fn load_all_indexes(
         storage: &dyn StorageEngine,
         config: &Config,
-    ) -> Result<HashMap<CollectiveId, HnswIndex>> {
+        unavailable: &mut HashMap<CollectiveId, String>,
+    ) -> Result<HashMap<CollectiveId, CollectiveIndex>> {
         let collectives = storage.list_collectives()?;
         let mut vectors = HashMap::with_capacity(collectives.len());
 
@@ -380,135 +780,571 @@ impl PulseDB {
         });
 
         for collective in &collectives {
-            let dimension = collective.embedding_dimension as usize;
+            match Self::build_experience_index(storage, config, collective, hnsw_dir.as_deref()) {
+                Ok(index) => {
+                    vectors.insert(collective.id, index);
+                }
+                Err(e) => {
+                    warn!(
+                        collective = %collective.id,
+                        error = %e,
+                        "Experience index failed to load, collective unavailable"
+                    );
+                    unavailable.insert(collective.id, e.to_string());
+                }
+            }
+        }
 
-            // List all experience IDs in this collective
-            let exp_ids = storage.list_experience_ids_in_collective(collective.id)?;
+        Ok(vectors)
+    }
 
-            // Load embeddings from redb (source of truth)
-            let mut embeddings = Vec::with_capacity(exp_ids.len());
-            for exp_id in &exp_ids {
-                if let Some(embedding) = storage.get_embedding(*exp_id)? {
-                    embeddings.push((*exp_id, embedding));
+    /// Loads or rebuilds the experience index for one collective, of the
+    /// kind recorded for it, or with the configured [`VectorBackend`].
+    ///
+    /// IVF indexes need a data file; storage without a path falls back to
+    /// HNSW. Limited by [`TimeoutConfig::rebuild`](crate::TimeoutConfig::rebuild).
+    fn build_experience_index(
+        storage: &dyn StorageEngine,
+        config: &Config,
+        collective: &Collective,
+        hnsw_dir: Option<&Path>,
+    ) -> Result<CollectiveIndex> {
+        let deadline = Deadline::start(TimedOperation::Rebuild, config.timeouts.rebuild);
+        if let Some(backend) = &config.vector_backend {
+            let mut embeddings = Vec::new();
+            for exp_id in storage.list_experience_ids_in_collective(collective.id)? {
+                deadline.check()?;
+                if let Some(embedding) = storage.get_embedding(exp_id)? {
+                    embeddings.push((exp_id, embedding));
                 }
             }
+            return Self::build_custom_index(
+                backend,
+                collective,
+                IndexRole::Experiences,
+                embeddings,
+            );
+        }
 
-            // Try loading metadata (for deleted set and ID mappings)
-            let metadata = hnsw_dir
-                .as_ref()
-                .and_then(|dir| HnswIndex::load_metadata(dir, &collective.id.to_string()).ok())
-                .flatten();
+        match (storage.get_collective_index_kind(collective.id)?, hnsw_dir) {
+            (VectorIndexKind::Ivf, Some(dir)) => Ok(CollectiveIndex::Ivf(Self::build_ivf_index(
+                storage, config, collective, dir, &deadline,
+            )?)),
+            (kind, _) => {
+                if kind == VectorIndexKind::Ivf {
+                    warn!(
+                        collective = %collective.id,
+                        "IVF index needs a file-backed database, using HNSW"
+                    );
+                }
+                Ok(CollectiveIndex::Hnsw(Self::build_hnsw_index(
+                    storage, config, collective, hnsw_dir, &deadline,
+                )?))
+            }
+        }
+    }
 
-            // Rebuild the HNSW graph from embeddings
-            let index = if embeddings.is_empty() {
-                HnswIndex::new(dimension, &config.hnsw)
-            } else {
-                let start = std::time::Instant::now();
-                let idx = HnswIndex::rebuild_from_embeddings(dimension, &config.hnsw, embeddings)?;
-                info!(
-                    collective = %collective.id,
-                    vectors = idx.active_count(),
-                    elapsed_ms = start.elapsed().as_millis() as u64,
-                    "Rebuilt HNSW index from redb embeddings"
-                );
-                idx
-            };
+    /// Builds an index with the configured backend and fills it with
+    /// `embeddings` in one batch.
+    fn build_custom_index(
+        backend: &VectorBackend,
+        collective: &Collective,
+        role: IndexRole,
+        embeddings: Vec<(ExperienceId, Vec<f32>)>,
+    ) -> Result<CollectiveIndex> {
+        let start = Instant::now();
+        let index = backend.build(collective.id, role, collective.embedding_dimension as usize)?;
+        let items: Vec<(ExperienceId, &Vec<f32>)> =
+            embeddings.iter().map(|(id, e)| (*id, e)).collect();
+        index.insert_experiences(&items)?;
+        info!(
+            collective = %collective.id,
+            role = ?role,
+            vectors = index.active_count(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Rebuilt custom vector index from redb"
+        );
+        Ok(CollectiveIndex::Custom(index))
+    }
 
-            // Restore deleted set from metadata if available
-            if let Some(meta) = metadata {
-                index.restore_deleted_set(&meta.deleted)?;
+    /// Rebuilds the disk-backed IVF experience index for one collective.
+    ///
+    /// Streams embeddings from redb into a fresh data file one at a time,
+    /// so the collective never has to fit in memory.
+    fn build_ivf_index(
+        storage: &dyn StorageEngine,
+        config: &Config,
+        collective: &Collective,
+        dir: &Path,
+        deadline: &Deadline,
+    ) -> Result<IvfIndex> {
+        let start = Instant::now();
+        let index = IvfIndex::create(
+            collective.embedding_dimension as usize,
+            &config.ivf,
+            dir,
+            &collective.id.to_string(),
+        )?;
+        for exp_id in storage.list_experience_ids_in_collective(collective.id)? {
+            deadline.check()?;
+            if let Some(embedding) = storage.get_embedding(exp_id)? {
+                index.insert_experience(exp_id, &embedding)?;
             }
+        }
+        info!(
+            collective = %collective.id,
+            vectors = index.active_count(),
+            clusters = index.cluster_count(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "Rebuilt IVF index from redb embeddings"
+        );
 
-            vectors.insert(collective.id, index);
+        if config.hnsw.warm_after_rebuild {
+            index.warm()?;
         }
 
-        Ok(vectors)
+        Ok(index)
     }
 
-    /// Loads or rebuilds insight HNSW indexes for all existing collectives.
+    /// Loads or rebuilds the experience HNSW index for one collective.
     ///
-    /// For each collective, loads all insights from storage and rebuilds
-    /// the HNSW graph from their inline embeddings. Uses InsightId→ExperienceId
-    /// byte conversion for the HNSW API.
-    fn load_all_insight_indexes(
+    /// 1. Try loading metadata from `.hnsw.meta` file
+    /// 2. Load the saved graph and reconcile it with redb (see
+    ///    [`load_saved_hnsw`](Self::load_saved_hnsw)); done if that works
+    /// 3. Otherwise rebuild the graph from redb embeddings
+    /// 4. Restore deleted set from metadata if available
+    fn build_hnsw_index(
         storage: &dyn StorageEngine,
         config: &Config,
-    ) -> Result<HashMap<CollectiveId, HnswIndex>> {
-        let collectives = storage.list_collectives()?;
-        let mut insight_vectors = HashMap::with_capacity(collectives.len());
-
-        let hnsw_dir = storage.path().map(|p| {
-            let mut hnsw_path = p.as_os_str().to_owned();
-            hnsw_path.push(".hnsw");
-            PathBuf::from(hnsw_path)
-        });
+        collective: &Collective,
+        hnsw_dir: Option<&Path>,
+        deadline: &Deadline,
+    ) -> Result<HnswIndex> {
+        let dimension = collective.embedding_dimension as usize;
 
-        for collective in &collectives {
-            let dimension = collective.embedding_dimension as usize;
+        // List all experience IDs in this collective
+        let exp_ids = storage.list_experience_ids_in_collective(collective.id)?;
 
-            // List all insight IDs in this collective
-            let insight_ids = storage.list_insight_ids_in_collective(collective.id)?;
+        // Try loading metadata (for saved graphs, deleted set and ID mappings)
+        let metadata = hnsw_dir.and_then(|dir| {
+            Self::load_index_metadata(dir, &collective.id.to_string(), collective.id)
+        });
 
-            // Load insights and extract embeddings (converting InsightId → ExperienceId)
-            let mut embeddings = Vec::with_capacity(insight_ids.len());
-            for insight_id in &insight_ids {
-                if let Some(insight) = storage.get_insight(*insight_id)? {
-                    let exp_id = ExperienceId::from_bytes(*insight_id.as_bytes());
-                    embeddings.push((exp_id, insight.embedding));
-                }
+        if let (Some(dir), Some(meta)) = (hnsw_dir, &metadata) {
+            if let Some(index) =
+                Self::load_saved_hnsw(config, collective, dir, meta, &exp_ids, deadline, |id| {
+                    storage.get_embedding(id)
+                })
+            {
+                return Ok(index);
             }
+        }
 
-            // Try loading metadata (for deleted set)
-            let name = format!("{}_insights", collective.id);
-            let metadata = hnsw_dir
-                .as_ref()
-                .and_then(|dir| HnswIndex::load_metadata(dir, &name).ok())
-                .flatten();
+        // Load embeddings from redb (source of truth)
+        let mut embeddings = Vec::with_capacity(exp_ids.len());
+        for exp_id in &exp_ids {
+            deadline.check()?;
+            if let Some(embedding) = storage.get_embedding(*exp_id)? {
+                embeddings.push((*exp_id, embedding));
+            }
+        }
 
-            // Rebuild HNSW graph from embeddings
-            let index = if embeddings.is_empty() {
-                HnswIndex::new(dimension, &config.hnsw)
-            } else {
-                let start = std::time::Instant::now();
-                let idx = HnswIndex::rebuild_from_embeddings(dimension, &config.hnsw, embeddings)?;
-                info!(
-                    collective = %collective.id,
-                    insights = idx.active_count(),
-                    elapsed_ms = start.elapsed().as_millis() as u64,
-                    "Rebuilt insight HNSW index from stored insights"
-                );
-                idx
-            };
+        // Rebuild the HNSW graph from embeddings
+        let index = if embeddings.is_empty() {
+            HnswIndex::new(dimension, &config.hnsw)
+        } else {
+            let start = std::time::Instant::now();
+            let idx = HnswIndex::rebuild_within(dimension, &config.hnsw, embeddings, deadline)?;
+            info!(
+                collective = %collective.id,
+                vectors = idx.active_count(),
+                elapsed_ms = start.elapsed().as_millis() as u64,
+                "Rebuilt HNSW index from redb embeddings"
+            );
+            idx
+        };
 
-            // Restore deleted set from metadata if available
-            if let Some(meta) = metadata {
-                index.restore_deleted_set(&meta.deleted)?;
-            }
+        // Restore deleted set from metadata if available
+        if let Some(meta) = metadata {
+            index.restore_deleted_set(&meta.deleted)?;
+        }
 
-            insight_vectors.insert(collective.id, index);
+        if config.hnsw.warm_after_rebuild {
+            index.warm()?;
         }
 
-        Ok(insight_vectors)
+        Ok(index)
     }
 
-    /// Executes a closure with the HNSW index for a collective.
-    ///
-    /// This is the primary accessor for vector search operations (used by
-    /// `search_similar()`). The closure runs while the outer RwLock guard
-    /// is held (read lock), so the HnswIndex reference stays valid.
-    /// Returns `None` if no index exists for the collective.
-    #[doc(hidden)]
-    pub fn with_vector_index<F, R>(&self, collective_id: CollectiveId, f: F) -> Result<Option<R>>
-    where
-        F: FnOnce(&HnswIndex) -> Result<R>,
-    {
-        let vectors = self
-            .vectors
-            .read()
-            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
-        match vectors.get(&collective_id) {
-            Some(index) => Ok(Some(f(index)?)),
-            None => Ok(None),
+    /// Loads a saved HNSW graph and brings it up to date with `stored`,
+    /// the IDs currently in redb. Returns `None` when the index should be
+    /// rebuilt instead.
+    ///
+    /// Every vector in the graph is compared with the embedding
+    /// `embedding_of` returns for it; any that changed since the save
+    /// makes the graph stale. Saved IDs that are no longer stored are
+    /// deleted from the index; stored IDs it lacks (written after the
+    /// last save, e.g. before a crash) are inserted using `embedding_of`.
+    /// A graph that would need more than half of its vectors changed is
+    /// stale too: rebuilding gives a better graph for about the same work.
+    ///
+    /// Dumps saved without checksums are never loaded. Failures are
+    /// logged, since the rebuild makes them harmless.
+    fn load_saved_hnsw(
+        config: &Config,
+        collective: &Collective,
+        dir: &Path,
+        metadata: &IndexMetadata,
+        stored: &[ExperienceId],
+        deadline: &Deadline,
+        mut embedding_of: impl FnMut(ExperienceId) -> Result<Option<Vec<f32>>>,
+    ) -> Option<HnswIndex> {
+        if metadata.graphs.is_empty() || metadata.files.is_empty() {
+            return None;
+        }
+        let start = Instant::now();
+        let loaded = (|| {
+            if metadata.dimension != collective.embedding_dimension as usize {
+                return Err(PulseDBError::vector(format!(
+                    "Saved HNSW graph has dimension {}, collective has {}",
+                    metadata.dimension, collective.embedding_dimension
+                )));
+            }
+            let index = HnswIndex::load_from_dir(&config.hnsw, dir, metadata)?;
+
+            let stored_set: HashSet<ExperienceId> = stored.iter().copied().collect();
+            let extra: Vec<ExperienceId> = index
+                .experience_ids()
+                .into_iter()
+                .filter(|id| !stored_set.contains(id))
+                .collect();
+            let missing: Vec<ExperienceId> = stored
+                .iter()
+                .copied()
+                .filter(|&id| !index.contains(id))
+                .collect();
+
+            // Membership alone misses an embedding replaced after the save,
+            // so every vector is checked against the stored one
+            let mut replaced = 0;
+            index.for_each_vector(|id, vector| {
+                if stored_set.contains(&id) {
+                    deadline.check()?;
+                    if let Some(embedding) = embedding_of(id)? {
+                        if !same_embedding(vector, &embedding, config.embedding_storage) {
+                            replaced += 1;
+                        }
+                    }
+                }
+                Ok(())
+            })?;
+            if replaced > 0 {
+                return Err(PulseDBError::vector(format!(
+                    "Saved HNSW graph is stale ({} embeddings changed since the save)",
+                    replaced
+                )));
+            }
+
+            let changed = extra.len() + missing.len();
+            if changed * 2 > stored.len().max(index.active_count()) {
+                return Err(PulseDBError::vector(format!(
+                    "Saved HNSW graph is stale ({} of {} vectors changed)",
+                    changed,
+                    stored.len()
+                )));
+            }
+
+            for &id in &extra {
+                index.delete_experience(id)?;
+            }
+            for &id in &missing {
+                deadline.check()?;
+                if let Some(embedding) = embedding_of(id)? {
+                    index.insert_experience(id, &embedding)?;
+                }
+            }
+            if config.hnsw.warm_after_rebuild {
+                index.warm()?;
+            }
+            Ok((index, missing.len(), extra.len()))
+        })();
+
+        match loaded {
+            Ok((index, inserted, dropped)) => {
+                info!(
+                    collective = %collective.id,
+                    vectors = index.active_count(),
+                    inserted,
+                    dropped,
+                    elapsed_ms = start.elapsed().as_millis() as u64,
+                    "Loaded saved HNSW graph"
+                );
+                Some(index)
+            }
+            Err(e) => {
+                warn!(
+                    collective = %collective.id,
+                    error = %e,
+                    "Saved HNSW graph not usable, rebuilding from redb"
+                );
+                None
+            }
+        }
+    }
+
+    /// Reads a saved index's metadata, treating unreadable metadata as
+    /// absent (the index is rebuilt from redb either way).
+    fn load_index_metadata(
+        dir: &Path,
+        name: &str,
+        collective_id: CollectiveId,
+    ) -> Option<IndexMetadata> {
+        match HnswIndex::load_metadata(dir, name) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                warn!(
+                    collective = %collective_id,
+                    error = %e,
+                    "Ignoring unreadable HNSW metadata"
+                );
+                None
+            }
+        }
+    }
+
+    /// Loads or rebuilds insight HNSW indexes for all existing collectives.
+    ///
+    /// See [`build_insight_index`](Self::build_insight_index) for the
+    /// per-collective procedure. Collectives already in `unavailable` are
+    /// skipped; ones that fail to load are added to it.
+    fn load_all_insight_indexes(
+        storage: &dyn StorageEngine,
+        config: &Config,
+        unavailable: &mut HashMap<CollectiveId, String>,
+    ) -> Result<HashMap<CollectiveId, CollectiveIndex>> {
+        let collectives = storage.list_collectives()?;
+        let mut insight_vectors = HashMap::with_capacity(collectives.len());
+
+        let hnsw_dir = storage.path().map(|p| {
+            let mut hnsw_path = p.as_os_str().to_owned();
+            hnsw_path.push(".hnsw");
+            PathBuf::from(hnsw_path)
+        });
+
+        for collective in &collectives {
+            if unavailable.contains_key(&collective.id) {
+                continue;
+            }
+            match Self::build_insight_index(storage, config, collective, hnsw_dir.as_deref()) {
+                Ok(index) => {
+                    insight_vectors.insert(collective.id, index);
+                }
+                Err(e) => {
+                    warn!(
+                        collective = %collective.id,
+                        error = %e,
+                        "Insight index failed to load, collective unavailable"
+                    );
+                    unavailable.insert(collective.id, e.to_string());
+                }
+            }
+        }
+
+        Ok(insight_vectors)
+    }
+
+    /// Loads or rebuilds the insight index for one collective.
+    ///
+    /// Loads all insights from storage and rebuilds the HNSW graph (or the
+    /// configured [`VectorBackend`]'s index) from their inline embeddings.
+    /// Uses InsightId→ExperienceId byte conversion for the index API.
+    fn build_insight_index(
+        storage: &dyn StorageEngine,
+        config: &Config,
+        collective: &Collective,
+        hnsw_dir: Option<&Path>,
+    ) -> Result<CollectiveIndex> {
+        let deadline = Deadline::start(TimedOperation::Rebuild, config.timeouts.rebuild);
+        let dimension = collective.embedding_dimension as usize;
+
+        // List all insight IDs in this collective
+        let insight_ids = storage.list_insight_ids_in_collective(collective.id)?;
+
+        // Try loading metadata (for saved graphs and deleted set)
+        let name = format!("{}_insights", collective.id);
+        let metadata =
+            hnsw_dir.and_then(|dir| Self::load_index_metadata(dir, &name, collective.id));
+
+        if config.vector_backend.is_none() {
+            if let (Some(dir), Some(meta)) = (hnsw_dir, &metadata) {
+                let stored: Vec<ExperienceId> = insight_ids
+                    .iter()
+                    .map(|id| ExperienceId::from_bytes(*id.as_bytes()))
+                    .collect();
+                let embedding_of = |id: ExperienceId| {
+                    let insight = storage.get_insight(InsightId::from_bytes(*id.as_bytes()))?;
+                    Ok(insight.map(|insight| insight.embedding))
+                };
+                if let Some(index) = Self::load_saved_hnsw(
+                    config,
+                    collective,
+                    dir,
+                    meta,
+                    &stored,
+                    &deadline,
+                    embedding_of,
+                ) {
+                    return Ok(CollectiveIndex::Hnsw(index));
+                }
+            }
+        }
+
+        // Load insights and extract embeddings (converting InsightId → ExperienceId)
+        let mut embeddings = Vec::with_capacity(insight_ids.len());
+        for insight_id in &insight_ids {
+            deadline.check()?;
+            if let Some(insight) = storage.get_insight(*insight_id)? {
+                let exp_id = ExperienceId::from_bytes(*insight_id.as_bytes());
+                embeddings.push((exp_id, insight.embedding));
+            }
+        }
+        if let Some(backend) = &config.vector_backend {
+            return Self::build_custom_index(backend, collective, IndexRole::Insights, embeddings);
+        }
+
+        // Rebuild HNSW graph from embeddings
+        let index = if embeddings.is_empty() {
+            HnswIndex::new(dimension, &config.hnsw)
+        } else {
+            let start = std::time::Instant::now();
+            let idx = HnswIndex::rebuild_within(dimension, &config.hnsw, embeddings, &deadline)?;
+            info!(
+                collective = %collective.id,
+                insights = idx.active_count(),
+                elapsed_ms = start.elapsed().as_millis() as u64,
+                "Rebuilt insight HNSW index from stored insights"
+            );
+            idx
+        };
+
+        // Restore deleted set from metadata if available
+        if let Some(meta) = metadata {
+            index.restore_deleted_set(&meta.deleted)?;
+        }
+
+        if config.hnsw.warm_after_rebuild {
+            index.warm()?;
+        }
+
+        Ok(CollectiveIndex::Hnsw(index))
+    }
+
+    /// Records a use of a collective's indexes for idle tracking.
+    ///
+    /// No-op unless [`Config::idle_eviction`] is set. Also runs an
+    /// automatic idle sweep when one is due (at most once per window).
+    fn touch_collective(&self, collective_id: CollectiveId) {
+        let Some(window) = self.config.idle_eviction else {
+            return;
+        };
+        let now = Instant::now();
+        if let Ok(mut last_access) = self.last_access.lock() {
+            last_access.insert(collective_id, now);
+        }
+
+        let sweep_due = match self.last_sweep.lock() {
+            Ok(mut last_sweep) if now.duration_since(*last_sweep) >= window => {
+                *last_sweep = now;
+                true
+            }
+            _ => false,
+        };
+        if sweep_due {
+            if let Err(e) = self.evict_idle_collectives() {
+                warn!(error = %e, "Automatic idle eviction failed");
+            }
+        }
+    }
+
+    /// Ensures a collective's indexes are resident, rebuilding them if
+    /// they were evicted for idleness, and pins them until the returned
+    /// guard drops.
+    ///
+    /// The rebuild runs under the map write lock, so a concurrent write
+    /// either lands in redb before the rebuild reads it or finds the
+    /// rebuilt index afterwards — nothing is lost in between. The pin is
+    /// taken first, so the idle sweep this may trigger leaves the
+    /// collective alone.
+    fn ensure_indexes_loaded(&self, collective_id: CollectiveId) -> Result<PinGuard<'_>> {
+        self.check_collective_available(collective_id)?;
+        let pin = self.pins.pin(collective_id);
+        self.touch_collective(collective_id);
+        if self.config.idle_eviction.is_none() || self.is_collective_loaded(collective_id) {
+            return Ok(pin);
+        }
+
+        // Unknown collectives are left to the caller's NotFound handling
+        let Some(collective) = self.storage.get_collective(collective_id)? else {
+            return Ok(pin);
+        };
+        let hnsw_dir = self.hnsw_dir();
+
+        {
+            let mut vectors = self
+                .vectors
+                .write()
+                .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+            if let Entry::Vacant(slot) = vectors.entry(collective_id) {
+                let index = Self::build_experience_index(
+                    self.storage.as_ref(),
+                    &self.config,
+                    &collective,
+                    hnsw_dir.as_deref(),
+                )?;
+                slot.insert(index);
+            }
+        }
+        {
+            let mut insight_vectors = self
+                .insight_vectors
+                .write()
+                .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+            if let Entry::Vacant(slot) = insight_vectors.entry(collective_id) {
+                let index = Self::build_insight_index(
+                    self.storage.as_ref(),
+                    &self.config,
+                    &collective,
+                    hnsw_dir.as_deref(),
+                )?;
+                slot.insert(index);
+            }
+        }
+
+        info!(collective = %collective_id, "Reloaded evicted collective indexes");
+        Ok(pin)
+    }
+
+    /// Executes a closure with the experience index for a collective.
+    ///
+    /// This is the primary accessor for vector search operations (used by
+    /// `search_similar()`). The closure runs while the outer RwLock guard
+    /// is held (read lock), so the index reference stays valid.
+    /// Returns `None` if no index exists for the collective.
+    #[doc(hidden)]
+    pub fn with_vector_index<F, R>(&self, collective_id: CollectiveId, f: F) -> Result<Option<R>>
+    where
+        F: FnOnce(&CollectiveIndex) -> Result<R>,
+    {
+        let _pin = self.ensure_indexes_loaded(collective_id)?;
+        // A failed insert shouldn't wait for the next write to be found
+        self.reinsert_unindexed();
+        let vectors = self
+            .vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+        match vectors.get(&collective_id) {
+            Some(index) => Ok(Some(f(index)?)),
+            None => Ok(None),
         }
     }
 
@@ -527,6 +1363,13 @@ impl PulseDB {
         self.storage.as_ref()
     }
 
+    /// Returns content in the form [`Config::log_content_policy`] allows
+    /// in tracing output. Every log event carrying content goes through
+    /// this.
+    fn loggable<'a>(&self, content: &'a str) -> Cow<'a, str> {
+        self.config.log_content_policy.redact(content)
+    }
+
     /// Returns true if this database is in read-only mode.
     pub fn is_read_only(&self) -> bool {
         self.config.read_only
@@ -541,6 +1384,68 @@ impl PulseDB {
         Ok(())
     }
 
+    /// Checks that a collective accepts writes: not frozen
+    /// ([`PulseDBError::Busy`]) and not out of service
+    /// ([`PulseDBError::Unavailable`]).
+    ///
+    /// The returned guard must be held until the write is done, so that
+    /// [`freeze_collective()`](Self::freeze_collective) can wait for it.
+    fn check_collective_writable(&self, collective_id: CollectiveId) -> Result<FenceGuard<'_>> {
+        let guard = self.check_not_frozen(collective_id)?;
+        self.check_collective_available(collective_id)?;
+        Ok(guard)
+    }
+
+    /// Checks if a collective is frozen and returns [`PulseDBError::Busy`] if
+    /// so; otherwise admits the write until the guard drops.
+    fn check_not_frozen(&self, collective_id: CollectiveId) -> Result<FenceGuard<'_>> {
+        self.fence.enter(collective_id)
+    }
+
+    /// Checks if a collective is out of service and returns
+    /// [`PulseDBError::Unavailable`] if so.
+    fn check_collective_available(&self, collective_id: CollectiveId) -> Result<()> {
+        let unavailable = self
+            .unavailable
+            .read()
+            .map_err(|_| PulseDBError::internal("Unavailable set lock poisoned"))?;
+        if let Some(reason) = unavailable.get(&collective_id) {
+            return Err(PulseDBError::unavailable(format!(
+                "collective {} failed to load: {}",
+                collective_id, reason
+            )));
+        }
+        Ok(())
+    }
+
+    /// Admits a write without looking up its collective when no collective
+    /// is frozen or out of service.
+    ///
+    /// Lets write paths that would need an extra read to discover the
+    /// owning collective skip that lookup in the common case.
+    fn enter_unfenced(&self) -> Option<FenceGuard<'_>> {
+        let any_unavailable = self
+            .unavailable
+            .read()
+            .map(|u| !u.is_empty())
+            .unwrap_or(true);
+        if any_unavailable {
+            return None;
+        }
+        self.fence.enter_unscoped()
+    }
+
+    /// Fences an existing experience's collective (extra read only when needed).
+    fn check_experience_writable(&self, id: ExperienceId) -> Result<FenceGuard<'_>> {
+        if let Some(guard) = self.enter_unfenced() {
+            return Ok(guard);
+        }
+        match self.storage.get_experience(id)? {
+            Some(exp) => self.check_collective_writable(exp.collective_id),
+            None => Ok(self.fence.pass()),
+        }
+    }
+
     // =========================================================================
     // Collective Management (E1-S02)
     // =========================================================================
@@ -577,21 +1482,7 @@ impl PulseDB {
         let dimension = self.config.embedding_dimension.size() as u16;
         let collective = Collective::new(name, dimension);
         let id = collective.id;
-
-        // Persist to redb first (source of truth)
-        self.storage.save_collective(&collective)?;
-
-        // Create empty HNSW indexes for this collective
-        let exp_index = HnswIndex::new(dimension as usize, &self.config.hnsw);
-        let insight_index = HnswIndex::new(dimension as usize, &self.config.hnsw);
-        self.vectors
-            .write()
-            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?
-            .insert(id, exp_index);
-        self.insight_vectors
-            .write()
-            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?
-            .insert(id, insight_index);
+        self.register_collective(&collective)?;
 
         info!(id = %id, name = %name, "Collective created");
         Ok(id)
@@ -625,13 +1516,21 @@ impl PulseDB {
         let dimension = self.config.embedding_dimension.size() as u16;
         let collective = Collective::with_owner(name, owner_id, dimension);
         let id = collective.id;
+        self.register_collective(&collective)?;
+
+        info!(id = %id, name = %name, owner = %owner_id, "Collective created with owner");
+        Ok(id)
+    }
+
+    /// Persists a new collective and creates its empty indexes.
+    fn register_collective(&self, collective: &Collective) -> Result<()> {
+        let id = collective.id;
 
         // Persist to redb first (source of truth)
-        self.storage.save_collective(&collective)?;
+        self.storage.save_collective(collective)?;
 
-        // Create empty HNSW indexes for this collective
-        let exp_index = HnswIndex::new(dimension as usize, &self.config.hnsw);
-        let insight_index = HnswIndex::new(dimension as usize, &self.config.hnsw);
+        // Create empty indexes for this collective
+        let (exp_index, insight_index) = self.empty_indexes(collective)?;
         self.vectors
             .write()
             .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?
@@ -640,9 +1539,32 @@ impl PulseDB {
             .write()
             .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?
             .insert(id, insight_index);
+        self.touch_collective(id);
+        Ok(())
+    }
 
-        info!(id = %id, name = %name, owner = %owner_id, "Collective created with owner");
-        Ok(id)
+    /// Creates the empty experience and insight indexes of a new
+    /// collective: HNSW, or the configured [`VectorBackend`]'s.
+    fn empty_indexes(&self, collective: &Collective) -> Result<(CollectiveIndex, CollectiveIndex)> {
+        let dimension = collective.embedding_dimension as usize;
+        match &self.config.vector_backend {
+            Some(backend) => Ok((
+                CollectiveIndex::Custom(backend.build(
+                    collective.id,
+                    IndexRole::Experiences,
+                    dimension,
+                )?),
+                CollectiveIndex::Custom(backend.build(
+                    collective.id,
+                    IndexRole::Insights,
+                    dimension,
+                )?),
+            )),
+            None => Ok((
+                CollectiveIndex::Hnsw(HnswIndex::new(dimension, &self.config.hnsw)),
+                CollectiveIndex::Hnsw(HnswIndex::new(dimension, &self.config.hnsw)),
+            )),
+        }
     }
 
     /// Returns a collective by ID, or `None` if not found.
@@ -665,6 +1587,63 @@ impl PulseDB {
         self.storage.get_collective(id)
     }
 
+    /// Updates a collective's description and settings.
+    ///
+    /// Only fields set to `Some(...)` in the update are changed; `settings`
+    /// replaces the whole map. Bumps `updated_at`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if the description or a setting
+    ///   is too long, or a setting key is empty
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let id = db.create_collective("billing")?;
+    /// use std::collections::HashMap;
+    /// use pulsedb::CollectiveUpdate;
+    ///
+    /// db.update_collective(id, CollectiveUpdate {
+    ///     description: Some("Billing service".to_string()),
+    ///     settings: Some(HashMap::from([(
+    ///         "repo".to_string(),
+    ///         "https://example.com/billing.git".to_string(),
+    ///     )])),
+    /// })?;
+    /// let collective = db.get_collective(id)?.unwrap();
+    /// assert_eq!(collective.settings["repo"], "https://example.com/billing.git");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, update))]
+    pub fn update_collective(&self, id: CollectiveId, update: CollectiveUpdate) -> Result<()> {
+        self.check_writable()?;
+        let _fence = self.check_collective_writable(id)?;
+        validate_collective_update(&update)?;
+
+        let mut collective = self
+            .storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+        if let Some(description) = update.description {
+            collective.description = (!description.is_empty()).then_some(description);
+        }
+        if let Some(settings) = update.settings {
+            collective.settings = settings;
+        }
+        collective.updated_at = Timestamp::now();
+        self.storage.save_collective(&collective)?;
+
+        info!(id = %id, "Collective updated");
+        Ok(())
+    }
+
     /// Lists all collectives in the database.
     ///
     /// Returns an empty vector if no collectives exist.
@@ -676,12 +1655,89 @@ impl PulseDB {
     ///
     /// Returns only collectives whose `owner_id` matches the given value.
     /// Returns an empty vector if no matching collectives exist.
+    ///
+    /// Reads the owner index, so cost scales with the owner's collectives,
+    /// not the whole database.
     pub fn list_collectives_by_owner(&self, owner_id: &str) -> Result<Vec<Collective>> {
-        let all = self.storage.list_collectives()?;
-        Ok(all
+        let mut collectives = Vec::new();
+        for id in self.storage.list_collective_ids_by_owner(owner_id)? {
+            if let Some(collective) = self.storage.get_collective(id)? {
+                collectives.push(collective);
+            }
+        }
+        Ok(collectives)
+    }
+
+    /// Returns aggregate statistics across every collective an owner holds.
+    ///
+    /// Returns zeroed stats if the owner has no collectives.
+    #[instrument(skip(self))]
+    pub fn stats_by_owner(&self, owner_id: &str) -> Result<OwnerStats> {
+        let mut stats = OwnerStats::default();
+        for id in self.storage.list_collective_ids_by_owner(owner_id)? {
+            stats.collective_count += 1;
+            stats.experience_count += self.storage.count_experiences_in_collective(id)?;
+            stats.insight_count += self.storage.list_insight_ids_in_collective(id)?.len() as u64;
+        }
+        Ok(stats)
+    }
+
+    /// Deletes every collective belonging to an owner, with full cascade.
+    ///
+    /// Sub-collectives are deleted before their parents. The whole set is
+    /// checked up front, so nothing is deleted if any check fails.
+    /// Returns the number of collectives deleted.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::Busy`] if any of the owner's collectives is frozen
+    /// - [`ValidationError::InvalidField`] if one of the owner's collectives
+    ///   has a sub-collective belonging to a different owner
+    #[instrument(skip(self))]
+    pub fn delete_collectives_by_owner(&self, owner_id: &str) -> Result<usize> {
+        self.check_writable()?;
+
+        let owned: HashSet<CollectiveId> = self
+            .storage
+            .list_collective_ids_by_owner(owner_id)?
             .into_iter()
-            .filter(|c| c.owner_id.as_deref() == Some(owner_id))
-            .collect())
+            .collect();
+
+        // Order parents before children, then delete in reverse
+        let mut ordered = Vec::with_capacity(owned.len());
+        for &id in &owned {
+            let is_root = match self.storage.get_collective_parent(id)? {
+                Some(parent) => !owned.contains(&parent),
+                None => true,
+            };
+            if !is_root {
+                continue;
+            }
+            for member in self.collective_subtree(id)? {
+                if !owned.contains(&member) {
+                    return Err(ValidationError::invalid_field(
+                        "owner_id",
+                        format!(
+                            "collective {} has sub-collective {} owned by someone else",
+                            id, member
+                        ),
+                    )
+                    .into());
+                }
+                ordered.push(member);
+            }
+        }
+        let _fences = ordered
+            .iter()
+            .map(|&id| self.check_not_frozen(id))
+            .collect::<Result<Vec<_>>>()?;
+
+        for &id in ordered.iter().rev() {
+            self.delete_collective(id)?;
+        }
+
+        info!(owner = %owner_id, count = ordered.len(), "Owner collectives deleted");
+        Ok(ordered.len())
     }
 
     /// Returns statistics for a collective.
@@ -697,12 +1753,20 @@ impl PulseDB {
             .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
 
         let experience_count = self.storage.count_experiences_in_collective(id)?;
+        let storage_bytes = self.storage.collective_storage_bytes(id)?;
+        let vector_index = self
+            .vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?
+            .get(&id)
+            .and_then(CollectiveIndex::hnsw_stats);
 
         Ok(CollectiveStats {
             experience_count,
-            storage_bytes: 0,
+            storage_bytes,
             oldest_experience: None,
             newest_experience: None,
+            vector_index,
         })
     }
 
@@ -713,7 +1777,9 @@ impl PulseDB {
     ///
     /// # Errors
     ///
-    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`ValidationError::InvalidField`] if the collective still has
+    ///   sub-collectives (delete or re-parent them first)
     ///
     /// # Example
     ///
@@ -730,11 +1796,24 @@ impl PulseDB {
     #[instrument(skip(self))]
     pub fn delete_collective(&self, id: CollectiveId) -> Result<()> {
         self.check_writable()?;
+        let _fence = self.check_not_frozen(id)?;
         // Verify collective exists
         self.storage
             .get_collective(id)?
             .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
 
+        let children = self.storage.list_child_collective_ids(id)?;
+        if !children.is_empty() {
+            return Err(ValidationError::invalid_field(
+                "id",
+                format!(
+                    "collective has {} sub-collective(s); delete or re-parent them first",
+                    children.len()
+                ),
+            )
+            .into());
+        }
+
         // Cascade: delete all experiences for this collective
         let deleted_count = self.storage.delete_experiences_by_collective(id)?;
         if deleted_count > 0 {
@@ -753,8 +1832,30 @@ impl PulseDB {
             info!(count = deleted_activities, "Cascade-deleted activities");
         }
 
-        // Delete the collective record from storage
-        self.storage.delete_collective(id)?;
+        // Cascade: delete all locks for this collective
+        let deleted_locks = self.storage.delete_locks_by_collective(id)?;
+        if deleted_locks > 0 {
+            info!(count = deleted_locks, "Cascade-deleted locks");
+        }
+
+        // Cascade: delete queued relation suggestions for this collective
+        let deleted_suggestions = self.storage.delete_relation_suggestions_by_collective(id)?;
+        if deleted_suggestions > 0 {
+            info!(
+                count = deleted_suggestions,
+                "Cascade-deleted relation suggestions"
+            );
+        }
+
+        // Cascade: delete recorded knowledge gaps for this collective
+        self.drop_queued_gaps(id);
+        let deleted_gaps = self.storage.delete_knowledge_gaps_by_collective(id)?;
+        if deleted_gaps > 0 {
+            info!(count = deleted_gaps, "Cascade-deleted knowledge gaps");
+        }
+
+        // Delete the collective record from storage
+        self.storage.delete_collective(id)?;
 
         // Remove HNSW indexes from memory
         self.vectors
@@ -765,6 +1866,12 @@ impl PulseDB {
             .write()
             .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?
             .remove(&id);
+        if let Ok(mut last_access) = self.last_access.lock() {
+            last_access.remove(&id);
+        }
+        if let Ok(mut unavailable) = self.unavailable.write() {
+            unavailable.remove(&id);
+        }
 
         // Remove HNSW files from disk (non-fatal if fails)
         if let Some(hnsw_dir) = self.hnsw_dir() {
@@ -775,6 +1882,13 @@ impl PulseDB {
                     "Failed to remove experience HNSW files (non-fatal)"
                 );
             }
+            if let Err(e) = IvfIndex::remove_files(&hnsw_dir, &id.to_string()) {
+                warn!(
+                    collective = %id,
+                    error = %e,
+                    "Failed to remove experience IVF files (non-fatal)"
+                );
+            }
             let insight_name = format!("{}_insights", id);
             if let Err(e) = HnswIndex::remove_files(&hnsw_dir, &insight_name) {
                 warn!(
@@ -790,504 +1904,725 @@ impl PulseDB {
     }
 
     // =========================================================================
-    // Experience CRUD (E1-S03)
+    // Collective Hierarchy
     // =========================================================================
 
-    /// Records a new experience in the database.
-    ///
-    /// This is the primary method for storing agent-learned knowledge. The method:
-    /// 1. Validates the input (content, scores, tags, embedding)
-    /// 2. Verifies the collective exists
-    /// 3. Resolves the embedding (generates if Builtin, requires if External)
-    /// 4. Stores the experience atomically across 4 tables
+    /// Creates a sub-collective nested under `parent_id`.
     ///
-    /// # Arguments
+    /// The child inherits the parent's `owner_id` and embedding dimension.
+    /// Hierarchies let large organisations scope knowledge as
+    /// team → project → feature: searches with
+    /// [`SearchFilter::include_descendants`] cover a whole subtree, and
+    /// [`get_collective_stats_rollup()`](Self::get_collective_stats_rollup)
+    /// aggregates over it.
     ///
-    /// * `exp` - The experience to record (see [`NewExperience`])
+    /// Hierarchy links are local to this database and are not replicated
+    /// by sync.
     ///
     /// # Errors
     ///
-    /// - [`ValidationError`](crate::ValidationError) if input is invalid
-    /// - [`NotFoundError::Collective`] if the collective doesn't exist
-    /// - [`PulseDBError::Embedding`] if embedding generation fails (Builtin mode)
-    #[instrument(skip(self, exp), fields(collective_id = %exp.collective_id))]
-    pub fn record_experience(&self, exp: NewExperience) -> Result<ExperienceId> {
+    /// - [`NotFoundError::Collective`] if the parent doesn't exist
+    /// - [`PulseDBError::Busy`] if the parent is frozen
+    /// - Validation error if the name is invalid
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// let team = db.create_collective("platform-team")?;
+    /// let project = db.create_sub_collective(team, "billing-service")?;
+    /// assert_eq!(db.get_parent_collective(project)?, Some(team));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn create_sub_collective(
+        &self,
+        parent_id: CollectiveId,
+        name: &str,
+    ) -> Result<CollectiveId> {
         self.check_writable()?;
-        let is_external = matches!(self.config.embedding_provider, EmbeddingProvider::External);
+        let _fence = self.check_collective_writable(parent_id)?;
+        validate_collective_name(name)?;
 
-        // Verify collective exists and get its dimension
-        let collective = self
+        let parent = self
             .storage
-            .get_collective(exp.collective_id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(exp.collective_id)))?;
+            .get_collective(parent_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(parent_id)))?;
 
-        // Validate input
-        validate_new_experience(&exp, collective.embedding_dimension, is_external)?;
-
-        // Resolve embedding
-        let embedding = match exp.embedding {
-            Some(emb) => emb,
-            None => {
-                // Builtin mode: generate embedding from content
-                self.embedding.embed(&exp.content)?
-            }
-        };
-
-        // Clone embedding for HNSW insertion (~1.5KB for 384d, negligible vs I/O)
-        let embedding_for_hnsw = embedding.clone();
-        let collective_id = exp.collective_id;
+        let mut collective = Collective::new(name, parent.embedding_dimension);
+        collective.owner_id = parent.owner_id;
+        let id = collective.id;
+        self.register_collective(&collective)?;
+        self.storage.set_collective_parent(id, Some(parent_id))?;
 
-        // Construct the full experience record
-        let experience = Experience {
-            id: ExperienceId::new(),
-            collective_id,
-            content: exp.content,
-            embedding,
-            experience_type: exp.experience_type,
-            importance: exp.importance,
-            confidence: exp.confidence,
-            applications: 0,
-            domain: exp.domain,
-            related_files: exp.related_files,
-            source_agent: exp.source_agent,
-            source_task: exp.source_task,
-            timestamp: Timestamp::now(),
-            archived: false,
-        };
+        info!(id = %id, parent = %parent_id, name = %name, "Sub-collective created");
+        Ok(id)
+    }
 
-        let id = experience.id;
+    /// Moves a collective under a new parent, or makes it a root with `None`.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if either collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    /// - [`ValidationError::DimensionMismatch`] if the two collectives use
+    ///   different embedding dimensions
+    /// - [`ValidationError::InvalidField`] if the move would make the
+    ///   collective its own ancestor
+    #[instrument(skip(self))]
+    pub fn set_collective_parent(
+        &self,
+        id: CollectiveId,
+        parent_id: Option<CollectiveId>,
+    ) -> Result<()> {
+        self.check_writable()?;
+        let _fence = self.check_collective_writable(id)?;
+        let collective = self
+            .storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
 
-        // Write to redb FIRST (source of truth). If crash happens after
-        // this but before HNSW insert, rebuild on next open will include it.
-        self.storage.save_experience(&experience)?;
+        if let Some(parent_id) = parent_id {
+            let parent = self
+                .storage
+                .get_collective(parent_id)?
+                .ok_or_else(|| PulseDBError::from(NotFoundError::collective(parent_id)))?;
+
+            // Subtree searches query every member with one embedding
+            if parent.embedding_dimension != collective.embedding_dimension {
+                return Err(ValidationError::dimension_mismatch(
+                    parent.embedding_dimension as usize,
+                    collective.embedding_dimension as usize,
+                )
+                .into());
+            }
 
-        // Insert into HNSW index (derived structure)
-        let vectors = self
-            .vectors
-            .read()
-            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
-        if let Some(index) = vectors.get(&collective_id) {
-            index.insert_experience(id, &embedding_for_hnsw)?;
+            // Walk up from the new parent; meeting `id` means a cycle
+            let mut seen = HashSet::new();
+            let mut cursor = Some(parent_id);
+            while let Some(current) = cursor {
+                if current == id {
+                    return Err(ValidationError::invalid_field(
+                        "parent_id",
+                        "a collective cannot be nested under itself or its descendants",
+                    )
+                    .into());
+                }
+                if !seen.insert(current) {
+                    break;
+                }
+                cursor = self.storage.get_collective_parent(current)?;
+            }
         }
 
-        // Emit watch event after both storage and HNSW succeed
-        self.watch.emit(
-            WatchEvent {
-                experience_id: id,
-                collective_id,
-                event_type: WatchEventType::Created,
-                timestamp: experience.timestamp,
-                experience: Some(experience.clone()),
-            },
-            &experience,
-        )?;
+        self.storage.set_collective_parent(id, parent_id)?;
 
-        info!(id = %id, "Experience recorded");
-        Ok(id)
+        info!(id = %id, parent = ?parent_id, "Collective parent set");
+        Ok(())
     }
 
-    /// Retrieves an experience by ID, including its embedding.
+    /// Returns the parent of a collective, or `None` for a root collective.
     ///
-    /// Returns `None` if no experience with the given ID exists.
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
     #[instrument(skip(self))]
-    pub fn get_experience(&self, id: ExperienceId) -> Result<Option<Experience>> {
-        self.storage.get_experience(id)
+    pub fn get_parent_collective(&self, id: CollectiveId) -> Result<Option<CollectiveId>> {
+        self.storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+        self.storage.get_collective_parent(id)
     }
 
-    /// Updates mutable fields of an experience.
-    ///
-    /// Only fields set to `Some(...)` in the update are changed.
-    /// Content and embedding are immutable — create a new experience instead.
+    /// Lists the direct sub-collectives of a collective.
     ///
     /// # Errors
     ///
-    /// - [`ValidationError`](crate::ValidationError) if updated values are invalid
-    /// - [`NotFoundError::Experience`] if the experience doesn't exist
-    #[instrument(skip(self, update))]
-    pub fn update_experience(&self, id: ExperienceId, update: ExperienceUpdate) -> Result<()> {
-        self.check_writable()?;
-        validate_experience_update(&update)?;
-
-        let updated = self.storage.update_experience(id, &update)?;
-        if !updated {
-            return Err(PulseDBError::from(NotFoundError::experience(id)));
-        }
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    #[instrument(skip(self))]
+    pub fn list_child_collectives(&self, id: CollectiveId) -> Result<Vec<Collective>> {
+        self.storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
 
-        // Emit watch event (fetch experience for collective_id + filter matching)
-        if self.watch.has_subscribers() {
-            if let Ok(Some(exp)) = self.storage.get_experience(id) {
-                let event_type = if update.archived == Some(true) {
-                    WatchEventType::Archived
-                } else {
-                    WatchEventType::Updated
-                };
-                self.watch.emit(
-                    WatchEvent {
-                        experience_id: id,
-                        collective_id: exp.collective_id,
-                        event_type,
-                        timestamp: Timestamp::now(),
-                        experience: Some(exp.clone()),
-                    },
-                    &exp,
-                )?;
+        let mut children = Vec::new();
+        for child_id in self.storage.list_child_collective_ids(id)? {
+            if let Some(child) = self.storage.get_collective(child_id)? {
+                children.push(child);
             }
         }
-
-        info!(id = %id, "Experience updated");
-        Ok(())
+        Ok(children)
     }
 
-    /// Archives an experience (soft-delete).
+    /// Lists every collective below `id` in the hierarchy, breadth-first.
     ///
-    /// Archived experiences remain in storage but are excluded from search
-    /// results. Use [`unarchive_experience`](Self::unarchive_experience) to restore.
+    /// The collective itself is not included.
     ///
     /// # Errors
     ///
-    /// Returns [`NotFoundError::Experience`] if the experience doesn't exist.
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
     #[instrument(skip(self))]
-    pub fn archive_experience(&self, id: ExperienceId) -> Result<()> {
-        self.check_writable()?;
-        self.update_experience(
-            id,
-            ExperienceUpdate {
-                archived: Some(true),
-                ..Default::default()
-            },
-        )
+    pub fn list_descendant_collectives(&self, id: CollectiveId) -> Result<Vec<CollectiveId>> {
+        self.storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+
+        let mut subtree = self.collective_subtree(id)?;
+        subtree.remove(0);
+        Ok(subtree)
     }
 
-    /// Restores an archived experience.
+    /// Returns statistics aggregated over a collective and all its descendants.
     ///
-    /// The experience will once again appear in search results.
+    /// Like [`get_collective_stats()`](Self::get_collective_stats), but
+    /// counts roll up the whole subtree.
     ///
     /// # Errors
     ///
-    /// Returns [`NotFoundError::Experience`] if the experience doesn't exist.
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
     #[instrument(skip(self))]
-    pub fn unarchive_experience(&self, id: ExperienceId) -> Result<()> {
-        self.check_writable()?;
-        self.update_experience(
-            id,
-            ExperienceUpdate {
-                archived: Some(false),
-                ..Default::default()
-            },
-        )
+    pub fn get_collective_stats_rollup(&self, id: CollectiveId) -> Result<CollectiveStats> {
+        self.storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+
+        let mut experience_count = 0;
+        let mut storage_bytes = 0;
+        for cid in self.collective_subtree(id)? {
+            experience_count += self.storage.count_experiences_in_collective(cid)?;
+            storage_bytes += self.storage.collective_storage_bytes(cid)?;
+        }
+
+        Ok(CollectiveStats {
+            experience_count,
+            storage_bytes,
+            oldest_experience: None,
+            newest_experience: None,
+            vector_index: None,
+        })
     }
 
-    /// Permanently deletes an experience and its embedding.
+    /// Sets the quota of a collective and everything below it.
     ///
-    /// This removes the experience from all tables and indices.
-    /// Unlike archiving, this is irreversible.
+    /// Quotas roll up the hierarchy: an experience recorded in a
+    /// sub-collective counts against every ancestor's quota too, and
+    /// [`record_experience()`](Self::record_experience) fails once any of
+    /// them is full. [`record_experiences_batch()`](Self::record_experiences_batch)
+    /// rejects the items that don't fit. Imports and sync are not limited.
+    /// Setting [`CollectiveQuota::default()`] removes every limit.
+    ///
+    /// Lowering a quota below the current count doesn't remove anything;
+    /// it only stops further writes.
     ///
     /// # Errors
     ///
-    /// Returns [`NotFoundError::Experience`] if the experience doesn't exist.
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// use pulsedb::{CollectiveQuota, NewExperience};
+    ///
+    /// let team = db.create_collective("platform-team")?;
+    /// let project = db.create_sub_collective(team, "billing-service")?;
+    /// db.set_collective_quota(team, CollectiveQuota { max_experiences: Some(1) })?;
+    ///
+    /// let record = |collective_id| {
+    ///     db.record_experience(NewExperience {
+    ///         collective_id,
+    ///         content: "Retry payments with idempotency keys".into(),
+    ///         embedding: Some(vec![0.1; 384]),
+    ///         ..Default::default()
+    ///     })
+    /// };
+    /// record(project)?;
+    /// assert!(record(team).unwrap_err().is_validation());
+    /// # Ok(())
+    /// # }
+    /// ```
     #[instrument(skip(self))]
-    pub fn delete_experience(&self, id: ExperienceId) -> Result<()> {
+    pub fn set_collective_quota(&self, id: CollectiveId, quota: CollectiveQuota) -> Result<()> {
         self.check_writable()?;
-        // Read experience first to get collective_id for HNSW lookup.
-        // This adds one extra read, but delete is not a hot path.
-        let experience = self
-            .storage
-            .get_experience(id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(id)))?;
+        self.storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+        let _fence = self.check_collective_writable(id)?;
 
-        // Cascade-delete any relations involving this experience.
-        // Done before experience deletion so we can still look up relation data.
-        let rel_count = self.storage.delete_relations_for_experience(id)?;
-        if rel_count > 0 {
-            info!(
-                count = rel_count,
-                "Cascade-deleted relations for experience"
-            );
+        self.storage.save_collective_quota(id, &quota)?;
+        info!(id = %id, max_experiences = ?quota.max_experiences, "Collective quota set");
+        Ok(())
+    }
+
+    /// Returns the quota of a collective.
+    ///
+    /// Collectives that never had one set have no limits.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    pub fn get_collective_quota(&self, id: CollectiveId) -> Result<CollectiveQuota> {
+        self.storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+        Ok(self.storage.get_collective_quota(id)?.unwrap_or_default())
+    }
+
+    /// Checks experiences about to be recorded, one entry per experience
+    /// in `collective_ids`, against the quotas of their collectives and
+    /// ancestors.
+    ///
+    /// Returns the error for each experience that doesn't fit (`None` for
+    /// those that do, counted in order), and the quota lock if any quota
+    /// applies. Hold the lock until the admitted experiences are written.
+    #[allow(clippy::type_complexity)]
+    fn check_quotas(
+        &self,
+        collective_ids: &[CollectiveId],
+    ) -> Result<(Option<MutexGuard<'_, ()>>, Vec<Option<PulseDBError>>)> {
+        // Limited collectives above each target, and their limits
+        let mut chains: HashMap<CollectiveId, Vec<CollectiveId>> = HashMap::new();
+        let mut limits: HashMap<CollectiveId, u64> = HashMap::new();
+        for &id in collective_ids {
+            if chains.contains_key(&id) {
+                continue;
+            }
+            let mut chain = Vec::new();
+            let mut seen = HashSet::new();
+            let mut cursor = Some(id);
+            while let Some(current) = cursor {
+                if !seen.insert(current) {
+                    break;
+                }
+                if let Some(max) = self
+                    .storage
+                    .get_collective_quota(current)?
+                    .and_then(|quota| quota.max_experiences)
+                {
+                    limits.insert(current, max);
+                    chain.push(current);
+                }
+                cursor = self.storage.get_collective_parent(current)?;
+            }
+            chains.insert(id, chain);
+        }
+        if limits.is_empty() {
+            return Ok((None, collective_ids.iter().map(|_| None).collect()));
         }
 
-        // Delete from redb FIRST (source of truth). If crash happens after
-        // this but before HNSW soft-delete, on reopen the experience won't be
-        // loaded from redb, so it's automatically excluded from the rebuilt index.
-        self.storage.delete_experience(id)?;
+        let guard = self
+            .quota_lock
+            .lock()
+            .map_err(|_| PulseDBError::internal("Quota lock poisoned"))?;
+        let mut used: HashMap<CollectiveId, u64> = HashMap::new();
+        for &limited in limits.keys() {
+            let mut count = 0;
+            for member in self.collective_subtree(limited)? {
+                count += self.storage.count_experiences_in_collective(member)?;
+            }
+            used.insert(limited, count);
+        }
 
-        // Soft-delete from HNSW index (mark as deleted, not removed from graph).
-        // This takes effect immediately for the current session's searches.
-        let vectors = self
-            .vectors
-            .read()
-            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
-        if let Some(index) = vectors.get(&experience.collective_id) {
-            index.delete_experience(id)?;
-        }
-
-        // Emit watch event after storage + HNSW deletion
-        self.watch.emit(
-            WatchEvent {
-                experience_id: id,
-                collective_id: experience.collective_id,
-                event_type: WatchEventType::Deleted,
-                timestamp: Timestamp::now(),
-                experience: None, // Deleted — no data to include
-            },
-            &experience,
-        )?;
-
-        info!(id = %id, "Experience deleted");
-        Ok(())
+        let verdicts = collective_ids
+            .iter()
+            .map(|id| {
+                let chain = &chains[id];
+                if let Some(full) = chain.iter().find(|l| used[*l] >= limits[*l]) {
+                    return Some(
+                        ValidationError::too_many_items(
+                            format!("experiences under collective {}", full),
+                            used[full] as usize + 1,
+                            limits[full] as usize,
+                        )
+                        .into(),
+                    );
+                }
+                for limited in chain {
+                    *used.entry(*limited).or_default() += 1;
+                }
+                None
+            })
+            .collect();
+        Ok((Some(guard), verdicts))
     }
 
-    /// Reinforces an experience by incrementing its application count.
-    ///
-    /// Each call atomically increments the `applications` counter by 1.
-    /// Returns the new application count.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`NotFoundError::Experience`] if the experience doesn't exist.
-    #[instrument(skip(self))]
-    pub fn reinforce_experience(&self, id: ExperienceId) -> Result<u32> {
-        self.check_writable()?;
-        let new_count = self
-            .storage
-            .reinforce_experience(id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(id)))?;
-
-        // Emit watch event (fetch experience for collective_id + filter matching)
-        if self.watch.has_subscribers() {
-            if let Ok(Some(exp)) = self.storage.get_experience(id) {
-                self.watch.emit(
-                    WatchEvent {
-                        experience_id: id,
-                        collective_id: exp.collective_id,
-                        event_type: WatchEventType::Updated,
-                        timestamp: Timestamp::now(),
-                        experience: Some(exp.clone()),
-                    },
-                    &exp,
-                )?;
+    /// Returns `id` followed by all of its descendants, breadth-first.
+    fn collective_subtree(&self, id: CollectiveId) -> Result<Vec<CollectiveId>> {
+        let mut subtree = vec![id];
+        let mut seen: HashSet<CollectiveId> = subtree.iter().copied().collect();
+        let mut next = 0;
+        while next < subtree.len() {
+            for child in self.storage.list_child_collective_ids(subtree[next])? {
+                if seen.insert(child) {
+                    subtree.push(child);
+                }
             }
+            next += 1;
         }
+        Ok(subtree)
+    }
 
-        info!(id = %id, applications = new_count, "Experience reinforced");
-        Ok(new_count)
+    /// Returns the collectives a filtered experience query should cover.
+    fn search_scope(&self, id: CollectiveId, filter: &SearchFilter) -> Result<Vec<CollectiveId>> {
+        if filter.include_descendants {
+            self.collective_subtree(id)
+        } else {
+            Ok(vec![id])
+        }
     }
 
-    // =========================================================================
-    // Recent Experiences
-    // =========================================================================
+    /// Drops candidates whose hot metadata fails the filter.
+    ///
+    /// Reads the fixed-size metadata sidecar in one transaction, so records
+    /// that are archived, out of range, or below a threshold are never
+    /// deserialized. Candidates without metadata are kept for the full
+    /// check.
+    fn prescreen<T>(
+        &self,
+        candidates: Vec<(ExperienceId, T)>,
+        filter: &SearchFilter,
+    ) -> Result<Vec<(ExperienceId, T)>> {
+        let ids: Vec<ExperienceId> = candidates.iter().map(|(id, _)| *id).collect();
+        let metas = self.storage.get_experience_meta(&ids)?;
+        Ok(candidates
+            .into_iter()
+            .zip(metas)
+            .filter(|(_, meta)| meta.as_ref().is_none_or(|m| filter.matches_meta(m)))
+            .map(|(candidate, _)| candidate)
+            .collect())
+    }
 
     // =========================================================================
-    // Paginated List Operations (PulseVision)
+    // Collective Write Fencing
     // =========================================================================
 
-    /// Lists experiences in a collective with pagination.
+    /// Freezes a collective, blocking all writes to it until thawed.
     ///
-    /// Returns full `Experience` records (including embeddings) ordered by
-    /// timestamp. Use `offset` and `limit` for pagination.
+    /// Use this around maintenance work (reindexing, re-embedding,
+    /// compaction) that must not race with agent writes. While frozen,
+    /// mutations targeting the collective return [`PulseDBError::Busy`];
+    /// reads and searches keep working.
     ///
-    /// Designed for visualization tools (PulseVision) that need to enumerate
-    /// the entire embedding space of a collective.
-    #[instrument(skip(self))]
-    pub fn list_experiences(
-        &self,
-        collective_id: CollectiveId,
-        limit: usize,
-        offset: usize,
-    ) -> Result<Vec<Experience>> {
-        let ids = self
-            .storage
-            .list_experience_ids_paginated(collective_id, limit, offset)?;
-        let mut experiences = Vec::with_capacity(ids.len());
-        for id in ids {
-            if let Some(exp) = self.storage.get_experience(id)? {
-                experiences.push(exp);
-            }
-        }
-        Ok(experiences)
-    }
-
-    /// Lists relations in a collective with pagination.
+    /// Writes already admitted to the collective are drained first: this
+    /// returns only after they have finished, so no write commits to the
+    /// collective after it returns. The wait is bounded by
+    /// [`TimeoutConfig::write_transaction`](crate::TimeoutConfig::write_transaction)
+    /// when set.
+    ///
+    /// Freezing is in-memory and does not survive a reopen.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is already frozen
+    /// - [`PulseDBError::Timeout`] if in-flight writes outlast the write
+    ///   timeout; the collective is left unfrozen
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// db.freeze_collective(collective_id)?;
+    /// // ... run maintenance ...
+    /// db.thaw_collective(collective_id)?;
+    /// # Ok(())
+    /// # }
+    /// ```
     #[instrument(skip(self))]
-    pub fn list_relations(
-        &self,
-        collective_id: CollectiveId,
-        limit: usize,
-        offset: usize,
-    ) -> Result<Vec<crate::relation::ExperienceRelation>> {
+    pub fn freeze_collective(&self, id: CollectiveId) -> Result<()> {
         self.storage
-            .list_relations_in_collective(collective_id, limit, offset)
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+
+        let deadline = Deadline::start(
+            TimedOperation::WriteTransaction,
+            self.config.timeouts.write_transaction,
+        );
+        self.fence.freeze(id, &deadline)?;
+
+        info!(id = %id, "Collective frozen");
+        Ok(())
     }
 
-    /// Lists insights in a collective with pagination.
+    /// Thaws a frozen collective, re-enabling writes.
     ///
-    /// Returns full `DerivedInsight` records including embeddings.
+    /// Thawing a collective that isn't frozen is a no-op.
     #[instrument(skip(self))]
-    pub fn list_insights(
-        &self,
-        collective_id: CollectiveId,
-        limit: usize,
-        offset: usize,
-    ) -> Result<Vec<DerivedInsight>> {
-        let ids = self
-            .storage
-            .list_insight_ids_paginated(collective_id, limit, offset)?;
-        let mut insights = Vec::with_capacity(ids.len());
-        for id in ids {
-            if let Some(insight) = self.storage.get_insight(id)? {
-                insights.push(insight);
-            }
+    pub fn thaw_collective(&self, id: CollectiveId) -> Result<()> {
+        let removed = self.fence.thaw(id)?;
+        if removed {
+            info!(id = %id, "Collective thawed");
         }
-        Ok(insights)
+        Ok(())
     }
 
-    /// Retrieves the most recent experiences in a collective.
-    ///
-    /// Returns full experiences ordered by timestamp (newest first).
-    #[instrument(skip(self))]
-    pub fn get_recent_experiences(
-        &self,
-        collective_id: CollectiveId,
-        limit: usize,
-    ) -> Result<Vec<Experience>> {
-        self.get_recent_experiences_filtered(collective_id, limit, SearchFilter::default())
+    /// Returns true if the collective is currently frozen.
+    pub fn is_collective_frozen(&self, id: CollectiveId) -> bool {
+        self.fence.is_frozen(id)
     }
 
-    /// Retrieves the most recent experiences in a collective with filtering.
+    // =========================================================================
+    // Idle Index Eviction
+    // =========================================================================
+
+    /// Persists and evicts the vector indexes of collectives that have been
+    /// idle for longer than [`Config::idle_eviction`].
     ///
-    /// Like [`get_recent_experiences()`](Self::get_recent_experiences), but
-    /// applies additional filters on domain, experience type, importance,
-    /// confidence, and timestamp.
+    /// Evicted indexes are saved to the `.hnsw` directory and dropped from
+    /// memory. The next search against an evicted collective rebuilds them
+    /// from redb transparently, so callers never observe the eviction
+    /// except as added latency on that first search.
     ///
-    /// Over-fetches from storage (2x `limit`) to account for entries removed
-    /// by post-filtering, then truncates to the requested `limit`.
+    /// This also runs automatically, at most once per eviction window,
+    /// whenever a collective is used. Call it directly to reclaim memory
+    /// on your own schedule (e.g. from a maintenance timer).
     ///
-    /// # Arguments
+    /// Returns the number of collectives evicted. Always `0` when
+    /// `idle_eviction` is `None`.
     ///
-    /// * `collective_id` - The collective to query
-    /// * `limit` - Maximum number of experiences to return (1-1000)
-    /// * `filter` - Filter criteria to apply
+    /// # Example
     ///
-    /// # Errors
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// use std::time::Duration;
+    /// use pulsedb::{Config, PulseDB};
     ///
-    /// - [`ValidationError::InvalidField`] if `limit` is 0 or > 1000
-    /// - [`NotFoundError::Collective`] if the collective doesn't exist
-    #[instrument(skip(self, filter))]
-    pub fn get_recent_experiences_filtered(
-        &self,
-        collective_id: CollectiveId,
-        limit: usize,
-        filter: SearchFilter,
-    ) -> Result<Vec<Experience>> {
-        // Validate limit
-        if limit == 0 || limit > 1000 {
-            return Err(
-                ValidationError::invalid_field("limit", "must be between 1 and 1000").into(),
-            );
+    /// let db = PulseDB::open(dir.path().join("test.db"), Config {
+    ///     idle_eviction: Some(Duration::from_secs(600)),
+    ///     ..Default::default()
+    /// })?;
+    /// let evicted = db.evict_idle_collectives()?;
+    /// # assert_eq!(evicted, 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn evict_idle_collectives(&self) -> Result<usize> {
+        let Some(window) = self.config.idle_eviction else {
+            return Ok(0);
+        };
+        let now = Instant::now();
+
+        let idle: Vec<CollectiveId> = self
+            .last_access
+            .lock()
+            .map_err(|_| PulseDBError::internal("Last-access lock poisoned"))?
+            .iter()
+            .filter(|(_, last)| now.duration_since(**last) >= window)
+            .map(|(id, _)| *id)
+            .collect();
+        if idle.is_empty() {
+            return Ok(0);
         }
 
-        // Verify collective exists
-        self.storage
-            .get_collective(collective_id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
-
-        // Over-fetch IDs to account for post-filtering losses
-        let over_fetch = limit.saturating_mul(2).min(2000);
-        let recent_ids = self
-            .storage
-            .get_recent_experience_ids(collective_id, over_fetch)?;
+        let hnsw_dir = self.hnsw_dir();
+        let mut vectors = self
+            .vectors
+            .write()
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+        let mut insight_vectors = self
+            .insight_vectors
+            .write()
+            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
 
-        // Load full experiences and apply filter
-        let mut results = Vec::with_capacity(limit);
-        for (exp_id, _timestamp) in recent_ids {
-            if results.len() >= limit {
-                break;
+        // Re-check under the index locks: a collective used since the scan
+        // above, or pinned by a search or write, stays resident
+        let last_access = self
+            .last_access
+            .lock()
+            .map_err(|_| PulseDBError::internal("Last-access lock poisoned"))?;
+        let idle: Vec<CollectiveId> = idle
+            .into_iter()
+            .filter(|id| {
+                last_access
+                    .get(id)
+                    .is_some_and(|last| now.duration_since(*last) >= window)
+                    && !self.pins.is_pinned(*id)
+            })
+            .collect();
+        drop(last_access);
+
+        let mut evicted = 0;
+        for id in &idle {
+            let exp_index = vectors.remove(id);
+            let insight_index = insight_vectors.remove(id);
+            if exp_index.is_none() && insight_index.is_none() {
+                continue;
             }
 
-            if let Some(experience) = self.storage.get_experience(exp_id)? {
-                if filter.matches(&experience) {
-                    results.push(experience);
+            // Persist before dropping (non-fatal: reload rebuilds from redb)
+            if let Some(dir) = &hnsw_dir {
+                if let Some(index) = &exp_index {
+                    if let Err(e) = index.save_to_dir(dir, &id.to_string()) {
+                        warn!(collective = %id, error = %e, "Failed to save evicted HNSW index");
+                    }
+                }
+                if let Some(index) = &insight_index {
+                    let name = format!("{}_insights", id);
+                    if let Err(e) = index.save_to_dir(dir, &name) {
+                        warn!(
+                            collective = %id,
+                            error = %e,
+                            "Failed to save evicted insight HNSW index"
+                        );
+                    }
                 }
             }
+            evicted += 1;
         }
+        drop(insight_vectors);
+        drop(vectors);
 
-        Ok(results)
-    }
+        // Forget evicted collectives unless they were used meanwhile
+        if let Ok(mut last_access) = self.last_access.lock() {
+            last_access.retain(|id, last| !idle.contains(id) || now < *last);
+        }
 
-    // =========================================================================
-    // Similarity Search (E2-S02)
-    // =========================================================================
+        if evicted > 0 {
+            info!(count = evicted, "Evicted idle collective indexes");
+        }
+        Ok(evicted)
+    }
 
-    /// Searches for experiences semantically similar to the query embedding.
-    ///
-    /// Uses the HNSW vector index for approximate nearest neighbor search,
-    /// then fetches full experience records from storage. Archived experiences
-    /// are excluded by default.
+    /// Returns true if the collective's vector indexes are resident in memory.
     ///
-    /// Results are sorted by similarity descending (most similar first).
-    /// Similarity is computed as `1.0 - cosine_distance`.
+    /// Collectives evicted by [`evict_idle_collectives`](Self::evict_idle_collectives)
+    /// report `false` until their next search reloads them.
+    pub fn is_collective_loaded(&self, id: CollectiveId) -> bool {
+        self.vectors
+            .read()
+            .map(|v| v.contains_key(&id))
+            .unwrap_or(false)
+    }
+
+    /// Warms a collective's vector indexes and hot storage pages.
     ///
-    /// # Arguments
+    /// Reloads the indexes if they were evicted for idleness, walks every
+    /// vector in the experience and insight graphs, runs one traversal of
+    /// each, and reads the collective's most recent experience records so
+    /// the first search afterwards doesn't take a cold-start latency hit.
     ///
-    /// * `collective_id` - The collective to search within
-    /// * `query` - Query embedding vector (must match collective's dimension)
-    /// * `k` - Maximum number of results to return (1-1000)
+    /// Returns the number of vectors touched across both indexes. Set
+    /// [`HnswConfig::warm_after_rebuild`](crate::HnswConfig::warm_after_rebuild)
+    /// to do the index part automatically on open and reload.
     ///
     /// # Errors
     ///
-    /// - [`ValidationError::InvalidField`] if `k` is 0 or > 1000
-    /// - [`ValidationError::DimensionMismatch`] if `query.len()` doesn't match
-    ///   the collective's embedding dimension
-    /// - [`NotFoundError::Collective`] if the collective doesn't exist
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # fn main() -> pulsedb::Result<()> {
-    /// # let dir = tempfile::tempdir().unwrap();
-    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    #[instrument(skip(self))]
+    pub fn warm_collective(&self, id: CollectiveId) -> Result<usize> {
+        self.storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+        let _pin = self.ensure_indexes_loaded(id)?;
+
+        let mut touched = 0;
+        if let Some(index) = self
+            .vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?
+            .get(&id)
+        {
+            touched += index.warm()?;
+        }
+        if let Some(index) = self
+            .insight_vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?
+            .get(&id)
+        {
+            touched += index.warm()?;
+        }
+
+        // Pull the recent end of the collective into the page cache
+        for (exp_id, _) in self
+            .storage
+            .get_recent_experience_ids(id, WARM_PREFETCH_EXPERIENCES)?
+        {
+            std::hint::black_box(self.storage.get_experience(exp_id)?);
+        }
+
+        info!(collective = %id, vectors = touched, "Collective warmed");
+        Ok(touched)
+    }
+
+    /// Merges one run of sealed segments in a collective's vector indexes.
+    ///
+    /// Indexes larger than [`HnswConfig::max_segment_size`](crate::HnswConfig::max_segment_size)
+    /// are split into segments. Each call compacts at most one run of
+    /// sealed segments per index (experience and insight), dropping vectors
+    /// of deleted records, while searches and writes continue. Call it
+    /// repeatedly from a maintenance thread until it returns `false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
     /// # let collective_id = db.create_collective("example")?;
-    /// let query = vec![0.1f32; 384]; // Your query embedding
-    /// let results = db.search_similar(collective_id, &query, 10)?;
-    /// for result in &results {
-    ///     println!(
-    ///         "[{:.3}] {}",
-    ///         result.similarity, result.experience.content
-    ///     );
-    /// }
+    /// while db.merge_index_segments(collective_id)? {}
     /// # Ok(())
     /// # }
     /// ```
-    #[instrument(skip(self, query))]
-    pub fn search_similar(
-        &self,
-        collective_id: CollectiveId,
-        query: &[f32],
-        k: usize,
-    ) -> Result<Vec<SearchResult>> {
-        self.search_similar_filtered(collective_id, query, k, SearchFilter::default())
+    #[instrument(skip(self))]
+    pub fn merge_index_segments(&self, id: CollectiveId) -> Result<bool> {
+        self.storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+        let _pin = self.ensure_indexes_loaded(id)?;
+
+        let mut merged = false;
+        if let Some(index) = self
+            .vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?
+            .get(&id)
+        {
+            merged |= index.merge_segments()?;
+        }
+        if let Some(index) = self
+            .insight_vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?
+            .get(&id)
+        {
+            merged |= index.merge_segments()?;
+        }
+        Ok(merged)
     }
 
-    /// Searches for semantically similar experiences with additional filtering.
-    ///
-    /// Like [`search_similar()`](Self::search_similar), but applies additional
-    /// filters on domain, experience type, importance, confidence, and timestamp.
-    ///
-    /// Over-fetches from the HNSW index (2x `k`) to account for entries removed
-    /// by post-filtering, then truncates to the requested `k`.
+    /// Switches the vector index a collective's experiences are searched
+    /// through.
     ///
-    /// # Arguments
-    ///
-    /// * `collective_id` - The collective to search within
-    /// * `query` - Query embedding vector (must match collective's dimension)
-    /// * `k` - Maximum number of results to return (1-1000)
-    /// * `filter` - Filter criteria to apply after vector search
+    /// [`VectorIndexKind::Ivf`] keeps vectors on disk for collectives
+    /// larger than RAM; [`VectorIndexKind::Hnsw`] (the default) keeps them
+    /// in memory. The choice is persisted, and the experience index is
+    /// rebuilt from redb in the new form before this returns, which takes
+    /// one pass over the collective's embeddings. Insight indexes are
+    /// unaffected. Setting the current kind is a no-op. While
+    /// [`Config::vector_backend`] is set, the kind is only recorded.
     ///
     /// # Errors
     ///
-    /// - [`ValidationError::InvalidField`] if `k` is 0 or > 1000
-    /// - [`ValidationError::DimensionMismatch`] if `query.len()` doesn't match
-    ///   the collective's embedding dimension
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
     /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
     ///
     /// # Example
     ///
@@ -1295,207 +2630,318 @@ impl PulseDB {
     /// # fn main() -> pulsedb::Result<()> {
     /// # let dir = tempfile::tempdir().unwrap();
     /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
-    /// # let collective_id = db.create_collective("example")?;
-    /// # let query_embedding = vec![0.1f32; 384];
-    /// use pulsedb::SearchFilter;
+    /// use pulsedb::VectorIndexKind;
     ///
-    /// let filter = SearchFilter {
-    ///     domains: Some(vec!["rust".to_string()]),
-    ///     min_importance: Some(0.5),
-    ///     ..SearchFilter::default()
-    /// };
-    /// let results = db.search_similar_filtered(
-    ///     collective_id,
-    ///     &query_embedding,
-    ///     10,
-    ///     filter,
-    /// )?;
+    /// let archive = db.create_collective("archive")?;
+    /// db.set_vector_index_kind(archive, VectorIndexKind::Ivf)?;
+    /// assert_eq!(db.get_vector_index_kind(archive)?, VectorIndexKind::Ivf);
     /// # Ok(())
     /// # }
     /// ```
-    #[instrument(skip(self, query, filter))]
-    pub fn search_similar_filtered(
-        &self,
-        collective_id: CollectiveId,
-        query: &[f32],
-        k: usize,
-        filter: SearchFilter,
-    ) -> Result<Vec<SearchResult>> {
-        // Validate k
-        if k == 0 || k > 1000 {
-            return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
-        }
-
-        // Verify collective exists and check embedding dimension
+    #[instrument(skip(self))]
+    pub fn set_vector_index_kind(&self, id: CollectiveId, kind: VectorIndexKind) -> Result<()> {
+        self.check_writable()?;
         let collective = self
             .storage
-            .get_collective(collective_id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
-
-        let expected_dim = collective.embedding_dimension as usize;
-        if query.len() != expected_dim {
-            return Err(ValidationError::dimension_mismatch(expected_dim, query.len()).into());
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+        let _fence = self.check_collective_writable(id)?;
+        if self.storage.get_collective_index_kind(id)? == kind {
+            return Ok(());
         }
+        self.storage.set_collective_index_kind(id, kind)?;
 
-        // Over-fetch from HNSW to compensate for post-filtering losses
-        let over_fetch = k.saturating_mul(2).min(2000);
-        let ef_search = self.config.hnsw.ef_search;
-
-        // Search HNSW index — returns (ExperienceId, cosine_distance) sorted
-        // by distance ascending (closest first)
-        let candidates = self
-            .with_vector_index(collective_id, |index| {
-                index.search_experiences(query, over_fetch, ef_search)
-            })?
-            .unwrap_or_default();
-
-        // Fetch full experiences, apply filter, convert distance → similarity
-        let mut results = Vec::with_capacity(k);
-        for (exp_id, distance) in candidates {
-            if results.len() >= k {
-                break;
-            }
-
-            if let Some(experience) = self.storage.get_experience(exp_id)? {
-                if filter.matches(&experience) {
-                    results.push(SearchResult {
-                        experience,
-                        similarity: 1.0 - distance,
-                    });
-                }
+        // Rebuild under the map write lock so no insert slips in between
+        let hnsw_dir = self.hnsw_dir();
+        {
+            let mut vectors = self
+                .vectors
+                .write()
+                .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+            let index = Self::build_experience_index(
+                self.storage.as_ref(),
+                &self.config,
+                &collective,
+                hnsw_dir.as_deref(),
+            )?;
+            drop(vectors.insert(id, index));
+        }
+        self.touch_collective(id);
+
+        // Drop the previous kind's files (non-fatal if fails)
+        if let Some(dir) = hnsw_dir {
+            let name = id.to_string();
+            let removed = match kind {
+                VectorIndexKind::Hnsw => IvfIndex::remove_files(&dir, &name),
+                VectorIndexKind::Ivf => HnswIndex::remove_files(&dir, &name),
+            };
+            if let Err(e) = removed {
+                warn!(collective = %id, error = %e, "Failed to remove old index files (non-fatal)");
             }
         }
 
-        Ok(results)
+        info!(collective = %id, kind = ?kind, "Vector index kind changed");
+        Ok(())
+    }
+
+    /// Returns the vector index kind a collective's experiences use.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    pub fn get_vector_index_kind(&self, id: CollectiveId) -> Result<VectorIndexKind> {
+        self.storage
+            .get_collective(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(id)))?;
+        self.storage.get_collective_index_kind(id)
     }
 
     // =========================================================================
-    // Experience Relations (E3-S01)
+    // Write Hooks
     // =========================================================================
 
-    /// Stores a new relation between two experiences.
+    /// Registers a write hook.
     ///
-    /// Relations are typed, directed edges connecting a source experience to a
-    /// target experience. Both experiences must exist and belong to the same
-    /// collective. Duplicate relations (same source, target, and type) are
-    /// rejected.
+    /// Hooks run for experiences, insights, and relations written through
+    /// this handle, in the order they were added. See [`Hook`] for the
+    /// pre-write and post-write stages.
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `relation` - The relation to create (source, target, type, strength)
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// use std::sync::Arc;
+    /// use pulsedb::{Config, Hook, NewExperience, PendingWrite, PulseDB, Result};
     ///
-    /// # Errors
+    /// struct Redact;
     ///
-    /// Returns an error if:
-    /// - Source or target experience doesn't exist ([`NotFoundError::Experience`])
-    /// - Experiences belong to different collectives ([`ValidationError::InvalidField`])
-    /// - A relation with the same (source, target, type) already exists
-    /// - Self-relation attempted (source == target)
-    /// - Strength is out of range `[0.0, 1.0]`
-    #[instrument(skip(self, relation))]
-    pub fn store_relation(
-        &self,
-        relation: crate::relation::NewExperienceRelation,
-    ) -> Result<crate::types::RelationId> {
-        self.check_writable()?;
-        use crate::relation::{validate_new_relation, ExperienceRelation};
-        use crate::types::RelationId;
-
-        // Validate input fields (self-relation, strength bounds, metadata size)
-        validate_new_relation(&relation)?;
-
-        // Load source and target experiences to verify existence
-        let source = self
-            .storage
-            .get_experience(relation.source_id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(relation.source_id)))?;
-        let target = self
-            .storage
-            .get_experience(relation.target_id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(relation.target_id)))?;
-
-        // Verify same collective
-        if source.collective_id != target.collective_id {
-            return Err(PulseDBError::from(ValidationError::invalid_field(
-                "target_id",
-                "source and target experiences must belong to the same collective",
-            )));
+    /// impl Hook for Redact {
+    ///     fn pre_write(&self, write: &mut PendingWrite<'_>) -> Result<()> {
+    ///         if let PendingWrite::Experience(exp) = write {
+    ///             exp.content = exp.content.replace("hunter2", "[redacted]");
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let db = PulseDB::open(dir.path().join("test.db"), Config::default())?;
+    /// db.add_hook(Arc::new(Redact));
+    ///
+    /// let collective_id = db.create_collective("ops")?;
+    /// let id = db.record_experience(NewExperience {
+    ///     collective_id,
+    ///     content: "password is hunter2".to_string(),
+    ///     embedding: Some(vec![0.1; 384]),
+    ///     ..Default::default()
+    /// })?;
+    /// assert_eq!(db.get_experience(id)?.unwrap().content, "password is [redacted]");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_hook(&self, hook: Arc<dyn Hook>) {
+        if let Ok(mut hooks) = self.hooks.write() {
+            hooks.push(hook);
         }
+    }
 
-        // Check for duplicate (same source, target, type)
-        if self.storage.relation_exists(
-            relation.source_id,
-            relation.target_id,
-            relation.relation_type,
-        )? {
-            return Err(PulseDBError::from(ValidationError::invalid_field(
-                "relation_type",
-                "a relation with this source, target, and type already exists",
-            )));
+    /// Removes all registered write hooks.
+    pub fn clear_hooks(&self) {
+        if let Ok(mut hooks) = self.hooks.write() {
+            hooks.clear();
         }
+    }
 
-        // Construct the full relation
-        let id = RelationId::new();
-        let full_relation = ExperienceRelation {
-            id,
-            source_id: relation.source_id,
-            target_id: relation.target_id,
-            relation_type: relation.relation_type,
-            strength: relation.strength,
-            metadata: relation.metadata,
-            created_at: Timestamp::now(),
-        };
+    /// Snapshot of the registered hooks, so none run under the lock.
+    fn hooks(&self) -> Result<Vec<Arc<dyn Hook>>> {
+        Ok(self
+            .hooks
+            .read()
+            .map_err(|_| PulseDBError::internal("Hooks lock poisoned"))?
+            .clone())
+    }
 
-        self.storage.save_relation(&full_relation)?;
+    /// Runs the pre-write stage; the first error aborts the write.
+    fn run_pre_write_hooks(&self, mut write: PendingWrite<'_>) -> Result<()> {
+        for hook in self.hooks()? {
+            hook.pre_write(&mut write)?;
+        }
+        Ok(())
+    }
 
-        info!(
-            id = %id,
-            source = %relation.source_id,
-            target = %relation.target_id,
-            relation_type = ?full_relation.relation_type,
-            "Relation stored"
-        );
-        Ok(id)
+    /// Runs the post-write stage; errors are logged, not returned.
+    fn run_post_write_hooks(&self, write: CommittedWrite<'_>) {
+        let Ok(hooks) = self.hooks() else {
+            return;
+        };
+        for hook in hooks {
+            if let Err(e) = hook.post_write(&write) {
+                warn!(error = %e, "Post-write hook failed");
+            }
+        }
     }
 
-    /// Retrieves experiences related to the given experience.
+    /// Registers a read hook for a collective.
     ///
-    /// Returns pairs of `(Experience, ExperienceRelation)` based on the
-    /// requested direction:
-    /// - `Outgoing`: experiences that this experience points TO (as source)
-    /// - `Incoming`: experiences that point TO this experience (as target)
-    /// - `Both`: union of outgoing and incoming
+    /// The hook rewrites the collective's experiences and insights as they
+    /// are returned from get, list, search, and context calls; see
+    /// [`ReadHook`]. Hooks for a collective run in the order they were
+    /// added. Results spanning sub-collectives run each record through the
+    /// hooks of the collective it belongs to.
     ///
-    /// To filter by relation type, use
-    /// [`get_related_experiences_filtered`](Self::get_related_experiences_filtered).
+    /// # Errors
     ///
-    /// Silently skips relations where the related experience no longer exists
-    /// (orphan tolerance).
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// Returns a storage error if the read transaction fails.
-    #[instrument(skip(self))]
-    pub fn get_related_experiences(
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// use std::sync::Arc;
+    /// use pulsedb::{Config, NewExperience, PulseDB, ReadHook, ReadRecord, Result};
+    ///
+    /// struct Shout;
+    ///
+    /// impl ReadHook for Shout {
+    ///     fn on_read(&self, record: &mut ReadRecord<'_>) -> Result<()> {
+    ///         if let ReadRecord::Experience(exp) = record {
+    ///             exp.content = exp.content.to_uppercase();
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let db = PulseDB::open(dir.path().join("test.db"), Config::default())?;
+    /// let collective_id = db.create_collective("loud")?;
+    /// db.add_read_hook(collective_id, Arc::new(Shout))?;
+    ///
+    /// let id = db.record_experience(NewExperience {
+    ///     collective_id,
+    ///     content: "hello".to_string(),
+    ///     embedding: Some(vec![0.1; 384]),
+    ///     ..Default::default()
+    /// })?;
+    /// assert_eq!(db.get_experience(id)?.unwrap().content, "HELLO");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_read_hook(
         &self,
-        experience_id: ExperienceId,
-        direction: crate::relation::RelationDirection,
-    ) -> Result<Vec<(Experience, crate::relation::ExperienceRelation)>> {
-        self.get_related_experiences_filtered(experience_id, direction, None)
+        collective_id: CollectiveId,
+        hook: Arc<dyn ReadHook>,
+    ) -> Result<()> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        self.read_hooks
+            .write()
+            .map_err(|_| PulseDBError::internal("Read hooks lock poisoned"))?
+            .entry(collective_id)
+            .or_default()
+            .push(hook);
+        Ok(())
     }
 
-    /// Retrieves experiences related to the given experience, with optional
-    /// type filtering.
+    /// Removes all read hooks registered for a collective.
+    pub fn clear_read_hooks(&self, collective_id: CollectiveId) {
+        if let Ok(mut hooks) = self.read_hooks.write() {
+            hooks.remove(&collective_id);
+        }
+    }
+
+    /// Snapshot of a collective's read hooks, so none run under the lock.
+    fn read_hooks_for(&self, collective_id: CollectiveId) -> Result<Vec<Arc<dyn ReadHook>>> {
+        Ok(self
+            .read_hooks
+            .read()
+            .map_err(|_| PulseDBError::internal("Read hooks lock poisoned"))?
+            .get(&collective_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Runs the read hooks of the experience's collective over it.
+    fn run_read_hooks_on_experience(&self, experience: &mut Experience) -> Result<()> {
+        for hook in self.read_hooks_for(experience.collective_id)? {
+            hook.on_read(&mut ReadRecord::Experience(experience))?;
+        }
+        Ok(())
+    }
+
+    /// Runs the read hooks of the insight's collective over it.
+    fn run_read_hooks_on_insight(&self, insight: &mut DerivedInsight) -> Result<()> {
+        for hook in self.read_hooks_for(insight.collective_id)? {
+            hook.on_read(&mut ReadRecord::Insight(insight))?;
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Experience CRUD (E1-S03)
+    // =========================================================================
+
+    /// Records a new experience in the database.
     ///
-    /// Like [`get_related_experiences()`](Self::get_related_experiences), but
-    /// accepts an optional [`RelationType`](crate::RelationType) filter.
-    /// When `Some(rt)`, only relations matching that type are returned.
+    /// This is the primary method for storing agent-learned knowledge. The method:
+    /// 1. Validates the input (content, scores, tags, embedding)
+    /// 2. Verifies the collective exists
+    /// 3. Resolves the embedding (generates if Builtin, requires if External)
+    /// 4. Stores the experience atomically across 4 tables
     ///
     /// # Arguments
     ///
-    /// * `experience_id` - The experience to query relations for
-    /// * `direction` - Which direction(s) to traverse
-    /// * `relation_type` - If `Some`, only return relations of this type
+    /// * `exp` - The experience to record (see [`NewExperience`])
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError`](crate::ValidationError) if input is invalid,
+    ///   if an experience with the resolved ID already exists, or if the
+    ///   collective or an ancestor is at its
+    ///   [quota](Self::set_collective_quota)
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Embedding`] if embedding generation fails (Builtin mode)
+    ///
+    /// A vector index insert that fails once the experience is stored is
+    /// not an error: the experience is queued for re-insertion and
+    /// reported by [`health()`](Self::health) until a later write or
+    /// search succeeds in indexing it.
+    #[instrument(skip(self, exp), fields(collective_id = %exp.collective_id))]
+    pub fn record_experience(&self, exp: NewExperience) -> Result<ExperienceId> {
+        match self.record_new_experience(exp)? {
+            Recorded::New(id) => Ok(id),
+            Recorded::Existing(id) => Err(ValidationError::invalid_field(
+                "id",
+                format!("experience {} already exists", id),
+            )
+            .into()),
+        }
+    }
+
+    /// Records many experiences, reporting an outcome per item instead of
+    /// failing the batch on the first bad one.
+    ///
+    /// Every item is checked as [`record_experience()`](Self::record_experience)
+    /// would check it, then all accepted items are written in a single redb
+    /// write transaction and added to each collective's vector index in one
+    /// batch — far faster than recording them one by one for bulk imports.
+    /// An item whose ID already exists, in the database or earlier in the
+    /// batch, is reported as [`BatchOutcome::Deduped`]; one refused by
+    /// validation, a missing or frozen collective, a content policy, a
+    /// [quota](Self::set_collective_quota), embedding generation, or a
+    /// write hook is [`BatchOutcome::Rejected`]
+    /// with the reason.
+    ///
+    /// # Errors
+    ///
+    /// Only failures that would affect every item stop the batch:
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`PulseDBError::Storage`] or [`PulseDBError::Io`] errors; if they
+    ///   occur before the write commits, nothing is recorded
+    /// - [`PulseDBError::Vector`] or [`PulseDBError::Watch`] errors after
+    ///   the commit; the items stay recorded and the vector index catches
+    ///   up on the next open
     ///
     /// # Example
     ///
@@ -1503,332 +2949,6657 @@ impl PulseDB {
     /// # fn main() -> pulsedb::Result<()> {
     /// # let dir = tempfile::tempdir().unwrap();
     /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
-    /// # let cid = db.create_collective("example")?;
-    /// # let exp_a = db.record_experience(pulsedb::NewExperience {
-    /// #     collective_id: cid,
-    /// #     content: "a".into(),
-    /// #     embedding: Some(vec![0.1f32; 384]),
-    /// #     ..Default::default()
-    /// # })?;
-    /// use pulsedb::{RelationType, RelationDirection};
+    /// # let cid = db.create_collective("logs")?;
+    /// use pulsedb::{BatchOutcome, NewExperience};
     ///
-    /// // Only "Supports" relations outgoing from exp_a
-    /// let supports = db.get_related_experiences_filtered(
-    ///     exp_a,
-    ///     RelationDirection::Outgoing,
-    ///     Some(RelationType::Supports),
-    /// )?;
+    /// let line = |content: &str| NewExperience {
+    ///     collective_id: cid,
+    ///     content: content.into(),
+    ///     embedding: Some(vec![0.1; 384]),
+    ///     ..Default::default()
+    /// };
+    /// let report = db.record_experiences_batch(vec![line("Disk full on node-3"), line("")])?;
+    ///
+    /// assert_eq!(report.accepted(), 1);
+    /// assert!(matches!(report.outcomes[1], BatchOutcome::Rejected(_)));
     /// # Ok(())
     /// # }
     /// ```
-    #[instrument(skip(self))]
-    pub fn get_related_experiences_filtered(
-        &self,
-        experience_id: ExperienceId,
-        direction: crate::relation::RelationDirection,
-        relation_type: Option<crate::relation::RelationType>,
-    ) -> Result<Vec<(Experience, crate::relation::ExperienceRelation)>> {
-        use crate::relation::RelationDirection;
-
-        let mut results = Vec::new();
+    #[instrument(skip(self, experiences), fields(count = experiences.len()))]
+    pub fn record_experiences_batch(&self, experiences: Vec<NewExperience>) -> Result<BatchReport> {
+        self.check_writable()?;
+        self.reinsert_unindexed();
+        self.write_queued_gaps();
+        let mut report = BatchReport {
+            outcomes: Vec::with_capacity(experiences.len()),
+        };
 
-        // Outgoing: this experience is the source → fetch target experiences
-        if matches!(
-            direction,
-            RelationDirection::Outgoing | RelationDirection::Both
-        ) {
-            let rel_ids = self.storage.get_relation_ids_by_source(experience_id)?;
-            for rel_id in rel_ids {
-                if let Some(relation) = self.storage.get_relation(rel_id)? {
-                    if relation_type.is_some_and(|rt| rt != relation.relation_type) {
-                        continue;
-                    }
-                    if let Some(experience) = self.storage.get_experience(relation.target_id)? {
-                        results.push((experience, relation));
-                    }
+        // Check every item first; accepted ones hold a Deduped outcome
+        // until the write says otherwise
+        let mut records = Vec::new();
+        let mut slots = Vec::new();
+        // Fence guards and index pins, held until the batch is written
+        let mut fences = Vec::new();
+        let mut pins = Vec::new();
+        for exp in experiences {
+            match self.prepare_experience(exp) {
+                Ok((experience, pending, fence)) => {
+                    fences.push(fence);
+                    pins.push(self.pins.pin(experience.collective_id));
+                    slots.push((report.outcomes.len(), pending));
+                    report.outcomes.push(BatchOutcome::Deduped(experience.id));
+                    records.push(experience);
                 }
+                Err(
+                    e @ (PulseDBError::Storage(_)
+                    | PulseDBError::Io(_)
+                    | PulseDBError::Vector(_)
+                    | PulseDBError::Watch(_)
+                    | PulseDBError::ReadOnly),
+                ) => return Err(e),
+                Err(e) => report.outcomes.push(BatchOutcome::Rejected(e.to_string())),
             }
         }
+        if records.is_empty() {
+            return Ok(report);
+        }
 
-        // Incoming: this experience is the target → fetch source experiences
-        if matches!(
-            direction,
-            RelationDirection::Incoming | RelationDirection::Both
-        ) {
-            let rel_ids = self.storage.get_relation_ids_by_target(experience_id)?;
-            for rel_id in rel_ids {
-                if let Some(relation) = self.storage.get_relation(rel_id)? {
-                    if relation_type.is_some_and(|rt| rt != relation.relation_type) {
-                        continue;
-                    }
-                    if let Some(experience) = self.storage.get_experience(relation.source_id)? {
-                        results.push((experience, relation));
-                    }
+        // Items past a quota are rejected; the rest are written while the
+        // quota lock is held
+        let collective_ids: Vec<CollectiveId> = records.iter().map(|e| e.collective_id).collect();
+        let (_quota, verdicts) = self.check_quotas(&collective_ids)?;
+        let mut admitted = Vec::with_capacity(records.len());
+        let mut admitted_slots = Vec::with_capacity(slots.len());
+        for ((experience, slot), verdict) in records.into_iter().zip(slots).zip(verdicts) {
+            match verdict {
+                Some(e) => report.outcomes[slot.0] = BatchOutcome::Rejected(e.to_string()),
+                None => {
+                    admitted.push(experience);
+                    admitted_slots.push(slot);
                 }
             }
         }
+        let (records, slots) = (admitted, admitted_slots);
+        if records.is_empty() {
+            return Ok(report);
+        }
 
-        Ok(results)
-    }
+        // One redb write transaction for the whole batch (source of truth)
+        let written = self.storage.insert_experiences(&records)?;
+        for ((experience, _), (_, pending)) in records
+            .iter()
+            .zip(&written)
+            .zip(&slots)
+            .filter(|((_, w), _)| **w)
+        {
+            self.queue_recorded(experience, *pending)?;
+        }
 
-    /// Retrieves a relation by ID.
-    ///
-    /// Returns `None` if no relation with the given ID exists.
-    pub fn get_relation(
-        &self,
-        id: crate::types::RelationId,
-    ) -> Result<Option<crate::relation::ExperienceRelation>> {
-        self.storage.get_relation(id)
+        // One vector index batch per collective (derived structure)
+        let mut by_collective: HashMap<CollectiveId, Vec<(ExperienceId, &Vec<f32>)>> =
+            HashMap::new();
+        for (experience, _) in records.iter().zip(&written).filter(|(_, w)| **w) {
+            by_collective
+                .entry(experience.collective_id)
+                .or_default()
+                .push((experience.id, &experience.embedding));
+        }
+        let vectors = self
+            .vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+        for (collective_id, items) in &by_collective {
+            if let Some(index) = vectors.get(collective_id) {
+                index.insert_experiences(items)?;
+            }
+        }
+        drop(vectors);
+        drop(by_collective);
+
+        for ((experience, written), (slot, pending)) in records.into_iter().zip(written).zip(slots)
+        {
+            if written {
+                report.outcomes[slot] = BatchOutcome::Accepted(experience.id);
+                self.announce_recorded(experience, pending)?;
+            }
+        }
+
+        info!(
+            accepted = report.accepted(),
+            deduped = report.deduped(),
+            rejected = report.rejected(),
+            "Experience batch recorded"
+        );
+        Ok(report)
     }
 
-    /// Deletes a relation by ID.
+    /// Records experiences mapped from OpenTelemetry traces in OTLP/JSON.
     ///
-    /// # Errors
+    /// Reads `reader` to the end, maps spans with `mapping` (see the
+    /// [`otel`](crate::otel) module for what is ingested), and records the
+    /// candidates like
+    /// [`record_experiences_batch()`](Self::record_experiences_batch).
+    /// Embeddings are generated by the database's provider, so with
+    /// [`EmbeddingProvider::External`] use
+    /// [`OtelMapping::candidates()`](crate::otel::OtelMapping::candidates)
+    /// and attach embeddings before recording. IDs are derived from trace
+    /// and span IDs, so re-ingesting a file reports its spans as deduped.
     ///
-    /// Returns [`NotFoundError::Relation`] if no relation with the given ID exists.
-    #[instrument(skip(self))]
-    pub fn delete_relation(&self, id: crate::types::RelationId) -> Result<()> {
-        self.check_writable()?;
-        let deleted = self.storage.delete_relation(id)?;
-        if !deleted {
-            return Err(PulseDBError::from(NotFoundError::relation(id)));
-        }
-        info!(id = %id, "Relation deleted");
-        Ok(())
-    }
-
-    // =========================================================================
-    // Derived Insights (E3-S02)
-    // =========================================================================
-
-    /// Stores a new derived insight.
+    /// # Errors
     ///
-    /// Creates a synthesized knowledge record from multiple source experiences.
-    /// The method:
-    /// 1. Validates the input (content, confidence, sources)
-    /// 2. Verifies the collective exists
-    /// 3. Verifies all source experiences exist and belong to the same collective
-    /// 4. Resolves the embedding (generates if Builtin, requires if External)
-    /// 5. Stores the insight with inline embedding
-    /// 6. Inserts into the insight HNSW index
+    /// - [`ValidationError::InvalidField`] if the input is not OTLP/JSON
+    /// - Otherwise as
+    ///   [`record_experiences_batch()`](Self::record_experiences_batch)
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `insight` - The insight to store (see [`NewDerivedInsight`])
+    /// ```rust,no_run
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// use pulsedb::otel::OtelMapping;
+    /// use pulsedb::{Config, EmbeddingDimension, PulseDB};
     ///
-    /// # Errors
+    /// let config = Config::with_hashing_embeddings(EmbeddingDimension::D384);
+    /// let db = PulseDB::open(dir.path().join("test.db"), config)?;
+    /// let cid = db.create_collective("agents")?;
     ///
-    /// - [`ValidationError`](crate::ValidationError) if input is invalid
-    /// - [`NotFoundError::Collective`] if the collective doesn't exist
-    /// - [`NotFoundError::Experience`] if any source experience doesn't exist
-    /// - [`ValidationError::InvalidField`] if source experiences belong to
-    ///   different collectives
-    /// - [`ValidationError::DimensionMismatch`] if embedding dimension is wrong
-    #[instrument(skip(self, insight), fields(collective_id = %insight.collective_id))]
-    pub fn store_insight(&self, insight: NewDerivedInsight) -> Result<InsightId> {
+    /// let traces = std::fs::File::open("traces.jsonl")?;
+    /// let report = db.ingest_otel(cid, traces, &OtelMapping::default())?;
+    /// println!("{} spans remembered", report.accepted());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "otel")]
+    #[instrument(skip(self, reader, mapping))]
+    pub fn ingest_otel(
+        &self,
+        collective_id: CollectiveId,
+        reader: impl std::io::Read,
+        mapping: &crate::otel::OtelMapping,
+    ) -> Result<BatchReport> {
         self.check_writable()?;
-        let is_external = matches!(self.config.embedding_provider, EmbeddingProvider::External);
-
-        // Validate input fields
-        validate_new_insight(&insight)?;
+        let candidates = mapping.candidates(collective_id, reader)?;
+        self.record_experiences_batch(candidates)
+    }
 
-        // Verify collective exists
-        let collective = self
-            .storage
-            .get_collective(insight.collective_id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(insight.collective_id)))?;
+    /// Body of [`record_experience()`](Self::record_experience), reporting
+    /// an existing ID as [`Recorded::Existing`] instead of an error.
+    fn record_new_experience(&self, exp: NewExperience) -> Result<Recorded> {
+        self.check_writable()?;
+        self.reinsert_unindexed();
+        self.write_queued_gaps();
+        let (experience, pending, _fence) = self.prepare_experience(exp)?;
+        let _pin = self.pins.pin(experience.collective_id);
+        let (_quota, verdicts) = self.check_quotas(&[experience.collective_id])?;
+        if let Some(Some(e)) = verdicts.into_iter().next() {
+            return Err(e);
+        }
+        let id = experience.id;
 
-        // Verify all source experiences exist and belong to this collective
-        for source_id in &insight.source_experience_ids {
-            let source_exp = self
-                .storage
-                .get_experience(*source_id)?
-                .ok_or_else(|| PulseDBError::from(NotFoundError::experience(*source_id)))?;
-            if source_exp.collective_id != insight.collective_id {
-                return Err(PulseDBError::from(ValidationError::invalid_field(
-                    "source_experience_ids",
-                    format!(
-                        "experience {} belongs to collective {}, not {}",
-                        source_id, source_exp.collective_id, insight.collective_id
-                    ),
-                )));
-            }
+        // Write to redb FIRST (source of truth). If crash happens after
+        // this but before HNSW insert, rebuild on next open will include it.
+        // The existence check runs in the same transaction as the write.
+        if !self.storage.insert_experience(&experience)? {
+            return Ok(Recorded::Existing(id));
         }
+        self.queue_recorded(&experience, pending)?;
 
-        // Resolve embedding
-        let embedding = match insight.embedding {
-            Some(ref emb) => {
-                // Validate dimension
-                let expected_dim = collective.embedding_dimension as usize;
-                if emb.len() != expected_dim {
-                    return Err(ValidationError::dimension_mismatch(expected_dim, emb.len()).into());
-                }
-                emb.clone()
-            }
-            None => {
-                if is_external {
-                    return Err(PulseDBError::embedding(
-                        "embedding is required when using External embedding provider",
-                    ));
+        // Insert into HNSW index (derived structure). The record is already
+        // committed, so a failed insert is queued for re-insertion rather
+        // than reported as a failed write.
+        let vectors = self
+            .vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+        if let Some(index) = vectors.get(&experience.collective_id) {
+            if let Err(e) = index.insert_experience(id, &experience.embedding) {
+                warn!(%id, error = %e, "Vector index insert failed; queued for re-insertion");
+                if let Ok(mut unindexed) = self.unindexed.lock() {
+                    unindexed.insert(id, experience.collective_id);
                 }
-                self.embedding.embed(&insight.content)?
             }
-        };
+        }
+        drop(vectors);
 
-        let embedding_for_hnsw = embedding.clone();
-        let now = Timestamp::now();
-        let id = InsightId::new();
+        self.announce_recorded(experience, pending)?;
+        Ok(Recorded::New(id))
+    }
 
-        // Construct the full insight record
-        let derived_insight = DerivedInsight {
-            id,
-            collective_id: insight.collective_id,
-            content: insight.content,
-            embedding,
-            source_experience_ids: insight.source_experience_ids,
-            insight_type: insight.insight_type,
-            confidence: insight.confidence,
-            domain: insight.domain,
-            created_at: now,
-            updated_at: now,
+    /// Retries vector index inserts that failed after their experience
+    /// was committed.
+    ///
+    /// Entries are dropped once inserted, or when the experience was
+    /// deleted or its collective's indexes are not resident — an evicted
+    /// or reopened collective is rebuilt from redb and picks them up.
+    fn reinsert_unindexed(&self) {
+        let queued: Vec<(ExperienceId, CollectiveId)> = match self.unindexed.lock() {
+            Ok(unindexed) if !unindexed.is_empty() => {
+                unindexed.iter().map(|(id, cid)| (*id, *cid)).collect()
+            }
+            _ => return,
         };
+        let Ok(vectors) = self.vectors.read() else {
+            return;
+        };
+        for (id, collective_id) in queued {
+            let done = match vectors.get(&collective_id) {
+                None => true,
+                Some(index) => match self.storage.get_experience(id) {
+                    Ok(None) => true,
+                    Ok(Some(experience)) => {
+                        match index.insert_experience(id, &experience.embedding) {
+                            Ok(()) => true,
+                            Err(e) => {
+                                warn!(%id, error = %e, "Vector index re-insertion failed");
+                                false
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        warn!(%id, error = %e, "Failed to load experience for re-insertion");
+                        false
+                    }
+                },
+            };
+            if done {
+                if let Ok(mut unindexed) = self.unindexed.lock() {
+                    unindexed.remove(&id);
+                }
+            }
+        }
+    }
 
-        // Write to redb FIRST (source of truth)
-        self.storage.save_insight(&derived_insight)?;
+    /// Turns a new experience into the record to write: runs pre-write
+    /// hooks, checks the collective, validation, and content policy,
+    /// resolves the embedding and ID, and decides whether it waits for
+    /// review (returned alongside; pending records are archived). The
+    /// returned fence guard must be held until the record is written.
+    fn prepare_experience(
+        &self,
+        mut exp: NewExperience,
+    ) -> Result<(Experience, bool, FenceGuard<'_>)> {
+        self.run_pre_write_hooks(PendingWrite::Experience(&mut exp))?;
+        let fence = self.check_collective_writable(exp.collective_id)?;
+        let is_external = self.requires_embeddings();
 
-        // Insert into insight HNSW index (using InsightId→ExperienceId byte conversion)
-        let exp_id = ExperienceId::from_bytes(*id.as_bytes());
-        let insight_vectors = self
-            .insight_vectors
+        // Verify collective exists and get its dimension
+        let collective = self
+            .storage
+            .get_collective(exp.collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(exp.collective_id)))?;
+
+        // Validate input
+        validate_new_experience(&exp, collective.embedding_dimension, is_external)?;
+        if self.config.content_storage == ContentStorage::External && exp.embedding.is_none() {
+            return Err(ValidationError::invalid_field(
+                "embedding",
+                "required with external content storage; content is only a handle",
+            )
+            .into());
+        }
+        if self.config.content_storage != ContentStorage::External {
+            if let Some(policy) = self.storage.get_content_policy(exp.collective_id)? {
+                policy.check(&exp.experience_type, &exp.content)?;
+            }
+        }
+
+        // Agents the collective's policy names wait for review
+        let pending = self
+            .storage
+            .get_moderation_policy(exp.collective_id)?
+            .is_some_and(|policy| policy.requires_review(&exp.source_agent));
+
+        // Resolve embedding
+        let embedding = match exp.embedding {
+            Some(emb) => emb,
+            None => {
+                // Builtin mode: generate embedding from content
+                self.embed_text(exp.collective_id, &exp.content)?
+            }
+        };
+
+        let collective_id = exp.collective_id;
+
+        // Caller-supplied ID wins over the configured strategy
+        let id = exp.id.unwrap_or_else(|| match self.config.id_strategy {
+            IdStrategy::TimeOrdered => ExperienceId::new(),
+            IdStrategy::ContentDerived => ExperienceId::derive(collective_id, &exp.content),
+        });
+
+        // Construct the full experience record
+        Ok((
+            Experience {
+                id,
+                collective_id,
+                content: exp.content,
+                embedding,
+                experience_type: exp.experience_type,
+                importance: exp.importance,
+                confidence: exp.confidence,
+                applications: 0,
+                domain: exp.domain,
+                related_files: exp.related_files,
+                source_agent: exp.source_agent,
+                source_task: exp.source_task,
+                user_id: exp.user_id,
+                timestamp: Timestamp::now(),
+                expires_at: exp.expires_at,
+                archived: pending,
+                attribution: exp.attribution,
+            },
+            pending,
+            fence,
+        ))
+    }
+
+    /// Bookkeeping right after a new experience is written: marks the
+    /// collective used, queues the experience for review if pending, and
+    /// runs an expiry sweep when one is due.
+    fn queue_recorded(&self, experience: &Experience, pending: bool) -> Result<()> {
+        self.touch_collective(experience.collective_id);
+        self.sweep_expired_if_due();
+        // Queued after the insert: a crash in between leaves the experience
+        // archived and out of the queue, never published
+        if pending {
+            self.storage.mark_experience_pending(
+                experience.collective_id,
+                experience.id,
+                experience.timestamp,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Announces a recorded experience once storage and the vector index
+    /// both have it: watch event, interests, and post-write hooks.
+    fn announce_recorded(&self, experience: Experience, pending: bool) -> Result<()> {
+        // Pending experiences are announced when approved
+        if !pending {
+            let event = WatchEvent {
+                experience_id: experience.id,
+                collective_id: experience.collective_id,
+                event_type: WatchEventType::Created,
+                timestamp: experience.timestamp,
+                experience: Some(experience.clone()),
+            };
+            self.notify_interests(&event, &experience)?;
+            self.watch.emit(event, &experience)?;
+            self.suggest_relations(&experience);
+        }
+        self.run_post_write_hooks(CommittedWrite::Experience(&experience));
+
+        info!(
+            id = %experience.id,
+            pending,
+            content = %self.loggable(&experience.content),
+            "Experience recorded"
+        );
+        Ok(())
+    }
+
+    /// Retrieves an experience by ID, including its embedding.
+    ///
+    /// Returns `None` if no experience with the given ID exists.
+    ///
+    /// With [`ContentStorage::External`], `content` is resolved through the
+    /// registered [`ContentResolver`]; without one it holds the stored
+    /// handle.
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by the content resolver
+    #[instrument(skip(self))]
+    pub fn get_experience(&self, id: ExperienceId) -> Result<Option<Experience>> {
+        let Some(mut experience) = self.storage.get_experience(id)? else {
+            return Ok(None);
+        };
+        if self.config.content_storage == ContentStorage::External {
+            let resolver = self
+                .content_resolver
+                .read()
+                .map_err(|_| PulseDBError::internal("Content resolver lock poisoned"))?
+                .clone();
+            if let Some(resolver) = resolver {
+                experience.content = resolver.resolve(&experience.content)?;
+            }
+        }
+        self.run_read_hooks_on_experience(&mut experience)?;
+        Ok(Some(experience))
+    }
+
+    /// Registers the resolver used to turn stored content handles into text.
+    ///
+    /// Only consulted when [`Config::content_storage`] is
+    /// [`ContentStorage::External`]. Replaces any previously registered
+    /// resolver.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// use std::sync::Arc;
+    /// use pulsedb::{Config, ContentResolver, ContentStorage, NewExperience, PulseDB};
+    ///
+    /// struct Upper;
+    /// impl ContentResolver for Upper {
+    ///     fn resolve(&self, handle: &str) -> pulsedb::Result<String> {
+    ///         Ok(handle.to_uppercase())
+    ///     }
+    /// }
+    ///
+    /// let config = Config {
+    ///     content_storage: ContentStorage::External,
+    ///     ..Default::default()
+    /// };
+    /// let db = PulseDB::open(dir.path().join("test.db"), config)?;
+    /// db.set_content_resolver(Arc::new(Upper));
+    ///
+    /// let collective_id = db.create_collective("private")?;
+    /// let id = db.record_experience(NewExperience {
+    ///     collective_id,
+    ///     content: "doc-42".to_string(),
+    ///     embedding: Some(vec![0.1; 384]),
+    ///     ..Default::default()
+    /// })?;
+    /// assert_eq!(db.get_experience(id)?.unwrap().content, "DOC-42");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_content_resolver(&self, resolver: Arc<dyn ContentResolver>) {
+        if let Ok(mut slot) = self.content_resolver.write() {
+            *slot = Some(resolver);
+        }
+    }
+
+    /// Updates mutable fields of an experience.
+    ///
+    /// Only fields set to `Some(...)` in the update are changed.
+    /// Content and embedding are immutable — create a new experience instead.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError`](crate::ValidationError) if updated values are invalid
+    /// - [`NotFoundError::Experience`] if the experience doesn't exist
+    #[instrument(skip(self, update))]
+    pub fn update_experience(&self, id: ExperienceId, update: ExperienceUpdate) -> Result<()> {
+        self.check_writable()?;
+        let _fence = self.check_experience_writable(id)?;
+        validate_experience_update(&update)?;
+        if update.archived == Some(false) {
+            self.check_not_pending(id)?;
+        }
+
+        let updated = self.storage.update_experience(id, &update)?;
+        if !updated {
+            return Err(PulseDBError::from(NotFoundError::experience(id)));
+        }
+
+        // Emit watch event (fetch experience for collective_id + filter matching)
+        if self.watch.has_subscribers() {
+            if let Ok(Some(exp)) = self.storage.get_experience(id) {
+                let event_type = if update.archived == Some(true) {
+                    WatchEventType::Archived
+                } else {
+                    WatchEventType::Updated
+                };
+                self.watch.emit(
+                    WatchEvent {
+                        experience_id: id,
+                        collective_id: exp.collective_id,
+                        event_type,
+                        timestamp: Timestamp::now(),
+                        experience: Some(exp.clone()),
+                    },
+                    &exp,
+                )?;
+            }
+        }
+
+        info!(id = %id, "Experience updated");
+        Ok(())
+    }
+
+    /// Archives an experience (soft-delete).
+    ///
+    /// Archived experiences remain in storage but are excluded from search
+    /// results. Use [`unarchive_experience`](Self::unarchive_experience) to restore.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Experience`] if the experience doesn't exist.
+    #[instrument(skip(self))]
+    pub fn archive_experience(&self, id: ExperienceId) -> Result<()> {
+        self.check_writable()?;
+        self.update_experience(
+            id,
+            ExperienceUpdate {
+                archived: Some(true),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Restores an archived experience.
+    ///
+    /// The experience will once again appear in search results.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Experience`] if the experience doesn't exist.
+    #[instrument(skip(self))]
+    pub fn unarchive_experience(&self, id: ExperienceId) -> Result<()> {
+        self.check_writable()?;
+        self.update_experience(
+            id,
+            ExperienceUpdate {
+                archived: Some(false),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Permanently deletes an experience and its embedding.
+    ///
+    /// This removes the experience from all tables and indices.
+    /// Unlike archiving, this is irreversible.
+    ///
+    /// Insights citing the experience as a source are handled according
+    /// to [`Config::insight_source_cascade`](crate::Config::insight_source_cascade).
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Experience`] if the experience doesn't exist
+    /// - [`ValidationError::InvalidField`] if the cascade policy is
+    ///   [`Block`](crate::InsightSourceCascade::Block) and insights still
+    ///   cite the experience
+    #[instrument(skip(self))]
+    pub fn delete_experience(&self, id: ExperienceId) -> Result<()> {
+        self.check_writable()?;
+        // Read experience first to get collective_id for HNSW lookup.
+        // This adds one extra read, but delete is not a hot path.
+        let experience = self
+            .storage
+            .get_experience(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(id)))?;
+        let _fence = self.check_collective_writable(experience.collective_id)?;
+
+        // Refuse before the relations go; the delete itself checks again
+        let cascade = self.config.insight_source_cascade;
+        if cascade == InsightSourceCascade::Block {
+            let citing = self.storage.list_insight_ids_citing(id)?;
+            if !citing.is_empty() {
+                return Err(cited_by_insights(id, citing.len()));
+            }
+        }
+
+        // Cascade-delete any relations involving this experience.
+        // Done before experience deletion so we can still look up relation data.
+        let rel_count = self.storage.delete_relations_for_experience(id)?;
+        if rel_count > 0 {
+            info!(
+                count = rel_count,
+                "Cascade-deleted relations for experience"
+            );
+        }
+
+        // Delete from redb FIRST (source of truth), applying the insight
+        // cascade in the same transaction. If crash happens after this but
+        // before HNSW soft-delete, on reopen the experience won't be loaded
+        // from redb, so it's automatically excluded from the rebuilt index.
+        let (citing, deleted_insights) = match self
+            .storage
+            .delete_experience_cascading(id, cascade)?
+        {
+            ExperienceDeletion::Deleted {
+                citing,
+                deleted_insights,
+                ..
+            } => (citing, deleted_insights),
+            ExperienceDeletion::Blocked(citing) => return Err(cited_by_insights(id, citing.len())),
+            ExperienceDeletion::NotFound => {
+                return Err(PulseDBError::from(NotFoundError::experience(id)))
+            }
+        };
+
+        // Soft-delete from HNSW index (mark as deleted, not removed from graph).
+        // This takes effect immediately for the current session's searches.
+        let vectors = self
+            .vectors
             .read()
-            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
-        if let Some(index) = insight_vectors.get(&insight.collective_id) {
-            index.insert_experience(exp_id, &embedding_for_hnsw)?;
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+        if let Some(index) = vectors.get(&experience.collective_id) {
+            index.delete_experience(id)?;
+        }
+        drop(vectors);
+
+        // Insights that lost their last source are gone from redb
+        if !deleted_insights.is_empty() {
+            let insight_vectors = self
+                .insight_vectors
+                .read()
+                .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+            if let Some(index) = insight_vectors.get(&experience.collective_id) {
+                for insight_id in &deleted_insights {
+                    index.delete_experience(ExperienceId::from_bytes(*insight_id.as_bytes()))?;
+                }
+            }
         }
+        if !citing.is_empty() {
+            info!(
+                count = citing.len(),
+                deleted = deleted_insights.len(),
+                policy = ?cascade,
+                "Applied insight source cascade"
+            );
+        }
+
+        // Emit watch event after storage + HNSW deletion
+        self.watch.emit(
+            WatchEvent {
+                experience_id: id,
+                collective_id: experience.collective_id,
+                event_type: WatchEventType::Deleted,
+                timestamp: Timestamp::now(),
+                experience: None, // Deleted — no data to include
+            },
+            &experience,
+        )?;
+
+        info!(id = %id, "Experience deleted");
+        Ok(())
+    }
+
+    /// Deletes every experience whose `expires_at` has passed.
+    ///
+    /// Each expired experience is removed with
+    /// [`delete_experience()`](Self::delete_experience), so relations,
+    /// citing insights, the vector index, and watchers are handled the
+    /// same way. Experiences that can't be deleted — for example because
+    /// the collective is frozen or the cascade policy blocks it — are
+    /// logged and left for a later sweep.
+    ///
+    /// Runs automatically when [`Config::expiry_sweep`] is set, inline on
+    /// the first experience write after each interval.
+    ///
+    /// Returns the number of experiences deleted.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] if the database is read-only
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use pulsedb::{NewExperience, Timestamp};
+    ///
+    /// let in_one_hour = Timestamp::from_millis(Timestamp::now().as_millis() + 3_600_000);
+    /// db.record_experience(NewExperience {
+    ///     collective_id,
+    ///     content: "Scratch note for this session".into(),
+    ///     embedding: Some(vec![0.1; 384]),
+    ///     expires_at: Some(in_one_hour),
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// // Nothing has expired yet
+    /// assert_eq!(db.purge_expired()?, 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn purge_expired(&self) -> Result<usize> {
+        self.check_writable()?;
+        let now = Timestamp::now();
+
+        let mut purged = 0;
+        for collective in self.storage.list_collectives()? {
+            for id in self
+                .storage
+                .list_expired_experience_ids(collective.id, now)?
+            {
+                match self.delete_experience(id) {
+                    Ok(()) => purged += 1,
+                    Err(e) => warn!(
+                        id = %id,
+                        collective = %collective.id,
+                        error = %e,
+                        "Expired experience not purged"
+                    ),
+                }
+            }
+        }
+
+        if purged > 0 {
+            info!(count = purged, "Purged expired experiences");
+        }
+        Ok(purged)
+    }
+
+    /// Runs [`purge_expired()`](Self::purge_expired) if
+    /// [`Config::expiry_sweep`] is set and its interval has elapsed.
+    ///
+    /// Called on the write path after the write has committed, so a due
+    /// sweep delays that write's return but never its commit.
+    fn sweep_expired_if_due(&self) {
+        let Some(interval) = self.config.expiry_sweep else {
+            return;
+        };
+        let now = Instant::now();
+        let sweep_due = match self.last_expiry_sweep.lock() {
+            Ok(mut last_sweep) if now.duration_since(*last_sweep) >= interval => {
+                *last_sweep = now;
+                true
+            }
+            _ => false,
+        };
+        if sweep_due {
+            if let Err(e) = self.purge_expired() {
+                warn!(error = %e, "Automatic expiry sweep failed");
+            }
+        }
+    }
+
+    /// Reinforces an experience by incrementing its application count.
+    ///
+    /// Each call atomically increments the `applications` counter by 1.
+    /// Returns the new application count.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Experience`] if the experience doesn't exist.
+    #[instrument(skip(self))]
+    pub fn reinforce_experience(&self, id: ExperienceId) -> Result<u32> {
+        self.check_writable()?;
+        let _fence = self.check_experience_writable(id)?;
+        let new_count = self
+            .storage
+            .reinforce_experience(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(id)))?;
+
+        // Emit watch event (fetch experience for collective_id + filter matching)
+        if self.watch.has_subscribers() {
+            if let Ok(Some(exp)) = self.storage.get_experience(id) {
+                self.watch.emit(
+                    WatchEvent {
+                        experience_id: id,
+                        collective_id: exp.collective_id,
+                        event_type: WatchEventType::Updated,
+                        timestamp: Timestamp::now(),
+                        experience: Some(exp.clone()),
+                    },
+                    &exp,
+                )?;
+            }
+        }
+
+        info!(id = %id, applications = new_count, "Experience reinforced");
+        Ok(new_count)
+    }
+
+    // =========================================================================
+    // Content Policy
+    // =========================================================================
+
+    /// Sets the content rules a collective's new experiences must follow.
+    ///
+    /// The policy is persisted and checked by
+    /// [`record_experience()`](Self::record_experience) from now on;
+    /// experiences already stored are not re-checked. With
+    /// [`ContentStorage::External`] content is an opaque handle and the
+    /// policy is not applied.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use pulsedb::{ContentPolicy, ContentRequirement, ExperienceType, NewExperience};
+    ///
+    /// let error_pattern = ExperienceType::ErrorPattern {
+    ///     signature: "E0382".into(),
+    ///     fix: "clone before the move".into(),
+    ///     prevention: "borrow instead".into(),
+    /// };
+    /// db.set_content_policy(
+    ///     collective_id,
+    ///     ContentPolicy::new().require(
+    ///         [error_pattern.clone()],
+    ///         ContentRequirement::IncludesField("signature".into()),
+    ///     ),
+    /// )?;
+    /// let err = db
+    ///     .record_experience(NewExperience {
+    ///         collective_id,
+    ///         content: "use after move".into(),
+    ///         experience_type: error_pattern,
+    ///         embedding: Some(vec![0.1; 384]),
+    ///         ..Default::default()
+    ///     })
+    ///     .unwrap_err();
+    /// assert!(err.is_validation());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, policy))]
+    pub fn set_content_policy(
+        &self,
+        collective_id: CollectiveId,
+        policy: ContentPolicy,
+    ) -> Result<()> {
+        self.check_writable()?;
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        let _fence = self.check_collective_writable(collective_id)?;
+
+        self.storage.save_content_policy(collective_id, &policy)?;
+        info!(collective_id = %collective_id, rules = policy.rules.len(), "Content policy set");
+        Ok(())
+    }
+
+    /// Returns the collective's content policy.
+    ///
+    /// Collectives that never had one set have a policy with no rules.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    pub fn get_content_policy(&self, collective_id: CollectiveId) -> Result<ContentPolicy> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        Ok(self
+            .storage
+            .get_content_policy(collective_id)?
+            .unwrap_or_default())
+    }
+
+    // =========================================================================
+    // Text Normalization
+    // =========================================================================
+
+    /// Sets the text normalization for one collective, overriding
+    /// [`Config::text_normalization`]. `None` reverts to the config.
+    ///
+    /// Normalization decides what text is embedded, so changing it under
+    /// stored records would leave their embeddings incomparable with new
+    /// queries. The effective settings can therefore only change while the
+    /// collective holds no experiences or insights; setting the same
+    /// effective settings again is always allowed.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    /// - [`ValidationError::InvalidField`] if the effective settings would
+    ///   change while the collective holds records
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// use pulsedb::TextNormalization;
+    ///
+    /// let code = db.create_collective("code-notes")?;
+    /// db.set_text_normalization(code, Some(TextNormalization::standard()))?;
+    /// assert_eq!(db.get_text_normalization(code)?, TextNormalization::standard());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, normalization))]
+    pub fn set_text_normalization(
+        &self,
+        collective_id: CollectiveId,
+        normalization: Option<TextNormalization>,
+    ) -> Result<()> {
+        self.check_writable()?;
+        let current = self.get_text_normalization(collective_id)?;
+        let _fence = self.check_collective_writable(collective_id)?;
+
+        let effective = normalization
+            .as_ref()
+            .unwrap_or(&self.config.text_normalization);
+        if *effective != current
+            && (self
+                .storage
+                .count_experiences_in_collective(collective_id)?
+                > 0
+                || !self
+                    .storage
+                    .list_insight_ids_in_collective(collective_id)?
+                    .is_empty())
+        {
+            return Err(ValidationError::invalid_field(
+                "text_normalization",
+                "cannot change while the collective holds embedded records",
+            )
+            .into());
+        }
+
+        self.storage
+            .save_text_normalization(collective_id, normalization.as_ref())?;
+        info!(
+            collective_id = %collective_id,
+            overridden = normalization.is_some(),
+            "Text normalization set"
+        );
+        Ok(())
+    }
+
+    /// Returns the text normalization in effect for a collective: its own
+    /// settings if set, otherwise [`Config::text_normalization`].
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    pub fn get_text_normalization(&self, collective_id: CollectiveId) -> Result<TextNormalization> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        Ok(self
+            .storage
+            .get_text_normalization(collective_id)?
+            .unwrap_or_else(|| self.config.text_normalization.clone()))
+    }
+
+    // =========================================================================
+    // Moderation
+    // =========================================================================
+
+    /// Sets which agents' experiences the collective holds for review.
+    ///
+    /// The policy is persisted and applies to experiences recorded from
+    /// now on; experiences already stored or queued are left as they are.
+    /// See the [`ModerationPolicy`] variants.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use pulsedb::{AgentId, ModerationPolicy, NewExperience};
+    ///
+    /// db.set_moderation_policy(
+    ///     collective_id,
+    ///     ModerationPolicy::ReviewAgents(vec![AgentId::new("intern")]),
+    /// )?;
+    /// let id = db.record_experience(NewExperience {
+    ///     collective_id,
+    ///     content: "rm -rf fixes flaky builds".into(),
+    ///     embedding: Some(vec![0.1; 384]),
+    ///     source_agent: AgentId::new("intern"),
+    ///     ..Default::default()
+    /// })?;
+    /// assert!(db.is_experience_pending(id)?);
+    /// db.reject_experience(id)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn set_moderation_policy(
+        &self,
+        collective_id: CollectiveId,
+        policy: ModerationPolicy,
+    ) -> Result<()> {
+        self.check_writable()?;
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        let _fence = self.check_collective_writable(collective_id)?;
+
+        self.storage
+            .save_moderation_policy(collective_id, &policy)?;
+        info!(collective_id = %collective_id, policy = ?policy, "Moderation policy set");
+        Ok(())
+    }
+
+    /// Returns the collective's moderation policy.
+    ///
+    /// Collectives that never had one set are [`ModerationPolicy::Open`].
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    pub fn get_moderation_policy(&self, collective_id: CollectiveId) -> Result<ModerationPolicy> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        Ok(self
+            .storage
+            .get_moderation_policy(collective_id)?
+            .unwrap_or_default())
+    }
+
+    /// Returns `true` if the experience is waiting for review.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Experience`] if the experience doesn't exist
+    pub fn is_experience_pending(&self, id: ExperienceId) -> Result<bool> {
+        let experience = self
+            .storage
+            .get_experience(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(id)))?;
+        self.storage
+            .is_experience_pending(experience.collective_id, id)
+    }
+
+    /// Lists the experiences waiting for review in a collective, oldest
+    /// queued first.
+    ///
+    /// Experiences are read as [`get_experience()`](Self::get_experience)
+    /// returns them; while pending they carry `archived = true`.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    #[instrument(skip(self))]
+    pub fn list_pending_experiences(&self, collective_id: CollectiveId) -> Result<Vec<Experience>> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let mut queued = self.storage.list_pending_experience_ids(collective_id)?;
+        queued.sort_by_key(|&(_, at)| at);
+        let mut experiences = Vec::with_capacity(queued.len());
+        for (id, _) in queued {
+            if let Some(experience) = self.get_experience(id)? {
+                experiences.push(experience);
+            }
+        }
+        Ok(experiences)
+    }
+
+    /// Publishes a pending experience.
+    ///
+    /// The experience leaves the review queue, is unarchived so search
+    /// returns it, and is announced to watchers as
+    /// [`Created`](WatchEventType::Created).
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Experience`] if the experience doesn't exist
+    /// - [`ValidationError::InvalidField`] if the experience isn't pending
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    #[instrument(skip(self))]
+    pub fn approve_experience(&self, id: ExperienceId) -> Result<()> {
+        let experience = self.pending_experience(id)?;
+        let collective_id = experience.collective_id;
+
+        self.storage.update_experience(
+            id,
+            &ExperienceUpdate {
+                archived: Some(false),
+                ..Default::default()
+            },
+        )?;
+        self.storage.clear_experience_pending(collective_id, id)?;
+        self.suggest_relations(&experience);
+
+        if self.watch.has_subscribers() || self.watch.has_agent_subscribers() {
+            if let Some(exp) = self.storage.get_experience(id)? {
+                let event = WatchEvent {
+                    experience_id: id,
+                    collective_id,
+                    event_type: WatchEventType::Created,
+                    timestamp: Timestamp::now(),
+                    experience: Some(exp.clone()),
+                };
+                self.notify_interests(&event, &exp)?;
+                self.watch.emit(event, &exp)?;
+            }
+        }
+
+        info!(id = %id, "Experience approved");
+        Ok(())
+    }
+
+    /// Rejects a pending experience, deleting it.
+    ///
+    /// Behaves like [`delete_experience()`](Self::delete_experience) once
+    /// the experience is confirmed pending.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Experience`] if the experience doesn't exist
+    /// - [`ValidationError::InvalidField`] if the experience isn't pending
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    #[instrument(skip(self))]
+    pub fn reject_experience(&self, id: ExperienceId) -> Result<()> {
+        self.pending_experience(id)?;
+        self.delete_experience(id)?;
+        info!(id = %id, "Experience rejected");
+        Ok(())
+    }
+
+    /// Rejects publishing a pending experience other than by approval.
+    fn check_not_pending(&self, id: ExperienceId) -> Result<()> {
+        if let Some(experience) = self.storage.get_experience(id)? {
+            if self
+                .storage
+                .is_experience_pending(experience.collective_id, id)?
+            {
+                return Err(ValidationError::invalid_field(
+                    "archived",
+                    format!("experience {} is pending review; approve it instead", id),
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads an experience for a review decision, checking it is pending
+    /// and its collective accepts writes.
+    fn pending_experience(&self, id: ExperienceId) -> Result<Experience> {
+        self.check_writable()?;
+        let experience = self
+            .storage
+            .get_experience(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(id)))?;
+        let _fence = self.check_collective_writable(experience.collective_id)?;
+        if !self
+            .storage
+            .is_experience_pending(experience.collective_id, id)?
+        {
+            return Err(ValidationError::invalid_field(
+                "id",
+                format!("experience {} is not pending review", id),
+            )
+            .into());
+        }
+        Ok(experience)
+    }
+
+    // =========================================================================
+    // Recent Experiences
+    // =========================================================================
+
+    // =========================================================================
+    // Paginated List Operations (PulseVision)
+    // =========================================================================
+
+    /// Lists experiences in a collective with pagination.
+    ///
+    /// Returns full `Experience` records (including embeddings) ordered by
+    /// timestamp. Use `offset` and `limit` for pagination. Offsets shift
+    /// when experiences are recorded or deleted between pages; use
+    /// [`list_experiences_page()`](Self::list_experiences_page) for a
+    /// traversal that stays stable under concurrent writes.
+    ///
+    /// Designed for visualization tools (PulseVision) that need to enumerate
+    /// the entire embedding space of a collective.
+    #[instrument(skip(self))]
+    pub fn list_experiences(
+        &self,
+        collective_id: CollectiveId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Experience>> {
+        let ids = self
+            .storage
+            .list_experience_ids_paginated(collective_id, limit, offset)?;
+        let mut experiences = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(mut exp) = self.storage.get_experience(id)? {
+                self.run_read_hooks_on_experience(&mut exp)?;
+                experiences.push(exp);
+            }
+        }
+        Ok(experiences)
+    }
+
+    /// Lists relations in a collective with pagination.
+    ///
+    /// Offsets shift under concurrent writes; see
+    /// [`list_relations_page()`](Self::list_relations_page).
+    #[instrument(skip(self))]
+    pub fn list_relations(
+        &self,
+        collective_id: CollectiveId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<crate::relation::ExperienceRelation>> {
+        self.storage
+            .list_relations_in_collective(collective_id, limit, offset)
+    }
+
+    /// Lists relations in a collective matching a [`RelationFilter`], one
+    /// [`Page`] at a time.
+    ///
+    /// Enumerates edges through the relations-by-collective index, so graph
+    /// views don't need to walk every experience. Relations belong to their
+    /// source experience's collective. `page.offset` counts matching
+    /// relations in the requested sort order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let cid = db.create_collective("example")?;
+    /// use pulsedb::{Page, RelationFilter, RelationSort};
+    ///
+    /// let newest = db.list_relations_filtered(
+    ///     cid,
+    ///     RelationFilter { sort: RelationSort::NewestFirst, ..Default::default() },
+    ///     Page::new(0, 50),
+    /// )?;
+    /// # assert!(newest.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, filter))]
+    pub fn list_relations_filtered(
+        &self,
+        collective_id: CollectiveId,
+        filter: RelationFilter,
+        page: Page,
+    ) -> Result<Vec<ExperienceRelation>> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let mut ids = self
+            .storage
+            .list_relation_ids_in_collective(collective_id)?;
+        if filter.sort == RelationSort::NewestFirst {
+            ids.reverse();
+        }
+
+        // Time-ordered sorts follow index order and can stop once the
+        // page is filled; strength ordering needs every match first.
+        let wanted = match filter.sort {
+            RelationSort::StrongestFirst => usize::MAX,
+            _ => page.offset.saturating_add(page.limit),
+        };
+
+        let mut relations = Vec::new();
+        for id in ids {
+            if relations.len() >= wanted {
+                break;
+            }
+            if let Some(relation) = self.storage.get_relation(id)? {
+                if filter.matches(&relation) {
+                    relations.push(relation);
+                }
+            }
+        }
+
+        if filter.sort == RelationSort::StrongestFirst {
+            // Stable sort keeps creation order among equal strengths
+            relations.sort_by(|a, b| b.strength.total_cmp(&a.strength));
+        }
+
+        Ok(relations
+            .into_iter()
+            .skip(page.offset)
+            .take(page.limit)
+            .collect())
+    }
+
+    /// Lists insights in a collective with pagination.
+    ///
+    /// Returns full `DerivedInsight` records including embeddings. Offsets
+    /// shift under concurrent writes; see
+    /// [`list_insights_page()`](Self::list_insights_page).
+    #[instrument(skip(self))]
+    pub fn list_insights(
+        &self,
+        collective_id: CollectiveId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<DerivedInsight>> {
+        let ids = self
+            .storage
+            .list_insight_ids_paginated(collective_id, limit, offset)?;
+        let mut insights = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(mut insight) = self.storage.get_insight(id)? {
+                self.run_read_hooks_on_insight(&mut insight)?;
+                insights.push(insight);
+            }
+        }
+        Ok(insights)
+    }
+
+    /// Lists experiences in a collective one [`Cursor`] page at a time.
+    ///
+    /// Unlike [`list_experiences()`](Self::list_experiences), the page
+    /// boundary is the last experience returned rather than a count, so
+    /// experiences recorded or deleted between calls never cause one to be
+    /// skipped or repeated. Pass [`Cursor::first()`] to start and each
+    /// page's [`next`](CursorPage::next) to continue; it is `None` once the
+    /// end is reached. Experiences are ordered by timestamp, then ID.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `limit` is 0
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let cid = db.create_collective("example")?;
+    /// use pulsedb::{Cursor, PageDirection};
+    ///
+    /// let mut cursor = Cursor::first(PageDirection::NewestFirst);
+    /// loop {
+    ///     let page = db.list_experiences_page(cid, &cursor, 100)?;
+    ///     for experience in &page.items {
+    ///         println!("{}", experience.content);
+    ///     }
+    ///     match page.next {
+    ///         Some(next) => cursor = next,
+    ///         None => break,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn list_experiences_page(
+        &self,
+        collective_id: CollectiveId,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<CursorPage<Experience>> {
+        self.check_page_request(collective_id, limit)?;
+        let mut keys = self
+            .storage
+            .list_experience_keys_after(collective_id, cursor, limit + 1)?;
+        let more = keys.len() > limit;
+        keys.truncate(limit);
+
+        let mut items = Vec::with_capacity(keys.len());
+        for &(_, id) in &keys {
+            if let Some(experience) = self.get_experience(id)? {
+                items.push(experience);
+            }
+        }
+        let next = keys
+            .last()
+            .filter(|_| more)
+            .map(|(timestamp, id)| Cursor::after(cursor.direction(), *timestamp, *id.as_bytes()));
+        Ok(CursorPage { items, next })
+    }
+
+    /// Lists relations in a collective one [`Cursor`] page at a time.
+    ///
+    /// The cursor counterpart of [`list_relations()`](Self::list_relations),
+    /// stable under concurrent writes in the same way as
+    /// [`list_experiences_page()`](Self::list_experiences_page). Relations
+    /// are ordered by creation time, then ID.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `limit` is 0
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    #[instrument(skip(self))]
+    pub fn list_relations_page(
+        &self,
+        collective_id: CollectiveId,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<CursorPage<ExperienceRelation>> {
+        self.check_page_request(collective_id, limit)?;
+        let mut keys = self
+            .storage
+            .list_relation_keys_after(collective_id, cursor, limit + 1)?;
+        let more = keys.len() > limit;
+        keys.truncate(limit);
+
+        let mut items = Vec::with_capacity(keys.len());
+        for &(_, id) in &keys {
+            if let Some(relation) = self.storage.get_relation(id)? {
+                items.push(relation);
+            }
+        }
+        let next = keys
+            .last()
+            .filter(|_| more)
+            .map(|(timestamp, id)| Cursor::after(cursor.direction(), *timestamp, *id.as_bytes()));
+        Ok(CursorPage { items, next })
+    }
+
+    /// Lists insights in a collective one [`Cursor`] page at a time.
+    ///
+    /// The cursor counterpart of [`list_insights()`](Self::list_insights),
+    /// stable under concurrent writes in the same way as
+    /// [`list_experiences_page()`](Self::list_experiences_page). Insights
+    /// are ordered by ID, which is time-ordered.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `limit` is 0
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    #[instrument(skip(self))]
+    pub fn list_insights_page(
+        &self,
+        collective_id: CollectiveId,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<CursorPage<DerivedInsight>> {
+        self.check_page_request(collective_id, limit)?;
+        let mut ids = self
+            .storage
+            .list_insight_ids_after(collective_id, cursor, limit + 1)?;
+        let more = ids.len() > limit;
+        ids.truncate(limit);
+
+        let mut items = Vec::with_capacity(ids.len());
+        let mut last_created_at = Timestamp::from_millis(0);
+        for &id in &ids {
+            if let Some(insight) = self.get_insight(id)? {
+                last_created_at = insight.created_at;
+                items.push(insight);
+            }
+        }
+        // The insight index orders by ID alone; the timestamp is carried
+        // for a uniform wire format but not compared
+        let next = ids
+            .last()
+            .filter(|_| more)
+            .map(|id| Cursor::after(cursor.direction(), last_created_at, *id.as_bytes()));
+        Ok(CursorPage { items, next })
+    }
+
+    /// Checks the shared arguments of the cursor list APIs.
+    fn check_page_request(&self, collective_id: CollectiveId, limit: usize) -> Result<()> {
+        if limit == 0 {
+            return Err(ValidationError::invalid_field("limit", "must be at least 1").into());
+        }
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        Ok(())
+    }
+
+    /// Lists insights in a collective matching an [`InsightFilter`].
+    ///
+    /// Browses insights by type, confidence, and age without a query
+    /// vector. When `filter.types` is set, candidates come from the
+    /// insights-by-type index instead of a full collective scan.
+    ///
+    /// Results are ordered newest first and truncated to `filter.limit`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let cid = db.create_collective("example")?;
+    /// use pulsedb::{InsightFilter, InsightType};
+    ///
+    /// let strong_patterns = db.list_insights_filtered(cid, InsightFilter {
+    ///     types: Some(vec![InsightType::Pattern]),
+    ///     min_confidence: Some(0.8),
+    ///     ..Default::default()
+    /// })?;
+    /// # assert!(strong_patterns.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, filter))]
+    pub fn list_insights_filtered(
+        &self,
+        collective_id: CollectiveId,
+        filter: InsightFilter,
+    ) -> Result<Vec<DerivedInsight>> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let ids = match filter.types {
+            Some(ref types) => {
+                let mut ids = Vec::new();
+                for insight_type in InsightType::all() {
+                    if types.contains(insight_type) {
+                        ids.extend(
+                            self.storage
+                                .list_insight_ids_by_type(collective_id, *insight_type)?,
+                        );
+                    }
+                }
+                ids
+            }
+            None => self.storage.list_insight_ids_in_collective(collective_id)?,
+        };
+
+        let mut insights = Vec::new();
+        for id in ids {
+            if let Some(insight) = self.storage.get_insight(id)? {
+                if filter.matches(&insight) {
+                    insights.push(insight);
+                }
+            }
+        }
+
+        insights.sort_by_key(|i| std::cmp::Reverse(i.created_at));
+        if let Some(limit) = filter.limit {
+            insights.truncate(limit);
+        }
+        for insight in &mut insights {
+            self.run_read_hooks_on_insight(insight)?;
+        }
+        Ok(insights)
+    }
+
+    /// Retrieves the most recent experiences in a collective.
+    ///
+    /// Returns full experiences ordered by timestamp (newest first).
+    #[instrument(skip(self))]
+    pub fn get_recent_experiences(
+        &self,
+        collective_id: CollectiveId,
+        limit: usize,
+    ) -> Result<Vec<Experience>> {
+        self.get_recent_experiences_filtered(collective_id, limit, SearchFilter::default())
+    }
+
+    /// Retrieves the most recent experiences in a collective with filtering.
+    ///
+    /// Like [`get_recent_experiences()`](Self::get_recent_experiences), but
+    /// applies additional filters on domain, experience type, importance,
+    /// confidence, and timestamp.
+    ///
+    /// Over-fetches from storage (2x `limit`) to account for entries removed
+    /// by post-filtering, then truncates to the requested `limit`.
+    ///
+    /// With [`SearchFilter::include_descendants`], sub-collectives are
+    /// included and the results merged newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `collective_id` - The collective to query
+    /// * `limit` - Maximum number of experiences to return (1-1000)
+    /// * `filter` - Filter criteria to apply
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `limit` is 0 or > 1000
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    #[instrument(skip(self, filter))]
+    pub fn get_recent_experiences_filtered(
+        &self,
+        collective_id: CollectiveId,
+        limit: usize,
+        filter: SearchFilter,
+    ) -> Result<Vec<Experience>> {
+        // Validate limit
+        if limit == 0 || limit > 1000 {
+            return Err(
+                ValidationError::invalid_field("limit", "must be between 1 and 1000").into(),
+            );
+        }
+
+        // Verify collective exists
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        // Over-fetch IDs to account for post-filtering losses
+        let over_fetch = limit.saturating_mul(2).min(2000);
+        let scope = self.search_scope(collective_id, &filter)?;
+        let mut recent_ids = Vec::new();
+        for cid in &scope {
+            recent_ids.extend(self.storage.get_experience_ids_in_range(
+                *cid,
+                filter.since,
+                filter.until,
+                over_fetch,
+            )?);
+        }
+        if scope.len() > 1 {
+            recent_ids.sort_by_key(|entry| std::cmp::Reverse(entry.1));
+        }
+        let recent_ids = self.prescreen(recent_ids, &filter)?;
+
+        // Load full experiences and apply filter
+        let mut results = Vec::with_capacity(limit);
+        for (exp_id, _timestamp) in recent_ids {
+            if results.len() >= limit {
+                break;
+            }
+
+            if let Some(mut experience) = self.storage.get_experience(exp_id)? {
+                if filter.matches(&experience) {
+                    self.run_read_hooks_on_experience(&mut experience)?;
+                    results.push(experience);
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    // =========================================================================
+    // Similarity Search (E2-S02)
+    // =========================================================================
+
+    /// Embeds query text the way the collective's records are embedded.
+    ///
+    /// Applies the collective's [`TextNormalization`] (see
+    /// [`get_text_normalization()`](Self::get_text_normalization)) and then
+    /// the embedding provider, so queries see exactly the preprocessing
+    /// stored records did. Pass the result to
+    /// [`search_similar()`](Self::search_similar) or any other search.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Embedding`] with the external provider, which
+    ///   cannot generate embeddings, or if generation fails
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// use pulsedb::{Config, EmbeddingDimension, PulseDB};
+    ///
+    /// let config = Config::with_hashing_embeddings(EmbeddingDimension::D384);
+    /// let db = PulseDB::open(dir.path().join("test.db"), config)?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// let query = db.embed_query(collective_id, "why did the migration fail?")?;
+    /// let results = db.search_similar(collective_id, &query, 10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, text))]
+    pub fn embed_query(&self, collective_id: CollectiveId, text: &str) -> Result<Embedding> {
+        let normalization = self.get_text_normalization(collective_id)?;
+        self.embedding.embed(&normalization.apply(text))
+    }
+
+    /// Whether records must carry their own embedding: the provider is
+    /// [`EmbeddingProvider::External`] and no service was injected.
+    fn requires_embeddings(&self) -> bool {
+        !self.injected_embedding
+            && matches!(self.config.embedding_provider, EmbeddingProvider::External)
+    }
+
+    /// Normalizes text with the collective's settings and embeds it.
+    fn embed_text(&self, collective_id: CollectiveId, text: &str) -> Result<Embedding> {
+        match self.storage.get_text_normalization(collective_id)? {
+            Some(normalization) => self.embedding.embed(&normalization.apply(text)),
+            None => self
+                .embedding
+                .embed(&self.config.text_normalization.apply(text)),
+        }
+    }
+
+    /// Searches for experiences semantically similar to the query embedding.
+    ///
+    /// Uses the HNSW vector index for approximate nearest neighbor search,
+    /// then fetches full experience records from storage. Archived experiences
+    /// are excluded by default.
+    ///
+    /// Results are sorted by similarity descending (most similar first).
+    /// Similarity is computed as `1.0 - cosine_distance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `collective_id` - The collective to search within
+    /// * `query` - Query embedding vector (must match collective's dimension)
+    /// * `k` - Maximum number of results to return (1-1000)
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `k` is 0 or > 1000
+    /// - [`ValidationError::DimensionMismatch`] if `query.len()` doesn't match
+    ///   the collective's embedding dimension
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// let query = vec![0.1f32; 384]; // Your query embedding
+    /// let results = db.search_similar(collective_id, &query, 10)?;
+    /// for result in &results {
+    ///     println!(
+    ///         "[{:.3}] {}",
+    ///         result.similarity, result.experience.content
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, query))]
+    pub fn search_similar(
+        &self,
+        collective_id: CollectiveId,
+        query: &[f32],
+        k: usize,
+    ) -> Result<Vec<SearchResult>> {
+        self.search_similar_filtered(collective_id, query, k, SearchFilter::default())
+    }
+
+    /// Searches for semantically similar experiences with additional filtering.
+    ///
+    /// Like [`search_similar()`](Self::search_similar), but applies additional
+    /// filters on domain, experience type, importance, confidence, and timestamp.
+    ///
+    /// Over-fetches from the HNSW index (2x `k`) to account for entries removed
+    /// by post-filtering, then truncates to the requested `k`.
+    ///
+    /// With [`SearchFilter::include_descendants`], every sub-collective's
+    /// index is searched as well and the hits are merged by distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `collective_id` - The collective to search within
+    /// * `query` - Query embedding vector (must match collective's dimension)
+    /// * `k` - Maximum number of results to return (1-1000)
+    /// * `filter` - Filter criteria to apply after vector search
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `k` is 0 or > 1000
+    /// - [`ValidationError::DimensionMismatch`] if `query.len()` doesn't match
+    ///   the collective's embedding dimension
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// # let query_embedding = vec![0.1f32; 384];
+    /// use pulsedb::SearchFilter;
+    ///
+    /// let filter = SearchFilter {
+    ///     domains: Some(vec!["rust".to_string()]),
+    ///     min_importance: Some(0.5),
+    ///     ..SearchFilter::default()
+    /// };
+    /// let results = db.search_similar_filtered(
+    ///     collective_id,
+    ///     &query_embedding,
+    ///     10,
+    ///     filter,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, query, filter))]
+    pub fn search_similar_filtered(
+        &self,
+        collective_id: CollectiveId,
+        query: &[f32],
+        k: usize,
+        filter: SearchFilter,
+    ) -> Result<Vec<SearchResult>> {
+        self.search_similar_explained(collective_id, query, k, filter, None, None)
+    }
+
+    /// Searches for experiences similar to query text.
+    ///
+    /// Embeds `text` with [`embed_query()`](Self::embed_query) and searches
+    /// as [`search_similar()`](Self::search_similar) does. Prefer this over
+    /// embedding queries yourself when
+    /// [`Config::knowledge_gaps`](crate::Config::knowledge_gaps) is set:
+    /// recorded gaps then carry the query text.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `k` is 0 or > 1000
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Embedding`] with the external provider, or if
+    ///   generation fails
+    #[instrument(skip(self, text))]
+    pub fn search_text(
+        &self,
+        collective_id: CollectiveId,
+        text: &str,
+        k: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let query = self.embed_query(collective_id, text)?;
+        self.search_similar_explained(
+            collective_id,
+            &query,
+            k,
+            SearchFilter::default(),
+            None,
+            Some(text),
+        )
+    }
+
+    /// Searches for similar experiences and reorders them with a
+    /// [`Reranker`].
+    ///
+    /// Fetches [`RERANK_OVER_FETCH`] × `k` candidates (at most 1000) as
+    /// [`search_similar()`](Self::search_similar) would, passes them to
+    /// `reranker` with `text`, and returns the first `k` of its output.
+    /// Results carry whatever `similarity` the reranker left on them.
+    ///
+    /// # Arguments
+    ///
+    /// * `collective_id` - The collective to search within
+    /// * `query` - Query embedding vector (must match collective's dimension)
+    /// * `text` - The query text the embedding came from, for the reranker
+    /// * `k` - Maximum number of results to return (1-1000)
+    /// * `reranker` - Reorders the candidates
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `k` is 0 or > 1000
+    /// - [`ValidationError::DimensionMismatch`] if `query.len()` doesn't match
+    ///   the collective's embedding dimension
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - Any error the reranker returns
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// # let query_embedding = vec![0.1f32; 384];
+    /// use pulsedb::SearchResult;
+    ///
+    /// // Stand-in for a cross-encoder: favor candidates mentioning the query
+    /// let reranker = |query: &str, mut candidates: Vec<SearchResult>| {
+    ///     candidates.sort_by_key(|r| !r.experience.content.contains(query));
+    ///     Ok(candidates)
+    /// };
+    /// let results = db.search_similar_reranked(
+    ///     collective_id,
+    ///     &query_embedding,
+    ///     "flaky test",
+    ///     10,
+    ///     &reranker,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, query, text, reranker))]
+    pub fn search_similar_reranked(
+        &self,
+        collective_id: CollectiveId,
+        query: &[f32],
+        text: &str,
+        k: usize,
+        reranker: &dyn Reranker,
+    ) -> Result<Vec<SearchResult>> {
+        if k == 0 || k > 1000 {
+            return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
+        }
+
+        let candidates = self.search_similar_explained(
+            collective_id,
+            query,
+            k.saturating_mul(RERANK_OVER_FETCH).min(1000),
+            SearchFilter::default(),
+            None,
+            Some(text),
+        )?;
+        let mut results = reranker.rerank(text, candidates)?;
+        results.truncate(k);
+        Ok(results)
+    }
+
+    /// Searches every collective an owner holds and merges the hits.
+    ///
+    /// For multi-tenant hosts: the collectives come from the owner index,
+    /// so callers never assemble the list themselves. Each collective is
+    /// searched as by [`search_similar_filtered()`](Self::search_similar_filtered)
+    /// with `filter`, in parallel, and the best `k` hits overall are
+    /// returned, most similar first. Collectives whose embedding dimension differs from
+    /// `query` are skipped.
+    ///
+    /// Every call re-checks isolation: each searched collective must carry
+    /// `owner_id`, and every hit must come from one of them. A violation
+    /// fails the call rather than return another tenant's data.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `k` is 0 or > 1000
+    /// - [`ValidationError::DimensionMismatch`] if the owner has collectives
+    ///   but none match `query.len()`
+    /// - [`PulseDBError::Internal`] if an isolation check fails
+    #[instrument(skip(self, query, filter))]
+    pub fn search_similar_scoped(
+        &self,
+        owner_id: &str,
+        query: &[f32],
+        k: usize,
+        filter: SearchFilter,
+    ) -> Result<Vec<SearchResult>> {
+        if k == 0 || k > 1000 {
+            return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
+        }
+
+        let collectives = self.list_collectives_by_owner(owner_id)?;
+        let mut scope = Vec::with_capacity(collectives.len());
+        let mut mismatched = None;
+        for collective in &collectives {
+            if collective.owner_id.as_deref() != Some(owner_id) {
+                return Err(PulseDBError::internal(format!(
+                    "tenant isolation violated: owner index lists collective {} for {}",
+                    collective.id, owner_id
+                )));
+            }
+            if collective.embedding_dimension as usize == query.len() {
+                scope.push(collective.id);
+            } else {
+                mismatched = Some(collective.embedding_dimension as usize);
+            }
+        }
+        if let (true, Some(expected)) = (scope.is_empty(), mismatched) {
+            return Err(ValidationError::dimension_mismatch(expected, query.len()).into());
+        }
+
+        // The owner index lists each collective once, in a stable order
+        let mut results = Vec::new();
+        for hits in claim_in_parallel(&scope, search_threads(), |&id| {
+            self.search_similar_filtered(id, query, k, filter.clone())
+        })? {
+            results.extend(hits?);
+        }
+        if let Some(leak) = results
+            .iter()
+            .find(|r| !scope.contains(&r.experience.collective_id))
+        {
+            return Err(PulseDBError::internal(format!(
+                "tenant isolation violated: experience {} from collective {} in results for {}",
+                leak.experience.id, leak.experience.collective_id, owner_id
+            )));
+        }
+
+        results.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        results.truncate(k);
+        Ok(results)
+    }
+
+    /// Searches several collectives at once and merges the hits.
+    ///
+    /// Each collective's index is searched as by
+    /// [`search_similar_filtered()`](Self::search_similar_filtered) with
+    /// `filter`, in parallel, and the best `k` hits overall are returned,
+    /// most similar first. Every result's
+    /// `experience.collective_id` says which collective it came from.
+    /// Duplicate IDs are searched once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// use pulsedb::SearchFilter;
+    ///
+    /// let api = db.create_collective("api-repo")?;
+    /// let web = db.create_collective("web-repo")?;
+    ///
+    /// let query = vec![0.1f32; 384];
+    /// let results = db.search_across_collectives(&[api, web], &query, 10, SearchFilter::default())?;
+    /// for result in &results {
+    ///     println!("{}: {}", result.experience.collective_id, result.experience.content);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `collective_ids` is empty or
+    ///   `k` is 0 or > 1000
+    /// - [`NotFoundError::Collective`] if any collective doesn't exist
+    /// - [`ValidationError::DimensionMismatch`] if any collective's
+    ///   dimension differs from `query.len()`
+    #[instrument(skip(self, query, filter))]
+    pub fn search_across_collectives(
+        &self,
+        collective_ids: &[CollectiveId],
+        query: &[f32],
+        k: usize,
+        filter: SearchFilter,
+    ) -> Result<Vec<SearchResult>> {
+        if collective_ids.is_empty() {
+            return Err(
+                ValidationError::invalid_field("collective_ids", "must not be empty").into(),
+            );
+        }
+        if k == 0 || k > 1000 {
+            return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
+        }
+
+        let mut seen = HashSet::new();
+        let ids: Vec<CollectiveId> = collective_ids
+            .iter()
+            .copied()
+            .filter(|id| seen.insert(*id))
+            .collect();
+
+        let searched = claim_in_parallel(&ids, search_threads(), |&id| {
+            self.search_similar_filtered(id, query, k, filter.clone())
+        })?;
+
+        // Report the first failing collective in the caller's order
+        let mut results = Vec::new();
+        for hits in searched {
+            results.extend(hits?);
+        }
+        results.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        results.truncate(k);
+        Ok(results)
+    }
+
+    /// Starts a fluent query against a collective.
+    ///
+    /// See [`Query`] for the options. The query compiles down to
+    /// [`search_similar_filtered()`](Self::search_similar_filtered).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// let results = db
+    ///     .query(collective_id)
+    ///     .similar_to(vec![0.1f32; 384])
+    ///     .filter(|f| f.domains(["rust"]).min_importance(0.5))
+    ///     .limit(5)
+    ///     .run()?;
+    /// assert!(results.results.len() <= 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query(&self, collective_id: CollectiveId) -> Query<'_> {
+        Query::new(self, collective_id)
+    }
+
+    /// Returns a handle confined to one collective and a set of
+    /// capabilities.
+    ///
+    /// See [`ScopedDb`]. Records in other collectives are invisible through
+    /// the handle, and calls outside `capabilities` fail with
+    /// [`PulseDBError::PermissionDenied`].
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    pub fn scoped(
+        &self,
+        collective_id: CollectiveId,
+        capabilities: Capabilities,
+    ) -> Result<ScopedDb<'_>> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        Ok(ScopedDb::new(self, collective_id, capabilities))
+    }
+
+    /// Body of [`search_similar_filtered()`](Self::search_similar_filtered),
+    /// recording how the search ran into `explain` when given and, if the
+    /// results fall short, a knowledge gap carrying `query_text`.
+    pub(crate) fn search_similar_explained(
+        &self,
+        collective_id: CollectiveId,
+        query: &[f32],
+        k: usize,
+        filter: SearchFilter,
+        explain: Option<&mut QueryExplain>,
+        query_text: Option<&str>,
+    ) -> Result<Vec<SearchResult>> {
+        let start = Instant::now();
+        let deadline = self.search_deadline();
+
+        // Validate k
+        if k == 0 || k > 1000 {
+            return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
+        }
+
+        // Verify collective exists and check embedding dimension
+        let collective = self
+            .storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let expected_dim = collective.embedding_dimension as usize;
+        if query.len() != expected_dim {
+            return Err(ValidationError::dimension_mismatch(expected_dim, query.len()).into());
+        }
+        if filter.min_similarity.is_some_and(|min| !min.is_finite()) {
+            return Err(ValidationError::invalid_field(
+                "min_similarity",
+                "must be a finite number",
+            )
+            .into());
+        }
+
+        // Over-fetch from HNSW to compensate for post-filtering losses. With
+        // a cutoff, fetch only `k` first: if the cutoff already falls inside
+        // them, nothing further out can pass and the over-fetch is skipped.
+        let max_fetch = k.saturating_mul(2).min(2000);
+        let mut over_fetch = if filter.min_similarity.is_some() {
+            k
+        } else {
+            max_fetch
+        };
+        let ef_search = self.config.hnsw.ef_search;
+        let score_kind = self.config.score_kind;
+        let passes = |distance: f32| {
+            filter
+                .min_similarity
+                .is_none_or(|min| score_kind.score(distance) >= min)
+        };
+
+        // Search HNSW index — returns (ExperienceId, cosine_distance) sorted
+        // by distance ascending (closest first)
+        let scope = self.search_scope(collective_id, &filter)?;
+        let mut candidates = Vec::new();
+        loop {
+            candidates.clear();
+            let mut exhausted = true;
+            for cid in &scope {
+                deadline.check()?;
+                let hits = self
+                    .with_vector_index(*cid, |index| {
+                        index.search_experiences(query, over_fetch, ef_search)
+                    })?
+                    .unwrap_or_default();
+                exhausted &= hits.len() < over_fetch;
+                candidates.extend(hits);
+            }
+            deadline.check()?;
+            if scope.len() > 1 {
+                candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+            }
+            let all_pass = candidates
+                .last()
+                .is_none_or(|&(_, distance)| passes(distance));
+            if over_fetch >= max_fetch || exhausted || !all_pass {
+                break;
+            }
+            over_fetch = max_fetch;
+        }
+        let fetched = candidates.len();
+        let nearest = candidates
+            .first()
+            .map(|&(_, distance)| score_kind.score(distance));
+        // Candidates are closest first: stop at the first one under the cutoff
+        let keep = candidates.partition_point(|&(_, distance)| passes(distance));
+        candidates.truncate(keep);
+        let below_min_similarity = fetched - candidates.len();
+        let screened = candidates.len();
+        let candidates = self.prescreen(candidates, &filter)?;
+        let prescreened_out = screened - candidates.len();
+
+        // Fetch full experiences, apply filter, convert distance → similarity
+        let (results, filtered_out) =
+            self.hydrate_candidates(&candidates, k, &deadline, |experience| {
+                filter.matches(experience)
+            })?;
+
+        if let Some(explain) = explain {
+            *explain = QueryExplain {
+                collectives_searched: scope.len(),
+                over_fetch,
+                ef_search,
+                candidates: fetched,
+                below_min_similarity,
+                prescreened_out,
+                filtered_out,
+                elapsed: start.elapsed(),
+            };
+        }
+        self.record_knowledge_gap(collective_id, query, query_text, nearest);
+        Ok(results)
+    }
+
+    /// Starts the clock for one search call; see
+    /// [`TimeoutConfig::search`](crate::TimeoutConfig::search).
+    fn search_deadline(&self) -> Deadline {
+        Deadline::start(TimedOperation::Search, self.config.timeouts.search)
+    }
+
+    /// Queues a knowledge gap if gap tracking is on and the nearest
+    /// indexed experience scores below the threshold.
+    ///
+    /// `nearest` is the best candidate before the search's filters and
+    /// similarity cutoff, so a narrow filter or a tight cutoff that returns
+    /// nothing doesn't count as missing knowledge. Failures are logged, not
+    /// returned: a search shouldn't fail because its gap couldn't be
+    /// written.
+    fn record_knowledge_gap(
+        &self,
+        collective_id: CollectiveId,
+        query: &[f32],
+        query_text: Option<&str>,
+        nearest: Option<f32>,
+    ) {
+        let Some(gaps) = &self.config.knowledge_gaps else {
+            return;
+        };
+        if self.config.read_only {
+            return;
+        }
+        if nearest.is_some_and(|score| score >= gaps.min_score) {
+            return;
+        }
+
+        let record = KnowledgeGapRecord {
+            collective_id,
+            query: query_text.map(str::to_string),
+            embedding: query.to_vec(),
+            best_score: nearest,
+            recorded_at: Timestamp::now(),
+        };
+        let full = match self.queued_gaps.lock() {
+            Ok(mut queued) => {
+                queued.push(record);
+                queued.len() >= KNOWLEDGE_GAP_BATCH
+            }
+            Err(_) => return,
+        };
+        if full {
+            self.write_queued_gaps();
+        }
+    }
+
+    /// Writes the queued knowledge gaps in one transaction.
+    ///
+    /// Gaps of collectives that are frozen or unavailable stay queued for
+    /// a later write. Failures are logged and drop the batch.
+    fn write_queued_gaps(&self) {
+        let queued = match self.queued_gaps.lock() {
+            Ok(mut queued) if !queued.is_empty() => std::mem::take(&mut *queued),
+            _ => return,
+        };
+
+        let mut fences = HashMap::new();
+        let mut held = Vec::new();
+        let mut batch = Vec::with_capacity(queued.len());
+        for record in queued {
+            let cid = record.collective_id;
+            let fence = fences
+                .entry(cid)
+                .or_insert_with(|| self.check_collective_writable(cid).ok());
+            match fence {
+                Some(_) => batch.push(record),
+                None => held.push(record),
+            }
+        }
+        if !batch.is_empty() {
+            if let Err(e) = self.storage.save_knowledge_gaps(&batch) {
+                warn!(count = batch.len(), error = %e, "Failed to record knowledge gaps");
+            }
+        }
+        drop(fences);
+        if !held.is_empty() {
+            if let Ok(mut queued) = self.queued_gaps.lock() {
+                held.append(&mut queued);
+                *queued = held;
+            }
+        }
+    }
+
+    /// Drops a collective's queued knowledge gaps, returning how many.
+    fn drop_queued_gaps(&self, collective_id: CollectiveId) -> usize {
+        let Ok(mut queued) = self.queued_gaps.lock() else {
+            return 0;
+        };
+        let before = queued.len();
+        queued.retain(|record| record.collective_id != collective_id);
+        before - queued.len()
+    }
+
+    /// Searches for similar experiences among those connected to an anchor
+    /// by relations.
+    ///
+    /// The neighborhood is every experience reachable from `anchor_id`
+    /// within `hops` relation edges, followed in either direction; the
+    /// anchor itself is left out. The vector search then runs as a filtered
+    /// traversal that only admits neighborhood members, so results are
+    /// ranked by similarity to `query` exactly as in
+    /// [`search_similar()`](Self::search_similar). Archived experiences are
+    /// excluded.
+    ///
+    /// The traversal can strand on a small neighborhood in a large index,
+    /// so fewer than `k` results may come back even when more members
+    /// exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `anchor_id` - The experience whose neighborhood is searched
+    /// * `query` - Query embedding vector (must match the anchor's collective)
+    /// * `k` - Maximum number of results to return (1-1000)
+    /// * `hops` - Maximum relation distance from the anchor (1-10)
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `k` is 0 or > 1000, or `hops`
+    ///   is 0 or > 10
+    /// - [`ValidationError::DimensionMismatch`] if `query.len()` doesn't match
+    ///   the collective's embedding dimension
+    /// - [`NotFoundError::Experience`] if the anchor doesn't exist
+    #[instrument(skip(self, query))]
+    pub fn search_within_neighborhood(
+        &self,
+        anchor_id: ExperienceId,
+        query: &[f32],
+        k: usize,
+        hops: usize,
+    ) -> Result<Vec<SearchResult>> {
+        if k == 0 || k > 1000 {
+            return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
+        }
+        if hops == 0 || hops > 10 {
+            return Err(ValidationError::invalid_field("hops", "must be between 1 and 10").into());
+        }
+        let deadline = self.search_deadline();
+
+        let anchor = self
+            .storage
+            .get_experience(anchor_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(anchor_id)))?;
+        let collective_id = anchor.collective_id;
+        let collective = self
+            .storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        let expected_dim = collective.embedding_dimension as usize;
+        if query.len() != expected_dim {
+            return Err(ValidationError::dimension_mismatch(expected_dim, query.len()).into());
+        }
+
+        // Breadth-first walk over relations, one hop per round
+        let mut visited = HashSet::from([anchor_id]);
+        let mut frontier = vec![anchor_id];
+        for _ in 0..hops {
+            let mut next = Vec::new();
+            for id in frontier {
+                deadline.check()?;
+                let rel_ids = self
+                    .storage
+                    .get_relation_ids_by_source(id)?
+                    .into_iter()
+                    .chain(self.storage.get_relation_ids_by_target(id)?);
+                for rel_id in rel_ids {
+                    if let Some(relation) = self.storage.get_relation(rel_id)? {
+                        let other = if relation.source_id == id {
+                            relation.target_id
+                        } else {
+                            relation.source_id
+                        };
+                        if visited.insert(other) {
+                            next.push(other);
+                        }
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        visited.remove(&anchor_id);
+        if visited.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Over-fetch to make up for archived members dropped below
+        let over_fetch = k.saturating_mul(2).min(2000);
+        let ef_search = self.config.hnsw.ef_search;
+        deadline.check()?;
+        let candidates = self
+            .with_vector_index(collective_id, |index| {
+                index.search_experiences_within(query, over_fetch, ef_search, &visited)
+            })?
+            .unwrap_or_default();
+
+        let (results, _) =
+            self.hydrate_candidates(&candidates, k, &deadline, |experience| !experience.archived)?;
+        Ok(results)
+    }
+
+    /// Fetches the experiences behind ranked search candidates until `k`
+    /// pass `keep`, preserving candidate order.
+    ///
+    /// Works in waves of only as many candidates as are still needed, so
+    /// nothing past the `k`th hit is fetched. A wave larger than one
+    /// [`HYDRATION_MORSEL`] is split into morsels that up to
+    /// [`Config::hydration_threads`] workers claim in turn; each worker
+    /// fetches, filters, and runs read hooks, and the morsels are
+    /// reassembled in order. Returns the hits and the number of candidates
+    /// rejected (missing or failing `keep`).
+    fn hydrate_candidates(
+        &self,
+        candidates: &[(ExperienceId, f32)],
+        k: usize,
+        deadline: &Deadline,
+        keep: impl Fn(&Experience) -> bool + Sync,
+    ) -> Result<(Vec<SearchResult>, usize)> {
+        let hydrate = |&(exp_id, distance): &(ExperienceId, f32)| -> Result<Option<SearchResult>> {
+            deadline.check()?;
+            match self.storage.get_experience(exp_id)? {
+                Some(mut experience) if keep(&experience) => {
+                    self.run_read_hooks_on_experience(&mut experience)?;
+                    Ok(Some(SearchResult {
+                        experience,
+                        similarity: self.config.score_kind.score(distance),
+                        distance,
+                        score_kind: self.config.score_kind,
+                    }))
+                }
+                _ => Ok(None),
+            }
+        };
+
+        let mut results = Vec::with_capacity(k.min(candidates.len()));
+        let mut rejected = 0;
+        let mut rest = candidates;
+        while results.len() < k && !rest.is_empty() {
+            let (wave, tail) = rest.split_at((k - results.len()).min(rest.len()));
+            rest = tail;
+
+            let morsels: Vec<&[(ExperienceId, f32)]> = wave.chunks(HYDRATION_MORSEL).collect();
+            let hydrated = claim_in_parallel(&morsels, self.config.hydration_threads, |morsel| {
+                morsel.iter().map(hydrate).collect::<Vec<_>>()
+            })?;
+
+            for hit in hydrated.into_iter().flatten() {
+                match hit? {
+                    Some(result) => results.push(result),
+                    None => rejected += 1,
+                }
+            }
+        }
+        Ok((results, rejected))
+    }
+
+    // =========================================================================
+    // Similarity Graph
+    // =========================================================================
+
+    /// Precomputes the `k` nearest neighbors of every experience in a
+    /// collective and stores them.
+    ///
+    /// Graph expansion, clustering, and duplicate detection can then read
+    /// neighbor lists with [`get_experience_neighbors()`](Self::get_experience_neighbors)
+    /// instead of issuing an ANN query per experience. Neighbors come from
+    /// the collective's vector index, archived experiences included; each
+    /// run replaces the previous lists. Experiences recorded afterwards get
+    /// a list on the next run, so schedule it like other maintenance.
+    /// Similarities are scored with the [`Config::score_kind`] in effect
+    /// for the run.
+    ///
+    /// Returns the number of experiences whose lists were written.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`ValidationError::InvalidField`] if `k` is 0 or > 1000
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// # let experience_id = db.record_experience(pulsedb::NewExperience {
+    /// #     collective_id,
+    /// #     content: "example".into(),
+    /// #     embedding: Some(vec![0.1; 384]),
+    /// #     ..Default::default()
+    /// # })?;
+    /// db.compute_neighbor_graph(collective_id, 10)?;
+    /// if let Some(neighbors) = db.get_experience_neighbors(experience_id)? {
+    ///     for neighbor in neighbors {
+    ///         println!("{} ({:.3})", neighbor.experience_id, neighbor.similarity);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn compute_neighbor_graph(&self, collective_id: CollectiveId, k: usize) -> Result<usize> {
+        self.check_writable()?;
+        if k == 0 || k > 1000 {
+            return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
+        }
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        let _fence = self.check_collective_writable(collective_id)?;
+
+        // One extra hit, since each experience finds itself first
+        let ef_search = self.config.hnsw.ef_search.max(k + 1);
+        let mut batch = Vec::with_capacity(NEIGHBOR_GRAPH_BATCH);
+        let mut computed = 0;
+        for exp_id in self
+            .storage
+            .list_experience_ids_in_collective(collective_id)?
+        {
+            let Some(embedding) = self.storage.get_embedding(exp_id)? else {
+                continue;
+            };
+            let hits = self
+                .with_vector_index(collective_id, |index| {
+                    index.search_experiences(&embedding, k + 1, ef_search)
+                })?
+                .unwrap_or_default();
+            let neighbors = hits
+                .into_iter()
+                .filter(|(id, _)| *id != exp_id)
+                .take(k)
+                .map(|(id, distance)| ExperienceNeighbor {
+                    experience_id: id,
+                    similarity: self.config.score_kind.score(distance),
+                })
+                .collect();
+            batch.push((exp_id, neighbors));
+
+            if batch.len() >= NEIGHBOR_GRAPH_BATCH {
+                self.storage.save_experience_neighbors(&batch)?;
+                computed += batch.len();
+                batch.clear();
+            }
+        }
+        if !batch.is_empty() {
+            self.storage.save_experience_neighbors(&batch)?;
+            computed += batch.len();
+        }
+
+        info!(collective = %collective_id, k, experiences = computed, "Neighbor graph computed");
+        Ok(computed)
+    }
+
+    /// Returns an experience's precomputed neighbors, most similar first.
+    ///
+    /// Returns `None` if [`compute_neighbor_graph()`](Self::compute_neighbor_graph)
+    /// hasn't covered the experience yet. Neighbors deleted since the graph
+    /// was computed are left out.
+    pub fn get_experience_neighbors(
+        &self,
+        id: ExperienceId,
+    ) -> Result<Option<Vec<ExperienceNeighbor>>> {
+        let Some(mut neighbors) = self.storage.get_experience_neighbors(id)? else {
+            return Ok(None);
+        };
+
+        let ids: Vec<ExperienceId> = neighbors.iter().map(|n| n.experience_id).collect();
+        let mut exists = self
+            .storage
+            .get_experience_meta(&ids)?
+            .into_iter()
+            .map(|meta| meta.is_some());
+        neighbors.retain(|_| exists.next().unwrap_or(false));
+        Ok(Some(neighbors))
+    }
+
+    /// Returns up to `n` query embeddings the experience most strongly
+    /// answers — "when will this memory fire?".
+    ///
+    /// The first query is the experience's own embedding. The rest target
+    /// topics it shares with its nearest active neighbors: each is the
+    /// bisector of the two embeddings, and the most mutually distinct ones
+    /// are kept. Every query is run through
+    /// [`search_similar()`](Self::search_similar) to report the
+    /// experience's rank. Turning a query into text is left to the caller;
+    /// the content of [`RepresentativeQuery::exemplar`] is a natural label.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `n` is 0 or > 100
+    /// - [`NotFoundError::Experience`] if the experience doesn't exist
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// # let experience_id = db.record_experience(pulsedb::NewExperience {
+    /// #     collective_id,
+    /// #     content: "example".into(),
+    /// #     embedding: Some(vec![0.1; 384]),
+    /// #     ..Default::default()
+    /// # })?;
+    /// for query in db.representative_queries(experience_id, 5)? {
+    ///     println!("{:?} rank {:?}", query.exemplar, query.rank);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn representative_queries(
+        &self,
+        experience_id: ExperienceId,
+        n: usize,
+    ) -> Result<Vec<RepresentativeQuery>> {
+        if n == 0 || n > 100 {
+            return Err(ValidationError::invalid_field("n", "must be between 1 and 100").into());
+        }
+        let experience = self
+            .storage
+            .get_experience(experience_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(experience_id)))?;
+        let Some(embedding) = self.storage.get_embedding(experience_id)? else {
+            return Ok(Vec::new());
+        };
+        let collective_id = experience.collective_id;
+
+        // Several neighbors per query to choose distinct topics from
+        let fetch = n.saturating_mul(8).min(1000);
+        let ef_search = self.config.hnsw.ef_search.max(fetch + 1);
+        let hits = self
+            .with_vector_index(collective_id, |index| {
+                index.search_experiences(&embedding, fetch + 1, ef_search)
+            })?
+            .unwrap_or_default();
+
+        let mut exemplars = vec![None];
+        let unit = normalized(&embedding);
+        let mut candidates = vec![unit.clone()];
+        for (id, _) in hits {
+            if id == experience_id {
+                continue;
+            }
+            let active = self
+                .storage
+                .get_experience(id)?
+                .is_some_and(|neighbor| !neighbor.archived);
+            if !active {
+                continue;
+            }
+            if let Some(neighbor) = self.storage.get_embedding(id)? {
+                exemplars.push(Some(id));
+                candidates.push(bisector(&embedding, &neighbor));
+            }
+        }
+
+        let mut queries = Vec::with_capacity(n);
+        for i in select_diverse(&candidates, n) {
+            let query = std::mem::take(&mut candidates[i]);
+            let results = self.search_similar(collective_id, &query, REPRESENTATIVE_QUERY_DEPTH)?;
+            let rank = results
+                .iter()
+                .position(|r| r.experience.id == experience_id)
+                .map(|position| position + 1);
+            let similarity = query.iter().zip(&unit).map(|(a, b)| a * b).sum();
+            queries.push(RepresentativeQuery {
+                embedding: query,
+                exemplar: exemplars[i],
+                similarity,
+                rank,
+            });
+        }
+        Ok(queries)
+    }
+
+    /// Describes the shape of a collective's embedding space.
+    ///
+    /// Reports the distribution of embedding norms, the mean vector, an
+    /// intrinsic dimensionality estimate, and the near-duplicate rate — the
+    /// first things to check when retrieval quality is poor. The estimate
+    /// uses TwoNN (Facco et al., 2017), which needs only each embedding's
+    /// two nearest neighbors, taken from the collective's vector index.
+    ///
+    /// Runs one ANN query per embedding, so it costs about as much as
+    /// [`compute_neighbor_graph()`](Self::compute_neighbor_graph). Archived
+    /// experiences are included.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    #[instrument(skip(self))]
+    pub fn embedding_stats(&self, collective_id: CollectiveId) -> Result<EmbeddingStats> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let ef_search = self.config.hnsw.ef_search.max(3);
+        let mut count = 0u64;
+        let (mut norm_min, mut norm_max) = (f64::INFINITY, 0.0f64);
+        let (mut norm_sum, mut norm_sq_sum) = (0.0f64, 0.0f64);
+        let mut vector_sum: Vec<f64> = Vec::new();
+        let mut near_duplicates = 0u64;
+        // TwoNN: sum of ln(r2 / r1) over points with distinct neighbors
+        let (mut log_ratio_sum, mut ratio_count) = (0.0f64, 0u64);
+
+        for exp_id in self
+            .storage
+            .list_experience_ids_in_collective(collective_id)?
+        {
+            let Some(embedding) = self.storage.get_embedding(exp_id)? else {
+                continue;
+            };
+
+            count += 1;
+            let norm = embedding
+                .iter()
+                .map(|&x| f64::from(x) * f64::from(x))
+                .sum::<f64>()
+                .sqrt();
+            norm_min = norm_min.min(norm);
+            norm_max = norm_max.max(norm);
+            norm_sum += norm;
+            norm_sq_sum += norm * norm;
+            if vector_sum.is_empty() {
+                vector_sum = vec![0.0; embedding.len()];
+            }
+            for (sum, &x) in vector_sum.iter_mut().zip(&embedding) {
+                *sum += f64::from(x);
+            }
+
+            let hits = self
+                .with_vector_index(collective_id, |index| {
+                    index.search_experiences(&embedding, 3, ef_search)
+                })?
+                .unwrap_or_default();
+            // Chord length on the unit sphere, a true metric unlike cosine distance
+            let distances: Vec<f64> = hits
+                .into_iter()
+                .filter(|(id, _)| *id != exp_id)
+                .take(2)
+                .map(|(_, distance)| (2.0 * f64::from(distance.max(0.0))).sqrt())
+                .collect();
+            if let Some(&r1) = distances.first() {
+                if 1.0 - (r1 * r1 / 2.0) >= f64::from(NEAR_DUPLICATE_SIMILARITY) {
+                    near_duplicates += 1;
+                }
+                if let Some(&r2) = distances.get(1) {
+                    if r1 > 0.0 {
+                        log_ratio_sum += (r2 / r1).ln();
+                        ratio_count += 1;
+                    }
+                }
+            }
+        }
+
+        if count == 0 {
+            return Ok(EmbeddingStats::default());
+        }
+        let n = count as f64;
+        let norm_mean = norm_sum / n;
+        let norm_variance = (norm_sq_sum / n - norm_mean * norm_mean).max(0.0);
+        let intrinsic_dimension = (count >= 3 && ratio_count > 0 && log_ratio_sum > 0.0)
+            .then(|| (ratio_count as f64 / log_ratio_sum) as f32);
+
+        Ok(EmbeddingStats {
+            embedding_count: count,
+            norm_min: norm_min as f32,
+            norm_max: norm_max as f32,
+            norm_mean: norm_mean as f32,
+            norm_std_dev: norm_variance.sqrt() as f32,
+            mean_vector: vector_sum.into_iter().map(|sum| (sum / n) as f32).collect(),
+            intrinsic_dimension,
+            near_duplicate_rate: (near_duplicates as f64 / n) as f32,
+        })
+    }
+
+    // =========================================================================
+    // Retrieval Evaluation
+    // =========================================================================
+
+    /// Evaluates retrieval configurations against a labeled query set.
+    ///
+    /// Runs every query in `set` under each configuration and returns one
+    /// [`EvalReport`] per configuration, in input order, with mean NDCG@k,
+    /// mean recall@k, and per-query latency. Configurations with
+    /// [`RetrievalConfig::hnsw`] set are searched through a scratch index
+    /// built from the collective's stored embeddings; the live index and
+    /// stored data are never modified.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`ValidationError::InvalidField`] if the set is empty, a `k` is
+    ///   outside 1–1000, or a query has no relevant experiences
+    /// - [`ValidationError::DimensionMismatch`] if a query embedding has
+    ///   the wrong dimension
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let cid = db.create_collective("eval")?;
+    /// use pulsedb::{EvalSet, HnswConfig, RetrievalConfig};
+    ///
+    /// # let set = EvalSet::default();
+    /// let configs = [
+    ///     RetrievalConfig::default(),
+    ///     RetrievalConfig {
+    ///         name: "low-ef".into(),
+    ///         hnsw: Some(HnswConfig { ef_search: 16, ..HnswConfig::default() }),
+    ///         ..RetrievalConfig::default()
+    ///     },
+    /// ];
+    /// # if !set.queries.is_empty() {
+    /// for report in db.evaluate(cid, &set, &configs)? {
+    ///     println!("{}: ndcg={:.3} recall={:.3}", report.config, report.ndcg, report.recall);
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, set, configs))]
+    pub fn evaluate(
+        &self,
+        collective_id: CollectiveId,
+        set: &EvalSet,
+        configs: &[RetrievalConfig],
+    ) -> Result<Vec<EvalReport>> {
+        let collective = self
+            .storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        if set.queries.is_empty() {
+            return Err(ValidationError::invalid_field("queries", "must not be empty").into());
+        }
+        let dimension = collective.embedding_dimension as usize;
+        validate_eval_set(set, dimension)?;
+        for config in configs {
+            validate_retrieval_config(config)?;
+        }
+
+        let mut reports = Vec::with_capacity(configs.len());
+        for config in configs {
+            // Scratch index per configuration, built once over the search scope
+            let scratch = match &config.hnsw {
+                Some(hnsw) => {
+                    let mut embeddings = Vec::new();
+                    for cid in self.search_scope(collective_id, &config.filter)? {
+                        for exp_id in self.storage.list_experience_ids_in_collective(cid)? {
+                            if let Some(embedding) = self.storage.get_embedding(exp_id)? {
+                                embeddings.push((exp_id, embedding));
+                            }
+                        }
+                    }
+                    Some((
+                        HnswIndex::rebuild_from_embeddings(dimension, hnsw, embeddings)?,
+                        hnsw.ef_search,
+                    ))
+                }
+                None => None,
+            };
+
+            let mut ndcg = 0.0;
+            let mut recall = 0.0;
+            let mut latencies = Vec::with_capacity(set.queries.len());
+            for query in &set.queries {
+                let start = Instant::now();
+                let ranked: Vec<ExperienceId> = match &scratch {
+                    Some((index, ef_search)) => {
+                        let over_fetch = config.k.saturating_mul(2).min(2000);
+                        let mut ranked = Vec::with_capacity(config.k);
+                        for (exp_id, _) in
+                            index.search_experiences(&query.embedding, over_fetch, *ef_search)?
+                        {
+                            if ranked.len() >= config.k {
+                                break;
+                            }
+                            if let Some(experience) = self.storage.get_experience(exp_id)? {
+                                if config.filter.matches(&experience) {
+                                    ranked.push(exp_id);
+                                }
+                            }
+                        }
+                        ranked
+                    }
+                    None => self
+                        .search_similar_filtered(
+                            collective_id,
+                            &query.embedding,
+                            config.k,
+                            config.filter.clone(),
+                        )?
+                        .into_iter()
+                        .map(|r| r.experience.id)
+                        .collect(),
+                };
+                latencies.push(start.elapsed());
+
+                ndcg += ndcg_at_k(&ranked, &query.relevant, config.k);
+                recall += recall_at_k(&ranked, &query.relevant, config.k);
+            }
+
+            let queries = set.queries.len();
+            let (mean_latency, p95_latency) = latency_summary(&mut latencies);
+            info!(
+                config = %config.name,
+                queries,
+                ndcg = ndcg / queries as f64,
+                recall = recall / queries as f64,
+                "Evaluated retrieval configuration"
+            );
+            reports.push(EvalReport {
+                config: config.name.clone(),
+                queries,
+                ndcg: ndcg / queries as f64,
+                recall: recall / queries as f64,
+                mean_latency,
+                p95_latency,
+            });
+        }
+
+        Ok(reports)
+    }
+
+    // =========================================================================
+    // Experience Relations (E3-S01)
+    // =========================================================================
+
+    /// Stores a new relation between two experiences.
+    ///
+    /// Relations are typed, directed edges connecting a source experience to a
+    /// target experience. Both experiences must exist and belong to the same
+    /// collective. Duplicate relations (same source, target, and type) are
+    /// rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `relation` - The relation to create (source, target, type, strength)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Source or target experience doesn't exist ([`NotFoundError::Experience`])
+    /// - Experiences belong to different collectives ([`ValidationError::InvalidField`])
+    /// - A relation with the same (source, target, type) already exists
+    /// - Self-relation attempted (source == target)
+    /// - Strength is out of range `[0.0, 1.0]`
+    #[instrument(skip(self, relation))]
+    pub fn store_relation(
+        &self,
+        mut relation: crate::relation::NewExperienceRelation,
+    ) -> Result<crate::types::RelationId> {
+        self.check_writable()?;
+        self.run_pre_write_hooks(PendingWrite::Relation(&mut relation))?;
+        use crate::relation::validate_new_relation;
+        use crate::types::RelationId;
+
+        // Validate input fields (self-relation, strength bounds, metadata size)
+        validate_new_relation(&relation)?;
+
+        // Load source and target experiences to verify existence
+        let source = self
+            .storage
+            .get_experience(relation.source_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(relation.source_id)))?;
+        let target = self
+            .storage
+            .get_experience(relation.target_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(relation.target_id)))?;
+
+        // Verify same collective
+        if source.collective_id != target.collective_id {
+            return Err(PulseDBError::from(ValidationError::invalid_field(
+                "target_id",
+                "source and target experiences must belong to the same collective",
+            )));
+        }
+        if relation.relation_type == RelationType::Answers
+            && target.experience_type.type_tag() != ExperienceTypeTag::OpenQuestion
+        {
+            return Err(PulseDBError::from(ValidationError::invalid_field(
+                "target_id",
+                "Answers relations must target an OpenQuestion experience",
+            )));
+        }
+        let _fence = self.check_collective_writable(source.collective_id)?;
+
+        // Check for duplicate (same source, target, type)
+        if self.storage.relation_exists(
+            relation.source_id,
+            relation.target_id,
+            relation.relation_type,
+        )? {
+            return Err(PulseDBError::from(ValidationError::invalid_field(
+                "relation_type",
+                "a relation with this source, target, and type already exists",
+            )));
+        }
+
+        // Construct the full relation
+        let id = RelationId::new();
+        let full_relation = ExperienceRelation {
+            id,
+            source_id: relation.source_id,
+            target_id: relation.target_id,
+            relation_type: relation.relation_type,
+            strength: relation.strength,
+            metadata: relation.metadata,
+            created_at: Timestamp::now(),
+        };
+
+        self.storage.save_relation(&full_relation)?;
+        self.run_post_write_hooks(CommittedWrite::Relation(&full_relation));
+
+        info!(
+            id = %id,
+            source = %relation.source_id,
+            target = %relation.target_id,
+            relation_type = ?full_relation.relation_type,
+            "Relation stored"
+        );
+        Ok(id)
+    }
+
+    /// Retrieves experiences related to the given experience.
+    ///
+    /// Returns pairs of `(Experience, ExperienceRelation)` based on the
+    /// requested direction:
+    /// - `Outgoing`: experiences that this experience points TO (as source)
+    /// - `Incoming`: experiences that point TO this experience (as target)
+    /// - `Both`: union of outgoing and incoming
+    ///
+    /// To filter by relation type, use
+    /// [`get_related_experiences_filtered`](Self::get_related_experiences_filtered).
+    ///
+    /// Silently skips relations where the related experience no longer exists
+    /// (orphan tolerance).
+    ///
+    /// # Errors
+    ///
+    /// Returns a storage error if the read transaction fails.
+    #[instrument(skip(self))]
+    pub fn get_related_experiences(
+        &self,
+        experience_id: ExperienceId,
+        direction: crate::relation::RelationDirection,
+    ) -> Result<Vec<(Experience, crate::relation::ExperienceRelation)>> {
+        self.get_related_experiences_filtered(experience_id, direction, None)
+    }
+
+    /// Retrieves experiences related to the given experience, with optional
+    /// type filtering.
+    ///
+    /// Like [`get_related_experiences()`](Self::get_related_experiences), but
+    /// accepts an optional [`RelationType`](crate::RelationType) filter.
+    /// When `Some(rt)`, only relations matching that type are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `experience_id` - The experience to query relations for
+    /// * `direction` - Which direction(s) to traverse
+    /// * `relation_type` - If `Some`, only return relations of this type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let cid = db.create_collective("example")?;
+    /// # let exp_a = db.record_experience(pulsedb::NewExperience {
+    /// #     collective_id: cid,
+    /// #     content: "a".into(),
+    /// #     embedding: Some(vec![0.1f32; 384]),
+    /// #     ..Default::default()
+    /// # })?;
+    /// use pulsedb::{RelationType, RelationDirection};
+    ///
+    /// // Only "Supports" relations outgoing from exp_a
+    /// let supports = db.get_related_experiences_filtered(
+    ///     exp_a,
+    ///     RelationDirection::Outgoing,
+    ///     Some(RelationType::Supports),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn get_related_experiences_filtered(
+        &self,
+        experience_id: ExperienceId,
+        direction: crate::relation::RelationDirection,
+        relation_type: Option<crate::relation::RelationType>,
+    ) -> Result<Vec<(Experience, crate::relation::ExperienceRelation)>> {
+        use crate::relation::RelationDirection;
+
+        let mut results = Vec::new();
+
+        // Outgoing: this experience is the source → fetch target experiences
+        if matches!(
+            direction,
+            RelationDirection::Outgoing | RelationDirection::Both
+        ) {
+            let rel_ids = self.storage.get_relation_ids_by_source(experience_id)?;
+            for rel_id in rel_ids {
+                if let Some(relation) = self.storage.get_relation(rel_id)? {
+                    if relation_type.is_some_and(|rt| rt != relation.relation_type) {
+                        continue;
+                    }
+                    if let Some(mut experience) = self.storage.get_experience(relation.target_id)? {
+                        self.run_read_hooks_on_experience(&mut experience)?;
+                        results.push((experience, relation));
+                    }
+                }
+            }
+        }
+
+        // Incoming: this experience is the target → fetch source experiences
+        if matches!(
+            direction,
+            RelationDirection::Incoming | RelationDirection::Both
+        ) {
+            let rel_ids = self.storage.get_relation_ids_by_target(experience_id)?;
+            for rel_id in rel_ids {
+                if let Some(relation) = self.storage.get_relation(rel_id)? {
+                    if relation_type.is_some_and(|rt| rt != relation.relation_type) {
+                        continue;
+                    }
+                    if let Some(mut experience) = self.storage.get_experience(relation.source_id)? {
+                        self.run_read_hooks_on_experience(&mut experience)?;
+                        results.push((experience, relation));
+                    }
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Retrieves a relation by ID.
+    ///
+    /// Returns `None` if no relation with the given ID exists.
+    pub fn get_relation(
+        &self,
+        id: crate::types::RelationId,
+    ) -> Result<Option<crate::relation::ExperienceRelation>> {
+        self.storage.get_relation(id)
+    }
+
+    /// Deletes a relation by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Relation`] if no relation with the given ID exists.
+    #[instrument(skip(self))]
+    pub fn delete_relation(&self, id: crate::types::RelationId) -> Result<()> {
+        self.check_writable()?;
+        let _fence = match self.enter_unfenced() {
+            Some(guard) => guard,
+            None => match self.storage.get_relation(id)? {
+                Some(relation) => self.check_experience_writable(relation.source_id)?,
+                None => self.fence.pass(),
+            },
+        };
+        let deleted = self.storage.delete_relation(id)?;
+        if !deleted {
+            return Err(PulseDBError::from(NotFoundError::relation(id)));
+        }
+        info!(id = %id, "Relation deleted");
+        Ok(())
+    }
+
+    // =========================================================================
+    // Relation Suggestions
+    // =========================================================================
+
+    /// Queues relation suggestions for a newly published experience, if
+    /// [`Config::relation_suggestions`](crate::Config::relation_suggestions)
+    /// is set. Failures are logged, not returned: the experience is already
+    /// recorded.
+    fn suggest_relations(&self, experience: &Experience) {
+        let Some(config) = &self.config.relation_suggestions else {
+            return;
+        };
+        if let Err(e) = self.try_suggest_relations(experience, config) {
+            warn!(id = %experience.id, error = %e, "Failed to suggest relations");
+        }
+    }
+
+    fn try_suggest_relations(
+        &self,
+        experience: &Experience,
+        config: &RelationSuggestionConfig,
+    ) -> Result<()> {
+        let k = config.max_suggestions;
+        // One extra hit, since the experience finds itself first
+        let ef_search = self.config.hnsw.ef_search.max(k + 1);
+        let hits = self
+            .with_vector_index(experience.collective_id, |index| {
+                index.search_experiences(&experience.embedding, k + 1, ef_search)
+            })?
+            .unwrap_or_default();
+
+        let now = Timestamp::now();
+        let mut suggestions = Vec::new();
+        for (target_id, distance) in hits {
+            let similarity = self.config.score_kind.score(distance);
+            if target_id == experience.id || similarity < config.min_similarity {
+                continue;
+            }
+            let Some(target) = self.storage.get_experience(target_id)? else {
+                continue;
+            };
+            if target.archived {
+                continue;
+            }
+            suggestions.push(RelationSuggestion {
+                collective_id: experience.collective_id,
+                source_id: experience.id,
+                target_id,
+                relation_type: suggest_relation_type(
+                    &experience.experience_type,
+                    &target.experience_type,
+                ),
+                similarity: similarity.min(1.0),
+                suggested_at: now,
+            });
+            if suggestions.len() == k {
+                break;
+            }
+        }
+        if !suggestions.is_empty() {
+            self.storage.save_relation_suggestions(&suggestions)?;
+        }
+        Ok(())
+    }
+
+    /// Lists a collective's queued relation suggestions, ordered by source
+    /// experience (oldest first when IDs are time-ordered), then target.
+    ///
+    /// Suggestions whose target has since been deleted are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// use pulsedb::{Config, NewExperience, PulseDB, RelationSuggestionConfig};
+    ///
+    /// let config = Config {
+    ///     relation_suggestions: Some(RelationSuggestionConfig::default()),
+    ///     ..Default::default()
+    /// };
+    /// let db = PulseDB::open(dir.path().join("test.db"), config)?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// for content in ["CI is slow", "CI is slow again"] {
+    ///     db.record_experience(NewExperience {
+    ///         collective_id,
+    ///         content: content.into(),
+    ///         embedding: Some(vec![0.1; 384]),
+    ///         ..Default::default()
+    ///     })?;
+    /// }
+    ///
+    /// for suggestion in db.list_relation_suggestions(collective_id)? {
+    ///     db.accept_relation_suggestion(suggestion.source_id, suggestion.target_id)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn list_relation_suggestions(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Vec<RelationSuggestion>> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let mut suggestions = self.storage.list_relation_suggestions(collective_id)?;
+        let targets: Vec<ExperienceId> = suggestions.iter().map(|s| s.target_id).collect();
+        let mut exists = self
+            .storage
+            .get_experience_meta(&targets)?
+            .into_iter()
+            .map(|meta| meta.is_some());
+        suggestions.retain(|_| exists.next().unwrap_or(false));
+        Ok(suggestions)
+    }
+
+    /// Confirms a relation suggestion, storing it as a relation.
+    ///
+    /// The relation gets the suggested type, with the similarity as its
+    /// strength, and the suggestion leaves the queue. To record a different
+    /// type, call [`store_relation()`](Self::store_relation) and
+    /// [`dismiss_relation_suggestion()`](Self::dismiss_relation_suggestion)
+    /// instead.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Experience`] if either experience doesn't exist
+    /// - [`ValidationError::InvalidField`] if no suggestion is queued for
+    ///   the pair, or the relation already exists
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    #[instrument(skip(self))]
+    pub fn accept_relation_suggestion(
+        &self,
+        source_id: ExperienceId,
+        target_id: ExperienceId,
+    ) -> Result<RelationId> {
+        self.check_writable()?;
+        let collective_id = self.suggestion_collective(source_id)?;
+        let suggestion = self
+            .storage
+            .get_relation_suggestion(collective_id, source_id, target_id)?
+            .ok_or_else(|| {
+                ValidationError::invalid_field(
+                    "target_id",
+                    format!("no relation suggested from {} to {}", source_id, target_id),
+                )
+            })?;
+
+        let id = self.store_relation(NewExperienceRelation {
+            source_id,
+            target_id,
+            relation_type: suggestion.relation_type,
+            strength: suggestion.similarity.clamp(0.0, 1.0),
+            metadata: None,
+        })?;
+        self.storage
+            .delete_relation_suggestion(collective_id, source_id, target_id)?;
+        Ok(id)
+    }
+
+    /// Drops a relation suggestion without storing it.
+    ///
+    /// Returns `true` if a suggestion was queued for the pair.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Experience`] if the source experience doesn't exist
+    #[instrument(skip(self))]
+    pub fn dismiss_relation_suggestion(
+        &self,
+        source_id: ExperienceId,
+        target_id: ExperienceId,
+    ) -> Result<bool> {
+        self.check_writable()?;
+        let collective_id = self.suggestion_collective(source_id)?;
+        self.storage
+            .delete_relation_suggestion(collective_id, source_id, target_id)
+    }
+
+    /// Collective of a suggestion's source experience.
+    fn suggestion_collective(&self, source_id: ExperienceId) -> Result<CollectiveId> {
+        Ok(self
+            .storage
+            .get_experience(source_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(source_id)))?
+            .collective_id)
+    }
+
+    // =========================================================================
+    // Open Questions
+    // =========================================================================
+
+    /// Links an experience as an answer to an open question.
+    ///
+    /// Stores an [`Answers`](RelationType::Answers) relation from the answer
+    /// to the question with full strength. A question with at least one
+    /// answer no longer appears in
+    /// [`list_open_questions()`](Self::list_open_questions); deleting the
+    /// answer (or the relation) reopens it.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Experience`] if either experience doesn't exist
+    /// - [`ValidationError::InvalidField`] if the question isn't an
+    ///   [`OpenQuestion`](crate::ExperienceType::OpenQuestion), the two
+    ///   belong to different collectives, or the answer is already linked
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use pulsedb::{ExperienceType, NewExperience};
+    ///
+    /// let question = db.record_experience(NewExperience {
+    ///     collective_id,
+    ///     content: "Why does the nightly build time out?".into(),
+    ///     experience_type: ExperienceType::OpenQuestion {
+    ///         question: "Why does the nightly build time out?".into(),
+    ///     },
+    ///     embedding: Some(vec![0.1; 384]),
+    ///     ..Default::default()
+    /// })?;
+    /// assert_eq!(db.list_open_questions(collective_id)?.len(), 1);
+    ///
+    /// let answer = db.record_experience(NewExperience {
+    ///     collective_id,
+    ///     content: "The integration suite waits on an unreachable mirror".into(),
+    ///     embedding: Some(vec![0.2; 384]),
+    ///     ..Default::default()
+    /// })?;
+    /// db.answer_question(question, answer)?;
+    /// assert!(db.list_open_questions(collective_id)?.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn answer_question(
+        &self,
+        question_id: ExperienceId,
+        answer_id: ExperienceId,
+    ) -> Result<crate::types::RelationId> {
+        self.store_relation(NewExperienceRelation {
+            source_id: answer_id,
+            target_id: question_id,
+            relation_type: RelationType::Answers,
+            strength: 1.0,
+            metadata: None,
+        })
+    }
+
+    /// Returns the experiences linked as answers to a question, in the
+    /// order they were linked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Experience`] if the question doesn't exist.
+    pub fn get_answers(&self, question_id: ExperienceId) -> Result<Vec<Experience>> {
+        self.storage
+            .get_experience(question_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(question_id)))?;
+        let mut answers = self.get_related_experiences_filtered(
+            question_id,
+            crate::relation::RelationDirection::Incoming,
+            Some(RelationType::Answers),
+        )?;
+        answers.sort_by_key(|(_, relation)| relation.created_at);
+        Ok(answers
+            .into_iter()
+            .map(|(experience, _)| experience)
+            .collect())
+    }
+
+    /// Lists a collective's unanswered questions, oldest first.
+    ///
+    /// Returns active [`OpenQuestion`](crate::ExperienceType::OpenQuestion)
+    /// experiences with no [`Answers`](RelationType::Answers) relation
+    /// pointing at them. Archived questions are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    #[instrument(skip(self))]
+    pub fn list_open_questions(&self, collective_id: CollectiveId) -> Result<Vec<Experience>> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let mut questions = Vec::new();
+        for id in self
+            .storage
+            .list_experience_ids_by_type(collective_id, ExperienceTypeTag::OpenQuestion)?
+        {
+            let Some(experience) = self.storage.get_experience(id)? else {
+                continue;
+            };
+            if experience.archived {
+                continue;
+            }
+            let mut answered = false;
+            for rel_id in self.storage.get_relation_ids_by_target(id)? {
+                if let Some(relation) = self.storage.get_relation(rel_id)? {
+                    if relation.relation_type == RelationType::Answers {
+                        answered = true;
+                        break;
+                    }
+                }
+            }
+            if !answered {
+                questions.push(experience);
+            }
+        }
+        questions.sort_by_key(|experience| (experience.timestamp, *experience.id.as_bytes()));
+        Ok(questions)
+    }
+
+    // =========================================================================
+    // Knowledge Gaps
+    // =========================================================================
+
+    /// Lists what a collective's searches couldn't find.
+    ///
+    /// Searches recorded under
+    /// [`Config::knowledge_gaps`](crate::Config::knowledge_gaps) are
+    /// grouped by embedding similarity into [`KnowledgeGap`]s, most
+    /// repeated first, then most recently hit. Empty if gap tracking is
+    /// off or nothing has been recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// use pulsedb::{Config, EmbeddingDimension, KnowledgeGapConfig, PulseDB};
+    ///
+    /// let config = Config {
+    ///     knowledge_gaps: Some(KnowledgeGapConfig::default()),
+    ///     ..Config::with_hashing_embeddings(EmbeddingDimension::D384)
+    /// };
+    /// let db = PulseDB::open(dir.path().join("test.db"), config)?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// db.search_text(collective_id, "who owns the deploy key?", 5)?;
+    ///
+    /// let gaps = db.list_knowledge_gaps(collective_id)?;
+    /// assert_eq!(gaps[0].queries, vec!["who owns the deploy key?"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn list_knowledge_gaps(&self, collective_id: CollectiveId) -> Result<Vec<KnowledgeGap>> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let cluster_similarity = self
+            .config
+            .knowledge_gaps
+            .clone()
+            .unwrap_or_default()
+            .cluster_similarity;
+        let mut records = self.storage.list_knowledge_gaps(collective_id)?;
+        // Queued gaps are newer than any written one
+        if let Ok(queued) = self.queued_gaps.lock() {
+            records.extend(
+                queued
+                    .iter()
+                    .filter(|record| record.collective_id == collective_id)
+                    .cloned(),
+            );
+        }
+        Ok(cluster_gaps(records, cluster_similarity))
+    }
+
+    /// Deletes a collective's recorded knowledge gaps, e.g. once they've
+    /// been filled. Returns the number of recorded searches removed.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    #[instrument(skip(self))]
+    pub fn clear_knowledge_gaps(&self, collective_id: CollectiveId) -> Result<usize> {
+        self.check_writable()?;
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        let queued = self.drop_queued_gaps(collective_id);
+        let count = self
+            .storage
+            .delete_knowledge_gaps_by_collective(collective_id)?;
+        Ok(count as usize + queued)
+    }
+
+    // =========================================================================
+    // Derived Insights (E3-S02)
+    // =========================================================================
+
+    /// Stores a new derived insight.
+    ///
+    /// Creates a synthesized knowledge record from multiple source experiences.
+    /// The method:
+    /// 1. Validates the input (content, confidence, sources)
+    /// 2. Verifies the collective exists
+    /// 3. Verifies all source experiences exist and belong to the same collective
+    /// 4. Resolves the embedding (generates if Builtin, requires if External)
+    /// 5. Stores the insight with inline embedding
+    /// 6. Inserts into the insight HNSW index
+    ///
+    /// # Arguments
+    ///
+    /// * `insight` - The insight to store (see [`NewDerivedInsight`])
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError`](crate::ValidationError) if input is invalid
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`NotFoundError::Experience`] if any source experience doesn't exist
+    /// - [`ValidationError::InvalidField`] if source experiences belong to
+    ///   different collectives
+    /// - [`ValidationError::DimensionMismatch`] if embedding dimension is wrong
+    #[instrument(skip(self, insight), fields(collective_id = %insight.collective_id))]
+    pub fn store_insight(&self, mut insight: NewDerivedInsight) -> Result<InsightId> {
+        self.check_writable()?;
+        self.run_pre_write_hooks(PendingWrite::Insight(&mut insight))?;
+        let _fence = self.check_collective_writable(insight.collective_id)?;
+        let is_external = self.requires_embeddings();
+
+        // Validate input fields
+        validate_new_insight(&insight)?;
+
+        // Verify collective exists
+        let collective = self
+            .storage
+            .get_collective(insight.collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(insight.collective_id)))?;
+
+        // Verify all source experiences exist and belong to this collective
+        // (one read transaction for the whole batch)
+        if self.config.strict_insight_sources {
+            let owners = self
+                .storage
+                .get_experience_collectives(&insight.source_experience_ids)?;
+            for (source_id, owner) in insight.source_experience_ids.iter().zip(owners) {
+                let owner = owner
+                    .ok_or_else(|| PulseDBError::from(NotFoundError::experience(*source_id)))?;
+                if owner != insight.collective_id {
+                    return Err(PulseDBError::from(ValidationError::invalid_field(
+                        "source_experience_ids",
+                        format!(
+                            "experience {} belongs to collective {}, not {}",
+                            source_id, owner, insight.collective_id
+                        ),
+                    )));
+                }
+            }
+        }
+
+        // Resolve embedding
+        let embedding = match insight.embedding {
+            Some(ref emb) => {
+                // Validate dimension
+                let expected_dim = collective.embedding_dimension as usize;
+                if emb.len() != expected_dim {
+                    return Err(ValidationError::dimension_mismatch(expected_dim, emb.len()).into());
+                }
+                emb.clone()
+            }
+            None => {
+                if is_external {
+                    return Err(PulseDBError::embedding(
+                        "embedding is required when using External embedding provider",
+                    ));
+                }
+                self.embed_text(insight.collective_id, &insight.content)?
+            }
+        };
+
+        let embedding_for_hnsw = embedding.clone();
+        let now = Timestamp::now();
+        let id = InsightId::new();
+
+        // Construct the full insight record
+        let derived_insight = DerivedInsight {
+            id,
+            collective_id: insight.collective_id,
+            content: insight.content,
+            embedding,
+            source_experience_ids: insight.source_experience_ids,
+            insight_type: insight.insight_type,
+            confidence: insight.confidence,
+            domain: insight.domain,
+            created_at: now,
+            updated_at: now,
+        };
+
+        // Write to redb FIRST (source of truth)
+        let _pin = self.pins.pin(insight.collective_id);
+        self.storage.save_insight(&derived_insight)?;
+        self.touch_collective(insight.collective_id);
+
+        // Insert into insight HNSW index (using InsightId→ExperienceId byte conversion)
+        let exp_id = ExperienceId::from_bytes(*id.as_bytes());
+        let insight_vectors = self
+            .insight_vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+        if let Some(index) = insight_vectors.get(&insight.collective_id) {
+            index.insert_experience(exp_id, &embedding_for_hnsw)?;
+        }
+        drop(insight_vectors);
+        self.run_post_write_hooks(CommittedWrite::Insight(&derived_insight));
+
+        info!(
+            id = %id,
+            content = %self.loggable(&derived_insight.content),
+            "Insight stored"
+        );
+        Ok(id)
+    }
+
+    /// Retrieves a derived insight by ID.
+    ///
+    /// Returns `None` if no insight with the given ID exists.
+    #[instrument(skip(self))]
+    pub fn get_insight(&self, id: InsightId) -> Result<Option<DerivedInsight>> {
+        let Some(mut insight) = self.storage.get_insight(id)? else {
+            return Ok(None);
+        };
+        self.run_read_hooks_on_insight(&mut insight)?;
+        Ok(Some(insight))
+    }
+
+    /// Searches for insights semantically similar to the query embedding.
+    ///
+    /// Uses the insight-specific HNSW index for approximate nearest neighbor
+    /// search, then fetches full insight records from storage. Scores follow
+    /// [`Config::score_kind`], as for experience search.
+    ///
+    /// # Arguments
+    ///
+    /// * `collective_id` - The collective to search within
+    /// * `query` - Query embedding vector (must match collective's dimension)
+    /// * `k` - Maximum number of results to return
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::DimensionMismatch`] if `query.len()` doesn't match
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    #[instrument(skip(self, query))]
+    pub fn get_insights(
+        &self,
+        collective_id: CollectiveId,
+        query: &[f32],
+        k: usize,
+    ) -> Result<Vec<(DerivedInsight, f32)>> {
+        // Verify collective exists and check embedding dimension
+        let collective = self
+            .storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let expected_dim = collective.embedding_dimension as usize;
+        if query.len() != expected_dim {
+            return Err(ValidationError::dimension_mismatch(expected_dim, query.len()).into());
+        }
+
+        let ef_search = self.config.hnsw.ef_search;
+        let _pin = self.ensure_indexes_loaded(collective_id)?;
+
+        // Search insight HNSW — returns (ExperienceId, distance) pairs
+        let insight_vectors = self
+            .insight_vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+
+        let candidates = match insight_vectors.get(&collective_id) {
+            Some(index) => index.search_experiences(query, k, ef_search)?,
+            None => return Ok(vec![]),
+        };
+        drop(insight_vectors);
+
+        // Convert ExperienceId back to InsightId and fetch records
+        let mut results = Vec::with_capacity(candidates.len());
+        for (exp_id, distance) in candidates {
+            let insight_id = InsightId::from_bytes(*exp_id.as_bytes());
+            if let Some(mut insight) = self.storage.get_insight(insight_id)? {
+                self.run_read_hooks_on_insight(&mut insight)?;
+                // Convert HNSW distance to similarity, matching search_similar
+                results.push((insight, self.config.score_kind.score(distance)));
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Deletes a derived insight by ID.
+    ///
+    /// Removes the insight from storage and soft-deletes it from the HNSW index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Insight`] if no insight with the given ID exists.
+    #[instrument(skip(self))]
+    pub fn delete_insight(&self, id: InsightId) -> Result<()> {
+        self.check_writable()?;
+        // Read insight first to get collective_id for HNSW lookup
+        let insight = self
+            .storage
+            .get_insight(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::insight(id)))?;
+        let _fence = self.check_collective_writable(insight.collective_id)?;
+
+        // Delete from redb FIRST (source of truth)
+        self.storage.delete_insight(id)?;
+
+        // Soft-delete from insight HNSW (using InsightId→ExperienceId byte conversion)
+        let exp_id = ExperienceId::from_bytes(*id.as_bytes());
+        let insight_vectors = self
+            .insight_vectors
+            .read()
+            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+        if let Some(index) = insight_vectors.get(&insight.collective_id) {
+            index.delete_experience(exp_id)?;
+        }
+
+        info!(id = %id, "Insight deleted");
+        Ok(())
+    }
+
+    /// Returns true if the insight is flagged as degraded.
+    ///
+    /// Insights are degraded when a source experience is deleted under
+    /// [`InsightSourceCascade::MarkDegraded`]. The flag is cleared by
+    /// [`repair_insight_sources()`](Self::repair_insight_sources).
+    pub fn is_insight_degraded(&self, id: InsightId) -> Result<bool> {
+        Ok(self.storage.get_insight_degraded_at(id)?.is_some())
+    }
+
+    /// Lists the degraded insights in a collective.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotFoundError::Collective`] if the collective doesn't exist.
+    pub fn list_degraded_insights(&self, collective_id: CollectiveId) -> Result<Vec<InsightId>> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let mut degraded = Vec::new();
+        for insight_id in self.storage.list_insight_ids_in_collective(collective_id)? {
+            if self.storage.get_insight_degraded_at(insight_id)?.is_some() {
+                degraded.push(insight_id);
+            }
+        }
+        Ok(degraded)
+    }
+
+    /// Removes dangling source references from a collective's insights.
+    ///
+    /// A source is dangling when the experience no longer exists or lives
+    /// in a different collective. Each affected insight has its source
+    /// list rewritten without the dangling IDs; insights left with no
+    /// sources are reported in
+    /// [`InsightRepairReport::orphaned`](crate::InsightRepairReport::orphaned)
+    /// rather than deleted. The degraded flag of every repaired insight is
+    /// cleared.
+    ///
+    /// Run this after deleting experiences under
+    /// [`InsightSourceCascade::MarkDegraded`], or after a bulk import with
+    /// [`Config::strict_insight_sources`] disabled.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    #[instrument(skip(self))]
+    pub fn repair_insight_sources(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<crate::insight::InsightRepairReport> {
+        self.check_writable()?;
+        let _fence = self.check_collective_writable(collective_id)?;
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let mut report = crate::insight::InsightRepairReport::default();
+        for insight_id in self.storage.list_insight_ids_in_collective(collective_id)? {
+            if let Some(insight) = self.storage.get_insight(insight_id)? {
+                self.repair_insight(collective_id, &insight, &mut report)?;
+            }
+        }
+
+        info!(
+            collective = %collective_id,
+            repaired = report.insights_repaired,
+            removed = report.sources_removed,
+            "Insight sources repaired"
+        );
+        Ok(report)
+    }
+
+    /// Drops the dangling sources of one insight of `collective_id`,
+    /// counting the outcome into `report`.
+    fn repair_insight(
+        &self,
+        collective_id: CollectiveId,
+        insight: &DerivedInsight,
+        report: &mut crate::insight::InsightRepairReport,
+    ) -> Result<()> {
+        report.insights_scanned += 1;
+
+        let owners = self
+            .storage
+            .get_experience_collectives(&insight.source_experience_ids)?;
+        let kept: Vec<ExperienceId> = insight
+            .source_experience_ids
+            .iter()
+            .zip(owners)
+            .filter(|(_, owner)| *owner == Some(collective_id))
+            .map(|(id, _)| *id)
+            .collect();
+
+        let removed = insight.source_experience_ids.len() - kept.len();
+        if removed == 0 {
+            // Nothing dangling: a leftover degraded flag is stale
+            if self.storage.get_insight_degraded_at(insight.id)?.is_some() {
+                self.storage.clear_insight_degraded(insight.id)?;
+            }
+            return Ok(());
+        }
+        self.storage.update_insight_sources(insight.id, &kept)?;
+        self.storage.clear_insight_degraded(insight.id)?;
+        report.insights_repaired += 1;
+        report.sources_removed += removed;
+        if kept.is_empty() {
+            report.orphaned.push(insight.id);
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Export and Import
+    // =========================================================================
+
+    /// Returns the embedding model name recorded in export manifests.
+    fn export_embedding_model(&self) -> Option<String> {
+        if self.injected_embedding {
+            return None;
+        }
+        match &self.config.embedding_provider {
+            EmbeddingProvider::Builtin {
+                model_path: None, ..
+            } => Some("all-MiniLM-L6-v2".to_string()),
+            EmbeddingProvider::Builtin {
+                model_path: Some(path),
+                ..
+            } => Some(path.display().to_string()),
+            EmbeddingProvider::Hashing => Some("pulsedb-hashing".to_string()),
+            EmbeddingProvider::External => None,
+        }
+    }
+
+    /// Exports every collective and its records to a single file.
+    ///
+    /// The file starts with an [`ExportManifest`] (schema version,
+    /// embedding model and dimension) followed by a zstd-compressed stream
+    /// of records, each with a CRC32 checksum, and ends with the record
+    /// counts. Records are read and written one at a time, so memory use
+    /// does not grow with the database. The manifest lists what an export
+    /// leaves out. The file is written to a temporary sibling and renamed
+    /// into place, so `path` never holds a partial export.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::Io`] if the file cannot be written
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # db.create_collective("example")?;
+    /// let manifest = db.export(dir.path().join("backup.pulse"))?;
+    /// println!("exported {} experiences", manifest.experience_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, path))]
+    pub fn export(&self, path: impl AsRef<Path>) -> Result<ExportManifest> {
+        let path = path.as_ref();
+        // Captured first: anything written while we read is re-sent by the
+        // next incremental backup, which is harmless since deltas upsert
+        let sequence = self.storage.get_wal_sequence()?;
+
+        let manifest = self.export_manifest(ExportKind::Full, 0, sequence);
+        let manifest = crate::export::write_export(path, manifest, |writer| {
+            let collective_ids = self.write_collective_snapshot(writer)?;
+            self.write_collective_records(writer, &collective_ids)
+        })?;
+
+        info!(
+            path = %path.display(),
+            collectives = manifest.collective_count,
+            experiences = manifest.experience_count,
+            "Export written"
+        );
+        Ok(manifest)
+    }
+
+    /// Writes an incremental backup of everything changed since a cursor.
+    ///
+    /// `since_cursor` is the [`sequence`](ExportManifest::sequence) of the
+    /// previous backup in the chain (full or incremental). The delta is
+    /// read from the changelog: it holds the current state of every
+    /// experience, relation, and insight written since then, tombstones
+    /// for those deleted, and a snapshot of all collectives and their
+    /// hierarchy. Each experience it holds carries its bookmarks, which
+    /// replace the experience's bookmarks on restore; bookmarking alone
+    /// does not change an experience, so bookmarks of unchanged
+    /// experiences are not captured. Like [`export()`](Self::export), the
+    /// delta is streamed to the file one record at a time. Restore a chain
+    /// with [`restore_chain()`](Self::restore_chain).
+    ///
+    /// Changes applied by sync from a remote peer are not recorded in the
+    /// changelog and so are not captured.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `since_cursor` is ahead of
+    ///   the changelog, or the changelog has been compacted past it
+    /// - [`PulseDBError::Io`] if the file cannot be written
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// let base = db.export(dir.path().join("base.pulse"))?;
+    /// db.create_collective("later")?;
+    /// let delta = db.backup_incremental(dir.path().join("delta-1.pulse"), base.sequence)?;
+    /// assert_eq!(delta.base_sequence, base.sequence);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, path))]
+    pub fn backup_incremental(
+        &self,
+        path: impl AsRef<Path>,
+        since_cursor: u64,
+    ) -> Result<ExportManifest> {
+        let path = path.as_ref();
+        let sequence = self.storage.get_wal_sequence()?;
+        if since_cursor > sequence {
+            return Err(ValidationError::invalid_field(
+                "since_cursor",
+                format!(
+                    "{} is ahead of the changelog (current sequence {})",
+                    since_cursor, sequence
+                ),
+            )
+            .into());
+        }
+
+        // Collect each changed record once, in first-change order
+        let mut changed = Vec::new();
+        let mut seen = HashSet::new();
+        let mut cursor = since_cursor;
+        loop {
+            let (events, max_seq) = self
+                .storage
+                .poll_watch_events(cursor, EXPORT_CHANGELOG_BATCH)?;
+            // Sequences are contiguous, so a short first batch means the
+            // events right after the cursor were compacted away
+            let compacted = events.is_empty() || max_seq != since_cursor + events.len() as u64;
+            if cursor == since_cursor && since_cursor < sequence && compacted {
+                return Err(ValidationError::invalid_field(
+                    "since_cursor",
+                    format!(
+                        "changelog has been compacted past sequence {}; take a full export",
+                        since_cursor
+                    ),
+                )
+                .into());
+            }
+            if events.is_empty() {
+                break;
+            }
+            for event in events {
+                if event.entity_type != EntityTypeTag::Collective
+                    && seen.insert((event.entity_type, event.entity_id))
+                {
+                    changed.push((event.entity_type, event.entity_id));
+                }
+            }
+            cursor = max_seq;
+        }
+
+        let manifest = self.export_manifest(ExportKind::Incremental, since_cursor, sequence);
+        let manifest = crate::export::write_export(path, manifest, |writer| {
+            self.write_collective_snapshot(writer)?;
+            let mut deleted = Vec::new();
+            let mut experiences = Vec::new();
+            for &(entity_type, id) in &changed {
+                if entity_type != EntityTypeTag::Experience {
+                    continue;
+                }
+                match self.storage.get_experience(ExperienceId::from_bytes(id))? {
+                    Some(experience) => {
+                        experiences.push(experience.id);
+                        writer.experience(&export_experience(experience))?;
+                    }
+                    None => deleted.push((entity_type, id)),
+                }
+            }
+            for &(entity_type, id) in &changed {
+                if entity_type != EntityTypeTag::Relation {
+                    continue;
+                }
+                match self.storage.get_relation(RelationId::from_bytes(id))? {
+                    Some(relation) => writer.relation(&relation)?,
+                    None => deleted.push((entity_type, id)),
+                }
+            }
+            for &(entity_type, id) in &changed {
+                if entity_type != EntityTypeTag::Insight {
+                    continue;
+                }
+                let insight_id = InsightId::from_bytes(id);
+                match self.storage.get_insight(insight_id)? {
+                    Some(insight) => writer
+                        .insight(&insight, self.storage.get_insight_degraded_at(insight_id)?)?,
+                    None => deleted.push((entity_type, id)),
+                }
+            }
+            for experience_id in experiences {
+                for agent_id in self.storage.list_bookmarking_agent_ids(experience_id)? {
+                    writer.bookmark(&agent_id, experience_id)?;
+                }
+            }
+            for (entity_type, id) in deleted {
+                writer.tombstone(entity_type, id)?;
+            }
+            Ok(())
+        })?;
+
+        info!(
+            path = %path.display(),
+            since = since_cursor,
+            sequence,
+            experiences = manifest.experience_count,
+            tombstones = manifest.tombstone_count,
+            "Incremental backup written"
+        );
+        Ok(manifest)
+    }
+
+    /// Restores a backup chain into a new database.
+    ///
+    /// `paths` is a full export followed by zero or more incremental
+    /// backups, oldest first. Every file is verified and the chain checked
+    /// for gaps before `db_path` is created; the files are then applied
+    /// one record at a time, and the database is opened with `config` and
+    /// its vector indexes rebuilt from the restored records.
+    ///
+    /// The result is the source database as of the last file in `paths`;
+    /// changes made after that backup was taken are not in it.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if the chain is empty, does not
+    ///   start with a full export, mixes databases, has a gap, or
+    ///   `db_path` already exists
+    /// - [`ValidationError::DimensionMismatch`] if the chain's embedding
+    ///   dimension differs from `config`
+    /// - [`StorageError::Corrupted`](crate::StorageError::Corrupted) if any
+    ///   file fails verification
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// use pulsedb::{Config, PulseDB};
+    ///
+    /// let base = db.export(dir.path().join("base.pulse"))?;
+    /// db.create_collective("later")?;
+    /// db.backup_incremental(dir.path().join("delta-1.pulse"), base.sequence)?;
+    ///
+    /// let restored = PulseDB::restore_chain(
+    ///     &[dir.path().join("base.pulse"), dir.path().join("delta-1.pulse")],
+    ///     dir.path().join("restored.db"),
+    ///     Config::default(),
+    /// )?;
+    /// assert_eq!(restored.list_collectives()?.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn restore_chain(
+        paths: &[impl AsRef<Path>],
+        db_path: impl AsRef<Path>,
+        config: Config,
+    ) -> Result<Self> {
+        let db_path = db_path.as_ref();
+        if db_path.exists() {
+            return Err(ValidationError::invalid_field(
+                "db_path",
+                format!("{} already exists", db_path.display()),
+            )
+            .into());
+        }
+        config.validate()?;
+
+        let mut manifests = Vec::with_capacity(paths.len());
+        for path in paths {
+            manifests.push(crate::export::verify_export(path.as_ref())?);
+        }
+        crate::export::validate_chain(&manifests)?;
+        let dimension = config.dimension();
+        if manifests[0].embedding_dimension != dimension {
+            return Err(ValidationError::dimension_mismatch(
+                dimension,
+                manifests[0].embedding_dimension,
+            )
+            .into());
+        }
+
+        {
+            let storage = open_storage(db_path, &config)?;
+            for (position, path) in paths.iter().enumerate() {
+                let mut source = crate::export::open_export(path.as_ref())?;
+                if position == 0 {
+                    crate::export::apply_full(&*storage, &mut source)?;
+                } else {
+                    crate::export::apply_incremental(&*storage, &mut source)?;
+                }
+            }
+            storage.close()?;
+        }
+
+        info!(path = %db_path.display(), files = manifests.len(), "Backup chain restored");
+        Self::open(db_path, config)
+    }
+
+    /// Restores the database as it was at a point in time.
+    ///
+    /// Scans `backup_dir` for export files and picks the newest full
+    /// export taken at or before `upto`, plus every incremental backup of
+    /// the same database taken at or before `upto` that extends it. That
+    /// chain is then restored into a new database at `db_path` exactly as
+    /// [`restore_chain()`](Self::restore_chain) does.
+    ///
+    /// This restores to the last backup taken at or before `upto`, not to
+    /// `upto` itself: changes made between that backup and `upto` are lost,
+    /// so the precision is the backup interval. Files in `backup_dir` that
+    /// are not exports are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if no full export was taken at
+    ///   or before `upto`, or the backups have a gap before it
+    /// - Any error from [`restore_chain()`](Self::restore_chain)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let backups = dir.path().join("backups");
+    /// # std::fs::create_dir(&backups).unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// use pulsedb::{Config, PulseDB, Timestamp};
+    ///
+    /// db.export(backups.join("base.pulse"))?;
+    /// let before_incident = Timestamp::now();
+    ///
+    /// let restored = PulseDB::restore(
+    ///     &backups,
+    ///     dir.path().join("rewound.db"),
+    ///     before_incident,
+    ///     Config::default(),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn restore(
+        backup_dir: impl AsRef<Path>,
+        db_path: impl AsRef<Path>,
+        upto: Timestamp,
+        config: Config,
+    ) -> Result<Self> {
+        let mut candidates = Vec::new();
+        for entry in std::fs::read_dir(backup_dir.as_ref())? {
+            let path = entry?.path();
+            if !path.is_file() {
+                continue;
+            }
+            match crate::export::read_export_manifest(&path) {
+                Ok(manifest) => candidates.push((path, manifest)),
+                Err(e) => debug!(path = %path.display(), error = %e, "Skipping non-export file"),
+            }
+        }
+
+        let chain = crate::export::select_chain(&candidates, upto)?;
+        info!(upto = %upto, files = chain.len(), "Restoring to point in time");
+        Self::restore_chain(&chain, db_path, config)
+    }
+
+    /// Writes a point-in-time copy of the database files to `dir`.
+    ///
+    /// Unlike [`export()`](Self::export), the backup is the database
+    /// itself: `dir/pulse.db` is a redb file holding every table as of a
+    /// single read transaction, and `dir/pulse.db.hnsw/` holds the index
+    /// graphs of the collectives currently in memory. Writers keep
+    /// running while the backup is taken; each write is either fully in
+    /// it or absent. The graphs are saved after the database copy, and
+    /// opening the backup reconciles them with the backed-up records, so
+    /// the indexes always match the data.
+    ///
+    /// Files are written to a staging directory next to `dir` and moved
+    /// into place once complete. A failed backup leaves nothing behind
+    /// and can simply be retried.
+    ///
+    /// Open the backup with [`restore_from()`](Self::restore_from).
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `dir` already holds a backup
+    /// - [`PulseDBError::Io`] if `dir` or the staging directory cannot be
+    ///   written
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// use pulsedb::{Config, PulseDB};
+    ///
+    /// db.backup_to(dir.path().join("nightly"))?;
+    ///
+    /// let restored = PulseDB::restore_from(
+    ///     dir.path().join("nightly"),
+    ///     dir.path().join("restored.db"),
+    ///     Config::default(),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, dir))]
+    pub fn backup_to(&self, dir: impl AsRef<Path>) -> Result<()> {
+        let dir = dir.as_ref();
+        let db_file = dir.join(BACKUP_DB_FILE);
+        if db_file.exists() {
+            return Err(ValidationError::invalid_field(
+                "path",
+                format!("{} already holds a backup", dir.display()),
+            )
+            .into());
+        }
+
+        let name = dir
+            .file_name()
+            .ok_or_else(|| ValidationError::invalid_field("path", "backup path has no name"))?;
+        let mut staging_name = std::ffi::OsString::from(".");
+        staging_name.push(name);
+        staging_name.push(format!(".{}.partial", uuid::Uuid::now_v7()));
+        let staging = dir.with_file_name(staging_name);
+
+        std::fs::create_dir_all(&staging)?;
+        let result = self
+            .write_backup(&staging)
+            .and_then(|()| Self::move_backup_into(&staging, dir));
+        // Best effort: on success nothing, or an emptied directory, is left
+        if staging.exists() {
+            if let Err(e) = std::fs::remove_dir_all(&staging) {
+                warn!(
+                    path = %staging.display(),
+                    error = %e,
+                    "Failed to remove backup staging directory"
+                );
+            }
+        }
+        result?;
+
+        info!(dir = %dir.display(), "Backup written");
+        Ok(())
+    }
+
+    /// Writes the database copy, then the index graphs, into `dir`.
+    ///
+    /// The copy comes first: a write landing in between then shows up in
+    /// the graphs but not the records, which reconciliation on open drops.
+    fn write_backup(&self, dir: &Path) -> Result<()> {
+        let db_file = dir.join(BACKUP_DB_FILE);
+        self.storage.snapshot_to(&db_file)?;
+
+        let mut hnsw_dir = db_file.into_os_string();
+        hnsw_dir.push(".hnsw");
+        self.save_indexes(Path::new(&hnsw_dir))
+    }
+
+    /// Moves a complete backup from `staging` into `dir`.
+    ///
+    /// The database file moves last, so `dir` only ever holds it once the
+    /// rest of the backup is in place.
+    fn move_backup_into(staging: &Path, dir: &Path) -> Result<()> {
+        if !dir.exists() {
+            std::fs::rename(staging, dir)?;
+            return Ok(());
+        }
+
+        let mut hnsw_name = std::ffi::OsString::from(BACKUP_DB_FILE);
+        hnsw_name.push(".hnsw");
+        let staged_hnsw = staging.join(&hnsw_name);
+        if staged_hnsw.exists() {
+            let target = dir.join(&hnsw_name);
+            // Left over from a backup that failed before its database moved
+            if target.exists() {
+                std::fs::remove_dir_all(&target)?;
+            }
+            std::fs::rename(staged_hnsw, target)?;
+        }
+        std::fs::rename(staging.join(BACKUP_DB_FILE), dir.join(BACKUP_DB_FILE))?;
+        Ok(())
+    }
+
+    /// Restores a backup written by [`backup_to()`](Self::backup_to) to
+    /// `db_path` and opens it.
+    ///
+    /// The backup is copied, not moved, so it can be restored again.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if `backup_dir` holds no backup
+    ///   or `db_path` already exists
+    /// - [`PulseDBError::Io`] if the files cannot be copied
+    /// - Any error from [`open()`](Self::open)
+    pub fn restore_from(
+        backup_dir: impl AsRef<Path>,
+        db_path: impl AsRef<Path>,
+        config: Config,
+    ) -> Result<Self> {
+        let backup_dir = backup_dir.as_ref();
+        let db_path = db_path.as_ref();
+        let db_file = backup_dir.join(BACKUP_DB_FILE);
+        if !db_file.is_file() {
+            return Err(ValidationError::invalid_field(
+                "backup_dir",
+                format!("{} holds no backup", backup_dir.display()),
+            )
+            .into());
+        }
+        if db_path.exists() {
+            return Err(ValidationError::invalid_field(
+                "db_path",
+                format!("{} already exists", db_path.display()),
+            )
+            .into());
+        }
+
+        std::fs::copy(&db_file, db_path)?;
+        let mut source_hnsw = db_file.into_os_string();
+        source_hnsw.push(".hnsw");
+        let mut target_hnsw = db_path.as_os_str().to_owned();
+        target_hnsw.push(".hnsw");
+        if Path::new(&source_hnsw).is_dir() {
+            std::fs::create_dir_all(&target_hnsw)?;
+            for entry in std::fs::read_dir(&source_hnsw)? {
+                let entry = entry?;
+                if entry.file_type()?.is_file() {
+                    std::fs::copy(
+                        entry.path(),
+                        Path::new(&target_hnsw).join(entry.file_name()),
+                    )?;
+                }
+            }
+        }
+
+        info!(backup = %backup_dir.display(), path = %db_path.display(), "Restoring backup");
+        Self::open(db_path, config)
+    }
+
+    /// Writes a collective's experiences and insights as a fine-tuning
+    /// dataset.
+    ///
+    /// Each matching record becomes one instruction-tuning example, laid
+    /// out by the format's [`TrainingTemplate`](crate::TrainingTemplate):
+    ///
+    /// | Source | Instruction | Input | Output |
+    /// |--------|-------------|-------|--------|
+    /// | Experience with a lesson | a question for its type | content | [`lesson()`](crate::ExperienceType::lesson) |
+    /// | [`Generic`](crate::ExperienceType::Generic) experience | "What was learned about {domains}?" | — | content |
+    /// | Insight | "What has been learned about {domains}?" | — | content |
+    ///
+    /// Records are read like
+    /// [`get_experience()`](Self::get_experience) reads them — external
+    /// content resolved, read hooks applied — so redaction hooks also
+    /// redact the dataset. Experiences pending review are never exported.
+    ///
+    /// `filter` selects experiences as in search, including
+    /// [`SearchFilter::include_descendants`]. Insights are matched on
+    /// domains, confidence, and creation time, and are left out entirely
+    /// when the filter restricts experience types, models, or tools.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Io`] if `writer` fails
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use pulsedb::{SearchFilter, TrainingFormat, TrainingTemplate};
+    ///
+    /// let mut dataset = Vec::new();
+    /// let format = TrainingFormat::Jsonl {
+    ///     template: TrainingTemplate::Chat { system: None },
+    /// };
+    /// let report =
+    ///     db.export_training_data(collective_id, format, &SearchFilter::default(), &mut dataset)?;
+    /// println!("{} examples", report.experiences + report.insights);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, filter, writer))]
+    pub fn export_training_data(
+        &self,
+        collective_id: CollectiveId,
+        format: TrainingFormat,
+        filter: &SearchFilter,
+        mut writer: impl Write,
+    ) -> Result<TrainingExportReport> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let with_insights =
+            filter.experience_types.is_none() && filter.models.is_none() && filter.tools.is_none();
+        let mut report = TrainingExportReport::default();
+        for cid in self.search_scope(collective_id, filter)? {
+            let ids = self
+                .storage
+                .list_experience_ids_in_collective(cid)?
+                .into_iter()
+                .map(|id| (id, ()))
+                .collect();
+            for (id, ()) in self.prescreen(ids, filter)? {
+                if self.storage.is_experience_pending(cid, id)? {
+                    continue;
+                }
+                let Some(experience) = self.get_experience(id)? else {
+                    continue;
+                };
+                if filter.matches(&experience) {
+                    TrainingExample::from_experience(&experience).write(&format, &mut writer)?;
+                    report.experiences += 1;
+                }
+            }
+
+            if !with_insights {
+                continue;
+            }
+            for insight_id in self.storage.list_insight_ids_in_collective(cid)? {
+                let Some(insight) = self.get_insight(insight_id)? else {
+                    continue;
+                };
+                let in_domain = filter
+                    .domains
+                    .as_ref()
+                    .is_none_or(|domains| insight.domain.iter().any(|d| domains.contains(d)));
+                if in_domain
+                    && filter
+                        .min_confidence
+                        .is_none_or(|min| insight.confidence >= min)
+                    && filter.since.is_none_or(|since| insight.created_at >= since)
+                    && filter.until.is_none_or(|until| insight.created_at < until)
+                {
+                    TrainingExample::from_insight(&insight).write(&format, &mut writer)?;
+                    report.insights += 1;
+                }
+            }
+        }
+        writer.flush()?;
+        Ok(report)
+    }
+
+    /// Writes every collective and its parent link, returning their IDs.
+    fn write_collective_snapshot<W: Write>(
+        &self,
+        writer: &mut ExportWriter<W>,
+    ) -> Result<Vec<CollectiveId>> {
+        let mut collective_ids = Vec::new();
+        for collective in self.storage.list_collectives()? {
+            let parent = self.storage.get_collective_parent(collective.id)?;
+            writer.collective(&collective, parent)?;
+            collective_ids.push(collective.id);
+        }
+        Ok(collective_ids)
+    }
+
+    /// Writes the experiences, relations, insights, and bookmarks of the
+    /// given collectives, reading one record at a time.
+    fn write_collective_records<W: Write>(
+        &self,
+        writer: &mut ExportWriter<W>,
+        collective_ids: &[CollectiveId],
+    ) -> Result<()> {
+        // Only bookmarks of exported experiences, never of later ones
+        let mut experiences = Vec::new();
+        for &cid in collective_ids {
+            for exp_id in self.storage.list_experience_ids_in_collective(cid)? {
+                if let Some(experience) = self.storage.get_experience(exp_id)? {
+                    writer.experience(&export_experience(experience))?;
+                    experiences.push(exp_id);
+                }
+            }
+        }
+        for &cid in collective_ids {
+            for rel_id in self.storage.list_relation_ids_in_collective(cid)? {
+                if let Some(relation) = self.storage.get_relation(rel_id)? {
+                    writer.relation(&relation)?;
+                }
+            }
+        }
+        for &cid in collective_ids {
+            for insight_id in self.storage.list_insight_ids_in_collective(cid)? {
+                if let Some(insight) = self.storage.get_insight(insight_id)? {
+                    let degraded_at = self.storage.get_insight_degraded_at(insight_id)?;
+                    writer.insight(&insight, degraded_at)?;
+                }
+            }
+        }
+        for exp_id in experiences {
+            for agent_id in self.storage.list_bookmarking_agent_ids(exp_id)? {
+                writer.bookmark(&agent_id, exp_id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the manifest header for an export; counts are filled in as
+    /// the records are written.
+    fn export_manifest(
+        &self,
+        kind: ExportKind,
+        base_sequence: u64,
+        sequence: u64,
+    ) -> ExportManifest {
+        let metadata = self.storage.metadata();
+        ExportManifest {
+            format_version: 0,
+            kind,
+            source_created_at: metadata.created_at,
+            base_sequence,
+            sequence,
+            schema_version: metadata.schema_version,
+            created_at: Timestamp::now(),
+            embedding_dimension: self.embedding_dimension(),
+            embedding_model: self.export_embedding_model(),
+            collective_count: 0,
+            experience_count: 0,
+            relation_count: 0,
+            insight_count: 0,
+            bookmark_count: 0,
+            tombstone_count: 0,
+        }
+    }
+
+    /// Verifies an export file without opening a database.
+    ///
+    /// Checks the header, decompresses every record, and compares each
+    /// record's checksum, and the record counts, against the file.
+    ///
+    /// # Errors
+    ///
+    /// - [`StorageError::Corrupted`](crate::StorageError::Corrupted) if any
+    ///   check fails
+    /// - [`PulseDBError::Io`] if the file cannot be read
+    pub fn verify_export(path: impl AsRef<Path>) -> Result<ExportManifest> {
+        crate::export::verify_export(path.as_ref())
+    }
+
+    /// Imports an export file into this database.
+    ///
+    /// The whole file is verified in a first pass before anything is
+    /// written: a corrupt file, a different embedding dimension, or a
+    /// different embedding model (when both sides name one) is rejected
+    /// with nothing applied. A second pass then applies it one record at a
+    /// time. Records whose ID already exists are skipped, not overwritten,
+    /// so importing the same file twice is harmless.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] if the database is read-only
+    /// - [`PulseDBError::Busy`] if an existing target collective is frozen
+    /// - [`StorageError::Corrupted`](crate::StorageError::Corrupted) if the
+    ///   file fails verification
+    /// - [`ValidationError::DimensionMismatch`] if the embedding dimension
+    ///   differs from this database's
+    /// - [`ValidationError::InvalidField`] if the embedding model differs
+    #[instrument(skip(self, path))]
+    pub fn import(&self, path: impl AsRef<Path>) -> Result<ImportReport> {
+        self.import_from(path.as_ref(), None)
+    }
+
+    /// Imports an export file, loading vector indexes from snapshots
+    /// instead of rebuilding them.
+    ///
+    /// Behaves like [`import()`](Self::import), except that each collective
+    /// the import creates looks for an index snapshot in
+    /// `{snapshot_dir}/{collective_id}/`, as written by
+    /// [`export_index_snapshot()`](Self::export_index_snapshot). Collectives
+    /// with a snapshot load its graphs, then catch up on any experience the
+    /// snapshot missed; the rest are indexed as usual. Every snapshot is
+    /// verified before anything is written.
+    ///
+    /// # Errors
+    ///
+    /// Everything [`import()`](Self::import) returns, plus
+    /// [`StorageError::Corrupted`](crate::StorageError::Corrupted) if a
+    /// snapshot fails verification or belongs to another collective.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let primary = pulsedb::PulseDB::open(dir.path().join("primary.db"), pulsedb::Config::default())?;
+    /// # let collective_id = primary.create_collective("example")?;
+    /// let export = dir.path().join("data.pulse");
+    /// let snapshots = dir.path().join("indexes");
+    /// primary.export(&export)?;
+    /// primary.export_index_snapshot(collective_id, snapshots.join(collective_id.to_string()))?;
+    ///
+    /// let replica = pulsedb::PulseDB::open(dir.path().join("replica.db"), pulsedb::Config::default())?;
+    /// replica.import_with_index_snapshots(&export, &snapshots)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, path, snapshot_dir))]
+    pub fn import_with_index_snapshots(
+        &self,
+        path: impl AsRef<Path>,
+        snapshot_dir: impl AsRef<Path>,
+    ) -> Result<ImportReport> {
+        self.import_from(path.as_ref(), Some(snapshot_dir.as_ref()))
+    }
+
+    /// Shared body of [`import()`](Self::import) and
+    /// [`import_with_index_snapshots()`](Self::import_with_index_snapshots).
+    fn import_from(&self, path: &Path, snapshot_dir: Option<&Path>) -> Result<ImportReport> {
+        self.check_writable()?;
+        // A first pass verifies the whole file before anything is written
+        let manifest = crate::export::verify_export(path)?;
+
+        if manifest.kind != ExportKind::Full {
+            return Err(ValidationError::invalid_field(
+                "path",
+                "only full exports can be imported; apply incremental backups with \
+                 restore_chain and collective exports with import_collective",
+            )
+            .into());
+        }
+        if manifest.embedding_dimension != self.embedding_dimension() {
+            return Err(ValidationError::dimension_mismatch(
+                self.embedding_dimension(),
+                manifest.embedding_dimension,
+            )
+            .into());
+        }
+        if let (Some(ours), Some(theirs)) =
+            (self.export_embedding_model(), &manifest.embedding_model)
+        {
+            if &ours != theirs {
+                return Err(ValidationError::invalid_field(
+                    "embedding_model",
+                    format!("export uses '{}', database uses '{}'", theirs, ours),
+                )
+                .into());
+            }
+        }
+
+        let mut source = crate::export::open_export(path)?;
+        let (collectives, mut next) = source.read_collectives()?;
+
+        // Refuse before writing anything if an existing target is frozen
+        let mut created = HashSet::new();
+        let mut _fences = Vec::new();
+        for (collective, _) in &collectives {
+            if self.storage.get_collective(collective.id)?.is_some() {
+                _fences.push(self.check_collective_writable(collective.id)?);
+            } else {
+                created.insert(collective.id);
+            }
+        }
+
+        let mut snapshots = HashMap::new();
+        if let Some(snapshot_dir) = snapshot_dir {
+            for &id in &created {
+                let dir = snapshot_dir.join(id.to_string());
+                if snapshot::snapshot_exists(&dir) {
+                    snapshots.insert(id, self.load_index_snapshot(id, &dir)?.1);
+                }
+            }
+        }
+
+        let mut report = ImportReport::default();
+        for (collective, _) in &collectives {
+            if created.contains(&collective.id) {
+                self.register_collective(collective)?;
+                report.collectives += 1;
+            } else {
+                report.skipped += 1;
+            }
+        }
+        // Only link collectives this import created, so existing
+        // hierarchies are never rewired (and cannot gain a cycle)
+        for (collective, parent) in &collectives {
+            if let Some(parent) = parent {
+                if created.contains(&collective.id) {
+                    self.storage
+                        .set_collective_parent(collective.id, Some(*parent))?;
+                }
+            }
+        }
+
+        while let Some(record) = next
+            .take()
+            .map(Ok)
+            .or_else(|| source.next_record().transpose())
+        {
+            match record? {
+                ExportRecord::Experience(record) => {
+                    if self.storage.get_experience(record.0.id)?.is_some() {
+                        report.skipped += 1;
+                        continue;
+                    }
+                    let experience = restore_experience(*record);
+                    self.storage.save_experience(&experience)?;
+                    // Snapshot collectives are indexed when it is installed below
+                    if !snapshots.contains_key(&experience.collective_id) {
+                        let vectors = self
+                            .vectors
+                            .read()
+                            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+                        if let Some(index) = vectors.get(&experience.collective_id) {
+                            index.insert_experience(experience.id, &experience.embedding)?;
+                        }
+                    }
+                    report.experiences += 1;
+                }
+                ExportRecord::Relation(relation) => {
+                    if self.storage.get_relation(relation.id)?.is_some() {
+                        report.skipped += 1;
+                        continue;
+                    }
+                    self.storage.save_relation(&relation)?;
+                    report.relations += 1;
+                }
+                ExportRecord::Insight(insight, degraded_at) => {
+                    if self.storage.get_insight(insight.id)?.is_some() {
+                        report.skipped += 1;
+                        continue;
+                    }
+                    self.storage.save_insight(&insight)?;
+                    if let Some(at) = degraded_at {
+                        self.storage.mark_insight_degraded(insight.id, at)?;
+                    }
+                    let insight_vectors = self
+                        .insight_vectors
+                        .read()
+                        .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+                    if let Some(index) = insight_vectors.get(&insight.collective_id) {
+                        let exp_id = ExperienceId::from_bytes(*insight.id.as_bytes());
+                        index.insert_experience(exp_id, &insight.embedding)?;
+                    }
+                    report.insights += 1;
+                }
+                ExportRecord::Bookmark(agent_id, experience_id) => {
+                    if self.storage.add_bookmark(&agent_id, experience_id)? {
+                        report.bookmarks += 1;
+                    } else {
+                        report.skipped += 1;
+                    }
+                }
+                ExportRecord::Collective(..) | ExportRecord::Tombstone(..) => {
+                    return Err(
+                        StorageError::corrupted("full export holds a record out of place").into(),
+                    );
+                }
+            }
+        }
+        for (id, index) in snapshots {
+            self.install_snapshot_index(id, index)?;
+        }
+
+        info!(
+            path = %path.display(),
+            collectives = report.collectives,
+            experiences = report.experiences,
+            skipped = report.skipped,
+            "Export imported"
+        );
+        Ok(report)
+    }
+
+    /// Streams one collective to a portable archive.
+    ///
+    /// Writes the collective with every experience (with its embedding),
+    /// relation, insight, and bookmark, archived ones included, in the
+    /// format of [`export()`](Self::export), with an
+    /// [`ExportKind::Collective`] manifest. Records are read and written one
+    /// at a time, so memory use does not grow with the collective.
+    /// Sub-collectives are not included.
+    ///
+    /// Read it back, on this machine or another, with
+    /// [`import_collective()`](Self::import_collective).
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Io`] if writing fails
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// let mut archive = Vec::new();
+    /// db.export_collective(collective_id, &mut archive)?;
+    ///
+    /// let other = pulsedb::PulseDB::open(dir.path().join("other.db"), pulsedb::Config::default())?;
+    /// let report = other.import_collective(archive.as_slice())?;
+    /// assert_eq!(report.collective_id, collective_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, writer))]
+    pub fn export_collective(
+        &self,
+        collective_id: CollectiveId,
+        writer: impl Write,
+    ) -> Result<CollectiveExportReport> {
+        let collective = self
+            .storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        let sequence = self.storage.get_wal_sequence()?;
+
+        let manifest = self.export_manifest(ExportKind::Collective, 0, sequence);
+        let mut export = ExportWriter::new(writer, manifest)?;
+        // Imported collectives are always top-level
+        export.collective(&collective, None)?;
+        self.write_collective_records(&mut export, &[collective_id])?;
+        let (mut writer, manifest) = export.finish()?;
+        writer.flush()?;
+
+        let report = CollectiveExportReport {
+            experiences: manifest.experience_count,
+            relations: manifest.relation_count,
+            insights: manifest.insight_count,
+            bookmarks: manifest.bookmark_count,
+        };
+        info!(
+            collective = %collective_id,
+            experiences = report.experiences,
+            relations = report.relations,
+            insights = report.insights,
+            "Collective exported"
+        );
+        Ok(report)
+    }
+
+    /// Imports a collective archive as a new collective.
+    ///
+    /// Reads an archive written by
+    /// [`export_collective()`](Self::export_collective) record by record,
+    /// writing each one and adding it to the vector indexes as it arrives,
+    /// so the index is built incrementally rather than in one pass at the
+    /// end. The collective keeps its archived ID and name unless the ID is
+    /// taken, in which case it gets a fresh one; likewise every experience,
+    /// relation, and insight whose ID already exists is given a fresh ID,
+    /// and the relations, insights, and bookmarks referring to it are
+    /// rewritten to match. The imported collective is always top-level.
+    ///
+    /// Each record is verified before it is applied. If the archive turns
+    /// out to be corrupt or truncated partway through, the collective
+    /// created so far is deleted again before the error is returned.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] if the database is read-only
+    /// - [`StorageError::Corrupted`](crate::StorageError::Corrupted) if the
+    ///   archive fails verification
+    /// - [`ValidationError::DimensionMismatch`] if the embedding dimension
+    ///   differs from this database's
+    /// - [`ValidationError::InvalidField`] if the embedding model differs,
+    ///   or the export is not a collective export
+    #[instrument(skip(self, reader))]
+    pub fn import_collective(&self, reader: impl Read) -> Result<CollectiveImportReport> {
+        self.check_writable()?;
+        let mut source = crate::export::open_stream(reader)?;
+        let manifest = source.manifest();
+
+        if manifest.kind != ExportKind::Collective {
+            return Err(ValidationError::invalid_field(
+                "reader",
+                "not a collective export; import full exports with import",
+            )
+            .into());
+        }
+        if manifest.embedding_dimension != self.embedding_dimension() {
+            return Err(ValidationError::dimension_mismatch(
+                self.embedding_dimension(),
+                manifest.embedding_dimension,
+            )
+            .into());
+        }
+        if let (Some(ours), Some(theirs)) =
+            (self.export_embedding_model(), &manifest.embedding_model)
+        {
+            if &ours != theirs {
+                return Err(ValidationError::invalid_field(
+                    "embedding_model",
+                    format!("archive uses '{}', database uses '{}'", theirs, ours),
+                )
+                .into());
+            }
+        }
+
+        let (collectives, next) = source.read_collectives()?;
+        let Ok([(mut collective, _)]) = <[_; 1]>::try_from(collectives) else {
+            return Err(StorageError::corrupted(
+                "collective export must hold exactly one collective",
+            )
+            .into());
+        };
+        let mut report = CollectiveImportReport {
+            collective_id: collective.id,
+            experiences: 0,
+            relations: 0,
+            insights: 0,
+            bookmarks: 0,
+            remapped: 0,
+        };
+        if self.storage.get_collective(collective.id)?.is_some() {
+            collective.id = CollectiveId::new();
+            report.collective_id = collective.id;
+            report.remapped += 1;
+        }
+        collective.embedding_dimension = self.embedding_dimension() as u16;
+        self.register_collective(&collective)?;
+
+        if let Err(err) = self.import_collective_records(&mut source, next, &mut report) {
+            if let Err(cleanup) = self.delete_collective(collective.id) {
+                warn!(
+                    collective = %collective.id,
+                    error = %cleanup,
+                    "Failed to remove partially imported collective"
+                );
+            }
+            return Err(err);
+        }
+
+        info!(
+            collective = %report.collective_id,
+            experiences = report.experiences,
+            remapped = report.remapped,
+            "Collective imported"
+        );
+        Ok(report)
+    }
+
+    /// Applies the records of a collective export, starting with `next`,
+    /// to the collective named in `report`, remapping IDs that already
+    /// exist.
+    fn import_collective_records(
+        &self,
+        source: &mut ExportReader<impl Read>,
+        mut next: Option<ExportRecord>,
+        report: &mut CollectiveImportReport,
+    ) -> Result<()> {
+        let cid = report.collective_id;
+        let mut experience_ids: HashMap<ExperienceId, ExperienceId> = HashMap::new();
+        let remap = |ids: &HashMap<ExperienceId, ExperienceId>, id: ExperienceId| {
+            ids.get(&id).copied().ok_or_else(|| {
+                PulseDBError::from(StorageError::corrupted(format!(
+                    "archive refers to experience {} it does not contain",
+                    id
+                )))
+            })
+        };
+
+        while let Some(record) = next
+            .take()
+            .map(Ok)
+            .or_else(|| source.next_record().transpose())
+        {
+            match record? {
+                ExportRecord::Experience(record) => {
+                    let mut experience = restore_experience(*record);
+                    if experience.embedding.len() != self.embedding_dimension() {
+                        return Err(ValidationError::dimension_mismatch(
+                            self.embedding_dimension(),
+                            experience.embedding.len(),
+                        )
+                        .into());
+                    }
+                    let archived_id = experience.id;
+                    if self.storage.get_experience(archived_id)?.is_some() {
+                        experience.id = ExperienceId::new();
+                        report.remapped += 1;
+                    }
+                    experience_ids.insert(archived_id, experience.id);
+                    experience.collective_id = cid;
+                    self.storage.save_experience(&experience)?;
+                    let vectors = self
+                        .vectors
+                        .read()
+                        .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+                    if let Some(index) = vectors.get(&cid) {
+                        index.insert_experience(experience.id, &experience.embedding)?;
+                    }
+                    report.experiences += 1;
+                }
+                ExportRecord::Relation(mut relation) => {
+                    relation.source_id = remap(&experience_ids, relation.source_id)?;
+                    relation.target_id = remap(&experience_ids, relation.target_id)?;
+                    if self.storage.get_relation(relation.id)?.is_some() {
+                        relation.id = RelationId::new();
+                        report.remapped += 1;
+                    }
+                    self.storage.save_relation(&relation)?;
+                    report.relations += 1;
+                }
+                ExportRecord::Insight(mut insight, degraded_at) => {
+                    if insight.embedding.len() != self.embedding_dimension() {
+                        return Err(ValidationError::dimension_mismatch(
+                            self.embedding_dimension(),
+                            insight.embedding.len(),
+                        )
+                        .into());
+                    }
+                    insight.source_experience_ids = insight
+                        .source_experience_ids
+                        .iter()
+                        .map(|&id| remap(&experience_ids, id))
+                        .collect::<Result<_>>()?;
+                    if self.storage.get_insight(insight.id)?.is_some() {
+                        insight.id = InsightId::new();
+                        report.remapped += 1;
+                    }
+                    insight.collective_id = cid;
+                    self.storage.save_insight(&insight)?;
+                    if let Some(at) = degraded_at {
+                        self.storage.mark_insight_degraded(insight.id, at)?;
+                    }
+                    let insight_vectors = self
+                        .insight_vectors
+                        .read()
+                        .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+                    if let Some(index) = insight_vectors.get(&cid) {
+                        let exp_id = ExperienceId::from_bytes(*insight.id.as_bytes());
+                        index.insert_experience(exp_id, &insight.embedding)?;
+                    }
+                    report.insights += 1;
+                }
+                ExportRecord::Bookmark(agent_id, experience_id) => {
+                    let experience_id = remap(&experience_ids, experience_id)?;
+                    if self.storage.add_bookmark(&agent_id, experience_id)? {
+                        report.bookmarks += 1;
+                    }
+                }
+                ExportRecord::Collective(..) | ExportRecord::Tombstone(..) => {
+                    return Err(StorageError::corrupted(
+                        "collective export holds a record out of place",
+                    )
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a snapshot of a collective's experience index to `dir`.
+    ///
+    /// The snapshot holds the HNSW graphs and ID mappings, independent of
+    /// redb, so a replica importing the data can load the index with
+    /// [`restore_index_snapshot()`](Self::restore_index_snapshot) or
+    /// [`import_with_index_snapshots()`](Self::import_with_index_snapshots)
+    /// instead of rebuilding it. An earlier snapshot in `dir` is replaced.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`ValidationError::InvalidField`] if the collective uses an IVF
+    ///   index, which lives in its own data file
+    /// - [`PulseDBError::Io`] if the snapshot cannot be written
+    #[instrument(skip(self, dir))]
+    pub fn export_index_snapshot(
+        &self,
+        collective_id: CollectiveId,
+        dir: impl AsRef<Path>,
+    ) -> Result<IndexSnapshotManifest> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let manifest = self
+            .with_vector_index(collective_id, |index| match index {
+                CollectiveIndex::Hnsw(index) => {
+                    snapshot::write_snapshot(index, collective_id, dir.as_ref())
+                }
+                CollectiveIndex::Ivf(_) | CollectiveIndex::Custom(_) => {
+                    Err(ValidationError::invalid_field(
+                        "collective_id",
+                        "index snapshots cover HNSW indexes only",
+                    )
+                    .into())
+                }
+            })?
+            .ok_or_else(|| PulseDBError::vector("No vector index for collective"))?;
+
+        info!(
+            collective = %collective_id,
+            vectors = manifest.vector_count,
+            segments = manifest.segments.len(),
+            "Index snapshot exported"
+        );
+        Ok(manifest)
+    }
+
+    /// Replaces a collective's experience index with a snapshot.
+    ///
+    /// The snapshot is verified, loaded, and reconciled with stored
+    /// embeddings: experiences recorded after the snapshot are inserted
+    /// and those deleted since are dropped, so a slightly stale snapshot
+    /// is safe to restore.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    /// - [`ValidationError::InvalidField`] if the collective uses an IVF index
+    /// - [`ValidationError::DimensionMismatch`] if the snapshot's embedding
+    ///   dimension differs from the collective's
+    /// - [`StorageError::Corrupted`](crate::StorageError::Corrupted) if the
+    ///   snapshot fails verification or belongs to another collective
+    #[instrument(skip(self, dir))]
+    pub fn restore_index_snapshot(
+        &self,
+        collective_id: CollectiveId,
+        dir: impl AsRef<Path>,
+    ) -> Result<IndexSnapshotManifest> {
+        self.check_writable()?;
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        let _fence = self.check_collective_writable(collective_id)?;
+        if self.config.vector_backend.is_some()
+            || self.storage.get_collective_index_kind(collective_id)? != VectorIndexKind::Hnsw
+        {
+            return Err(ValidationError::invalid_field(
+                "collective_id",
+                "index snapshots cover HNSW indexes only",
+            )
+            .into());
+        }
+
+        let (manifest, index) = self.load_index_snapshot(collective_id, dir.as_ref())?;
+        // Keep the insight index resident alongside the restored one
+        let _pin = self.ensure_indexes_loaded(collective_id)?;
+        self.install_snapshot_index(collective_id, index)?;
+
+        info!(
+            collective = %collective_id,
+            vectors = manifest.vector_count,
+            "Index snapshot restored"
+        );
+        Ok(manifest)
+    }
+
+    /// Verifies and loads the snapshot in `dir`, checking it was taken of
+    /// `collective_id` at this database's embedding dimension.
+    fn load_index_snapshot(
+        &self,
+        collective_id: CollectiveId,
+        dir: &Path,
+    ) -> Result<(IndexSnapshotManifest, HnswIndex)> {
+        let (manifest, index) = snapshot::load_snapshot(dir, &self.config.hnsw)?;
+        if manifest.collective_id != collective_id {
+            return Err(StorageError::corrupted(format!(
+                "index snapshot belongs to collective {}",
+                manifest.collective_id
+            ))
+            .into());
+        }
+        if manifest.embedding_dimension != self.embedding_dimension() {
+            return Err(ValidationError::dimension_mismatch(
+                self.embedding_dimension(),
+                manifest.embedding_dimension,
+            )
+            .into());
+        }
+        Ok((manifest, index))
+    }
+
+    /// Reconciles a loaded snapshot with stored embeddings and swaps it in.
+    ///
+    /// Runs under the map write lock, so a concurrent write either lands in
+    /// redb before the reconcile reads it or finds the new index afterwards.
+    fn install_snapshot_index(&self, collective_id: CollectiveId, index: HnswIndex) -> Result<()> {
+        let mut vectors = self
+            .vectors
+            .write()
+            .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?;
+
+        let stored: HashSet<ExperienceId> = self
+            .storage
+            .list_experience_ids_in_collective(collective_id)?
+            .into_iter()
+            .collect();
+        let mut dropped = 0;
+        for exp_id in index.experience_ids() {
+            if !stored.contains(&exp_id) {
+                index.delete_experience(exp_id)?;
+                dropped += 1;
+            }
+        }
+        let mut inserted = 0;
+        for &exp_id in &stored {
+            if index.contains(exp_id) {
+                continue;
+            }
+            if let Some(embedding) = self.storage.get_embedding(exp_id)? {
+                index.insert_experience(exp_id, &embedding)?;
+                inserted += 1;
+            }
+        }
+        debug!(collective = %collective_id, inserted, dropped, "Reconciled index snapshot");
+
+        drop(vectors.insert(collective_id, CollectiveIndex::Hnsw(index)));
+        drop(vectors);
+        self.touch_collective(collective_id);
+        Ok(())
+    }
+
+    // =========================================================================
+    // Planned Maintenance
+    // =========================================================================
+
+    /// Computes what maintenance would do to a collective, without doing it.
+    ///
+    /// The returned [`MaintenancePlan`] lists every experience that would be
+    /// archived or merged away, every stale activity and lapsed lease that
+    /// would be expired, every insight whose sources would be repaired, and
+    /// an estimate of the bytes reclaimed. Inspect it (or require approval)
+    /// before passing it to [`run_maintenance()`](Self::run_maintenance).
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`ValidationError::InvalidField`] if the policy is invalid
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use pulsedb::MaintenancePolicy;
+    ///
+    /// let plan = db.plan_maintenance(collective_id, MaintenancePolicy::default())?;
+    /// println!("would reclaim ~{} bytes", plan.bytes_reclaimed);
+    /// if !plan.is_empty() {
+    ///     db.run_maintenance(&plan)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, policy))]
+    pub fn plan_maintenance(
+        &self,
+        collective_id: CollectiveId,
+        policy: MaintenancePolicy,
+    ) -> Result<MaintenancePlan> {
+        validate_maintenance_policy(&policy)?;
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let now = Timestamp::now();
+        let mut plan = MaintenancePlan {
+            collective_id,
+            planned_at: now,
+            archive: vec![],
+            expire_activities: vec![],
+            expire_locks: vec![],
+            merge: vec![],
+            repair_insights: vec![],
+            bytes_reclaimed: 0,
+        };
+
+        // Experiences: duplicates first, so merged copies aren't also archived
+        if policy.merge_duplicates || policy.archive_older_than.is_some() {
+            let archive_cutoff = policy
+                .archive_older_than
+                .map(|age| now.as_millis() - age.as_millis() as i64);
+            let mut first_by_content: HashMap<String, usize> = HashMap::new();
+
+            // Archive-only plans read just the day buckets before the cutoff
+            let candidates = match archive_cutoff {
+                Some(cutoff) if !policy.merge_duplicates => {
+                    let mut ids: Vec<ExperienceId> = self
+                        .storage
+                        .get_experience_ids_in_range(
+                            collective_id,
+                            None,
+                            Some(Timestamp::from_millis(cutoff)),
+                            usize::MAX,
+                        )?
+                        .into_iter()
+                        .map(|(id, _)| id)
+                        .collect();
+                    ids.reverse();
+                    ids
+                }
+                _ => self
+                    .storage
+                    .list_experience_ids_in_collective(collective_id)?,
+            };
+
+            // Index order is oldest first, so the first copy seen is kept
+            for id in candidates {
+                let Some(experience) = self.storage.get_experience(id)? else {
+                    continue;
+                };
+
+                if policy.merge_duplicates {
+                    match first_by_content.entry(experience.content.trim().to_string()) {
+                        Entry::Occupied(slot) => {
+                            let group = &mut plan.merge[*slot.get()];
+                            group.duplicates.push(id);
+                            plan.bytes_reclaimed +=
+                                bincode::serialized_size(&experience).unwrap_or(0);
+                            continue;
+                        }
+                        Entry::Vacant(slot) => {
+                            slot.insert(plan.merge.len());
+                            plan.merge.push(DuplicateGroup {
+                                keep: id,
+                                duplicates: vec![],
+                            });
+                        }
+                    }
+                }
+
+                if let Some(cutoff) = archive_cutoff {
+                    let below = policy
+                        .archive_below_importance
+                        .is_none_or(|max| experience.importance < max);
+                    if !experience.archived && experience.timestamp.as_millis() < cutoff && below {
+                        plan.archive.push(id);
+                    }
+                }
+            }
+            plan.merge.retain(|group| !group.duplicates.is_empty());
+        }
+
+        if policy.expire_stale_activities {
+            let cutoff = now.as_millis() - self.config.activity.stale_threshold.as_millis() as i64;
+            for activity in self.storage.list_activities_in_collective(collective_id)? {
+                if activity.last_heartbeat.as_millis() < cutoff {
+                    plan.bytes_reclaimed += bincode::serialized_size(&activity).unwrap_or(0);
+                    plan.expire_activities.push(activity.agent_id);
+                }
+            }
+        }
+
+        if policy.expire_lapsed_locks {
+            for lease in self.storage.list_locks_in_collective(collective_id)? {
+                if lease.is_expired_at(now) {
+                    plan.bytes_reclaimed += bincode::serialized_size(&lease).unwrap_or(0);
+                    plan.expire_locks.push(lease);
+                }
+            }
+        }
+
+        if policy.repair_insight_sources {
+            for insight_id in self.storage.list_insight_ids_in_collective(collective_id)? {
+                let Some(insight) = self.storage.get_insight(insight_id)? else {
+                    continue;
+                };
+                let owners = self
+                    .storage
+                    .get_experience_collectives(&insight.source_experience_ids)?;
+                if owners.iter().any(|owner| *owner != Some(collective_id)) {
+                    plan.repair_insights.push(insight_id);
+                }
+            }
+        }
+
+        info!(
+            collective = %collective_id,
+            archive = plan.archive.len(),
+            merge = plan.merge.len(),
+            bytes = plan.bytes_reclaimed,
+            "Maintenance planned"
+        );
+        Ok(plan)
+    }
+
+    /// Applies a reviewed [`MaintenancePlan`].
+    ///
+    /// Only the items listed in the plan are touched — nothing is
+    /// re-planned. Items that changed since planning are skipped: deleted
+    /// experiences, duplicate groups whose kept experience is gone,
+    /// archived, or moved, agents that have since heartbeated, and leases
+    /// that were renewed or re-acquired (detected via their fencing token).
+    ///
+    /// Duplicate deletions follow [`Config::insight_source_cascade`]; under
+    /// [`InsightSourceCascade::Block`] a duplicate cited by an insight stops
+    /// the run with an error.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    #[instrument(skip(self, plan), fields(collective = %plan.collective_id))]
+    pub fn run_maintenance(&self, plan: &MaintenancePlan) -> Result<MaintenanceReport> {
+        self.check_writable()?;
+        let collective_id = plan.collective_id;
+        let _fence = self.check_collective_writable(collective_id)?;
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let mut report = MaintenanceReport::default();
+        let in_collective = |experience: &Experience| experience.collective_id == collective_id;
+
+        for group in &plan.merge {
+            // Never delete copies unless the one kept is still there to
+            // stand for them
+            match self.storage.get_experience(group.keep)? {
+                Some(keep) if in_collective(&keep) && !keep.archived => {}
+                _ => continue,
+            }
+            for &id in &group.duplicates {
+                let Some(experience) = self.storage.get_experience(id)? else {
+                    continue;
+                };
+                if !in_collective(&experience) {
+                    continue;
+                }
+                self.delete_experience(id)?;
+                report.experiences_merged += 1;
+                report.bytes_reclaimed += bincode::serialized_size(&experience).unwrap_or(0);
+            }
+        }
+
+        for &id in &plan.archive {
+            match self.storage.get_experience(id)? {
+                Some(experience) if in_collective(&experience) && !experience.archived => {
+                    self.archive_experience(id)?;
+                    report.archived += 1;
+                }
+                _ => {}
+            }
+        }
+
+        let now = Timestamp::now();
+        let cutoff = now.as_millis() - self.config.activity.stale_threshold.as_millis() as i64;
+        for agent_id in &plan.expire_activities {
+            if let Some(activity) = self.storage.get_activity(agent_id, collective_id)? {
+                if activity.last_heartbeat.as_millis() < cutoff
+                    && self.storage.delete_activity(agent_id, collective_id)?
+                {
+                    report.activities_expired += 1;
+                    report.bytes_reclaimed += bincode::serialized_size(&activity).unwrap_or(0);
+                }
+            }
+        }
+
+        for planned in &plan.expire_locks {
+            if let Some(lease) = self.storage.get_lock(collective_id, &planned.name)? {
+                if lease.fencing_token == planned.fencing_token
+                    && lease.is_expired_at(now)
+                    && self
+                        .storage
+                        .release_lock(collective_id, &lease.name, lease.fencing_token)?
+                {
+                    report.locks_expired += 1;
+                    report.bytes_reclaimed += bincode::serialized_size(&lease).unwrap_or(0);
+                }
+            }
+        }
+
+        let mut repairs = crate::insight::InsightRepairReport::default();
+        for &insight_id in &plan.repair_insights {
+            if let Some(insight) = self.storage.get_insight(insight_id)? {
+                if insight.collective_id == collective_id {
+                    self.repair_insight(collective_id, &insight, &mut repairs)?;
+                }
+            }
+        }
+        report.insights_repaired = repairs.insights_repaired;
+
+        info!(
+            collective = %collective_id,
+            archived = report.archived,
+            merged = report.experiences_merged,
+            expired = report.activities_expired + report.locks_expired,
+            bytes = report.bytes_reclaimed,
+            "Maintenance applied"
+        );
+        Ok(report)
+    }
+
+    // =========================================================================
+    // Consolidation
+    // =========================================================================
+
+    /// Folds clusters of similar low-importance experiences into insights.
+    ///
+    /// Active experiences with importance below
+    /// [`ConsolidationPolicy::max_importance`] are clustered through the
+    /// collective's vector index, oldest seeds first: a cluster is a seed
+    /// plus its nearest unclustered candidates scoring at or above
+    /// [`ConsolidationPolicy::min_similarity`] under
+    /// [`Config::score_kind`]. Each cluster of at least
+    /// [`ConsolidationPolicy::min_cluster_size`] becomes a
+    /// [`Synthesis`](InsightType::Synthesis) insight citing every member,
+    /// with the content of the member closest to all others (its
+    /// representative) and the members' centroid as the embedding. The
+    /// representative gets a [`Summarizes`](RelationType::Summarizes)
+    /// relation to each other member, and every member is archived.
+    ///
+    /// Archived experiences stay readable and can be restored with
+    /// [`unarchive_experience()`](Self::unarchive_experience). Clusters
+    /// already written stay consolidated if a later one fails.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`ValidationError::InvalidField`] if the policy is invalid
+    /// - [`PulseDBError::Busy`] if the collective is frozen
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use pulsedb::ConsolidationPolicy;
+    ///
+    /// let report = db.consolidate(collective_id, ConsolidationPolicy::default())?;
+    /// println!(
+    ///     "{} clusters, {} experiences archived",
+    ///     report.clusters.len(),
+    ///     report.archived
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, policy))]
+    pub fn consolidate(
+        &self,
+        collective_id: CollectiveId,
+        policy: ConsolidationPolicy,
+    ) -> Result<ConsolidationReport> {
+        self.check_writable()?;
+        validate_consolidation_policy(&policy)?;
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        let _fence = self.check_collective_writable(collective_id)?;
+
+        // Candidates in index order (oldest first), with unit-length embeddings
+        let mut seeds = Vec::new();
+        let mut embeddings: HashMap<ExperienceId, Vec<f32>> = HashMap::new();
+        for id in self
+            .storage
+            .list_experience_ids_in_collective(collective_id)?
+        {
+            let Some(experience) = self.storage.get_experience(id)? else {
+                continue;
+            };
+            if experience.archived || experience.importance >= policy.max_importance {
+                continue;
+            }
+            let Some(embedding) = self.storage.get_embedding(id)? else {
+                continue;
+            };
+            seeds.push(id);
+            embeddings.insert(id, normalized(&embedding));
+        }
+
+        let mut report = ConsolidationReport {
+            collective_id,
+            candidates: seeds.len(),
+            clusters: vec![],
+            archived: 0,
+            relations: 0,
+        };
+        let mut unclustered: HashSet<ExperienceId> = seeds.iter().copied().collect();
+        let ef_search = self.config.hnsw.ef_search.max(policy.neighbors + 1);
+        let score_kind = self.config.score_kind;
+
+        for seed in seeds {
+            if !unclustered.remove(&seed) {
+                continue;
+            }
+            let hits = self
+                .with_vector_index(collective_id, |index| {
+                    index.search_experiences_within(
+                        &embeddings[&seed],
+                        policy.neighbors,
+                        ef_search,
+                        &unclustered,
+                    )
+                })?
+                .unwrap_or_default();
+            let mut members = vec![seed];
+            members.extend(
+                hits.into_iter()
+                    .filter(|&(_, distance)| score_kind.score(distance) >= policy.min_similarity)
+                    .map(|(id, _)| id),
+            );
+            if members.len() < policy.min_cluster_size {
+                continue;
+            }
+            for id in &members {
+                unclustered.remove(id);
+            }
+
+            let (cluster, relations) =
+                self.consolidate_cluster(collective_id, members, &embeddings)?;
+            report.archived += cluster.members.len();
+            report.relations += relations;
+            report.clusters.push(cluster);
+        }
+
+        info!(
+            collective = %collective_id,
+            candidates = report.candidates,
+            clusters = report.clusters.len(),
+            archived = report.archived,
+            "Consolidation applied"
+        );
+        Ok(report)
+    }
+
+    /// Writes one cluster's insight and relations, then archives its members.
+    /// Returns the cluster and the number of relations recorded.
+    fn consolidate_cluster(
+        &self,
+        collective_id: CollectiveId,
+        members: Vec<ExperienceId>,
+        embeddings: &HashMap<ExperienceId, Vec<f32>>,
+    ) -> Result<(ConsolidatedCluster, usize)> {
+        let vectors: Vec<Vec<f32>> = members.iter().map(|id| embeddings[id].clone()).collect();
+        let center = centroid(&vectors);
+        let representative = members[medoid(&vectors)];
+        let dot = |a: &[f32], b: &[f32]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+        let cohesion = (vectors.iter().map(|v| dot(v, &center)).sum::<f32>()
+            / vectors.len() as f32)
+            .clamp(0.0, 1.0);
+
+        let mut content = String::new();
+        let mut domain: Vec<String> = Vec::new();
+        for &id in &members {
+            let experience = self
+                .storage
+                .get_experience(id)?
+                .ok_or_else(|| PulseDBError::from(NotFoundError::experience(id)))?;
+            if id == representative {
+                content = experience.content;
+            }
+            for tag in experience.domain {
+                if !domain.contains(&tag) {
+                    domain.push(tag);
+                }
+            }
+        }
+        if content.len() > MAX_INSIGHT_CONTENT_SIZE {
+            let mut end = MAX_INSIGHT_CONTENT_SIZE;
+            while !content.is_char_boundary(end) {
+                end -= 1;
+            }
+            content.truncate(end);
+        }
+
+        let insight_id = self.store_insight(NewDerivedInsight {
+            collective_id,
+            content,
+            embedding: Some(center),
+            source_experience_ids: members.clone(),
+            insight_type: InsightType::Synthesis,
+            confidence: cohesion,
+            domain,
+        })?;
+
+        let mut relations = 0;
+        for &id in &members {
+            if id == representative
+                || self
+                    .storage
+                    .relation_exists(representative, id, RelationType::Summarizes)?
+            {
+                continue;
+            }
+            self.store_relation(NewExperienceRelation {
+                source_id: representative,
+                target_id: id,
+                relation_type: RelationType::Summarizes,
+                strength: dot(&embeddings[&representative], &embeddings[&id]).clamp(0.0, 1.0),
+                metadata: None,
+            })?;
+            relations += 1;
+        }
+        for &id in &members {
+            self.archive_experience(id)?;
+        }
+
+        let cluster = ConsolidatedCluster {
+            insight_id,
+            representative,
+            members,
+            cohesion,
+        };
+        Ok((cluster, relations))
+    }
+
+    // =========================================================================
+    // Data Subject Erasure
+    // =========================================================================
+
+    /// Erases everything owned by a user.
+    ///
+    /// Users own collectives (see
+    /// [`create_collective_with_owner()`](Self::create_collective_with_owner)),
+    /// so this deletes every collective whose `owner_id` is the user, with
+    /// the same cascade as [`delete_collectives_by_owner()`](Self::delete_collectives_by_owner):
+    /// experiences, relations, insights, activities, and locks. Experiences
+    /// linked to the user through [`NewExperience::user_id`] in other
+    /// collectives are hard-deleted too, with insights handled as in
+    /// [`erase_by_agent()`](Self::erase_by_agent). The whole set is checked
+    /// before anything is deleted.
+    ///
+    /// Returns an [`ErasureReport`] with what was removed. Erasing a user
+    /// who owns nothing returns an empty report.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::Busy`] if any of the user's collectives, or a
+    ///   collective holding an experience linked to the user, is frozen
+    /// - [`ValidationError::InvalidField`] if one of the user's collectives
+    ///   has a sub-collective belonging to someone else
+    #[instrument(skip(self))]
+    pub fn erase_by_user(&self, user_id: &UserId) -> Result<ErasureReport> {
+        self.check_writable()?;
+        if user_id.as_str().is_empty() {
+            return Err(ValidationError::required_field("user_id").into());
+        }
+
+        let owned = self
+            .storage
+            .list_collective_ids_by_owner(user_id.as_str())?;
+
+        // Experiences linked to the user in collectives they don't own
+        let mut linked: HashMap<CollectiveId, HashSet<ExperienceId>> = HashMap::new();
+        let mut linked_handles = Vec::new();
+        for id in self.storage.list_experience_ids_by_user(user_id.as_str())? {
+            let Some(experience) = self.storage.get_experience(id)? else {
+                continue;
+            };
+            if owned.contains(&experience.collective_id) {
+                continue;
+            }
+            linked
+                .entry(experience.collective_id)
+                .or_default()
+                .insert(id);
+            if self.config.content_storage == ContentStorage::External {
+                linked_handles.push(experience.content);
+            }
+        }
+        let _fences = linked
+            .keys()
+            .map(|&collective_id| self.check_collective_writable(collective_id))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut report = ErasureReport::default();
+        for &id in &owned {
+            report.experiences_erased += self.storage.count_experiences_in_collective(id)? as usize;
+            report.relations_erased += self.storage.list_relation_ids_in_collective(id)?.len();
+            report.insights_erased += self.storage.list_insight_ids_in_collective(id)?.len();
+            report.activities_erased += self.storage.list_activities_in_collective(id)?.len();
+            if self.config.content_storage == ContentStorage::External {
+                for exp_id in self.storage.list_experience_ids_in_collective(id)? {
+                    if let Some(experience) = self.storage.get_experience(exp_id)? {
+                        report.content_handles.push(experience.content);
+                    }
+                }
+            }
+        }
+
+        report.collectives_erased = self.delete_collectives_by_owner(user_id.as_str())?;
+        self.erase_experiences(&linked, &mut report)?;
+        report.content_handles.extend(linked_handles);
+
+        info!(
+            user = %user_id,
+            collectives = report.collectives_erased,
+            experiences = report.experiences_erased,
+            "User data erased"
+        );
+        Ok(report)
+    }
+
+    /// Erases everything attributable to an agent.
+    ///
+    /// Across all collectives, this:
+    ///
+    /// - hard-deletes every experience whose `source_agent` is the agent,
+    ///   together with the relations touching them
+    /// - deletes insights whose sources were all erased, and strips the
+    ///   erased IDs from insights that also cite other experiences
+    /// - deletes the agent's activity records and bookmarks
+    ///
+    /// Insights are handled this way regardless of
+    /// [`Config::insight_source_cascade`]: erasure must not be blocked or
+    /// leave references to erased records behind. Every affected collective
+    /// is checked before anything is deleted.
+    ///
+    /// # Errors
+    ///
+    /// - Validation error if `agent_id` is empty or longer than 255 bytes
+    /// - [`PulseDBError::Busy`] if a collective holding the agent's
+    ///   experiences is frozen
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let cid = db.create_collective("example")?;
+    /// use pulsedb::{AgentId, NewExperience};
+    ///
+    /// db.record_experience(NewExperience {
+    ///     collective_id: cid,
+    ///     content: "Customer prefers email".into(),
+    ///     source_agent: AgentId::new("support-bot"),
+    ///     embedding: Some(vec![0.1f32; 384]),
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// let report = db.erase_by_agent(&AgentId::new("support-bot"))?;
+    /// assert_eq!(report.experiences_erased, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn erase_by_agent(&self, agent_id: &AgentId) -> Result<ErasureReport> {
+        self.check_writable()?;
+        validate_agent_id(agent_id.as_str())?;
+
+        let collectives = self.storage.list_collectives()?;
+
+        // Find the agent's experiences, grouped by collective
+        let mut erased: HashMap<CollectiveId, HashSet<ExperienceId>> = HashMap::new();
+        let mut content_handles = Vec::new();
+        let agent_key = agent_hash(agent_id.as_str());
+        for collective in &collectives {
+            let ids = self
+                .storage
+                .list_experience_ids_in_collective(collective.id)?;
+            let metas = self.storage.get_experience_meta(&ids)?;
+            for (id, meta) in ids.into_iter().zip(metas) {
+                // Skip other agents' records without deserializing them
+                if meta.is_some_and(|m| m.agent_hash != agent_key) {
+                    continue;
+                }
+                let Some(experience) = self.storage.get_experience(id)? else {
+                    continue;
+                };
+                if experience.source_agent != *agent_id {
+                    continue;
+                }
+                erased.entry(collective.id).or_default().insert(id);
+                if self.config.content_storage == ContentStorage::External {
+                    content_handles.push(experience.content);
+                }
+            }
+        }
+        let _fences = erased
+            .keys()
+            .map(|&collective_id| self.check_collective_writable(collective_id))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut report = ErasureReport {
+            content_handles,
+            ..Default::default()
+        };
+        self.erase_experiences(&erased, &mut report)?;
+
+        for collective in &collectives {
+            if self
+                .storage
+                .delete_activity(agent_id.as_str(), collective.id)?
+            {
+                report.activities_erased += 1;
+            }
+        }
+
+        for id in self.storage.list_bookmark_ids(agent_id.as_str())? {
+            if self.storage.remove_bookmark(agent_id.as_str(), id)? {
+                report.bookmarks_erased += 1;
+            }
+        }
+
+        info!(
+            agent = %agent_id,
+            experiences = report.experiences_erased,
+            insights = report.insights_erased + report.insights_detached,
+            activities = report.activities_erased,
+            "Agent data erased"
+        );
+        Ok(report)
+    }
+
+    /// Hard-deletes experiences grouped by collective, settling citing
+    /// insights first and counting everything removed into `report`.
+    ///
+    /// Insights whose sources were all erased are deleted; the rest have
+    /// the erased IDs stripped, so the cascade policy never applies.
+    fn erase_experiences(
+        &self,
+        erased: &HashMap<CollectiveId, HashSet<ExperienceId>>,
+        report: &mut ErasureReport,
+    ) -> Result<()> {
+        for (&collective_id, ids) in erased {
+            // Relations between two erased experiences are counted once
+            let mut relations = HashSet::new();
+            for &id in ids {
+                relations.extend(self.storage.get_relation_ids_by_source(id)?);
+                relations.extend(self.storage.get_relation_ids_by_target(id)?);
+            }
+            report.relations_erased += relations.len();
+
+            // Settle citing insights first so the cascade policy never applies
+            for insight_id in self.storage.list_insight_ids_in_collective(collective_id)? {
+                let Some(insight) = self.storage.get_insight(insight_id)? else {
+                    continue;
+                };
+                if !insight
+                    .source_experience_ids
+                    .iter()
+                    .any(|source| ids.contains(source))
+                {
+                    continue;
+                }
+                let kept: Vec<ExperienceId> = insight
+                    .source_experience_ids
+                    .iter()
+                    .copied()
+                    .filter(|source| !ids.contains(source))
+                    .collect();
+                if kept.is_empty() {
+                    self.delete_insight(insight_id)?;
+                    report.insights_erased += 1;
+                } else {
+                    self.storage.update_insight_sources(insight_id, &kept)?;
+                    report.insights_detached += 1;
+                }
+            }
+
+            for &id in ids {
+                self.delete_experience(id)?;
+                report.experiences_erased += 1;
+            }
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Activity Tracking (E3-S03)
+    // =========================================================================
+
+    /// Registers an agent's presence in a collective.
+    ///
+    /// Creates a new activity record or replaces an existing one for the
+    /// same `(collective_id, agent_id)` pair (upsert semantics). Both
+    /// `started_at` and `last_heartbeat` are set to `Timestamp::now()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `activity` - The activity registration (see [`NewActivity`])
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError`] if agent_id is empty or fields exceed size limits
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use pulsedb::NewActivity;
+    ///
+    /// db.register_activity(NewActivity {
+    ///     agent_id: "claude-opus".to_string(),
+    ///     collective_id,
+    ///     current_task: Some("Reviewing pull request".to_string()),
+    ///     context_summary: None,
+    ///     capabilities: vec![],
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, activity), fields(agent_id = %activity.agent_id, collective_id = %activity.collective_id))]
+    pub fn register_activity(&self, activity: NewActivity) -> Result<()> {
+        // Validate input
+        validate_new_activity(&activity)?;
+
+        // Verify collective exists
+        self.storage
+            .get_collective(activity.collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(activity.collective_id)))?;
+
+        // Collapse duplicate capabilities, keeping first-seen order
+        let mut seen = HashSet::new();
+        let capabilities: Vec<String> = activity
+            .capabilities
+            .into_iter()
+            .filter(|c| seen.insert(c.clone()))
+            .collect();
+
+        // Build stored activity with timestamps
+        let now = Timestamp::now();
+        let stored = Activity {
+            agent_id: activity.agent_id,
+            collective_id: activity.collective_id,
+            current_task: activity.current_task,
+            context_summary: activity.context_summary,
+            capabilities,
+            started_at: now,
+            last_heartbeat: now,
+        };
+
+        self.storage.save_activity(&stored)?;
+
+        info!(
+            agent_id = %stored.agent_id,
+            collective_id = %stored.collective_id,
+            "Activity registered"
+        );
+        Ok(())
+    }
+
+    /// Updates an agent's heartbeat timestamp.
+    ///
+    /// Refreshes the `last_heartbeat` to `Timestamp::now()` without changing
+    /// any other fields. The agent must have an existing activity registered.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Activity`] if no activity exists for the agent/collective pair
+    #[instrument(skip(self))]
+    pub fn update_heartbeat(&self, agent_id: &str, collective_id: CollectiveId) -> Result<()> {
+        self.check_writable()?;
+        let mut activity = self
+            .storage
+            .get_activity(agent_id, collective_id)?
+            .ok_or_else(|| {
+                PulseDBError::from(NotFoundError::activity(format!(
+                    "{} in {}",
+                    agent_id, collective_id
+                )))
+            })?;
+
+        activity.last_heartbeat = Timestamp::now();
+        self.storage.save_activity(&activity)?;
+
+        info!(agent_id = %agent_id, collective_id = %collective_id, "Heartbeat updated");
+        Ok(())
+    }
+
+    /// Ends an agent's activity in a collective.
+    ///
+    /// Removes the activity record. After calling this, the agent will no
+    /// longer appear in `get_active_agents()` results.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Activity`] if no activity exists for the agent/collective pair
+    #[instrument(skip(self))]
+    pub fn end_activity(&self, agent_id: &str, collective_id: CollectiveId) -> Result<()> {
+        let deleted = self.storage.delete_activity(agent_id, collective_id)?;
+
+        if !deleted {
+            return Err(PulseDBError::from(NotFoundError::activity(format!(
+                "{} in {}",
+                agent_id, collective_id
+            ))));
+        }
+
+        info!(agent_id = %agent_id, collective_id = %collective_id, "Activity ended");
+        Ok(())
+    }
+
+    /// Returns all active (non-stale) agents in a collective.
+    ///
+    /// Fetches all activities, filters out those whose `last_heartbeat` is
+    /// older than `config.activity.stale_threshold`, and returns the rest
+    /// sorted by `last_heartbeat` descending (most recently active first).
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    #[instrument(skip(self))]
+    pub fn get_active_agents(&self, collective_id: CollectiveId) -> Result<Vec<Activity>> {
+        // Verify collective exists
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let all_activities = self.storage.list_activities_in_collective(collective_id)?;
+
+        // Filter stale activities
+        let now = Timestamp::now();
+        let threshold_ms = self.config.activity.stale_threshold.as_millis() as i64;
+        let cutoff = now.as_millis() - threshold_ms;
+
+        let mut active: Vec<Activity> = all_activities
+            .into_iter()
+            .filter(|a| a.last_heartbeat.as_millis() >= cutoff)
+            .collect();
+
+        // Sort by last_heartbeat descending (most recently active first)
+        #[allow(clippy::unnecessary_sort_by)]
+        active.sort_by(|a, b| b.last_heartbeat.cmp(&a.last_heartbeat));
+
+        Ok(active)
+    }
+
+    /// Returns the active (non-stale) agents in a collective that advertise
+    /// `capability`.
+    ///
+    /// Matching is exact and case-sensitive against the capabilities given
+    /// at [`register_activity()`](Self::register_activity). Candidates come
+    /// from the capability index; staleness and ordering follow
+    /// [`get_active_agents()`](Self::get_active_agents).
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use pulsedb::NewActivity;
+    ///
+    /// db.register_activity(NewActivity {
+    ///     agent_id: "reviewer-1".to_string(),
+    ///     collective_id,
+    ///     current_task: None,
+    ///     context_summary: None,
+    ///     capabilities: vec!["code-review".to_string()],
+    /// })?;
+    ///
+    /// let reviewers = db.find_agents_with_capability(collective_id, "code-review")?;
+    /// assert_eq!(reviewers[0].agent_id, "reviewer-1");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn find_agents_with_capability(
+        &self,
+        collective_id: CollectiveId,
+        capability: &str,
+    ) -> Result<Vec<Activity>> {
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let cutoff =
+            Timestamp::now().as_millis() - self.config.activity.stale_threshold.as_millis() as i64;
+
+        let mut agents = Vec::new();
+        for agent_id in self
+            .storage
+            .list_agent_ids_with_capability(collective_id, capability)?
+        {
+            if let Some(activity) = self.storage.get_activity(&agent_id, collective_id)? {
+                if activity.last_heartbeat.as_millis() >= cutoff {
+                    agents.push(activity);
+                }
+            }
+        }
+
+        agents.sort_by_key(|a| std::cmp::Reverse(a.last_heartbeat));
+        Ok(agents)
+    }
+
+    // =========================================================================
+    // Bookmarks
+    // =========================================================================
+
+    /// Adds an experience to an agent's personal reading list.
+    ///
+    /// Bookmarks are a lightweight per-agent layer over the shared store:
+    /// they never modify the experience itself and are dropped automatically
+    /// when the experience is deleted. Bookmarking twice is a no-op.
+    ///
+    /// Returns `true` if the bookmark was added, `false` if it already existed.
+    ///
+    /// # Errors
+    ///
+    /// - Validation error if `agent_id` is empty or longer than 255 bytes
+    /// - [`NotFoundError::Experience`] if the experience doesn't exist
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let cid = db.create_collective("example")?;
+    /// # let id = db.record_experience(pulsedb::NewExperience {
+    /// #     collective_id: cid,
+    /// #     content: "Pin the toolchain in CI".into(),
+    /// #     embedding: Some(vec![0.1f32; 384]),
+    /// #     ..Default::default()
+    /// # })?;
+    /// db.bookmark_experience("agent-1", id)?;
+    /// let reading_list = db.list_bookmarks("agent-1")?;
+    /// assert_eq!(reading_list[0].id, id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn bookmark_experience(&self, agent_id: &str, id: ExperienceId) -> Result<bool> {
+        self.check_writable()?;
+        validate_agent_id(agent_id)?;
+
+        self.storage
+            .get_experience(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::experience(id)))?;
+
+        let added = self.storage.add_bookmark(agent_id, id)?;
+
+        info!(agent_id = %agent_id, experience_id = %id, added, "Experience bookmarked");
+        Ok(added)
+    }
+
+    /// Removes an experience from an agent's reading list.
+    ///
+    /// Returns `true` if the bookmark existed and was removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `agent_id` is invalid.
+    #[instrument(skip(self))]
+    pub fn unbookmark_experience(&self, agent_id: &str, id: ExperienceId) -> Result<bool> {
+        self.check_writable()?;
+        validate_agent_id(agent_id)?;
 
-        info!(id = %id, "Insight stored");
-        Ok(id)
+        let removed = self.storage.remove_bookmark(agent_id, id)?;
+
+        info!(agent_id = %agent_id, experience_id = %id, removed, "Experience unbookmarked");
+        Ok(removed)
     }
 
-    /// Retrieves a derived insight by ID.
+    /// Returns the experiences an agent has bookmarked.
     ///
-    /// Returns `None` if no insight with the given ID exists.
+    /// Experiences are returned in the order they were recorded (oldest
+    /// first, by [`Experience::timestamp`]), across all collectives, whatever
+    /// the ID scheme. Returns an empty vector if the agent has no bookmarks.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `agent_id` is invalid.
     #[instrument(skip(self))]
-    pub fn get_insight(&self, id: InsightId) -> Result<Option<DerivedInsight>> {
-        self.storage.get_insight(id)
+    pub fn list_bookmarks(&self, agent_id: &str) -> Result<Vec<Experience>> {
+        validate_agent_id(agent_id)?;
+
+        let mut experiences = Vec::new();
+        for id in self.storage.list_bookmark_ids(agent_id)? {
+            if let Some(experience) = self.storage.get_experience(id)? {
+                experiences.push(experience);
+            }
+        }
+        sort_by_recording(&mut experiences);
+        Ok(experiences)
     }
 
-    /// Searches for insights semantically similar to the query embedding.
+    // =========================================================================
+    // User and Task Links
+    // =========================================================================
+
+    /// Returns the experiences linked to a user via [`NewExperience::user_id`].
     ///
-    /// Uses the insight-specific HNSW index for approximate nearest neighbor
-    /// search, then fetches full insight records from storage.
+    /// Experiences are returned in the order they were recorded (oldest
+    /// first), across all collectives. Returns an empty vector if nothing
+    /// is linked to the user.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `collective_id` - The collective to search within
-    /// * `query` - Query embedding vector (must match collective's dimension)
-    /// * `k` - Maximum number of results to return
+    /// Returns a validation error if `user_id` is empty.
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// - [`ValidationError::DimensionMismatch`] if `query.len()` doesn't match
-    /// - [`NotFoundError::Collective`] if the collective doesn't exist
-    #[instrument(skip(self, query))]
-    pub fn get_insights(
-        &self,
-        collective_id: CollectiveId,
-        query: &[f32],
-        k: usize,
-    ) -> Result<Vec<(DerivedInsight, f32)>> {
-        // Verify collective exists and check embedding dimension
-        let collective = self
-            .storage
-            .get_collective(collective_id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
-
-        let expected_dim = collective.embedding_dimension as usize;
-        if query.len() != expected_dim {
-            return Err(ValidationError::dimension_mismatch(expected_dim, query.len()).into());
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let cid = db.create_collective("support")?;
+    /// use pulsedb::{NewExperience, UserId};
+    ///
+    /// db.record_experience(NewExperience {
+    ///     collective_id: cid,
+    ///     content: "Prefers answers with code samples".into(),
+    ///     embedding: Some(vec![0.1; 384]),
+    ///     user_id: Some(UserId::new("user-42")),
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// assert_eq!(db.experiences_for_user(&UserId::new("user-42"))?.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn experiences_for_user(&self, user_id: &UserId) -> Result<Vec<Experience>> {
+        if user_id.as_str().is_empty() {
+            return Err(ValidationError::required_field("user_id").into());
         }
+        self.hydrate_experiences(self.storage.list_experience_ids_by_user(user_id.as_str())?)
+    }
 
-        let ef_search = self.config.hnsw.ef_search;
+    /// Returns the experiences recorded under a task via
+    /// [`NewExperience::source_task`], oldest first, across all collectives.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `task_id` is empty.
+    pub fn experiences_for_task(&self, task_id: &TaskId) -> Result<Vec<Experience>> {
+        if task_id.as_str().is_empty() {
+            return Err(ValidationError::required_field("task_id").into());
+        }
+        self.hydrate_experiences(self.storage.list_experience_ids_by_task(task_id.as_str())?)
+    }
 
-        // Search insight HNSW — returns (ExperienceId, distance) pairs
-        let insight_vectors = self
-            .insight_vectors
-            .read()
-            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
+    /// Returns the distinct tasks an agent has recorded experiences under,
+    /// sorted by task ID.
+    ///
+    /// A task stays listed while at least one of the agent's experiences
+    /// for it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `agent_id` is invalid.
+    pub fn tasks_touched_by(&self, agent_id: &AgentId) -> Result<Vec<TaskId>> {
+        validate_agent_id(agent_id.as_str())?;
+        self.storage.list_tasks_by_agent(agent_id.as_str())
+    }
 
-        let candidates = match insight_vectors.get(&collective_id) {
-            Some(index) => index.search_experiences(query, k, ef_search)?,
-            None => return Ok(vec![]),
+    /// Returns an episode: the experiences recorded under a task, in
+    /// recording order, with its summary insight if one is attached.
+    ///
+    /// An unknown episode comes back with no experiences.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `episode_id` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let cid = db.create_collective("ops")?;
+    /// use pulsedb::{EpisodeId, NewExperience};
+    ///
+    /// let episode = EpisodeId::new("deploy-1182");
+    /// for step in ["Drained node a", "Upgraded node a", "Rejoined node a"] {
+    ///     db.record_experience(NewExperience {
+    ///         collective_id: cid,
+    ///         content: step.into(),
+    ///         embedding: Some(vec![0.1; 384]),
+    ///         source_task: Some(episode.clone()),
+    ///         ..Default::default()
+    ///     })?;
+    /// }
+    ///
+    /// let steps = db.get_episode(&episode)?.experiences;
+    /// assert_eq!(steps[0].content, "Drained node a");
+    /// assert_eq!(steps.len(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub fn get_episode(&self, episode_id: &EpisodeId) -> Result<Episode> {
+        let experiences = self.experiences_for_task(episode_id)?;
+        let summary = match self.storage.get_episode_summary(episode_id.as_str())? {
+            Some(insight_id) => self.get_insight(insight_id)?,
+            None => None,
         };
-        drop(insight_vectors);
-
-        // Convert ExperienceId back to InsightId and fetch records
-        let mut results = Vec::with_capacity(candidates.len());
-        for (exp_id, distance) in candidates {
-            let insight_id = InsightId::from_bytes(*exp_id.as_bytes());
-            if let Some(insight) = self.storage.get_insight(insight_id)? {
-                // Convert HNSW distance to similarity (1.0 - distance), matching search_similar pattern
-                results.push((insight, 1.0 - distance));
-            }
-        }
-
-        Ok(results)
+        Ok(Episode {
+            id: episode_id.clone(),
+            experiences,
+            summary,
+        })
     }
 
-    /// Deletes a derived insight by ID.
+    /// Attaches a summary insight to an episode, replacing any earlier one;
+    /// `None` detaches it.
     ///
-    /// Removes the insight from storage and soft-deletes it from the HNSW index.
+    /// Deleting the insight later detaches it as well.
     ///
     /// # Errors
     ///
-    /// Returns [`NotFoundError::Insight`] if no insight with the given ID exists.
+    /// - [`ValidationError::RequiredField`] if `episode_id` is empty
+    /// - [`NotFoundError::Insight`] if the insight doesn't exist
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
     #[instrument(skip(self))]
-    pub fn delete_insight(&self, id: InsightId) -> Result<()> {
+    pub fn set_episode_summary(
+        &self,
+        episode_id: &EpisodeId,
+        insight_id: Option<InsightId>,
+    ) -> Result<()> {
         self.check_writable()?;
-        // Read insight first to get collective_id for HNSW lookup
-        let insight = self
-            .storage
-            .get_insight(id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::insight(id)))?;
-
-        // Delete from redb FIRST (source of truth)
-        self.storage.delete_insight(id)?;
-
-        // Soft-delete from insight HNSW (using InsightId→ExperienceId byte conversion)
-        let exp_id = ExperienceId::from_bytes(*id.as_bytes());
-        let insight_vectors = self
-            .insight_vectors
-            .read()
-            .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?;
-        if let Some(index) = insight_vectors.get(&insight.collective_id) {
-            index.delete_experience(exp_id)?;
+        if episode_id.as_str().is_empty() {
+            return Err(ValidationError::required_field("episode_id").into());
         }
+        let _fence = match insight_id {
+            Some(id) => {
+                let insight = self
+                    .storage
+                    .get_insight(id)?
+                    .ok_or_else(|| PulseDBError::from(NotFoundError::insight(id)))?;
+                Some(self.check_collective_writable(insight.collective_id)?)
+            }
+            None => None,
+        };
+        self.storage
+            .set_episode_summary(episode_id.as_str(), insight_id)
+    }
 
-        info!(id = %id, "Insight deleted");
-        Ok(())
+    /// Loads experiences by ID, skipping any that no longer exist.
+    fn hydrate_experiences(&self, ids: Vec<ExperienceId>) -> Result<Vec<Experience>> {
+        let mut experiences = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(mut experience) = self.storage.get_experience(id)? {
+                self.run_read_hooks_on_experience(&mut experience)?;
+                experiences.push(experience);
+            }
+        }
+        sort_by_recording(&mut experiences);
+        Ok(experiences)
     }
 
     // =========================================================================
-    // Activity Tracking (E3-S03)
+    // Changeset Knowledge
     // =========================================================================
 
-    /// Registers an agent's presence in a collective.
-    ///
-    /// Creates a new activity record or replaces an existing one for the
-    /// same `(collective_id, agent_id)` pair (upsert semantics). Both
-    /// `started_at` and `last_heartbeat` are set to `Timestamp::now()`.
+    /// Returns what the collective knows about a set of changed files.
     ///
-    /// # Arguments
+    /// Coding agents call this before editing, with the paths from a diff.
+    /// Experiences are found through the related-file index: a related
+    /// file that is one of `paths` counts fully, one in the same directory
+    /// counts half, and the sum is scaled by the experience's importance
+    /// and confidence (see [`FileExperience::score`]). Relations touching
+    /// the experiences found, and insights citing them, come along ranked.
     ///
-    /// * `activity` - The activity registration (see [`NewActivity`])
+    /// Paths compare after stripping a leading `./` and trailing `/`.
+    /// Archived experiences and those pending review are left out; read
+    /// hooks run on everything returned.
     ///
     /// # Errors
     ///
-    /// - [`ValidationError`] if agent_id is empty or fields exceed size limits
     /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`ValidationError::InvalidField`] if a path is empty
     ///
     /// # Example
     ///
@@ -1837,131 +9608,263 @@ impl PulseDB {
     /// # let dir = tempfile::tempdir().unwrap();
     /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
     /// # let collective_id = db.create_collective("example")?;
-    /// use pulsedb::NewActivity;
+    /// use pulsedb::NewExperience;
     ///
-    /// db.register_activity(NewActivity {
-    ///     agent_id: "claude-opus".to_string(),
+    /// db.record_experience(NewExperience {
     ///     collective_id,
-    ///     current_task: Some("Reviewing pull request".to_string()),
-    ///     context_summary: None,
+    ///     content: "db.rs holds the facade; keep storage calls behind it".into(),
+    ///     related_files: vec!["src/db.rs".into()],
+    ///     embedding: Some(vec![0.1; 384]),
+    ///     ..Default::default()
     /// })?;
+    ///
+    /// let knowledge = db.knowledge_for_changeset(collective_id, &["src/db.rs", "README.md"])?;
+    /// assert_eq!(knowledge.experiences[0].matched_files, vec!["src/db.rs"]);
     /// # Ok(())
     /// # }
     /// ```
-    #[instrument(skip(self, activity), fields(agent_id = %activity.agent_id, collective_id = %activity.collective_id))]
-    pub fn register_activity(&self, activity: NewActivity) -> Result<()> {
-        // Validate input
-        validate_new_activity(&activity)?;
-
-        // Verify collective exists
+    #[instrument(skip(self, paths))]
+    pub fn knowledge_for_changeset(
+        &self,
+        collective_id: CollectiveId,
+        paths: &[impl AsRef<str>],
+    ) -> Result<ChangesetKnowledge> {
         self.storage
-            .get_collective(activity.collective_id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(activity.collective_id)))?;
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
 
-        // Build stored activity with timestamps
-        let now = Timestamp::now();
-        let stored = Activity {
-            agent_id: activity.agent_id,
-            collective_id: activity.collective_id,
-            current_task: activity.current_task,
-            context_summary: activity.context_summary,
-            started_at: now,
-            last_heartbeat: now,
-        };
+        let mut changed: Vec<&str> = Vec::with_capacity(paths.len());
+        for path in paths {
+            let path = changeset::normalize_path(path.as_ref());
+            if path.is_empty() {
+                return Err(ValidationError::invalid_field("paths", "must not be empty").into());
+            }
+            if !changed.contains(&path) {
+                changed.push(path);
+            }
+        }
 
-        self.storage.save_activity(&stored)?;
+        // Candidates: every indexed file in a changed file's directory (or
+        // the file itself at the top level), under either spelling
+        let mut candidates: Vec<ExperienceId> = Vec::new();
+        let mut seen = HashSet::new();
+        let mut prefixes: HashSet<&str> = HashSet::new();
+        for path in &changed {
+            let prefix = changeset::parent_dir(path).unwrap_or(path);
+            if prefixes.insert(prefix) {
+                for scope in [prefix.to_string(), format!("./{}", prefix)] {
+                    for (_, id) in self.storage.list_experience_files(collective_id, &scope)? {
+                        if seen.insert(id) {
+                            candidates.push(id);
+                        }
+                    }
+                }
+            }
+        }
 
-        info!(
-            agent_id = %stored.agent_id,
-            collective_id = %stored.collective_id,
-            "Activity registered"
-        );
-        Ok(())
+        let mut experiences = Vec::new();
+        for id in candidates {
+            if self.storage.is_experience_pending(collective_id, id)? {
+                continue;
+            }
+            let Some(experience) = self.get_experience(id)? else {
+                continue;
+            };
+            if experience.archived {
+                continue;
+            }
+            let mut overlap = 0.0;
+            let mut matched_files = Vec::new();
+            for path in &changed {
+                let weight = experience
+                    .related_files
+                    .iter()
+                    .map(|related| changeset::match_weight(path, related))
+                    .fold(0.0, f32::max);
+                if weight > 0.0 {
+                    overlap += weight;
+                    matched_files.push(path.to_string());
+                }
+            }
+            if overlap > 0.0 {
+                let score = overlap * (experience.importance + experience.confidence) / 2.0;
+                experiences.push(FileExperience {
+                    experience,
+                    matched_files,
+                    score,
+                });
+            }
+        }
+        experiences.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let scores: HashMap<ExperienceId, f32> = experiences
+            .iter()
+            .map(|found| (found.experience.id, found.score))
+            .collect();
+
+        let mut relation_ids = HashSet::new();
+        for id in scores.keys() {
+            relation_ids.extend(self.storage.get_relation_ids_by_source(*id)?);
+            relation_ids.extend(self.storage.get_relation_ids_by_target(*id)?);
+        }
+        let mut relations = Vec::with_capacity(relation_ids.len());
+        for relation_id in relation_ids {
+            if let Some(relation) = self.storage.get_relation(relation_id)? {
+                relations.push(relation);
+            }
+        }
+        relations.sort_by(|a, b| b.strength.total_cmp(&a.strength));
+
+        let mut insights = Vec::new();
+        if !scores.is_empty() {
+            for insight_id in self.storage.list_insight_ids_in_collective(collective_id)? {
+                let Some(insight) = self.get_insight(insight_id)? else {
+                    continue;
+                };
+                let cited: f32 = insight
+                    .source_experience_ids
+                    .iter()
+                    .filter_map(|id| scores.get(id))
+                    .sum();
+                if cited > 0.0 {
+                    let score = cited * insight.confidence;
+                    insights.push(FileInsight { insight, score });
+                }
+            }
+            insights.sort_by(|a, b| b.score.total_cmp(&a.score));
+        }
+
+        Ok(ChangesetKnowledge {
+            experiences,
+            relations,
+            insights,
+        })
     }
 
-    /// Updates an agent's heartbeat timestamp.
+    // =========================================================================
+    // Locks and Leases
+    // =========================================================================
+
+    /// Acquires (or renews) a named lock in a collective for `ttl`.
     ///
-    /// Refreshes the `last_heartbeat` to `Timestamp::now()` without changing
-    /// any other fields. The agent must have an existing activity registered.
+    /// The check and grant commit in a single redb write transaction, so
+    /// concurrent callers on this `PulseDB` handle (threads and tasks
+    /// sharing it) can never both hold the lock. redb admits one writing
+    /// process per file, so agents in other processes must go through a
+    /// process that owns the handle. Re-acquiring a lock you already
+    /// hold extends it and keeps its fencing token; an expired lease can be
+    /// taken over by anyone and receives a new, higher token.
+    ///
+    /// # Arguments
+    ///
+    /// * `collective_id` - The collective the lock is scoped to
+    /// * `name` - Lock name, e.g. `"repo:main"` (non-empty, ≤ 255 bytes)
+    /// * `owner` - Holder identity, typically the agent ID (non-empty, ≤ 255 bytes)
+    /// * `ttl` - How long the lease lasts without renewal (must be > 0)
     ///
     /// # Errors
     ///
-    /// - [`NotFoundError::Activity`] if no activity exists for the agent/collective pair
+    /// - [`ValidationError`] if arguments are empty, too long, or `ttl` is zero
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`PulseDBError::Busy`] if another owner holds an unexpired lease
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// use std::time::Duration;
+    ///
+    /// let lease = db.acquire_lock(collective_id, "deploy:staging", "agent-1", Duration::from_secs(30))?;
+    /// // ... deploy, passing lease.fencing_token to the target ...
+    /// assert!(db.release_lock(collective_id, "deploy:staging", lease.fencing_token)?);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[instrument(skip(self))]
-    pub fn update_heartbeat(&self, agent_id: &str, collective_id: CollectiveId) -> Result<()> {
+    pub fn acquire_lock(
+        &self,
+        collective_id: CollectiveId,
+        name: &str,
+        owner: &str,
+        ttl: Duration,
+    ) -> Result<Lease> {
         self.check_writable()?;
-        let mut activity = self
-            .storage
-            .get_activity(agent_id, collective_id)?
-            .ok_or_else(|| {
-                PulseDBError::from(NotFoundError::activity(format!(
-                    "{} in {}",
-                    agent_id, collective_id
-                )))
-            })?;
+        let _fence = self.check_collective_writable(collective_id)?;
+        validate_lock_request(name, owner, ttl)?;
 
-        activity.last_heartbeat = Timestamp::now();
-        self.storage.save_activity(&activity)?;
+        self.storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+
+        let now = Timestamp::now();
+        let ttl_ms = i64::try_from(ttl.as_millis()).unwrap_or(i64::MAX);
+        let expires_at = Timestamp::from_millis(now.as_millis().saturating_add(ttl_ms));
 
-        info!(agent_id = %agent_id, collective_id = %collective_id, "Heartbeat updated");
-        Ok(())
+        let lease = self
+            .storage
+            .acquire_lock(collective_id, name, owner, now, expires_at)?;
+        if lease.owner != owner {
+            return Err(PulseDBError::busy(format!(
+                "lock '{}' in {} is held by '{}' until {}",
+                name, collective_id, lease.owner, lease.expires_at
+            )));
+        }
+
+        info!(
+            name = %name,
+            owner = %owner,
+            fencing_token = lease.fencing_token,
+            "Lock acquired"
+        );
+        Ok(lease)
     }
 
-    /// Ends an agent's activity in a collective.
+    /// Releases a named lock held under `fencing_token`.
     ///
-    /// Removes the activity record. After calling this, the agent will no
-    /// longer appear in `get_active_agents()` results.
+    /// Returns `false` if the lock isn't held or `fencing_token` no longer
+    /// matches — for example, the lease expired and was taken over — so a
+    /// stale holder can never release someone else's lease.
     ///
     /// # Errors
     ///
-    /// - [`NotFoundError::Activity`] if no activity exists for the agent/collective pair
+    /// - [`ValidationError`] if `name` is empty or too long
     #[instrument(skip(self))]
-    pub fn end_activity(&self, agent_id: &str, collective_id: CollectiveId) -> Result<()> {
-        let deleted = self.storage.delete_activity(agent_id, collective_id)?;
+    pub fn release_lock(
+        &self,
+        collective_id: CollectiveId,
+        name: &str,
+        fencing_token: u64,
+    ) -> Result<bool> {
+        self.check_writable()?;
+        validate_lock_name(name)?;
 
-        if !deleted {
-            return Err(PulseDBError::from(NotFoundError::activity(format!(
-                "{} in {}",
-                agent_id, collective_id
-            ))));
-        }
+        let released = self
+            .storage
+            .release_lock(collective_id, name, fencing_token)?;
 
-        info!(agent_id = %agent_id, collective_id = %collective_id, "Activity ended");
-        Ok(())
+        if released {
+            info!(name = %name, fencing_token, "Lock released");
+        }
+        Ok(released)
     }
 
-    /// Returns all active (non-stale) agents in a collective.
-    ///
-    /// Fetches all activities, filters out those whose `last_heartbeat` is
-    /// older than `config.activity.stale_threshold`, and returns the rest
-    /// sorted by `last_heartbeat` descending (most recently active first).
+    /// Returns the unexpired lease on a named lock, if any.
     ///
     /// # Errors
     ///
-    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    /// - [`ValidationError`] if `name` is empty or too long
     #[instrument(skip(self))]
-    pub fn get_active_agents(&self, collective_id: CollectiveId) -> Result<Vec<Activity>> {
-        // Verify collective exists
-        self.storage
-            .get_collective(collective_id)?
-            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
-
-        let all_activities = self.storage.list_activities_in_collective(collective_id)?;
+    pub fn get_lock(&self, collective_id: CollectiveId, name: &str) -> Result<Option<Lease>> {
+        validate_lock_name(name)?;
 
-        // Filter stale activities
         let now = Timestamp::now();
-        let threshold_ms = self.config.activity.stale_threshold.as_millis() as i64;
-        let cutoff = now.as_millis() - threshold_ms;
-
-        let mut active: Vec<Activity> = all_activities
-            .into_iter()
-            .filter(|a| a.last_heartbeat.as_millis() >= cutoff)
-            .collect();
-
-        // Sort by last_heartbeat descending (most recently active first)
-        active.sort_by(|a, b| b.last_heartbeat.cmp(&a.last_heartbeat));
-
-        Ok(active)
+        Ok(self
+            .storage
+            .get_lock(collective_id, name)?
+            .filter(|lease| !lease.is_expired_at(now)))
     }
 
     // =========================================================================
@@ -1976,6 +9879,8 @@ impl PulseDB {
     /// 3. Insight search ([`get_insights`](Self::get_insights)) — if requested
     /// 4. Relation collection ([`get_related_experiences`](Self::get_related_experiences)) — if requested
     /// 5. Active agents ([`get_active_agents`](Self::get_active_agents)) — if requested
+    /// 6. Source collapsing — if `collapse_insight_sources` is set, experiences
+    ///    cited by a returned insight move into `insight_sources`
     ///
     /// # Arguments
     ///
@@ -2052,28 +9957,31 @@ impl PulseDB {
         }
 
         // ── 1. Similar experiences (HNSW vector search) ──────────
-        let similar_experiences = self.search_similar_filtered(
+        let mut similar_experiences = self.search_similar_filtered(
             request.collective_id,
             &request.query_embedding,
             request.max_similar,
             request.filter.clone(),
         )?;
 
+        let min_similarity = request.filter.min_similarity;
+
         // ── 2. Recent experiences (timestamp index scan) ─────────
-        let recent_experiences = self.get_recent_experiences_filtered(
+        let mut recent_experiences = self.get_recent_experiences_filtered(
             request.collective_id,
             request.max_recent,
             request.filter,
         )?;
 
         // ── 3. Insights (HNSW vector search on insight index) ────
-        let insights = if request.include_insights {
+        let insights: Vec<DerivedInsight> = if request.include_insights {
             self.get_insights(
                 request.collective_id,
                 &request.query_embedding,
                 request.max_similar,
             )?
             .into_iter()
+            .filter(|(_, score)| min_similarity.is_none_or(|min| *score >= min))
             .map(|(insight, _score)| insight)
             .collect()
         } else {
@@ -2117,12 +10025,229 @@ impl PulseDB {
             vec![]
         };
 
+        // ── 6. Collapse cited sources under their insights ───────
+        let mut insight_sources: HashMap<InsightId, Vec<Experience>> = HashMap::new();
+        if request.collapse_insight_sources && !insights.is_empty() {
+            // First citing insight wins, so each source lands exactly once
+            let mut owner: HashMap<ExperienceId, InsightId> = HashMap::new();
+            for insight in &insights {
+                for source in &insight.source_experience_ids {
+                    owner.entry(*source).or_insert(insight.id);
+                }
+            }
+
+            similar_experiences.retain(|r| match owner.get(&r.experience.id) {
+                Some(insight_id) => {
+                    insight_sources
+                        .entry(*insight_id)
+                        .or_default()
+                        .push(r.experience.clone());
+                    false
+                }
+                None => true,
+            });
+            recent_experiences.retain(|e| match owner.get(&e.id) {
+                Some(insight_id) => {
+                    let children = insight_sources.entry(*insight_id).or_default();
+                    // Already attached via the similar list
+                    if !children.iter().any(|c| c.id == e.id) {
+                        children.push(e.clone());
+                    }
+                    false
+                }
+                None => true,
+            });
+        }
+
         Ok(ContextCandidates {
             similar_experiences,
             recent_experiences,
             insights,
             relations,
             active_agents,
+            insight_sources,
+        })
+    }
+
+    /// Measures the content of experiences and insights without returning it.
+    ///
+    /// Each record's content is read the way [`get_experience()`](Self::get_experience)
+    /// and [`get_insight()`](Self::get_insight) would return it (resolved
+    /// and passed through read hooks), then counted with `counter`. Only the
+    /// counts come back, so callers can pick what fits a prompt budget and
+    /// fetch just that. IDs that don't exist are reported in
+    /// [`ContextCost::missing`] rather than failing the call.
+    ///
+    /// Counts cover the content alone; rendering with
+    /// [`Experience::render_for_context()`] adds labels and fields on top.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// # let id = db.record_experience(pulsedb::NewExperience {
+    /// #     collective_id,
+    /// #     content: "pin the dependency".into(),
+    /// #     embedding: Some(vec![0.1; 384]),
+    /// #     ..Default::default()
+    /// # })?;
+    /// use pulsedb::{ApproxTokenCounter, ContextItem};
+    ///
+    /// let cost = db.estimate_context_cost(&[ContextItem::from(id)], &ApproxTokenCounter)?;
+    /// assert_eq!(cost.total_bytes, 18);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, ids, counter), fields(count = ids.len()))]
+    pub fn estimate_context_cost(
+        &self,
+        ids: &[ContextItem],
+        counter: &dyn TokenCounter,
+    ) -> Result<ContextCost> {
+        let mut cost = ContextCost::default();
+        for &item in ids {
+            let content = match item {
+                ContextItem::Experience(id) => self.get_experience(id)?.map(|e| e.content),
+                ContextItem::Insight(id) => self.get_insight(id)?.map(|i| i.content),
+            };
+            let Some(content) = content else {
+                cost.missing.push(item);
+                continue;
+            };
+            let tokens = counter.count_tokens(&content);
+            cost.total_tokens += tokens;
+            cost.total_bytes += content.len();
+            cost.items.push(ItemCost {
+                item,
+                tokens,
+                bytes: content.len(),
+            });
+        }
+        Ok(cost)
+    }
+
+    /// Gathers the evidence for and against an answer to a query.
+    ///
+    /// Runs [`search_similar()`](Self::search_similar) and splits the `k`
+    /// hits into a supporting and a contradicting [`Stance`] using the
+    /// relations between them. The best hit anchors the supporting side;
+    /// `Supports`, `Elaborates`, and `Implies` keep a related experience on
+    /// the same side, while `Contradicts` moves it to the other. Hits with
+    /// no such relation to an earlier hit count as supporting.
+    ///
+    /// Experiences one relation away from a hit are included even if the
+    /// search did not return them, so a recorded contradiction always
+    /// surfaces. Each piece of evidence is weighted by its confidence times
+    /// its similarity (for hits) or relation strength (for neighbors), and
+    /// each stance's confidence combines those weights. Use
+    /// [`AnswerSupport::render()`] to put the result in a prompt.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`search_similar()`](Self::search_similar).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("example")?;
+    /// # let query = vec![0.1f32; 384];
+    /// let support = db.answer_support(collective_id, &query, 10)?;
+    /// if support.contradicting.confidence > support.supporting.confidence {
+    ///     println!("the hive disagrees:\n{}", support.render());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, query))]
+    pub fn answer_support(
+        &self,
+        collective_id: CollectiveId,
+        query: &[f32],
+        k: usize,
+    ) -> Result<AnswerSupport> {
+        let hits = self.search_similar(collective_id, query, k)?;
+        let mut pending_hits: HashMap<ExperienceId, SearchResult> = hits
+            .iter()
+            .map(|hit| (hit.experience.id, hit.clone()))
+            .collect();
+        let mut seen: HashSet<ExperienceId> = HashSet::new();
+        let mut supporting = Vec::new();
+        let mut contradicting = Vec::new();
+
+        for hit in &hits {
+            let Some(anchor) = pending_hits.remove(&hit.experience.id) else {
+                continue;
+            };
+            seen.insert(anchor.experience.id);
+            let mut queue = VecDeque::from([(anchor, true, None)]);
+            while let Some((found, supports, via)) = queue.pop_front() {
+                let id = found.experience.id;
+                let mut related = Vec::new();
+                for rel_id in self.storage.get_relation_ids_by_source(id)? {
+                    if let Some(relation) = self.storage.get_relation(rel_id)? {
+                        related.push((relation.target_id, relation));
+                    }
+                }
+                for rel_id in self.storage.get_relation_ids_by_target(id)? {
+                    if let Some(relation) = self.storage.get_relation(rel_id)? {
+                        related.push((relation.source_id, relation));
+                    }
+                }
+                let side = if supports {
+                    &mut supporting
+                } else {
+                    &mut contradicting
+                };
+                side.push(Evidence {
+                    weight: found.experience.confidence * found.similarity.clamp(0.0, 1.0),
+                    similarity: Some(found.similarity),
+                    experience: found.experience,
+                    via,
+                });
+
+                for (other, relation) in related {
+                    let Some(same) = answer::same_side(relation.relation_type) else {
+                        continue;
+                    };
+                    if !seen.insert(other) {
+                        continue;
+                    }
+                    let other_supports = supports == same;
+                    if let Some(next) = pending_hits.remove(&other) {
+                        queue.push_back((next, other_supports, Some(relation.relation_type)));
+                        continue;
+                    }
+                    // Neighbors outside the hits are included but not followed
+                    let Some(experience) = self.get_experience(other)? else {
+                        continue;
+                    };
+                    if experience.archived {
+                        continue;
+                    }
+                    let side = if other_supports {
+                        &mut supporting
+                    } else {
+                        &mut contradicting
+                    };
+                    side.push(Evidence {
+                        weight: experience.confidence * relation.strength,
+                        similarity: None,
+                        via: Some(relation.relation_type),
+                        experience,
+                    });
+                }
+            }
+        }
+
+        Ok(AnswerSupport {
+            supporting: Stance::from_evidence(supporting),
+            contradicting: Stance::from_evidence(contradicting),
         })
     }
 
@@ -2192,6 +10317,136 @@ impl PulseDB {
         self.watch.subscribe(collective_id, Some(filter))
     }
 
+    /// Registers a standing interest in a collective.
+    ///
+    /// From then on, every experience recorded (or approved out of review)
+    /// in the collective within `interest.min_similarity` of
+    /// `interest.embedding` is sent as a
+    /// [`Created`](WatchEventType::Created) event to the streams
+    /// `interest.agent_id` opened with
+    /// [`watch_interests()`](Self::watch_interests). Agents are not
+    /// notified of their own experiences. Interests persist across reopen;
+    /// registering one with the same agent and name replaces it.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    /// - [`ValidationError::InvalidField`] if the agent ID is invalid or
+    ///   `min_similarity` is outside [-1.0, 1.0]
+    /// - [`ValidationError::RequiredField`] if `name` is empty
+    /// - [`ValidationError::DimensionMismatch`] if the embedding doesn't
+    ///   match the collective's dimension
+    /// - [`NotFoundError::Collective`] if the collective doesn't exist
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// # let collective_id = db.create_collective("hive")?;
+    /// use pulsedb::{AgentId, Interest};
+    ///
+    /// let reviewer = AgentId::new("security-reviewer");
+    /// db.register_interest(
+    ///     collective_id,
+    ///     Interest::new(reviewer.clone(), "auth", vec![0.1; 384], 0.8),
+    /// )?;
+    /// let alerts = db.watch_interests(&reviewer)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, interest), fields(agent_id = %interest.agent_id, name = %interest.name))]
+    pub fn register_interest(&self, collective_id: CollectiveId, interest: Interest) -> Result<()> {
+        self.check_writable()?;
+        validate_agent_id(interest.agent_id.as_str())?;
+        if interest.name.is_empty() {
+            return Err(ValidationError::required_field("name").into());
+        }
+        if !(-1.0..=1.0).contains(&interest.min_similarity) {
+            return Err(ValidationError::invalid_field(
+                "min_similarity",
+                "must be between -1.0 and 1.0",
+            )
+            .into());
+        }
+        let collective = self
+            .storage
+            .get_collective(collective_id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::collective(collective_id)))?;
+        let expected_dim = collective.embedding_dimension as usize;
+        if interest.embedding.len() != expected_dim {
+            return Err(ValidationError::dimension_mismatch(
+                expected_dim,
+                interest.embedding.len(),
+            )
+            .into());
+        }
+
+        self.storage.upsert_interest(collective_id, &interest)?;
+        info!(collective = %collective_id, "Interest registered");
+        Ok(())
+    }
+
+    /// Removes an agent's named interest from a collective.
+    ///
+    /// Returns `true` if it existed.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::ReadOnly`] in read-only mode
+    #[instrument(skip(self))]
+    pub fn remove_interest(
+        &self,
+        collective_id: CollectiveId,
+        agent_id: &AgentId,
+        name: &str,
+    ) -> Result<bool> {
+        self.check_writable()?;
+        self.storage
+            .remove_interest(collective_id, agent_id.as_str(), name)
+    }
+
+    /// Returns the standing interests registered in a collective.
+    pub fn list_interests(&self, collective_id: CollectiveId) -> Result<Vec<Interest>> {
+        self.storage.get_interests(collective_id)
+    }
+
+    /// Subscribes to experiences matching an agent's interests.
+    ///
+    /// The stream receives one [`Created`](WatchEventType::Created) event
+    /// per new experience matching any interest the agent registered with
+    /// [`register_interest()`](Self::register_interest), in any collective.
+    /// Matches are routed in-process only, while the stream is open.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] if the agent ID is invalid
+    pub fn watch_interests(&self, agent_id: &AgentId) -> Result<WatchStream> {
+        validate_agent_id(agent_id.as_str())?;
+        self.watch.subscribe_agent(agent_id.as_str())
+    }
+
+    /// Sends a creation event to every other agent with a matching
+    /// interest in the experience's collective.
+    fn notify_interests(&self, event: &WatchEvent, experience: &Experience) -> Result<()> {
+        if !self.watch.has_agent_subscribers() {
+            return Ok(());
+        }
+        let mut notified = HashSet::new();
+        for interest in self.storage.get_interests(experience.collective_id)? {
+            if interest.agent_id != experience.source_agent
+                && !notified.contains(&interest.agent_id)
+                && interest.matches(&experience.embedding)
+            {
+                self.watch
+                    .emit_to_agent(interest.agent_id.as_str(), event)?;
+                notified.insert(interest.agent_id);
+            }
+        }
+        Ok(())
+    }
+
     // =========================================================================
     // Cross-Process Watch (E4-S02)
     // =========================================================================
@@ -2283,6 +10538,78 @@ impl PulseDB {
         Ok((events, new_seq))
     }
 
+    // =========================================================================
+    // Change Log
+    // =========================================================================
+
+    /// Returns changes to any entity after sequence `since_seq`, oldest
+    /// first.
+    ///
+    /// Unlike [`poll_changes()`](Self::poll_changes), this covers relations,
+    /// insights, and collectives as well as experiences. At most 1000
+    /// changes are returned per call; pass the last change's `sequence`
+    /// back to read on. Works across processes: a reader can follow a
+    /// writer's changes through the shared file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// use pulsedb::ChangedEntity;
+    ///
+    /// let mut seq = db.get_current_sequence()?;
+    /// let collective_id = db.create_collective("example")?;
+    ///
+    /// let changes = db.changes_since(seq)?;
+    /// assert_eq!(changes[0].entity, ChangedEntity::Collective(collective_id));
+    /// seq = changes.last().map_or(seq, |c| c.sequence);
+    /// assert!(db.changes_since(seq)?.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn changes_since(&self, since_seq: u64) -> Result<Vec<Change>> {
+        let events = self
+            .storage
+            .poll_sync_events(since_seq, CHANGES_PAGE_SIZE)?;
+        Ok(events
+            .iter()
+            .map(|(seq, record)| Change::from_record(*seq, record))
+            .collect())
+    }
+
+    /// Subscribes to every change committed by this `PulseDB` instance
+    /// from now on.
+    ///
+    /// The subscription receives the same [`Change`]s as
+    /// [`changes_since()`](Self::changes_since), pushed as each write
+    /// commits, so agents sharing the database learn about new knowledge
+    /// without polling. Writes from other processes are not pushed; follow
+    /// those with `changes_since()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn main() -> pulsedb::Result<()> {
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+    /// use pulsedb::{ChangedEntity, WatchEventType};
+    ///
+    /// let changes = db.subscribe()?;
+    /// let collective_id = db.create_collective("example")?;
+    ///
+    /// let change = changes.try_recv().unwrap();
+    /// assert_eq!(change.entity, ChangedEntity::Collective(collective_id));
+    /// assert_eq!(change.change_type, WatchEventType::Created);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribe(&self) -> Result<ChangeSubscription> {
+        let current_seq = self.storage.get_wal_sequence()?;
+        self.watch.subscribe_changes(current_seq)
+    }
+
     // =========================================================================
     // Sync WAL Compaction (feature: sync)
     // =========================================================================
@@ -2363,7 +10690,11 @@ impl PulseDB {
             index.insert_experience(id, &embedding)?;
         }
 
-        debug!(id = %id, "Synced experience applied");
+        debug!(
+            id = %id,
+            content = %self.loggable(&experience.content),
+            "Synced experience applied"
+        );
         Ok(())
     }
 
@@ -2458,7 +10789,11 @@ impl PulseDB {
             index.insert_experience(exp_id, &embedding)?;
         }
 
-        debug!(id = %id, "Synced insight applied");
+        debug!(
+            id = %id,
+            content = %self.loggable(&insight.content),
+            "Synced insight applied"
+        );
         Ok(())
     }
 
@@ -2495,13 +10830,11 @@ impl PulseDB {
     #[allow(dead_code)] // Called by sync applier (Phase 3)
     pub fn apply_synced_collective(&self, collective: Collective) -> Result<()> {
         let id = collective.id;
-        let dimension = collective.embedding_dimension as usize;
 
         self.storage.save_collective(&collective)?;
 
-        // Create HNSW indexes (same as create_collective)
-        let exp_index = crate::vector::HnswIndex::new(dimension, &self.config.hnsw);
-        let insight_index = crate::vector::HnswIndex::new(dimension, &self.config.hnsw);
+        // Create indexes (same as create_collective)
+        let (exp_index, insight_index) = self.empty_indexes(&collective)?;
         self.vectors
             .write()
             .map_err(|_| PulseDBError::vector("Vectors lock poisoned"))?
@@ -2510,12 +10843,95 @@ impl PulseDB {
             .write()
             .map_err(|_| PulseDBError::vector("Insight vectors lock poisoned"))?
             .insert(id, insight_index);
+        self.touch_collective(id);
 
         debug!(id = %id, "Synced collective applied");
         Ok(())
     }
 }
 
+/// The error for deleting an experience that insights still cite under
+/// [`InsightSourceCascade::Block`].
+fn cited_by_insights(id: ExperienceId, count: usize) -> PulseDBError {
+    ValidationError::invalid_field(
+        "id",
+        format!("experience {} is a source of {} insight(s)", id, count),
+    )
+    .into()
+}
+
+/// Orders experiences oldest first, by timestamp then ID.
+///
+/// Index tables keyed by ID are only chronological for time-ordered IDs,
+/// so listings that promise recording order sort explicitly.
+fn sort_by_recording(experiences: &mut [Experience]) {
+    experiences.sort_by_key(|experience| (experience.timestamp, *experience.id.as_bytes()));
+}
+
+/// Runs `work` on every item, with up to `threads` scoped workers each
+/// claiming the next unclaimed item in turn, and returns the outputs in
+/// item order. One thread (or one item) runs inline.
+fn claim_in_parallel<T: Sync, R: Send>(
+    items: &[T],
+    threads: usize,
+    work: impl Fn(&T) -> R + Sync,
+) -> Result<Vec<R>> {
+    let threads = threads.min(items.len());
+    if threads <= 1 {
+        return Ok(items.iter().map(work).collect());
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut done = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut claimed = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(i) else {
+                            break claimed;
+                        };
+                        claimed.push((i, work(item)));
+                    }
+                })
+            })
+            .collect();
+        let mut done = Vec::with_capacity(items.len());
+        for worker in workers {
+            done.extend(
+                worker
+                    .join()
+                    .map_err(|_| PulseDBError::internal("Search worker panicked"))?,
+            );
+        }
+        Ok::<_, PulseDBError>(done)
+    })?;
+    done.sort_unstable_by_key(|(i, _)| *i);
+    Ok(done.into_iter().map(|(_, output)| output).collect())
+}
+
+/// Worker threads for fanning a search out over several collectives.
+fn search_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Returns true if a graph's copy of a vector matches the stored one.
+///
+/// A graph built from the caller's embedding holds it unquantized, so
+/// under a lossy [`EmbeddingStorage`] the stored copy may differ by up to
+/// one quantization step.
+fn same_embedding(graph: &[f32], stored: &[f32], storage: EmbeddingStorage) -> bool {
+    if graph.len() != stored.len() {
+        return false;
+    }
+    if storage == EmbeddingStorage::F32 {
+        return graph == stored;
+    }
+    let step = stored.iter().fold(0.0f32, |max, x| max.max(x.abs())) / 127.0;
+    graph.iter().zip(stored).all(|(a, b)| (a - b).abs() <= step)
+}
+
 // PulseDB is auto Send + Sync: Box<dyn StorageEngine + Send + Sync>,
 // Box<dyn EmbeddingService + Send + Sync>, and Config are all Send + Sync.
 
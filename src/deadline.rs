@@ -0,0 +1,83 @@
+//! Cooperative deadlines for [`Config::timeouts`](crate::Config::timeouts).
+//!
+//! A [`Deadline`] is the cancellation token a long operation carries. The
+//! operation calls [`Deadline::check()`] between units of work and stops
+//! with [`PulseDBError::Timeout`] once the deadline has passed; nothing is
+//! interrupted preemptively, so a check must come before any unit of work
+//! that changes state.
+
+use std::time::{Duration, Instant};
+
+use crate::error::{PulseDBError, Result, TimedOperation};
+
+/// When an operation must give up, if ever.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Deadline {
+    operation: TimedOperation,
+    /// The configured limit and the instant it runs out.
+    expiry: Option<(Duration, Instant)>,
+}
+
+impl Deadline {
+    /// Starts the clock for `operation`, limited to `limit` if set.
+    pub(crate) fn start(operation: TimedOperation, limit: Option<Duration>) -> Self {
+        Self {
+            operation,
+            expiry: limit.map(|limit| (limit, Instant::now() + limit)),
+        }
+    }
+
+    /// A deadline that never passes.
+    pub(crate) fn unbounded(operation: TimedOperation) -> Self {
+        Self::start(operation, None)
+    }
+
+    /// Time left before the deadline, or `None` if unbounded.
+    pub(crate) fn remaining(&self) -> Option<Duration> {
+        self.expiry
+            .map(|(_, at)| at.saturating_duration_since(Instant::now()))
+    }
+
+    /// Fails with [`PulseDBError::Timeout`] if the deadline has passed.
+    #[inline]
+    pub(crate) fn check(&self) -> Result<()> {
+        match self.expiry {
+            Some((_, at)) if Instant::now() >= at => Err(self.expired()),
+            _ => Ok(()),
+        }
+    }
+
+    /// The error for this deadline having passed, for callers that
+    /// detect it themselves (e.g. a timed wait running out).
+    pub(crate) fn expired(&self) -> PulseDBError {
+        PulseDBError::timeout(
+            self.operation,
+            self.expiry.map_or(Duration::ZERO, |(limit, _)| limit),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deadline_check() {
+        let unbounded = Deadline::unbounded(TimedOperation::Search);
+        assert!(unbounded.check().is_ok());
+        assert!(unbounded.remaining().is_none());
+
+        let deadline = Deadline::start(TimedOperation::Rebuild, Some(Duration::from_millis(5)));
+        assert!(deadline.check().is_ok());
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(deadline.remaining(), Some(Duration::ZERO));
+        let err = deadline.check().unwrap_err();
+        assert!(matches!(
+            err,
+            PulseDBError::Timeout {
+                operation: TimedOperation::Rebuild,
+                limit,
+            } if limit == Duration::from_millis(5)
+        ));
+    }
+}
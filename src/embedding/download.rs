@@ -0,0 +1,382 @@
+//! Resumable downloads for the builtin model files.
+//!
+//! Bytes are appended to a `{file}.part` sibling as they arrive. When a
+//! transfer drops, the next attempt asks the server for the rest with a
+//! `Range` request instead of starting over, and the `.part` file also
+//! survives the process, so a restart picks up where the last run stopped.
+//! Failed attempts are retried with exponential backoff, and each mirror
+//! is tried in turn before giving up. The file only appears under its real
+//! name once it is complete.
+//!
+//! The HTTP client sits behind [`Fetch`] so the retry and resume logic can
+//! be exercised without a network.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tracing::{debug, warn};
+
+use super::verify;
+use crate::error::{PulseDBError, Result};
+
+/// Longest delay between two attempts.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// A response body, positioned at the requested offset.
+pub(crate) struct FetchResponse {
+    /// The server honoured the `Range` request (`206 Partial Content`).
+    /// When false the body starts at byte 0.
+    pub partial: bool,
+
+    /// Length of the body, from `Content-Length`.
+    pub len: Option<u64>,
+
+    pub body: Box<dyn Read>,
+}
+
+/// Why a fetch failed.
+#[derive(Debug)]
+pub(crate) enum FetchError {
+    /// Worth retrying: connection errors, timeouts, 5xx, 429.
+    Transient(String),
+
+    /// The requested offset is at or past the end of the file (`416`).
+    RangeNotSatisfiable,
+
+    /// Retrying this URL won't help (404, 403, ...); try the next mirror.
+    Fatal(String),
+}
+
+/// Issues HTTP GETs for [`download`].
+pub(crate) trait Fetch {
+    /// Requests `url`, from byte `offset` onwards when `offset > 0`.
+    fn fetch(&self, url: &str, offset: u64) -> std::result::Result<FetchResponse, FetchError>;
+}
+
+/// How often and how patiently to retry.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RetryPolicy {
+    /// Attempts per URL after the first fails.
+    pub max_retries: u32,
+
+    /// Delay before the first retry; doubles for each further retry.
+    pub backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `retry` (1-based).
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        let factor = 1u32 << retry.saturating_sub(1).min(16);
+        self.backoff.saturating_mul(factor).min(MAX_BACKOFF)
+    }
+}
+
+/// The `.part` file a download of `dest` accumulates in.
+pub(crate) fn partial_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+/// Downloads to `dest` from the first of `urls` that succeeds, returning
+/// the file's SHA-256.
+///
+/// `progress` is called with `(bytes on disk, total bytes if known)` as
+/// data arrives, including the bytes already present when resuming.
+///
+/// # Errors
+///
+/// An embedding error carrying the last failure once every URL has run
+/// out of retries. The `.part` file is left in place so the next call
+/// resumes it.
+pub(crate) fn download(
+    fetch: &dyn Fetch,
+    urls: &[String],
+    dest: &Path,
+    policy: &RetryPolicy,
+    progress: &mut dyn FnMut(u64, Option<u64>),
+) -> Result<String> {
+    let part = partial_path(dest);
+    let mut last_error = String::from("no download URLs");
+
+    for url in urls {
+        let mut retries = 0;
+        loop {
+            match attempt(fetch, url, &part, progress) {
+                Ok(Attempt::Complete) => {
+                    fs::rename(&part, dest).map_err(|e| {
+                        PulseDBError::embedding(format!(
+                            "Failed to finalize download {}: {e}",
+                            dest.display()
+                        ))
+                    })?;
+                    return verify::sha256_file(dest).map_err(|e| {
+                        PulseDBError::embedding(format!("Failed to hash {}: {e}", dest.display()))
+                    });
+                }
+                Ok(Attempt::Interrupted { progressed, reason }) => {
+                    if progressed {
+                        retries = 0;
+                    }
+                    last_error = format!("{url}: {reason}");
+                }
+                Err(FetchError::RangeNotSatisfiable) => {
+                    // The partial file is as long as (or longer than) the
+                    // remote one, so it can't be trusted; start over.
+                    let _ = fs::remove_file(&part);
+                    last_error = format!("{url}: partial download does not match the server");
+                }
+                Err(FetchError::Transient(reason)) => {
+                    last_error = format!("{url}: {reason}");
+                }
+                Err(FetchError::Fatal(reason)) => {
+                    last_error = format!("{url}: {reason}");
+                    warn!(url, error = %reason, "Download failed, trying next mirror");
+                    break;
+                }
+            }
+
+            retries += 1;
+            if retries > policy.max_retries {
+                warn!(url, error = %last_error, "Download retries exhausted");
+                break;
+            }
+            let delay = policy.delay(retries);
+            debug!(url, retry = retries, ?delay, error = %last_error, "Retrying download");
+            std::thread::sleep(delay);
+        }
+    }
+
+    Err(PulseDBError::embedding(format!(
+        "Failed to download {}: {last_error}",
+        dest.display()
+    )))
+}
+
+/// Outcome of one request that got a response.
+enum Attempt {
+    Complete,
+    Interrupted { progressed: bool, reason: String },
+}
+
+/// Fetches the rest of `part` from `url` and appends it.
+fn attempt(
+    fetch: &dyn Fetch,
+    url: &str,
+    part: &Path,
+    progress: &mut dyn FnMut(u64, Option<u64>),
+) -> std::result::Result<Attempt, FetchError> {
+    let local = |e: io::Error| FetchError::Fatal(format!("{}: {e}", part.display()));
+
+    let offset = match fs::metadata(part) {
+        Ok(meta) => meta.len(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+        Err(e) => return Err(local(e)),
+    };
+    let response = fetch.fetch(url, offset)?;
+
+    // A server that ignores `Range` sends the whole file again.
+    let start = if response.partial { offset } else { 0 };
+    let total = response.len.map(|len| start + len);
+    let mut file = if start == 0 {
+        File::create(part)
+    } else {
+        OpenOptions::new().append(true).open(part)
+    }
+    .map_err(local)?;
+
+    let mut received = start;
+    progress(received, total);
+    let mut body = response.body;
+    let mut buf = vec![0u8; 1 << 16];
+    let read_error = loop {
+        match body.read(&mut buf) {
+            Ok(0) => break None,
+            Ok(n) => {
+                file.write_all(&buf[..n]).map_err(local)?;
+                received += n as u64;
+                progress(received, total);
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => break Some(e),
+        }
+    };
+    file.sync_all().map_err(local)?;
+
+    let progressed = received > offset;
+    match (read_error, total) {
+        (Some(e), _) => Ok(Attempt::Interrupted {
+            progressed,
+            reason: format!("connection dropped after {received} bytes: {e}"),
+        }),
+        (None, Some(total)) if received < total => Ok(Attempt::Interrupted {
+            progressed,
+            reason: format!("received {received} of {total} bytes"),
+        }),
+        (None, Some(total)) if received > total => {
+            let _ = fs::remove_file(part);
+            Err(FetchError::Transient(format!(
+                "received {received} bytes, more than the expected {total}"
+            )))
+        }
+        (None, _) => Ok(Attempt::Complete),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Serves `data`, honouring `Range`, and cuts each body off after
+    /// `chunk` bytes. URLs starting with "bad" return 404.
+    struct Flaky {
+        data: Vec<u8>,
+        chunk: usize,
+        ranges: bool,
+        requests: RefCell<Vec<(String, u64)>>,
+    }
+
+    struct CutOff(io::Cursor<Vec<u8>>, bool);
+
+    impl Read for CutOff {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.read(buf)? {
+                0 if self.1 => Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset")),
+                n => Ok(n),
+            }
+        }
+    }
+
+    impl Fetch for Flaky {
+        fn fetch(&self, url: &str, offset: u64) -> std::result::Result<FetchResponse, FetchError> {
+            self.requests.borrow_mut().push((url.to_string(), offset));
+            if url.starts_with("bad") {
+                return Err(FetchError::Fatal("404 Not Found".into()));
+            }
+            let start = if self.ranges { offset as usize } else { 0 };
+            if start > 0 && start >= self.data.len() {
+                return Err(FetchError::RangeNotSatisfiable);
+            }
+            let rest = &self.data[start..];
+            let end = rest.len().min(self.chunk);
+            Ok(FetchResponse {
+                partial: self.ranges && offset > 0,
+                len: Some(rest.len() as u64),
+                body: Box::new(CutOff(
+                    io::Cursor::new(rest[..end].to_vec()),
+                    end < rest.len(),
+                )),
+            })
+        }
+    }
+
+    fn flaky(len: usize, chunk: usize, ranges: bool) -> Flaky {
+        Flaky {
+            data: (0..len).map(|i| (i % 251) as u8).collect(),
+            chunk,
+            ranges,
+            requests: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            backoff: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_resumes_with_range_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.onnx");
+        let server = flaky(1000, 300, true);
+
+        let mut seen = Vec::new();
+        let digest = download(
+            &server,
+            &["https://hub".to_string()],
+            &dest,
+            &policy(1),
+            &mut |done, total| seen.push((done, total)),
+        )
+        .unwrap();
+
+        assert_eq!(fs::read(&dest).unwrap(), server.data);
+        assert_eq!(digest, verify::sha256_file(&dest).unwrap());
+        assert!(!partial_path(&dest).exists());
+        let offsets: Vec<u64> = server.requests.borrow().iter().map(|r| r.1).collect();
+        assert_eq!(offsets, vec![0, 300, 600, 900]);
+        assert_eq!(seen.last(), Some(&(1000, Some(1000))));
+    }
+
+    #[test]
+    fn test_restarts_when_server_ignores_range() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("tokenizer.json");
+        let server = flaky(100, 100, false);
+        fs::write(partial_path(&dest), [9u8; 40]).unwrap();
+
+        download(
+            &server,
+            &["https://hub".to_string()],
+            &dest,
+            &policy(0),
+            &mut |_, _| {},
+        )
+        .unwrap();
+        assert_eq!(fs::read(&dest).unwrap(), server.data);
+    }
+
+    #[test]
+    fn test_oversized_partial_starts_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.onnx");
+        let server = flaky(100, 100, true);
+        fs::write(partial_path(&dest), [0u8; 150]).unwrap();
+
+        download(
+            &server,
+            &["https://hub".to_string()],
+            &dest,
+            &policy(1),
+            &mut |_, _| {},
+        )
+        .unwrap();
+        assert_eq!(fs::read(&dest).unwrap(), server.data);
+    }
+
+    #[test]
+    fn test_falls_through_mirrors_and_keeps_partial() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.onnx");
+
+        let server = flaky(1000, 300, true);
+        let urls = ["bad-mirror".to_string(), "https://hub".to_string()];
+        let err = download(&server, &urls, &dest, &policy(0), &mut |_, _| {}).unwrap_err();
+        assert!(err.to_string().contains("after 300 bytes"));
+        assert!(!dest.exists());
+        assert_eq!(fs::metadata(partial_path(&dest)).unwrap().len(), 300);
+
+        // A later call resumes the leftover partial file
+        download(&server, &urls, &dest, &policy(3), &mut |_, _| {}).unwrap();
+        assert_eq!(fs::read(&dest).unwrap(), server.data);
+        let requests = server.requests.borrow();
+        assert_eq!(requests[2], ("bad-mirror".to_string(), 300));
+        assert_eq!(requests[3], ("https://hub".to_string(), 300));
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_cap() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            backoff: Duration::from_secs(1),
+        };
+        assert_eq!(policy.delay(1), Duration::from_secs(1));
+        assert_eq!(policy.delay(3), Duration::from_secs(4));
+        assert_eq!(policy.delay(40), MAX_BACKOFF);
+    }
+}
@@ -0,0 +1,140 @@
+//! Deterministic hash-based embeddings.
+//!
+//! [`HashingEmbedding`] turns text into a vector with the hashing trick:
+//! every lowercased word is hashed to a dimension and a sign, and the
+//! counts are L2-normalized. Texts sharing words land near each other, so
+//! similarity search behaves plausibly, but there is no semantics beyond
+//! word overlap. It needs no model files and no features, which makes it
+//! useful for tests, CI, and examples that exercise the code paths where
+//! PulseDB generates embeddings itself.
+
+use crate::embedding::EmbeddingService;
+use crate::error::Result;
+use crate::types::Embedding;
+
+/// FNV-1a offset basis (64-bit).
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a prime (64-bit).
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Embedding provider that hashes words into a fixed-size vector.
+///
+/// Output depends only on the text and the dimension — not on the
+/// platform, the process, or the Rust version — so embeddings recorded in
+/// one run can be searched in another.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::embedding::{EmbeddingService, HashingEmbedding};
+///
+/// let service = HashingEmbedding::new(384);
+/// let a = service.embed("Rust borrow checker error").unwrap();
+/// let b = service.embed("rust borrow checker").unwrap();
+/// let c = service.embed("Database migration failed").unwrap();
+///
+/// let dot = |x: &[f32], y: &[f32]| x.iter().zip(y).map(|(p, q)| p * q).sum::<f32>();
+/// assert!(dot(&a, &b) > dot(&a, &c));
+/// assert_eq!(a, service.embed("Rust borrow checker error").unwrap());
+/// ```
+#[derive(Clone, Debug)]
+pub struct HashingEmbedding {
+    dimension: usize,
+}
+
+impl HashingEmbedding {
+    /// Creates a hashing provider producing `dimension`-length vectors.
+    pub fn new(dimension: usize) -> Self {
+        Self { dimension }
+    }
+}
+
+impl EmbeddingService for HashingEmbedding {
+    fn embed(&self, text: &str) -> Result<Embedding> {
+        let mut vector = vec![0.0f32; self.dimension];
+        if self.dimension == 0 {
+            return Ok(vector);
+        }
+
+        for word in text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+        {
+            add_feature(&mut vector, &word.to_lowercase());
+        }
+        // Text with no words (or whose words cancelled out) still needs a
+        // non-zero vector for cosine distance
+        if vector.iter().all(|&x| x == 0.0) {
+            add_feature(&mut vector, text);
+        }
+
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|x| *x /= norm);
+        }
+        Ok(vector)
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Embedding>> {
+        texts.iter().map(|text| self.embed(text)).collect()
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+}
+
+/// Adds ±1 at the dimension a feature hashes to.
+///
+/// The top bit picks the sign so collisions between unrelated words tend
+/// to cancel rather than accumulate.
+fn add_feature(vector: &mut [f32], feature: &str) {
+    let hash = fnv1a(feature.as_bytes());
+    let index = (hash % vector.len() as u64) as usize;
+    vector[index] += if hash >> 63 == 0 { 1.0 } else { -1.0 };
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dot(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_hashing_embedding_is_stable() {
+        // Pinned so a change to the hash shows up as a format change
+        assert_eq!(fnv1a(b"pulsedb"), 0x2991_12f2_ba62_0ea4);
+
+        let service = HashingEmbedding::new(64);
+        let embedding = service.embed("Hello, hello world").unwrap();
+        assert_eq!(embedding.len(), 64);
+        assert!((dot(&embedding, &embedding) - 1.0).abs() < 1e-5);
+        assert_eq!(embedding, service.embed("hello HELLO world!").unwrap());
+    }
+
+    #[test]
+    fn test_hashing_embedding_handles_text_without_words() {
+        let service = HashingEmbedding::new(16);
+        for text in ["", "!!!", "  "] {
+            let embedding = service.embed(text).unwrap();
+            assert!((dot(&embedding, &embedding) - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_hashing_embedding_batch_matches_single() {
+        let service = HashingEmbedding::new(32);
+        let batch = service.embed_batch(&["alpha beta", "gamma"]).unwrap();
+        assert_eq!(batch[0], service.embed("alpha beta").unwrap());
+        assert_eq!(batch[1], service.embed("gamma").unwrap());
+    }
+}
@@ -7,6 +7,7 @@
 //!
 //! - [`ExternalEmbedding`] - For pre-computed embeddings (e.g., OpenAI, Cohere)
 //! - `OnnxEmbedding` - Built-in ONNX model (requires `builtin-embeddings` feature)
+//! - [`HashingEmbedding`] - Deterministic word-hashing vectors for tests and examples
 //!
 //! # Example
 //!
@@ -26,6 +27,21 @@
 #[cfg_attr(docsrs, doc(cfg(feature = "builtin-embeddings")))]
 pub mod onnx;
 
+#[cfg(any(feature = "builtin-embeddings", test))]
+pub(crate) mod download;
+
+mod hashing;
+mod normalize;
+
+#[cfg(any(feature = "builtin-embeddings", test))]
+pub(crate) mod pool;
+
+#[cfg(any(feature = "builtin-embeddings", test))]
+pub(crate) mod verify;
+
+pub use hashing::HashingEmbedding;
+pub use normalize::TextNormalization;
+
 use crate::error::{PulseDBError, Result};
 use crate::types::Embedding;
 
@@ -215,17 +231,34 @@ pub fn create_embedding_service(
             Ok(Box::new(ExternalEmbedding::new(dimension)))
         }
 
+        EmbeddingProvider::Hashing => Ok(Box::new(HashingEmbedding::new(
+            config.embedding_dimension.size(),
+        ))),
+
         #[cfg(feature = "builtin-embeddings")]
-        EmbeddingProvider::Builtin { model_path } => {
+        EmbeddingProvider::Builtin {
+            model_path,
+            execution_provider,
+        } => {
             let dim = config.embedding_dimension.size();
-            match onnx::OnnxEmbedding::with_dimension(model_path.clone(), dim) {
+            let options = onnx::OnnxOptions {
+                threads: config.embedding_threads,
+                sessions: config.onnx_sessions.unwrap_or(config.embedding_threads),
+                execution_provider: *execution_provider,
+            };
+            match onnx::OnnxEmbedding::with_options(model_path.clone(), dim, &options) {
                 Ok(service) => Ok(Box::new(service)),
                 Err(ref e) if e.to_string().contains("Model not found") => {
                     tracing::info!(
                         "Builtin embedding model not found, downloading (dimension: {dim})..."
                     );
-                    let _path = onnx::OnnxEmbedding::download_default_model(dim)?;
-                    let service = onnx::OnnxEmbedding::with_dimension(model_path.clone(), dim)?;
+                    let _path = onnx::OnnxEmbedding::download_default_model_with(
+                        dim,
+                        &config.model_download,
+                        None,
+                    )?;
+                    let service =
+                        onnx::OnnxEmbedding::with_options(model_path.clone(), dim, &options)?;
                     Ok(Box::new(service))
                 }
                 Err(e) => Err(e),
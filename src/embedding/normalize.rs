@@ -0,0 +1,150 @@
+//! Text normalization applied before embedding.
+//!
+//! Two texts that differ only in case, spacing, or markdown decoration
+//! should embed alike. [`TextNormalization`] describes the clean-up, and
+//! PulseDB applies it to everything it embeds — experience and insight
+//! content at record time, and query text in
+//! [`PulseDB::embed_query()`](crate::PulseDB::embed_query) — so the write
+//! and read sides always see the same preprocessing. Stored content is
+//! never changed, and caller-supplied embeddings are used as given.
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// Pre-embedding text clean-up steps.
+///
+/// Every step is off by default, so enabling normalization on an existing
+/// database changes what new records embed to; re-embed older records if
+/// they need to stay comparable. [`standard()`](Self::standard) turns on
+/// the steps that are safe for most prose and code.
+///
+/// Steps run in field order: fences and boilerplate lines are dropped
+/// first, then case is folded, then whitespace collapsed.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::TextNormalization;
+///
+/// let normalization = TextNormalization {
+///     strip_boilerplate: vec!["Signed-off-by:".into()],
+///     ..TextNormalization::standard()
+/// };
+/// let text = "Fix the  PARSER\n```rust\nlet x = 1;\n```\nSigned-off-by: a@b.c";
+/// assert_eq!(normalization.apply(text), "fix the parser let x = 1;");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextNormalization {
+    /// Remove markdown code fence lines (```` ``` ```` or `~~~`, with any
+    /// language tag), keeping the code between them.
+    pub strip_code_fences: bool,
+
+    /// Drop lines that start with any of these phrases, ignoring case and
+    /// leading whitespace — signatures, generated-by footers, and other
+    /// boilerplate that would pull unrelated records together.
+    pub strip_boilerplate: Vec<String>,
+
+    /// Lowercase the text.
+    pub lowercase: bool,
+
+    /// Collapse runs of whitespace, including newlines, into one space and
+    /// trim the ends.
+    pub collapse_whitespace: bool,
+}
+
+impl TextNormalization {
+    /// Strips code fences, lowercases, and collapses whitespace. No
+    /// boilerplate phrases are set.
+    pub fn standard() -> Self {
+        Self {
+            strip_code_fences: true,
+            strip_boilerplate: Vec::new(),
+            lowercase: true,
+            collapse_whitespace: true,
+        }
+    }
+
+    /// Returns true if no step is enabled.
+    pub fn is_noop(&self) -> bool {
+        !self.strip_code_fences
+            && self.strip_boilerplate.is_empty()
+            && !self.lowercase
+            && !self.collapse_whitespace
+    }
+
+    /// Applies the enabled steps to `text`.
+    ///
+    /// Borrows `text` unchanged when no step is enabled.
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.is_noop() {
+            return Cow::Borrowed(text);
+        }
+
+        let boilerplate: Vec<String> = self
+            .strip_boilerplate
+            .iter()
+            .map(|phrase| phrase.to_lowercase())
+            .collect();
+        let mut out = String::with_capacity(text.len());
+        for line in text.lines() {
+            let trimmed = line.trim_start();
+            if self.strip_code_fences && (trimmed.starts_with("```") || trimmed.starts_with("~~~"))
+            {
+                continue;
+            }
+            if !boilerplate.is_empty() {
+                let lower = trimmed.to_lowercase();
+                if boilerplate.iter().any(|phrase| lower.starts_with(phrase)) {
+                    continue;
+                }
+            }
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(line);
+        }
+
+        if self.lowercase {
+            out = out.to_lowercase();
+        }
+        if self.collapse_whitespace {
+            out = out.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+        Cow::Owned(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_normalization_borrows() {
+        let text = "  Keep   AS IS\n";
+        assert!(matches!(
+            TextNormalization::default().apply(text),
+            Cow::Borrowed(t) if t == text
+        ));
+    }
+
+    #[test]
+    fn test_standard_normalization_makes_variants_equal() {
+        let n = TextNormalization::standard();
+        let a = "Use `?` for\terror propagation\n\n~~~\nfoo()?;\n~~~";
+        let b = "use `?` FOR error   propagation foo()?;";
+        assert_eq!(n.apply(a), n.apply(b));
+    }
+
+    #[test]
+    fn test_boilerplate_lines_are_dropped_case_insensitively() {
+        let n = TextNormalization {
+            strip_boilerplate: vec!["Generated by".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            n.apply("Cache misses spike\n  generated BY tool v2\nafter deploy"),
+            "Cache misses spike\nafter deploy"
+        );
+    }
+}
@@ -35,17 +35,28 @@
 //!
 //! - Embedding generation is CPU-intensive
 //! - Use `embed_batch()` for multiple texts (more efficient due to batched inference)
+//! - Inference runs on a pool of worker threads sharing one or more model
+//!   sessions round-robin (see [`OnnxOptions`]); tokenization and pooling
+//!   run on the calling thread
 //! - Consider using `spawn_blocking` when called from async context
 
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::Arc;
 
 use ndarray::Array2;
+use ort::execution_providers::{
+    CUDAExecutionProvider, CoreMLExecutionProvider, DirectMLExecutionProvider,
+    ExecutionProvider as _, ExecutionProviderDispatch,
+};
 use ort::session::builder::GraphOptimizationLevel;
 use ort::session::Session;
 use tokenizers::Tokenizer;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
+use super::download::{self, Fetch, FetchError, FetchResponse, RetryPolicy};
+use super::pool::{RoundRobin, WorkerPool};
+use super::verify;
+use crate::config::{ExecutionProvider, ModelDownloadConfig};
 use crate::embedding::EmbeddingService;
 use crate::error::{PulseDBError, Result};
 use crate::types::Embedding;
@@ -71,6 +82,49 @@ const TOKENIZER_FILENAME: &str = "tokenizer.json";
 // OnnxEmbedding struct
 // ---------------------------------------------------------------------------
 
+/// Runtime options for [`OnnxEmbedding`].
+///
+/// `threads` bounds how many inferences run at once; `sessions` bounds how
+/// many copies of the model are loaded. Workers take the sessions
+/// round-robin, so fewer sessions than threads trades some parallelism
+/// for memory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OnnxOptions {
+    /// Inference worker threads (values below 1 are treated as 1).
+    pub threads: usize,
+
+    /// Model sessions shared by the workers, clamped to `1..=threads`.
+    pub sessions: usize,
+
+    /// Hardware the sessions run on, falling back to the CPU when it is
+    /// unavailable.
+    pub execution_provider: ExecutionProvider,
+}
+
+impl Default for OnnxOptions {
+    fn default() -> Self {
+        Self {
+            threads: 1,
+            sessions: 1,
+            execution_provider: ExecutionProvider::Cpu,
+        }
+    }
+}
+
+/// Progress of one file in
+/// [`OnnxEmbedding::download_default_model_with()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// File being downloaded (`model.onnx` or `tokenizer.json`).
+    pub file: &'static str,
+
+    /// Bytes on disk so far, including any resumed from an earlier attempt.
+    pub downloaded: u64,
+
+    /// Size of the file, if the server reported it.
+    pub total: Option<u64>,
+}
+
 /// ONNX-based embedding service.
 ///
 /// Generates embeddings locally using an ONNX model via ONNX Runtime.
@@ -80,13 +134,16 @@ const TOKENIZER_FILENAME: &str = "tokenizer.json";
 ///
 /// # Thread Safety
 ///
-/// `OnnxEmbedding` is `Send + Sync`. ONNX Runtime's `Session` handles
-/// internal synchronization for concurrent inference requests.
+/// `OnnxEmbedding` is `Send + Sync`. `Session::run()` requires `&mut self`,
+/// so inference workers take turns on a set of sessions; concurrent
+/// requests are queued and run on as many sessions as there are.
 pub struct OnnxEmbedding {
-    /// ONNX Runtime session (the loaded model, ready for inference).
-    /// Wrapped in Mutex because `Session::run()` requires `&mut self`,
-    /// but our `EmbeddingService` trait uses `&self` for concurrent access.
-    session: Mutex<Session>,
+    /// Inference workers, sharing the ONNX Runtime sessions (the loaded
+    /// model, ready for inference) round-robin.
+    workers: WorkerPool<Arc<RoundRobin<Session>>>,
+
+    /// The sessions the workers share.
+    sessions: Arc<RoundRobin<Session>>,
 
     /// HuggingFace tokenizer (converts text to token IDs).
     /// Tokenizer is immutable after loading so no Mutex needed.
@@ -142,6 +199,46 @@ impl OnnxEmbedding {
     /// * `model_path` - Optional path to a model directory
     /// * `dimension` - Expected embedding dimension
     pub fn with_dimension(model_path: Option<PathBuf>, dimension: usize) -> Result<Self> {
+        Self::with_threads(model_path, dimension, 1)
+    }
+
+    /// Creates an ONNX embedding service running inference on `threads`
+    /// worker threads, each with its own session.
+    ///
+    /// Memory use grows with the model size per thread. ONNX Runtime's
+    /// intra-op parallelism is split between the sessions so they don't
+    /// oversubscribe the CPU.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_path` - Optional path to a model directory
+    /// * `dimension` - Expected embedding dimension
+    /// * `threads` - Inference workers (values below 1 are treated as 1)
+    pub fn with_threads(
+        model_path: Option<PathBuf>,
+        dimension: usize,
+        threads: usize,
+    ) -> Result<Self> {
+        let options = OnnxOptions {
+            threads,
+            sessions: threads,
+            ..Default::default()
+        };
+        Self::with_options(model_path, dimension, &options)
+    }
+
+    /// Creates an ONNX embedding service with explicit runtime options.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_path` - Optional path to a model directory
+    /// * `dimension` - Expected embedding dimension
+    /// * `options` - Worker threads and sessions; see [`OnnxOptions`]
+    pub fn with_options(
+        model_path: Option<PathBuf>,
+        dimension: usize,
+        options: &OnnxOptions,
+    ) -> Result<Self> {
         let max_length = match dimension {
             DEFAULT_DIMENSION => DEFAULT_MAX_LENGTH,
             768 => BGE_MAX_LENGTH,
@@ -157,13 +254,23 @@ impl OnnxEmbedding {
             "Loading ONNX embedding model"
         );
 
-        Self::load_from_dir(&model_dir, dimension, max_length)
+        Self::load_from_dir(&model_dir, dimension, max_length, options)
+    }
+
+    /// Returns the number of inference worker threads.
+    pub fn threads(&self) -> usize {
+        self.workers.size()
+    }
+
+    /// Returns the number of model sessions the workers share.
+    pub fn sessions(&self) -> usize {
+        self.sessions.len()
     }
 
     /// Downloads the default model files to the cache directory.
     ///
-    /// Downloads `model.onnx` and `tokenizer.json` from HuggingFace Hub
-    /// to `~/.cache/pulsedb/models/{model_name}/`.
+    /// Equivalent to [`download_default_model_with()`](Self::download_default_model_with)
+    /// with the default [`ModelDownloadConfig`] and no progress callback.
     ///
     /// # Arguments
     ///
@@ -175,17 +282,52 @@ impl OnnxEmbedding {
     ///
     /// The path to the model directory.
     pub fn download_default_model(dimension: usize) -> Result<PathBuf> {
-        let (model_name, model_url, tokenizer_url) = match dimension {
-            DEFAULT_DIMENSION => (
-                DEFAULT_MODEL_NAME,
-                "https://huggingface.co/sentence-transformers/all-MiniLM-L6-v2/resolve/main/onnx/model.onnx",
-                "https://huggingface.co/sentence-transformers/all-MiniLM-L6-v2/resolve/main/tokenizer.json",
-            ),
-            768 => (
-                BGE_MODEL_NAME,
-                "https://huggingface.co/BAAI/bge-base-en-v1.5/resolve/main/onnx/model.onnx",
-                "https://huggingface.co/BAAI/bge-base-en-v1.5/resolve/main/tokenizer.json",
-            ),
+        Self::download_default_model_with(dimension, &ModelDownloadConfig::default(), None)
+    }
+
+    /// Downloads the default model files to the cache directory.
+    ///
+    /// Downloads `model.onnx` and `tokenizer.json` from the first of
+    /// `config.mirrors` that serves them to
+    /// `~/.cache/pulsedb/models/{model_name}/`. Interrupted transfers are
+    /// retried with exponential backoff and resumed with HTTP `Range`
+    /// requests; a partial file left by an earlier run is resumed too.
+    ///
+    /// The SHA-256 of each file is recorded in a `checksums.sha256`
+    /// manifest beside it. Files that are already present are verified
+    /// against that manifest and downloaded again if they no longer match.
+    /// With `config.offline` set nothing is downloaded: present files are
+    /// verified and missing ones are an error.
+    ///
+    /// `progress`, if given, is called as data arrives.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use pulsedb::embedding::onnx::OnnxEmbedding;
+    /// use pulsedb::ModelDownloadConfig;
+    ///
+    /// # fn main() -> pulsedb::Result<()> {
+    /// let dir = OnnxEmbedding::download_default_model_with(
+    ///     384,
+    ///     &ModelDownloadConfig::default(),
+    ///     Some(&|p| {
+    ///         if let Some(total) = p.total {
+    ///             eprintln!("{}: {}/{} bytes", p.file, p.downloaded, total);
+    ///         }
+    ///     }),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn download_default_model_with(
+        dimension: usize,
+        config: &ModelDownloadConfig,
+        progress: Option<&dyn Fn(&DownloadProgress)>,
+    ) -> Result<PathBuf> {
+        let (model_name, repo) = match dimension {
+            DEFAULT_DIMENSION => (DEFAULT_MODEL_NAME, "sentence-transformers/all-MiniLM-L6-v2"),
+            768 => (BGE_MODEL_NAME, "BAAI/bge-base-en-v1.5"),
             _ => {
                 return Err(PulseDBError::embedding(format!(
                     "No default model for dimension {dimension}. \
@@ -195,6 +337,19 @@ impl OnnxEmbedding {
         };
 
         let cache_dir = default_cache_dir(model_name);
+        let model_path = cache_dir.join(MODEL_FILENAME);
+        let tokenizer_path = cache_dir.join(TOKENIZER_FILENAME);
+
+        if config.offline {
+            if !(model_path.exists() && tokenizer_path.exists()) {
+                return Err(PulseDBError::embedding(format!(
+                    "Model not found at {} and downloads are disabled (offline mode)",
+                    cache_dir.display()
+                )));
+            }
+            verify::verify_model_dir(&cache_dir)?;
+            return Ok(cache_dir);
+        }
 
         // Create directory
         std::fs::create_dir_all(&cache_dir).map_err(|e| {
@@ -214,33 +369,77 @@ impl OnnxEmbedding {
             PulseDBError::embedding(format!("Failed to acquire download lock: {e}"))
         })?;
 
-        let model_path = cache_dir.join(MODEL_FILENAME);
-        let tokenizer_path = cache_dir.join(TOKENIZER_FILENAME);
-
         // Double-check after acquiring lock — another process may have downloaded while we waited
         if model_path.exists() && tokenizer_path.exists() {
-            info!(dir = %cache_dir.display(), "Model files already downloaded by another process");
-            return Ok(cache_dir);
+            match verify::verify_model_dir(&cache_dir) {
+                Ok(true) => {
+                    info!(dir = %cache_dir.display(), "Model files already downloaded and verified");
+                    return Ok(cache_dir);
+                }
+                Ok(false) => {
+                    // Downloaded before checksums were recorded
+                    warn!(dir = %cache_dir.display(), "Model files have no checksums, downloading again");
+                }
+                Err(e) => {
+                    warn!(error = %e, "Cached model files failed verification, downloading again");
+                }
+            }
+            let _ = std::fs::remove_file(&model_path);
+            let _ = std::fs::remove_file(&tokenizer_path);
         }
 
-        // Download model if not present
-        if !model_path.exists() {
-            info!(url = model_url, dest = %model_path.display(), "Downloading ONNX model");
-            download_file(model_url, &model_path)?;
+        let fetch = UreqFetch::new();
+        let policy = RetryPolicy {
+            max_retries: config.max_retries,
+            backoff: config.retry_backoff,
+        };
+        let mut digests = Vec::with_capacity(2);
+        for (file, remote, dest) in [
+            (MODEL_FILENAME, "onnx/model.onnx", &model_path),
+            (TOKENIZER_FILENAME, "tokenizer.json", &tokenizer_path),
+        ] {
+            let urls: Vec<String> = config
+                .mirrors
+                .iter()
+                .map(|mirror| {
+                    format!(
+                        "{}/{repo}/resolve/main/{remote}",
+                        mirror.trim_end_matches('/')
+                    )
+                })
+                .collect();
+            info!(file, dest = %dest.display(), "Downloading model file");
+            let digest =
+                download::download(&fetch, &urls, dest, &policy, &mut |downloaded, total| {
+                    if let Some(progress) = progress {
+                        progress(&DownloadProgress {
+                            file,
+                            downloaded,
+                            total,
+                        });
+                    }
+                })?;
+            digests.push((file, digest));
         }
 
-        // Download tokenizer if not present
-        if !tokenizer_path.exists() {
-            info!(url = tokenizer_url, dest = %tokenizer_path.display(), "Downloading tokenizer");
-            download_file(tokenizer_url, &tokenizer_path)?;
-        }
+        verify::write_manifest(&cache_dir, &digests).map_err(|e| {
+            PulseDBError::embedding(format!(
+                "Failed to record model checksums in {}: {e}",
+                cache_dir.display()
+            ))
+        })?;
 
         info!(dir = %cache_dir.display(), "Model files ready");
         Ok(cache_dir)
     }
 
     /// Loads the model and tokenizer from a directory.
-    fn load_from_dir(model_dir: &Path, dimension: usize, max_length: usize) -> Result<Self> {
+    fn load_from_dir(
+        model_dir: &Path,
+        dimension: usize,
+        max_length: usize,
+        options: &OnnxOptions,
+    ) -> Result<Self> {
         let model_path = model_dir.join(MODEL_FILENAME);
         let tokenizer_path = model_dir.join(TOKENIZER_FILENAME);
 
@@ -261,13 +460,34 @@ impl OnnxEmbedding {
             )));
         }
 
-        let session = create_session(&model_path)?;
+        // Refuse files that no longer match the digests recorded at
+        // download time. Directories without a manifest are trusted as-is.
+        if verify::verify_model_dir(model_dir)? {
+            debug!(dir = %model_dir.display(), "Model files verified");
+        }
+
+        let threads = options.threads.max(1);
+        let session_count = options.sessions.clamp(1, threads);
+        let intra_threads = std::thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .div_ceil(session_count);
+        let sessions = (0..session_count)
+            .map(|_| create_session(&model_path, intra_threads, options.execution_provider))
+            .collect::<Result<Vec<_>>>()?;
+        let sessions = Arc::new(RoundRobin::new(sessions));
         let tokenizer = load_tokenizer(&tokenizer_path, max_length)?;
 
-        debug!(dimension, max_length, "ONNX embedding model loaded");
+        debug!(
+            dimension,
+            max_length,
+            threads,
+            sessions = session_count,
+            "ONNX embedding model loaded"
+        );
 
         Ok(Self {
-            session: Mutex::new(session),
+            workers: WorkerPool::new("pulsedb-embed", vec![Arc::clone(&sessions); threads])?,
+            sessions,
             tokenizer,
             dimension,
             max_length,
@@ -313,29 +533,16 @@ impl EmbeddingService for OnnxEmbedding {
         let type_tensor = ort::value::Tensor::from_array(type_array)
             .map_err(|e| PulseDBError::embedding(format!("Tensor creation failed: {e}")))?;
 
-        // 5. Run ONNX inference (lock session for mutable access)
-        let mut session = self
-            .session
-            .lock()
-            .map_err(|e| PulseDBError::embedding(format!("Session lock poisoned: {e}")))?;
-        let outputs = session
-            .run(ort::inputs![
-                "input_ids" => ids_tensor,
-                "attention_mask" => mask_tensor,
-                "token_type_ids" => type_tensor,
-            ])
-            .map_err(|e| PulseDBError::embedding(format!("ONNX inference failed: {e}")))?;
-
-        // 6. Extract token embeddings [1, seq_len, dim]
-        let token_embeddings = outputs[0]
-            .try_extract_tensor::<f32>()
-            .map_err(|e| PulseDBError::embedding(format!("Output extraction failed: {e}")))?;
+        // 5. Run ONNX inference on a worker; token embeddings [1, seq_len, dim]
+        let token_embeddings = self.workers.run(move |sessions| {
+            sessions.with(|session| run_model(session, ids_tensor, mask_tensor, type_tensor))
+        })???;
 
         // Convert attention mask for pooling
         let mask_u32: Vec<u32> = attention_mask.iter().map(|&x| x as u32).collect();
 
-        // 7. Mean pool → [dim], then L2 normalize
-        let pooled = mean_pool_raw(token_embeddings.1, &mask_u32, self.dimension, len);
+        // 6. Mean pool → [dim], then L2 normalize
+        let pooled = mean_pool_raw(&token_embeddings, &mask_u32, self.dimension, len);
         Ok(l2_normalize(&pooled))
     }
 
@@ -394,25 +601,10 @@ impl EmbeddingService for OnnxEmbedding {
         let type_tensor = ort::value::Tensor::from_array(type_array)
             .map_err(|e| PulseDBError::embedding(format!("Tensor creation failed: {e}")))?;
 
-        // 5. Run batched inference (lock session for mutable access)
-        let mut session = self
-            .session
-            .lock()
-            .map_err(|e| PulseDBError::embedding(format!("Session lock poisoned: {e}")))?;
-        let outputs = session
-            .run(ort::inputs![
-                "input_ids" => ids_tensor,
-                "attention_mask" => mask_tensor,
-                "token_type_ids" => type_tensor,
-            ])
-            .map_err(|e| PulseDBError::embedding(format!("ONNX inference failed: {e}")))?;
-
-        // 6. Extract [batch_size, max_len, dim]
-        let token_embeddings = outputs[0]
-            .try_extract_tensor::<f32>()
-            .map_err(|e| PulseDBError::embedding(format!("Output extraction failed: {e}")))?;
-
-        let (_shape, data) = token_embeddings;
+        // 5. Run batched inference on a worker; [batch_size, max_len, dim]
+        let data = self.workers.run(move |sessions| {
+            sessions.with(|session| run_model(session, ids_tensor, mask_tensor, type_tensor))
+        })???;
 
         // 7. Per-text mean pooling + L2 normalization
         let mut results = Vec::with_capacity(batch_size);
@@ -441,20 +633,86 @@ impl EmbeddingService for OnnxEmbedding {
 // Helper functions
 // ---------------------------------------------------------------------------
 
-/// Creates an ONNX Runtime session with optimized settings.
-fn create_session(model_path: &Path) -> Result<Session> {
-    Session::builder()
+/// Creates an ONNX Runtime session with optimized settings, using up to
+/// `intra_threads` threads per inference, on `provider` if available.
+fn create_session(
+    model_path: &Path,
+    intra_threads: usize,
+    provider: ExecutionProvider,
+) -> Result<Session> {
+    let mut builder = Session::builder()
         .map_err(|e| PulseDBError::embedding(format!("Failed to create session builder: {e}")))?
         // Level3: all optimizations (operator fusion, constant folding, etc.)
         .with_optimization_level(GraphOptimizationLevel::Level3)
         .map_err(|e| PulseDBError::embedding(format!("Failed to set optimization level: {e}")))?
-        .commit_from_file(model_path)
-        .map_err(|e| {
-            PulseDBError::embedding(format!(
-                "Failed to load ONNX model from {}: {e}",
-                model_path.display()
-            ))
-        })
+        .with_intra_threads(intra_threads)
+        .map_err(|e| PulseDBError::embedding(format!("Failed to set intra-op threads: {e}")))?;
+    if let Some(dispatch) = execution_provider_dispatch(provider) {
+        // Registration failures are logged by ort and leave the CPU provider
+        builder = builder.with_execution_providers([dispatch]).map_err(|e| {
+            PulseDBError::embedding(format!("Failed to register execution provider: {e}"))
+        })?;
+    }
+    builder.commit_from_file(model_path).map_err(|e| {
+        PulseDBError::embedding(format!(
+            "Failed to load ONNX model from {}: {e}",
+            model_path.display()
+        ))
+    })
+}
+
+/// Returns the ort registration for `provider`, or `None` to run on the
+/// CPU (requested, or the provider is unavailable on this host).
+fn execution_provider_dispatch(provider: ExecutionProvider) -> Option<ExecutionProviderDispatch> {
+    let (available, dispatch) = match provider {
+        ExecutionProvider::Cpu => return None,
+        ExecutionProvider::Cuda => {
+            let ep = CUDAExecutionProvider::default();
+            (ep.is_available(), ep.build())
+        }
+        ExecutionProvider::CoreMl => {
+            let ep = CoreMLExecutionProvider::default();
+            (ep.is_available(), ep.build())
+        }
+        ExecutionProvider::DirectMl => {
+            let ep = DirectMLExecutionProvider::default();
+            (ep.is_available(), ep.build())
+        }
+    };
+    match available {
+        Ok(true) => {
+            info!(?provider, "Using ONNX execution provider");
+            Some(dispatch)
+        }
+        _ => {
+            warn!(
+                ?provider,
+                "ONNX execution provider unavailable, falling back to CPU"
+            );
+            None
+        }
+    }
+}
+
+/// Runs the model on one batch of input tensors, returning the flattened
+/// token embeddings `[batch, seq_len, dim]`.
+fn run_model(
+    session: &mut Session,
+    input_ids: ort::value::Tensor<i64>,
+    attention_mask: ort::value::Tensor<i64>,
+    token_type_ids: ort::value::Tensor<i64>,
+) -> Result<Vec<f32>> {
+    let outputs = session
+        .run(ort::inputs![
+            "input_ids" => input_ids,
+            "attention_mask" => attention_mask,
+            "token_type_ids" => token_type_ids,
+        ])
+        .map_err(|e| PulseDBError::embedding(format!("ONNX inference failed: {e}")))?;
+    let (_shape, data) = outputs[0]
+        .try_extract_tensor::<f32>()
+        .map_err(|e| PulseDBError::embedding(format!("Output extraction failed: {e}")))?;
+    Ok(data.to_vec())
 }
 
 /// Loads a HuggingFace tokenizer from a tokenizer.json file.
@@ -588,40 +846,56 @@ fn l2_normalize(v: &[f32]) -> Vec<f32> {
     }
 }
 
-/// Downloads a file from a URL to a local path.
-///
-/// Uses atomic write (temp file + rename) to prevent partial downloads
-/// from leaving corrupted files that block future retry attempts.
-fn download_file(url: &str, dest: &Path) -> Result<()> {
-    let response = ureq::get(url)
-        .call()
-        .map_err(|e| PulseDBError::embedding(format!("Download failed for {url}: {e}")))?;
-
-    // Write to temp file first — rename on success prevents partial corruption
-    let temp = dest.with_extension("tmp");
-    let mut reader = response.into_body().into_reader();
-    let mut file = std::fs::File::create(&temp).map_err(|e| {
-        PulseDBError::embedding(format!("Failed to create file {}: {e}", temp.display()))
-    })?;
+/// [`Fetch`] over ureq, sending `Range` headers to resume.
+struct UreqFetch {
+    agent: ureq::Agent,
+}
 
-    if let Err(e) = std::io::copy(&mut reader, &mut file) {
-        let _ = std::fs::remove_file(&temp);
-        return Err(PulseDBError::embedding(format!(
-            "Failed to write to {}: {e}",
-            dest.display()
-        )));
+impl UreqFetch {
+    fn new() -> Self {
+        let config = ureq::Agent::config_builder()
+            // Status codes are classified in `fetch` rather than as errors
+            .http_status_as_error(false)
+            .timeout_connect(Some(std::time::Duration::from_secs(30)))
+            .build();
+        Self {
+            agent: config.into(),
+        }
     }
+}
 
-    // Atomic rename — only the complete file appears at the destination
-    std::fs::rename(&temp, dest).map_err(|e| {
-        let _ = std::fs::remove_file(&temp);
-        PulseDBError::embedding(format!(
-            "Failed to finalize download {}: {e}",
-            dest.display()
-        ))
-    })?;
-
-    Ok(())
+impl Fetch for UreqFetch {
+    fn fetch(&self, url: &str, offset: u64) -> std::result::Result<FetchResponse, FetchError> {
+        let mut request = self.agent.get(url);
+        if offset > 0 {
+            request = request.header("Range", format!("bytes={offset}-"));
+        }
+        let response = request
+            .call()
+            .map_err(|e| FetchError::Transient(e.to_string()))?;
+
+        let status = response.status().as_u16();
+        let partial = match status {
+            200 => false,
+            206 => true,
+            416 => return Err(FetchError::RangeNotSatisfiable),
+            408 | 429 | 500..=599 => {
+                return Err(FetchError::Transient(format!("HTTP {status}")));
+            }
+            _ => return Err(FetchError::Fatal(format!("HTTP {status}"))),
+        };
+        let len = response
+            .headers()
+            .get("content-length")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+
+        Ok(FetchResponse {
+            partial,
+            len,
+            body: Box::new(response.into_body().into_reader()),
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -0,0 +1,201 @@
+//! Bounded worker pool for embedding inference.
+//!
+//! Each worker owns its own state and takes jobs from one bounded queue.
+//! Callers block while the queue is full, so a burst of
+//! `record_experience()` calls applies backpressure instead of buffering
+//! without limit. Workers that share fewer model sessions than there are
+//! workers hand them out with [`RoundRobin`].
+
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use crate::error::{PulseDBError, Result};
+
+/// Queued jobs allowed per worker before callers block.
+const QUEUE_DEPTH_PER_WORKER: usize = 4;
+
+type Job<S> = Box<dyn FnOnce(&mut S) + Send>;
+
+/// A fixed set of worker threads, each owning one `S`.
+pub(crate) struct WorkerPool<S> {
+    /// Job queue; `None` once the pool is shutting down.
+    sender: Option<SyncSender<Job<S>>>,
+    workers: Vec<JoinHandle<()>>,
+    _state: PhantomData<fn(S)>,
+}
+
+impl<S: Send + 'static> WorkerPool<S> {
+    /// Starts one worker per state. Threads are named `{name}-{i}`.
+    pub(crate) fn new(name: &str, states: Vec<S>) -> Result<Self> {
+        let (sender, receiver) = mpsc::sync_channel(states.len() * QUEUE_DEPTH_PER_WORKER);
+        let receiver: Arc<Mutex<Receiver<Job<S>>>> = Arc::new(Mutex::new(receiver));
+        let workers = states
+            .into_iter()
+            .enumerate()
+            .map(|(i, mut state)| {
+                let receiver = Arc::clone(&receiver);
+                std::thread::Builder::new()
+                    .name(format!("{}-{}", name, i))
+                    .spawn(move || loop {
+                        // The guard is released before the job runs
+                        let job = match receiver.lock() {
+                            Ok(receiver) => receiver.recv(),
+                            Err(_) => return,
+                        };
+                        let Ok(job) = job else {
+                            return;
+                        };
+                        // A panicking job drops its reply channel, which
+                        // surfaces as an error to its caller; the worker
+                        // carries on with the next job.
+                        let _ = panic::catch_unwind(AssertUnwindSafe(|| job(&mut state)));
+                    })
+                    .map_err(|e| {
+                        PulseDBError::embedding(format!("Failed to start {} worker: {e}", name))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            sender: Some(sender),
+            workers,
+            _state: PhantomData,
+        })
+    }
+
+    /// Runs `job` on the next free worker and waits for its result.
+    ///
+    /// Blocks while the queue is full.
+    pub(crate) fn run<R: Send + 'static>(
+        &self,
+        job: impl FnOnce(&mut S) -> R + Send + 'static,
+    ) -> Result<R> {
+        let (reply, result) = mpsc::sync_channel(1);
+        let sender = self
+            .sender
+            .as_ref()
+            .ok_or_else(|| PulseDBError::embedding("Embedding workers are shut down"))?;
+        sender
+            .send(Box::new(move |state: &mut S| {
+                let _ = reply.send(job(state));
+            }))
+            .map_err(|_| PulseDBError::embedding("Embedding workers are shut down"))?;
+        result
+            .recv()
+            .map_err(|_| PulseDBError::embedding("Embedding worker failed while running a job"))
+    }
+
+    /// Number of workers.
+    pub(crate) fn size(&self) -> usize {
+        self.workers.len()
+    }
+}
+
+/// A fixed set of items handed out in turn, each to one user at a time.
+pub(crate) struct RoundRobin<T> {
+    items: Vec<Mutex<T>>,
+    next: AtomicUsize,
+}
+
+impl<T> RoundRobin<T> {
+    /// Creates a set over `items`, which must not be empty.
+    pub(crate) fn new(items: Vec<T>) -> Self {
+        debug_assert!(!items.is_empty());
+        Self {
+            items: items.into_iter().map(Mutex::new).collect(),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Runs `f` on the next item in turn. Items in use are skipped if a
+    /// later one is free; if all are busy, waits for the one whose turn
+    /// it is.
+    pub(crate) fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R> {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let n = self.items.len();
+        let free = (0..n).find_map(|i| self.items[(start + i) % n].try_lock().ok());
+        let mut item = match free {
+            Some(item) => item,
+            None => self.items[start % n]
+                .lock()
+                .map_err(|_| PulseDBError::embedding("Embedding session lock poisoned"))?,
+        };
+        Ok(f(&mut item))
+    }
+
+    /// Number of items.
+    pub(crate) fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl<S> Drop for WorkerPool<S> {
+    fn drop(&mut self) {
+        // Closing the queue ends each worker after its current job
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Barrier;
+
+    #[test]
+    fn test_jobs_run_concurrently_on_separate_states() {
+        let pool = WorkerPool::new("test", vec![0usize, 1, 2]).unwrap();
+        assert_eq!(pool.size(), 3);
+
+        // All three jobs must be in flight at once to pass the barrier
+        let barrier = Arc::new(Barrier::new(3));
+        let mut owners: Vec<usize> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..3)
+                .map(|_| {
+                    let barrier = Arc::clone(&barrier);
+                    let pool = &pool;
+                    scope.spawn(move || {
+                        pool.run(move |state: &mut usize| {
+                            barrier.wait();
+                            *state
+                        })
+                        .unwrap()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        owners.sort();
+        assert_eq!(owners, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_round_robin_rotates_and_skips_busy_items() {
+        let items = RoundRobin::new(vec![0usize, 1, 2]);
+        assert_eq!(items.len(), 3);
+        let turns: Vec<usize> = (0..4).map(|_| items.with(|i| *i).unwrap()).collect();
+        assert_eq!(turns, vec![0, 1, 2, 0]);
+
+        // Item 1's turn, but it's busy: item 2 is used instead
+        let nested = items
+            .with(|outer| {
+                assert_eq!(*outer, 1);
+                items.with(|inner| *inner).unwrap()
+            })
+            .unwrap();
+        assert_eq!(nested, 2);
+    }
+
+    #[test]
+    fn test_panicking_job_fails_only_its_caller() {
+        let pool = WorkerPool::new("test", vec![()]).unwrap();
+        let err = pool.run(|_: &mut ()| -> u32 { panic!("boom") });
+        assert!(err.is_err());
+        assert_eq!(pool.run(|_: &mut ()| 7).unwrap(), 7);
+    }
+}
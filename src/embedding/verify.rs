@@ -0,0 +1,136 @@
+//! SHA-256 verification of downloaded model files.
+//!
+//! [`OnnxEmbedding::download_default_model()`](super::onnx::OnnxEmbedding::download_default_model)
+//! hashes each file as it streams in and records the digests in a
+//! `checksums.sha256` manifest beside them, in `sha256sum` format. Loading
+//! a model directory that has a manifest re-hashes the files and refuses
+//! any that no longer match, so a truncated or altered file fails with a
+//! clear error instead of a cryptic ONNX load failure.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+use crate::error::{PulseDBError, Result};
+
+/// Name of the digest manifest in a model directory.
+pub(crate) const MANIFEST_FILENAME: &str = "checksums.sha256";
+
+/// Lowercase hex SHA-256 of a digest state.
+fn hex_digest(hasher: Sha256) -> String {
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Hashes a file.
+pub(crate) fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 1 << 16];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hex_digest(hasher))
+}
+
+/// Writes the manifest for `(file name, digest)` pairs, replacing any
+/// earlier one.
+pub(crate) fn write_manifest(dir: &Path, entries: &[(&str, String)]) -> io::Result<()> {
+    let mut contents = String::new();
+    for (name, digest) in entries {
+        contents.push_str(&format!("{}  {}\n", digest, name));
+    }
+    let partial = dir.join(format!("{}.tmp", MANIFEST_FILENAME));
+    let mut file = File::create(&partial)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(partial, dir.join(MANIFEST_FILENAME))
+}
+
+/// Checks every file listed in `dir`'s manifest.
+///
+/// Returns `Ok(false)` if there is no manifest (a directory the caller
+/// assembled), `Ok(true)` if every listed file matches.
+///
+/// # Errors
+///
+/// An embedding error naming the first file that is missing or whose
+/// digest differs, or if the manifest is malformed.
+pub(crate) fn verify_model_dir(dir: &Path) -> Result<bool> {
+    let manifest = match fs::read_to_string(dir.join(MANIFEST_FILENAME)) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => {
+            return Err(PulseDBError::embedding(format!(
+                "Failed to read model checksums in {}: {e}",
+                dir.display()
+            )))
+        }
+    };
+    for line in manifest.lines().filter(|l| !l.trim().is_empty()) {
+        let (expected, name) = line.split_once("  ").ok_or_else(|| {
+            PulseDBError::embedding(format!(
+                "Malformed model checksum line in {}: {line}",
+                dir.display()
+            ))
+        })?;
+        let actual = sha256_file(&dir.join(name)).map_err(|e| {
+            PulseDBError::embedding(format!(
+                "Model file {name} in {} is unreadable: {e}",
+                dir.display()
+            ))
+        })?;
+        if actual != expected {
+            return Err(PulseDBError::embedding(format!(
+                "Model file {name} in {} fails verification (expected SHA-256 {expected}, \
+                 got {actual}); delete it to download it again",
+                dir.display()
+            )));
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_of_known_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("abc");
+        fs::write(&path, b"abc").unwrap();
+        assert_eq!(
+            sha256_file(&path).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_verify_detects_altered_files() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!verify_model_dir(dir.path()).unwrap());
+
+        fs::write(dir.path().join("model.onnx"), b"weights").unwrap();
+        let digest = sha256_file(&dir.path().join("model.onnx")).unwrap();
+        write_manifest(dir.path(), &[("model.onnx", digest)]).unwrap();
+        assert!(verify_model_dir(dir.path()).unwrap());
+
+        fs::write(dir.path().join("model.onnx"), b"weigh").unwrap();
+        let err = verify_model_dir(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("model.onnx"));
+        assert!(err.to_string().contains("fails verification"));
+
+        fs::remove_file(dir.path().join("model.onnx")).unwrap();
+        assert!(verify_model_dir(dir.path()).is_err());
+    }
+}
@@ -0,0 +1,31 @@
+//! Data subject erasure.
+//!
+//! Compliance requests ("forget everything about this user / agent") are
+//! answered from the library in a single call:
+//!
+//! - [`erase_by_user(user_id)`](crate::PulseDB::erase_by_user) deletes
+//!   every collective the user owns, with full cascade, plus experiences
+//!   linked to the user in other collectives.
+//! - [`erase_by_agent(agent_id)`](crate::PulseDB::erase_by_agent) deletes
+//!   the experiences an agent recorded across all collectives, together with
+//!   their relations, the insights derived only from them, and the agent's
+//!   activity records and bookmarks.
+//!
+//! Both return an [`ErasureReport`] that can be filed as evidence that the
+//! request was carried out.
+//!
+//! # Crypto-shredding
+//!
+//! With [`ContentStorage::External`](crate::ContentStorage::External) the
+//! raw text lives outside the database. The report lists the content
+//! handles of every erased experience so the caller can destroy the
+//! external copies (or their encryption keys).
+//!
+//! # Scope
+//!
+//! Erasure covers the live database only. Export and backup files written
+//! before the erasure still contain the erased records.
+
+pub mod types;
+
+pub use types::ErasureReport;
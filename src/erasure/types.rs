@@ -0,0 +1,54 @@
+//! Data types for data subject erasure.
+
+/// What an erasure call removed.
+///
+/// Returned by [`PulseDB::erase_by_user()`](crate::PulseDB::erase_by_user)
+/// and [`PulseDB::erase_by_agent()`](crate::PulseDB::erase_by_agent).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErasureReport {
+    /// Collectives deleted.
+    pub collectives_erased: usize,
+    /// Experiences deleted.
+    pub experiences_erased: usize,
+    /// Relations deleted.
+    pub relations_erased: usize,
+    /// Insights deleted because all of their sources were erased.
+    pub insights_erased: usize,
+    /// Insights kept but stripped of the erased source IDs.
+    pub insights_detached: usize,
+    /// Activity records deleted.
+    pub activities_erased: usize,
+    /// Bookmarks deleted from the erased identity's reading list.
+    pub bookmarks_erased: usize,
+    /// Stored content of every erased experience when
+    /// [`ContentStorage::External`](crate::ContentStorage::External) is in
+    /// use, so external copies can be shredded. Empty otherwise.
+    pub content_handles: Vec<String>,
+}
+
+impl ErasureReport {
+    /// Returns `true` if nothing was erased.
+    pub fn is_empty(&self) -> bool {
+        self.collectives_erased == 0
+            && self.experiences_erased == 0
+            && self.relations_erased == 0
+            && self.insights_erased == 0
+            && self.insights_detached == 0
+            && self.activities_erased == 0
+            && self.bookmarks_erased == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_report_is_empty() {
+        let mut report = ErasureReport::default();
+        assert!(report.is_empty());
+
+        report.activities_erased = 1;
+        assert!(!report.is_empty());
+    }
+}
@@ -17,7 +17,9 @@
 //! }
 //! ```
 
+use std::fmt;
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 
 #[cfg(feature = "sync")]
@@ -78,6 +80,47 @@ pub enum PulseDBError {
     #[error("Database is in read-only mode")]
     ReadOnly,
 
+    /// The target resource is temporarily unavailable for writes.
+    ///
+    /// Returned when a mutation targets a collective that has been frozen
+    /// with [`PulseDB::freeze_collective()`](crate::PulseDB::freeze_collective)
+    /// for maintenance. Reads remain available; retry after the collective
+    /// is thawed.
+    ///
+    /// Also returned when another write held the write lock through every
+    /// retry in [`Config::write_retry`](crate::Config::write_retry).
+    #[error("Resource busy: {0}")]
+    Busy(String),
+
+    /// The target collective could not be loaded and is out of service.
+    ///
+    /// Returned for reads and writes against a collective whose index or
+    /// records failed to load at open; see
+    /// [`PulseDB::health()`](crate::PulseDB::health). Other collectives
+    /// are unaffected. The collective can still be deleted.
+    #[error("Collective unavailable: {0}")]
+    Unavailable(String),
+
+    /// The handle lacks the capability the operation needs.
+    ///
+    /// Returned by a [`ScopedDb`](crate::ScopedDb) handle whose
+    /// [`Capabilities`](crate::Capabilities) don't cover the call.
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    /// An operation ran past its limit in
+    /// [`Config::timeouts`](crate::Config::timeouts).
+    ///
+    /// The operation was abandoned before changing anything, so it can be
+    /// retried as is.
+    #[error("{operation} timed out after {limit:?}")]
+    Timeout {
+        /// Which limit was exceeded.
+        operation: TimedOperation,
+        /// The configured limit.
+        limit: Duration,
+    },
+
     /// Sync protocol error.
     ///
     /// Only available when the `sync` feature is enabled.
@@ -115,6 +158,26 @@ impl PulseDBError {
         Self::Internal(msg.into())
     }
 
+    /// Creates a busy error with the given message.
+    pub fn busy(msg: impl Into<String>) -> Self {
+        Self::Busy(msg.into())
+    }
+
+    /// Creates an unavailable-collective error with the given message.
+    pub fn unavailable(msg: impl Into<String>) -> Self {
+        Self::Unavailable(msg.into())
+    }
+
+    /// Creates a permission-denied error with the given message.
+    pub fn permission_denied(msg: impl Into<String>) -> Self {
+        Self::PermissionDenied(msg.into())
+    }
+
+    /// Creates a timeout error for `operation` exceeding `limit`.
+    pub fn timeout(operation: TimedOperation, limit: Duration) -> Self {
+        Self::Timeout { operation, limit }
+    }
+
     /// Returns true if this is a "not found" error.
     pub fn is_not_found(&self) -> bool {
         matches!(self, Self::NotFound(_))
@@ -165,6 +228,26 @@ impl PulseDBError {
         matches!(self, Self::ReadOnly)
     }
 
+    /// Returns true if this is a busy error.
+    pub fn is_busy(&self) -> bool {
+        matches!(self, Self::Busy(_))
+    }
+
+    /// Returns true if this is an unavailable-collective error.
+    pub fn is_unavailable(&self) -> bool {
+        matches!(self, Self::Unavailable(_))
+    }
+
+    /// Returns true if this is a permission-denied error.
+    pub fn is_permission_denied(&self) -> bool {
+        matches!(self, Self::PermissionDenied(_))
+    }
+
+    /// Returns true if this is a timeout error.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout { .. })
+    }
+
     /// Returns true if this is a sync error.
     ///
     /// Only available when the `sync` feature is enabled.
@@ -175,6 +258,27 @@ impl PulseDBError {
     }
 }
 
+/// The operations limited by [`Config::timeouts`](crate::Config::timeouts).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimedOperation {
+    /// A similarity search.
+    Search,
+    /// Waiting to start a write transaction.
+    WriteTransaction,
+    /// Loading or rebuilding a collective's vector index.
+    Rebuild,
+}
+
+impl fmt::Display for TimedOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Search => "Search",
+            Self::WriteTransaction => "Write transaction",
+            Self::Rebuild => "Index rebuild",
+        })
+    }
+}
+
 /// Storage-related errors.
 ///
 /// These errors indicate problems with the underlying storage layer.
@@ -577,6 +681,41 @@ mod tests {
         assert!(!err.is_validation());
     }
 
+    #[test]
+    fn test_is_busy() {
+        let err = PulseDBError::busy("collective frozen");
+        assert!(err.is_busy());
+        assert!(!err.is_read_only());
+        assert_eq!(err.to_string(), "Resource busy: collective frozen");
+    }
+
+    #[test]
+    fn test_is_unavailable() {
+        let err = PulseDBError::unavailable("index failed to load");
+        assert!(err.is_unavailable());
+        assert!(!err.is_busy());
+        assert_eq!(
+            err.to_string(),
+            "Collective unavailable: index failed to load"
+        );
+    }
+
+    #[test]
+    fn test_is_permission_denied() {
+        let err = PulseDBError::permission_denied("handle cannot delete");
+        assert!(err.is_permission_denied());
+        assert!(!err.is_read_only());
+        assert_eq!(err.to_string(), "Permission denied: handle cannot delete");
+    }
+
+    #[test]
+    fn test_is_timeout() {
+        let err = PulseDBError::timeout(TimedOperation::Search, Duration::from_millis(250));
+        assert!(err.is_timeout());
+        assert!(!err.is_busy());
+        assert_eq!(err.to_string(), "Search timed out after 250ms");
+    }
+
     #[test]
     fn test_is_io() {
         let err = PulseDBError::Io(std::io::Error::new(
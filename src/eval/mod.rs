@@ -0,0 +1,169 @@
+//! Offline evaluation of retrieval configurations.
+//!
+//! Tuning retrieval without measurements is guesswork. This module runs a
+//! labeled [`EvalSet`] against one or more [`RetrievalConfig`]s and reports
+//! ranking quality (NDCG@k, recall@k) and latency for each, so
+//! configurations can be compared side by side:
+//!
+//! - [`EvalSet::load(path)`](EvalSet::load) reads the labeled queries
+//! - [`PulseDB::evaluate(collective_id, set, configs)`](crate::PulseDB::evaluate)
+//!   returns one [`EvalReport`] per configuration
+//!
+//! Configurations differ in `k`, post-filtering, and HNSW graph
+//! parameters. Vectors are always stored at full `f32` precision, so there
+//! are no quantization settings to compare yet. Evaluation never modifies
+//! the database.
+
+pub mod types;
+
+pub use types::{EvalQuery, EvalReport, EvalSet, RetrievalConfig};
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::error::{PulseDBError, ValidationError};
+use crate::types::ExperienceId;
+
+/// Validates an evaluation set against the collective's dimension.
+pub(crate) fn validate_eval_set(set: &EvalSet, dimension: usize) -> Result<(), PulseDBError> {
+    for query in &set.queries {
+        if query.embedding.len() != dimension {
+            return Err(
+                ValidationError::dimension_mismatch(dimension, query.embedding.len()).into(),
+            );
+        }
+        if !query.relevant.values().any(|&gain| gain > 0.0) {
+            return Err(ValidationError::invalid_field(
+                "relevant",
+                format!("query '{}' has no relevant experiences", query.name),
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Validates a retrieval configuration.
+pub(crate) fn validate_retrieval_config(config: &RetrievalConfig) -> Result<(), PulseDBError> {
+    if config.k == 0 || config.k > 1000 {
+        return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
+    }
+    Ok(())
+}
+
+/// Normalized discounted cumulative gain of a ranking, cut off at `k`.
+///
+/// Uses the linear-gain form `gain / log2(rank + 1)`. Returns 0.0 when
+/// nothing is relevant.
+pub(crate) fn ndcg_at_k(
+    ranked: &[ExperienceId],
+    relevant: &HashMap<ExperienceId, f32>,
+    k: usize,
+) -> f64 {
+    let discount = |rank: usize| 1.0 / ((rank + 2) as f64).log2();
+
+    let dcg: f64 = ranked
+        .iter()
+        .take(k)
+        .enumerate()
+        .map(|(rank, id)| {
+            let gain = relevant.get(id).copied().unwrap_or(0.0).max(0.0) as f64;
+            gain * discount(rank)
+        })
+        .sum();
+
+    let mut ideal: Vec<f64> = relevant
+        .values()
+        .filter(|&&gain| gain > 0.0)
+        .map(|&gain| gain as f64)
+        .collect();
+    ideal.sort_by(|a, b| b.total_cmp(a));
+    let idcg: f64 = ideal
+        .iter()
+        .take(k)
+        .enumerate()
+        .map(|(rank, gain)| gain * discount(rank))
+        .sum();
+
+    if idcg == 0.0 {
+        0.0
+    } else {
+        dcg / idcg
+    }
+}
+
+/// Fraction of relevant experiences found in the top `k`.
+pub(crate) fn recall_at_k(
+    ranked: &[ExperienceId],
+    relevant: &HashMap<ExperienceId, f32>,
+    k: usize,
+) -> f64 {
+    let total = relevant.values().filter(|&&gain| gain > 0.0).count();
+    if total == 0 {
+        return 0.0;
+    }
+    let found = ranked
+        .iter()
+        .take(k)
+        .filter(|id| relevant.get(id).is_some_and(|&gain| gain > 0.0))
+        .count();
+    found as f64 / total as f64
+}
+
+/// Returns the mean and 95th percentile of `latencies`.
+pub(crate) fn latency_summary(latencies: &mut [Duration]) -> (Duration, Duration) {
+    if latencies.is_empty() {
+        return (Duration::ZERO, Duration::ZERO);
+    }
+    latencies.sort();
+    let mean = latencies.iter().sum::<Duration>() / latencies.len() as u32;
+    let p95_index = ((latencies.len() as f64 * 0.95).ceil() as usize).saturating_sub(1);
+    (mean, latencies[p95_index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn judgments(pairs: &[(ExperienceId, f32)]) -> HashMap<ExperienceId, f32> {
+        pairs.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_perfect_ranking_scores_one() {
+        let (a, b) = (ExperienceId::new(), ExperienceId::new());
+        let relevant = judgments(&[(a, 3.0), (b, 1.0)]);
+        assert!((ndcg_at_k(&[a, b], &relevant, 10) - 1.0).abs() < 1e-9);
+        assert_eq!(recall_at_k(&[a, b], &relevant, 10), 1.0);
+    }
+
+    #[test]
+    fn test_swapped_ranking_scores_lower() {
+        let (a, b) = (ExperienceId::new(), ExperienceId::new());
+        let relevant = judgments(&[(a, 3.0), (b, 1.0)]);
+        let swapped = ndcg_at_k(&[b, a], &relevant, 10);
+        assert!(swapped < 1.0 && swapped > 0.0);
+        assert_eq!(recall_at_k(&[b, a], &relevant, 10), 1.0);
+    }
+
+    #[test]
+    fn test_cutoff_limits_recall() {
+        let (a, b, c) = (
+            ExperienceId::new(),
+            ExperienceId::new(),
+            ExperienceId::new(),
+        );
+        let relevant = judgments(&[(a, 1.0), (b, 1.0)]);
+        assert_eq!(recall_at_k(&[c, a, b], &relevant, 2), 0.5);
+        assert_eq!(ndcg_at_k(&[c], &relevant, 1), 0.0);
+    }
+
+    #[test]
+    fn test_latency_summary() {
+        let mut latencies: Vec<Duration> = (1..=20).map(Duration::from_millis).collect();
+        let (mean, p95) = latency_summary(&mut latencies);
+        assert_eq!(mean, Duration::from_micros(10_500));
+        assert_eq!(p95, Duration::from_millis(19));
+        assert_eq!(latency_summary(&mut []), (Duration::ZERO, Duration::ZERO));
+    }
+}
@@ -0,0 +1,126 @@
+//! Data types for retrieval evaluation.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::HnswConfig;
+use crate::error::{PulseDBError, ValidationError};
+use crate::search::SearchFilter;
+use crate::types::ExperienceId;
+
+/// One labeled query: an embedding plus graded relevance judgments.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EvalQuery {
+    /// Label used when reporting (e.g., the query text).
+    pub name: String,
+
+    /// Query embedding (must match the collective's dimension).
+    pub embedding: Vec<f32>,
+
+    /// Relevance gain per experience. Experiences with a gain above zero
+    /// count as relevant; unlisted experiences have gain zero.
+    pub relevant: HashMap<ExperienceId, f32>,
+}
+
+/// A labeled query/relevance set.
+///
+/// # JSON Format
+///
+/// ```json
+/// {
+///   "queries": [
+///     {
+///       "name": "retry flaky network calls",
+///       "embedding": [0.12, -0.03, ...],
+///       "relevant": { "01927c3e-...": 3.0, "01927c41-...": 1.0 }
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EvalSet {
+    /// The labeled queries.
+    pub queries: Vec<EvalQuery>,
+}
+
+impl EvalSet {
+    /// Parses an evaluation set from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the JSON doesn't match the format.
+    pub fn from_json(json: &str) -> Result<Self, PulseDBError> {
+        serde_json::from_str(json)
+            .map_err(|e| ValidationError::invalid_field("eval_set", e.to_string()).into())
+    }
+
+    /// Loads an evaluation set from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// - [`PulseDBError::Io`] if the file can't be read
+    /// - Validation error if the JSON doesn't match the format
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PulseDBError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+}
+
+/// A retrieval configuration to evaluate.
+#[derive(Clone, Debug)]
+pub struct RetrievalConfig {
+    /// Label used in the [`EvalReport`].
+    pub name: String,
+
+    /// Number of results retrieved per query (1–1000).
+    pub k: usize,
+
+    /// HNSW parameters to evaluate.
+    ///
+    /// `None` searches the collective's live index with the database's
+    /// [`Config::hnsw`](crate::Config::hnsw). `Some` builds a scratch
+    /// index from the collective's stored embeddings with these parameters,
+    /// so graph settings can be compared without reopening the database.
+    pub hnsw: Option<HnswConfig>,
+
+    /// Post-filter applied to candidates, as in
+    /// [`search_similar_filtered()`](crate::PulseDB::search_similar_filtered).
+    pub filter: SearchFilter,
+}
+
+impl Default for RetrievalConfig {
+    fn default() -> Self {
+        Self {
+            name: "default".to_string(),
+            k: 10,
+            hnsw: None,
+            filter: SearchFilter::default(),
+        }
+    }
+}
+
+/// Retrieval quality and latency of one configuration over an [`EvalSet`].
+///
+/// Returned by [`PulseDB::evaluate()`](crate::PulseDB::evaluate).
+#[derive(Clone, Debug)]
+pub struct EvalReport {
+    /// The [`RetrievalConfig::name`] this report is for.
+    pub config: String,
+
+    /// Number of queries evaluated.
+    pub queries: usize,
+
+    /// Mean NDCG@k over all queries (0.0–1.0).
+    pub ndcg: f64,
+
+    /// Mean recall@k over all queries (0.0–1.0).
+    pub recall: f64,
+
+    /// Mean per-query search latency, including hydration and filtering.
+    pub mean_latency: Duration,
+
+    /// 95th percentile per-query search latency.
+    pub p95_latency: Duration,
+}
@@ -0,0 +1,64 @@
+//! Per-item outcomes of batch ingestion.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::ExperienceId;
+
+/// What happened to one experience passed to
+/// [`PulseDB::record_experiences_batch()`](crate::PulseDB::record_experiences_batch).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BatchOutcome {
+    /// Recorded under this ID.
+    Accepted(ExperienceId),
+
+    /// Not recorded: an experience with this ID already exists. With
+    /// [`IdStrategy::ContentDerived`](crate::IdStrategy::ContentDerived)
+    /// this catches the same content recorded twice in a collective.
+    Deduped(ExperienceId),
+
+    /// Not recorded, with the reason it was refused.
+    Rejected(String),
+}
+
+/// Output of
+/// [`PulseDB::record_experiences_batch()`](crate::PulseDB::record_experiences_batch).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchReport {
+    /// One outcome per input experience, in input order.
+    pub outcomes: Vec<BatchOutcome>,
+}
+
+impl BatchReport {
+    /// IDs of the experiences recorded, in input order.
+    pub fn accepted_ids(&self) -> Vec<ExperienceId> {
+        self.outcomes
+            .iter()
+            .filter_map(|outcome| match outcome {
+                BatchOutcome::Accepted(id) => Some(*id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Number of experiences recorded.
+    pub fn accepted(&self) -> usize {
+        self.count(|outcome| matches!(outcome, BatchOutcome::Accepted(_)))
+    }
+
+    /// Number of experiences skipped as duplicates.
+    pub fn deduped(&self) -> usize {
+        self.count(|outcome| matches!(outcome, BatchOutcome::Deduped(_)))
+    }
+
+    /// Number of experiences refused.
+    pub fn rejected(&self) -> usize {
+        self.count(|outcome| matches!(outcome, BatchOutcome::Rejected(_)))
+    }
+
+    fn count(&self, predicate: impl Fn(&BatchOutcome) -> bool) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|outcome| predicate(outcome))
+            .count()
+    }
+}
@@ -0,0 +1,41 @@
+//! Pluggable resolution of externally stored experience content.
+//!
+//! With [`ContentStorage::External`](crate::ContentStorage::External) the
+//! database keeps only an opaque content handle per experience (a key into
+//! an external store, or ciphertext) alongside its embedding and metadata.
+//! A [`ContentResolver`] registered with
+//! [`PulseDB::set_content_resolver()`](crate::PulseDB::set_content_resolver)
+//! turns handles back into text when an experience is read.
+
+use crate::error::Result;
+
+/// Turns a stored content handle back into experience content.
+///
+/// Called by [`PulseDB::get_experience()`](crate::PulseDB::get_experience)
+/// when the database uses external content storage. Implementations may
+/// fetch from a document store, decrypt, or apply access checks; an error
+/// fails the read.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use pulsedb::{ContentResolver, PulseDBError, Result};
+///
+/// struct VaultResolver {
+///     documents: HashMap<String, String>,
+/// }
+///
+/// impl ContentResolver for VaultResolver {
+///     fn resolve(&self, handle: &str) -> Result<String> {
+///         self.documents
+///             .get(handle)
+///             .cloned()
+///             .ok_or_else(|| PulseDBError::internal(format!("no document for {}", handle)))
+///     }
+/// }
+/// ```
+pub trait ContentResolver: Send + Sync {
+    /// Returns the content for `handle`.
+    fn resolve(&self, handle: &str) -> Result<String>;
+}
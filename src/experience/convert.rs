@@ -0,0 +1,42 @@
+//! Conversion of host-application types into experiences.
+
+use crate::types::CollectiveId;
+
+use super::types::NewExperience;
+
+/// Converts a value, typically a domain event, into a [`NewExperience`].
+///
+/// With the `derive` feature, `#[derive(ToExperience)]` generates the
+/// implementation from attributes: a content template over the struct's
+/// fields, domain tags, importance, confidence, and experience type.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{CollectiveId, NewExperience, ToExperience};
+///
+/// struct BuildFailed {
+///     target: String,
+/// }
+///
+/// impl ToExperience for BuildFailed {
+///     fn to_experience(&self, collective_id: CollectiveId) -> NewExperience {
+///         NewExperience {
+///             collective_id,
+///             content: format!("Build of {} failed", self.target),
+///             domain: vec!["ci".to_string()],
+///             ..Default::default()
+///         }
+///     }
+/// }
+///
+/// let exp = BuildFailed { target: "api".into() }.to_experience(CollectiveId::new());
+/// assert_eq!(exp.content, "Build of api failed");
+/// ```
+pub trait ToExperience {
+    /// Builds the experience to record in `collective_id`.
+    ///
+    /// The embedding is left to the caller, or to the database's
+    /// embedding service.
+    fn to_experience(&self, collective_id: CollectiveId) -> NewExperience;
+}
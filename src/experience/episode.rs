@@ -0,0 +1,22 @@
+//! Episodes: the experiences recorded under one task.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::Experience;
+use crate::insight::DerivedInsight;
+use crate::types::EpisodeId;
+
+/// Output of [`PulseDB::get_episode()`](crate::PulseDB::get_episode).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Episode {
+    /// The episode (task) ID.
+    pub id: EpisodeId,
+
+    /// Experiences recorded under the task, in recording order.
+    pub experiences: Vec<Experience>,
+
+    /// Summary insight attached with
+    /// [`set_episode_summary()`](crate::PulseDB::set_episode_summary),
+    /// if one is attached and still exists.
+    pub summary: Option<DerivedInsight>,
+}
@@ -9,15 +9,33 @@
 //! All experience operations are available on [`PulseDB`](crate::PulseDB):
 //!
 //! - [`record_experience(exp)`](crate::PulseDB::record_experience)
+//! - [`record_experiences_batch(exps)`](crate::PulseDB::record_experiences_batch)
 //! - [`get_experience(id)`](crate::PulseDB::get_experience)
 //! - [`update_experience(id, update)`](crate::PulseDB::update_experience)
 //! - [`archive_experience(id)`](crate::PulseDB::archive_experience)
 //! - [`unarchive_experience(id)`](crate::PulseDB::unarchive_experience)
 //! - [`delete_experience(id)`](crate::PulseDB::delete_experience)
 //! - [`reinforce_experience(id)`](crate::PulseDB::reinforce_experience)
+//! - [`get_episode(id)`](crate::PulseDB::get_episode)
 
+mod batch;
+mod content;
+mod convert;
+mod episode;
+mod policy;
+mod render;
 pub mod types;
 mod validation;
+mod wire;
 
-pub use types::{Experience, ExperienceType, ExperienceUpdate, NewExperience, Severity};
+pub use batch::{BatchOutcome, BatchReport};
+pub use content::ContentResolver;
+pub use convert::ToExperience;
+pub use episode::Episode;
+pub use policy::{ContentPolicy, ContentRequirement, ContentRule};
+pub use render::RenderStyle;
+pub use types::{
+    Experience, ExperienceType, ExperienceUpdate, ModelAttribution, NewExperience, Severity,
+};
 pub(crate) use validation::{validate_experience_update, validate_new_experience};
+pub(crate) use wire::ExperienceHead;
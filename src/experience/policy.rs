@@ -0,0 +1,190 @@
+//! Per-collective content rules.
+//!
+//! A [`ContentPolicy`] set with
+//! [`PulseDB::set_content_policy()`](crate::PulseDB::set_content_policy)
+//! holds experience content to a shape downstream parsers can rely on —
+//! for example, that an `ErrorPattern`'s content quotes its signature.
+//! [`record_experience()`](crate::PulseDB::record_experience) rejects
+//! content that breaks a rule with a [`ValidationError`] naming the rule.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::ExperienceType;
+use crate::error::ValidationError;
+
+/// Content rules for a collective's experiences.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{ContentPolicy, ContentRequirement, ExperienceType};
+///
+/// let error_pattern = ExperienceType::ErrorPattern {
+///     signature: String::new(),
+///     fix: String::new(),
+///     prevention: String::new(),
+/// };
+/// let policy = ContentPolicy::new()
+///     .require([error_pattern], ContentRequirement::IncludesField("signature".into()))
+///     .require([], ContentRequirement::MinLength(10));
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ContentPolicy {
+    /// Rules, checked in order; the first one broken is reported.
+    pub rules: Vec<ContentRule>,
+}
+
+/// One content rule.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContentRule {
+    /// Experience types the rule applies to, matched on the variant only.
+    /// Empty applies it to every type.
+    pub types: Vec<ExperienceType>,
+
+    /// What the content must satisfy.
+    pub requirement: ContentRequirement,
+}
+
+/// What a [`ContentRule`] demands of the content.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContentRequirement {
+    /// Content must include the value of the named type field, matched
+    /// case-insensitively against the labels of
+    /// [`ExperienceType::fields()`] (`"signature"`, `"technology"`, ...).
+    /// Types without the field, or with it unset, pass.
+    IncludesField(String),
+
+    /// Content must include this text.
+    Contains(String),
+
+    /// Content must be at least this many characters.
+    MinLength(usize),
+
+    /// Content must be at most this many characters.
+    MaxLength(usize),
+}
+
+impl ContentPolicy {
+    /// Creates a policy with no rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule for experiences of these types (matched on the variant
+    /// only); an empty list applies it to every type.
+    pub fn require(
+        mut self,
+        types: impl IntoIterator<Item = ExperienceType>,
+        requirement: ContentRequirement,
+    ) -> Self {
+        self.rules.push(ContentRule {
+            types: types.into_iter().collect(),
+            requirement,
+        });
+        self
+    }
+
+    /// Checks content against every rule that applies to its type.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`] on `content` describing the
+    ///   first rule broken
+    pub fn check(
+        &self,
+        experience_type: &ExperienceType,
+        content: &str,
+    ) -> Result<(), ValidationError> {
+        let tag = experience_type.type_tag();
+        let label = experience_type.label();
+        for rule in &self.rules {
+            if !rule.types.is_empty() && !rule.types.iter().any(|t| t.type_tag() == tag) {
+                continue;
+            }
+            let broken = match &rule.requirement {
+                ContentRequirement::IncludesField(field) => experience_type
+                    .fields()
+                    .into_iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(field))
+                    .filter(|(_, value)| !value.is_empty() && !content.contains(value.as_str()))
+                    .map(|(name, value)| {
+                        format!(
+                            "{} content must include its {} `{}`",
+                            label,
+                            name.to_lowercase(),
+                            value
+                        )
+                    }),
+                ContentRequirement::Contains(text) => (!content.contains(text.as_str()))
+                    .then(|| format!("{} content must include `{}`", label, text)),
+                ContentRequirement::MinLength(min) => {
+                    let len = content.chars().count();
+                    (len < *min).then(|| {
+                        format!(
+                            "{} content must be at least {} characters, got {}",
+                            label, min, len
+                        )
+                    })
+                }
+                ContentRequirement::MaxLength(max) => {
+                    let len = content.chars().count();
+                    (len > *max).then(|| {
+                        format!(
+                            "{} content must be at most {} characters, got {}",
+                            label, max, len
+                        )
+                    })
+                }
+            };
+            if let Some(reason) = broken {
+                return Err(ValidationError::invalid_field("content", reason));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_pattern() -> ExperienceType {
+        ExperienceType::ErrorPattern {
+            signature: "E0382".to_string(),
+            fix: "clone".to_string(),
+            prevention: "borrow".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_includes_field_names_the_missing_value() {
+        let policy = ContentPolicy::new().require(
+            [error_pattern()],
+            ContentRequirement::IncludesField("Signature".into()),
+        );
+        assert!(policy
+            .check(&error_pattern(), "hit E0382 in the loop")
+            .is_ok());
+
+        let err = policy
+            .check(&error_pattern(), "use after move")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid field 'content': Error pattern content must include its signature `E0382`"
+        );
+
+        // Other types are not covered by the rule
+        assert!(policy.check(&ExperienceType::default(), "anything").is_ok());
+    }
+
+    #[test]
+    fn test_untyped_rules_apply_to_every_type() {
+        let policy = ContentPolicy::new()
+            .require([], ContentRequirement::MinLength(5))
+            .require([], ContentRequirement::MaxLength(8));
+        assert!(policy.check(&ExperienceType::default(), "four").is_err());
+        assert!(policy.check(&error_pattern(), "E0382!").is_ok());
+        assert!(policy.check(&error_pattern(), "too long now").is_err());
+    }
+}
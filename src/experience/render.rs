@@ -0,0 +1,332 @@
+//! Prompt rendering for experiences.
+//!
+//! Code that turns experiences into prompt text otherwise has to match on
+//! [`ExperienceType`] in every consumer. The helpers here do it once:
+//!
+//! - [`ExperienceType::label()`] — human-readable type name
+//! - [`ExperienceType::fields()`] — the variant's data as labeled strings
+//! - [`ExperienceType::lesson()`] — a one-line takeaway
+//! - [`ExperienceType::outcome_summary()`] — whether a solution worked
+//! - [`ExperienceType::to_prompt_block()`] — the fields as a Markdown list
+//! - [`Experience::render_for_context()`] — a full entry in a [`RenderStyle`]
+//!
+//! [`ContextCandidates::render()`](crate::ContextCandidates::render) uses
+//! these to render a whole context window.
+
+use super::types::{Experience, ExperienceType, Severity};
+
+/// How [`Experience::render_for_context()`] formats an experience.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RenderStyle {
+    /// One line: type label, content, and lesson.
+    #[default]
+    Compact,
+
+    /// A Markdown section: type heading, content, a bullet per field, and
+    /// domain tags.
+    Markdown,
+}
+
+impl Severity {
+    /// Returns the lower-case name (`"low"`, `"medium"`, `"high"`, `"critical"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+impl ExperienceType {
+    /// Returns a human-readable name for the variant.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Difficulty { .. } => "Difficulty",
+            Self::Solution { .. } => "Solution",
+            Self::ErrorPattern { .. } => "Error pattern",
+            Self::SuccessPattern { .. } => "Success pattern",
+            Self::UserPreference { .. } => "User preference",
+            Self::ArchitecturalDecision { .. } => "Architectural decision",
+            Self::TechInsight { .. } => "Tech insight",
+            Self::Fact { .. } => "Fact",
+            Self::Generic { .. } => "Note",
+            Self::OpenQuestion { .. } => "Open question",
+        }
+    }
+
+    /// Returns the variant's data as `(label, value)` pairs, in declaration
+    /// order. Unset optional fields are omitted.
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::Difficulty {
+                description,
+                severity,
+            } => vec![
+                ("Description", description.clone()),
+                ("Severity", severity.as_str().to_string()),
+            ],
+            Self::Solution {
+                problem_ref,
+                approach,
+                worked,
+            } => {
+                let mut fields = vec![
+                    ("Approach", approach.clone()),
+                    ("Outcome", outcome(*worked).to_string()),
+                ];
+                if let Some(problem) = problem_ref {
+                    fields.push(("Solves", problem.to_string()));
+                }
+                fields
+            }
+            Self::ErrorPattern {
+                signature,
+                fix,
+                prevention,
+            } => vec![
+                ("Signature", signature.clone()),
+                ("Fix", fix.clone()),
+                ("Prevention", prevention.clone()),
+            ],
+            Self::SuccessPattern {
+                task_type,
+                approach,
+                quality,
+            } => vec![
+                ("Task type", task_type.clone()),
+                ("Approach", approach.clone()),
+                ("Quality", format!("{:.2}", quality)),
+            ],
+            Self::UserPreference {
+                category,
+                preference,
+                strength,
+            } => vec![
+                ("Category", category.clone()),
+                ("Preference", preference.clone()),
+                ("Strength", format!("{:.2}", strength)),
+            ],
+            Self::ArchitecturalDecision {
+                decision,
+                rationale,
+            } => vec![
+                ("Decision", decision.clone()),
+                ("Rationale", rationale.clone()),
+            ],
+            Self::TechInsight {
+                technology,
+                insight,
+            } => vec![
+                ("Technology", technology.clone()),
+                ("Insight", insight.clone()),
+            ],
+            Self::Fact { statement, source } => {
+                vec![("Statement", statement.clone()), ("Source", source.clone())]
+            }
+            Self::Generic { category } => category
+                .iter()
+                .map(|category| ("Category", category.clone()))
+                .collect(),
+            Self::OpenQuestion { question } => vec![("Question", question.clone())],
+        }
+    }
+
+    /// Returns the one-line takeaway an agent should act on, or `None` for
+    /// [`Generic`](Self::Generic) experiences, whose content is the lesson.
+    pub fn lesson(&self) -> Option<String> {
+        let lesson = match self {
+            Self::Difficulty {
+                description,
+                severity,
+            } => format!("Watch out for ({}): {}", severity.as_str(), description),
+            Self::Solution { .. } => return self.outcome_summary(),
+            Self::ErrorPattern {
+                signature,
+                fix,
+                prevention,
+            } => format!(
+                "On `{}`: fix by {}; prevent by {}",
+                signature, fix, prevention
+            ),
+            Self::SuccessPattern {
+                task_type,
+                approach,
+                quality,
+            } => format!("For {}: {} (quality {:.2})", task_type, approach, quality),
+            Self::UserPreference {
+                category,
+                preference,
+                ..
+            } => format!("User prefers ({}): {}", category, preference),
+            Self::ArchitecturalDecision {
+                decision,
+                rationale,
+            } => format!("Decided: {}, because {}", decision, rationale),
+            Self::TechInsight {
+                technology,
+                insight,
+            } => format!("{}: {}", technology, insight),
+            Self::Fact { statement, source } => format!("{} (source: {})", statement, source),
+            Self::Generic { .. } => return None,
+            Self::OpenQuestion { question } => format!("Still unknown: {}", question),
+        };
+        Some(lesson)
+    }
+
+    /// Summarizes a [`Solution`](Self::Solution)'s outcome, e.g.
+    /// `"Worked: pin the dependency"`. `None` for other variants.
+    pub fn outcome_summary(&self) -> Option<String> {
+        match self {
+            Self::Solution {
+                approach, worked, ..
+            } => {
+                let outcome = outcome(*worked);
+                let mut summary = outcome[..1].to_uppercase();
+                summary.push_str(&outcome[1..]);
+                Some(format!("{}: {}", summary, approach))
+            }
+            _ => None,
+        }
+    }
+
+    /// Renders [`fields()`](Self::fields) as a Markdown bullet list, one
+    /// `- Label: value` line per field.
+    pub fn to_prompt_block(&self) -> String {
+        self.fields()
+            .into_iter()
+            .map(|(label, value)| format!("- {}: {}\n", label, value))
+            .collect()
+    }
+}
+
+impl Experience {
+    /// Renders the experience for inclusion in an agent's context.
+    ///
+    /// See [`RenderStyle`] for the formats. The output never ends in a
+    /// newline, so entries can be joined with whatever separator suits.
+    pub fn render_for_context(&self, style: RenderStyle) -> String {
+        let kind = &self.experience_type;
+        match style {
+            RenderStyle::Compact => match kind.lesson() {
+                Some(lesson) => format!("[{}] {} — {}", kind.label(), self.content, lesson),
+                None => format!("[{}] {}", kind.label(), self.content),
+            },
+            RenderStyle::Markdown => {
+                let mut out = format!("### {}\n\n{}\n", kind.label(), self.content);
+                let mut block = kind.to_prompt_block();
+                if !self.domain.is_empty() {
+                    block.push_str(&format!("- Domain: {}\n", self.domain.join(", ")));
+                }
+                if !block.is_empty() {
+                    out.push('\n');
+                    out.push_str(&block);
+                }
+                out.truncate(out.trim_end().len());
+                out
+            }
+        }
+    }
+}
+
+/// Lower-case outcome phrase for a solution.
+fn outcome(worked: bool) -> &'static str {
+    if worked {
+        "worked"
+    } else {
+        "did not work"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AgentId, CollectiveId, ExperienceId, Timestamp};
+
+    fn experience(experience_type: ExperienceType, domain: &[&str]) -> Experience {
+        Experience {
+            id: ExperienceId::new(),
+            collective_id: CollectiveId::new(),
+            content: "borrow checker rejects the loop".to_string(),
+            embedding: vec![],
+            experience_type,
+            importance: 0.5,
+            confidence: 0.5,
+            applications: 0,
+            domain: domain.iter().map(|d| d.to_string()).collect(),
+            related_files: vec![],
+            source_agent: AgentId::new("agent"),
+            source_task: None,
+            user_id: None,
+            timestamp: Timestamp::now(),
+            expires_at: None,
+            archived: false,
+            attribution: None,
+        }
+    }
+
+    fn error_pattern() -> ExperienceType {
+        ExperienceType::ErrorPattern {
+            signature: "E0382".to_string(),
+            fix: "clone before the move".to_string(),
+            prevention: "borrow instead of moving".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_error_pattern_prompt_block() {
+        assert_eq!(
+            error_pattern().to_prompt_block(),
+            "- Signature: E0382\n- Fix: clone before the move\n- Prevention: borrow instead of moving\n"
+        );
+        assert_eq!(
+            error_pattern().lesson().unwrap(),
+            "On `E0382`: fix by clone before the move; prevent by borrow instead of moving"
+        );
+    }
+
+    #[test]
+    fn test_solution_outcome_summary() {
+        let solution = |worked| ExperienceType::Solution {
+            problem_ref: None,
+            approach: "pin the dependency".to_string(),
+            worked,
+        };
+        assert_eq!(
+            solution(true).outcome_summary().unwrap(),
+            "Worked: pin the dependency"
+        );
+        assert_eq!(
+            solution(false).lesson().unwrap(),
+            "Did not work: pin the dependency"
+        );
+        assert!(error_pattern().outcome_summary().is_none());
+    }
+
+    #[test]
+    fn test_render_styles() {
+        let exp = experience(error_pattern(), &["rust"]);
+        assert_eq!(
+            exp.render_for_context(RenderStyle::Compact),
+            "[Error pattern] borrow checker rejects the loop — On `E0382`: fix by clone \
+             before the move; prevent by borrow instead of moving"
+        );
+        assert_eq!(
+            exp.render_for_context(RenderStyle::Markdown),
+            "### Error pattern\n\nborrow checker rejects the loop\n\n- Signature: E0382\n\
+             - Fix: clone before the move\n- Prevention: borrow instead of moving\n- Domain: rust"
+        );
+
+        // Generic experiences have no lesson or fields
+        let note = experience(ExperienceType::default(), &[]);
+        assert_eq!(
+            note.render_for_context(RenderStyle::Compact),
+            "[Note] borrow checker rejects the loop"
+        );
+        assert_eq!(
+            note.render_for_context(RenderStyle::Markdown),
+            "### Note\n\nborrow checker rejects the loop"
+        );
+    }
+}
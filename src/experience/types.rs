@@ -15,7 +15,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::storage::schema::ExperienceTypeTag;
-use crate::types::{AgentId, CollectiveId, ExperienceId, TaskId, Timestamp};
+use crate::types::{AgentId, CollectiveId, ExperienceId, TaskId, Timestamp, UserId};
 
 // ============================================================================
 // Severity
@@ -59,6 +59,7 @@ pub enum Severity {
 /// - **TechInsight** — Technical knowledge about a technology
 /// - **Fact** — A verified factual statement with source
 /// - **Generic** — Catch-all for uncategorized experiences
+/// - **OpenQuestion** — Something the collective doesn't know yet
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ExperienceType {
     /// Problem encountered by the agent.
@@ -138,6 +139,16 @@ pub enum ExperienceType {
         /// Optional category label.
         category: Option<String>,
     },
+
+    /// Open question the collective can't answer yet.
+    ///
+    /// Link answers with [`PulseDB::answer_question()`](crate::PulseDB::answer_question);
+    /// [`PulseDB::list_open_questions()`](crate::PulseDB::list_open_questions)
+    /// lists the questions that have none.
+    OpenQuestion {
+        /// The question being asked.
+        question: String,
+    },
 }
 
 impl ExperienceType {
@@ -156,6 +167,7 @@ impl ExperienceType {
             Self::TechInsight { .. } => ExperienceTypeTag::TechInsight,
             Self::Fact { .. } => ExperienceTypeTag::Fact,
             Self::Generic { .. } => ExperienceTypeTag::Generic,
+            Self::OpenQuestion { .. } => ExperienceTypeTag::OpenQuestion,
         }
     }
 }
@@ -178,10 +190,12 @@ impl Default for ExperienceType {
 ///
 /// # Serialization Note
 ///
-/// The `embedding` field is marked `#[serde(skip)]` because embeddings are
-/// stored in a separate `EMBEDDINGS_TABLE` for performance. The storage
-/// layer reconstitutes the full struct by joining both tables on read.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Binary formats (the storage codec) leave out `embedding`, `user_id`,
+/// `expires_at`, and `attribution`, which live in side tables; the storage layer
+/// reconstitutes the full struct by joining the tables on read.
+/// Human-readable formats such as JSON carry every field. See the
+/// [crate docs](crate#serialization) for the wire format.
+#[derive(Clone, Debug)]
 pub struct Experience {
     /// Unique identifier (UUID v7, time-ordered).
     pub id: ExperienceId,
@@ -196,7 +210,6 @@ pub struct Experience {
     ///
     /// Stored separately in EMBEDDINGS_TABLE; skipped during bincode
     /// serialization of the main experience record.
-    #[serde(skip)]
     pub embedding: Vec<f32>,
 
     /// Rich experience type with associated data.
@@ -223,14 +236,34 @@ pub struct Experience {
     /// Optional task context where this experience was created.
     pub source_task: Option<TaskId>,
 
+    /// The end user this experience is about or was produced for, if any.
+    ///
+    /// Stored separately in EXPERIENCE_USERS_TABLE (like `attribution`)
+    /// and indexed for [`experiences_for_user()`](crate::PulseDB::experiences_for_user).
+    pub user_id: Option<UserId>,
+
     /// When this experience was recorded.
     pub timestamp: Timestamp,
 
+    /// When this experience expires, if it was recorded with a TTL.
+    ///
+    /// Stored separately in EXPERIENCE_EXPIRY_TABLE and indexed by
+    /// collective and time. Expired experiences stay readable until
+    /// [`purge_expired()`](crate::PulseDB::purge_expired) deletes them.
+    pub expires_at: Option<Timestamp>,
+
     /// Whether this experience is archived (soft-deleted).
     ///
     /// Archived experiences are excluded from search results but remain
     /// in storage and can be restored via `unarchive_experience()`.
     pub archived: bool,
+
+    /// The model that produced this experience, if recorded.
+    ///
+    /// Stored separately in EXPERIENCE_ATTRIBUTION_TABLE so experience
+    /// records written before attribution existed decode unchanged;
+    /// skipped during bincode serialization of the main record.
+    pub attribution: Option<ModelAttribution>,
 }
 
 // ============================================================================
@@ -246,11 +279,19 @@ pub struct Experience {
 ///
 /// - **External provider**: `embedding` is required (must be `Some`)
 /// - **Builtin provider**: `embedding` is optional; if `None`, PulseDB generates it
-#[derive(Clone, Debug)]
+///
+/// When deserialized, missing fields take their [`Default`] values.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct NewExperience {
     /// The collective to store this experience in.
     pub collective_id: CollectiveId,
 
+    /// Caller-supplied ID, overriding [`Config::id_strategy`](crate::Config::id_strategy).
+    ///
+    /// Recording fails if an experience with this ID already exists.
+    pub id: Option<ExperienceId>,
+
     /// The experience content (text).
     pub content: String,
 
@@ -277,12 +318,26 @@ pub struct NewExperience {
 
     /// Optional task context.
     pub source_task: Option<TaskId>,
+
+    /// Optional end user this experience is about or was produced for.
+    pub user_id: Option<UserId>,
+
+    /// When the experience expires (must be in the future).
+    ///
+    /// Use this for short-lived observations such as per-session scratch
+    /// memories; [`purge_expired()`](crate::PulseDB::purge_expired)
+    /// deletes them once the time has passed.
+    pub expires_at: Option<Timestamp>,
+
+    /// The model that produced this experience.
+    pub attribution: Option<ModelAttribution>,
 }
 
 impl Default for NewExperience {
     fn default() -> Self {
         Self {
             collective_id: CollectiveId::nil(),
+            id: None,
             content: String::new(),
             experience_type: ExperienceType::default(),
             embedding: None,
@@ -292,10 +347,53 @@ impl Default for NewExperience {
             related_files: Vec::new(),
             source_agent: AgentId::new("anonymous"),
             source_task: None,
+            user_id: None,
+            expires_at: None,
+            attribution: None,
         }
     }
 }
 
+// ============================================================================
+// ModelAttribution — Provenance of model-produced experiences
+// ============================================================================
+
+/// Which language model produced an experience, and how.
+///
+/// Recorded as structured provenance so knowledge quality can be compared
+/// across producing models with [`SearchFilter`](crate::SearchFilter)
+/// instead of being stuffed into content or domain tags.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::ModelAttribution;
+///
+/// let attribution = ModelAttribution {
+///     model_name: "gpt-4o".to_string(),
+///     model_version: Some("2024-08-06".to_string()),
+///     temperature: Some(0.2),
+///     tool: Some("code-review".to_string()),
+/// };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelAttribution {
+    /// Model name (e.g., "claude-sonnet", "gpt-4o"). Required.
+    pub model_name: String,
+
+    /// Model version or snapshot identifier.
+    #[serde(default)]
+    pub model_version: Option<String>,
+
+    /// Sampling temperature the model ran with.
+    #[serde(default)]
+    pub temperature: Option<f32>,
+
+    /// Tool or pipeline step that invoked the model.
+    #[serde(default)]
+    pub tool: Option<String>,
+}
+
 // ============================================================================
 // ExperienceUpdate — Partial update for mutable fields
 // ============================================================================
@@ -304,7 +402,8 @@ impl Default for NewExperience {
 ///
 /// Only fields set to `Some(...)` will be updated. Content and embedding
 /// are immutable — create a new experience if content changes.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ExperienceUpdate {
     /// New importance score (0.0–1.0).
     pub importance: Option<f32>,
@@ -507,8 +606,14 @@ mod tests {
             related_files: vec!["src/main.rs".into()],
             source_agent: AgentId::new("agent-1"),
             source_task: Some(TaskId::new("task-42")),
+            user_id: None,
             timestamp: Timestamp::now(),
+            expires_at: None,
             archived: false,
+            attribution: Some(ModelAttribution {
+                model_name: "gpt-4o".into(),
+                ..Default::default()
+            }),
         };
 
         let bytes = bincode::serialize(&exp).unwrap();
@@ -532,6 +637,8 @@ mod tests {
         assert_eq!(exp.source_task, restored.source_task);
         assert_eq!(exp.timestamp, restored.timestamp);
         assert_eq!(exp.archived, restored.archived);
+        // Attribution lives in its own table — skipped like the embedding
+        assert!(restored.attribution.is_none());
     }
 
     #[test]
@@ -549,8 +656,11 @@ mod tests {
             related_files: vec![],
             source_agent: AgentId::new("a"),
             source_task: None,
+            user_id: None,
             timestamp: Timestamp::now(),
+            expires_at: None,
             archived: false,
+            attribution: None,
         };
 
         let bytes = bincode::serialize(&exp).unwrap();
@@ -583,6 +693,7 @@ mod tests {
         assert!(ne.related_files.is_empty());
         assert_eq!(ne.source_agent.as_str(), "anonymous");
         assert!(ne.source_task.is_none());
+        assert!(ne.attribution.is_none());
     }
 
     // ====================================================================
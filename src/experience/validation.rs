@@ -14,11 +14,12 @@
 //! ```
 
 use crate::error::{PulseDBError, ValidationError};
-use crate::experience::types::{ExperienceType, ExperienceUpdate, NewExperience};
+use crate::experience::types::{ExperienceType, ExperienceUpdate, ModelAttribution, NewExperience};
 use crate::storage::schema::{
-    MAX_CONTENT_SIZE, MAX_DOMAIN_TAGS, MAX_FILE_PATH_LENGTH, MAX_SOURCE_AGENT_LENGTH,
-    MAX_SOURCE_FILES, MAX_TAG_LENGTH,
+    MAX_ATTRIBUTION_FIELD_LENGTH, MAX_CONTENT_SIZE, MAX_DOMAIN_TAGS, MAX_FILE_PATH_LENGTH,
+    MAX_SOURCE_AGENT_LENGTH, MAX_SOURCE_FILES, MAX_SUBJECT_ID_LENGTH, MAX_TAG_LENGTH,
 };
+use crate::types::{ExperienceId, Timestamp};
 
 /// Validates a [`NewExperience`] before storage.
 ///
@@ -26,6 +27,7 @@ use crate::storage::schema::{
 ///
 /// | Field | Constraint |
 /// |-------|------------|
+/// | `id` | Not the nil UUID when set |
 /// | `content` | Non-empty, max 100 KB |
 /// | `importance` | 0.0–1.0 |
 /// | `confidence` | 0.0–1.0 |
@@ -33,12 +35,20 @@ use crate::storage::schema::{
 /// | `related_files` | Max 100 paths, each max 500 chars |
 /// | `embedding` | Required if `is_external_provider`; dimension must match collective |
 /// | `source_agent` | Non-empty, max 256 chars |
+/// | `source_task`, `user_id` | Non-empty, max 256 chars when set |
+/// | `expires_at` | In the future when set |
+/// | `attribution` | Non-empty model name; names, version, tool max 255 chars; temperature finite and ≥ 0 |
 /// | `experience_type` | Variant-specific field validation (quality, strength) |
 pub(crate) fn validate_new_experience(
     exp: &NewExperience,
     collective_dimension: u16,
     is_external_provider: bool,
 ) -> Result<(), PulseDBError> {
+    // ID: the nil UUID is never a valid caller-supplied ID
+    if exp.id == Some(ExperienceId::nil()) {
+        return Err(ValidationError::invalid_field("id", "must not be the nil UUID").into());
+    }
+
     // Content: non-empty
     if exp.content.is_empty() {
         return Err(ValidationError::required_field("content").into());
@@ -153,12 +163,96 @@ pub(crate) fn validate_new_experience(
         .into());
     }
 
+    // Task and user links: non-empty, bounded (they key secondary indexes)
+    if let Some(ref task) = exp.source_task {
+        validate_subject_id("source_task", task.as_str())?;
+    }
+    if let Some(ref user) = exp.user_id {
+        validate_subject_id("user_id", user.as_str())?;
+    }
+
+    // Expiry: an already-expired experience would only be purged again
+    if let Some(expires_at) = exp.expires_at {
+        if expires_at <= Timestamp::now() {
+            return Err(
+                ValidationError::invalid_field("expires_at", "must be in the future").into(),
+            );
+        }
+    }
+
+    // Attribution: structured model provenance
+    if let Some(ref attribution) = exp.attribution {
+        validate_attribution(attribution)?;
+    }
+
     // Experience type: variant-specific validation
     validate_experience_type(&exp.experience_type)?;
 
     Ok(())
 }
 
+/// Validates a task or user identifier linked to an experience.
+fn validate_subject_id(field: &str, id: &str) -> Result<(), PulseDBError> {
+    if id.is_empty() {
+        return Err(ValidationError::required_field(field).into());
+    }
+    if id.len() > MAX_SUBJECT_ID_LENGTH {
+        return Err(ValidationError::invalid_field(
+            field,
+            format!(
+                "exceeds max length of {} chars (got {})",
+                MAX_SUBJECT_ID_LENGTH,
+                id.len()
+            ),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Validates a [`ModelAttribution`].
+fn validate_attribution(attribution: &ModelAttribution) -> Result<(), PulseDBError> {
+    if attribution.model_name.is_empty() {
+        return Err(ValidationError::required_field("attribution.model_name").into());
+    }
+
+    let fields = [
+        ("attribution.model_name", Some(&attribution.model_name)),
+        (
+            "attribution.model_version",
+            attribution.model_version.as_ref(),
+        ),
+        ("attribution.tool", attribution.tool.as_ref()),
+    ];
+    for (field, value) in fields {
+        if let Some(value) = value {
+            if value.len() > MAX_ATTRIBUTION_FIELD_LENGTH {
+                return Err(ValidationError::invalid_field(
+                    field,
+                    format!(
+                        "exceeds max length of {} chars (got {})",
+                        MAX_ATTRIBUTION_FIELD_LENGTH,
+                        value.len()
+                    ),
+                )
+                .into());
+            }
+        }
+    }
+
+    if let Some(temperature) = attribution.temperature {
+        if !temperature.is_finite() || temperature < 0.0 {
+            return Err(ValidationError::invalid_field(
+                "attribution.temperature",
+                format!("must be a finite value >= 0.0, got {}", temperature),
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
 /// Validates an [`ExperienceUpdate`] before applying.
 ///
 /// Only validates fields that are `Some(...)`.
@@ -245,6 +339,7 @@ pub(crate) fn validate_experience_update(update: &ExperienceUpdate) -> Result<()
 ///
 /// Other variants have no additional numeric constraints beyond what
 /// Rust's type system enforces.
+#[allow(clippy::collapsible_match)]
 fn validate_experience_type(et: &ExperienceType) -> Result<(), PulseDBError> {
     match et {
         ExperienceType::SuccessPattern { quality, .. } => {
@@ -276,11 +371,12 @@ mod tests {
     use crate::storage::schema::{
         MAX_CONTENT_SIZE, MAX_FILE_PATH_LENGTH, MAX_SOURCE_AGENT_LENGTH, MAX_TAG_LENGTH,
     };
-    use crate::types::{AgentId, CollectiveId};
+    use crate::types::{AgentId, CollectiveId, TaskId, UserId};
 
     fn valid_new_experience() -> NewExperience {
         NewExperience {
             collective_id: CollectiveId::new(),
+            id: None,
             content: "Test experience content".into(),
             experience_type: ExperienceType::default(),
             embedding: Some(vec![0.1; 384]),
@@ -290,6 +386,9 @@ mod tests {
             related_files: vec!["src/main.rs".into()],
             source_agent: AgentId::new("agent-1"),
             source_task: None,
+            user_id: None,
+            expires_at: None,
+            attribution: None,
         }
     }
 
@@ -575,6 +674,100 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_nil_id_rejected() {
+        let mut exp = valid_new_experience();
+        exp.id = Some(ExperienceId::new());
+        assert!(validate_new_experience(&exp, 384, true).is_ok());
+
+        exp.id = Some(ExperienceId::nil());
+        let err = validate_new_experience(&exp, 384, true).unwrap_err();
+        assert!(err.to_string().contains("id"));
+    }
+
+    // ====================================================================
+    // Task and user link validation
+    // ====================================================================
+
+    #[test]
+    fn test_subject_ids_validated() {
+        let mut exp = valid_new_experience();
+        exp.source_task = Some(TaskId::new("task-1"));
+        exp.user_id = Some(UserId::new("user-1"));
+        assert!(validate_new_experience(&exp, 384, true).is_ok());
+
+        exp.user_id = Some(UserId::new(""));
+        let err = validate_new_experience(&exp, 384, true).unwrap_err();
+        assert!(err.to_string().contains("user_id"));
+
+        exp.user_id = None;
+        exp.source_task = Some(TaskId::new("t".repeat(MAX_SUBJECT_ID_LENGTH + 1)));
+        let err = validate_new_experience(&exp, 384, true).unwrap_err();
+        assert!(err.to_string().contains("source_task"));
+    }
+
+    #[test]
+    fn test_expires_at_must_be_in_the_future() {
+        let mut exp = valid_new_experience();
+        exp.expires_at = Some(Timestamp::from_millis(
+            Timestamp::now().as_millis() + 60_000,
+        ));
+        assert!(validate_new_experience(&exp, 384, true).is_ok());
+
+        exp.expires_at = Some(Timestamp::from_millis(1));
+        let err = validate_new_experience(&exp, 384, true).unwrap_err();
+        assert!(err.to_string().contains("expires_at"));
+    }
+
+    // ====================================================================
+    // Attribution validation
+    // ====================================================================
+
+    fn attribution(model_name: &str) -> ModelAttribution {
+        ModelAttribution {
+            model_name: model_name.into(),
+            model_version: Some("2024-08-06".into()),
+            temperature: Some(0.7),
+            tool: Some("planner".into()),
+        }
+    }
+
+    #[test]
+    fn test_valid_attribution_passes() {
+        let mut exp = valid_new_experience();
+        exp.attribution = Some(attribution("gpt-4o"));
+        assert!(validate_new_experience(&exp, 384, true).is_ok());
+    }
+
+    #[test]
+    fn test_attribution_empty_model_name_rejected() {
+        let mut exp = valid_new_experience();
+        exp.attribution = Some(attribution(""));
+        let err = validate_new_experience(&exp, 384, true).unwrap_err();
+        assert!(err.to_string().contains("attribution.model_name"));
+    }
+
+    #[test]
+    fn test_attribution_field_too_long_rejected() {
+        let mut exp = valid_new_experience();
+        let mut attr = attribution("gpt-4o");
+        attr.tool = Some("t".repeat(MAX_ATTRIBUTION_FIELD_LENGTH + 1));
+        exp.attribution = Some(attr);
+        let err = validate_new_experience(&exp, 384, true).unwrap_err();
+        assert!(err.to_string().contains("attribution.tool"));
+    }
+
+    #[test]
+    fn test_attribution_invalid_temperature_rejected() {
+        for temperature in [-0.1, f32::NAN, f32::INFINITY] {
+            let mut exp = valid_new_experience();
+            let mut attr = attribution("gpt-4o");
+            attr.temperature = Some(temperature);
+            exp.attribution = Some(attr);
+            assert!(validate_new_experience(&exp, 384, true).is_err());
+        }
+    }
+
     // ====================================================================
     // NEW: ExperienceType variant validation
     // ====================================================================
@@ -0,0 +1,300 @@
+//! Serde representation of [`Experience`].
+//!
+//! An experience has two serialized forms, chosen by the format's
+//! [`is_human_readable()`](serde::Serializer::is_human_readable):
+//!
+//! - **Storage** (bincode and other binary formats) — the record layout
+//!   of `EXPERIENCES_TABLE`. `embedding`, `user_id`, `expires_at`, and
+//!   `attribution` live in side tables and are left out. This layout must
+//!   never change.
+//! - **Wire** (JSON and other human-readable formats) — every field, so
+//!   an experience can cross a service boundary whole. `embedding`,
+//!   `user_id`, `expires_at`, `attribution`, and the collection fields may
+//!   be omitted on input and default to empty; unknown fields are ignored.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::types::{Experience, ExperienceType, ModelAttribution};
+use crate::types::{AgentId, CollectiveId, ExperienceId, TaskId, Timestamp, UserId};
+
+/// Storage layout, borrowed for serialization. Field order is the
+/// on-disk order.
+#[derive(Serialize)]
+struct StoredRef<'a> {
+    id: ExperienceId,
+    collective_id: CollectiveId,
+    content: &'a str,
+    experience_type: &'a ExperienceType,
+    importance: f32,
+    confidence: f32,
+    applications: u32,
+    domain: &'a [String],
+    related_files: &'a [String],
+    source_agent: &'a AgentId,
+    source_task: &'a Option<TaskId>,
+    timestamp: Timestamp,
+    archived: bool,
+}
+
+/// Storage layout, owned for deserialization.
+#[derive(Deserialize)]
+struct Stored {
+    id: ExperienceId,
+    collective_id: CollectiveId,
+    content: String,
+    experience_type: ExperienceType,
+    importance: f32,
+    confidence: f32,
+    applications: u32,
+    domain: Vec<String>,
+    related_files: Vec<String>,
+    source_agent: AgentId,
+    source_task: Option<TaskId>,
+    timestamp: Timestamp,
+    archived: bool,
+}
+
+/// Leading fields of the storage layout.
+///
+/// Decoding a stored record as this stops after `collective_id`, so the
+/// content and everything after it are never copied out.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ExperienceHead {
+    pub id: ExperienceId,
+    pub collective_id: CollectiveId,
+}
+
+/// Wire layout, borrowed for serialization.
+#[derive(Serialize)]
+struct WireRef<'a> {
+    id: ExperienceId,
+    collective_id: CollectiveId,
+    content: &'a str,
+    embedding: &'a [f32],
+    experience_type: &'a ExperienceType,
+    importance: f32,
+    confidence: f32,
+    applications: u32,
+    domain: &'a [String],
+    related_files: &'a [String],
+    source_agent: &'a AgentId,
+    source_task: &'a Option<TaskId>,
+    user_id: &'a Option<UserId>,
+    timestamp: Timestamp,
+    expires_at: Option<Timestamp>,
+    archived: bool,
+    attribution: &'a Option<ModelAttribution>,
+}
+
+/// Wire layout, owned for deserialization.
+#[derive(Deserialize)]
+struct Wire {
+    id: ExperienceId,
+    collective_id: CollectiveId,
+    content: String,
+    #[serde(default)]
+    embedding: Vec<f32>,
+    experience_type: ExperienceType,
+    importance: f32,
+    confidence: f32,
+    #[serde(default)]
+    applications: u32,
+    #[serde(default)]
+    domain: Vec<String>,
+    #[serde(default)]
+    related_files: Vec<String>,
+    source_agent: AgentId,
+    #[serde(default)]
+    source_task: Option<TaskId>,
+    #[serde(default)]
+    user_id: Option<UserId>,
+    timestamp: Timestamp,
+    #[serde(default)]
+    expires_at: Option<Timestamp>,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    attribution: Option<ModelAttribution>,
+}
+
+impl Serialize for Experience {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            WireRef {
+                id: self.id,
+                collective_id: self.collective_id,
+                content: &self.content,
+                embedding: &self.embedding,
+                experience_type: &self.experience_type,
+                importance: self.importance,
+                confidence: self.confidence,
+                applications: self.applications,
+                domain: &self.domain,
+                related_files: &self.related_files,
+                source_agent: &self.source_agent,
+                source_task: &self.source_task,
+                user_id: &self.user_id,
+                timestamp: self.timestamp,
+                expires_at: self.expires_at,
+                archived: self.archived,
+                attribution: &self.attribution,
+            }
+            .serialize(serializer)
+        } else {
+            StoredRef {
+                id: self.id,
+                collective_id: self.collective_id,
+                content: &self.content,
+                experience_type: &self.experience_type,
+                importance: self.importance,
+                confidence: self.confidence,
+                applications: self.applications,
+                domain: &self.domain,
+                related_files: &self.related_files,
+                source_agent: &self.source_agent,
+                source_task: &self.source_task,
+                timestamp: self.timestamp,
+                archived: self.archived,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Experience {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let w = Wire::deserialize(deserializer)?;
+            Ok(Self {
+                id: w.id,
+                collective_id: w.collective_id,
+                content: w.content,
+                embedding: w.embedding,
+                experience_type: w.experience_type,
+                importance: w.importance,
+                confidence: w.confidence,
+                applications: w.applications,
+                domain: w.domain,
+                related_files: w.related_files,
+                source_agent: w.source_agent,
+                source_task: w.source_task,
+                user_id: w.user_id,
+                timestamp: w.timestamp,
+                expires_at: w.expires_at,
+                archived: w.archived,
+                attribution: w.attribution,
+            })
+        } else {
+            let s = Stored::deserialize(deserializer)?;
+            Ok(Self {
+                id: s.id,
+                collective_id: s.collective_id,
+                content: s.content,
+                embedding: Vec::new(),
+                experience_type: s.experience_type,
+                importance: s.importance,
+                confidence: s.confidence,
+                applications: s.applications,
+                domain: s.domain,
+                related_files: s.related_files,
+                source_agent: s.source_agent,
+                source_task: s.source_task,
+                user_id: None,
+                timestamp: s.timestamp,
+                expires_at: None,
+                archived: s.archived,
+                attribution: None,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn experience() -> Experience {
+        Experience {
+            id: ExperienceId::new(),
+            collective_id: CollectiveId::new(),
+            content: "cache invalidation".to_string(),
+            embedding: vec![0.25, 0.5],
+            experience_type: ExperienceType::default(),
+            importance: 0.7,
+            confidence: 0.6,
+            applications: 3,
+            domain: vec!["caching".to_string()],
+            related_files: vec![],
+            source_agent: AgentId::new("agent"),
+            source_task: Some(TaskId::new("task")),
+            user_id: Some(UserId::new("user")),
+            timestamp: Timestamp::from_millis(1_700_000_000_000),
+            expires_at: Some(Timestamp::from_millis(1_800_000_000_000)),
+            archived: false,
+            attribution: Some(ModelAttribution {
+                model_name: "model".to_string(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_storage_layout_matches_original_derive() {
+        // The layout the derive with #[serde(skip)] fields produced
+        #[derive(Serialize)]
+        struct Original<'a> {
+            id: ExperienceId,
+            collective_id: CollectiveId,
+            content: &'a String,
+            experience_type: &'a ExperienceType,
+            importance: f32,
+            confidence: f32,
+            applications: u32,
+            domain: &'a Vec<String>,
+            related_files: &'a Vec<String>,
+            source_agent: &'a AgentId,
+            source_task: &'a Option<TaskId>,
+            timestamp: Timestamp,
+            archived: bool,
+        }
+
+        let exp = experience();
+        let original = Original {
+            id: exp.id,
+            collective_id: exp.collective_id,
+            content: &exp.content,
+            experience_type: &exp.experience_type,
+            importance: exp.importance,
+            confidence: exp.confidence,
+            applications: exp.applications,
+            domain: &exp.domain,
+            related_files: &exp.related_files,
+            source_agent: &exp.source_agent,
+            source_task: &exp.source_task,
+            timestamp: exp.timestamp,
+            archived: exp.archived,
+        };
+        let bytes = bincode::serialize(&exp).unwrap();
+        assert_eq!(bytes, bincode::serialize(&original).unwrap());
+
+        let restored: Experience = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(restored.id, exp.id);
+        assert_eq!(restored.source_task, exp.source_task);
+        assert!(restored.embedding.is_empty());
+        assert!(restored.user_id.is_none());
+        assert!(restored.expires_at.is_none());
+        assert!(restored.attribution.is_none());
+    }
+
+    #[test]
+    fn test_wire_layout_keeps_side_table_fields() {
+        let exp = experience();
+        let json = serde_json::to_string(&exp).unwrap();
+        let restored: Experience = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.embedding, exp.embedding);
+        assert_eq!(restored.user_id, exp.user_id);
+        assert_eq!(restored.expires_at, exp.expires_at);
+        assert_eq!(restored.attribution, exp.attribution);
+        assert_eq!(restored.applications, 3);
+    }
+}
@@ -0,0 +1,102 @@
+//! Checksummed record frames of the export stream.
+//!
+//! ```text
+//! frame = [tag: u8][payload_len: u32 LE][crc32: u32 LE][payload: bincode]
+//! ```
+//!
+//! The tag says what the payload holds; see [`stream`](super::stream).
+
+use std::io::{Read, Write};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{PulseDBError, StorageError};
+
+/// Upper bound on a single frame, guarding against garbage lengths.
+const MAX_FRAME_BYTES: u32 = 64 * 1024 * 1024;
+
+/// Encodes `record` and writes it as one frame.
+pub(crate) fn write_frame<T: Serialize>(
+    writer: &mut impl Write,
+    tag: u8,
+    record: &T,
+) -> Result<(), PulseDBError> {
+    let payload =
+        bincode::serialize(record).map_err(|e| StorageError::serialization(e.to_string()))?;
+    if payload.len() > MAX_FRAME_BYTES as usize {
+        return Err(StorageError::serialization("export record exceeds 64 MiB").into());
+    }
+    writer.write_all(&[tag])?;
+    writer.write_all(&(payload.len() as u32).to_le_bytes())?;
+    writer.write_all(&crc32fast::hash(&payload).to_le_bytes())?;
+    writer.write_all(&payload)?;
+    Ok(())
+}
+
+/// Reads one frame, verifying its checksum, and returns its tag and
+/// payload.
+///
+/// A stream that ends partway through a frame is reported as corruption.
+pub(crate) fn read_frame(reader: &mut impl Read) -> Result<(u8, Vec<u8>), PulseDBError> {
+    let mut tag = [0u8; 1];
+    reader.read_exact(&mut tag).map_err(truncated)?;
+    let len = read_u32(reader).map_err(truncated)?;
+    if len > MAX_FRAME_BYTES {
+        return Err(StorageError::corrupted("export frame is implausibly large").into());
+    }
+    let checksum = read_u32(reader).map_err(truncated)?;
+    let mut payload = vec![0u8; len as usize];
+    reader.read_exact(&mut payload).map_err(truncated)?;
+    if crc32fast::hash(&payload) != checksum {
+        return Err(StorageError::corrupted("checksum mismatch in export frame").into());
+    }
+    Ok((tag[0], payload))
+}
+
+/// Decodes a frame payload.
+pub(crate) fn decode<T: DeserializeOwned>(payload: &[u8]) -> Result<T, PulseDBError> {
+    bincode::deserialize(payload)
+        .map_err(|e| StorageError::corrupted(format!("invalid export record: {}", e)).into())
+}
+
+fn read_u32(reader: &mut impl Read) -> std::io::Result<u32> {
+    let mut word = [0u8; 4];
+    reader.read_exact(&mut word)?;
+    Ok(u32::from_le_bytes(word))
+}
+
+/// Reports a stream that ends early, or that fails to decompress, as
+/// corruption.
+pub(crate) fn truncated(err: std::io::Error) -> PulseDBError {
+    match err.kind() {
+        std::io::ErrorKind::UnexpectedEof => StorageError::corrupted("export is truncated").into(),
+        // zstd reports malformed input as these
+        std::io::ErrorKind::InvalidData | std::io::ErrorKind::Other => {
+            StorageError::corrupted(format!("export does not decompress: {}", err)).into()
+        }
+        _ => err.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_roundtrip_and_checksum() {
+        let mut bytes = Vec::new();
+        write_frame(&mut bytes, 7, &("note".to_string(), 42u64)).unwrap();
+
+        let (tag, payload) = read_frame(&mut bytes.as_slice()).unwrap();
+        assert_eq!(tag, 7);
+        let record: (String, u64) = decode(&payload).unwrap();
+        assert_eq!(record, ("note".to_string(), 42));
+
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        assert!(read_frame(&mut bytes.as_slice()).is_err());
+        let err = read_frame(&mut &bytes[..5]).unwrap_err();
+        assert!(err.to_string().contains("truncated"));
+    }
+}
@@ -0,0 +1,621 @@
+//! Portable, verifiable export format.
+//!
+//! Every logical backup PulseDB writes uses this format:
+//! [`export()`](crate::PulseDB::export) for the whole database,
+//! [`backup_incremental()`](crate::PulseDB::backup_incremental) for the
+//! changes since an earlier backup, and
+//! [`export_collective()`](crate::PulseDB::export_collective) for a single
+//! collective. [`backup_to()`](crate::PulseDB::backup_to) is the one
+//! exception: it copies the database files themselves.
+//!
+//! # Layout
+//!
+//! ```text
+//! [magic "PULSEEXP": 8B][format_version: u32 LE][manifest_len: u32 LE]
+//! [manifest: JSON][records]
+//! ```
+//!
+//! The records are a zstd-compressed stream of checksummed frames, written
+//! and read one record at a time; see [`stream`]. The manifest can be read
+//! and checked without decompressing anything.
+//!
+//! # Operations
+//!
+//! - [`PulseDB::export(path)`](crate::PulseDB::export)
+//! - [`PulseDB::verify_export(path)`](crate::PulseDB::verify_export)
+//! - [`PulseDB::import(path)`](crate::PulseDB::import)
+//! - [`PulseDB::backup_incremental(path, since_cursor)`](crate::PulseDB::backup_incremental)
+//! - [`PulseDB::restore_chain(paths, db_path, config)`](crate::PulseDB::restore_chain)
+//! - [`PulseDB::export_collective(collective_id, writer)`](crate::PulseDB::export_collective)
+//! - [`PulseDB::import_collective(reader)`](crate::PulseDB::import_collective)
+//! - [`PulseDB::export_training_data(collective_id, format, filter, writer)`](crate::PulseDB::export_training_data)
+//!
+//! # Backup chains
+//!
+//! Full exports and incremental backups share this layout. A delta holds
+//! the changelog-driven upserts and tombstones since its `base_sequence`,
+//! plus a full snapshot of collectives (they are few, and deleting one
+//! does not leave a changelog entry per record).
+
+mod frame;
+pub(crate) mod stream;
+pub mod training;
+pub mod types;
+
+pub use training::{TrainingExportReport, TrainingFormat, TrainingTemplate};
+pub use types::{
+    CollectiveExportReport, CollectiveImportReport, ExportKind, ExportManifest, ImportReport,
+};
+
+pub(crate) use stream::{ExportReader, ExportRecord, ExportWriter};
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read};
+use std::path::Path;
+
+use crate::error::{PulseDBError, StorageError, ValidationError};
+use crate::experience::{Experience, ModelAttribution};
+use crate::storage::schema::EntityTypeTag;
+use crate::storage::StorageEngine;
+use crate::types::{CollectiveId, ExperienceId, InsightId, RelationId, Timestamp, UserId};
+
+/// File signature at the start of every export.
+const EXPORT_MAGIC: &[u8; 8] = b"PULSEEXP";
+
+/// Current export layout version.
+pub(crate) const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Upper bound on the manifest size, guarding against garbage lengths.
+const MAX_MANIFEST_BYTES: u32 = 16 * 1024 * 1024;
+
+/// An experience with the fields serde skips: embedding, model
+/// attribution, user link, and expiry time.
+pub(crate) type ExportedExperience = (
+    Experience,
+    Vec<f32>,
+    Option<ModelAttribution>,
+    Option<UserId>,
+    Option<Timestamp>,
+);
+
+/// Splits out the fields serde skips so they travel with the record.
+pub(crate) fn export_experience(experience: Experience) -> ExportedExperience {
+    let embedding = experience.embedding.clone();
+    let attribution = experience.attribution.clone();
+    let user_id = experience.user_id.clone();
+    let expires_at = experience.expires_at;
+    (experience, embedding, attribution, user_id, expires_at)
+}
+
+/// Puts the fields serde skips back onto the experience.
+pub(crate) fn restore_experience(record: ExportedExperience) -> Experience {
+    let (mut experience, embedding, attribution, user_id, expires_at) = record;
+    experience.embedding = embedding;
+    experience.attribution = attribution;
+    experience.user_id = user_id;
+    experience.expires_at = expires_at;
+    experience
+}
+
+/// Writes an export to `path`: `write` streams the records into the
+/// writer, and the manifest with its counts filled in is returned.
+///
+/// Writes to a sibling `.partial` file first and renames it into place, so
+/// a crash never leaves a truncated export at `path`.
+pub(crate) fn write_export(
+    path: &Path,
+    manifest: ExportManifest,
+    write: impl FnOnce(&mut ExportWriter<BufWriter<File>>) -> Result<(), PulseDBError>,
+) -> Result<ExportManifest, PulseDBError> {
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(".partial");
+    let partial = Path::new(&partial);
+
+    let result = (|| {
+        let mut writer = ExportWriter::new(BufWriter::new(File::create(partial)?), manifest)?;
+        write(&mut writer)?;
+        let (file, manifest) = writer.finish()?;
+        file.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        Ok(manifest)
+    })();
+    match result {
+        Ok(manifest) => {
+            std::fs::rename(partial, path)?;
+            Ok(manifest)
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(partial);
+            Err(e)
+        }
+    }
+}
+
+/// Reads and validates the header and manifest of an export file.
+fn read_manifest(reader: &mut impl Read) -> Result<ExportManifest, PulseDBError> {
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic).map_err(frame::truncated)?;
+    if &magic != EXPORT_MAGIC {
+        return Err(StorageError::corrupted("not a PulseDB export file").into());
+    }
+
+    let mut word = [0u8; 4];
+    reader.read_exact(&mut word)?;
+    let version = u32::from_le_bytes(word);
+    if version != EXPORT_FORMAT_VERSION {
+        return Err(StorageError::corrupted(format!(
+            "unsupported export format version {} (expected {})",
+            version, EXPORT_FORMAT_VERSION
+        ))
+        .into());
+    }
+
+    reader.read_exact(&mut word)?;
+    let manifest_len = u32::from_le_bytes(word);
+    if manifest_len > MAX_MANIFEST_BYTES {
+        return Err(StorageError::corrupted("export manifest is implausibly large").into());
+    }
+    let mut manifest_bytes = vec![0u8; manifest_len as usize];
+    reader.read_exact(&mut manifest_bytes)?;
+    let mut manifest: ExportManifest = serde_json::from_slice(&manifest_bytes)
+        .map_err(|e| StorageError::corrupted(format!("invalid export manifest: {}", e)))?;
+    manifest.format_version = version;
+    Ok(manifest)
+}
+
+/// Reads only the manifest of an export file, without verifying records.
+///
+/// The counts are only known once every record has been read, and are
+/// returned as zero.
+pub(crate) fn read_export_manifest(path: &Path) -> Result<ExportManifest, PulseDBError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    read_manifest(&mut reader)
+}
+
+/// Opens an export file for reading.
+pub(crate) fn open_export(path: &Path) -> Result<ExportReader<BufReader<File>>, PulseDBError> {
+    open_stream(BufReader::new(File::open(path)?))
+}
+
+/// Starts reading an export from `reader`.
+pub(crate) fn open_stream<R: Read>(mut reader: R) -> Result<ExportReader<R>, PulseDBError> {
+    let manifest = read_manifest(&mut reader)?;
+    ExportReader::new(reader, manifest)
+}
+
+/// Reads and fully verifies an export file, returning its manifest.
+pub(crate) fn verify_export(path: &Path) -> Result<ExportManifest, PulseDBError> {
+    open_export(path)?.verify()
+}
+
+/// Selects the backup chain that restores the source database as of `upto`.
+///
+/// Picks the newest full export taken at or before `upto`, then every
+/// delta of the same database taken at or before `upto` that extends it,
+/// oldest first. Deltas already covered by the chain are skipped.
+pub(crate) fn select_chain<P>(
+    candidates: &[(P, ExportManifest)],
+    upto: Timestamp,
+) -> Result<Vec<&P>, PulseDBError> {
+    let base = candidates
+        .iter()
+        .filter(|(_, m)| m.kind == ExportKind::Full && m.created_at <= upto)
+        .max_by_key(|(_, m)| m.created_at)
+        .ok_or_else(|| {
+            ValidationError::invalid_field(
+                "upto",
+                format!("no full export was taken at or before {}", upto),
+            )
+        })?;
+
+    let mut deltas: Vec<&(P, ExportManifest)> = candidates
+        .iter()
+        .filter(|(_, m)| {
+            m.kind == ExportKind::Incremental
+                && m.source_created_at == base.1.source_created_at
+                && m.created_at <= upto
+        })
+        .collect();
+    deltas.sort_by_key(|(_, m)| m.sequence);
+
+    let mut chain = vec![&base.0];
+    let mut reached = base.1.sequence;
+    for (path, manifest) in deltas {
+        if manifest.sequence <= reached {
+            continue;
+        }
+        if manifest.base_sequence > reached {
+            return Err(ValidationError::invalid_field(
+                "upto",
+                format!(
+                    "backups are missing changes between sequence {} and {}",
+                    reached, manifest.base_sequence
+                ),
+            )
+            .into());
+        }
+        chain.push(path);
+        reached = manifest.sequence;
+    }
+    Ok(chain)
+}
+
+/// Checks that `manifests` form one restorable backup chain.
+///
+/// The chain must start with a full export, and every delta after it must
+/// come from the same source database and start no later than the point
+/// the chain has reached so far.
+pub(crate) fn validate_chain(manifests: &[ExportManifest]) -> Result<(), PulseDBError> {
+    let Some((base, deltas)) = manifests.split_first() else {
+        return Err(ValidationError::required_field("paths").into());
+    };
+    if base.kind != ExportKind::Full {
+        return Err(ValidationError::invalid_field(
+            "paths",
+            "backup chain must start with a full export",
+        )
+        .into());
+    }
+
+    let mut reached = base.sequence;
+    for (position, delta) in deltas.iter().enumerate().map(|(i, d)| (i + 1, d)) {
+        if delta.kind != ExportKind::Incremental {
+            return Err(ValidationError::invalid_field(
+                "paths",
+                format!(
+                    "backup {} is a full export; only the first may be",
+                    position
+                ),
+            )
+            .into());
+        }
+        if delta.source_created_at != base.source_created_at {
+            return Err(ValidationError::invalid_field(
+                "paths",
+                format!("backup {} comes from a different database", position),
+            )
+            .into());
+        }
+        if delta.embedding_dimension != base.embedding_dimension {
+            return Err(ValidationError::dimension_mismatch(
+                base.embedding_dimension,
+                delta.embedding_dimension,
+            )
+            .into());
+        }
+        if delta.base_sequence > reached {
+            return Err(ValidationError::invalid_field(
+                "paths",
+                format!(
+                    "backup {} starts after sequence {} but the chain only reaches {}",
+                    position, delta.base_sequence, reached
+                ),
+            )
+            .into());
+        }
+        reached = reached.max(delta.sequence);
+    }
+    Ok(())
+}
+
+/// Writes a full export's records into empty storage.
+pub(crate) fn apply_full(
+    storage: &dyn StorageEngine,
+    source: &mut ExportReader<impl Read>,
+) -> Result<(), PulseDBError> {
+    let (collectives, next) = source.read_collectives()?;
+    for (collective, _) in &collectives {
+        storage.save_collective(collective)?;
+    }
+    for (collective, parent) in &collectives {
+        if parent.is_some() {
+            storage.set_collective_parent(collective.id, *parent)?;
+        }
+    }
+    apply_records(storage, source, next)
+}
+
+/// Applies an incremental export on top of storage restored so far.
+///
+/// Collectives and their hierarchy are snapshotted in full by every
+/// delta, so collectives missing from it are deleted with their contents.
+pub(crate) fn apply_incremental(
+    storage: &dyn StorageEngine,
+    source: &mut ExportReader<impl Read>,
+) -> Result<(), PulseDBError> {
+    let (collectives, next) = source.read_collectives()?;
+    let live: HashSet<CollectiveId> = collectives.iter().map(|(c, _)| c.id).collect();
+    for existing in storage.list_collectives()? {
+        if !live.contains(&existing.id) {
+            storage.delete_experiences_by_collective(existing.id)?;
+            storage.delete_insights_by_collective(existing.id)?;
+            storage.delete_collective(existing.id)?;
+        }
+    }
+
+    for (collective, _) in &collectives {
+        storage.save_collective(collective)?;
+    }
+    for (collective, parent) in &collectives {
+        storage.set_collective_parent(collective.id, *parent)?;
+    }
+    apply_records(storage, source, next)
+}
+
+/// Upserts the remaining records of an export, starting with `next`, and
+/// applies its tombstones.
+///
+/// An experience's bookmarks are replaced by the bookmarks that follow
+/// it, so a delta also carries bookmarks removed since the base.
+fn apply_records(
+    storage: &dyn StorageEngine,
+    source: &mut ExportReader<impl Read>,
+    mut next: Option<ExportRecord>,
+) -> Result<(), PulseDBError> {
+    while let Some(record) = next
+        .take()
+        .map(Ok)
+        .or_else(|| source.next_record().transpose())
+    {
+        match record? {
+            ExportRecord::Collective(..) => {
+                return Err(StorageError::corrupted("export records are out of order").into())
+            }
+            ExportRecord::Experience(record) => {
+                let experience = restore_experience(*record);
+                storage.save_experience(&experience)?;
+                for agent_id in storage.list_bookmarking_agent_ids(experience.id)? {
+                    storage.remove_bookmark(&agent_id, experience.id)?;
+                }
+            }
+            ExportRecord::Relation(relation) => storage.save_relation(&relation)?,
+            ExportRecord::Insight(insight, degraded_at) => {
+                storage.save_insight(&insight)?;
+                match degraded_at {
+                    Some(at) => storage.mark_insight_degraded(insight.id, at)?,
+                    None => {
+                        storage.clear_insight_degraded(insight.id)?;
+                    }
+                }
+            }
+            ExportRecord::Bookmark(agent_id, experience_id) => {
+                storage.add_bookmark(&agent_id, experience_id)?;
+            }
+            ExportRecord::Tombstone(entity_type, id) => match entity_type {
+                EntityTypeTag::Experience => {
+                    let id = ExperienceId::from_bytes(id);
+                    storage.delete_relations_for_experience(id)?;
+                    storage.delete_experience(id)?;
+                }
+                EntityTypeTag::Relation => {
+                    storage.delete_relation(RelationId::from_bytes(id))?;
+                }
+                EntityTypeTag::Insight => {
+                    storage.delete_insight(InsightId::from_bytes(id))?;
+                }
+                // Collective deletions are carried by the snapshot
+                EntityTypeTag::Collective => {}
+            },
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collective::Collective;
+    use tempfile::tempdir;
+
+    fn empty_manifest() -> ExportManifest {
+        ExportManifest {
+            format_version: 0,
+            kind: ExportKind::Full,
+            source_created_at: Timestamp::from_millis(1_000),
+            base_sequence: 0,
+            sequence: 0,
+            schema_version: 2,
+            created_at: Timestamp::now(),
+            embedding_dimension: 384,
+            embedding_model: None,
+            collective_count: 0,
+            experience_count: 0,
+            relation_count: 0,
+            insight_count: 0,
+            bookmark_count: 0,
+            tombstone_count: 0,
+        }
+    }
+
+    /// Reads every record of an export file.
+    fn read_all(path: &Path) -> Result<(ExportManifest, Vec<ExportRecord>), PulseDBError> {
+        let mut source = open_export(path)?;
+        let mut records = Vec::new();
+        while let Some(record) = source.next_record()? {
+            records.push(record);
+        }
+        Ok((source.verify()?, records))
+    }
+
+    fn collective_of(record: &ExportRecord) -> &Collective {
+        match record {
+            ExportRecord::Collective(collective, _) => collective,
+            other => panic!("expected a collective, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_write_then_read_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("out.pulse");
+        let parent = Collective::new("c", 384);
+        let mut described = Collective::new("d", 384);
+        described.description = Some("described".to_string());
+        described
+            .settings
+            .insert("team".to_string(), "platform".to_string());
+
+        let written = write_export(&path, empty_manifest(), |writer| {
+            writer.collective(&parent, None)?;
+            writer.collective(&described, Some(parent.id))
+        })
+        .unwrap();
+        assert_eq!(written.format_version, EXPORT_FORMAT_VERSION);
+        assert_eq!(written.collective_count, 2);
+
+        // The header alone carries no counts
+        assert_eq!(read_export_manifest(&path).unwrap().collective_count, 0);
+
+        let (read, records) = read_all(&path).unwrap();
+        assert_eq!(read, written);
+        assert_eq!(collective_of(&records[0]).name, "c");
+        assert!(collective_of(&records[0]).description.is_none());
+        assert_eq!(
+            collective_of(&records[1]).description.as_deref(),
+            Some("described")
+        );
+        assert_eq!(collective_of(&records[1]).settings["team"], "platform");
+        assert!(matches!(&records[1], ExportRecord::Collective(_, Some(id)) if *id == parent.id));
+        assert!(!dir.path().join("out.pulse.partial").exists());
+    }
+
+    #[test]
+    fn test_failed_write_leaves_nothing_behind() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("out.pulse");
+        let err = write_export(&path, empty_manifest(), |_| {
+            Err(PulseDBError::internal("interrupted"))
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("interrupted"));
+        assert!(!path.exists());
+        assert!(!dir.path().join("out.pulse.partial").exists());
+    }
+
+    fn delta(base_sequence: u64, sequence: u64) -> ExportManifest {
+        ExportManifest {
+            kind: ExportKind::Incremental,
+            base_sequence,
+            sequence,
+            ..empty_manifest()
+        }
+    }
+
+    #[test]
+    fn test_validate_chain_accepts_contiguous_and_overlapping_deltas() {
+        let base = ExportManifest {
+            sequence: 10,
+            ..empty_manifest()
+        };
+        assert!(validate_chain(std::slice::from_ref(&base)).is_ok());
+        assert!(validate_chain(&[base.clone(), delta(10, 20), delta(15, 30)]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_chain_rejects_bad_chains() {
+        let base = ExportManifest {
+            sequence: 10,
+            ..empty_manifest()
+        };
+        assert!(validate_chain(&[]).is_err());
+        // Must start with a full export
+        assert!(validate_chain(&[delta(0, 10)]).is_err());
+        // Gap between base and delta
+        let err = validate_chain(&[base.clone(), delta(11, 20)]).unwrap_err();
+        assert!(err.to_string().contains("chain only reaches 10"));
+        // Second full export in the middle
+        assert!(validate_chain(&[base.clone(), base.clone()]).is_err());
+        // Delta from another database
+        let foreign = ExportManifest {
+            source_created_at: Timestamp::from_millis(2_000),
+            ..delta(10, 20)
+        };
+        assert!(validate_chain(&[base, foreign]).is_err());
+    }
+
+    #[test]
+    fn test_select_chain_stops_at_upto() {
+        let at = |millis| Timestamp::from_millis(millis);
+        let candidates = vec![
+            (
+                "old-full",
+                ExportManifest {
+                    sequence: 5,
+                    created_at: at(100),
+                    ..empty_manifest()
+                },
+            ),
+            (
+                "full",
+                ExportManifest {
+                    sequence: 10,
+                    created_at: at(200),
+                    ..empty_manifest()
+                },
+            ),
+            (
+                "d1",
+                ExportManifest {
+                    created_at: at(300),
+                    ..delta(10, 20)
+                },
+            ),
+            (
+                "d2",
+                ExportManifest {
+                    created_at: at(400),
+                    ..delta(20, 30)
+                },
+            ),
+            (
+                "stale",
+                ExportManifest {
+                    created_at: at(150),
+                    ..delta(5, 8)
+                },
+            ),
+        ];
+
+        let chain = select_chain(&candidates, at(350)).unwrap();
+        assert_eq!(chain, vec![&"full", &"d1"]);
+        let chain = select_chain(&candidates, at(450)).unwrap();
+        assert_eq!(chain, vec![&"full", &"d1", &"d2"]);
+        // The older full export is extended by the delta taken after it
+        let chain = select_chain(&candidates, at(199)).unwrap();
+        assert_eq!(chain, vec![&"old-full", &"stale"]);
+        assert!(select_chain(&candidates, at(50)).is_err());
+    }
+
+    #[test]
+    fn test_select_chain_rejects_gap() {
+        let at = |millis| Timestamp::from_millis(millis);
+        let candidates = vec![
+            (
+                "full",
+                ExportManifest {
+                    sequence: 10,
+                    created_at: at(200),
+                    ..empty_manifest()
+                },
+            ),
+            (
+                "d2",
+                ExportManifest {
+                    created_at: at(400),
+                    ..delta(20, 30)
+                },
+            ),
+        ];
+        assert!(select_chain(&candidates, at(450)).is_err());
+    }
+
+    #[test]
+    fn test_wrong_magic_rejected() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("bogus");
+        std::fs::write(&path, b"NOTANEXPORTFILE!").unwrap();
+
+        let err = verify_export(&path).unwrap_err();
+        assert!(err.to_string().contains("not a PulseDB export"));
+    }
+}
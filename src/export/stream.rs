@@ -0,0 +1,423 @@
+//! Streaming export layout.
+//!
+//! # Layout
+//!
+//! ```text
+//! [magic "PULSEEXP": 8B][format_version: u32 LE][manifest_len: u32 LE]
+//! [manifest: JSON][zstd stream: frame... end frame]
+//! ```
+//!
+//! The manifest describes the export but carries no counts, since it is
+//! written before the first record. Every record follows in its own
+//! checksummed [frame](super::frame), all in one zstd stream, and the end
+//! frame holds the record counts, which catch a truncated or spliced
+//! stream. Neither side ever holds more than one record.
+//!
+//! Frames come in tag order: collectives (with their parent), then
+//! experiences, relations, insights (with their degraded flag),
+//! bookmarks, and tombstones.
+
+use std::io::{BufReader, Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use super::frame::{decode, read_frame, truncated, write_frame};
+use super::{ExportManifest, ExportedExperience, EXPORT_FORMAT_VERSION, EXPORT_MAGIC};
+use crate::collective::{Collective, CollectiveDetails};
+use crate::error::{PulseDBError, StorageError};
+use crate::insight::DerivedInsight;
+use crate::relation::ExperienceRelation;
+use crate::storage::schema::EntityTypeTag;
+use crate::types::{CollectiveId, ExperienceId, Timestamp};
+
+const TAG_END: u8 = 0;
+const TAG_COLLECTIVE: u8 = 1;
+const TAG_EXPERIENCE: u8 = 2;
+const TAG_RELATION: u8 = 3;
+const TAG_INSIGHT: u8 = 4;
+const TAG_BOOKMARK: u8 = 5;
+const TAG_TOMBSTONE: u8 = 6;
+
+/// A collective in its storage layout, the details that layout leaves
+/// out, and its parent.
+type ExportedCollective = (Collective, Option<CollectiveDetails>, Option<CollectiveId>);
+
+/// One record of an export.
+#[derive(Debug)]
+pub(crate) enum ExportRecord {
+    /// A collective and its parent.
+    Collective(Box<Collective>, Option<CollectiveId>),
+    Experience(Box<ExportedExperience>),
+    Relation(ExperienceRelation),
+    /// An insight and when it was marked degraded, if it is.
+    Insight(Box<DerivedInsight>, Option<Timestamp>),
+    /// An agent and the experience it bookmarked.
+    Bookmark(String, ExperienceId),
+    /// A record deleted since the base sequence (incremental exports only).
+    Tombstone(EntityTypeTag, [u8; 16]),
+}
+
+/// Record counts carried by the end frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct ExportCounts {
+    collectives: u64,
+    experiences: u64,
+    relations: u64,
+    insights: u64,
+    bookmarks: u64,
+    tombstones: u64,
+}
+
+impl ExportCounts {
+    fn count(&mut self, tag: u8) {
+        match tag {
+            TAG_COLLECTIVE => self.collectives += 1,
+            TAG_EXPERIENCE => self.experiences += 1,
+            TAG_RELATION => self.relations += 1,
+            TAG_INSIGHT => self.insights += 1,
+            TAG_BOOKMARK => self.bookmarks += 1,
+            TAG_TOMBSTONE => self.tombstones += 1,
+            _ => {}
+        }
+    }
+
+    fn fill(&self, manifest: &mut ExportManifest) {
+        manifest.collective_count = self.collectives;
+        manifest.experience_count = self.experiences;
+        manifest.relation_count = self.relations;
+        manifest.insight_count = self.insights;
+        manifest.bookmark_count = self.bookmarks;
+        manifest.tombstone_count = self.tombstones;
+    }
+}
+
+/// Writes an export record by record.
+///
+/// Records must be written in tag order; see the [module docs](self).
+pub(crate) struct ExportWriter<W: Write> {
+    encoder: zstd::stream::write::Encoder<'static, W>,
+    manifest: ExportManifest,
+    counts: ExportCounts,
+    last_tag: u8,
+}
+
+impl<W: Write> ExportWriter<W> {
+    /// Writes the signature and manifest.
+    pub(crate) fn new(mut writer: W, mut manifest: ExportManifest) -> Result<Self, PulseDBError> {
+        manifest.format_version = EXPORT_FORMAT_VERSION;
+        ExportCounts::default().fill(&mut manifest);
+
+        let manifest_bytes = serde_json::to_vec(&manifest)
+            .map_err(|e| StorageError::serialization(e.to_string()))?;
+        writer.write_all(EXPORT_MAGIC)?;
+        writer.write_all(&EXPORT_FORMAT_VERSION.to_le_bytes())?;
+        writer.write_all(&(manifest_bytes.len() as u32).to_le_bytes())?;
+        writer.write_all(&manifest_bytes)?;
+
+        Ok(Self {
+            encoder: zstd::stream::write::Encoder::new(writer, zstd::DEFAULT_COMPRESSION_LEVEL)?,
+            manifest,
+            counts: ExportCounts::default(),
+            last_tag: TAG_COLLECTIVE,
+        })
+    }
+
+    /// Writes a collective and its parent.
+    pub(crate) fn collective(
+        &mut self,
+        collective: &Collective,
+        parent: Option<CollectiveId>,
+    ) -> Result<(), PulseDBError> {
+        let details = CollectiveDetails::of(collective);
+        self.frame(TAG_COLLECTIVE, &(collective, details, parent))
+    }
+
+    /// Writes an experience.
+    pub(crate) fn experience(&mut self, record: &ExportedExperience) -> Result<(), PulseDBError> {
+        self.frame(TAG_EXPERIENCE, record)
+    }
+
+    /// Writes a relation.
+    pub(crate) fn relation(&mut self, relation: &ExperienceRelation) -> Result<(), PulseDBError> {
+        self.frame(TAG_RELATION, relation)
+    }
+
+    /// Writes an insight and when it was marked degraded.
+    pub(crate) fn insight(
+        &mut self,
+        insight: &DerivedInsight,
+        degraded_at: Option<Timestamp>,
+    ) -> Result<(), PulseDBError> {
+        self.frame(TAG_INSIGHT, &(insight, degraded_at))
+    }
+
+    /// Writes an agent's bookmark of an experience.
+    pub(crate) fn bookmark(
+        &mut self,
+        agent_id: &str,
+        experience_id: ExperienceId,
+    ) -> Result<(), PulseDBError> {
+        self.frame(TAG_BOOKMARK, &(agent_id, experience_id))
+    }
+
+    /// Writes a tombstone for a deleted record.
+    pub(crate) fn tombstone(
+        &mut self,
+        entity_type: EntityTypeTag,
+        id: [u8; 16],
+    ) -> Result<(), PulseDBError> {
+        self.frame(TAG_TOMBSTONE, &(entity_type, id))
+    }
+
+    /// Writes the end frame and finishes the stream, returning the writer
+    /// and the manifest with its counts filled in.
+    pub(crate) fn finish(mut self) -> Result<(W, ExportManifest), PulseDBError> {
+        write_frame(&mut self.encoder, TAG_END, &self.counts)?;
+        let writer = self.encoder.finish()?;
+        self.counts.fill(&mut self.manifest);
+        Ok((writer, self.manifest))
+    }
+
+    fn frame<T: Serialize>(&mut self, tag: u8, record: &T) -> Result<(), PulseDBError> {
+        if tag < self.last_tag {
+            return Err(PulseDBError::internal(
+                "export records written out of order",
+            ));
+        }
+        write_frame(&mut self.encoder, tag, record)?;
+        self.last_tag = tag;
+        self.counts.count(tag);
+        Ok(())
+    }
+}
+
+/// Reads an export record by record, after its manifest.
+pub(crate) struct ExportReader<R: Read> {
+    decoder: zstd::stream::read::Decoder<'static, BufReader<R>>,
+    manifest: ExportManifest,
+    counts: ExportCounts,
+    last_tag: u8,
+    finished: bool,
+}
+
+impl<R: Read> ExportReader<R> {
+    /// Starts reading the records that follow `manifest` in `reader`.
+    pub(crate) fn new(reader: R, manifest: ExportManifest) -> Result<Self, PulseDBError> {
+        Ok(Self {
+            decoder: zstd::stream::read::Decoder::new(reader)?.single_frame(),
+            manifest,
+            counts: ExportCounts::default(),
+            last_tag: TAG_COLLECTIVE,
+            finished: false,
+        })
+    }
+
+    /// Returns the manifest; its counts are filled in once the end frame
+    /// has been read.
+    pub(crate) fn manifest(&self) -> &ExportManifest {
+        &self.manifest
+    }
+
+    /// Reads the next record, or `None` after the end frame.
+    ///
+    /// Records must come in tag order, and the end frame's counts must
+    /// match what was read.
+    pub(crate) fn next_record(&mut self) -> Result<Option<ExportRecord>, PulseDBError> {
+        if self.finished {
+            return Ok(None);
+        }
+        let (tag, payload) = read_frame(&mut self.decoder)?;
+        if tag != TAG_END && tag < self.last_tag {
+            return Err(StorageError::corrupted("export records are out of order").into());
+        }
+
+        let record = match tag {
+            TAG_COLLECTIVE => {
+                let (mut collective, details, parent): ExportedCollective = decode(&payload)?;
+                if let Some(details) = details {
+                    details.apply_to(&mut collective);
+                }
+                ExportRecord::Collective(Box::new(collective), parent)
+            }
+            TAG_EXPERIENCE => ExportRecord::Experience(Box::new(decode(&payload)?)),
+            TAG_RELATION => ExportRecord::Relation(decode(&payload)?),
+            TAG_INSIGHT => {
+                let (insight, degraded_at) = decode(&payload)?;
+                ExportRecord::Insight(Box::new(insight), degraded_at)
+            }
+            TAG_BOOKMARK => {
+                let (agent_id, experience_id) = decode(&payload)?;
+                ExportRecord::Bookmark(agent_id, experience_id)
+            }
+            TAG_TOMBSTONE => {
+                let (entity_type, id) = decode(&payload)?;
+                ExportRecord::Tombstone(entity_type, id)
+            }
+            TAG_END => {
+                let expected: ExportCounts = decode(&payload)?;
+                if expected != self.counts {
+                    return Err(StorageError::corrupted(format!(
+                        "export holds {:?}, end frame says {:?}",
+                        self.counts, expected
+                    ))
+                    .into());
+                }
+                self.counts.fill(&mut self.manifest);
+                self.finished = true;
+                return Ok(None);
+            }
+            other => {
+                return Err(
+                    StorageError::corrupted(format!("unknown export frame tag {}", other)).into(),
+                )
+            }
+        };
+        self.last_tag = tag;
+        self.counts.count(tag);
+        Ok(Some(record))
+    }
+
+    /// Reads the collective records at the start of the export, with
+    /// their parents, and returns the first record after them.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn read_collectives(
+        &mut self,
+    ) -> Result<
+        (
+            Vec<(Collective, Option<CollectiveId>)>,
+            Option<ExportRecord>,
+        ),
+        PulseDBError,
+    > {
+        let mut collectives = Vec::new();
+        loop {
+            match self.next_record()? {
+                Some(ExportRecord::Collective(collective, parent)) => {
+                    collectives.push((*collective, parent))
+                }
+                other => return Ok((collectives, other)),
+            }
+        }
+    }
+
+    /// Reads the rest of the export, verifying every record, and returns
+    /// the manifest with its counts.
+    pub(crate) fn verify(mut self) -> Result<ExportManifest, PulseDBError> {
+        while self.next_record()?.is_some() {}
+        let manifest = self.manifest.clone();
+        self.finish()?;
+        Ok(manifest)
+    }
+
+    /// Checks that nothing follows the end frame.
+    pub(crate) fn finish(mut self) -> Result<(), PulseDBError> {
+        let mut trailing = [0u8; 1];
+        // Reading past the end frame consumes the rest of the zstd frame,
+        // so anything left in the file afterwards really is trailing data
+        if self.decoder.read(&mut trailing).map_err(truncated)? != 0 {
+            return Err(StorageError::corrupted("unexpected data after export end frame").into());
+        }
+        let mut rest = self.decoder.finish();
+        if rest.read(&mut trailing)? != 0 {
+            return Err(StorageError::corrupted("unexpected data after export end frame").into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::read_manifest;
+    use crate::export::types::ExportKind;
+
+    fn manifest() -> ExportManifest {
+        ExportManifest {
+            format_version: 0,
+            kind: ExportKind::Full,
+            source_created_at: Timestamp::from_millis(1_000),
+            base_sequence: 0,
+            sequence: 0,
+            schema_version: 2,
+            created_at: Timestamp::now(),
+            embedding_dimension: 4,
+            embedding_model: None,
+            collective_count: 0,
+            experience_count: 0,
+            relation_count: 0,
+            insight_count: 0,
+            bookmark_count: 0,
+            tombstone_count: 0,
+        }
+    }
+
+    fn export() -> Vec<u8> {
+        let mut described = Collective::new("c", 4);
+        described.description = Some("described".to_string());
+        let mut writer = ExportWriter::new(Vec::new(), manifest()).unwrap();
+        writer.collective(&described, None).unwrap();
+        writer.bookmark("agent", ExperienceId::new()).unwrap();
+        writer
+            .tombstone(EntityTypeTag::Experience, [7; 16])
+            .unwrap();
+        let (bytes, written) = writer.finish().unwrap();
+        assert_eq!(written.collective_count, 1);
+        assert_eq!(written.bookmark_count, 1);
+        assert_eq!(written.tombstone_count, 1);
+        bytes
+    }
+
+    fn read_all(bytes: &[u8]) -> Result<Vec<ExportRecord>, PulseDBError> {
+        let mut input = bytes;
+        let manifest = read_manifest(&mut input)?;
+        let mut reader = ExportReader::new(input, manifest)?;
+        let mut records = Vec::new();
+        while let Some(record) = reader.next_record()? {
+            records.push(record);
+        }
+        reader.finish()?;
+        Ok(records)
+    }
+
+    #[test]
+    fn test_stream_roundtrip() {
+        let records = read_all(&export()).unwrap();
+        assert_eq!(records.len(), 3);
+        match &records[0] {
+            ExportRecord::Collective(collective, None) => {
+                assert_eq!(collective.description.as_deref(), Some("described"))
+            }
+            other => panic!("expected a collective, got {:?}", other),
+        }
+        assert!(matches!(&records[1], ExportRecord::Bookmark(agent, _) if agent == "agent"));
+        assert!(matches!(
+            records[2],
+            ExportRecord::Tombstone(EntityTypeTag::Experience, [7, ..])
+        ));
+    }
+
+    #[test]
+    fn test_truncated_and_corrupt_streams_are_rejected() {
+        let bytes = export();
+        assert!(read_all(&bytes[..bytes.len() - 3]).is_err());
+
+        let mut flipped = bytes.clone();
+        let last = flipped.len() - 5;
+        flipped[last] ^= 0xFF;
+        assert!(read_all(&flipped).unwrap_err().is_storage());
+
+        let mut trailing = bytes;
+        trailing.push(0);
+        let err = read_all(&trailing).unwrap_err();
+        assert!(err.to_string().contains("after export end frame"));
+    }
+
+    #[test]
+    fn test_out_of_order_writes_are_refused() {
+        let mut writer = ExportWriter::new(Vec::new(), manifest()).unwrap();
+        writer.bookmark("agent", ExperienceId::new()).unwrap();
+        assert!(writer
+            .collective(&Collective::new("late", 4), None)
+            .is_err());
+    }
+}
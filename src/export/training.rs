@@ -0,0 +1,261 @@
+//! Fine-tuning dataset export.
+//!
+//! [`PulseDB::export_training_data()`](crate::PulseDB::export_training_data)
+//! turns a collective's experiences and insights into instruction-tuning
+//! records, one JSON object per line. Each record is an instruction, an
+//! optional input, and the answer the model should learn; a
+//! [`TrainingTemplate`] then lays the triple out in the shape the
+//! training framework expects.
+
+use std::io::Write;
+
+use serde::Serialize;
+
+use crate::error::{PulseDBError, Result};
+use crate::experience::{Experience, ExperienceType};
+use crate::insight::DerivedInsight;
+
+/// Output format of a training export.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TrainingFormat {
+    /// JSON Lines: one record per line, laid out by `template`.
+    Jsonl {
+        /// Record layout.
+        template: TrainingTemplate,
+    },
+}
+
+/// Layout of each exported training record.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TrainingTemplate {
+    /// `{"instruction", "input", "output"}` (Alpaca style).
+    #[default]
+    Alpaca,
+
+    /// `{"messages": [...]}` with `system`, `user`, and `assistant` roles
+    /// (OpenAI-style chat fine-tuning). The system message is omitted when
+    /// `system` is `None`.
+    Chat {
+        /// System prompt prepended to every conversation.
+        system: Option<String>,
+    },
+
+    /// `{"prompt", "completion"}`, the prompt being the instruction and
+    /// input separated by a blank line.
+    PromptCompletion,
+}
+
+/// Outcome of a training export.
+///
+/// Returned by
+/// [`PulseDB::export_training_data()`](crate::PulseDB::export_training_data).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TrainingExportReport {
+    /// Records written from experiences.
+    pub experiences: usize,
+    /// Records written from insights.
+    pub insights: usize,
+}
+
+/// An instruction-tuning example before template layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TrainingExample {
+    pub instruction: String,
+    pub input: String,
+    pub output: String,
+}
+
+impl TrainingExample {
+    /// Builds the example for an experience.
+    pub(crate) fn from_experience(experience: &Experience) -> Self {
+        let kind = &experience.experience_type;
+        match kind.lesson() {
+            Some(lesson) => Self {
+                instruction: experience_question(kind).to_string(),
+                input: experience.content.clone(),
+                output: lesson,
+            },
+            None => Self {
+                instruction: format!("What was learned about {}?", topic(&experience.domain)),
+                input: String::new(),
+                output: experience.content.clone(),
+            },
+        }
+    }
+
+    /// Builds the example for an insight.
+    pub(crate) fn from_insight(insight: &DerivedInsight) -> Self {
+        Self {
+            instruction: format!("What has been learned about {}?", topic(&insight.domain)),
+            input: String::new(),
+            output: insight.content.clone(),
+        }
+    }
+
+    /// Writes the example as one JSON line in the given format.
+    pub(crate) fn write(&self, format: &TrainingFormat, writer: &mut impl Write) -> Result<()> {
+        let TrainingFormat::Jsonl { template } = format;
+        let line = match template {
+            TrainingTemplate::Alpaca => serde_json::to_string(&AlpacaRecord {
+                instruction: &self.instruction,
+                input: &self.input,
+                output: &self.output,
+            }),
+            TrainingTemplate::Chat { system } => {
+                let prompt = self.prompt();
+                let mut messages = Vec::with_capacity(3);
+                if let Some(system) = system {
+                    messages.push(ChatMessage {
+                        role: "system",
+                        content: system,
+                    });
+                }
+                messages.push(ChatMessage {
+                    role: "user",
+                    content: &prompt,
+                });
+                messages.push(ChatMessage {
+                    role: "assistant",
+                    content: &self.output,
+                });
+                serde_json::to_string(&ChatRecord { messages })
+            }
+            TrainingTemplate::PromptCompletion => serde_json::to_string(&PromptRecord {
+                prompt: &self.prompt(),
+                completion: &self.output,
+            }),
+        }
+        .map_err(|e| PulseDBError::internal(format!("training record encoding failed: {}", e)))?;
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    /// The instruction and input as a single prompt.
+    fn prompt(&self) -> String {
+        if self.input.is_empty() {
+            self.instruction.clone()
+        } else {
+            format!("{}\n\n{}", self.instruction, self.input)
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AlpacaRecord<'a> {
+    instruction: &'a str,
+    input: &'a str,
+    output: &'a str,
+}
+
+#[derive(Serialize)]
+struct ChatRecord<'a> {
+    messages: Vec<ChatMessage<'a>>,
+}
+
+#[derive(Serialize)]
+struct ChatMessage<'a> {
+    role: &'static str,
+    content: &'a str,
+}
+
+#[derive(Serialize)]
+struct PromptRecord<'a> {
+    prompt: &'a str,
+    completion: &'a str,
+}
+
+/// The question an experience of this type answers.
+fn experience_question(kind: &ExperienceType) -> &'static str {
+    match kind {
+        ExperienceType::Difficulty { .. } => "What should I watch out for here?",
+        ExperienceType::Solution { .. } => "Did this approach solve the problem?",
+        ExperienceType::ErrorPattern { .. } => "How do I fix and prevent this error?",
+        ExperienceType::SuccessPattern { .. } => "What approach works for this kind of task?",
+        ExperienceType::UserPreference { .. } => "What does the user prefer here?",
+        ExperienceType::ArchitecturalDecision { .. } => "What was decided here, and why?",
+        ExperienceType::TechInsight { .. } => "What is worth knowing about this technology?",
+        ExperienceType::Fact { .. } => "What is the relevant fact here?",
+        ExperienceType::Generic { .. } => "What was learned here?",
+        ExperienceType::OpenQuestion { .. } => "What is still unknown here?",
+    }
+}
+
+/// Names a record's subject from its domain tags.
+fn topic(domain: &[String]) -> String {
+    if domain.is_empty() {
+        "this project".to_string()
+    } else {
+        domain.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> TrainingExample {
+        TrainingExample {
+            instruction: "How do I fix and prevent this error?".to_string(),
+            input: "E0502 in the loop".to_string(),
+            output: "Clone first".to_string(),
+        }
+    }
+
+    fn render(template: TrainingTemplate) -> serde_json::Value {
+        let mut out = Vec::new();
+        example()
+            .write(&TrainingFormat::Jsonl { template }, &mut out)
+            .unwrap();
+        assert_eq!(out.iter().filter(|&&b| b == b'\n').count(), 1);
+        serde_json::from_slice(&out).unwrap()
+    }
+
+    #[test]
+    fn test_alpaca_layout() {
+        let record = render(TrainingTemplate::Alpaca);
+        assert_eq!(
+            record["instruction"],
+            "How do I fix and prevent this error?"
+        );
+        assert_eq!(record["input"], "E0502 in the loop");
+        assert_eq!(record["output"], "Clone first");
+    }
+
+    #[test]
+    fn test_chat_layout_with_and_without_system() {
+        let record = render(TrainingTemplate::Chat {
+            system: Some("You are a Rust expert.".to_string()),
+        });
+        let messages = record["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0]["role"], "system");
+        assert_eq!(
+            messages[1]["content"],
+            "How do I fix and prevent this error?\n\nE0502 in the loop"
+        );
+        assert_eq!(messages[2]["role"], "assistant");
+
+        let record = render(TrainingTemplate::Chat { system: None });
+        assert_eq!(record["messages"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_prompt_completion_without_input() {
+        let mut out = Vec::new();
+        TrainingExample {
+            input: String::new(),
+            ..example()
+        }
+        .write(
+            &TrainingFormat::Jsonl {
+                template: TrainingTemplate::PromptCompletion,
+            },
+            &mut out,
+        )
+        .unwrap();
+        let record: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(record["prompt"], "How do I fix and prevent this error?");
+        assert_eq!(record["completion"], "Clone first");
+    }
+}
@@ -0,0 +1,176 @@
+//! Data types for the export format.
+//!
+//! An export file starts with a plain JSON [`ExportManifest`] describing
+//! its contents, followed by its zstd-compressed records. The manifest can
+//! be read and checked without decompressing anything.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{CollectiveId, Timestamp};
+
+/// Describes the contents of an export file.
+///
+/// Returned by [`PulseDB::export()`](crate::PulseDB::export) and
+/// [`PulseDB::verify_export()`](crate::PulseDB::verify_export).
+///
+/// An export holds collectives and their hierarchy, experiences with their
+/// embeddings, relations, insights with their degraded flags, and
+/// bookmarks. Leases and agent activities (with their capabilities) are
+/// short-lived coordination state and are not exported; neither are
+/// vector indexes, which are rebuilt from the records.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportManifest {
+    /// Version of the export file layout.
+    pub format_version: u32,
+
+    /// Whether this file is a full export or an incremental delta.
+    pub kind: ExportKind,
+
+    /// Creation time of the source database, identifying which database
+    /// a backup chain belongs to.
+    pub source_created_at: Timestamp,
+
+    /// Changelog sequence the delta starts after (0 for full exports).
+    pub base_sequence: u64,
+
+    /// Changelog sequence captured when the export began.
+    ///
+    /// Pass this as `since_cursor` to the next
+    /// [`backup_incremental()`](crate::PulseDB::backup_incremental).
+    pub sequence: u64,
+
+    /// Storage schema version of the database that wrote the export.
+    pub schema_version: u32,
+
+    /// When the export was written.
+    pub created_at: Timestamp,
+
+    /// Embedding dimension of every vector in the export.
+    pub embedding_dimension: usize,
+
+    /// Embedding model that produced the vectors, if known.
+    ///
+    /// `None` when embeddings were supplied externally.
+    pub embedding_model: Option<String>,
+
+    /// Number of collectives exported.
+    pub collective_count: u64,
+
+    /// Number of experiences exported.
+    pub experience_count: u64,
+
+    /// Number of relations exported.
+    pub relation_count: u64,
+
+    /// Number of insights exported.
+    pub insight_count: u64,
+
+    /// Number of bookmarks exported.
+    pub bookmark_count: u64,
+
+    /// Number of deleted records listed (always 0 for full exports).
+    pub tombstone_count: u64,
+}
+
+/// What an export file contains.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExportKind {
+    /// Every collective and record in the database.
+    Full,
+
+    /// Records changed since `base_sequence`, plus tombstones for deleted
+    /// ones. Only meaningful on top of a full export of the same database.
+    Incremental,
+
+    /// One collective and its records, without its sub-collectives,
+    /// written by [`export_collective()`](crate::PulseDB::export_collective).
+    Collective,
+}
+
+/// Outcome of importing an export file.
+///
+/// Returned by [`PulseDB::import()`](crate::PulseDB::import). Records whose
+/// ID already exists in the database are skipped, never overwritten.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// Collectives created.
+    pub collectives: usize,
+    /// Experiences written.
+    pub experiences: usize,
+    /// Relations written.
+    pub relations: usize,
+    /// Insights written.
+    pub insights: usize,
+    /// Bookmarks written.
+    pub bookmarks: usize,
+    /// Records skipped because their ID already existed.
+    pub skipped: usize,
+}
+
+/// Outcome of exporting a collective.
+///
+/// Returned by
+/// [`PulseDB::export_collective()`](crate::PulseDB::export_collective).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectiveExportReport {
+    /// Experiences written.
+    pub experiences: u64,
+    /// Relations written.
+    pub relations: u64,
+    /// Insights written.
+    pub insights: u64,
+    /// Bookmarks written.
+    pub bookmarks: u64,
+}
+
+/// Outcome of importing a collective.
+///
+/// Returned by
+/// [`PulseDB::import_collective()`](crate::PulseDB::import_collective).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectiveImportReport {
+    /// The collective created by the import. Differs from the exported ID
+    /// when that ID was already taken.
+    pub collective_id: CollectiveId,
+    /// Experiences written.
+    pub experiences: u64,
+    /// Relations written.
+    pub relations: u64,
+    /// Insights written.
+    pub insights: u64,
+    /// Bookmarks written.
+    pub bookmarks: u64,
+    /// Records (the collective included) given a fresh ID because their
+    /// exported ID already existed in this database.
+    pub remapped: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_json_roundtrip() {
+        let manifest = ExportManifest {
+            format_version: 1,
+            kind: ExportKind::Incremental,
+            source_created_at: Timestamp::from_millis(500),
+            base_sequence: 10,
+            sequence: 20,
+            schema_version: 2,
+            created_at: Timestamp::from_millis(1_000),
+            embedding_dimension: 384,
+            embedding_model: Some("all-MiniLM-L6-v2".to_string()),
+            collective_count: 1,
+            experience_count: 2,
+            relation_count: 0,
+            insight_count: 0,
+            bookmark_count: 0,
+            tombstone_count: 0,
+        };
+
+        let json = serde_json::to_vec(&manifest).unwrap();
+        let restored: ExportManifest = serde_json::from_slice(&json).unwrap();
+        assert_eq!(manifest, restored);
+    }
+}
@@ -0,0 +1,210 @@
+//! Collective write fencing for [`PulseDB::freeze_collective()`](crate::PulseDB::freeze_collective).
+//!
+//! Checking a frozen set before writing is not enough on its own: a write
+//! that passed the check can still commit after the freeze returns. Every
+//! write therefore holds a [`FenceGuard`] from its check until it is done,
+//! and freezing waits for the guards already admitted to the collective to
+//! drop. New writes see the collective frozen and are refused.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+use crate::deadline::Deadline;
+use crate::error::{PulseDBError, Result};
+use crate::types::CollectiveId;
+
+/// Frozen collectives and the writes in flight against them.
+#[derive(Default)]
+pub(crate) struct WriteFence {
+    state: Mutex<FenceState>,
+    /// Signalled whenever a writer leaves.
+    drained: Condvar,
+}
+
+#[derive(Default)]
+struct FenceState {
+    frozen: HashSet<CollectiveId>,
+    /// Writers admitted per collective.
+    writers: HashMap<CollectiveId, usize>,
+    /// Writers admitted while nothing was frozen, without looking up their
+    /// collective. A freeze waits for these too.
+    unscoped: usize,
+}
+
+/// How a [`FenceGuard`] was admitted.
+enum Admission {
+    Collective(CollectiveId),
+    Unscoped,
+    /// Nothing to fence (the target doesn't exist).
+    Nothing,
+}
+
+/// Keeps a write admitted until dropped.
+#[must_use = "the write is only fenced while the guard is held"]
+pub(crate) struct FenceGuard<'a> {
+    fence: &'a WriteFence,
+    admission: Admission,
+}
+
+impl WriteFence {
+    fn lock(&self) -> Result<MutexGuard<'_, FenceState>> {
+        self.state.lock().map_err(poisoned)
+    }
+
+    /// Admits a write to `id`, or fails with [`PulseDBError::Busy`] if the
+    /// collective is frozen.
+    pub(crate) fn enter(&self, id: CollectiveId) -> Result<FenceGuard<'_>> {
+        let mut state = self.lock()?;
+        if state.frozen.contains(&id) {
+            return Err(PulseDBError::busy(format!(
+                "collective {} is frozen for maintenance",
+                id
+            )));
+        }
+        *state.writers.entry(id).or_insert(0) += 1;
+        Ok(FenceGuard {
+            fence: self,
+            admission: Admission::Collective(id),
+        })
+    }
+
+    /// Admits a write without naming its collective, provided no collective
+    /// is frozen. Returns `None` otherwise, so the caller looks the
+    /// collective up and uses [`enter()`](Self::enter).
+    pub(crate) fn enter_unscoped(&self) -> Option<FenceGuard<'_>> {
+        let mut state = self.lock().ok()?;
+        if !state.frozen.is_empty() {
+            return None;
+        }
+        state.unscoped += 1;
+        Some(FenceGuard {
+            fence: self,
+            admission: Admission::Unscoped,
+        })
+    }
+
+    /// A guard that fences nothing, for writes that will fail anyway.
+    pub(crate) fn pass(&self) -> FenceGuard<'_> {
+        FenceGuard {
+            fence: self,
+            admission: Admission::Nothing,
+        }
+    }
+
+    /// Freezes `id` and waits for the writes already admitted to it to
+    /// finish.
+    ///
+    /// If `deadline` passes first, the freeze is undone and
+    /// [`PulseDBError::Timeout`] is returned.
+    pub(crate) fn freeze(&self, id: CollectiveId, deadline: &Deadline) -> Result<()> {
+        let mut state = self.lock()?;
+        if !state.frozen.insert(id) {
+            return Err(PulseDBError::busy(format!(
+                "collective {} is already frozen",
+                id
+            )));
+        }
+        while state.writers.get(&id).copied().unwrap_or(0) > 0 || state.unscoped > 0 {
+            if let Err(e) = deadline.check() {
+                state.frozen.remove(&id);
+                return Err(e);
+            }
+            state = match deadline.remaining() {
+                Some(remaining) => {
+                    self.drained
+                        .wait_timeout(state, remaining)
+                        .map_err(poisoned)?
+                        .0
+                }
+                None => self.drained.wait(state).map_err(poisoned)?,
+            };
+        }
+        Ok(())
+    }
+
+    /// Thaws `id`. Returns whether it was frozen.
+    pub(crate) fn thaw(&self, id: CollectiveId) -> Result<bool> {
+        Ok(self.lock()?.frozen.remove(&id))
+    }
+
+    /// Returns true if `id` is frozen.
+    pub(crate) fn is_frozen(&self, id: CollectiveId) -> bool {
+        self.lock().map(|s| s.frozen.contains(&id)).unwrap_or(false)
+    }
+}
+
+impl Drop for FenceGuard<'_> {
+    fn drop(&mut self) {
+        // A poisoned lock leaves the count high, which can only make a
+        // later freeze wait for its deadline; never under-count
+        let Ok(mut state) = self.fence.state.lock() else {
+            return;
+        };
+        match self.admission {
+            Admission::Collective(id) => {
+                if let Some(count) = state.writers.get_mut(&id) {
+                    *count -= 1;
+                    if *count == 0 {
+                        state.writers.remove(&id);
+                    }
+                }
+            }
+            Admission::Unscoped => state.unscoped -= 1,
+            Admission::Nothing => return,
+        }
+        drop(state);
+        self.fence.drained.notify_all();
+    }
+}
+
+fn poisoned<T>(_: PoisonError<T>) -> PulseDBError {
+    PulseDBError::internal("Write fence lock poisoned")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TimedOperation;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_freeze_waits_for_admitted_writers() {
+        let fence = Arc::new(WriteFence::default());
+        let id = CollectiveId::new();
+        let guard = fence.enter(id).unwrap();
+
+        let freezer = {
+            let fence = Arc::clone(&fence);
+            thread::spawn(move || {
+                fence
+                    .freeze(id, &Deadline::unbounded(TimedOperation::WriteTransaction))
+                    .unwrap()
+            })
+        };
+        thread::sleep(Duration::from_millis(50));
+        assert!(!freezer.is_finished());
+        assert!(matches!(fence.enter(id), Err(e) if e.is_busy()));
+
+        drop(guard);
+        freezer.join().unwrap();
+        assert!(fence.is_frozen(id));
+        assert!(fence.enter_unscoped().is_none());
+        assert!(fence.enter(CollectiveId::new()).is_ok());
+    }
+
+    #[test]
+    fn test_freeze_times_out_and_undoes_itself() {
+        let fence = WriteFence::default();
+        let id = CollectiveId::new();
+        let _guard = fence.enter_unscoped().unwrap();
+
+        let deadline = Deadline::start(
+            TimedOperation::WriteTransaction,
+            Some(Duration::from_millis(20)),
+        );
+        assert!(fence.freeze(id, &deadline).unwrap_err().is_timeout());
+        assert!(!fence.is_frozen(id));
+    }
+}
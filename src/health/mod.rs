@@ -0,0 +1,21 @@
+//! Database health and degraded mode.
+//!
+//! [`PulseDB::open()`](crate::PulseDB::open) loads every collective's
+//! vector indexes. If one collective's index or records are damaged, the
+//! database still opens: that collective is taken out of service and the
+//! rest stay available. [`PulseDB::health()`](crate::PulseDB::health)
+//! reports which collectives are out of service and why.
+//!
+//! Reads and writes against an unavailable collective fail with
+//! [`PulseDBError::Unavailable`](crate::PulseDBError::Unavailable). The
+//! collective can still be deleted, or repaired offline and picked up by
+//! the next open.
+//!
+//! An experience whose vector index insert fails after it was written to
+//! storage is still recorded: it is queued for re-insertion, retried on
+//! the next write or index search, and counted in
+//! [`HealthReport::unindexed_experiences`] until then.
+
+pub mod types;
+
+pub use types::{HealthReport, UnavailableCollective};
@@ -0,0 +1,44 @@
+//! Data types for database health reporting.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::CollectiveId;
+
+/// Health of an open database.
+///
+/// Returned by [`PulseDB::health()`](crate::PulseDB::health).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// Number of collectives in the database, available or not.
+    pub collective_count: usize,
+
+    /// Collectives that failed to load and are out of service.
+    pub unavailable: Vec<UnavailableCollective>,
+
+    /// Experiences recorded in storage but missing from their vector
+    /// index because the index insert failed.
+    ///
+    /// They are found by ID and by non-semantic reads, but not by
+    /// similarity search until re-inserted, which is retried on the next
+    /// write or index search.
+    #[serde(default)]
+    pub unindexed_experiences: usize,
+}
+
+impl HealthReport {
+    /// Returns `true` if any collective is out of service or any
+    /// experience is waiting to be re-inserted into its vector index.
+    pub fn is_degraded(&self) -> bool {
+        !self.unavailable.is_empty() || self.unindexed_experiences > 0
+    }
+}
+
+/// A collective taken out of service because it failed to load.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnavailableCollective {
+    /// The collective.
+    pub collective_id: CollectiveId,
+
+    /// Why loading failed.
+    pub reason: String,
+}
@@ -0,0 +1,114 @@
+//! Write and read hooks.
+//!
+//! # Write hooks
+//!
+//! A [`Hook`] registered with [`PulseDB::add_hook()`](crate::PulseDB::add_hook)
+//! sees every experience, insight, and relation written through the public
+//! API, in two stages:
+//!
+//! - **Pre-write** — runs before built-in validation with mutable access to
+//!   the input. Hooks enrich or tag records, redact content, or reject the
+//!   write by returning an error.
+//! - **Post-write** — runs after the record is committed with read access to
+//!   the stored record. Hooks notify external systems; a failure is logged
+//!   and does not undo the write.
+//!
+//! Hooks run in registration order at each stage, so an enrichment hook
+//! registered before a validation hook is seen by it.
+//!
+//! ```text
+//! record_experience() ──┐                                  ┌── post_write()
+//! store_insight()     ──┼── pre_write() ── validate ── save ┤   (in order)
+//! store_relation()    ──┘   (in order)                     └──
+//! ```
+//!
+//! Records written by sync, import, and restore carry data that already
+//! passed the hooks of the source database and bypass them.
+//!
+//! # Read hooks
+//!
+//! A [`ReadHook`] registered for a collective with
+//! [`PulseDB::add_read_hook()`](crate::PulseDB::add_read_hook) rewrites
+//! experiences and insights of that collective on their way out of get,
+//! list, and search calls — to decrypt, rehydrate external content, or
+//! annotate records with live status. Read hooks run after filtering and
+//! ranking, which always see the stored record.
+
+pub mod types;
+
+pub use types::{CommittedWrite, PendingWrite, ReadRecord};
+
+use crate::error::Result;
+
+/// Write-time policy plugged into [`PulseDB`](crate::PulseDB).
+///
+/// Both stages default to doing nothing, so a hook implements only the
+/// stage it needs.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{Hook, PendingWrite, PulseDBError, Result};
+///
+/// /// Tags every experience with its origin and rejects secrets.
+/// struct Policy;
+///
+/// impl Hook for Policy {
+///     fn pre_write(&self, write: &mut PendingWrite<'_>) -> Result<()> {
+///         if let PendingWrite::Experience(exp) = write {
+///             if exp.content.contains("BEGIN PRIVATE KEY") {
+///                 return Err(PulseDBError::internal("refusing to store a private key"));
+///             }
+///             exp.domain.push("ingest".to_string());
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Hook: Send + Sync {
+    /// Inspects or rewrites a record before it is validated and stored.
+    ///
+    /// An error aborts the write and is returned to the caller; later hooks
+    /// do not run.
+    fn pre_write(&self, write: &mut PendingWrite<'_>) -> Result<()> {
+        let _ = write;
+        Ok(())
+    }
+
+    /// Observes a record after it has been committed.
+    ///
+    /// An error is logged; the write has already succeeded and later hooks
+    /// still run.
+    fn post_write(&self, write: &CommittedWrite<'_>) -> Result<()> {
+        let _ = write;
+        Ok(())
+    }
+}
+
+/// Read-time enrichment plugged into [`PulseDB`](crate::PulseDB) for one
+/// collective.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{ReadHook, ReadRecord, Result};
+///
+/// /// Marks records served from a replica.
+/// struct ReplicaTag;
+///
+/// impl ReadHook for ReplicaTag {
+///     fn on_read(&self, record: &mut ReadRecord<'_>) -> Result<()> {
+///         match record {
+///             ReadRecord::Experience(exp) => exp.domain.push("replica".to_string()),
+///             ReadRecord::Insight(insight) => insight.domain.push("replica".to_string()),
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait ReadHook: Send + Sync {
+    /// Rewrites a record before it is returned to the caller.
+    ///
+    /// Changes are never written back. An error fails the read.
+    fn on_read(&self, record: &mut ReadRecord<'_>) -> Result<()>;
+}
@@ -0,0 +1,45 @@
+//! Records passed to write hooks.
+
+use crate::experience::{Experience, NewExperience};
+use crate::insight::{DerivedInsight, NewDerivedInsight};
+use crate::relation::{ExperienceRelation, NewExperienceRelation};
+
+/// A write about to be validated and stored, as seen by
+/// [`Hook::pre_write`](crate::Hook::pre_write).
+///
+/// Changes made through the reference are what gets validated and stored.
+pub enum PendingWrite<'a> {
+    /// Input to [`PulseDB::record_experience`](crate::PulseDB::record_experience).
+    Experience(&'a mut NewExperience),
+
+    /// Input to [`PulseDB::store_insight`](crate::PulseDB::store_insight).
+    Insight(&'a mut NewDerivedInsight),
+
+    /// Input to [`PulseDB::store_relation`](crate::PulseDB::store_relation).
+    Relation(&'a mut NewExperienceRelation),
+}
+
+/// A committed write, as seen by
+/// [`Hook::post_write`](crate::Hook::post_write).
+#[derive(Clone, Copy, Debug)]
+pub enum CommittedWrite<'a> {
+    /// The stored experience, including its assigned ID.
+    Experience(&'a Experience),
+
+    /// The stored insight, including its assigned ID.
+    Insight(&'a DerivedInsight),
+
+    /// The stored relation, including its assigned ID.
+    Relation(&'a ExperienceRelation),
+}
+
+/// A record being returned from a read, as seen by
+/// [`ReadHook::on_read`](crate::ReadHook::on_read).
+#[derive(Debug)]
+pub enum ReadRecord<'a> {
+    /// An experience from a get, list, or search call.
+    Experience(&'a mut Experience),
+
+    /// An insight from a get, list, or search call.
+    Insight(&'a mut DerivedInsight),
+}
@@ -11,18 +11,24 @@
 //! - [`store_insight(insight)`](crate::PulseDB::store_insight)
 //! - [`get_insight(id)`](crate::PulseDB::get_insight)
 //! - [`get_insights(collective_id, query, k)`](crate::PulseDB::get_insights)
+//! - [`list_insights_filtered(collective_id, filter)`](crate::PulseDB::list_insights_filtered)
 //! - [`delete_insight(id)`](crate::PulseDB::delete_insight)
+//! - [`repair_insight_sources(collective_id)`](crate::PulseDB::repair_insight_sources)
 //!
 //! # Constraints
 //!
 //! - Content must be non-empty and ≤ 50KB
 //! - Confidence must be in `[0.0, 1.0]`
 //! - At least 1 and at most 100 source experience IDs
-//! - All source experiences must belong to the same collective
+//! - All source experiences must exist and belong to the same collective
+//!   (enforced when [`Config::strict_insight_sources`](crate::Config::strict_insight_sources)
+//!   is on, the default)
 
 pub mod types;
 
-pub use types::{DerivedInsight, InsightType, NewDerivedInsight};
+pub use types::{
+    DerivedInsight, InsightFilter, InsightRepairReport, InsightType, NewDerivedInsight,
+};
 
 use crate::error::{PulseDBError, ValidationError};
 use crate::storage::schema::{MAX_INSIGHT_CONTENT_SIZE, MAX_INSIGHT_SOURCES};
@@ -36,7 +42,8 @@ use crate::storage::schema::{MAX_INSIGHT_CONTENT_SIZE, MAX_INSIGHT_SOURCES};
 /// - No more than 100 source experience IDs
 ///
 /// Does NOT check cross-collective or existence constraints — those
-/// require storage lookups and are handled by the PulseDB facade.
+/// require storage lookups and are handled by the PulseDB facade in a
+/// single batched read (see `Config::strict_insight_sources`).
 pub(crate) fn validate_new_insight(insight: &NewDerivedInsight) -> Result<(), PulseDBError> {
     // Content must be non-empty
     if insight.content.is_empty() {
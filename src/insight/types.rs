@@ -33,6 +33,18 @@ pub enum InsightType {
     Correlation,
 }
 
+impl InsightType {
+    /// Returns all variants in declaration order.
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::Pattern,
+            Self::Synthesis,
+            Self::Abstraction,
+            Self::Correlation,
+        ]
+    }
+}
+
 /// A stored derived insight — synthesized knowledge from multiple experiences.
 ///
 /// Unlike experiences (which are raw agent observations), insights are
@@ -68,6 +80,7 @@ pub struct DerivedInsight {
     pub confidence: f32,
 
     /// Domain tags for categorical filtering.
+    #[serde(default)]
     pub domain: Vec<String>,
 
     /// When this insight was created.
@@ -116,6 +129,7 @@ pub struct DerivedInsight {
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Serialize, Deserialize)]
 pub struct NewDerivedInsight {
     /// The collective to store this insight in.
     pub collective_id: CollectiveId,
@@ -124,6 +138,7 @@ pub struct NewDerivedInsight {
     pub content: String,
 
     /// Pre-computed embedding vector (required for External provider).
+    #[serde(default)]
     pub embedding: Option<Vec<f32>>,
 
     /// IDs of the source experiences this insight was derived from (1-100).
@@ -136,9 +151,89 @@ pub struct NewDerivedInsight {
     pub confidence: f32,
 
     /// Domain tags for categorical filtering.
+    #[serde(default)]
     pub domain: Vec<String>,
 }
 
+/// Filter criteria for browsing insights without a query vector.
+///
+/// Used by [`PulseDB::list_insights_filtered()`](crate::PulseDB::list_insights_filtered).
+/// Fields set to `None` are not filtered on.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{InsightFilter, InsightType};
+///
+/// // All Pattern insights with confidence >= 0.8, newest 20
+/// let filter = InsightFilter {
+///     types: Some(vec![InsightType::Pattern]),
+///     min_confidence: Some(0.8),
+///     limit: Some(20),
+///     ..InsightFilter::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct InsightFilter {
+    /// Only include insights of these types.
+    ///
+    /// `None` means all types. An empty `Some(vec![])` matches nothing.
+    pub types: Option<Vec<InsightType>>,
+
+    /// Only include insights with confidence >= this threshold.
+    pub min_confidence: Option<f32>,
+
+    /// Only include insights created at or after this timestamp.
+    pub since: Option<Timestamp>,
+
+    /// Maximum number of insights to return (`None` = no limit).
+    pub limit: Option<usize>,
+}
+
+impl InsightFilter {
+    /// Returns `true` if the given insight passes the type, confidence,
+    /// and timestamp criteria. `limit` is applied by the caller.
+    pub fn matches(&self, insight: &DerivedInsight) -> bool {
+        if let Some(ref types) = self.types {
+            if !types.contains(&insight.insight_type) {
+                return false;
+            }
+        }
+
+        if let Some(min) = self.min_confidence {
+            if insight.confidence < min {
+                return false;
+            }
+        }
+
+        if let Some(since) = self.since {
+            if insight.created_at < since {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Outcome of a source-repair pass over a collective's insights.
+///
+/// Returned by [`PulseDB::repair_insight_sources()`](crate::PulseDB::repair_insight_sources).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InsightRepairReport {
+    /// Number of insights examined.
+    pub insights_scanned: usize,
+    /// Number of insights whose source list was rewritten.
+    pub insights_repaired: usize,
+    /// Total dangling source IDs removed across all insights.
+    pub sources_removed: usize,
+    /// Insights left with no sources at all after repair.
+    ///
+    /// These are kept (the caller decides whether to delete them) but no
+    /// longer have any provenance.
+    pub orphaned: Vec<InsightId>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,6 +284,42 @@ mod tests {
         assert_eq!(insight.domain, restored.domain);
     }
 
+    #[test]
+    fn test_insight_filter_matches() {
+        let insight = DerivedInsight {
+            id: InsightId::new(),
+            collective_id: CollectiveId::new(),
+            content: "Filter me".to_string(),
+            embedding: vec![],
+            source_experience_ids: vec![],
+            insight_type: InsightType::Pattern,
+            confidence: 0.8,
+            domain: vec![],
+            created_at: Timestamp::from_millis(1_000),
+            updated_at: Timestamp::from_millis(1_000),
+        };
+
+        assert!(InsightFilter::default().matches(&insight));
+
+        let by_type = InsightFilter {
+            types: Some(vec![InsightType::Synthesis]),
+            ..Default::default()
+        };
+        assert!(!by_type.matches(&insight));
+
+        let by_confidence = InsightFilter {
+            min_confidence: Some(0.9),
+            ..Default::default()
+        };
+        assert!(!by_confidence.matches(&insight));
+
+        let by_time = InsightFilter {
+            since: Some(Timestamp::from_millis(1_000)),
+            ..Default::default()
+        };
+        assert!(by_time.matches(&insight));
+    }
+
     #[test]
     fn test_insight_type_copy_and_eq() {
         let a = InsightType::Synthesis;
@@ -72,6 +72,30 @@
 //! - `SyncServer` — Server-side handler for Axum consumers (`sync-http`)
 //! - `PulseDB::compact_wal()` — WAL compaction for disk space reclamation
 //!
+//! ## Serialization
+//!
+//! Public domain types ([`Experience`], [`SearchResult`], [`Collective`],
+//! [`DerivedInsight`], [`ExperienceRelation`], [`Activity`], the `New*`
+//! inputs, and the stats and context types) implement `Serialize` and
+//! `Deserialize` with a stable JSON representation, so they can cross
+//! service boundaries:
+//!
+//! - Field names are the Rust field names; enum variants are the Rust
+//!   variant names, externally tagged (`{"Difficulty": {...}}`).
+//! - IDs are UUID strings. [`ExperienceId`]s are also accepted as ULIDs
+//!   on input (see [`ExperienceId::to_ulid()`]).
+//! - [`Timestamp`]s are RFC 3339 strings in UTC with milliseconds
+//!   (`"2023-11-14T22:13:20.000Z"`). Input may use any UTC offset, or
+//!   Unix milliseconds as written by earlier versions.
+//! - Fields may be added in minor releases, never renamed or removed.
+//!   Readers ignore fields they don't know, and optional or collection
+//!   fields may be omitted on input. New enum variants are breaking
+//!   changes and wait for a major release.
+//!
+//! In JSON, an [`Experience`] carries its embedding, user, expiry, and
+//! model attribution, and a [`Collective`] its description and settings.
+//! Binary formats use the storage layout, which leaves those out.
+//!
 //! ## Thread Safety
 //!
 //! `PulseDB` is `Send + Sync` and can be shared across threads using `Arc`.
@@ -85,6 +109,9 @@
 //! | `sync` | Core sync protocol: types, transport trait, in-memory transport, echo prevention guard. |
 //! | `sync-http` | HTTP sync transport via reqwest (implies `sync`). |
 //! | `sync-websocket` | WebSocket sync transport via tokio-tungstenite (implies `sync`). |
+//! | `bench` | Synthetic capacity-planning benchmarks ([`bench`] module) and the `pulsedb bench` CLI. |
+//! | `derive` | `#[derive(ToExperience)]` for mapping application structs to [`NewExperience`]. |
+//! | `otel` | Ingest OpenTelemetry traces (OTLP/JSON) as experiences with `PulseDB::ingest_otel()`. |
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![warn(missing_docs)]
@@ -106,9 +133,24 @@ pub mod storage;
 // Domain modules
 mod activity;
 mod collective;
+mod consolidation;
+mod cursor;
+mod deadline;
+mod erasure;
+mod eval;
 mod experience;
+mod export;
+mod fence;
+mod health;
+mod hook;
 mod insight;
+mod lock;
+mod maintenance;
+mod metrics;
+mod moderation;
+mod pin;
 mod relation;
+mod scope;
 mod search;
 mod watch;
 
@@ -123,6 +165,34 @@ pub mod substrate;
 #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
 pub mod sync;
 
+/// Ingestion of OpenTelemetry traces as experiences.
+///
+/// Requires the `otel` feature flag.
+#[cfg(feature = "otel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "otel")))]
+pub mod otel;
+
+/// Capacity-planning benchmarks on synthetic collectives.
+///
+/// Requires the `bench` feature flag.
+#[cfg(feature = "bench")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bench")))]
+pub mod bench;
+
+/// Realistic synthetic collectives for demos, benchmarks, and bug reports.
+///
+/// Requires the `testdata` feature flag.
+#[cfg(feature = "testdata")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testdata")))]
+pub mod testdata;
+
+/// Multi-agent simulation harness for concurrency testing.
+///
+/// Requires the `test-util` feature flag.
+#[cfg(feature = "test-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+pub mod sim;
+
 /// Vector index module for HNSW-based approximate nearest neighbor search.
 pub mod vector;
 
@@ -135,37 +205,106 @@ pub use db::PulseDB;
 
 // Configuration
 pub use config::{
-    ActivityConfig, Config, EmbeddingDimension, EmbeddingProvider, HnswConfig, SyncMode,
-    WatchConfig,
+    ActivityConfig, Config, ContentStorage, EmbeddingDimension, EmbeddingProvider,
+    EmbeddingStorage, ExecutionProvider, HnswConfig, IdStrategy, InsightSourceCascade, IvfConfig,
+    KnowledgeGapConfig, LogContentPolicy, ModelDownloadConfig, RelationSuggestionConfig, ScoreKind,
+    SyncMode, TimeoutConfig, VectorIndexKind, WatchConfig, WriteRetryConfig,
 };
+pub use embedding::TextNormalization;
 
 // Error handling
-pub use error::{NotFoundError, PulseDBError, Result, StorageError, ValidationError};
+pub use error::{
+    NotFoundError, PulseDBError, Result, StorageError, TimedOperation, ValidationError,
+};
 
 // Core types
+pub use cursor::{Cursor, CursorPage, PageDirection};
 pub use types::{
-    AgentId, CollectiveId, Embedding, ExperienceId, InsightId, RelationId, TaskId, Timestamp,
-    UserId,
+    AgentId, CollectiveId, Embedding, EpisodeId, ExperienceId, InsightId, Page, RelationId, TaskId,
+    Timestamp, UserId, UtcOffset,
 };
 
 // Domain types
-pub use collective::{Collective, CollectiveStats};
-pub use experience::{Experience, ExperienceType, ExperienceUpdate, NewExperience, Severity};
+pub use collective::{
+    Collective, CollectiveQuota, CollectiveStats, CollectiveUpdate, EmbeddingStats, OwnerStats,
+};
+pub use experience::{
+    BatchOutcome, BatchReport, ContentPolicy, ContentRequirement, ContentResolver, ContentRule,
+    Episode, Experience, ExperienceType, ExperienceUpdate, ModelAttribution, NewExperience,
+    RenderStyle, Severity, ToExperience,
+};
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use pulsedb_derive::ToExperience;
 
 // Relations
-pub use relation::{ExperienceRelation, NewExperienceRelation, RelationDirection, RelationType};
+pub use relation::{
+    ExperienceRelation, NewExperienceRelation, RelationDirection, RelationFilter, RelationSort,
+    RelationSuggestion, RelationType,
+};
 
 // Insights
-pub use insight::{DerivedInsight, InsightType, NewDerivedInsight};
+pub use insight::{
+    DerivedInsight, InsightFilter, InsightRepairReport, InsightType, NewDerivedInsight,
+};
 
 // Activities
 pub use activity::{Activity, NewActivity};
 
+// Locks
+pub use lock::Lease;
+
+// Write hooks
+pub use hook::{CommittedWrite, Hook, PendingWrite, ReadHook, ReadRecord};
+
+// Erasure
+pub use erasure::ErasureReport;
+
+// Health
+pub use health::{HealthReport, UnavailableCollective};
+
+// Metrics
+pub use metrics::{DatabaseMetrics, IndexLockStats, LockWaitStats, LOCK_WAIT_BUCKETS};
+
+// Retrieval evaluation
+pub use eval::{EvalQuery, EvalReport, EvalSet, RetrievalConfig};
+
+// Export / Import
+pub use export::{
+    CollectiveExportReport, CollectiveImportReport, ExportKind, ExportManifest, ImportReport,
+    TrainingExportReport, TrainingFormat, TrainingTemplate,
+};
+pub use vector::{
+    HnswStats, IndexRole, IndexSnapshotFile, IndexSnapshotManifest, IndexSpec, VectorBackend,
+    VectorIndex,
+};
+
+// Consolidation
+pub use consolidation::{ConsolidatedCluster, ConsolidationPolicy, ConsolidationReport};
+
+// Maintenance
+pub use maintenance::{DuplicateGroup, MaintenancePlan, MaintenancePolicy, MaintenanceReport};
+
+// Moderation
+pub use moderation::ModerationPolicy;
+
+// Scoped handles
+pub use scope::{Capabilities, ScopedDb};
+
 // Search & Context
-pub use search::{ContextCandidates, ContextRequest, SearchFilter, SearchResult};
+pub use search::{
+    AnswerSupport, ApproxTokenCounter, ChangesetKnowledge, ContextCandidates, ContextCost,
+    ContextItem, ContextRequest, Evidence, ExperienceNeighbor, FileExperience, FileInsight,
+    ItemCost, KnowledgeGap, KnowledgeGapRecord, Query, QueryExplain, QueryResults,
+    RepresentativeQuery, Reranker, SearchFilter, SearchResult, Stance, TokenCounter,
+    REPRESENTATIVE_QUERY_DEPTH, RERANK_OVER_FETCH,
+};
 
 // Watch (real-time notifications + cross-process change detection)
-pub use watch::{ChangePoller, WatchEvent, WatchEventType, WatchFilter, WatchLock, WatchStream};
+pub use watch::{
+    Change, ChangePoller, ChangeSubscription, ChangedEntity, Interest, WatchEvent, WatchEventType,
+    WatchFilter, WatchLock, WatchStream,
+};
 
 // Substrate (async agent framework integration)
 pub use substrate::{PulseDBSubstrate, SubstrateProvider};
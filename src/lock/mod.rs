@@ -0,0 +1,123 @@
+//! Named locks and leases module.
+//!
+//! A **lock** is a named, collective-scoped lease that lets agents
+//! coordinate on shared external resources (a repository, a deployment
+//! target) through the substrate they already share.
+//!
+//! # Operations
+//!
+//! All lock operations are available on [`PulseDB`](crate::PulseDB):
+//!
+//! - [`acquire_lock(collective_id, name, owner, ttl)`](crate::PulseDB::acquire_lock)
+//! - [`release_lock(collective_id, name, fencing_token)`](crate::PulseDB::release_lock)
+//! - [`get_lock(collective_id, name)`](crate::PulseDB::get_lock)
+//!
+//! # Semantics
+//!
+//! - Acquisition is a single atomic redb write: check, grant, and token
+//!   issuance commit together
+//! - Re-acquiring a lock you hold renews it and keeps its fencing token
+//! - Expired leases can be taken over by anyone and get a new token
+//! - Release only succeeds with the current fencing token
+//!
+//! # Constraints
+//!
+//! - Name and owner must be non-empty and ≤ 255 bytes
+//! - TTL must be greater than zero
+
+pub mod types;
+
+pub use types::Lease;
+
+use std::time::Duration;
+
+use crate::error::{PulseDBError, ValidationError};
+use crate::storage::schema::MAX_LOCK_FIELD_LENGTH;
+
+/// Validates lock acquisition arguments.
+///
+/// Does NOT check collective existence or contention — those require
+/// storage access and are handled by the PulseDB facade.
+pub(crate) fn validate_lock_request(
+    name: &str,
+    owner: &str,
+    ttl: Duration,
+) -> Result<(), PulseDBError> {
+    validate_lock_name(name)?;
+
+    if owner.is_empty() {
+        return Err(ValidationError::required_field("owner").into());
+    }
+    if owner.len() > MAX_LOCK_FIELD_LENGTH {
+        return Err(ValidationError::invalid_field(
+            "owner",
+            format!(
+                "must be at most {} bytes, got {}",
+                MAX_LOCK_FIELD_LENGTH,
+                owner.len()
+            ),
+        )
+        .into());
+    }
+
+    if ttl.is_zero() {
+        return Err(ValidationError::invalid_field("ttl", "must be greater than 0").into());
+    }
+
+    Ok(())
+}
+
+/// Validates a lock name.
+pub(crate) fn validate_lock_name(name: &str) -> Result<(), PulseDBError> {
+    if name.is_empty() {
+        return Err(ValidationError::required_field("name").into());
+    }
+    if name.len() > MAX_LOCK_FIELD_LENGTH {
+        return Err(ValidationError::invalid_field(
+            "name",
+            format!(
+                "must be at most {} bytes, got {}",
+                MAX_LOCK_FIELD_LENGTH,
+                name.len()
+            ),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_lock_request_passes() {
+        assert!(validate_lock_request("repo:main", "agent-1", Duration::from_secs(30)).is_ok());
+    }
+
+    #[test]
+    fn test_empty_fields_rejected() {
+        let err = validate_lock_request("", "agent-1", Duration::from_secs(1)).unwrap_err();
+        assert!(err.to_string().contains("name"));
+
+        let err = validate_lock_request("repo", "", Duration::from_secs(1)).unwrap_err();
+        assert!(err.to_string().contains("owner"));
+    }
+
+    #[test]
+    fn test_zero_ttl_rejected() {
+        let err = validate_lock_request("repo", "agent-1", Duration::ZERO).unwrap_err();
+        assert!(err.is_validation());
+        assert!(err.to_string().contains("ttl"));
+    }
+
+    #[test]
+    fn test_fields_too_long_rejected() {
+        let long = "x".repeat(MAX_LOCK_FIELD_LENGTH + 1);
+        assert!(validate_lock_request(&long, "o", Duration::from_secs(1)).is_err());
+        assert!(validate_lock_request("n", &long, Duration::from_secs(1)).is_err());
+
+        let at_limit = "x".repeat(MAX_LOCK_FIELD_LENGTH);
+        assert!(validate_lock_request(&at_limit, &at_limit, Duration::from_secs(1)).is_ok());
+    }
+}
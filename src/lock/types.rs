@@ -0,0 +1,84 @@
+//! Data types for named locks and leases.
+//!
+//! A lease grants exclusive ownership of a named lock within a collective
+//! until it is released or its TTL elapses. Each grant carries a fencing
+//! token so external resources can reject writes from a holder whose
+//! lease has since been taken over.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{CollectiveId, Timestamp};
+
+/// A granted lease on a named lock.
+///
+/// Returned by [`PulseDB::acquire_lock()`](crate::PulseDB::acquire_lock).
+///
+/// # Fencing
+///
+/// `fencing_token` is strictly increasing across every grant in the
+/// database. Pass it along with each operation on the protected resource
+/// and have the resource reject tokens lower than the highest it has seen:
+/// a holder that stalled past its TTL then carries a stale token.
+/// Renewing a lease you already hold keeps its token.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lease {
+    /// The collective the lock is scoped to.
+    pub collective_id: CollectiveId,
+
+    /// The lock name (e.g., "repo:main", "deploy:staging").
+    pub name: String,
+
+    /// The current holder.
+    pub owner: String,
+
+    /// Monotonic token identifying this grant.
+    pub fencing_token: u64,
+
+    /// When the current holder first acquired the lock.
+    pub acquired_at: Timestamp,
+
+    /// When the lease lapses unless renewed.
+    pub expires_at: Timestamp,
+}
+
+impl Lease {
+    /// Returns `true` if the lease has lapsed at `now`.
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        now >= self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lease_bincode_roundtrip() {
+        let lease = Lease {
+            collective_id: CollectiveId::new(),
+            name: "repo:main".to_string(),
+            owner: "agent-1".to_string(),
+            fencing_token: 7,
+            acquired_at: Timestamp::from_millis(1_000),
+            expires_at: Timestamp::from_millis(31_000),
+        };
+
+        let bytes = bincode::serialize(&lease).unwrap();
+        let restored: Lease = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(lease, restored);
+    }
+
+    #[test]
+    fn test_lease_expiry_boundary() {
+        let lease = Lease {
+            collective_id: CollectiveId::new(),
+            name: "n".to_string(),
+            owner: "o".to_string(),
+            fencing_token: 1,
+            acquired_at: Timestamp::from_millis(0),
+            expires_at: Timestamp::from_millis(100),
+        };
+        assert!(!lease.is_expired_at(Timestamp::from_millis(99)));
+        assert!(lease.is_expired_at(Timestamp::from_millis(100)));
+    }
+}
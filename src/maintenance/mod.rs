@@ -0,0 +1,54 @@
+//! Planned, reviewable collective maintenance.
+//!
+//! Destructive maintenance runs in two steps so nothing is removed blindly:
+//!
+//! 1. [`plan_maintenance(collective_id, policy)`](crate::PulseDB::plan_maintenance)
+//!    computes a [`MaintenancePlan`] — what would be archived, expired,
+//!    merged, and repaired, plus an estimate of bytes reclaimed — without
+//!    modifying anything.
+//! 2. [`run_maintenance(&plan)`](crate::PulseDB::run_maintenance) applies
+//!    exactly the items in that plan and returns a [`MaintenanceReport`].
+//!
+//! Callers can inspect, log, filter, or gate a plan on human approval
+//! between the two steps.
+
+pub mod types;
+
+pub use types::{DuplicateGroup, MaintenancePlan, MaintenancePolicy, MaintenanceReport};
+
+use crate::error::{PulseDBError, ValidationError};
+
+/// Validates a maintenance policy.
+pub(crate) fn validate_maintenance_policy(policy: &MaintenancePolicy) -> Result<(), PulseDBError> {
+    if let Some(threshold) = policy.archive_below_importance {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(ValidationError::invalid_field(
+                "archive_below_importance",
+                "must be between 0.0 and 1.0",
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_is_valid() {
+        assert!(validate_maintenance_policy(&MaintenancePolicy::default()).is_ok());
+    }
+
+    #[test]
+    fn test_importance_threshold_out_of_range_rejected() {
+        let policy = MaintenancePolicy {
+            archive_below_importance: Some(1.5),
+            ..MaintenancePolicy::default()
+        };
+        let err = validate_maintenance_policy(&policy).unwrap_err();
+        assert!(err.is_validation());
+        assert!(err.to_string().contains("archive_below_importance"));
+    }
+}
@@ -0,0 +1,162 @@
+//! Data types for planned collective maintenance.
+//!
+//! Maintenance is two-phase: [`PulseDB::plan_maintenance()`](crate::PulseDB::plan_maintenance)
+//! produces a [`MaintenancePlan`] without touching any data, and
+//! [`PulseDB::run_maintenance()`](crate::PulseDB::run_maintenance) applies
+//! exactly the items listed in a plan the caller has reviewed.
+
+use std::time::Duration;
+
+use crate::lock::Lease;
+use crate::types::{CollectiveId, ExperienceId, InsightId, Timestamp};
+
+/// Which maintenance actions to plan.
+///
+/// The defaults plan only non-lossy cleanup: expiring stale agents and
+/// lapsed leases, and repairing dangling insight sources. Archiving and
+/// duplicate merging must be opted into.
+#[derive(Clone, Debug)]
+pub struct MaintenancePolicy {
+    /// Archive experiences recorded longer ago than this (default: `None`, disabled).
+    pub archive_older_than: Option<Duration>,
+
+    /// When archiving, only archive experiences with importance strictly
+    /// below this value (default: `None`, any importance).
+    pub archive_below_importance: Option<f32>,
+
+    /// Delete experiences whose content exactly duplicates an older
+    /// experience in the same collective (default: `false`).
+    pub merge_duplicates: bool,
+
+    /// Remove activity records whose heartbeat is past
+    /// `ActivityConfig::stale_threshold` (default: `true`).
+    pub expire_stale_activities: bool,
+
+    /// Remove leases whose TTL has elapsed (default: `true`).
+    pub expire_lapsed_locks: bool,
+
+    /// Strip dangling source references from insights (default: `true`).
+    pub repair_insight_sources: bool,
+}
+
+impl Default for MaintenancePolicy {
+    fn default() -> Self {
+        Self {
+            archive_older_than: None,
+            archive_below_importance: None,
+            merge_duplicates: false,
+            expire_stale_activities: true,
+            expire_lapsed_locks: true,
+            repair_insight_sources: true,
+        }
+    }
+}
+
+/// A set of experiences with identical content.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    /// The oldest copy, which is kept.
+    pub keep: ExperienceId,
+
+    /// Newer copies that would be deleted.
+    pub duplicates: Vec<ExperienceId>,
+}
+
+/// Dry-run report of what maintenance would do to a collective.
+///
+/// Returned by [`PulseDB::plan_maintenance()`](crate::PulseDB::plan_maintenance).
+/// Nothing has been changed when a plan is produced; pass it to
+/// [`PulseDB::run_maintenance()`](crate::PulseDB::run_maintenance) to apply it.
+#[derive(Clone, Debug)]
+pub struct MaintenancePlan {
+    /// The collective the plan targets.
+    pub collective_id: CollectiveId,
+
+    /// When the plan was computed.
+    pub planned_at: Timestamp,
+
+    /// Experiences that would be archived.
+    pub archive: Vec<ExperienceId>,
+
+    /// Agents whose stale activity records would be removed.
+    pub expire_activities: Vec<String>,
+
+    /// Lapsed leases that would be removed.
+    pub expire_locks: Vec<Lease>,
+
+    /// Duplicate experiences that would be deleted.
+    pub merge: Vec<DuplicateGroup>,
+
+    /// Insights with dangling sources that would be repaired.
+    pub repair_insights: Vec<InsightId>,
+
+    /// Estimated bytes freed by the deletions in this plan.
+    ///
+    /// Based on the serialized size of each record; archiving frees nothing.
+    pub bytes_reclaimed: u64,
+}
+
+impl MaintenancePlan {
+    /// Returns `true` if the plan contains no actions.
+    pub fn is_empty(&self) -> bool {
+        self.archive.is_empty()
+            && self.expire_activities.is_empty()
+            && self.expire_locks.is_empty()
+            && self.merge.is_empty()
+            && self.repair_insights.is_empty()
+    }
+}
+
+/// Outcome of applying a [`MaintenancePlan`].
+///
+/// Returned by [`PulseDB::run_maintenance()`](crate::PulseDB::run_maintenance).
+/// Items that changed since planning (deleted, renewed, heartbeated) are
+/// skipped, so counts can be lower than the plan's.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MaintenanceReport {
+    /// Experiences archived.
+    pub archived: usize,
+    /// Stale activity records removed.
+    pub activities_expired: usize,
+    /// Lapsed leases removed.
+    pub locks_expired: usize,
+    /// Duplicate experiences deleted.
+    pub experiences_merged: usize,
+    /// Insights whose sources were repaired.
+    pub insights_repaired: usize,
+    /// Estimated bytes freed.
+    pub bytes_reclaimed: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_is_non_lossy() {
+        let policy = MaintenancePolicy::default();
+        assert!(policy.archive_older_than.is_none());
+        assert!(!policy.merge_duplicates);
+        assert!(policy.expire_stale_activities);
+        assert!(policy.expire_lapsed_locks);
+        assert!(policy.repair_insight_sources);
+    }
+
+    #[test]
+    fn test_plan_is_empty() {
+        let mut plan = MaintenancePlan {
+            collective_id: CollectiveId::new(),
+            planned_at: Timestamp::now(),
+            archive: vec![],
+            expire_activities: vec![],
+            expire_locks: vec![],
+            merge: vec![],
+            repair_insights: vec![],
+            bytes_reclaimed: 0,
+        };
+        assert!(plan.is_empty());
+
+        plan.expire_activities.push("agent-1".to_string());
+        assert!(!plan.is_empty());
+    }
+}
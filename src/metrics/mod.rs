@@ -0,0 +1,187 @@
+//! Lock contention metrics.
+//!
+//! [`PulseDB::metrics()`](crate::PulseDB::metrics) reports how long
+//! operations waited on the database's shared locks: the map of vector
+//! indexes, each index's internal locks, and the storage write
+//! transaction, which redb serializes. Throughput that collapses under
+//! concurrency shows up here as contended acquisitions and long waits.
+//!
+//! Uncontended acquisitions are counted without reading the clock, so the
+//! instrumentation costs a few atomic increments on the fast path.
+
+pub mod types;
+
+pub use types::{DatabaseMetrics, IndexLockStats, LockWaitStats, LOCK_WAIT_BUCKETS};
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{
+    LockResult, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError,
+    TryLockResult,
+};
+use std::time::{Duration, Instant};
+
+/// Accumulates [`LockWaitStats`] for one lock.
+#[derive(Debug, Default)]
+pub(crate) struct LockWaitRecorder {
+    acquisitions: AtomicU64,
+    contended: AtomicU64,
+    total_wait_nanos: AtomicU64,
+    max_wait_nanos: AtomicU64,
+    histogram: [AtomicU64; LOCK_WAIT_BUCKETS.len() + 1],
+}
+
+impl LockWaitRecorder {
+    /// Acquires a lock with `try_acquire`, falling back to the blocking
+    /// `acquire` (timed) when the lock is held.
+    pub(crate) fn acquire<G>(
+        &self,
+        try_acquire: impl FnOnce() -> TryLockResult<G>,
+        acquire: impl FnOnce() -> LockResult<G>,
+    ) -> LockResult<G> {
+        self.acquisitions.fetch_add(1, Ordering::Relaxed);
+        match try_acquire() {
+            Ok(guard) => Ok(guard),
+            Err(TryLockError::Poisoned(poisoned)) => Err(poisoned),
+            Err(TryLockError::WouldBlock) => {
+                let start = Instant::now();
+                let guard = acquire();
+                self.record_wait(start.elapsed());
+                guard
+            }
+        }
+    }
+
+    /// Times a blocking acquisition that has no non-blocking form. Waits
+    /// shorter than the first bucket bound count as uncontended.
+    pub(crate) fn time<G>(&self, acquire: impl FnOnce() -> G) -> G {
+        self.acquisitions.fetch_add(1, Ordering::Relaxed);
+        let start = Instant::now();
+        let guard = acquire();
+        let wait = start.elapsed();
+        if wait >= LOCK_WAIT_BUCKETS[0] {
+            self.record_wait(wait);
+        }
+        guard
+    }
+
+    fn record_wait(&self, wait: Duration) {
+        let nanos = u64::try_from(wait.as_nanos()).unwrap_or(u64::MAX);
+        self.contended.fetch_add(1, Ordering::Relaxed);
+        self.total_wait_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.max_wait_nanos.fetch_max(nanos, Ordering::Relaxed);
+        let bucket = LOCK_WAIT_BUCKETS
+            .iter()
+            .position(|bound| wait <= *bound)
+            .unwrap_or(LOCK_WAIT_BUCKETS.len());
+        self.histogram[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the waits recorded so far.
+    pub(crate) fn stats(&self) -> LockWaitStats {
+        LockWaitStats {
+            acquisitions: self.acquisitions.load(Ordering::Relaxed),
+            contended: self.contended.load(Ordering::Relaxed),
+            total_wait: Duration::from_nanos(self.total_wait_nanos.load(Ordering::Relaxed)),
+            max_wait: Duration::from_nanos(self.max_wait_nanos.load(Ordering::Relaxed)),
+            histogram: std::array::from_fn(|i| self.histogram[i].load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// A `std::sync::RwLock` that records how long acquisitions wait.
+///
+/// `read()` and `write()` behave like the `RwLock` methods of the same
+/// name, so it drops in for one.
+#[derive(Debug, Default)]
+pub(crate) struct TimedRwLock<T> {
+    lock: RwLock<T>,
+    waits: LockWaitRecorder,
+}
+
+impl<T> TimedRwLock<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            lock: RwLock::new(value),
+            waits: LockWaitRecorder::default(),
+        }
+    }
+
+    pub(crate) fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
+        self.waits
+            .acquire(|| self.lock.try_read(), || self.lock.read())
+    }
+
+    pub(crate) fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+        self.waits
+            .acquire(|| self.lock.try_write(), || self.lock.write())
+    }
+
+    /// Returns the waits recorded so far.
+    pub(crate) fn waits(&self) -> LockWaitStats {
+        self.waits.stats()
+    }
+}
+
+/// A `std::sync::Mutex` that records how long acquisitions wait.
+#[derive(Debug, Default)]
+pub(crate) struct TimedMutex<T> {
+    lock: Mutex<T>,
+    waits: LockWaitRecorder,
+}
+
+impl<T> TimedMutex<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            lock: Mutex::new(value),
+            waits: LockWaitRecorder::default(),
+        }
+    }
+
+    pub(crate) fn lock(&self) -> LockResult<MutexGuard<'_, T>> {
+        self.waits
+            .acquire(|| self.lock.try_lock(), || self.lock.lock())
+    }
+
+    /// Returns the waits recorded so far.
+    pub(crate) fn waits(&self) -> LockWaitStats {
+        self.waits.stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_uncontended_acquisitions_record_no_wait() {
+        let lock = TimedRwLock::new(0);
+        *lock.write().unwrap() += 1;
+        assert_eq!(*lock.read().unwrap(), 1);
+        let waits = lock.waits();
+        assert_eq!(waits.acquisitions, 2);
+        assert_eq!(waits.contended, 0);
+        assert_eq!(waits.total_wait, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_contended_acquisition_lands_in_histogram() {
+        let lock = Arc::new(TimedMutex::new(()));
+        let guard = lock.lock().unwrap();
+        let waiter = {
+            let lock = Arc::clone(&lock);
+            std::thread::spawn(move || drop(lock.lock().unwrap()))
+        };
+        std::thread::sleep(Duration::from_millis(50));
+        drop(guard);
+        waiter.join().unwrap();
+
+        let waits = lock.waits();
+        assert_eq!(waits.acquisitions, 2);
+        assert_eq!(waits.contended, 1);
+        assert!(waits.max_wait >= Duration::from_millis(10));
+        assert_eq!(waits.histogram.iter().sum::<u64>(), 1);
+        assert_eq!(waits.histogram[..4], [0, 0, 0, 0]);
+        assert_eq!(waits.mean_wait(), waits.total_wait);
+    }
+}
@@ -0,0 +1,90 @@
+//! Data types for database metrics.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::CollectiveId;
+
+/// Upper bounds of the [`LockWaitStats::histogram`] buckets. A final,
+/// unbounded bucket counts longer waits.
+pub const LOCK_WAIT_BUCKETS: [Duration; 5] = [
+    Duration::from_micros(10),
+    Duration::from_micros(100),
+    Duration::from_millis(1),
+    Duration::from_millis(10),
+    Duration::from_millis(100),
+];
+
+/// Time spent waiting to acquire a lock, since the database was opened.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockWaitStats {
+    /// Times the lock was acquired.
+    pub acquisitions: u64,
+
+    /// Acquisitions that found the lock held and had to wait.
+    pub contended: u64,
+
+    /// Total time spent waiting.
+    pub total_wait: Duration,
+
+    /// Longest single wait.
+    pub max_wait: Duration,
+
+    /// Contended acquisitions by wait time: entry `i` counts waits up to
+    /// [`LOCK_WAIT_BUCKETS`]`[i]` (and above the previous bound); the last
+    /// entry counts waits above 100 ms.
+    pub histogram: [u64; 6],
+}
+
+impl LockWaitStats {
+    /// Mean wait over contended acquisitions (zero if there were none).
+    pub fn mean_wait(&self) -> Duration {
+        if self.contended == 0 {
+            return Duration::ZERO;
+        }
+        self.total_wait.div_f64(self.contended as f64)
+    }
+
+    /// Adds `other`'s counts to these.
+    pub(crate) fn merge(&mut self, other: &Self) {
+        self.acquisitions += other.acquisitions;
+        self.contended += other.contended;
+        self.total_wait += other.total_wait;
+        self.max_wait = self.max_wait.max(other.max_wait);
+        for (bucket, count) in self.histogram.iter_mut().zip(other.histogram) {
+            *bucket += count;
+        }
+    }
+}
+
+/// Lock waits of one collective's vector indexes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexLockStats {
+    /// The collective.
+    pub collective_id: CollectiveId,
+
+    /// Waits on the internal locks of its experience and insight indexes.
+    pub waits: LockWaitStats,
+}
+
+/// Runtime metrics of an open database.
+///
+/// Returned by [`PulseDB::metrics()`](crate::PulseDB::metrics).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseMetrics {
+    /// Waits on the lock guarding the map of experience and insight
+    /// indexes, taken by every search and write.
+    pub vectors_lock: LockWaitStats,
+
+    /// Waits on the internal locks of every resident index, summed.
+    /// Indexes evicted or dropped since open no longer count.
+    pub index_locks: LockWaitStats,
+
+    /// `index_locks` per collective, ordered by collective ID.
+    pub indexes: Vec<IndexLockStats>,
+
+    /// Waits to begin a storage write transaction, which queue behind the
+    /// one in progress.
+    pub write_transactions: LockWaitStats,
+}
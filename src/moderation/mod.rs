@@ -0,0 +1,17 @@
+//! Review queue for experiences from untrusted agents.
+//!
+//! A collective's [`ModerationPolicy`], set with
+//! [`PulseDB::set_moderation_policy()`](crate::PulseDB::set_moderation_policy),
+//! names the agents whose experiences need review. Their experiences are
+//! stored as pending: archived, so search, recent listings, and context
+//! retrieval skip them, and held in a review queue until
+//! [`approve_experience()`](crate::PulseDB::approve_experience) publishes
+//! them or [`reject_experience()`](crate::PulseDB::reject_experience)
+//! deletes them.
+//!
+//! Policies are persisted with the collective. A policy change only
+//! affects experiences recorded afterwards.
+
+pub mod types;
+
+pub use types::ModerationPolicy;
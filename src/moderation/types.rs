@@ -0,0 +1,42 @@
+//! Data types for experience moderation.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::AgentId;
+
+/// Which agents' experiences a collective holds for review.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{AgentId, ModerationPolicy};
+///
+/// // Everyone except the curator waits for review
+/// let policy = ModerationPolicy::ReviewAllExcept(vec![AgentId::new("curator")]);
+/// assert!(policy.requires_review(&AgentId::new("new-agent")));
+/// assert!(!policy.requires_review(&AgentId::new("curator")));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModerationPolicy {
+    /// Every experience is published immediately.
+    #[default]
+    Open,
+
+    /// Experiences from these agents wait for review.
+    ReviewAgents(Vec<AgentId>),
+
+    /// Experiences from every agent but these wait for review, so agents
+    /// the collective hasn't vetted yet are held by default.
+    ReviewAllExcept(Vec<AgentId>),
+}
+
+impl ModerationPolicy {
+    /// Returns `true` if experiences from `agent` wait for review.
+    pub fn requires_review(&self, agent: &AgentId) -> bool {
+        match self {
+            Self::Open => false,
+            Self::ReviewAgents(agents) => agents.contains(agent),
+            Self::ReviewAllExcept(trusted) => !trusted.contains(agent),
+        }
+    }
+}
@@ -0,0 +1,436 @@
+//! Ingestion of OpenTelemetry traces as experiences.
+//!
+//! Agent frameworks instrumented with OpenTelemetry already describe what
+//! happened in their spans. This module reads traces in OTLP/JSON — the
+//! format the OpenTelemetry Collector's `file` exporter writes, one
+//! `ExportTraceServiceRequest` per line — and maps spans worth remembering
+//! to [`NewExperience`] candidates:
+//!
+//! - spans with status `ERROR` become [`ExperienceType::Difficulty`]
+//!   experiences describing the failure, using the span's `exception`
+//!   event when there is one;
+//! - spans carrying the [`OtelMapping::content_attribute`] (by default
+//!   `pulsedb.content`) become experiences with that content, so
+//!   instrumentation can mark spans for memory explicitly;
+//! - other spans are skipped unless [`OtelMapping::include_ok_spans`] is set.
+//!
+//! Attributes follow the OpenTelemetry semantic conventions:
+//! `gen_ai.agent.name` (else the resource's `service.name`) becomes the
+//! source agent, `gen_ai.request.model` / `gen_ai.response.model`,
+//! `gen_ai.request.temperature`, and `gen_ai.tool.name` become the
+//! [`ModelAttribution`], `code.filepath` / `code.file.path` become related
+//! files, and the trace ID becomes the source task. Experience IDs are
+//! derived from the trace and span IDs, so ingesting the same file twice
+//! records each span once.
+//!
+//! Use [`PulseDB::ingest_otel()`](crate::PulseDB::ingest_otel) to record
+//! the candidates directly, or [`OtelMapping::candidates()`] to inspect or
+//! embed them first.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::error::{PulseDBError, Result, ValidationError};
+use crate::experience::{ExperienceType, ModelAttribution, NewExperience, Severity};
+use crate::types::{AgentId, CollectiveId, ExperienceId, TaskId};
+
+/// How OpenTelemetry spans map to experiences.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::otel::OtelMapping;
+/// use pulsedb::CollectiveId;
+///
+/// let line = r#"{"resourceSpans":[{"resource":{"attributes":[
+///     {"key":"service.name","value":{"stringValue":"planner"}}]},
+///   "scopeSpans":[{"spans":[{
+///     "traceId":"5b8efff798038103d269b633813fc60c","spanId":"eee19b7ec3c1b174",
+///     "name":"tool_call","status":{"code":2,"message":"rate limited"}}]}]}]}"#;
+///
+/// let candidates = OtelMapping::default()
+///     .candidates(CollectiveId::new(), line.as_bytes())
+///     .unwrap();
+/// assert_eq!(candidates.len(), 1);
+/// assert_eq!(candidates[0].content, "tool_call failed: rate limited");
+/// assert_eq!(candidates[0].source_agent.as_str(), "planner");
+/// ```
+#[derive(Clone, Debug)]
+pub struct OtelMapping {
+    /// Span attribute whose string value is used as experience content.
+    ///
+    /// Spans carrying it are always ingested.
+    ///
+    /// Default: `"pulsedb.content"`
+    pub content_attribute: String,
+
+    /// Also ingest successful spans without the content attribute, as
+    /// generic experiences named after the span.
+    ///
+    /// Default: false
+    pub include_ok_spans: bool,
+
+    /// Domain tags added to every ingested experience.
+    ///
+    /// Default: `["otel"]`
+    pub domain: Vec<String>,
+
+    /// Source agent when neither `gen_ai.agent.name` nor `service.name` is
+    /// set.
+    ///
+    /// Default: `"otel"`
+    pub default_agent: String,
+
+    /// Importance of experiences from failed spans.
+    ///
+    /// Default: 0.7
+    pub error_importance: f32,
+}
+
+impl Default for OtelMapping {
+    fn default() -> Self {
+        Self {
+            content_attribute: "pulsedb.content".to_string(),
+            include_ok_spans: false,
+            domain: vec!["otel".to_string()],
+            default_agent: "otel".to_string(),
+            error_importance: 0.7,
+        }
+    }
+}
+
+impl OtelMapping {
+    /// Reads OTLP/JSON traces and maps their spans to candidate
+    /// experiences, in file order.
+    ///
+    /// Accepts newline-delimited or concatenated `ExportTraceServiceRequest`
+    /// objects. Candidates have no embedding.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the byte offset if the input is
+    /// not valid OTLP/JSON.
+    pub fn candidates(
+        &self,
+        collective_id: CollectiveId,
+        reader: impl Read,
+    ) -> Result<Vec<NewExperience>> {
+        let mut candidates = Vec::new();
+        let stream = serde_json::Deserializer::from_reader(reader).into_iter::<TracesData>();
+        for request in stream {
+            let request = request.map_err(|e| {
+                PulseDBError::from(ValidationError::invalid_field(
+                    "otel",
+                    format!("invalid OTLP/JSON: {e}"),
+                ))
+            })?;
+            for resource_spans in request.resource_spans {
+                let resource = attributes(&resource_spans.resource.attributes);
+                for scope_spans in resource_spans.scope_spans {
+                    for span in &scope_spans.spans {
+                        if let Some(exp) = self.map_span(collective_id, &resource, span) {
+                            candidates.push(exp);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(candidates)
+    }
+
+    fn map_span(
+        &self,
+        collective_id: CollectiveId,
+        resource: &HashMap<&str, String>,
+        span: &Span,
+    ) -> Option<NewExperience> {
+        let attrs = attributes(&span.attributes);
+        let failed = span.status.code == STATUS_CODE_ERROR;
+
+        let (content, experience_type) =
+            if let Some(content) = attrs.get(self.content_attribute.as_str()) {
+                let experience_type = if failed {
+                    ExperienceType::Difficulty {
+                        description: failure(span),
+                        severity: Severity::High,
+                    }
+                } else {
+                    ExperienceType::Generic {
+                        category: Some(span.name.clone()),
+                    }
+                };
+                (content.clone(), experience_type)
+            } else if failed {
+                let description = failure(span);
+                (
+                    format!("{} failed: {description}", span.name),
+                    ExperienceType::Difficulty {
+                        description,
+                        severity: Severity::High,
+                    },
+                )
+            } else if self.include_ok_spans {
+                (
+                    span.name.clone(),
+                    ExperienceType::Generic {
+                        category: Some("trace".to_string()),
+                    },
+                )
+            } else {
+                return None;
+            };
+
+        let agent = attrs
+            .get("gen_ai.agent.name")
+            .or_else(|| resource.get("service.name"))
+            .cloned()
+            .unwrap_or_else(|| self.default_agent.clone());
+        let attribution = attrs
+            .get("gen_ai.response.model")
+            .or_else(|| attrs.get("gen_ai.request.model"))
+            .map(|model| ModelAttribution {
+                model_name: model.clone(),
+                model_version: None,
+                temperature: attrs
+                    .get("gen_ai.request.temperature")
+                    .and_then(|t| t.parse().ok()),
+                tool: attrs.get("gen_ai.tool.name").cloned(),
+            });
+        let related_files = ["code.filepath", "code.file.path"]
+            .iter()
+            .filter_map(|key| attrs.get(key).cloned())
+            .take(1)
+            .collect();
+
+        let mut exp = NewExperience {
+            collective_id,
+            id: Some(ExperienceId::derive(
+                collective_id,
+                &format!("otel:{}:{}", span.trace_id, span.span_id),
+            )),
+            content,
+            experience_type,
+            domain: self.domain.clone(),
+            related_files,
+            source_agent: AgentId::new(agent),
+            source_task: (!span.trace_id.is_empty()).then(|| TaskId::new(&span.trace_id)),
+            attribution,
+            ..Default::default()
+        };
+        if failed {
+            exp.importance = self.error_importance;
+        }
+        Some(exp)
+    }
+}
+
+/// `Status.code` value for errors (`STATUS_CODE_ERROR`).
+const STATUS_CODE_ERROR: u8 = 2;
+
+/// Describes why a span failed: its `exception` event if any, else its
+/// status message, else just that it errored.
+fn failure(span: &Span) -> String {
+    let exception = span
+        .events
+        .iter()
+        .find(|event| event.name == "exception")
+        .map(|event| attributes(&event.attributes));
+    if let Some(exception) = exception {
+        match (
+            exception.get("exception.type"),
+            exception.get("exception.message"),
+        ) {
+            (Some(kind), Some(message)) => return format!("{kind}: {message}"),
+            (None, Some(message)) | (Some(message), None) => return message.clone(),
+            (None, None) => {}
+        }
+    }
+    if span.status.message.is_empty() {
+        "error".to_string()
+    } else {
+        span.status.message.clone()
+    }
+}
+
+/// Flattens OTLP key-values to strings. Arrays are joined with ", ";
+/// nested key-value lists are skipped.
+fn attributes(list: &[KeyValue]) -> HashMap<&str, String> {
+    list.iter()
+        .filter_map(|kv| any_value(&kv.value).map(|v| (kv.key.as_str(), v)))
+        .collect()
+}
+
+fn any_value(value: &Value) -> Option<String> {
+    let (kind, inner) = value.as_object()?.iter().next()?;
+    match (kind.as_str(), inner) {
+        ("stringValue", Value::String(s)) => Some(s.clone()),
+        // int64 is encoded as a JSON string in OTLP/JSON, but accept numbers too
+        ("intValue", Value::String(s)) => Some(s.clone()),
+        ("intValue" | "doubleValue", Value::Number(n)) => Some(n.to_string()),
+        ("boolValue", Value::Bool(b)) => Some(b.to_string()),
+        ("arrayValue", array) => {
+            let values = array.get("values")?.as_array()?;
+            Some(
+                values
+                    .iter()
+                    .filter_map(any_value)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+        }
+        _ => None,
+    }
+}
+
+// OTLP/JSON wire types, reduced to the fields the mapping reads.
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TracesData {
+    #[serde(default)]
+    resource_spans: Vec<ResourceSpans>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceSpans {
+    #[serde(default)]
+    resource: Resource,
+    #[serde(default)]
+    scope_spans: Vec<ScopeSpans>,
+}
+
+#[derive(Default, Deserialize)]
+struct Resource {
+    #[serde(default)]
+    attributes: Vec<KeyValue>,
+}
+
+#[derive(Deserialize)]
+struct ScopeSpans {
+    #[serde(default)]
+    spans: Vec<Span>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Span {
+    #[serde(default)]
+    trace_id: String,
+    #[serde(default)]
+    span_id: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    attributes: Vec<KeyValue>,
+    #[serde(default)]
+    events: Vec<Event>,
+    #[serde(default)]
+    status: Status,
+}
+
+#[derive(Deserialize)]
+struct Event {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    attributes: Vec<KeyValue>,
+}
+
+#[derive(Default, Deserialize)]
+struct Status {
+    #[serde(default)]
+    code: u8,
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct KeyValue {
+    key: String,
+    #[serde(default)]
+    value: Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACE: &str = r#"{"resourceSpans":[{
+      "resource":{"attributes":[{"key":"service.name","value":{"stringValue":"coder"}}]},
+      "scopeSpans":[{"scope":{"name":"agent"},"spans":[
+        {"traceId":"aa","spanId":"01","name":"plan","status":{}},
+        {"traceId":"aa","spanId":"02","name":"run_tests",
+         "attributes":[
+           {"key":"gen_ai.agent.name","value":{"stringValue":"tester"}},
+           {"key":"gen_ai.request.model","value":{"stringValue":"gpt-4o"}},
+           {"key":"gen_ai.request.temperature","value":{"doubleValue":0.2}},
+           {"key":"code.filepath","value":{"stringValue":"src/lib.rs"}}],
+         "events":[{"name":"exception","attributes":[
+           {"key":"exception.type","value":{"stringValue":"AssertionError"}},
+           {"key":"exception.message","value":{"stringValue":"left != right"}}]}],
+         "status":{"code":2,"message":"tests failed"}},
+        {"traceId":"aa","spanId":"03","name":"summarize",
+         "attributes":[{"key":"pulsedb.content","value":{"stringValue":"Use nextest for flaky suites"}}]}
+      ]}]}]}
+    {"resourceSpans":[]}"#;
+
+    #[test]
+    fn test_maps_failed_and_marked_spans() {
+        let cid = CollectiveId::new();
+        let candidates = OtelMapping::default()
+            .candidates(cid, TRACE.as_bytes())
+            .unwrap();
+        assert_eq!(candidates.len(), 2);
+
+        let failed = &candidates[0];
+        assert_eq!(
+            failed.content,
+            "run_tests failed: AssertionError: left != right"
+        );
+        assert!(matches!(
+            failed.experience_type,
+            ExperienceType::Difficulty {
+                severity: Severity::High,
+                ..
+            }
+        ));
+        assert_eq!(failed.source_agent.as_str(), "tester");
+        assert_eq!(failed.source_task.as_ref().unwrap().as_str(), "aa");
+        assert_eq!(failed.related_files, vec!["src/lib.rs"]);
+        let attribution = failed.attribution.as_ref().unwrap();
+        assert_eq!(attribution.model_name, "gpt-4o");
+        assert_eq!(attribution.temperature, Some(0.2));
+        assert_eq!(failed.importance, 0.7);
+
+        let marked = &candidates[1];
+        assert_eq!(marked.content, "Use nextest for flaky suites");
+        assert_eq!(marked.source_agent.as_str(), "coder");
+        assert_eq!(marked.domain, vec!["otel"]);
+        assert_ne!(marked.id, failed.id);
+    }
+
+    #[test]
+    fn test_ok_spans_are_opt_in() {
+        let mapping = OtelMapping {
+            include_ok_spans: true,
+            ..Default::default()
+        };
+        let candidates = mapping
+            .candidates(CollectiveId::new(), TRACE.as_bytes())
+            .unwrap();
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates[0].content, "plan");
+    }
+
+    #[test]
+    fn test_invalid_json_is_a_validation_error() {
+        let err = OtelMapping::default()
+            .candidates(CollectiveId::new(), "{\"resourceSpans\": [".as_bytes())
+            .unwrap_err();
+        assert!(err.is_validation());
+    }
+}
@@ -0,0 +1,81 @@
+//! Pins that keep a collective's indexes resident while they are in use.
+//!
+//! [`PulseDB::evict_idle_collectives()`](crate::PulseDB::evict_idle_collectives)
+//! skips pinned collectives. A search pins its collective before making
+//! sure the indexes are loaded and holds the pin until it is done with
+//! them, so an eviction sweep — including one triggered by that search —
+//! can't drop them in between.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::types::CollectiveId;
+
+/// Collectives whose indexes are in use, with their pin counts.
+#[derive(Default)]
+pub(crate) struct IndexPins {
+    counts: Mutex<HashMap<CollectiveId, usize>>,
+}
+
+/// Keeps a collective pinned until dropped.
+#[must_use = "the collective is only pinned while the guard is held"]
+pub(crate) struct PinGuard<'a> {
+    pins: &'a IndexPins,
+    id: CollectiveId,
+}
+
+impl IndexPins {
+    /// Pins `id` until the returned guard drops.
+    pub(crate) fn pin(&self, id: CollectiveId) -> PinGuard<'_> {
+        if let Ok(mut counts) = self.counts.lock() {
+            *counts.entry(id).or_insert(0) += 1;
+        }
+        PinGuard { pins: self, id }
+    }
+
+    /// Returns true if `id` is pinned.
+    ///
+    /// A poisoned lock reports every collective as pinned, so nothing is
+    /// evicted from under a user.
+    pub(crate) fn is_pinned(&self, id: CollectiveId) -> bool {
+        self.counts
+            .lock()
+            .map(|counts| counts.contains_key(&id))
+            .unwrap_or(true)
+    }
+}
+
+impl Drop for PinGuard<'_> {
+    fn drop(&mut self) {
+        let Ok(mut counts) = self.pins.counts.lock() else {
+            return;
+        };
+        if let Some(count) = counts.get_mut(&self.id) {
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&self.id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pins_are_counted() {
+        let pins = IndexPins::default();
+        let id = CollectiveId::new();
+        assert!(!pins.is_pinned(id));
+
+        let first = pins.pin(id);
+        let second = pins.pin(id);
+        drop(first);
+        assert!(pins.is_pinned(id));
+        assert!(!pins.is_pinned(CollectiveId::new()));
+
+        drop(second);
+        assert!(!pins.is_pinned(id));
+    }
+}
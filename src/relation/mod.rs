@@ -12,6 +12,8 @@
 //! - [`get_related_experiences(id, direction)`](crate::PulseDB::get_related_experiences)
 //! - [`get_relation(id)`](crate::PulseDB::get_relation)
 //! - [`delete_relation(id)`](crate::PulseDB::delete_relation)
+//! - [`list_relations_filtered(collective_id, filter, page)`](crate::PulseDB::list_relations_filtered)
+//! - [`list_relation_suggestions(collective_id)`](crate::PulseDB::list_relation_suggestions)
 //!
 //! # Constraints
 //!
@@ -23,9 +25,13 @@
 
 pub mod types;
 
-pub use types::{ExperienceRelation, NewExperienceRelation, RelationDirection, RelationType};
+pub use types::{
+    ExperienceRelation, NewExperienceRelation, RelationDirection, RelationFilter, RelationSort,
+    RelationSuggestion, RelationType,
+};
 
 use crate::error::{PulseDBError, ValidationError};
+use crate::experience::ExperienceType;
 use crate::storage::schema::MAX_RELATION_METADATA_SIZE;
 
 /// Validates a new relation before storage.
@@ -70,9 +76,34 @@ pub(crate) fn validate_new_relation(rel: &NewExperienceRelation) -> Result<(), P
     Ok(())
 }
 
+/// Guesses how a newly recorded experience (`source`) relates to a similar
+/// existing one (`target`) from their types.
+pub(crate) fn suggest_relation_type(
+    source: &ExperienceType,
+    target: &ExperienceType,
+) -> RelationType {
+    use ExperienceType::*;
+    match (source, target) {
+        (OpenQuestion { .. }, OpenQuestion { .. }) => RelationType::RelatedTo,
+        (_, OpenQuestion { .. }) => RelationType::Answers,
+        (Solution { .. } | ErrorPattern { .. }, Difficulty { .. }) => RelationType::Elaborates,
+        (Solution { worked: false, .. } | Difficulty { .. }, SuccessPattern { .. }) => {
+            RelationType::Contradicts
+        }
+        (Solution { worked: true, .. } | SuccessPattern { .. }, SuccessPattern { .. }) => {
+            RelationType::Supports
+        }
+        (ArchitecturalDecision { .. }, ArchitecturalDecision { .. })
+        | (UserPreference { .. }, UserPreference { .. }) => RelationType::Supersedes,
+        (Fact { .. }, Fact { .. }) => RelationType::Supports,
+        _ => RelationType::RelatedTo,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::experience::Severity;
     use crate::types::ExperienceId;
 
     fn valid_new_relation() -> NewExperienceRelation {
@@ -149,4 +180,36 @@ mod tests {
         rel.metadata = Some("x".repeat(MAX_RELATION_METADATA_SIZE));
         assert!(validate_new_relation(&rel).is_ok());
     }
+
+    #[test]
+    fn test_suggest_relation_type() {
+        let question = ExperienceType::OpenQuestion {
+            question: "why?".into(),
+        };
+        let difficulty = ExperienceType::Difficulty {
+            description: "slow CI".into(),
+            severity: Severity::Medium,
+        };
+        let solution = ExperienceType::Solution {
+            problem_ref: None,
+            approach: "cache deps".into(),
+            worked: true,
+        };
+        let decision = ExperienceType::ArchitecturalDecision {
+            decision: "use redb".into(),
+            rationale: "embedded".into(),
+        };
+        let generic = ExperienceType::Generic { category: None };
+
+        let cases = [
+            (&solution, &question, RelationType::Answers),
+            (&question, &question, RelationType::RelatedTo),
+            (&solution, &difficulty, RelationType::Elaborates),
+            (&decision, &decision, RelationType::Supersedes),
+            (&generic, &difficulty, RelationType::RelatedTo),
+        ];
+        for (source, target, expected) in cases {
+            assert_eq!(suggest_relation_type(source, target), expected);
+        }
+    }
 }
@@ -6,7 +6,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::types::{ExperienceId, RelationId, Timestamp};
+use crate::types::{CollectiveId, ExperienceId, RelationId, Timestamp};
 
 /// Type of relationship between two experiences.
 ///
@@ -35,6 +35,13 @@ pub enum RelationType {
     Implies,
     /// General relationship with no specific semantics.
     RelatedTo,
+    /// Source experience summarizes the target; recorded by
+    /// [`PulseDB::consolidate()`](crate::PulseDB::consolidate) from a
+    /// cluster's representative to each other member.
+    Summarizes,
+    /// Source experience answers the target, which must be an
+    /// [`OpenQuestion`](crate::ExperienceType::OpenQuestion).
+    Answers,
 }
 
 /// Direction for querying relations from a given experience.
@@ -43,7 +50,7 @@ pub enum RelationType {
 /// - **Outgoing**: "What does this experience point to?"
 /// - **Incoming**: "What points to this experience?"
 /// - **Both**: All connections regardless of direction
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RelationDirection {
     /// Relations where the experience is the source (source → target).
     Outgoing,
@@ -82,12 +89,86 @@ pub struct ExperienceRelation {
     pub strength: f32,
 
     /// Optional JSON metadata (max 10KB).
+    #[serde(default)]
     pub metadata: Option<String>,
 
     /// When this relation was created.
     pub created_at: Timestamp,
 }
 
+/// Ordering for collective-scoped relation listings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RelationSort {
+    /// Creation time ascending (index order).
+    #[default]
+    OldestFirst,
+    /// Creation time descending.
+    NewestFirst,
+    /// Strength descending; ties broken by creation time ascending.
+    StrongestFirst,
+}
+
+/// Filter criteria for enumerating a collective's relations.
+///
+/// Used by [`PulseDB::list_relations_filtered()`](crate::PulseDB::list_relations_filtered).
+/// Fields set to `None` are not filtered on.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{RelationFilter, RelationSort, RelationType};
+///
+/// // Strong contradictions, strongest first
+/// let filter = RelationFilter {
+///     relation_types: Some(vec![RelationType::Contradicts]),
+///     min_strength: Some(0.7),
+///     sort: RelationSort::StrongestFirst,
+///     ..RelationFilter::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RelationFilter {
+    /// Only include relations of these types.
+    ///
+    /// `None` means all types. An empty `Some(vec![])` matches nothing.
+    pub relation_types: Option<Vec<RelationType>>,
+
+    /// Only include relations with strength >= this threshold.
+    pub min_strength: Option<f32>,
+
+    /// Only include relations created at or after this timestamp.
+    pub since: Option<Timestamp>,
+
+    /// Result ordering (default: oldest first).
+    pub sort: RelationSort,
+}
+
+impl RelationFilter {
+    /// Returns `true` if the given relation passes the type, strength,
+    /// and timestamp criteria. `sort` is applied by the caller.
+    pub fn matches(&self, relation: &ExperienceRelation) -> bool {
+        if let Some(ref types) = self.relation_types {
+            if !types.contains(&relation.relation_type) {
+                return false;
+            }
+        }
+
+        if let Some(min) = self.min_strength {
+            if relation.strength < min {
+                return false;
+            }
+        }
+
+        if let Some(since) = self.since {
+            if relation.created_at < since {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
 /// Input for creating a new relation between two experiences.
 ///
 /// # Example
@@ -117,6 +198,7 @@ pub struct ExperienceRelation {
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Serialize, Deserialize)]
 pub struct NewExperienceRelation {
     /// The experience this relation originates from.
     pub source_id: ExperienceId,
@@ -131,9 +213,41 @@ pub struct NewExperienceRelation {
     pub strength: f32,
 
     /// Optional JSON metadata (max 10KB).
+    #[serde(default)]
     pub metadata: Option<String>,
 }
 
+/// A relation proposed for a newly recorded experience, waiting for an
+/// agent to confirm it.
+///
+/// Queued when [`Config::relation_suggestions`](crate::Config::relation_suggestions)
+/// is set. Accept with
+/// [`accept_relation_suggestion()`](crate::PulseDB::accept_relation_suggestion)
+/// or drop with
+/// [`dismiss_relation_suggestion()`](crate::PulseDB::dismiss_relation_suggestion).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RelationSuggestion {
+    /// The collective both experiences belong to.
+    pub collective_id: CollectiveId,
+
+    /// The newly recorded experience.
+    pub source_id: ExperienceId,
+
+    /// The existing experience it was found similar to.
+    pub target_id: ExperienceId,
+
+    /// The relation type guessed from the two experience types.
+    pub relation_type: RelationType,
+
+    /// Similarity of the two embeddings, scored per
+    /// [`Config::score_kind`](crate::Config::score_kind); the relation's
+    /// strength if accepted.
+    pub similarity: f32,
+
+    /// When the suggestion was made.
+    pub suggested_at: Timestamp,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,6 +261,8 @@ mod tests {
             RelationType::Supersedes,
             RelationType::Implies,
             RelationType::RelatedTo,
+            RelationType::Summarizes,
+            RelationType::Answers,
         ];
         for rt in &types {
             let bytes = bincode::serialize(rt).unwrap();
@@ -192,4 +308,34 @@ mod tests {
         assert_ne!(RelationDirection::Outgoing, RelationDirection::Both);
         assert_ne!(RelationDirection::Incoming, RelationDirection::Both);
     }
+
+    #[test]
+    fn test_relation_filter_matches() {
+        let relation = ExperienceRelation {
+            id: RelationId::new(),
+            source_id: ExperienceId::new(),
+            target_id: ExperienceId::new(),
+            relation_type: RelationType::Supports,
+            strength: 0.6,
+            metadata: None,
+            created_at: Timestamp::from_millis(1_000),
+        };
+
+        assert!(RelationFilter::default().matches(&relation));
+        assert!(!RelationFilter {
+            relation_types: Some(vec![RelationType::Contradicts]),
+            ..Default::default()
+        }
+        .matches(&relation));
+        assert!(!RelationFilter {
+            min_strength: Some(0.7),
+            ..Default::default()
+        }
+        .matches(&relation));
+        assert!(!RelationFilter {
+            since: Some(Timestamp::from_millis(2_000)),
+            ..Default::default()
+        }
+        .matches(&relation));
+    }
 }
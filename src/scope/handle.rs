@@ -0,0 +1,320 @@
+//! The [`ScopedDb`] handle and its [`Capabilities`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::PulseDB;
+use crate::error::{NotFoundError, PulseDBError, Result};
+use crate::experience::{Experience, ExperienceUpdate, NewExperience};
+use crate::insight::{DerivedInsight, NewDerivedInsight};
+use crate::relation::{ExperienceRelation, NewExperienceRelation, RelationDirection};
+use crate::search::{ContextCandidates, ContextRequest, SearchFilter, SearchResult};
+use crate::types::{CollectiveId, ExperienceId, InsightId, RelationId};
+
+/// What a [`ScopedDb`] handle may do.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::Capabilities;
+///
+/// let caps = Capabilities::no_delete();
+/// assert!(caps.read && caps.write && !caps.delete);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Get, search, and list records.
+    pub read: bool,
+
+    /// Record and update experiences, relations, and insights.
+    pub write: bool,
+
+    /// Delete experiences, relations, and insights.
+    pub delete: bool,
+}
+
+impl Capabilities {
+    /// Every capability.
+    pub const fn all() -> Self {
+        Self {
+            read: true,
+            write: true,
+            delete: true,
+        }
+    }
+
+    /// Reads only.
+    pub const fn read_only() -> Self {
+        Self {
+            read: true,
+            write: false,
+            delete: false,
+        }
+    }
+
+    /// Reads and writes, but no deletes.
+    pub const fn no_delete() -> Self {
+        Self {
+            read: true,
+            write: true,
+            delete: false,
+        }
+    }
+}
+
+/// A handle confined to one collective and a set of [`Capabilities`].
+///
+/// Created by [`PulseDB::scoped()`]. Methods mirror their [`PulseDB`]
+/// namesakes with the collective argument dropped; new records land in the
+/// handle's collective whatever `collective_id` they carry.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> pulsedb::Result<()> {
+/// # let dir = tempfile::tempdir().unwrap();
+/// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+/// # let collective_id = db.create_collective("example")?;
+/// use pulsedb::Capabilities;
+///
+/// let plugin_db = db.scoped(collective_id, Capabilities::read_only())?;
+/// let results = plugin_db.search_similar(&[0.1; 384], 5)?;
+/// assert!(plugin_db.delete_experience(pulsedb::ExperienceId::new()).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct ScopedDb<'db> {
+    db: &'db PulseDB,
+    collective_id: CollectiveId,
+    capabilities: Capabilities,
+}
+
+impl std::fmt::Debug for ScopedDb<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScopedDb")
+            .field("collective_id", &self.collective_id)
+            .field("capabilities", &self.capabilities)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'db> ScopedDb<'db> {
+    pub(crate) fn new(
+        db: &'db PulseDB,
+        collective_id: CollectiveId,
+        capabilities: Capabilities,
+    ) -> Self {
+        Self {
+            db,
+            collective_id,
+            capabilities,
+        }
+    }
+
+    /// The collective this handle is confined to.
+    pub fn collective_id(&self) -> CollectiveId {
+        self.collective_id
+    }
+
+    /// What this handle may do.
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    // =========================================================================
+    // Reads
+    // =========================================================================
+
+    /// See [`PulseDB::get_experience()`]. `None` for experiences in other
+    /// collectives.
+    pub fn get_experience(&self, id: ExperienceId) -> Result<Option<Experience>> {
+        self.require(self.capabilities.read, "read")?;
+        Ok(self
+            .db
+            .get_experience(id)?
+            .filter(|exp| exp.collective_id == self.collective_id))
+    }
+
+    /// See [`PulseDB::search_similar()`].
+    pub fn search_similar(&self, query: &[f32], k: usize) -> Result<Vec<SearchResult>> {
+        self.search_similar_filtered(query, k, SearchFilter::default())
+    }
+
+    /// See [`PulseDB::search_similar_filtered()`].
+    /// [`SearchFilter::include_descendants`] is ignored.
+    pub fn search_similar_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        mut filter: SearchFilter,
+    ) -> Result<Vec<SearchResult>> {
+        self.require(self.capabilities.read, "read")?;
+        filter.include_descendants = false;
+        self.db
+            .search_similar_filtered(self.collective_id, query, k, filter)
+    }
+
+    /// See [`PulseDB::get_recent_experiences()`].
+    pub fn get_recent_experiences(&self, limit: usize) -> Result<Vec<Experience>> {
+        self.require(self.capabilities.read, "read")?;
+        self.db.get_recent_experiences(self.collective_id, limit)
+    }
+
+    /// See [`PulseDB::get_related_experiences()`].
+    pub fn get_related_experiences(
+        &self,
+        experience_id: ExperienceId,
+        direction: RelationDirection,
+    ) -> Result<Vec<(Experience, ExperienceRelation)>> {
+        self.require(self.capabilities.read, "read")?;
+        self.check_experience(experience_id)?;
+        self.db.get_related_experiences(experience_id, direction)
+    }
+
+    /// See [`PulseDB::get_context_candidates()`]. The request's collective
+    /// is replaced with the handle's and
+    /// [`SearchFilter::include_descendants`] is ignored.
+    pub fn get_context_candidates(&self, mut request: ContextRequest) -> Result<ContextCandidates> {
+        self.require(self.capabilities.read, "read")?;
+        request.collective_id = self.collective_id;
+        request.filter.include_descendants = false;
+        self.db.get_context_candidates(request)
+    }
+
+    /// See [`PulseDB::get_insight()`]. `None` for insights in other
+    /// collectives.
+    pub fn get_insight(&self, id: InsightId) -> Result<Option<DerivedInsight>> {
+        self.require(self.capabilities.read, "read")?;
+        Ok(self
+            .db
+            .get_insight(id)?
+            .filter(|insight| insight.collective_id == self.collective_id))
+    }
+
+    /// See [`PulseDB::get_insights()`].
+    pub fn get_insights(&self, query: &[f32], k: usize) -> Result<Vec<(DerivedInsight, f32)>> {
+        self.require(self.capabilities.read, "read")?;
+        self.db.get_insights(self.collective_id, query, k)
+    }
+
+    // =========================================================================
+    // Writes
+    // =========================================================================
+
+    /// See [`PulseDB::record_experience()`].
+    pub fn record_experience(&self, mut exp: NewExperience) -> Result<ExperienceId> {
+        self.require(self.capabilities.write, "write")?;
+        exp.collective_id = self.collective_id;
+        self.db.record_experience(exp)
+    }
+
+    /// See [`PulseDB::update_experience()`].
+    pub fn update_experience(&self, id: ExperienceId, update: ExperienceUpdate) -> Result<()> {
+        self.require(self.capabilities.write, "write")?;
+        self.check_experience(id)?;
+        self.db.update_experience(id, update)
+    }
+
+    /// See [`PulseDB::archive_experience()`].
+    pub fn archive_experience(&self, id: ExperienceId) -> Result<()> {
+        self.require(self.capabilities.write, "write")?;
+        self.check_experience(id)?;
+        self.db.archive_experience(id)
+    }
+
+    /// See [`PulseDB::unarchive_experience()`].
+    pub fn unarchive_experience(&self, id: ExperienceId) -> Result<()> {
+        self.require(self.capabilities.write, "write")?;
+        self.check_experience(id)?;
+        self.db.unarchive_experience(id)
+    }
+
+    /// See [`PulseDB::reinforce_experience()`].
+    pub fn reinforce_experience(&self, id: ExperienceId) -> Result<u32> {
+        self.require(self.capabilities.write, "write")?;
+        self.check_experience(id)?;
+        self.db.reinforce_experience(id)
+    }
+
+    /// See [`PulseDB::store_relation()`]. Both ends must be in the
+    /// handle's collective.
+    pub fn store_relation(&self, relation: NewExperienceRelation) -> Result<RelationId> {
+        self.require(self.capabilities.write, "write")?;
+        self.check_experience(relation.source_id)?;
+        self.check_experience(relation.target_id)?;
+        self.db.store_relation(relation)
+    }
+
+    /// See [`PulseDB::store_insight()`].
+    pub fn store_insight(&self, mut insight: NewDerivedInsight) -> Result<InsightId> {
+        self.require(self.capabilities.write, "write")?;
+        insight.collective_id = self.collective_id;
+        self.db.store_insight(insight)
+    }
+
+    // =========================================================================
+    // Deletes
+    // =========================================================================
+
+    /// See [`PulseDB::delete_experience()`].
+    pub fn delete_experience(&self, id: ExperienceId) -> Result<()> {
+        self.require(self.capabilities.delete, "delete")?;
+        self.check_experience(id)?;
+        self.db.delete_experience(id)
+    }
+
+    /// See [`PulseDB::delete_relation()`].
+    pub fn delete_relation(&self, id: RelationId) -> Result<()> {
+        self.require(self.capabilities.delete, "delete")?;
+        let relation = self
+            .db
+            .get_relation(id)?
+            .ok_or_else(|| PulseDBError::from(NotFoundError::relation(id)))?;
+        self.check_experience(relation.source_id)
+            .map_err(|_| PulseDBError::from(NotFoundError::relation(id)))?;
+        self.db.delete_relation(id)
+    }
+
+    /// See [`PulseDB::delete_insight()`].
+    pub fn delete_insight(&self, id: InsightId) -> Result<()> {
+        self.require(self.capabilities.delete, "delete")?;
+        let in_scope = self
+            .db
+            .storage()
+            .get_insight(id)?
+            .is_some_and(|insight| insight.collective_id == self.collective_id);
+        if !in_scope {
+            return Err(NotFoundError::insight(id).into());
+        }
+        self.db.delete_insight(id)
+    }
+
+    // =========================================================================
+    // Checks
+    // =========================================================================
+
+    /// Fails with [`PulseDBError::PermissionDenied`] unless `allowed`.
+    fn require(&self, allowed: bool, capability: &str) -> Result<()> {
+        if allowed {
+            return Ok(());
+        }
+        Err(PulseDBError::permission_denied(format!(
+            "handle scoped to collective {} lacks the {} capability",
+            self.collective_id, capability
+        )))
+    }
+
+    /// Fails with [`NotFoundError::Experience`] unless the experience
+    /// exists in the handle's collective.
+    fn check_experience(&self, id: ExperienceId) -> Result<()> {
+        let in_scope = self
+            .db
+            .storage()
+            .get_experience(id)?
+            .is_some_and(|exp| exp.collective_id == self.collective_id);
+        if !in_scope {
+            return Err(NotFoundError::experience(id).into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+//! Scoped database handles.
+//!
+//! [`PulseDB::scoped()`](crate::PulseDB::scoped) returns a [`ScopedDb`]
+//! confined to one collective and a set of [`Capabilities`], for handing
+//! to plugins and tools that shouldn't see or change anything else.
+//!
+//! The handle exposes the experience, relation, insight, and search
+//! operations. Records in other collectives look as if they don't exist,
+//! and calls outside the capability set fail with
+//! [`PulseDBError::PermissionDenied`](crate::PulseDBError::PermissionDenied).
+//! Collective management, hooks, policies, and maintenance have no scoped
+//! form.
+
+mod handle;
+
+pub use handle::{Capabilities, ScopedDb};
@@ -0,0 +1,183 @@
+//! Supporting and contradicting evidence for a question.
+//!
+//! [`PulseDB::answer_support()`](crate::PulseDB::answer_support) gives an
+//! agent both sides of what the hive knows instead of a single top-k
+//! list. The most similar experiences anchor the supporting stance;
+//! relations then sort the rest: [`Supports`](crate::RelationType::Supports),
+//! [`Elaborates`](crate::RelationType::Elaborates), and
+//! [`Implies`](crate::RelationType::Implies) keep an experience on its
+//! neighbor's side, while [`Contradicts`](crate::RelationType::Contradicts)
+//! puts it on the other. Experiences one relation away from a search hit
+//! are pulled in even if the search missed them, so a contradiction is
+//! surfaced whenever one has been recorded.
+
+use serde::{Deserialize, Serialize};
+
+use crate::experience::{Experience, RenderStyle};
+use crate::relation::RelationType;
+
+/// Evidence for and against an answer, with aggregate confidence.
+///
+/// Returned by
+/// [`PulseDB::answer_support()`](crate::PulseDB::answer_support).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AnswerSupport {
+    /// Experiences on the side of the best search hit.
+    pub supporting: Stance,
+
+    /// Experiences that contradict the supporting side.
+    pub contradicting: Stance,
+}
+
+/// One side of the evidence.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Stance {
+    /// The experiences on this side, by descending weight.
+    pub evidence: Vec<Evidence>,
+
+    /// Probability that at least one piece of evidence holds, treating
+    /// each [`Evidence::weight`] as independent: `1 - Π(1 - weight)`.
+    /// 0.0 for an empty stance.
+    pub confidence: f32,
+}
+
+/// One experience counted toward a stance.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Evidence {
+    /// The experience.
+    pub experience: Experience,
+
+    /// Similarity to the query, or `None` if the experience was reached
+    /// only through a relation.
+    pub similarity: Option<f32>,
+
+    /// The relation that placed the experience on its side, or `None` for
+    /// a search hit that anchors its side.
+    pub via: Option<RelationType>,
+
+    /// How much the experience counts, in [0, 1]: its confidence scaled by
+    /// similarity, or by relation strength when reached through a
+    /// relation.
+    pub weight: f32,
+}
+
+impl Stance {
+    /// Builds a stance from its evidence, sorting it and computing the
+    /// aggregate confidence.
+    pub(crate) fn from_evidence(mut evidence: Vec<Evidence>) -> Self {
+        evidence.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+        let doubt: f32 = evidence.iter().map(|e| 1.0 - e.weight).product();
+        let confidence = if evidence.is_empty() {
+            0.0
+        } else {
+            1.0 - doubt
+        };
+        Self {
+            evidence,
+            confidence,
+        }
+    }
+}
+
+impl AnswerSupport {
+    /// Renders both stances as Markdown for an LLM prompt: a heading with
+    /// the aggregate confidence per stance, then one
+    /// [`Compact`](RenderStyle::Compact) line per experience with its
+    /// weight. An empty stance reads "(none)".
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (title, stance) in [
+            ("Supporting", &self.supporting),
+            ("Contradicting", &self.contradicting),
+        ] {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!(
+                "## {} (confidence {:.2})\n\n",
+                title, stance.confidence
+            ));
+            if stance.evidence.is_empty() {
+                out.push_str("(none)\n");
+            }
+            for evidence in &stance.evidence {
+                out.push_str(&format!(
+                    "- ({:.2}) {}\n",
+                    evidence.weight,
+                    evidence.experience.render_for_context(RenderStyle::Compact)
+                ));
+            }
+        }
+        out.truncate(out.trim_end().len());
+        out
+    }
+}
+
+/// The side a relation puts its far end on relative to the near end:
+/// `Some(true)` for the same side, `Some(false)` for the opposite side,
+/// `None` if the relation says nothing about agreement.
+pub(crate) fn same_side(relation_type: RelationType) -> Option<bool> {
+    match relation_type {
+        RelationType::Supports
+        | RelationType::Elaborates
+        | RelationType::Implies
+        | RelationType::Summarizes => Some(true),
+        RelationType::Contradicts => Some(false),
+        RelationType::Supersedes | RelationType::RelatedTo | RelationType::Answers => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::experience::ExperienceType;
+    use crate::types::{AgentId, CollectiveId, ExperienceId, Timestamp};
+
+    fn evidence(content: &str, weight: f32) -> Evidence {
+        Evidence {
+            experience: Experience {
+                id: ExperienceId::new(),
+                collective_id: CollectiveId::new(),
+                content: content.to_string(),
+                embedding: vec![],
+                experience_type: ExperienceType::default(),
+                importance: 0.5,
+                confidence: 0.5,
+                applications: 0,
+                domain: vec![],
+                related_files: vec![],
+                source_agent: AgentId::new("agent"),
+                source_task: None,
+                user_id: None,
+                timestamp: Timestamp::now(),
+                expires_at: None,
+                archived: false,
+                attribution: None,
+            },
+            similarity: None,
+            via: None,
+            weight,
+        }
+    }
+
+    #[test]
+    fn test_stance_confidence_is_noisy_or() {
+        let stance = Stance::from_evidence(vec![evidence("a", 0.5), evidence("b", 0.8)]);
+        assert!((stance.confidence - 0.9).abs() < 1e-6);
+        assert_eq!(stance.evidence[0].experience.content, "b");
+        assert_eq!(Stance::from_evidence(vec![]).confidence, 0.0);
+    }
+
+    #[test]
+    fn test_render_lists_both_stances() {
+        let support = AnswerSupport {
+            supporting: Stance::from_evidence(vec![evidence("pin the version", 0.6)]),
+            contradicting: Stance::default(),
+        };
+        assert_eq!(
+            support.render(),
+            "## Supporting (confidence 0.60)\n\n- (0.60) [Note] pin the version\n\n\
+             ## Contradicting (confidence 0.00)\n\n(none)"
+        );
+    }
+}
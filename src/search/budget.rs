@@ -0,0 +1,114 @@
+//! Context budget accounting.
+//!
+//! [`PulseDB::estimate_context_cost()`](crate::PulseDB::estimate_context_cost)
+//! measures a set of experiences and insights with a caller-supplied
+//! [`TokenCounter`] and returns only the counts, so prompt assembly can be
+//! planned against a token budget before any content is fetched.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ExperienceId, InsightId};
+
+/// Counts the tokens a piece of text costs in a prompt.
+///
+/// Implement it over the tokenizer of the target model. Closures
+/// `Fn(&str) -> usize` implement it too, and [`ApproxTokenCounter`] is a
+/// tokenizer-free fallback.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::TokenCounter;
+///
+/// let words = |text: &str| text.split_whitespace().count();
+/// assert_eq!(words.count_tokens("pin the dependency"), 3);
+/// ```
+pub trait TokenCounter: Send + Sync {
+    /// Returns the number of tokens in `text`.
+    fn count_tokens(&self, text: &str) -> usize;
+}
+
+impl<F> TokenCounter for F
+where
+    F: Fn(&str) -> usize + Send + Sync,
+{
+    fn count_tokens(&self, text: &str) -> usize {
+        self(text)
+    }
+}
+
+/// Estimates one token per four bytes of UTF-8, rounded up.
+///
+/// Close enough for English text with common BPE tokenizers; use a real
+/// tokenizer when the budget is tight.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ApproxTokenCounter;
+
+impl TokenCounter for ApproxTokenCounter {
+    fn count_tokens(&self, text: &str) -> usize {
+        text.len().div_ceil(4)
+    }
+}
+
+/// A record whose context cost is estimated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ContextItem {
+    /// An experience.
+    Experience(ExperienceId),
+
+    /// A derived insight.
+    Insight(InsightId),
+}
+
+impl From<ExperienceId> for ContextItem {
+    fn from(id: ExperienceId) -> Self {
+        Self::Experience(id)
+    }
+}
+
+impl From<InsightId> for ContextItem {
+    fn from(id: InsightId) -> Self {
+        Self::Insight(id)
+    }
+}
+
+/// Cost of one record's content.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemCost {
+    /// The record measured.
+    pub item: ContextItem,
+
+    /// Tokens in the content, per the [`TokenCounter`].
+    pub tokens: usize,
+
+    /// Content size in UTF-8 bytes.
+    pub bytes: usize,
+}
+
+/// Output of [`PulseDB::estimate_context_cost()`](crate::PulseDB::estimate_context_cost).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextCost {
+    /// Per-record costs, in request order. Records not found are left out.
+    pub items: Vec<ItemCost>,
+
+    /// Sum of `tokens` over `items`.
+    pub total_tokens: usize,
+
+    /// Sum of `bytes` over `items`.
+    pub total_bytes: usize,
+
+    /// Requested records that don't exist.
+    pub missing: Vec<ContextItem>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approx_counter_rounds_up() {
+        assert_eq!(ApproxTokenCounter.count_tokens(""), 0);
+        assert_eq!(ApproxTokenCounter.count_tokens("abcd"), 1);
+        assert_eq!(ApproxTokenCounter.count_tokens("abcde"), 2);
+    }
+}
@@ -0,0 +1,109 @@
+//! Knowledge relevant to a set of changed files.
+//!
+//! [`PulseDB::knowledge_for_changeset()`](crate::PulseDB::knowledge_for_changeset)
+//! answers "what do we know about the files I'm about to touch?" in one
+//! call. Experiences are found through their
+//! [`related_files`](crate::Experience::related_files) and ranked by how
+//! closely those files match the changeset; relations and insights are
+//! then gathered around the experiences found.
+
+use serde::{Deserialize, Serialize};
+
+use crate::experience::Experience;
+use crate::insight::DerivedInsight;
+use crate::relation::ExperienceRelation;
+
+/// Match weight of a related file that is one of the changed files.
+pub(crate) const EXACT_FILE_WEIGHT: f32 = 1.0;
+
+/// Match weight of a related file in the same directory as a changed file.
+pub(crate) const SIBLING_FILE_WEIGHT: f32 = 0.5;
+
+/// Knowledge about a changeset, most relevant first.
+///
+/// Returned by
+/// [`PulseDB::knowledge_for_changeset()`](crate::PulseDB::knowledge_for_changeset).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ChangesetKnowledge {
+    /// Experiences whose related files match the changeset, by descending
+    /// score.
+    pub experiences: Vec<FileExperience>,
+
+    /// Relations touching any returned experience, by descending strength.
+    pub relations: Vec<ExperienceRelation>,
+
+    /// Insights citing any returned experience, by descending score.
+    pub insights: Vec<FileInsight>,
+}
+
+/// An experience matched through its related files.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FileExperience {
+    /// The experience.
+    pub experience: Experience,
+
+    /// The changed files it matched, exactly or by directory.
+    pub matched_files: Vec<String>,
+
+    /// Relevance: the summed match weight over changed files (1.0 for the
+    /// file itself, 0.5 for a file in the same directory), scaled by the
+    /// mean of importance and confidence.
+    pub score: f32,
+}
+
+/// An insight reached through the experiences it cites.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FileInsight {
+    /// The insight.
+    pub insight: DerivedInsight,
+
+    /// Relevance: the summed score of its cited experiences, scaled by its
+    /// confidence.
+    pub score: f32,
+}
+
+/// Strips a leading `./` and trailing `/`, so paths from different tools
+/// compare equal.
+pub(crate) fn normalize_path(path: &str) -> &str {
+    path.trim_start_matches("./").trim_end_matches('/')
+}
+
+/// Returns the directory part of a path including its trailing `/`, or
+/// `None` for a top-level file.
+pub(crate) fn parent_dir(path: &str) -> Option<&str> {
+    path.rfind('/').map(|slash| &path[..=slash])
+}
+
+/// Weight of a related file against one changed file: exact, sibling, or
+/// no match.
+pub(crate) fn match_weight(changed: &str, related: &str) -> f32 {
+    let related = normalize_path(related);
+    if related == changed {
+        EXACT_FILE_WEIGHT
+    } else if parent_dir(changed).is_some() && parent_dir(related) == parent_dir(changed) {
+        SIBLING_FILE_WEIGHT
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_weight() {
+        assert_eq!(match_weight("src/db.rs", "./src/db.rs"), EXACT_FILE_WEIGHT);
+        assert_eq!(match_weight("src/db.rs", "src/lib.rs"), SIBLING_FILE_WEIGHT);
+        assert_eq!(match_weight("src/db.rs", "src/search/mod.rs"), 0.0);
+        // Top-level files are not siblings of each other
+        assert_eq!(match_weight("README.md", "Cargo.toml"), 0.0);
+    }
+
+    #[test]
+    fn test_parent_dir() {
+        assert_eq!(parent_dir("src/search/mod.rs"), Some("src/search/"));
+        assert_eq!(parent_dir("README.md"), None);
+        assert_eq!(normalize_path("./src/"), "src");
+    }
+}
@@ -5,12 +5,16 @@
 //! that orchestrates all retrieval primitives (similarity search, recent
 //! experiences, insights, relations, active agents) into one response.
 
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
 use crate::activity::Activity;
-use crate::experience::Experience;
+use crate::experience::{Experience, RenderStyle};
 use crate::insight::DerivedInsight;
 use crate::relation::ExperienceRelation;
 use crate::search::{SearchFilter, SearchResult};
-use crate::types::CollectiveId;
+use crate::types::{CollectiveId, InsightId};
 
 /// Request for unified context retrieval.
 ///
@@ -74,7 +78,20 @@ pub struct ContextRequest {
     pub include_active_agents: bool,
 
     /// Filter criteria applied to similar and recent experience queries.
+    /// Its [`min_similarity`](SearchFilter::min_similarity) cutoff also
+    /// applies to insights.
     pub filter: SearchFilter,
+
+    /// Fold source experiences under the insights that cite them
+    /// (default: false).
+    ///
+    /// When `true`, any similar or recent experience listed in a returned
+    /// insight's `source_experience_ids` is removed from
+    /// `similar_experiences`/`recent_experiences` and attached to that
+    /// insight in [`ContextCandidates::insight_sources`] instead, so the
+    /// agent's context budget isn't spent on the same knowledge twice.
+    /// Has no effect unless `include_insights` is set.
+    pub collapse_insight_sources: bool,
 }
 
 impl Default for ContextRequest {
@@ -88,6 +105,7 @@ impl Default for ContextRequest {
             include_relations: true,
             include_active_agents: true,
             filter: SearchFilter::default(),
+            collapse_insight_sources: false,
         }
     }
 }
@@ -105,7 +123,8 @@ impl Default for ContextRequest {
 /// - `insights` - Similar insights found via HNSW vector search
 /// - `relations` - Relations involving any returned experience (deduplicated)
 /// - `active_agents` - Non-stale agents in the collective
-#[derive(Clone, Debug)]
+/// - `insight_sources` - Experiences folded under insights (collapse only)
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ContextCandidates {
     /// Semantically similar experiences, sorted by similarity descending.
     pub similar_experiences: Vec<SearchResult>,
@@ -127,6 +146,47 @@ pub struct ContextCandidates {
     ///
     /// Empty if `include_active_agents` was `false` in the request.
     pub active_agents: Vec<Activity>,
+
+    /// Source experiences collapsed under returned insights, keyed by insight.
+    ///
+    /// Each experience appears under the first insight (in `insights` order)
+    /// that cites it, in the order it would otherwise have been returned.
+    /// Empty unless `collapse_insight_sources` was set in the request.
+    pub insight_sources: HashMap<InsightId, Vec<Experience>>,
+}
+
+impl ContextCandidates {
+    /// Renders the experiences and insights as prompt text.
+    ///
+    /// Similar experiences come first, followed by recent experiences not
+    /// already listed, then insights. Each experience is rendered with
+    /// [`Experience::render_for_context()`]; entries are separated by a
+    /// blank line in [`RenderStyle::Markdown`] and a newline otherwise.
+    pub fn render(&self, style: RenderStyle) -> String {
+        let mut seen = HashSet::new();
+        let mut entries: Vec<String> = self
+            .similar_experiences
+            .iter()
+            .map(|result| &result.experience)
+            .chain(&self.recent_experiences)
+            .filter(|exp| seen.insert(exp.id))
+            .map(|exp| exp.render_for_context(style))
+            .collect();
+
+        entries.extend(self.insights.iter().map(|insight| match style {
+            RenderStyle::Compact => format!("[Insight] {}", insight.content),
+            RenderStyle::Markdown => format!(
+                "### Insight\n\n{}\n\n- Confidence: {:.2}",
+                insight.content, insight.confidence
+            ),
+        }));
+
+        let separator = match style {
+            RenderStyle::Compact => "\n",
+            RenderStyle::Markdown => "\n\n",
+        };
+        entries.join(separator)
+    }
 }
 
 #[cfg(test)]
@@ -144,6 +204,7 @@ mod tests {
         assert!(req.include_relations);
         assert!(req.include_active_agents);
         assert!(req.filter.exclude_archived);
+        assert!(!req.collapse_insight_sources);
     }
 
     #[test]
@@ -170,6 +231,7 @@ mod tests {
             insights: vec![],
             relations: vec![],
             active_agents: vec![],
+            insight_sources: HashMap::new(),
         };
         let cloned = candidates.clone();
         assert!(cloned.similar_experiences.is_empty());
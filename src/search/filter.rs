@@ -6,6 +6,7 @@
 //! or HNSW search).
 
 use crate::experience::{Experience, ExperienceType};
+use crate::storage::ExperienceMeta;
 use crate::types::Timestamp;
 
 /// Filter criteria for experience search operations.
@@ -52,8 +53,51 @@ pub struct SearchFilter {
     /// Only include experiences created at or after this timestamp.
     pub since: Option<Timestamp>,
 
+    /// Only include experiences created before this timestamp.
+    ///
+    /// Together with `since`, recent queries read only the matching day
+    /// buckets of the recency index instead of the whole collective.
+    pub until: Option<Timestamp>,
+
     /// Whether to exclude archived experiences (default: `true`).
     pub exclude_archived: bool,
+
+    /// Only include experiences attributed to one of these models, matched
+    /// on [`ModelAttribution::model_name`](crate::ModelAttribution::model_name).
+    ///
+    /// Experiences without attribution never match. `None` means no model filtering.
+    pub models: Option<Vec<String>>,
+
+    /// Only include experiences whose attribution names one of these tools.
+    ///
+    /// Experiences without a recorded tool never match. `None` means no tool filtering.
+    pub tools: Option<Vec<String>>,
+
+    /// Whether to also search the collective's sub-collectives (default: `false`).
+    ///
+    /// When `true`, similarity and recent queries cover the whole subtree
+    /// rooted at the queried collective and merge the results. This is a
+    /// scope setting, not a per-experience criterion, so [`matches()`](Self::matches)
+    /// ignores it.
+    pub include_descendants: bool,
+
+    /// Only include results scoring at least this similarity, on the scale
+    /// of [`Config::score_kind`](crate::Config::score_kind).
+    ///
+    /// Applied to the ranked candidates before any record is read, and the
+    /// search stops at the first candidate below it. The index is asked for
+    /// `k` candidates first and only over-fetched if all of them pass, so a
+    /// tight cutoff does less work than a large `k`.
+    ///
+    /// Honored by every search that takes a filter:
+    /// [`search_similar_filtered()`](crate::PulseDB::search_similar_filtered),
+    /// [`search_similar_scoped()`](crate::PulseDB::search_similar_scoped),
+    /// [`search_across_collectives()`](crate::PulseDB::search_across_collectives),
+    /// [`Query`](crate::Query), and the similar experiences and insights of
+    /// [`get_context_candidates()`](crate::PulseDB::get_context_candidates).
+    /// Recent-experience listings have no similarity and ignore it; like
+    /// `include_descendants`, [`matches()`](Self::matches) ignores it too.
+    pub min_similarity: Option<f32>,
 }
 
 impl Default for SearchFilter {
@@ -64,11 +108,106 @@ impl Default for SearchFilter {
             min_importance: None,
             min_confidence: None,
             since: None,
+            until: None,
             exclude_archived: true,
+            models: None,
+            tools: None,
+            include_descendants: false,
+            min_similarity: None,
         }
     }
 }
 
+/// Chainable setters, used by [`Query::filter()`](crate::Query::filter).
+///
+/// ```rust
+/// use pulsedb::{ExperienceType, SearchFilter};
+///
+/// let filter = SearchFilter::default()
+///     .types([ExperienceType::default()])
+///     .min_importance(0.5);
+/// assert_eq!(filter.min_importance, Some(0.5));
+/// ```
+impl SearchFilter {
+    /// Keeps experiences with at least one of these domain tags.
+    pub fn domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.domains = Some(domains.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Keeps experiences of these types, matched on the variant only.
+    pub fn types(mut self, types: impl IntoIterator<Item = ExperienceType>) -> Self {
+        self.experience_types = Some(types.into_iter().collect());
+        self
+    }
+
+    /// Keeps experiences with importance >= `min`.
+    pub fn min_importance(mut self, min: f32) -> Self {
+        self.min_importance = Some(min);
+        self
+    }
+
+    /// Keeps experiences with confidence >= `min`.
+    pub fn min_confidence(mut self, min: f32) -> Self {
+        self.min_confidence = Some(min);
+        self
+    }
+
+    /// Keeps experiences created at or after `since`.
+    pub fn since(mut self, since: Timestamp) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Keeps experiences created before `until`.
+    pub fn until(mut self, until: Timestamp) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Includes archived experiences.
+    pub fn include_archived(mut self) -> Self {
+        self.exclude_archived = false;
+        self
+    }
+
+    /// Keeps experiences attributed to one of these models.
+    pub fn models<I, S>(mut self, models: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.models = Some(models.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Keeps experiences whose attribution names one of these tools.
+    pub fn tools<I, S>(mut self, tools: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tools = Some(tools.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Also searches the collective's sub-collectives.
+    pub fn with_descendants(mut self) -> Self {
+        self.include_descendants = true;
+        self
+    }
+
+    /// Keeps similarity results scoring at least `min`.
+    pub fn min_similarity(mut self, min: f32) -> Self {
+        self.min_similarity = Some(min);
+        self
+    }
+}
+
 impl SearchFilter {
     /// Returns `true` if the given experience passes all filter criteria.
     pub fn matches(&self, experience: &Experience) -> bool {
@@ -111,13 +250,62 @@ impl SearchFilter {
             }
         }
 
-        // Check timestamp
+        // Check timestamp range
         if let Some(since) = self.since {
             if experience.timestamp < since {
                 return false;
             }
         }
+        if let Some(until) = self.until {
+            if experience.timestamp >= until {
+                return false;
+            }
+        }
 
+        // Check model attribution
+        let attribution = experience.attribution.as_ref();
+        if let Some(ref models) = self.models {
+            let has_match = attribution.is_some_and(|a| models.contains(&a.model_name));
+            if !has_match {
+                return false;
+            }
+        }
+        if let Some(ref tools) = self.tools {
+            let has_match = attribution
+                .and_then(|a| a.tool.as_ref())
+                .is_some_and(|tool| tools.contains(tool));
+            if !has_match {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns `false` if the experience's hot metadata already rules it out.
+    ///
+    /// Checks archived status, type, score thresholds, and time range — the
+    /// criteria answerable from the metadata sidecar. A `true` result still
+    /// needs [`matches()`](Self::matches) on the full record.
+    pub(crate) fn matches_meta(&self, meta: &ExperienceMeta) -> bool {
+        if self.exclude_archived && meta.archived {
+            return false;
+        }
+        if let Some(ref types) = self.experience_types {
+            if !types.iter().any(|t| t.type_tag() == meta.type_tag) {
+                return false;
+            }
+        }
+        if self.min_importance.is_some_and(|min| meta.importance < min)
+            || self.min_confidence.is_some_and(|min| meta.confidence < min)
+        {
+            return false;
+        }
+        if self.since.is_some_and(|since| meta.timestamp < since)
+            || self.until.is_some_and(|until| meta.timestamp >= until)
+        {
+            return false;
+        }
         true
     }
 }
@@ -125,6 +313,7 @@ impl SearchFilter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::experience::ModelAttribution;
     use crate::types::{AgentId, CollectiveId, ExperienceId};
 
     /// Helper to create a minimal test experience.
@@ -145,8 +334,11 @@ mod tests {
             related_files: vec![],
             source_agent: AgentId::new("agent-1"),
             source_task: None,
+            user_id: None,
             timestamp: Timestamp::now(),
+            expires_at: None,
             archived: false,
+            attribution: None,
         }
     }
 
@@ -154,6 +346,7 @@ mod tests {
     fn test_default_filter_excludes_archived() {
         let filter = SearchFilter::default();
         assert!(filter.exclude_archived);
+        assert!(!filter.include_descendants);
 
         let mut exp = test_experience();
         assert!(filter.matches(&exp));
@@ -244,6 +437,22 @@ mod tests {
         assert!(filter.matches(&exp));
     }
 
+    #[test]
+    fn test_until_filter() {
+        let exp = test_experience();
+        let filter = SearchFilter {
+            until: Some(exp.timestamp),
+            ..SearchFilter::default()
+        };
+        assert!(!filter.matches(&exp));
+
+        let filter = SearchFilter {
+            until: Some(Timestamp::from_millis(exp.timestamp.as_millis() + 1)),
+            ..SearchFilter::default()
+        };
+        assert!(filter.matches(&exp));
+    }
+
     #[test]
     fn test_combined_filters() {
         let filter = SearchFilter {
@@ -257,4 +466,64 @@ mod tests {
         let exp = test_experience(); // domain: ["rust", "testing"], importance: 0.5, confidence: 0.8
         assert!(filter.matches(&exp));
     }
+
+    #[test]
+    fn test_attribution_filters() {
+        let by_model = SearchFilter {
+            models: Some(vec!["gpt-4o".to_string()]),
+            ..SearchFilter::default()
+        };
+        let by_tool = SearchFilter {
+            tools: Some(vec!["planner".to_string()]),
+            ..SearchFilter::default()
+        };
+
+        // Unattributed experiences never match
+        let mut exp = test_experience();
+        assert!(!by_model.matches(&exp));
+        assert!(!by_tool.matches(&exp));
+
+        exp.attribution = Some(ModelAttribution {
+            model_name: "gpt-4o".to_string(),
+            ..Default::default()
+        });
+        assert!(by_model.matches(&exp));
+        assert!(!by_tool.matches(&exp));
+
+        exp.attribution = Some(ModelAttribution {
+            model_name: "claude-sonnet".to_string(),
+            tool: Some("planner".to_string()),
+            ..Default::default()
+        });
+        assert!(!by_model.matches(&exp));
+        assert!(by_tool.matches(&exp));
+    }
+
+    #[test]
+    fn test_meta_prescreen_agrees_with_matches() {
+        let mut exp = test_experience();
+        let filters = [
+            SearchFilter::default(),
+            SearchFilter {
+                min_importance: Some(0.6),
+                ..SearchFilter::default()
+            },
+            SearchFilter {
+                min_confidence: Some(0.7),
+                experience_types: Some(vec![ExperienceType::Generic { category: None }]),
+                ..SearchFilter::default()
+            },
+            SearchFilter {
+                until: Some(Timestamp::from_millis(0)),
+                ..SearchFilter::default()
+            },
+        ];
+        for archived in [false, true] {
+            exp.archived = archived;
+            let meta = ExperienceMeta::of(&exp);
+            for filter in &filters {
+                assert_eq!(filter.matches_meta(&meta), filter.matches(&exp));
+            }
+        }
+    }
 }
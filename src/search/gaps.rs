@@ -0,0 +1,170 @@
+//! Knowledge gaps: searches the collective couldn't answer.
+//!
+//! With [`Config::knowledge_gaps`](crate::Config::knowledge_gaps) set,
+//! each similarity search whose best result scores below the threshold is
+//! stored as a [`KnowledgeGapRecord`]. Agents tend to ask the same missing
+//! question many ways, so
+//! [`list_knowledge_gaps()`](crate::PulseDB::list_knowledge_gaps) groups the
+//! records into [`KnowledgeGap`]s by embedding similarity, most repeated
+//! first — a ranked list of what the collective should learn next.
+
+use serde::{Deserialize, Serialize};
+
+use crate::search::normalized;
+use crate::types::{CollectiveId, Timestamp};
+
+/// One search whose nearest experience scored below the gap threshold.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KnowledgeGapRecord {
+    /// The collective that was searched.
+    pub collective_id: CollectiveId,
+
+    /// The query text, for searches made through
+    /// [`search_text()`](crate::PulseDB::search_text).
+    pub query: Option<String>,
+
+    /// The query embedding.
+    pub embedding: Vec<f32>,
+
+    /// Score of the nearest indexed experience, before filters, or `None`
+    /// if the index was empty.
+    pub best_score: Option<f32>,
+
+    /// When the search ran.
+    pub recorded_at: Timestamp,
+}
+
+/// Repeated searches for the same missing knowledge.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KnowledgeGap {
+    /// Distinct query texts in the gap, most recent first. Empty if every
+    /// search was made with an embedding alone.
+    pub queries: Vec<String>,
+
+    /// Number of searches that hit the gap.
+    pub occurrences: usize,
+
+    /// Unit-length centroid of the searches' embeddings; search with it to
+    /// check whether the gap has been filled.
+    pub embedding: Vec<f32>,
+
+    /// Best score any of the searches' nearest experiences got, or `None`
+    /// if none found anything.
+    pub best_score: Option<f32>,
+
+    /// When the gap was first hit.
+    pub first_seen: Timestamp,
+
+    /// When the gap was last hit.
+    pub last_seen: Timestamp,
+}
+
+/// Groups records (oldest first) into gaps: each record joins the gap
+/// whose centroid it is most similar to, if at least `cluster_similarity`,
+/// or starts a new one. Gaps are returned most occurrences first, then
+/// most recently hit.
+pub(crate) fn cluster_gaps(
+    records: Vec<KnowledgeGapRecord>,
+    cluster_similarity: f32,
+) -> Vec<KnowledgeGap> {
+    let dot = |a: &[f32], b: &[f32]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+
+    // Running embedding sums alongside each gap
+    let mut gaps: Vec<(Vec<f32>, KnowledgeGap)> = Vec::new();
+    for record in records {
+        let embedding = normalized(&record.embedding);
+        let nearest = gaps
+            .iter()
+            .enumerate()
+            .map(|(i, (_, gap))| (i, dot(&embedding, &gap.embedding)))
+            .filter(|&(_, similarity)| similarity >= cluster_similarity)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i);
+
+        match nearest {
+            Some(i) => {
+                let (sum, gap) = &mut gaps[i];
+                for (total, v) in sum.iter_mut().zip(&embedding) {
+                    *total += v;
+                }
+                gap.embedding = normalized(sum);
+                gap.occurrences += 1;
+                gap.last_seen = record.recorded_at;
+                gap.best_score = match (gap.best_score, record.best_score) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    (a, b) => a.or(b),
+                };
+                if let Some(query) = record.query {
+                    gap.queries.retain(|q| *q != query);
+                    gap.queries.insert(0, query);
+                }
+            }
+            None => gaps.push((
+                embedding.clone(),
+                KnowledgeGap {
+                    queries: record.query.into_iter().collect(),
+                    occurrences: 1,
+                    embedding,
+                    best_score: record.best_score,
+                    first_seen: record.recorded_at,
+                    last_seen: record.recorded_at,
+                },
+            )),
+        }
+    }
+
+    let mut gaps: Vec<KnowledgeGap> = gaps.into_iter().map(|(_, gap)| gap).collect();
+    gaps.sort_by(|a, b| {
+        b.occurrences
+            .cmp(&a.occurrences)
+            .then(b.last_seen.cmp(&a.last_seen))
+    });
+    gaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(query: &str, embedding: Vec<f32>, at: i64) -> KnowledgeGapRecord {
+        KnowledgeGapRecord {
+            collective_id: CollectiveId::nil(),
+            query: Some(query.to_string()),
+            embedding,
+            best_score: Some(0.1),
+            recorded_at: Timestamp::from_millis(at),
+        }
+    }
+
+    #[test]
+    fn test_cluster_gaps_groups_similar_queries() {
+        let records = vec![
+            record("deploy key?", vec![1.0, 0.0], 1),
+            record("who rotates certs?", vec![0.0, 1.0], 2),
+            record("where is the deploy key", vec![0.99, 0.05], 3),
+            record("deploy key?", vec![1.0, 0.01], 4),
+        ];
+        let gaps = cluster_gaps(records, 0.9);
+        assert_eq!(gaps.len(), 2);
+
+        assert_eq!(gaps[0].occurrences, 3);
+        assert_eq!(
+            gaps[0].queries,
+            vec!["deploy key?", "where is the deploy key"]
+        );
+        assert_eq!(gaps[0].first_seen, Timestamp::from_millis(1));
+        assert_eq!(gaps[0].last_seen, Timestamp::from_millis(4));
+        assert_eq!(gaps[1].occurrences, 1);
+        assert_eq!(gaps[1].queries, vec!["who rotates certs?"]);
+    }
+
+    #[test]
+    fn test_cluster_gaps_keeps_best_score() {
+        let mut first = record("q", vec![1.0, 0.0], 1);
+        first.best_score = None;
+        let mut second = record("q", vec![1.0, 0.0], 2);
+        second.best_score = Some(0.3);
+        let gaps = cluster_gaps(vec![first, second], 0.9);
+        assert_eq!(gaps[0].best_score, Some(0.3));
+    }
+}
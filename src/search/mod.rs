@@ -3,13 +3,33 @@
 //! This module provides search filtering and query building for experience
 //! retrieval operations (recent, similarity, context candidates).
 
+pub(crate) mod answer;
+mod budget;
+pub(crate) mod changeset;
 mod context;
 mod filter;
+pub(crate) mod gaps;
+mod query;
+mod rerank;
+mod reverse;
 
+pub use answer::{AnswerSupport, Evidence, Stance};
+pub use budget::{ApproxTokenCounter, ContextCost, ContextItem, ItemCost, TokenCounter};
+pub use changeset::{ChangesetKnowledge, FileExperience, FileInsight};
 pub use context::{ContextCandidates, ContextRequest};
 pub use filter::SearchFilter;
+pub(crate) use gaps::cluster_gaps;
+pub use gaps::{KnowledgeGap, KnowledgeGapRecord};
+pub use query::{Query, QueryExplain, QueryResults};
+pub use rerank::{Reranker, RERANK_OVER_FETCH};
+pub(crate) use reverse::{bisector, normalized, select_diverse};
+pub use reverse::{RepresentativeQuery, REPRESENTATIVE_QUERY_DEPTH};
 
+use serde::{Deserialize, Serialize};
+
+use crate::config::ScoreKind;
 use crate::experience::Experience;
+use crate::types::ExperienceId;
 
 /// A search result pairing an experience with its similarity score.
 ///
@@ -19,9 +39,12 @@ use crate::experience::Experience;
 ///
 /// # Similarity Score
 ///
-/// The `similarity` field is computed as `1.0 - cosine_distance`, where
-/// cosine distance ranges from 0.0 (identical) to 2.0 (opposite). This
-/// gives a similarity range of [-1.0, 1.0], where:
+/// `distance` is the cosine distance from the query, from 0.0 (identical)
+/// to 2.0 (opposite). `similarity` is derived from it as configured by
+/// [`Config::score_kind`](crate::Config::score_kind), and `score_kind`
+/// records which conversion was used. With the default
+/// [`ScoreKind::Cosine`], `similarity` is `1.0 - distance`, in
+/// [-1.0, 1.0], where:
 /// - `1.0` = identical vectors
 /// - `0.0` = orthogonal vectors
 /// - `-1.0` = opposite vectors
@@ -48,15 +71,36 @@ use crate::experience::Experience;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SearchResult {
     /// The full experience record.
     pub experience: Experience,
 
-    /// Similarity score (1.0 - cosine_distance).
+    /// Similarity score, derived from `distance` as `score_kind` says.
     ///
-    /// Higher is more similar. Typically in [0.0, 1.0] for transformer
-    /// embeddings. Theoretical range is [-1.0, 1.0].
+    /// Higher is more similar.
+    pub similarity: f32,
+
+    /// Cosine distance between the query and the experience's embedding.
+    pub distance: f32,
+
+    /// How `similarity` was derived from `distance`.
+    pub score_kind: ScoreKind,
+}
+
+/// One edge of the precomputed experience similarity graph.
+///
+/// Returned by [`PulseDB::get_experience_neighbors()`](crate::PulseDB::get_experience_neighbors)
+/// after [`PulseDB::compute_neighbor_graph()`](crate::PulseDB::compute_neighbor_graph)
+/// has run.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExperienceNeighbor {
+    /// The neighboring experience.
+    pub experience_id: ExperienceId,
+
+    /// Similarity to the experience, scored per
+    /// [`Config::score_kind`](crate::Config::score_kind) when the graph
+    /// was computed.
     pub similarity: f32,
 }
 
@@ -82,10 +126,15 @@ mod tests {
                 related_files: vec![],
                 source_agent: AgentId::new("agent-1"),
                 source_task: None,
+                user_id: None,
                 timestamp: Timestamp::now(),
+                expires_at: None,
                 archived: false,
+                attribution: None,
             },
             similarity,
+            distance: 1.0 - similarity,
+            score_kind: ScoreKind::Cosine,
         }
     }
 
@@ -0,0 +1,160 @@
+//! Fluent query builder.
+//!
+//! [`Query`] collects search options step by step and compiles down to the
+//! existing search paths when [`run()`](Query::run) is called. New options
+//! land as new builder methods, so call sites keep compiling as the set
+//! grows.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::PulseDB;
+use crate::error::{Result, ValidationError};
+use crate::search::{SearchFilter, SearchResult};
+use crate::types::CollectiveId;
+
+/// Default number of results returned by [`Query::run()`].
+const DEFAULT_LIMIT: usize = 10;
+
+/// A search being built against one collective.
+///
+/// Created by [`PulseDB::query()`].
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> pulsedb::Result<()> {
+/// # let dir = tempfile::tempdir().unwrap();
+/// # let db = pulsedb::PulseDB::open(dir.path().join("test.db"), pulsedb::Config::default())?;
+/// # let collective_id = db.create_collective("example")?;
+/// # let query_embedding = vec![0.1f32; 384];
+/// use pulsedb::ExperienceType;
+///
+/// let results = db
+///     .query(collective_id)
+///     .similar_to(query_embedding)
+///     .filter(|f| f.types([ExperienceType::default()]).min_importance(0.5))
+///     .limit(10)
+///     .with_explain()
+///     .run()?;
+/// if let Some(explain) = &results.explain {
+///     println!("{} candidates, {:?}", explain.candidates, explain.elapsed);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "a query does nothing until run() is called"]
+pub struct Query<'db> {
+    db: &'db PulseDB,
+    collective_id: CollectiveId,
+    embedding: Option<Vec<f32>>,
+    filter: SearchFilter,
+    limit: usize,
+    explain: bool,
+}
+
+impl<'db> Query<'db> {
+    pub(crate) fn new(db: &'db PulseDB, collective_id: CollectiveId) -> Self {
+        Self {
+            db,
+            collective_id,
+            embedding: None,
+            filter: SearchFilter::default(),
+            limit: DEFAULT_LIMIT,
+            explain: false,
+        }
+    }
+
+    /// Ranks experiences by similarity to this embedding. Required.
+    pub fn similar_to(mut self, embedding: impl Into<Vec<f32>>) -> Self {
+        self.embedding = Some(embedding.into());
+        self
+    }
+
+    /// Refines the filter with the [`SearchFilter`] setters.
+    ///
+    /// Calls compose: each receives the filter built so far.
+    pub fn filter(mut self, f: impl FnOnce(SearchFilter) -> SearchFilter) -> Self {
+        self.filter = f(self.filter);
+        self
+    }
+
+    /// Sets the maximum number of results (1-1000, default 10).
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Reports how the query ran in [`QueryResults::explain`].
+    pub fn with_explain(mut self) -> Self {
+        self.explain = true;
+        self
+    }
+
+    /// Runs the query.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::RequiredField`] if [`similar_to()`](Self::similar_to)
+    ///   wasn't called
+    /// - Everything [`PulseDB::search_similar_filtered()`] returns
+    pub fn run(self) -> Result<QueryResults> {
+        let embedding = self
+            .embedding
+            .ok_or_else(|| ValidationError::required_field("similar_to"))?;
+        let mut explain = self.explain.then(QueryExplain::default);
+        let results = self.db.search_similar_explained(
+            self.collective_id,
+            &embedding,
+            self.limit,
+            self.filter,
+            explain.as_mut(),
+            None,
+        )?;
+        Ok(QueryResults { results, explain })
+    }
+}
+
+/// Output of [`Query::run()`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryResults {
+    /// Matching experiences, most similar first.
+    pub results: Vec<SearchResult>,
+
+    /// How the query ran. `Some` only if [`Query::with_explain()`] was set.
+    pub explain: Option<QueryExplain>,
+}
+
+/// How a query ran, from [`Query::with_explain()`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueryExplain {
+    /// Collectives whose indexes were searched (more than one with
+    /// [`SearchFilter::include_descendants`]).
+    pub collectives_searched: usize,
+
+    /// Candidates requested from each index (the limit, over-fetched to
+    /// make up for filtering unless a [`SearchFilter::min_similarity`]
+    /// cutoff already fell within the limit).
+    pub over_fetch: usize,
+
+    /// `ef_search` used for HNSW traversal.
+    pub ef_search: usize,
+
+    /// Candidates the vector indexes returned.
+    pub candidates: usize,
+
+    /// Candidates cut for scoring below
+    /// [`SearchFilter::min_similarity`], before any record was read.
+    pub below_min_similarity: usize,
+
+    /// Candidates dropped by the metadata prescreen, before any record
+    /// was read.
+    pub prescreened_out: usize,
+
+    /// Candidates dropped after reading the full record.
+    pub filtered_out: usize,
+
+    /// Wall-clock time spent in the search.
+    pub elapsed: Duration,
+}
@@ -0,0 +1,48 @@
+//! Reranking of similarity search results.
+//!
+//! [`PulseDB::search_similar_reranked()`](crate::PulseDB::search_similar_reranked)
+//! over-fetches candidates from the vector index and hands them to a
+//! caller-supplied [`Reranker`] — typically a cross-encoder, which scores
+//! each (query, content) pair jointly and orders results better than
+//! embedding similarity alone, but is too slow to run over a whole
+//! collective.
+
+use crate::error::Result;
+use crate::search::SearchResult;
+
+/// Candidates fetched per requested result before reranking.
+pub const RERANK_OVER_FETCH: usize = 4;
+
+/// Reorders search candidates for a query.
+///
+/// Implementations return the candidates best first. They may drop
+/// candidates, and may overwrite `similarity` with their own score.
+/// Closures `Fn(&str, Vec<SearchResult>) -> Result<Vec<SearchResult>>`
+/// implement it too.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::{Reranker, SearchResult};
+///
+/// // Prefer shorter content among candidates
+/// let shortest_first = |_query: &str, mut candidates: Vec<SearchResult>| {
+///     candidates.sort_by_key(|r| r.experience.content.len());
+///     Ok(candidates)
+/// };
+/// assert!(shortest_first.rerank("query", Vec::new())?.is_empty());
+/// # Ok::<(), pulsedb::PulseDBError>(())
+/// ```
+pub trait Reranker: Send + Sync {
+    /// Returns `candidates` reordered for `query`, best first.
+    fn rerank(&self, query: &str, candidates: Vec<SearchResult>) -> Result<Vec<SearchResult>>;
+}
+
+impl<F> Reranker for F
+where
+    F: Fn(&str, Vec<SearchResult>) -> Result<Vec<SearchResult>> + Send + Sync,
+{
+    fn rerank(&self, query: &str, candidates: Vec<SearchResult>) -> Result<Vec<SearchResult>> {
+        self(query, candidates)
+    }
+}
@@ -0,0 +1,109 @@
+//! Reverse similarity: the queries an experience answers.
+//!
+//! [`PulseDB::representative_queries()`](crate::PulseDB::representative_queries)
+//! answers "when will this memory fire?" for curation tools. Each topic an
+//! experience shares with a neighbor is represented by the bisector of
+//! their embeddings — a query that sits between the two — and the most
+//! mutually distinct bisectors are kept, alongside the experience's own
+//! embedding. Each query is then run to report where the experience ranks.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::ExperienceId;
+
+/// A query embedding an experience answers.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RepresentativeQuery {
+    /// Unit-length query embedding.
+    pub embedding: Vec<f32>,
+
+    /// The neighbor whose shared topic the query targets; its content is a
+    /// natural label for the query. `None` for the experience's own
+    /// embedding.
+    pub exemplar: Option<ExperienceId>,
+
+    /// Cosine similarity between the query and the experience.
+    pub similarity: f32,
+
+    /// 1-based position of the experience in
+    /// [`search_similar()`](crate::PulseDB::search_similar) results for
+    /// the query, or `None` if it falls outside the top
+    /// [`REPRESENTATIVE_QUERY_DEPTH`].
+    pub rank: Option<usize>,
+}
+
+/// Result depth searched when ranking an experience for a representative
+/// query.
+pub const REPRESENTATIVE_QUERY_DEPTH: usize = 20;
+
+/// Scales a vector to unit length (zero vectors are returned unchanged).
+pub(crate) fn normalized(vector: &[f32]) -> Vec<f32> {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return vector.to_vec();
+    }
+    vector.iter().map(|v| v / norm).collect()
+}
+
+/// Unit-length bisector of two vectors.
+pub(crate) fn bisector(a: &[f32], b: &[f32]) -> Vec<f32> {
+    let (a, b) = (normalized(a), normalized(b));
+    let sum: Vec<f32> = a.iter().zip(&b).map(|(x, y)| x + y).collect();
+    normalized(&sum)
+}
+
+/// Picks up to `n` of `candidates` (unit-length) by farthest-point
+/// traversal, starting from the first: each pick is the candidate least
+/// similar to everything picked so far. Returns indices in pick order.
+pub(crate) fn select_diverse(candidates: &[Vec<f32>], n: usize) -> Vec<usize> {
+    let dot = |a: &[f32], b: &[f32]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+
+    let mut picked = Vec::with_capacity(n.min(candidates.len()));
+    if candidates.is_empty() || n == 0 {
+        return picked;
+    }
+    picked.push(0);
+    // Highest similarity of each candidate to any pick
+    let mut closest: Vec<f32> = candidates.iter().map(|c| dot(c, &candidates[0])).collect();
+    while picked.len() < n {
+        let next = closest
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !picked.contains(i))
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(i, _)| i);
+        let Some(next) = next else {
+            break;
+        };
+        picked.push(next);
+        for (i, candidate) in candidates.iter().enumerate() {
+            closest[i] = closest[i].max(dot(candidate, &candidates[next]));
+        }
+    }
+    picked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bisector_is_unit_length_between_inputs() {
+        let q = bisector(&[2.0, 0.0], &[0.0, 1.0]);
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        assert!((q[0] - half).abs() < 1e-6 && (q[1] - half).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_select_diverse_skips_near_copies() {
+        let candidates = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.99, 0.141, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ];
+        assert_eq!(select_diverse(&candidates, 3), vec![0, 2, 3]);
+        assert_eq!(select_diverse(&candidates, 10).len(), 4);
+        assert!(select_diverse(&[], 3).is_empty());
+    }
+}
@@ -0,0 +1,532 @@
+//! Multi-agent simulation harness.
+//!
+//! Requires the `test-util` feature flag. [`run()`] starts
+//! [`SimConfig::agents`] threads against one shared [`PulseDB`], each acting
+//! as a separate agent that issues a random mix of record, search, and
+//! relate calls drawn from [`WorkloadMix`]. Embeddings come from
+//! [`SyntheticVectors`], so clustered and uniform vector spaces can both be
+//! exercised.
+//!
+//! While the workload runs, every result is checked as it comes back; once
+//! all agents finish, the collective is checked as a whole. Broken
+//! invariants are collected in [`SimReport::violations`] rather than
+//! panicking mid-run, so a test sees every problem at once:
+//!
+//! - every experience an agent recorded can be read back, in the right
+//!   collective, attributed to that agent
+//! - the collective's experience count grew by exactly the number recorded
+//! - every relation an agent stored exists and both its ends exist
+//! - search hits belong to the collective and are ordered by similarity
+//! - no call fails with anything but an expected rejection (a duplicate
+//!   relation)
+//!
+//! # Example
+//!
+//! ```rust
+//! # fn main() -> pulsedb::Result<()> {
+//! # let dir = tempfile::tempdir().unwrap();
+//! use pulsedb::sim::{self, SimConfig};
+//!
+//! let db = pulsedb::PulseDB::open(dir.path().join("sim.db"), pulsedb::Config::default())?;
+//! let cid = db.create_collective("sim")?;
+//! let report = sim::run(&db, cid, &SimConfig { agents: 4, operations_per_agent: 25, ..SimConfig::default() })?;
+//! report.assert_invariants();
+//! println!("{}", report);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::bench::{SyntheticVectors, Throughput, VectorDistribution};
+use crate::db::PulseDB;
+use crate::error::{Result, ValidationError};
+use crate::experience::{Experience, NewExperience};
+use crate::relation::{NewExperienceRelation, RelationType};
+use crate::types::{AgentId, CollectiveId, ExperienceId, RelationId};
+
+/// Relative weights of the operations each agent issues.
+///
+/// An agent picks each operation independently with probability
+/// proportional to its weight. A weight of 0 disables the operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkloadMix {
+    /// Weight of `record_experience` calls.
+    pub record: u32,
+    /// Weight of `search_similar` calls.
+    pub search: u32,
+    /// Weight of `store_relation` calls between two experiences recorded
+    /// earlier in the run (by any agent).
+    pub relate: u32,
+}
+
+impl Default for WorkloadMix {
+    fn default() -> Self {
+        Self {
+            record: 5,
+            search: 4,
+            relate: 1,
+        }
+    }
+}
+
+/// Parameters of a simulation run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SimConfig {
+    /// Number of concurrent agents, one thread each.
+    pub agents: usize,
+
+    /// Operations each agent issues.
+    pub operations_per_agent: usize,
+
+    /// Relative frequency of each operation.
+    pub mix: WorkloadMix,
+
+    /// Shape of the embedding space for recorded experiences and queries.
+    pub distribution: VectorDistribution,
+
+    /// Results per search (1-1000).
+    pub k: usize,
+
+    /// Seed for operation choice and vectors. Each agent derives its own
+    /// stream from it, so a run's per-agent workload is reproducible even
+    /// though the interleaving is not.
+    pub seed: u64,
+}
+
+impl Default for SimConfig {
+    fn default() -> Self {
+        Self {
+            agents: 8,
+            operations_per_agent: 100,
+            mix: WorkloadMix::default(),
+            distribution: VectorDistribution::Clustered {
+                clusters: 8,
+                spread: 0.1,
+            },
+            k: 10,
+            seed: 42,
+        }
+    }
+}
+
+/// Measurements and invariant checks from one [`run()`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SimReport {
+    /// The configuration that was run.
+    pub config: SimConfig,
+
+    /// Wall-clock time from the first agent starting to the last finishing.
+    pub elapsed: Duration,
+
+    /// Successful `record_experience` calls; `elapsed` sums the time spent
+    /// in them across all agents.
+    pub record: Throughput,
+
+    /// Successful `search_similar` calls, timed as for `record`.
+    pub search: Throughput,
+
+    /// Successful `store_relation` calls, timed as for `record`.
+    pub relate: Throughput,
+
+    /// Relate attempts skipped because fewer than two experiences existed
+    /// yet, or rejected as duplicates.
+    pub skipped: usize,
+
+    /// Broken invariants, empty for a clean run.
+    pub violations: Vec<String>,
+}
+
+impl SimReport {
+    /// Total successful operations per second of wall-clock time, across
+    /// all agents.
+    pub fn per_second(&self) -> f64 {
+        let ops = self.record.operations + self.search.operations + self.relate.operations;
+        let secs = self.elapsed.as_secs_f64();
+        if secs == 0.0 {
+            0.0
+        } else {
+            ops as f64 / secs
+        }
+    }
+
+    /// Panics with every violation if the run broke any invariant.
+    pub fn assert_invariants(&self) {
+        assert!(
+            self.violations.is_empty(),
+            "simulation broke {} invariant(s):\n{}",
+            self.violations.len(),
+            self.violations.join("\n")
+        );
+    }
+}
+
+impl fmt::Display for SimReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = &self.config;
+        writeln!(
+            f,
+            "agents={} ops/agent={} mix={:?} distribution={:?}",
+            c.agents, c.operations_per_agent, c.mix, c.distribution
+        )?;
+        writeln!(
+            f,
+            "total    {:>12.1} ops/s  wall {:?}",
+            self.per_second(),
+            self.elapsed
+        )?;
+        for (name, phase) in [
+            ("record", &self.record),
+            ("search", &self.search),
+            ("relate", &self.relate),
+        ] {
+            writeln!(
+                f,
+                "{:<8} {:>12} ops    mean {:?}",
+                name,
+                phase.operations,
+                phase.mean_latency()
+            )?;
+        }
+        write!(
+            f,
+            "skipped  {}  violations {}",
+            self.skipped,
+            self.violations.len()
+        )
+    }
+}
+
+/// What one agent did, merged into the report after the run.
+#[derive(Default)]
+struct AgentOutcome {
+    recorded: Vec<(ExperienceId, AgentId)>,
+    relations: Vec<RelationId>,
+    record: (usize, Duration),
+    search: (usize, Duration),
+    relate: (usize, Duration),
+    skipped: usize,
+    violations: Vec<String>,
+}
+
+/// Runs a simulated multi-agent workload against `collective_id`.
+///
+/// The collective should see no other writes during the run, since the
+/// final checks compare its experience count against what the agents
+/// recorded.
+///
+/// # Errors
+///
+/// - Validation error if `agents`, `operations_per_agent`, or `k` is out
+///   of range, or every weight in `mix` is 0
+/// - [`NotFoundError::Collective`](crate::NotFoundError::Collective) if the
+///   collective doesn't exist
+/// - Any error raised by the final checks' own reads
+///
+/// Errors raised by the workload itself are reported as violations.
+pub fn run(db: &PulseDB, collective_id: CollectiveId, config: &SimConfig) -> Result<SimReport> {
+    if config.agents == 0 {
+        return Err(ValidationError::invalid_field("agents", "must be at least 1").into());
+    }
+    if config.operations_per_agent == 0 {
+        return Err(
+            ValidationError::invalid_field("operations_per_agent", "must be at least 1").into(),
+        );
+    }
+    if config.k == 0 || config.k > 1000 {
+        return Err(ValidationError::invalid_field("k", "must be between 1 and 1000").into());
+    }
+    let mix = config.mix;
+    if mix.record as u64 + mix.search as u64 + mix.relate as u64 == 0 {
+        return Err(
+            ValidationError::invalid_field("mix", "at least one weight must be set").into(),
+        );
+    }
+    let initial = db.get_collective_stats(collective_id)?.experience_count;
+
+    info!(
+        agents = config.agents,
+        operations = config.operations_per_agent,
+        "Starting simulation"
+    );
+
+    let pool = Mutex::new(Vec::new());
+    let start = Instant::now();
+    let outcomes: Vec<AgentOutcome> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..config.agents)
+            .map(|agent| {
+                let pool = &pool;
+                scope.spawn(move || run_agent(db, collective_id, config, agent, pool))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| match handle.join() {
+                Ok(outcome) => outcome,
+                Err(_) => AgentOutcome {
+                    violations: vec!["agent thread panicked".to_string()],
+                    ..AgentOutcome::default()
+                },
+            })
+            .collect()
+    });
+    let elapsed = start.elapsed();
+
+    let mut report = SimReport {
+        config: config.clone(),
+        elapsed,
+        record: idle(),
+        search: idle(),
+        relate: idle(),
+        skipped: 0,
+        violations: Vec::new(),
+    };
+    let mut recorded = Vec::new();
+    let mut relations = Vec::new();
+    for outcome in outcomes {
+        add(&mut report.record, outcome.record);
+        add(&mut report.search, outcome.search);
+        add(&mut report.relate, outcome.relate);
+        report.skipped += outcome.skipped;
+        report.violations.extend(outcome.violations);
+        recorded.extend(outcome.recorded);
+        relations.extend(outcome.relations);
+    }
+
+    check_collective(
+        db,
+        collective_id,
+        initial,
+        &recorded,
+        &relations,
+        &mut report,
+    )?;
+
+    info!(
+        ops_per_second = report.per_second(),
+        violations = report.violations.len(),
+        "Simulation finished"
+    );
+    Ok(report)
+}
+
+/// One agent's workload.
+fn run_agent(
+    db: &PulseDB,
+    collective_id: CollectiveId,
+    config: &SimConfig,
+    agent: usize,
+    pool: &Mutex<Vec<ExperienceId>>,
+) -> AgentOutcome {
+    let agent_id = AgentId::new(format!("sim-agent-{}", agent));
+    let seed = config.seed ^ (agent as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let mut vectors = SyntheticVectors::new(db.embedding_dimension(), config.distribution, seed);
+    let mut rng = SplitMix64(seed);
+    let mix = config.mix;
+    let total = mix.record as u64 + mix.search as u64 + mix.relate as u64;
+    let mut outcome = AgentOutcome::default();
+
+    for op in 0..config.operations_per_agent {
+        let roll = rng.next() % total;
+        if roll < mix.record as u64 {
+            let started = Instant::now();
+            let result = db.record_experience(NewExperience {
+                collective_id,
+                content: format!("{} operation {}", agent_id, op),
+                embedding: Some(vectors.next_vector()),
+                source_agent: agent_id.clone(),
+                ..Default::default()
+            });
+            let took = started.elapsed();
+            match result {
+                Ok(id) => {
+                    outcome.record.0 += 1;
+                    outcome.record.1 += took;
+                    outcome.recorded.push((id, agent_id.clone()));
+                    lock(pool).push(id);
+                }
+                Err(err) => outcome
+                    .violations
+                    .push(format!("{}: record failed: {}", agent_id, err)),
+            }
+        } else if roll < mix.record as u64 + mix.search as u64 {
+            let query = vectors.next_vector();
+            let started = Instant::now();
+            let result = db.search_similar(collective_id, &query, config.k);
+            let took = started.elapsed();
+            match result {
+                Ok(hits) => {
+                    outcome.search.0 += 1;
+                    outcome.search.1 += took;
+                    if hits.len() > config.k {
+                        outcome.violations.push(format!(
+                            "{}: search returned {} hits for k = {}",
+                            agent_id,
+                            hits.len(),
+                            config.k
+                        ));
+                    }
+                    if hits
+                        .iter()
+                        .any(|hit| hit.experience.collective_id != collective_id)
+                    {
+                        outcome.violations.push(format!(
+                            "{}: search returned a hit from another collective",
+                            agent_id
+                        ));
+                    }
+                    if hits.windows(2).any(|w| w[0].similarity < w[1].similarity) {
+                        outcome.violations.push(format!(
+                            "{}: search hits are not ordered by similarity",
+                            agent_id
+                        ));
+                    }
+                }
+                Err(err) => outcome
+                    .violations
+                    .push(format!("{}: search failed: {}", agent_id, err)),
+            }
+        } else {
+            let pair = {
+                let pool = lock(pool);
+                if pool.len() < 2 {
+                    None
+                } else {
+                    let source = (rng.next() % pool.len() as u64) as usize;
+                    let offset = 1 + (rng.next() % (pool.len() as u64 - 1)) as usize;
+                    Some((pool[source], pool[(source + offset) % pool.len()]))
+                }
+            };
+            let Some((source_id, target_id)) = pair else {
+                outcome.skipped += 1;
+                continue;
+            };
+            let relation_type = if rng.next() & 1 == 0 {
+                RelationType::Supports
+            } else {
+                RelationType::RelatedTo
+            };
+            let started = Instant::now();
+            let result = db.store_relation(NewExperienceRelation {
+                source_id,
+                target_id,
+                relation_type,
+                strength: 0.5,
+                metadata: None,
+            });
+            let took = started.elapsed();
+            match result {
+                Ok(id) => {
+                    outcome.relate.0 += 1;
+                    outcome.relate.1 += took;
+                    outcome.relations.push(id);
+                }
+                // Two agents may pick the same pair
+                Err(err) if err.is_validation() => outcome.skipped += 1,
+                Err(err) => outcome
+                    .violations
+                    .push(format!("{}: relate failed: {}", agent_id, err)),
+            }
+        }
+    }
+    outcome
+}
+
+/// Checks the collective against what the agents did.
+fn check_collective(
+    db: &PulseDB,
+    collective_id: CollectiveId,
+    initial: u64,
+    recorded: &[(ExperienceId, AgentId)],
+    relations: &[RelationId],
+    report: &mut SimReport,
+) -> Result<()> {
+    for (id, agent_id) in recorded {
+        match db.get_experience(*id)? {
+            None => report
+                .violations
+                .push(format!("recorded experience {} is missing", id)),
+            Some(Experience {
+                collective_id: cid,
+                source_agent,
+                ..
+            }) => {
+                if cid != collective_id {
+                    report
+                        .violations
+                        .push(format!("experience {} landed in collective {}", id, cid));
+                }
+                if &source_agent != agent_id {
+                    report.violations.push(format!(
+                        "experience {} is attributed to {}, recorded by {}",
+                        id, source_agent, agent_id
+                    ));
+                }
+            }
+        }
+    }
+
+    let count = db.get_collective_stats(collective_id)?.experience_count;
+    if count != initial + recorded.len() as u64 {
+        report.violations.push(format!(
+            "collective holds {} experiences, expected {} + {} recorded",
+            count,
+            initial,
+            recorded.len()
+        ));
+    }
+
+    for id in relations {
+        match db.get_relation(*id)? {
+            None => report
+                .violations
+                .push(format!("stored relation {} is missing", id)),
+            Some(relation) => {
+                for end in [relation.source_id, relation.target_id] {
+                    if db.get_experience(end)?.is_none() {
+                        report.violations.push(format!(
+                            "relation {} points at missing experience {}",
+                            id, end
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Locks the shared pool, recovering it if another agent panicked.
+fn lock(pool: &Mutex<Vec<ExperienceId>>) -> std::sync::MutexGuard<'_, Vec<ExperienceId>> {
+    pool.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn idle() -> Throughput {
+    Throughput {
+        operations: 0,
+        elapsed: Duration::ZERO,
+    }
+}
+
+fn add(total: &mut Throughput, (operations, elapsed): (usize, Duration)) {
+    total.operations += operations;
+    total.elapsed += elapsed;
+}
+
+/// SplitMix64, for operation choice independent of the vector stream.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,210 @@
+//! Bounded cache of hydrated experience records.
+//!
+//! Hot experiences are hydrated over and over by consecutive searches.
+//! [`ExperienceCache`] keeps the most recently read records so those reads
+//! skip redb and record decoding entirely.
+//!
+//! The storage engine invalidates an entry after every committed write that
+//! touches the experience. A generation counter guards the window between
+//! a reader's transaction and its cache fill: a fill is dropped if any
+//! invalidation happened since the reader took its [`generation()`]
+//! snapshot, so a stale record can never be cached after its replacement
+//! was committed.
+//!
+//! A poisoned lock turns every lookup into a miss, so a panic mid-update
+//! can cost hits but never serve a stale record.
+//!
+//! [`generation()`]: ExperienceCache::generation
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use crate::experience::Experience;
+use crate::types::ExperienceId;
+
+/// LRU cache of experiences keyed by ID.
+#[derive(Debug)]
+pub struct ExperienceCache {
+    capacity: usize,
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// Record and last-use tick per experience.
+    entries: HashMap<ExperienceId, (Experience, u64)>,
+    /// Experiences by last-use tick, least recent first.
+    by_use: BTreeMap<u64, ExperienceId>,
+    /// Monotonic use counter.
+    tick: u64,
+    /// Bumped on every invalidation.
+    generation: u64,
+}
+
+impl Inner {
+    fn touch(&mut self, id: ExperienceId) -> Option<&Experience> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (experience, last_used) = self.entries.get_mut(&id)?;
+        self.by_use.remove(last_used);
+        *last_used = tick;
+        self.by_use.insert(tick, id);
+        Some(experience)
+    }
+}
+
+impl ExperienceCache {
+    /// Creates a cache holding up to `capacity` records. Zero disables it.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(Inner::default()),
+        }
+    }
+
+    /// Returns a copy of the cached record, marking it recently used.
+    pub fn get(&self, id: ExperienceId) -> Option<Experience> {
+        if self.capacity == 0 {
+            return None;
+        }
+        self.inner.lock().ok()?.touch(id).cloned()
+    }
+
+    /// Returns the current invalidation generation.
+    ///
+    /// Take this before opening the read transaction whose result will be
+    /// passed to [`insert()`](Self::insert).
+    pub fn generation(&self) -> u64 {
+        self.inner.lock().map(|inner| inner.generation).unwrap_or(0)
+    }
+
+    /// Caches a record read under `generation`, evicting the least recently
+    /// used entry when full.
+    ///
+    /// Does nothing if an invalidation happened since `generation`.
+    pub fn insert(&self, experience: &Experience, generation: u64) {
+        if self.capacity == 0 {
+            return;
+        }
+        let Ok(mut inner) = self.inner.lock() else {
+            return;
+        };
+        if inner.generation != generation {
+            return;
+        }
+        if inner.touch(experience.id).is_some() {
+            return;
+        }
+        while inner.entries.len() >= self.capacity {
+            let Some((_, oldest)) = inner.by_use.pop_first() else {
+                break;
+            };
+            inner.entries.remove(&oldest);
+        }
+        let tick = inner.tick;
+        inner.by_use.insert(tick, experience.id);
+        inner
+            .entries
+            .insert(experience.id, (experience.clone(), tick));
+    }
+
+    /// Drops the records for `ids`. Call after committing a write to them.
+    pub fn invalidate(&self, ids: &[ExperienceId]) {
+        if self.capacity == 0 {
+            return;
+        }
+        let Ok(mut inner) = self.inner.lock() else {
+            return;
+        };
+        inner.generation += 1;
+        for id in ids {
+            if let Some((_, last_used)) = inner.entries.remove(id) {
+                inner.by_use.remove(&last_used);
+            }
+        }
+    }
+
+    /// Returns the number of cached records.
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.inner
+            .lock()
+            .map(|inner| inner.entries.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if nothing is cached.
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::experience::ExperienceType;
+    use crate::types::{AgentId, CollectiveId, Timestamp};
+
+    fn experience(content: &str) -> Experience {
+        Experience {
+            id: ExperienceId::new(),
+            collective_id: CollectiveId::new(),
+            content: content.to_string(),
+            embedding: vec![],
+            experience_type: ExperienceType::default(),
+            importance: 0.5,
+            confidence: 0.5,
+            applications: 0,
+            domain: vec![],
+            related_files: vec![],
+            source_agent: AgentId::new("agent"),
+            source_task: None,
+            user_id: None,
+            timestamp: Timestamp::now(),
+            expires_at: None,
+            archived: false,
+            attribution: None,
+        }
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = ExperienceCache::new(2);
+        let (a, b, c) = (experience("a"), experience("b"), experience("c"));
+        cache.insert(&a, cache.generation());
+        cache.insert(&b, cache.generation());
+
+        // Touch a so b is the eviction candidate
+        assert!(cache.get(a.id).is_some());
+        cache.insert(&c, cache.generation());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(a.id).is_some());
+        assert!(cache.get(b.id).is_none());
+        assert_eq!(cache.get(c.id).unwrap().content, "c");
+    }
+
+    #[test]
+    fn test_fill_after_invalidation_is_dropped() {
+        let cache = ExperienceCache::new(8);
+        let a = experience("a");
+        cache.insert(&a, cache.generation());
+
+        let generation = cache.generation();
+        cache.invalidate(&[a.id]);
+        assert!(cache.get(a.id).is_none());
+
+        // A reader that started before the invalidation must not refill
+        cache.insert(&a, generation);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_zero_capacity_disables() {
+        let cache = ExperienceCache::new(0);
+        let a = experience("a");
+        cache.insert(&a, cache.generation());
+        assert!(cache.get(a.id).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+//! Versioned record encoding for storage tables.
+//!
+//! Every record value written to a redb table starts with a one-byte
+//! header naming the [`Codec`] that encoded the rest:
+//!
+//! ```text
+//! ┌────────┬──────────────────────────────┐
+//! │ tag u8 │ payload (codec-specific)     │
+//! └────────┴──────────────────────────────┘
+//! ```
+//!
+//! Readers dispatch on the tag, so a table can switch to a new codec (or a
+//! new revision of one) by changing [`Record::CODEC`] for its record type:
+//! new writes use the new codec while existing records stay readable, with
+//! no migration pass.
+//!
+//! # Schema evolution
+//!
+//! Decoding ignores bytes after the fields a reader knows about. New fields
+//! appended to the end of a record struct are therefore skipped by older
+//! readers instead of failing the read. Fields that older records lack
+//! still need a side table or a codec revision, as before.
+//!
+//! Databases created before schema version 3 stored headerless bincode;
+//! they are rewritten once on open.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::collective::{Collective, CollectiveDetails, CollectiveQuota};
+use crate::embedding::TextNormalization;
+use crate::experience::{ContentPolicy, Experience, ExperienceHead, ModelAttribution};
+use crate::insight::DerivedInsight;
+use crate::lock::Lease;
+use crate::moderation::ModerationPolicy;
+use crate::relation::{ExperienceRelation, RelationSuggestion};
+use crate::search::{ExperienceNeighbor, KnowledgeGapRecord};
+use crate::storage::schema::WatchEventRecord;
+use crate::watch::Interest;
+
+/// Errors from encoding or decoding a record.
+#[derive(Debug, thiserror::Error)]
+pub enum CodecError {
+    /// The value has no header byte.
+    #[error("empty record")]
+    Empty,
+
+    /// The header names a codec this version doesn't know.
+    #[error("unknown codec tag {0:#04x}")]
+    UnknownCodec(u8),
+
+    /// The bincode payload is malformed.
+    #[error("bincode: {0}")]
+    Bincode(#[from] bincode::Error),
+}
+
+/// A serialization format for record payloads.
+pub trait Codec {
+    /// Header byte identifying this codec. Never reuse a retired tag.
+    const TAG: u8;
+
+    /// Encodes a value, without the header.
+    fn encode_payload<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, CodecError>;
+
+    /// Decodes a payload, without the header. Trailing bytes are ignored.
+    fn decode_payload<T: DeserializeOwned>(payload: &[u8]) -> Result<T, CodecError>;
+}
+
+/// bincode 1.x with fixed-width integers (the original storage format).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bincode;
+
+impl Codec for Bincode {
+    const TAG: u8 = 0x01;
+
+    fn encode_payload<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, CodecError> {
+        Ok(bincode::serialize(value)?)
+    }
+
+    fn decode_payload<T: DeserializeOwned>(payload: &[u8]) -> Result<T, CodecError> {
+        // bincode::deserialize allows trailing bytes
+        Ok(bincode::deserialize(payload)?)
+    }
+}
+
+/// The codecs a record can be written with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodecId {
+    /// See [`Bincode`].
+    Bincode,
+}
+
+impl CodecId {
+    /// Returns the header byte for this codec.
+    pub fn tag(self) -> u8 {
+        match self {
+            Self::Bincode => Bincode::TAG,
+        }
+    }
+
+    /// Looks up a codec by header byte.
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            Bincode::TAG => Some(Self::Bincode),
+            _ => None,
+        }
+    }
+}
+
+/// A value stored in a record table.
+///
+/// `CODEC` selects the format new writes of this type use; each table
+/// holds one record type, so this is the per-table format setting.
+pub trait Record: Serialize + DeserializeOwned {
+    /// Codec used when writing this record.
+    const CODEC: CodecId = CodecId::Bincode;
+}
+
+impl Record for Collective {}
+impl Record for CollectiveDetails {}
+impl Record for CollectiveQuota {}
+impl Record for Experience {}
+impl Record for ExperienceHead {}
+impl Record for ModelAttribution {}
+impl Record for ExperienceRelation {}
+impl Record for RelationSuggestion {}
+impl Record for DerivedInsight {}
+impl Record for Lease {}
+impl Record for ModerationPolicy {}
+impl Record for ContentPolicy {}
+impl Record for TextNormalization {}
+impl Record for Vec<Interest> {}
+impl Record for WatchEventRecord {}
+impl Record for Vec<ExperienceNeighbor> {}
+impl Record for KnowledgeGapRecord {}
+// Activity capabilities
+impl Record for Vec<String> {}
+#[cfg(feature = "sync")]
+impl Record for crate::sync::SyncCursor {}
+
+/// Encodes a record with its header byte.
+pub fn encode<T: Record>(value: &T) -> Result<Vec<u8>, CodecError> {
+    let payload = match T::CODEC {
+        CodecId::Bincode => Bincode::encode_payload(value)?,
+    };
+    let mut bytes = Vec::with_capacity(payload.len() + 1);
+    bytes.push(T::CODEC.tag());
+    bytes.extend_from_slice(&payload);
+    Ok(bytes)
+}
+
+/// Decodes a record written with any known codec.
+pub fn decode<T: Record>(bytes: &[u8]) -> Result<T, CodecError> {
+    let (&tag, payload) = bytes.split_first().ok_or(CodecError::Empty)?;
+    match CodecId::from_tag(tag) {
+        Some(CodecId::Bincode) => Bincode::decode_payload(payload),
+        None => Err(CodecError::UnknownCodec(tag)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct V1 {
+        id: u32,
+        name: String,
+    }
+    impl Record for V1 {}
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct V2 {
+        id: u32,
+        name: String,
+        tags: Vec<String>,
+    }
+    impl Record for V2 {}
+
+    #[test]
+    fn test_roundtrip_writes_header() {
+        let value = V1 {
+            id: 7,
+            name: "seven".into(),
+        };
+        let bytes = encode(&value).unwrap();
+        assert_eq!(bytes[0], Bincode::TAG);
+        assert_eq!(&bytes[1..], bincode::serialize(&value).unwrap().as_slice());
+        assert_eq!(decode::<V1>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_unknown_trailing_fields_are_skipped() {
+        let newer = V2 {
+            id: 1,
+            name: "one".into(),
+            tags: vec!["x".into()],
+        };
+        let older: V1 = decode(&encode(&newer).unwrap()).unwrap();
+        assert_eq!(
+            older,
+            V1 {
+                id: 1,
+                name: "one".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_bad_headers_rejected() {
+        assert!(matches!(decode::<V1>(&[]), Err(CodecError::Empty)));
+        assert!(matches!(
+            decode::<V1>(&[0xff, 0, 0]),
+            Err(CodecError::UnknownCodec(0xff))
+        ));
+    }
+}
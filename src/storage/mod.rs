@@ -22,22 +22,57 @@
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
 
+mod cache;
+pub mod codec;
 pub mod redb;
 pub mod schema;
+mod write_gate;
 
 pub use self::redb::RedbStorage;
-pub use schema::{DatabaseMetadata, SCHEMA_VERSION};
+pub use schema::{DatabaseMetadata, ExperienceMeta, SCHEMA_VERSION};
+
+/// Callback run after a committed write, see
+/// [`StorageEngine::set_commit_listener()`].
+pub type CommitListener = Arc<dyn Fn(&dyn StorageEngine) + Send + Sync>;
+
+/// Outcome of [`StorageEngine::delete_experience_cascading()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExperienceDeletion {
+    /// No experience with the given ID exists.
+    NotFound,
+    /// Nothing was deleted: the cascade is
+    /// [`Block`](InsightSourceCascade::Block) and these insights cite the
+    /// experience.
+    Blocked(Vec<InsightId>),
+    /// The experience was deleted.
+    Deleted {
+        /// Collective the experience belonged to.
+        collective_id: CollectiveId,
+        /// Insights that cited the experience, including deleted ones.
+        citing: Vec<InsightId>,
+        /// Insights deleted because the experience was their last source.
+        deleted_insights: Vec<InsightId>,
+    },
+}
 
 use std::path::Path;
+use std::sync::Arc;
 
 use crate::activity::Activity;
-use crate::collective::Collective;
-use crate::config::Config;
+use crate::collective::{Collective, CollectiveQuota};
+use crate::config::{Config, InsightSourceCascade, VectorIndexKind};
+use crate::cursor::Cursor;
+use crate::embedding::TextNormalization;
 use crate::error::Result;
-use crate::experience::{Experience, ExperienceUpdate};
-use crate::insight::DerivedInsight;
-use crate::relation::{ExperienceRelation, RelationType};
-use crate::types::{CollectiveId, ExperienceId, InsightId, RelationId, Timestamp};
+use crate::experience::{ContentPolicy, Experience, ExperienceUpdate};
+use crate::insight::{DerivedInsight, InsightType};
+use crate::lock::Lease;
+use crate::metrics::LockWaitStats;
+use crate::moderation::ModerationPolicy;
+use crate::relation::{ExperienceRelation, RelationSuggestion, RelationType};
+use crate::search::{ExperienceNeighbor, KnowledgeGapRecord};
+use crate::types::{CollectiveId, ExperienceId, InsightId, RelationId, TaskId, Timestamp};
+use crate::watch::Interest;
 
 /// Storage engine trait for PulseDB.
 ///
@@ -74,6 +109,10 @@ pub trait StorageEngine: Send + Sync {
     /// The metadata includes schema version, embedding dimension, and timestamps.
     fn metadata(&self) -> &DatabaseMetadata;
 
+    /// Returns the time write transactions spent queueing behind the one
+    /// in progress, since the storage was opened.
+    fn write_waits(&self) -> LockWaitStats;
+
     /// Closes the storage engine, flushing any pending writes.
     ///
     /// This method consumes the storage engine. After calling `close()`,
@@ -91,6 +130,14 @@ pub trait StorageEngine: Send + Sync {
     /// Some storage implementations (like in-memory) may not have a path.
     fn path(&self) -> Option<&Path>;
 
+    /// Writes a consistent copy of all stored data to a new database file
+    /// at `dest`.
+    ///
+    /// The copy is taken from a single read transaction, so every write is
+    /// either fully included or absent, and writers are not blocked while
+    /// it runs. `dest` must not exist.
+    fn snapshot_to(&self, dest: &Path) -> Result<()>;
+
     // =========================================================================
     // Collective Storage Operations
     // =========================================================================
@@ -123,6 +170,12 @@ pub trait StorageEngine: Send + Sync {
     /// Returns an error if the read transaction or deserialization fails.
     fn list_collectives(&self) -> Result<Vec<Collective>>;
 
+    /// Lists the IDs of all collectives with the given owner.
+    ///
+    /// Reads the owner index, so cost is proportional to the owner's
+    /// collectives rather than the whole database.
+    fn list_collective_ids_by_owner(&self, owner_id: &str) -> Result<Vec<CollectiveId>>;
+
     /// Deletes a collective by ID.
     ///
     /// Returns `true` if the collective existed and was deleted,
@@ -133,6 +186,32 @@ pub trait StorageEngine: Send + Sync {
     /// Returns an error if the write transaction fails.
     fn delete_collective(&self, id: CollectiveId) -> Result<bool>;
 
+    // =========================================================================
+    // Collective Hierarchy Operations
+    // =========================================================================
+
+    /// Sets or clears the parent of a collective.
+    ///
+    /// Replaces any existing parent link. Does NOT check that either
+    /// collective exists or that the link is acyclic — the PulseDB facade
+    /// validates before calling.
+    fn set_collective_parent(&self, id: CollectiveId, parent: Option<CollectiveId>) -> Result<()>;
+
+    /// Returns the parent of a collective, or `None` for a root.
+    fn get_collective_parent(&self, id: CollectiveId) -> Result<Option<CollectiveId>>;
+
+    /// Lists the direct children of a collective.
+    fn list_child_collective_ids(&self, id: CollectiveId) -> Result<Vec<CollectiveId>>;
+
+    /// Records which vector index a collective's experiences use.
+    ///
+    /// Does NOT check that the collective exists.
+    fn set_collective_index_kind(&self, id: CollectiveId, kind: VectorIndexKind) -> Result<()>;
+
+    /// Returns a collective's vector index kind ([`VectorIndexKind::Hnsw`]
+    /// unless set otherwise).
+    fn get_collective_index_kind(&self, id: CollectiveId) -> Result<VectorIndexKind>;
+
     // =========================================================================
     // Experience Index Operations (for collective stats & cascade delete)
     // =========================================================================
@@ -147,6 +226,12 @@ pub trait StorageEngine: Send + Sync {
     /// Returns an error if the read transaction fails.
     fn count_experiences_in_collective(&self, id: CollectiveId) -> Result<u64>;
 
+    /// Sums the encoded size of a collective's experience records,
+    /// embeddings and insight records, in bytes.
+    ///
+    /// Index entries and redb page overhead are not counted.
+    fn collective_storage_bytes(&self, id: CollectiveId) -> Result<u64>;
+
     /// Deletes all experiences and related index entries for a collective.
     ///
     /// Used for cascade deletion when a collective is removed. Cleans up:
@@ -168,12 +253,33 @@ pub trait StorageEngine: Send + Sync {
     /// Iterates the `experiences_by_collective` multimap index.
     fn list_experience_ids_in_collective(&self, id: CollectiveId) -> Result<Vec<ExperienceId>>;
 
+    /// Lists the IDs of experiences of one type in a collective.
+    ///
+    /// Point lookup on `EXPERIENCES_BY_TYPE_TABLE`; order is by ExperienceId.
+    fn list_experience_ids_by_type(
+        &self,
+        collective_id: CollectiveId,
+        type_tag: schema::ExperienceTypeTag,
+    ) -> Result<Vec<ExperienceId>>;
+
+    /// Lists the experiences in a collective that expired at or before
+    /// `now`, earliest expiry first.
+    ///
+    /// Reads a prefix of `EXPERIENCES_BY_EXPIRY_TABLE`, so the cost follows
+    /// the number of expired experiences rather than the collective's size.
+    fn list_expired_experience_ids(
+        &self,
+        collective_id: CollectiveId,
+        now: Timestamp,
+    ) -> Result<Vec<ExperienceId>>;
+
     /// Retrieves the most recent experience IDs in a collective.
     ///
-    /// Performs a reverse iteration on `EXPERIENCES_BY_COLLECTIVE_TABLE`
-    /// to get IDs ordered by timestamp descending (newest first).
-    /// The multimap values are `[timestamp_be: 8 bytes][experience_id: 16 bytes]`,
-    /// and since timestamps are big-endian, reverse lexicographic order = newest first.
+    /// Walks `EXPERIENCES_BY_DAY_TABLE` backwards, newest day first, and
+    /// stops after `limit` entries, so the cost follows `limit` rather than
+    /// the collective's size. Equivalent to
+    /// [`get_experience_ids_in_range`](Self::get_experience_ids_in_range)
+    /// with no bounds.
     ///
     /// Returns `(ExperienceId, Timestamp)` pairs for the caller to fetch full
     /// records and apply post-filters.
@@ -188,6 +294,19 @@ pub trait StorageEngine: Send + Sync {
         limit: usize,
     ) -> Result<Vec<(ExperienceId, Timestamp)>>;
 
+    /// Retrieves experience IDs recorded in `[since, until)`, newest first.
+    ///
+    /// Only the day buckets overlapping the range are read; entries in the
+    /// boundary days are checked against the exact bounds. `None` leaves
+    /// that side unbounded. Returns at most `limit` entries.
+    fn get_experience_ids_in_range(
+        &self,
+        collective_id: CollectiveId,
+        since: Option<Timestamp>,
+        until: Option<Timestamp>,
+        limit: usize,
+    ) -> Result<Vec<(ExperienceId, Timestamp)>>;
+
     // =========================================================================
     // Experience Storage Operations
     // =========================================================================
@@ -200,15 +319,35 @@ pub trait StorageEngine: Send + Sync {
     /// - `EXPERIENCES_BY_COLLECTIVE_TABLE` — secondary index by collective+timestamp
     /// - `EXPERIENCES_BY_TYPE_TABLE` — secondary index by collective+type
     ///
+    /// plus `EXPERIENCE_ATTRIBUTION_TABLE` when the experience carries a
+    /// model attribution.
+    ///
     /// # Errors
     ///
     /// Returns an error if the transaction or serialization fails.
     fn save_experience(&self, experience: &Experience) -> Result<()>;
 
+    /// Saves a new experience unless one with the same ID already exists.
+    ///
+    /// Same writes as [`save_experience`](Self::save_experience), with the
+    /// existence check made in the same write transaction.
+    ///
+    /// Returns `false` (and writes nothing) if the ID is already taken.
+    fn insert_experience(&self, experience: &Experience) -> Result<bool>;
+
+    /// Saves new experiences in a single write transaction.
+    ///
+    /// Each is written as by [`insert_experience`](Self::insert_experience):
+    /// one whose ID already exists — in the database or earlier in the
+    /// slice — is skipped. Returns whether each experience was written, in
+    /// order. On error nothing is written.
+    fn insert_experiences(&self, experiences: &[Experience]) -> Result<Vec<bool>>;
+
     /// Retrieves an experience by ID, including its embedding.
     ///
-    /// Reads from both `EXPERIENCES_TABLE` and `EMBEDDINGS_TABLE` to
-    /// reconstitute the full experience with embedding.
+    /// Reads from `EXPERIENCES_TABLE`, `EMBEDDINGS_TABLE`, and
+    /// `EXPERIENCE_ATTRIBUTION_TABLE` to reconstitute the full experience
+    /// with embedding and attribution.
     ///
     /// Returns `None` if no experience with the given ID exists.
     fn get_experience(&self, id: ExperienceId) -> Result<Option<Experience>>;
@@ -230,6 +369,21 @@ pub trait StorageEngine: Send + Sync {
     /// `false` if not found.
     fn delete_experience(&self, id: ExperienceId) -> Result<bool>;
 
+    /// Permanently deletes an experience and applies `cascade` to the
+    /// insights citing it, all in one write transaction.
+    ///
+    /// Citing insights are found through `INSIGHTS_BY_SOURCE_TABLE`. Under
+    /// [`Detach`](InsightSourceCascade::Detach) the ID is removed from
+    /// each insight's sources, and an insight left without sources is
+    /// deleted, since every insight must cite at least one experience.
+    /// Under [`MarkDegraded`](InsightSourceCascade::MarkDegraded) each
+    /// insight is flagged in `DEGRADED_INSIGHTS_TABLE`.
+    fn delete_experience_cascading(
+        &self,
+        id: ExperienceId,
+        cascade: InsightSourceCascade,
+    ) -> Result<ExperienceDeletion>;
+
     /// Atomically increments the applications counter for an experience.
     ///
     /// Performs a read-modify-write in a single write transaction to prevent
@@ -250,6 +404,37 @@ pub trait StorageEngine: Send + Sync {
     /// Returns `None` if no embedding exists for the given ID.
     fn get_embedding(&self, id: ExperienceId) -> Result<Option<Vec<f32>>>;
 
+    /// Resolves the owning collective of each experience.
+    ///
+    /// All lookups run in a single read transaction, so the answer is a
+    /// consistent snapshot. Returns one entry per input ID, in order;
+    /// `None` where no experience with that ID exists.
+    fn get_experience_collectives(&self, ids: &[ExperienceId])
+        -> Result<Vec<Option<CollectiveId>>>;
+
+    /// Reads the hot-metadata projection of each experience.
+    ///
+    /// Served from a fixed-size sidecar table, so callers can screen
+    /// candidates by type, archived state, scores, time, or agent without
+    /// deserializing full records. All lookups run in a single read
+    /// transaction. Returns one entry per input ID, in order; `None` where
+    /// no experience with that ID exists.
+    fn get_experience_meta(&self, ids: &[ExperienceId]) -> Result<Vec<Option<ExperienceMeta>>>;
+
+    /// Stores precomputed neighbor lists, replacing any previous list for
+    /// each experience, in a single transaction.
+    ///
+    /// Does NOT check that the experiences exist.
+    fn save_experience_neighbors(
+        &self,
+        lists: &[(ExperienceId, Vec<ExperienceNeighbor>)],
+    ) -> Result<()>;
+
+    /// Returns the precomputed neighbor list of an experience, or `None`
+    /// if none has been computed.
+    fn get_experience_neighbors(&self, id: ExperienceId)
+        -> Result<Option<Vec<ExperienceNeighbor>>>;
+
     // =========================================================================
     // Relation Storage Operations (E3-S01)
     // =========================================================================
@@ -301,15 +486,53 @@ pub trait StorageEngine: Send + Sync {
         relation_type: RelationType,
     ) -> Result<bool>;
 
+    /// Queues relation suggestions, replacing any already queued for the
+    /// same (source, target) pair.
+    fn save_relation_suggestions(&self, suggestions: &[RelationSuggestion]) -> Result<()>;
+
+    /// Lists a collective's queued relation suggestions, ordered by source
+    /// then target ID.
+    fn list_relation_suggestions(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Vec<RelationSuggestion>>;
+
+    /// Retrieves the suggestion queued for a (source, target) pair.
+    fn get_relation_suggestion(
+        &self,
+        collective_id: CollectiveId,
+        source_id: ExperienceId,
+        target_id: ExperienceId,
+    ) -> Result<Option<RelationSuggestion>>;
+
+    /// Removes the suggestion queued for a (source, target) pair.
+    ///
+    /// Returns `true` if it existed.
+    fn delete_relation_suggestion(
+        &self,
+        collective_id: CollectiveId,
+        source_id: ExperienceId,
+        target_id: ExperienceId,
+    ) -> Result<bool>;
+
+    /// Deletes all relation suggestions belonging to a collective.
+    ///
+    /// Used for cascade deletion when a collective is removed.
+    /// Returns the count of deleted suggestions.
+    fn delete_relation_suggestions_by_collective(&self, collective_id: CollectiveId)
+        -> Result<u64>;
+
     // =========================================================================
     // Insight Storage Operations (E3-S02)
     // =========================================================================
 
     /// Saves a derived insight and its index entries atomically.
     ///
-    /// Writes to 2 tables in a single transaction:
+    /// Writes to 4 tables in a single transaction:
     /// - `INSIGHTS_TABLE` — the insight record (with inline embedding)
     /// - `INSIGHTS_BY_COLLECTIVE_TABLE` — index by collective
+    /// - `INSIGHTS_BY_TYPE_TABLE` — index by collective and type
+    /// - `INSIGHTS_BY_SOURCE_TABLE` — index by source experience
     fn save_insight(&self, insight: &DerivedInsight) -> Result<()>;
 
     /// Retrieves a derived insight by ID.
@@ -323,12 +546,154 @@ pub trait StorageEngine: Send + Sync {
     /// `false` if not found.
     fn delete_insight(&self, id: InsightId) -> Result<bool>;
 
+    /// Replaces the source experience IDs of an insight.
+    ///
+    /// Read-modify-write in a single write transaction. Bumps `updated_at`
+    /// and records an `Updated` WAL event.
+    ///
+    /// Returns `true` if the insight existed and was updated,
+    /// `false` if not found.
+    fn update_insight_sources(&self, id: InsightId, sources: &[ExperienceId]) -> Result<bool>;
+
+    /// Lists the IDs of insights of one type in a collective.
+    ///
+    /// Point lookup on `INSIGHTS_BY_TYPE_TABLE`; order is by InsightId.
+    fn list_insight_ids_by_type(
+        &self,
+        collective_id: CollectiveId,
+        insight_type: InsightType,
+    ) -> Result<Vec<InsightId>>;
+
+    /// Flags an insight as degraded (citing a deleted source experience).
+    ///
+    /// Stored in `DEGRADED_INSIGHTS_TABLE`; overwrites any earlier mark.
+    /// Removed automatically when the insight is deleted.
+    fn mark_insight_degraded(&self, id: InsightId, at: Timestamp) -> Result<()>;
+
+    /// Returns when the insight was marked degraded, or `None` if it isn't.
+    fn get_insight_degraded_at(&self, id: InsightId) -> Result<Option<Timestamp>>;
+
+    /// Clears the degraded flag of an insight.
+    ///
+    /// Returns `true` if the insight was degraded.
+    fn clear_insight_degraded(&self, id: InsightId) -> Result<bool>;
+
+    // =========================================================================
+    // Moderation
+    // =========================================================================
+
+    /// Stores a collective's moderation policy, replacing any earlier one.
+    ///
+    /// Removed automatically when the collective is deleted.
+    fn save_moderation_policy(
+        &self,
+        collective_id: CollectiveId,
+        policy: &ModerationPolicy,
+    ) -> Result<()>;
+
+    /// Returns a collective's moderation policy, or `None` if none was set.
+    fn get_moderation_policy(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Option<ModerationPolicy>>;
+
+    /// Stores a collective's content policy, replacing any earlier one.
+    ///
+    /// Removed automatically when the collective is deleted.
+    fn save_content_policy(
+        &self,
+        collective_id: CollectiveId,
+        policy: &ContentPolicy,
+    ) -> Result<()>;
+
+    /// Returns a collective's content policy, or `None` if none was set.
+    fn get_content_policy(&self, collective_id: CollectiveId) -> Result<Option<ContentPolicy>>;
+
+    /// Stores a collective's quota, replacing any earlier one.
+    ///
+    /// Removed automatically when the collective is deleted.
+    fn save_collective_quota(
+        &self,
+        collective_id: CollectiveId,
+        quota: &CollectiveQuota,
+    ) -> Result<()>;
+
+    /// Returns a collective's quota, or `None` if none was set.
+    fn get_collective_quota(&self, collective_id: CollectiveId) -> Result<Option<CollectiveQuota>>;
+
+    /// Stores a collective's text normalization, or removes it with `None`.
+    ///
+    /// Removed automatically when the collective is deleted.
+    fn save_text_normalization(
+        &self,
+        collective_id: CollectiveId,
+        normalization: Option<&TextNormalization>,
+    ) -> Result<()>;
+
+    /// Returns a collective's text normalization, or `None` if none was set.
+    fn get_text_normalization(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Option<TextNormalization>>;
+
+    /// Stores a standing interest, replacing the one with the same agent
+    /// and name in the collective.
+    ///
+    /// Removed automatically when the collective is deleted.
+    fn upsert_interest(&self, collective_id: CollectiveId, interest: &Interest) -> Result<()>;
+
+    /// Removes an agent's named interest. Returns `true` if it existed.
+    fn remove_interest(
+        &self,
+        collective_id: CollectiveId,
+        agent_id: &str,
+        name: &str,
+    ) -> Result<bool>;
+
+    /// Returns a collective's standing interests.
+    fn get_interests(&self, collective_id: CollectiveId) -> Result<Vec<Interest>>;
+
+    /// Queues an experience for review.
+    ///
+    /// Stored in `PENDING_EXPERIENCES_TABLE`. Removed automatically when
+    /// the experience is deleted.
+    fn mark_experience_pending(
+        &self,
+        collective_id: CollectiveId,
+        id: ExperienceId,
+        at: Timestamp,
+    ) -> Result<()>;
+
+    /// Returns `true` if the experience is waiting for review.
+    fn is_experience_pending(&self, collective_id: CollectiveId, id: ExperienceId) -> Result<bool>;
+
+    /// Removes an experience from the review queue.
+    ///
+    /// Returns `true` if the experience was pending.
+    fn clear_experience_pending(
+        &self,
+        collective_id: CollectiveId,
+        id: ExperienceId,
+    ) -> Result<bool>;
+
+    /// Lists a collective's pending experiences with the time each was
+    /// queued, ordered by ExperienceId.
+    fn list_pending_experience_ids(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Vec<(ExperienceId, Timestamp)>>;
+
     /// Lists all insight IDs belonging to a collective.
     ///
     /// Used to rebuild HNSW indexes from stored insights on startup.
     /// Iterates the `INSIGHTS_BY_COLLECTIVE_TABLE` multimap.
     fn list_insight_ids_in_collective(&self, id: CollectiveId) -> Result<Vec<InsightId>>;
 
+    /// Lists the IDs of insights citing an experience as a source.
+    ///
+    /// Point lookup on `INSIGHTS_BY_SOURCE_TABLE`; order is by InsightId.
+    fn list_insight_ids_citing(&self, experience_id: ExperienceId) -> Result<Vec<InsightId>>;
+
     /// Deletes all insights belonging to a collective.
     ///
     /// Used for cascade deletion when a collective is removed.
@@ -364,12 +729,135 @@ pub trait StorageEngine: Send + Sync {
     /// no guaranteed order.
     fn list_activities_in_collective(&self, collective_id: CollectiveId) -> Result<Vec<Activity>>;
 
+    /// Lists the agent IDs in a collective whose activity advertises
+    /// `capability` (exact match), via the capability index.
+    fn list_agent_ids_with_capability(
+        &self,
+        collective_id: CollectiveId,
+        capability: &str,
+    ) -> Result<Vec<String>>;
+
     /// Deletes all activities belonging to a collective.
     ///
     /// Used for cascade deletion when a collective is removed.
     /// Returns the count of deleted activities.
     fn delete_activities_by_collective(&self, collective_id: CollectiveId) -> Result<u64>;
 
+    // =========================================================================
+    // Bookmark Operations
+    // =========================================================================
+
+    /// Adds an experience to an agent's bookmarks.
+    ///
+    /// Returns `true` if the bookmark was added, `false` if it already existed.
+    fn add_bookmark(&self, agent_id: &str, experience_id: ExperienceId) -> Result<bool>;
+
+    /// Removes an experience from an agent's bookmarks.
+    ///
+    /// Returns `true` if the bookmark existed and was removed.
+    fn remove_bookmark(&self, agent_id: &str, experience_id: ExperienceId) -> Result<bool>;
+
+    /// Lists the experience IDs an agent has bookmarked, oldest experience first.
+    fn list_bookmark_ids(&self, agent_id: &str) -> Result<Vec<ExperienceId>>;
+
+    /// Lists the agents that have bookmarked an experience, sorted.
+    fn list_bookmarking_agent_ids(&self, experience_id: ExperienceId) -> Result<Vec<String>>;
+
+    // =========================================================================
+    // User and Task Links
+    // =========================================================================
+
+    /// Lists the IDs of experiences linked to a user, oldest first.
+    fn list_experience_ids_by_user(&self, user_id: &str) -> Result<Vec<ExperienceId>>;
+
+    /// Lists the IDs of experiences recorded under a task, oldest first.
+    fn list_experience_ids_by_task(&self, task_id: &str) -> Result<Vec<ExperienceId>>;
+
+    /// Lists the `(path, experience)` entries of a collective's related-file
+    /// index whose path starts with `prefix`, sorted by path.
+    ///
+    /// An empty prefix lists the whole collective.
+    fn list_experience_files(
+        &self,
+        collective_id: CollectiveId,
+        prefix: &str,
+    ) -> Result<Vec<(String, ExperienceId)>>;
+
+    /// Lists the distinct tasks an agent recorded experiences under, sorted.
+    fn list_tasks_by_agent(&self, agent_id: &str) -> Result<Vec<TaskId>>;
+
+    /// Attaches a summary insight to a task's episode, replacing any earlier
+    /// one; `None` detaches it.
+    fn set_episode_summary(&self, task_id: &str, insight_id: Option<InsightId>) -> Result<()>;
+
+    /// Returns the summary insight attached to a task's episode, if any.
+    fn get_episode_summary(&self, task_id: &str) -> Result<Option<InsightId>>;
+
+    // =========================================================================
+    // Lock Operations
+    // =========================================================================
+
+    /// Attempts to grant `owner` a lease on `(collective_id, name)` until
+    /// `expires_at`, atomically in one write transaction.
+    ///
+    /// - No lease, or the stored lease expired at `now`: grants a new lease
+    ///   with the next fencing token.
+    /// - Unexpired lease held by `owner`: renews it (same token, new expiry).
+    /// - Unexpired lease held by someone else: writes nothing.
+    ///
+    /// Returns the lease in effect afterwards. If its `owner` differs from
+    /// `owner`, the lock is held elsewhere.
+    fn acquire_lock(
+        &self,
+        collective_id: CollectiveId,
+        name: &str,
+        owner: &str,
+        now: Timestamp,
+        expires_at: Timestamp,
+    ) -> Result<Lease>;
+
+    /// Removes the lease on `(collective_id, name)` if its fencing token
+    /// equals `fencing_token`.
+    ///
+    /// Returns `false` (and writes nothing) if no lease exists or the token
+    /// doesn't match.
+    fn release_lock(
+        &self,
+        collective_id: CollectiveId,
+        name: &str,
+        fencing_token: u64,
+    ) -> Result<bool>;
+
+    /// Retrieves the stored lease for `(collective_id, name)`, which may
+    /// already be expired.
+    fn get_lock(&self, collective_id: CollectiveId, name: &str) -> Result<Option<Lease>>;
+
+    /// Lists every stored lease in a collective, including expired ones.
+    fn list_locks_in_collective(&self, collective_id: CollectiveId) -> Result<Vec<Lease>>;
+
+    /// Deletes all leases belonging to a collective.
+    ///
+    /// Used for cascade deletion when a collective is removed.
+    /// Returns the count of deleted leases.
+    fn delete_locks_by_collective(&self, collective_id: CollectiveId) -> Result<u64>;
+
+    // =========================================================================
+    // Knowledge Gap Operations
+    // =========================================================================
+
+    /// Stores searches that scored below the knowledge gap threshold, in
+    /// one transaction.
+    fn save_knowledge_gaps(&self, records: &[KnowledgeGapRecord]) -> Result<()>;
+
+    /// Lists a collective's knowledge gap records, oldest first.
+    fn list_knowledge_gaps(&self, collective_id: CollectiveId) -> Result<Vec<KnowledgeGapRecord>>;
+
+    /// Deletes all knowledge gap records belonging to a collective.
+    ///
+    /// Used to clear gaps and for cascade deletion when a collective is
+    /// removed. Returns the count of deleted records.
+    fn delete_knowledge_gaps_by_collective(&self, collective_id: CollectiveId) -> Result<u64>;
+
     // =========================================================================
     // Paginated List Operations (PulseVision)
     // =========================================================================
@@ -387,7 +875,9 @@ pub trait StorageEngine: Send + Sync {
 
     /// Lists all relations in a collective with pagination.
     ///
-    /// Scans relations whose source experience belongs to the collective.
+    /// Relations are attributed to their source experience's collective and
+    /// returned in creation order (oldest first) via the relations-by-collective
+    /// index.
     fn list_relations_in_collective(
         &self,
         collective_id: CollectiveId,
@@ -395,6 +885,12 @@ pub trait StorageEngine: Send + Sync {
         offset: usize,
     ) -> Result<Vec<crate::relation::ExperienceRelation>>;
 
+    /// Lists every relation ID in a collective, oldest first.
+    fn list_relation_ids_in_collective(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Vec<RelationId>>;
+
     /// Lists insight IDs in a collective with pagination.
     fn list_insight_ids_paginated(
         &self,
@@ -403,6 +899,36 @@ pub trait StorageEngine: Send + Sync {
         offset: usize,
     ) -> Result<Vec<InsightId>>;
 
+    /// Lists up to `limit` experiences in a collective strictly after the
+    /// cursor's position, in its direction, as `(timestamp, id)` index keys.
+    fn list_experience_keys_after(
+        &self,
+        collective_id: CollectiveId,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<Vec<(Timestamp, ExperienceId)>>;
+
+    /// Lists up to `limit` relations in a collective strictly after the
+    /// cursor's position, in its direction, as `(created_at, id)` index keys.
+    fn list_relation_keys_after(
+        &self,
+        collective_id: CollectiveId,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<Vec<(Timestamp, RelationId)>>;
+
+    /// Lists up to `limit` insight IDs in a collective strictly after the
+    /// cursor's position, in its direction.
+    ///
+    /// The insight index is keyed by ID alone, which is time-ordered
+    /// (UUID v7), so only the cursor's ID is compared.
+    fn list_insight_ids_after(
+        &self,
+        collective_id: CollectiveId,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<Vec<InsightId>>;
+
     // =========================================================================
     // Watch Event Operations (E4-S02) — Cross-Process Change Detection
     // =========================================================================
@@ -431,22 +957,29 @@ pub trait StorageEngine: Send + Sync {
         limit: usize,
     ) -> Result<(Vec<schema::WatchEventRecord>, u64)>;
 
-    // =========================================================================
-    // Sync Operations (feature: sync)
-    // =========================================================================
-
     /// Retrieves ALL watch events (all entity types) with their sequence numbers.
     ///
     /// Unlike `poll_watch_events()` which returns records without sequences,
     /// this method returns `(sequence, record)` pairs needed by the sync
-    /// pusher to construct `SyncChange` objects.
-    #[cfg(feature = "sync")]
+    /// pusher to construct `SyncChange` objects, and by
+    /// [`PulseDB::changes_since()`](crate::PulseDB::changes_since).
     fn poll_sync_events(
         &self,
         since_seq: u64,
         limit: usize,
     ) -> Result<Vec<(u64, schema::WatchEventRecord)>>;
 
+    /// Registers a callback run after each committed write that recorded
+    /// watch events, replacing any previous one.
+    ///
+    /// The callback receives the engine so it can read the new events.
+    /// Engines without a change log ignore it.
+    fn set_commit_listener(&self, _listener: CommitListener) {}
+
+    // =========================================================================
+    // Sync Operations (feature: sync)
+    // =========================================================================
+
     /// Returns the persistent instance ID for this database.
     ///
     /// Generated on first open and stable across restarts.
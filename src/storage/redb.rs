@@ -16,31 +16,70 @@
 //! - `./pulse.db` - Main database file
 //! - `./pulse.db.lock` - Lock file for writer coordination (may not be visible)
 
+use std::ops::Bound;
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::Duration;
 
-use ::redb::{Database, ReadableTable};
+use ::redb::{
+    Database, Key, MultimapTableDefinition, MultimapTableHandle, ReadTransaction,
+    ReadableMultimapTable, ReadableTable, ReadableTableMetadata, TableDefinition, TableHandle,
+    Value, WriteTransaction,
+};
+use half::f16;
+use serde::{Deserialize, Serialize};
 use tracing::{debug, info, instrument, warn};
 
 use crate::activity::Activity;
-use crate::collective::Collective;
-use crate::experience::{Experience, ExperienceUpdate};
-use crate::insight::DerivedInsight;
-use crate::relation::{ExperienceRelation, RelationType};
-use crate::types::{CollectiveId, ExperienceId, InsightId, RelationId, Timestamp};
-
+use crate::collective::{Collective, CollectiveDetails, CollectiveQuota};
+use crate::cursor::Cursor;
+use crate::experience::{ContentPolicy, Experience, ExperienceHead, ExperienceUpdate};
+use crate::insight::{DerivedInsight, InsightType};
+use crate::lock::Lease;
+use crate::metrics::{LockWaitRecorder, LockWaitStats};
+use crate::moderation::ModerationPolicy;
+use crate::relation::{ExperienceRelation, RelationSuggestion, RelationType};
+use crate::search::{ExperienceNeighbor, KnowledgeGapRecord};
+use crate::types::{CollectiveId, ExperienceId, InsightId, RelationId, TaskId, Timestamp, UserId};
+use crate::watch::Interest;
+
+use super::cache::ExperienceCache;
+use super::codec::{self, CodecId, Record};
+#[cfg(feature = "sync")]
+use super::schema::INSTANCE_ID_KEY;
 use super::schema::{
-    decode_collective_from_activity_key, encode_activity_key, encode_type_index_key,
-    DatabaseMetadata, EntityTypeTag, ExperienceTypeTag, WatchEventRecord, WatchEventTypeTag,
-    ACTIVITIES_TABLE, COLLECTIVES_TABLE, EMBEDDINGS_TABLE, EXPERIENCES_BY_COLLECTIVE_TABLE,
-    EXPERIENCES_BY_TYPE_TABLE, EXPERIENCES_TABLE, INSIGHTS_BY_COLLECTIVE_TABLE, INSIGHTS_TABLE,
-    METADATA_TABLE, RELATIONS_BY_SOURCE_TABLE, RELATIONS_BY_TARGET_TABLE, RELATIONS_TABLE,
-    SCHEMA_VERSION, WAL_SEQUENCE_KEY, WATCH_EVENTS_TABLE,
+    day_bucket, decode_agent_id_from_activity_key, decode_collective_from_activity_key,
+    encode_activity_key, encode_capability_key, encode_day_key, encode_file_key, encode_gap_key,
+    encode_insight_type_key, encode_lock_key, encode_pending_key, encode_suggestion_key,
+    encode_type_index_key, DatabaseMetadata, EntityTypeTag, ExperienceMeta, ExperienceTypeTag,
+    WatchEventRecord, WatchEventTypeTag, ACTIVITIES_TABLE, ACTIVITY_CAPABILITIES_TABLE,
+    AGENTS_BY_CAPABILITY_TABLE, BOOKMARKED_BY_TABLE, BOOKMARKS_TABLE, COLLECTIVES_BY_OWNER_TABLE,
+    COLLECTIVES_TABLE, COLLECTIVE_CHILDREN_TABLE, COLLECTIVE_DETAILS_TABLE,
+    COLLECTIVE_INDEX_KINDS_TABLE, COLLECTIVE_NORMALIZATION_TABLE, COLLECTIVE_PARENTS_TABLE,
+    COLLECTIVE_QUOTAS_TABLE, CONTENT_POLICIES_TABLE, CONTENT_STORAGE_EXTERNAL,
+    CONTENT_STORAGE_INLINE, CONTENT_STORAGE_KEY, DEGRADED_INSIGHTS_TABLE, EMBEDDINGS_TABLE,
+    EMBEDDING_STORAGE_F16, EMBEDDING_STORAGE_F32, EMBEDDING_STORAGE_INT8, EMBEDDING_STORAGE_KEY,
+    EPISODE_SUMMARIES_TABLE, EXPERIENCES_BY_COLLECTIVE_TABLE, EXPERIENCES_BY_DAY_TABLE,
+    EXPERIENCES_BY_EXPIRY_TABLE, EXPERIENCES_BY_FILE_TABLE, EXPERIENCES_BY_TASK_TABLE,
+    EXPERIENCES_BY_TYPE_TABLE, EXPERIENCES_BY_USER_TABLE, EXPERIENCES_TABLE,
+    EXPERIENCE_APPLICATIONS_TABLE, EXPERIENCE_ATTRIBUTION_TABLE, EXPERIENCE_EXPIRY_TABLE,
+    EXPERIENCE_META_TABLE, EXPERIENCE_NEIGHBORS_TABLE, EXPERIENCE_USERS_TABLE, INDEX_KIND_IVF,
+    INSIGHTS_BY_COLLECTIVE_TABLE, INSIGHTS_BY_SOURCE_TABLE, INSIGHTS_BY_TYPE_TABLE, INSIGHTS_TABLE,
+    INTERESTS_TABLE, KNOWLEDGE_GAPS_TABLE, LOCKS_TABLE, LOCK_FENCING_TOKEN_KEY, METADATA_TABLE,
+    MODERATION_POLICIES_TABLE, PENDING_EXPERIENCES_TABLE, RELATIONS_BY_COLLECTIVE_TABLE,
+    RELATIONS_BY_SOURCE_TABLE, RELATIONS_BY_TARGET_TABLE, RELATIONS_TABLE,
+    RELATION_SUGGESTIONS_TABLE, SCHEMA_VERSION, SYNC_CURSORS_TABLE, TASKS_BY_AGENT_TABLE,
+    WAL_SEQUENCE_KEY, WATCH_EVENTS_TABLE,
 };
-#[cfg(feature = "sync")]
-use super::schema::{INSTANCE_ID_KEY, SYNC_CURSORS_TABLE};
-use super::StorageEngine;
-use crate::config::{Config, EmbeddingDimension};
-use crate::error::{PulseDBError, Result, StorageError, ValidationError};
+use super::write_gate::{GatedWrite, WriteGate, WriteGateGuard};
+use super::{CommitListener, ExperienceDeletion, StorageEngine};
+use crate::config::{
+    Config, ContentStorage, EmbeddingDimension, EmbeddingStorage, InsightSourceCascade,
+    VectorIndexKind, WriteRetryConfig,
+};
+use crate::deadline::Deadline;
+use crate::embedding::TextNormalization;
+use crate::error::{PulseDBError, Result, StorageError, TimedOperation, ValidationError};
 
 /// Metadata key in the metadata table.
 const METADATA_KEY: &str = "db_metadata";
@@ -54,7 +93,6 @@ const METADATA_KEY: &str = "db_metadata";
 ///
 /// `RedbStorage` is `Send + Sync`. redb handles internal synchronization
 /// using MVCC for readers and exclusive locking for writers.
-#[derive(Debug)]
 pub struct RedbStorage {
     /// The redb database handle.
     db: Database,
@@ -65,11 +103,47 @@ pub struct RedbStorage {
     /// Path to the database file.
     path: PathBuf,
 
+    /// Encoding of every value in `EMBEDDINGS_TABLE`.
+    embedding_storage: EmbeddingStorage,
+
+    /// Recently read experiences, invalidated on every experience write.
+    experience_cache: ExperienceCache,
+
+    /// Time spent queueing for write transactions.
+    write_waits: LockWaitRecorder,
+
+    /// How long writes wait for the write lock before failing as busy.
+    write_retry: WriteRetryConfig,
+
+    /// Queue in front of redb's write lock, so waits can time out.
+    write_gate: WriteGate,
+
+    /// Limit on waiting for a write transaction.
+    write_timeout: Option<Duration>,
+
+    /// Called after each commit that recorded watch events.
+    commit_listener: RwLock<Option<CommitListener>>,
+
     /// Persistent instance ID for sync protocol (only with `sync` feature).
     #[cfg(feature = "sync")]
     instance_id: crate::sync::InstanceId,
 }
 
+impl std::fmt::Debug for RedbStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedbStorage")
+            .field("db", &self.db)
+            .field("metadata", &self.metadata)
+            .field("path", &self.path)
+            .field("embedding_storage", &self.embedding_storage)
+            .field("experience_cache", &self.experience_cache)
+            .field("write_waits", &self.write_waits)
+            .field("write_retry", &self.write_retry)
+            .field("write_timeout", &self.write_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
 impl RedbStorage {
     /// Opens or creates a database at the given path.
     ///
@@ -158,19 +232,62 @@ impl RedbStorage {
             let metadata_bytes = bincode::serialize(&metadata)
                 .map_err(|e| StorageError::serialization(e.to_string()))?;
             meta_table.insert(METADATA_KEY, metadata_bytes.as_slice())?;
+            meta_table.insert(
+                EMBEDDING_STORAGE_KEY,
+                [embedding_storage_tag(config.embedding_storage)].as_slice(),
+            )?;
+            meta_table.insert(
+                CONTENT_STORAGE_KEY,
+                [content_storage_tag(config.content_storage)].as_slice(),
+            )?;
 
             // Create other tables (they're created on first access)
             let _ = write_txn.open_table(COLLECTIVES_TABLE)?;
+            let _ = write_txn.open_multimap_table(COLLECTIVES_BY_OWNER_TABLE)?;
+            let _ = write_txn.open_table(COLLECTIVE_DETAILS_TABLE)?;
+            let _ = write_txn.open_table(COLLECTIVE_PARENTS_TABLE)?;
+            let _ = write_txn.open_multimap_table(COLLECTIVE_CHILDREN_TABLE)?;
+            let _ = write_txn.open_table(COLLECTIVE_INDEX_KINDS_TABLE)?;
             let _ = write_txn.open_table(EXPERIENCES_TABLE)?;
             let _ = write_txn.open_table(EMBEDDINGS_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_ATTRIBUTION_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_USERS_TABLE)?;
+            let _ = write_txn.open_multimap_table(EXPERIENCES_BY_USER_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_EXPIRY_TABLE)?;
+            let _ = write_txn.open_multimap_table(EXPERIENCES_BY_EXPIRY_TABLE)?;
+            let _ = write_txn.open_multimap_table(EXPERIENCES_BY_TASK_TABLE)?;
+            let _ = write_txn.open_multimap_table(TASKS_BY_AGENT_TABLE)?;
+            let _ = write_txn.open_multimap_table(EXPERIENCES_BY_FILE_TABLE)?;
             let _ = write_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
+            let _ = write_txn.open_multimap_table(EXPERIENCES_BY_DAY_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_META_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_NEIGHBORS_TABLE)?;
             let _ = write_txn.open_multimap_table(EXPERIENCES_BY_TYPE_TABLE)?;
             let _ = write_txn.open_table(RELATIONS_TABLE)?;
             let _ = write_txn.open_multimap_table(RELATIONS_BY_SOURCE_TABLE)?;
             let _ = write_txn.open_multimap_table(RELATIONS_BY_TARGET_TABLE)?;
+            let _ = write_txn.open_multimap_table(RELATIONS_BY_COLLECTIVE_TABLE)?;
             let _ = write_txn.open_table(INSIGHTS_TABLE)?;
             let _ = write_txn.open_multimap_table(INSIGHTS_BY_COLLECTIVE_TABLE)?;
+            let _ = write_txn.open_multimap_table(INSIGHTS_BY_TYPE_TABLE)?;
+            let _ = write_txn.open_multimap_table(INSIGHTS_BY_SOURCE_TABLE)?;
+            let _ = write_txn.open_table(DEGRADED_INSIGHTS_TABLE)?;
+            let _ = write_txn.open_table(MODERATION_POLICIES_TABLE)?;
+            let _ = write_txn.open_table(PENDING_EXPERIENCES_TABLE)?;
+            let _ = write_txn.open_table(CONTENT_POLICIES_TABLE)?;
+            let _ = write_txn.open_table(COLLECTIVE_QUOTAS_TABLE)?;
+            let _ = write_txn.open_table(COLLECTIVE_NORMALIZATION_TABLE)?;
+            let _ = write_txn.open_table(EPISODE_SUMMARIES_TABLE)?;
+            let _ = write_txn.open_table(INTERESTS_TABLE)?;
             let _ = write_txn.open_table(ACTIVITIES_TABLE)?;
+            let _ = write_txn.open_table(ACTIVITY_CAPABILITIES_TABLE)?;
+            let _ = write_txn.open_multimap_table(AGENTS_BY_CAPABILITY_TABLE)?;
+            let _ = write_txn.open_multimap_table(BOOKMARKS_TABLE)?;
+            let _ = write_txn.open_multimap_table(BOOKMARKED_BY_TABLE)?;
+            let _ = write_txn.open_table(LOCKS_TABLE)?;
+            let _ = write_txn.open_table(KNOWLEDGE_GAPS_TABLE)?;
+            let _ = write_txn.open_table(RELATION_SUGGESTIONS_TABLE)?;
             let _ = write_txn.open_table(WATCH_EVENTS_TABLE)?;
 
             // Sync tables and instance ID (behind feature gate)
@@ -209,6 +326,13 @@ impl RedbStorage {
             db,
             metadata,
             path,
+            embedding_storage: config.embedding_storage,
+            experience_cache: ExperienceCache::new(Self::experience_cache_capacity(config)),
+            write_waits: LockWaitRecorder::default(),
+            write_retry: config.write_retry.clone(),
+            write_gate: WriteGate::default(),
+            write_timeout: config.timeouts.write_transaction,
+            commit_listener: RwLock::new(None),
             #[cfg(feature = "sync")]
             instance_id,
         })
@@ -235,11 +359,31 @@ impl RedbStorage {
             bincode::deserialize::<DatabaseMetadata>(metadata_bytes.value())
                 .map_err(|e| StorageError::corrupted(format!("Invalid metadata format: {}", e)))?
         };
+        let stored_embedding_storage = {
+            let meta_table = read_txn.open_table(METADATA_TABLE)?;
+            let entry = meta_table.get(EMBEDDING_STORAGE_KEY)?;
+            match entry.as_ref().map(|e| e.value().first().copied()) {
+                None => EmbeddingStorage::F32,
+                Some(tag) => tag.and_then(embedding_storage_from_tag).ok_or_else(|| {
+                    StorageError::corrupted(format!("Unknown embedding storage tag {:?}", tag))
+                })?,
+            }
+        };
+        let stored_content_storage = {
+            let meta_table = read_txn.open_table(METADATA_TABLE)?;
+            let entry = meta_table.get(CONTENT_STORAGE_KEY)?;
+            match entry.as_ref().map(|e| e.value().first().copied()) {
+                None => None,
+                Some(tag) => Some(tag.and_then(content_storage_from_tag).ok_or_else(|| {
+                    StorageError::corrupted(format!("Unknown content storage tag {:?}", tag))
+                })?),
+            }
+        };
 
         drop(read_txn);
 
-        // Validate schema version (allow migration from v1 → v2)
-        if metadata.schema_version != SCHEMA_VERSION && metadata.schema_version != 1 {
+        // Validate schema version (allow migration from v1 and v2)
+        if metadata.schema_version > SCHEMA_VERSION || metadata.schema_version == 0 {
             warn!(
                 expected = SCHEMA_VERSION,
                 found = metadata.schema_version,
@@ -251,6 +395,7 @@ impl RedbStorage {
             }));
         }
         let needs_v2_migration = metadata.schema_version == 1;
+        let needs_v3_migration = metadata.schema_version < 3;
 
         // Validate embedding dimension
         if metadata.embedding_dimension != config.embedding_dimension {
@@ -267,28 +412,102 @@ impl RedbStorage {
             ));
         }
 
+        // Validate content storage: handles and raw text must not mix
+        if let Some(stored) = stored_content_storage {
+            if stored != config.content_storage {
+                warn!(
+                    expected = ?config.content_storage,
+                    found = ?stored,
+                    "Content storage mismatch"
+                );
+                return Err(PulseDBError::config(format!(
+                    "database stores content as {:?}, but content_storage is {:?}",
+                    stored, config.content_storage
+                )));
+            }
+        }
+
         // Update last_opened_at timestamp and bump schema version if migrating
         let mut metadata = metadata;
         metadata.touch();
-        if needs_v2_migration {
+        if needs_v3_migration {
             metadata.schema_version = SCHEMA_VERSION;
         }
 
         let write_txn = db.begin_write().map_err(StorageError::from)?;
         {
-            // Ensure watch_events table exists (migration for pre-E4-S02 databases)
-            let _ = write_txn.open_table(WATCH_EVENTS_TABLE)?;
-
             // Migrate WAL records from v1 → v2 (add entity_type field)
             if needs_v2_migration {
                 Self::migrate_wal_v1_to_v2(&write_txn)?;
                 info!("Migrated WAL records from schema v1 to v2");
             }
+            // Add codec headers before anything below decodes a record
+            if needs_v3_migration {
+                Self::migrate_records_to_v3(&write_txn)?;
+                info!("Migrated records from schema v2 to v3");
+            }
+
+            // Ensure watch_events table exists (migration for pre-E4-S02 databases)
+            let _ = write_txn.open_table(WATCH_EVENTS_TABLE)?;
+            // Tables added after the initial schema (created empty on first open)
+            let _ = write_txn.open_table(DEGRADED_INSIGHTS_TABLE)?;
+            let _ = write_txn.open_table(MODERATION_POLICIES_TABLE)?;
+            let _ = write_txn.open_table(PENDING_EXPERIENCES_TABLE)?;
+            let _ = write_txn.open_table(CONTENT_POLICIES_TABLE)?;
+            let _ = write_txn.open_table(COLLECTIVE_QUOTAS_TABLE)?;
+            let _ = write_txn.open_table(COLLECTIVE_NORMALIZATION_TABLE)?;
+            let _ = write_txn.open_table(EPISODE_SUMMARIES_TABLE)?;
+            let _ = write_txn.open_table(INTERESTS_TABLE)?;
+            Self::backfill_insight_type_index(&write_txn)?;
+            Self::backfill_relation_collective_index(&write_txn)?;
+            let _ = write_txn.open_table(ACTIVITY_CAPABILITIES_TABLE)?;
+            let _ = write_txn.open_multimap_table(AGENTS_BY_CAPABILITY_TABLE)?;
+            let _ = write_txn.open_table(LOCKS_TABLE)?;
+            let _ = write_txn.open_table(KNOWLEDGE_GAPS_TABLE)?;
+            let _ = write_txn.open_table(RELATION_SUGGESTIONS_TABLE)?;
+            let _ = write_txn.open_table(COLLECTIVE_PARENTS_TABLE)?;
+            let _ = write_txn.open_multimap_table(COLLECTIVE_CHILDREN_TABLE)?;
+            Self::backfill_collective_owner_index(&write_txn)?;
+            let _ = write_txn.open_multimap_table(BOOKMARKS_TABLE)?;
+            let _ = write_txn.open_multimap_table(BOOKMARKED_BY_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_ATTRIBUTION_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_USERS_TABLE)?;
+            let _ = write_txn.open_multimap_table(EXPERIENCES_BY_USER_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_EXPIRY_TABLE)?;
+            let _ = write_txn.open_multimap_table(EXPERIENCES_BY_EXPIRY_TABLE)?;
+            Self::backfill_task_indexes(&write_txn)?;
+            Self::backfill_file_index(&write_txn)?;
+            Self::backfill_day_index(&write_txn)?;
+            Self::backfill_experience_meta(&write_txn)?;
+            Self::backfill_experience_applications(&write_txn)?;
+            let _ = write_txn.open_table(COLLECTIVE_INDEX_KINDS_TABLE)?;
+            let _ = write_txn.open_table(EXPERIENCE_NEIGHBORS_TABLE)?;
+            let _ = write_txn.open_table(COLLECTIVE_DETAILS_TABLE)?;
+            Self::backfill_insight_source_index(&write_txn)?;
+
+            // Re-encode embeddings if the configured encoding changed
+            if stored_embedding_storage != config.embedding_storage {
+                Self::reencode_embeddings(
+                    &write_txn,
+                    stored_embedding_storage,
+                    config.embedding_storage,
+                )?;
+            }
 
             let mut meta_table = write_txn.open_table(METADATA_TABLE)?;
             let metadata_bytes = bincode::serialize(&metadata)
                 .map_err(|e| StorageError::serialization(e.to_string()))?;
             meta_table.insert(METADATA_KEY, metadata_bytes.as_slice())?;
+            meta_table.insert(
+                EMBEDDING_STORAGE_KEY,
+                [embedding_storage_tag(config.embedding_storage)].as_slice(),
+            )?;
+            if stored_content_storage.is_none() {
+                meta_table.insert(
+                    CONTENT_STORAGE_KEY,
+                    [content_storage_tag(config.content_storage)].as_slice(),
+                )?;
+            }
 
             // Ensure sync tables and instance ID exist (migration for pre-sync databases)
             #[cfg(feature = "sync")]
@@ -329,6 +548,13 @@ impl RedbStorage {
             db,
             metadata,
             path,
+            embedding_storage: config.embedding_storage,
+            experience_cache: ExperienceCache::new(Self::experience_cache_capacity(config)),
+            write_waits: LockWaitRecorder::default(),
+            write_retry: config.write_retry.clone(),
+            write_gate: WriteGate::default(),
+            write_timeout: config.timeouts.write_transaction,
+            commit_listener: RwLock::new(None),
             #[cfg(feature = "sync")]
             instance_id,
         })
@@ -343,6 +569,21 @@ impl RedbStorage {
         &self.db
     }
 
+    /// Begins a write transaction, recording how long it queued behind
+    /// the one in progress.
+    ///
+    /// Queueing is bounded by [`Config::write_retry`], after which the
+    /// write fails as busy, and by
+    /// [`TimeoutConfig::write_transaction`](crate::TimeoutConfig::write_transaction).
+    fn begin_write(&self) -> Result<GatedWrite<'_>> {
+        let deadline = Deadline::start(TimedOperation::WriteTransaction, self.write_timeout);
+        self.write_waits.time(|| {
+            let gate = acquire_write_gate(&self.write_gate, &self.write_retry, &deadline)?;
+            let txn = self.db.begin_write().map_err(StorageError::from)?;
+            Ok(GatedWrite::new(txn, gate))
+        })
+    }
+
     /// Increments the WAL sequence and records a watch event within an existing write transaction.
     ///
     /// This is the core of cross-process change detection. By executing within the caller's
@@ -399,7 +640,7 @@ impl RedbStorage {
             entity_type,
         };
         let record_bytes =
-            bincode::serialize(&record).map_err(|e| StorageError::serialization(e.to_string()))?;
+            codec::encode(&record).map_err(|e| StorageError::serialization(e.to_string()))?;
 
         let mut events_table = write_txn.open_table(WATCH_EVENTS_TABLE)?;
         events_table.insert(&seq_bytes, record_bytes.as_slice())?;
@@ -407,6 +648,365 @@ impl RedbStorage {
         Ok(new_seq)
     }
 
+    /// Commits a transaction that recorded watch events, then runs the
+    /// commit listener so in-process change subscribers see the events.
+    fn commit_wal(&self, write_txn: GatedWrite<'_>) -> Result<()> {
+        write_txn.commit().map_err(StorageError::from)?;
+        let listener = self
+            .commit_listener
+            .read()
+            .ok()
+            .and_then(|listener| listener.clone());
+        if let Some(listener) = listener {
+            listener(self);
+        }
+        Ok(())
+    }
+
+    /// Deletes an experience and, with a cascade, updates the insights
+    /// citing it in the same transaction.
+    fn delete_experience_with(
+        &self,
+        id: ExperienceId,
+        cascade: Option<InsightSourceCascade>,
+    ) -> Result<ExperienceDeletion> {
+        // First read the experience to get collective_id, timestamp, and type_tag
+        // (needed for cleaning up secondary indices and WAL event)
+        let (collective_id, timestamp, type_tag) = {
+            let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+            let exp_table = read_txn.open_table(EXPERIENCES_TABLE)?;
+
+            match exp_table.get(id.as_bytes())? {
+                Some(entry) => {
+                    let exp: Experience = codec::decode(entry.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    (
+                        exp.collective_id,
+                        exp.timestamp,
+                        exp.experience_type.type_tag(),
+                    )
+                }
+                None => return Ok(ExperienceDeletion::NotFound),
+            }
+        };
+
+        // Delete from all 4 tables in a single transaction
+        let write_txn = self.begin_write()?;
+        let outcome = match cascade {
+            Some(cascade) => {
+                match self.cascade_to_insights(&write_txn, id, collective_id, cascade)? {
+                    blocked @ ExperienceDeletion::Blocked(_) => return Ok(blocked),
+                    outcome => outcome,
+                }
+            }
+            None => ExperienceDeletion::Deleted {
+                collective_id,
+                citing: Vec::new(),
+                deleted_insights: Vec::new(),
+            },
+        };
+        remove_subject_links_for(&write_txn, id.as_bytes())?;
+        {
+            let mut exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
+            exp_table.remove(id.as_bytes())?;
+            let mut meta_table = write_txn.open_table(EXPERIENCE_META_TABLE)?;
+            meta_table.remove(id.as_bytes())?;
+            let mut apps_table = write_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE)?;
+            apps_table.remove(id.as_bytes())?;
+            let mut neighbors_table = write_txn.open_table(EXPERIENCE_NEIGHBORS_TABLE)?;
+            neighbors_table.remove(id.as_bytes())?;
+        }
+        {
+            let mut emb_table = write_txn.open_table(EMBEDDINGS_TABLE)?;
+            emb_table.remove(id.as_bytes())?;
+        }
+        {
+            let mut attr_table = write_txn.open_table(EXPERIENCE_ATTRIBUTION_TABLE)?;
+            attr_table.remove(id.as_bytes())?;
+        }
+        {
+            // Remove specific entry from by-collective multimap
+            let mut idx_table = write_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
+            let mut value = [0u8; 24];
+            value[..8].copy_from_slice(&timestamp.to_be_bytes());
+            value[8..24].copy_from_slice(id.as_bytes());
+            idx_table.remove(collective_id.as_bytes(), &value)?;
+
+            let mut day_table = write_txn.open_multimap_table(EXPERIENCES_BY_DAY_TABLE)?;
+            let day_key = encode_day_key(collective_id.as_bytes(), day_bucket(timestamp));
+            day_table.remove(&day_key, &value)?;
+        }
+        {
+            // Remove specific entry from by-type multimap
+            let mut type_table = write_txn.open_multimap_table(EXPERIENCES_BY_TYPE_TABLE)?;
+            let type_key = encode_type_index_key(collective_id.as_bytes(), type_tag);
+            type_table.remove(&type_key, id.as_bytes())?;
+        }
+        {
+            let mut pending = write_txn.open_table(PENDING_EXPERIENCES_TABLE)?;
+            pending.remove(&encode_pending_key(collective_id.as_bytes(), id.as_bytes()))?;
+        }
+        {
+            // Suggestions made for this experience; ones targeting it are
+            // skipped on read
+            let mut suggestions = write_txn.open_table(RELATION_SUGGESTIONS_TABLE)?;
+            let start = encode_suggestion_key(collective_id.as_bytes(), id.as_bytes(), &[0u8; 16]);
+            let end = encode_suggestion_key(collective_id.as_bytes(), id.as_bytes(), &[0xFF; 16]);
+            let mut keys = Vec::new();
+            for entry in suggestions.range::<&[u8; 48]>(&start..=&end)? {
+                let (key, _) = entry.map_err(StorageError::from)?;
+                keys.push(*key.value());
+            }
+            for key in &keys {
+                suggestions.remove(key)?;
+            }
+        }
+        remove_bookmarks_for(&write_txn, id.as_bytes())?;
+        // Record WAL event for cross-process change detection
+        self.increment_wal_and_record(
+            &write_txn,
+            id.as_bytes(),
+            collective_id,
+            EntityTypeTag::Experience,
+            WatchEventTypeTag::Deleted,
+            timestamp,
+        )?;
+        self.commit_wal(write_txn)?;
+        self.experience_cache.invalidate(&[id]);
+
+        debug!(id = %id, "Experience deleted");
+        Ok(outcome)
+    }
+
+    /// Applies `cascade` to the insights citing `experience_id`, inside the
+    /// transaction that deletes the experience.
+    ///
+    /// Returns [`ExperienceDeletion::Blocked`] without writing anything
+    /// when the cascade is `Block` and any insight cites the experience.
+    fn cascade_to_insights(
+        &self,
+        write_txn: &::redb::WriteTransaction,
+        experience_id: ExperienceId,
+        collective_id: CollectiveId,
+        cascade: InsightSourceCascade,
+    ) -> Result<ExperienceDeletion> {
+        let citing: Vec<InsightId> = {
+            let by_source = write_txn.open_multimap_table(INSIGHTS_BY_SOURCE_TABLE)?;
+            let mut ids = Vec::new();
+            for result in by_source.get(experience_id.as_bytes())? {
+                let value = result.map_err(StorageError::from)?;
+                ids.push(InsightId::from_bytes(*value.value()));
+            }
+            ids
+        };
+        let mut deleted_insights = Vec::new();
+        let now = Timestamp::now();
+        match cascade {
+            InsightSourceCascade::Block if !citing.is_empty() => {
+                return Ok(ExperienceDeletion::Blocked(citing));
+            }
+            InsightSourceCascade::Block => {}
+            InsightSourceCascade::Detach => {
+                for &insight_id in &citing {
+                    let mut table = write_txn.open_table(INSIGHTS_TABLE)?;
+                    let Some(entry) = table.get(insight_id.as_bytes())? else {
+                        continue;
+                    };
+                    let mut insight: DerivedInsight = codec::decode(entry.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    drop(entry);
+                    insight
+                        .source_experience_ids
+                        .retain(|source| *source != experience_id);
+
+                    let event = if insight.source_experience_ids.is_empty() {
+                        // Every insight must cite at least one experience
+                        drop(table);
+                        remove_insight_in(write_txn, insight_id)?;
+                        deleted_insights.push(insight_id);
+                        WatchEventTypeTag::Deleted
+                    } else {
+                        insight.updated_at = now;
+                        let bytes = codec::encode(&insight)
+                            .map_err(|e| StorageError::serialization(e.to_string()))?;
+                        table.insert(insight_id.as_bytes(), bytes.as_slice())?;
+                        drop(table);
+                        write_txn
+                            .open_multimap_table(INSIGHTS_BY_SOURCE_TABLE)?
+                            .remove(experience_id.as_bytes(), insight_id.as_bytes())?;
+                        WatchEventTypeTag::Updated
+                    };
+                    self.increment_wal_and_record(
+                        write_txn,
+                        insight_id.as_bytes(),
+                        insight.collective_id,
+                        EntityTypeTag::Insight,
+                        event,
+                        now,
+                    )?;
+                }
+            }
+            InsightSourceCascade::MarkDegraded => {
+                let mut degraded = write_txn.open_table(DEGRADED_INSIGHTS_TABLE)?;
+                for insight_id in &citing {
+                    degraded.insert(insight_id.as_bytes(), now.as_millis())?;
+                }
+            }
+        }
+        Ok(ExperienceDeletion::Deleted {
+            collective_id,
+            citing,
+            deleted_insights,
+        })
+    }
+
+    /// Writes an experience and its index entries in one transaction.
+    ///
+    /// With `reject_existing`, returns `false` without writing if the ID is
+    /// already taken.
+    fn write_experience(&self, experience: &Experience, reject_existing: bool) -> Result<bool> {
+        let write_txn = self.begin_write()?;
+        if !self.stage_experience(&write_txn, experience, reject_existing)? {
+            // Dropping the transaction aborts it
+            return Ok(false);
+        }
+        self.commit_wal(write_txn)?;
+        self.experience_cache.invalidate(&[experience.id]);
+
+        debug!(
+            id = %experience.id,
+            collective_id = %experience.collective_id,
+            "Experience saved"
+        );
+        Ok(true)
+    }
+
+    /// Writes an experience, its index entries, and its WAL event into an
+    /// open transaction.
+    ///
+    /// With `reject_existing`, returns `false` without writing anything if
+    /// the ID is already taken, including by an earlier write in the same
+    /// transaction.
+    fn stage_experience(
+        &self,
+        write_txn: &::redb::WriteTransaction,
+        experience: &Experience,
+        reject_existing: bool,
+    ) -> Result<bool> {
+        // Serialize experience (embedding is #[serde(skip)], excluded automatically)
+        let exp_bytes =
+            codec::encode(experience).map_err(|e| StorageError::serialization(e.to_string()))?;
+
+        // Encode the embedding in its stored layout
+        let emb_bytes = encode_embedding(self.embedding_storage, &experience.embedding);
+
+        let attribution_bytes = experience
+            .attribution
+            .as_ref()
+            .map(codec::encode)
+            .transpose()
+            .map_err(|e| StorageError::serialization(e.to_string()))?;
+
+        // Build index keys
+        let type_key = encode_type_index_key(
+            experience.collective_id.as_bytes(),
+            experience.experience_type.type_tag(),
+        );
+
+        {
+            // Main experience record
+            let mut exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
+            if reject_existing && exp_table.get(experience.id.as_bytes())?.is_some() {
+                return Ok(false);
+            }
+            exp_table.insert(experience.id.as_bytes(), exp_bytes.as_slice())?;
+
+            // Hot metadata sidecar
+            let mut meta_table = write_txn.open_table(EXPERIENCE_META_TABLE)?;
+            meta_table.insert(
+                experience.id.as_bytes(),
+                &ExperienceMeta::of(experience).to_bytes(),
+            )?;
+            let mut apps_table = write_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE)?;
+            apps_table.insert(experience.id.as_bytes(), experience.applications)?;
+        }
+        {
+            // Embedding vector (stored separately for compactness)
+            let mut emb_table = write_txn.open_table(EMBEDDINGS_TABLE)?;
+            emb_table.insert(experience.id.as_bytes(), emb_bytes.as_slice())?;
+        }
+        if let Some(bytes) = attribution_bytes {
+            let mut attr_table = write_txn.open_table(EXPERIENCE_ATTRIBUTION_TABLE)?;
+            attr_table.insert(experience.id.as_bytes(), bytes.as_slice())?;
+        }
+        if let Some(user) = experience.user_id.as_ref() {
+            // User link plus its reverse entry
+            let mut users = write_txn.open_table(EXPERIENCE_USERS_TABLE)?;
+            users.insert(experience.id.as_bytes(), user.as_str())?;
+            let mut by_user = write_txn.open_multimap_table(EXPERIENCES_BY_USER_TABLE)?;
+            by_user.insert(user.as_str(), experience.id.as_bytes())?;
+        }
+        if let Some(expires_at) = experience.expires_at {
+            // Expiry time plus its index entry
+            let mut expiry = write_txn.open_table(EXPERIENCE_EXPIRY_TABLE)?;
+            expiry.insert(experience.id.as_bytes(), expires_at.as_millis())?;
+            let mut by_expiry = write_txn.open_multimap_table(EXPERIENCES_BY_EXPIRY_TABLE)?;
+            by_expiry.insert(
+                experience.collective_id.as_bytes(),
+                &expiry_entry(expires_at, experience.id.as_bytes()),
+            )?;
+        }
+        if let Some(task) = experience.source_task.as_ref() {
+            // Task links: experiences per task, tasks per agent
+            let mut by_task = write_txn.open_multimap_table(EXPERIENCES_BY_TASK_TABLE)?;
+            by_task.insert(task.as_str(), experience.id.as_bytes())?;
+            let mut by_agent = write_txn.open_multimap_table(TASKS_BY_AGENT_TABLE)?;
+            by_agent.insert(
+                experience.source_agent.as_str(),
+                task_agent_entry(task.as_str(), experience.id.as_bytes()).as_slice(),
+            )?;
+        }
+        if !experience.related_files.is_empty() {
+            let mut by_file = write_txn.open_multimap_table(EXPERIENCES_BY_FILE_TABLE)?;
+            for path in &experience.related_files {
+                let key = encode_file_key(experience.collective_id.as_bytes(), path);
+                by_file.insert(key.as_slice(), experience.id.as_bytes())?;
+            }
+        }
+        {
+            // By-collective index: key=collective_id, value=timestamp+experience_id
+            let mut idx_table = write_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
+            // Value is [timestamp_be: 8 bytes][experience_id: 16 bytes] = 24 bytes
+            let mut value = [0u8; 24];
+            value[..8].copy_from_slice(&experience.timestamp.to_be_bytes());
+            value[8..24].copy_from_slice(experience.id.as_bytes());
+            idx_table.insert(experience.collective_id.as_bytes(), &value)?;
+
+            // Day bucket index: same value, keyed by collective + day
+            let mut day_table = write_txn.open_multimap_table(EXPERIENCES_BY_DAY_TABLE)?;
+            let day_key = encode_day_key(
+                experience.collective_id.as_bytes(),
+                day_bucket(experience.timestamp),
+            );
+            day_table.insert(&day_key, &value)?;
+        }
+        {
+            // By-type index: key=collective_id+type_tag, value=experience_id
+            let mut type_table = write_txn.open_multimap_table(EXPERIENCES_BY_TYPE_TABLE)?;
+            type_table.insert(&type_key, experience.id.as_bytes())?;
+        }
+        // Record WAL event for cross-process change detection
+        self.increment_wal_and_record(
+            write_txn,
+            experience.id.as_bytes(),
+            experience.collective_id,
+            EntityTypeTag::Experience,
+            WatchEventTypeTag::Created,
+            experience.timestamp,
+        )?;
+        Ok(true)
+    }
+
     /// Migrates WAL records from schema v1 to v2.
     ///
     /// V1 records have 4 fields: experience_id, collective_id, event_type, timestamp_ms.
@@ -446,62 +1046,638 @@ impl RedbStorage {
         Ok(())
     }
 
-    /// Returns the embedding dimension configured for this database.
-    #[inline]
-    pub fn embedding_dimension(&self) -> EmbeddingDimension {
-        self.metadata.embedding_dimension
+    /// Migrates record tables from schema v2 to v3.
+    ///
+    /// V2 stored headerless bincode; v3 prefixes each value with the
+    /// bincode codec tag. The payload bytes are unchanged.
+    fn migrate_records_to_v3(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        let mut count = 0;
+        count += prefix_codec_headers(write_txn, COLLECTIVES_TABLE)?;
+        count += prefix_codec_headers(write_txn, EXPERIENCES_TABLE)?;
+        count += prefix_codec_headers(write_txn, EXPERIENCE_ATTRIBUTION_TABLE)?;
+        count += prefix_codec_headers(write_txn, RELATIONS_TABLE)?;
+        count += prefix_codec_headers(write_txn, INSIGHTS_TABLE)?;
+        count += prefix_codec_headers(write_txn, ACTIVITIES_TABLE)?;
+        count += prefix_codec_headers(write_txn, ACTIVITY_CAPABILITIES_TABLE)?;
+        count += prefix_codec_headers(write_txn, LOCKS_TABLE)?;
+        count += prefix_codec_headers(write_txn, WATCH_EVENTS_TABLE)?;
+        // Defined here rather than via the feature-gated constant so cursors
+        // are migrated even when this build doesn't enable sync
+        count += prefix_codec_headers(
+            write_txn,
+            TableDefinition::<&[u8; 16], &[u8]>::new("sync_cursors"),
+        )?;
+        debug!(count, "Added codec headers to records");
+        Ok(())
     }
-}
 
-impl StorageEngine for RedbStorage {
-    // =========================================================================
-    // Lifecycle
-    // =========================================================================
+    /// Populates `INSIGHTS_BY_TYPE_TABLE` for databases created before it existed.
+    ///
+    /// Runs only when the index is empty but insights exist, so it is a
+    /// no-op after the first open.
+    fn backfill_insight_type_index(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        let mut type_table = write_txn.open_multimap_table(INSIGHTS_BY_TYPE_TABLE)?;
+        if !type_table.is_empty()? {
+            return Ok(());
+        }
+        let insights_table = write_txn.open_table(INSIGHTS_TABLE)?;
 
-    fn metadata(&self) -> &DatabaseMetadata {
-        &self.metadata
+        let mut count = 0usize;
+        for entry in insights_table.iter()? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            let insight: DerivedInsight = codec::decode(value.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            let key =
+                encode_insight_type_key(insight.collective_id.as_bytes(), insight.insight_type);
+            type_table.insert(&key, insight.id.as_bytes())?;
+            count += 1;
+        }
+
+        if count > 0 {
+            info!(count, "Backfilled insight type index");
+        }
+        Ok(())
     }
 
-    #[instrument(skip(self))]
-    fn close(self: Box<Self>) -> Result<()> {
-        info!("Closing storage engine");
+    /// Populates `INSIGHTS_BY_SOURCE_TABLE` for databases created before it existed.
+    ///
+    /// Runs only when the index is empty but insights exist, so it is a
+    /// no-op after the first open.
+    fn backfill_insight_source_index(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        if !write_txn
+            .open_multimap_table(INSIGHTS_BY_SOURCE_TABLE)?
+            .is_empty()?
+        {
+            return Ok(());
+        }
+        let insights_table = write_txn.open_table(INSIGHTS_TABLE)?;
 
-        // redb flushes all data durably on drop. Since `Database::drop` is
-        // infallible, this method currently always returns Ok(()). The Result
-        // return type is retained for API forward-compatibility if a future
-        // storage backend can report flush errors.
-        drop(self.db);
+        let mut count = 0usize;
+        for entry in insights_table.iter()? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            let insight: DerivedInsight = codec::decode(value.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            index_insight_sources(write_txn, &insight)?;
+            count += 1;
+        }
 
-        info!("Storage engine closed");
+        if count > 0 {
+            info!(count, "Backfilled insight source index");
+        }
         Ok(())
     }
 
-    fn path(&self) -> Option<&Path> {
-        Some(&self.path)
-    }
-
-    // =========================================================================
-    // Collective Storage Operations
-    // =========================================================================
+    /// Populates `RELATIONS_BY_COLLECTIVE_TABLE` for databases created before it existed.
+    ///
+    /// Relations are attributed to their source experience's collective.
+    /// Relations whose source experience no longer exists are skipped.
+    fn backfill_relation_collective_index(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        let mut idx_table = write_txn.open_multimap_table(RELATIONS_BY_COLLECTIVE_TABLE)?;
+        if !idx_table.is_empty()? {
+            return Ok(());
+        }
+        let rel_table = write_txn.open_table(RELATIONS_TABLE)?;
+        let exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
 
-    fn save_collective(&self, collective: &Collective) -> Result<()> {
-        let bytes = bincode::serialize(collective)
-            .map_err(|e| StorageError::serialization(e.to_string()))?;
+        let mut count = 0usize;
+        for entry in rel_table.iter()? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            let relation: ExperienceRelation = codec::decode(value.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            let Some(exp_entry) = exp_table.get(relation.source_id.as_bytes())? else {
+                continue;
+            };
+            let exp: Experience = codec::decode(exp_entry.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            idx_table.insert(
+                exp.collective_id.as_bytes(),
+                &relation_collective_entry(&relation),
+            )?;
+            count += 1;
+        }
+
+        if count > 0 {
+            info!(count, "Backfilled relation collective index");
+        }
+        Ok(())
+    }
+
+    /// Populates `COLLECTIVES_BY_OWNER_TABLE` from existing collectives.
+    ///
+    /// No-op when the index already has entries.
+    fn backfill_collective_owner_index(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        let mut idx_table = write_txn.open_multimap_table(COLLECTIVES_BY_OWNER_TABLE)?;
+        if !idx_table.is_empty()? {
+            return Ok(());
+        }
+        let table = write_txn.open_table(COLLECTIVES_TABLE)?;
+
+        let mut count = 0usize;
+        for entry in table.iter()? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            let collective: Collective = codec::decode(value.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            if let Some(owner) = collective.owner_id.as_deref() {
+                idx_table.insert(owner, collective.id.as_bytes())?;
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            info!(count, "Backfilled collective owner index");
+        }
+        Ok(())
+    }
+
+    /// Populates `EXPERIENCES_BY_TASK_TABLE` and `TASKS_BY_AGENT_TABLE`
+    /// from existing experiences.
+    ///
+    /// No-op when the task index already has entries.
+    fn backfill_task_indexes(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        let mut by_task = write_txn.open_multimap_table(EXPERIENCES_BY_TASK_TABLE)?;
+        if !by_task.is_empty()? {
+            return Ok(());
+        }
+        let mut by_agent = write_txn.open_multimap_table(TASKS_BY_AGENT_TABLE)?;
+        let table = write_txn.open_table(EXPERIENCES_TABLE)?;
+
+        let mut count = 0usize;
+        for entry in table.iter()? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            let experience: Experience = codec::decode(value.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            if let Some(task) = experience.source_task.as_ref() {
+                by_task.insert(task.as_str(), experience.id.as_bytes())?;
+                by_agent.insert(
+                    experience.source_agent.as_str(),
+                    task_agent_entry(task.as_str(), experience.id.as_bytes()).as_slice(),
+                )?;
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            info!(count, "Backfilled experience task indexes");
+        }
+        Ok(())
+    }
+
+    /// Populates `EXPERIENCES_BY_FILE_TABLE` from existing experiences.
+    ///
+    /// No-op when the file index already has entries.
+    fn backfill_file_index(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        let mut by_file = write_txn.open_multimap_table(EXPERIENCES_BY_FILE_TABLE)?;
+        if !by_file.is_empty()? {
+            return Ok(());
+        }
+        let table = write_txn.open_table(EXPERIENCES_TABLE)?;
+
+        let mut count = 0usize;
+        for entry in table.iter()? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            let experience: Experience = codec::decode(value.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            for path in &experience.related_files {
+                let key = encode_file_key(experience.collective_id.as_bytes(), path);
+                by_file.insert(key.as_slice(), experience.id.as_bytes())?;
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            info!(count, "Backfilled experience file index");
+        }
+        Ok(())
+    }
+
+    /// Populates `EXPERIENCES_BY_DAY_TABLE` from `EXPERIENCES_BY_COLLECTIVE_TABLE`.
+    ///
+    /// No-op when the day index already has entries.
+    fn backfill_day_index(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        let mut day_table = write_txn.open_multimap_table(EXPERIENCES_BY_DAY_TABLE)?;
+        if !day_table.is_empty()? {
+            return Ok(());
+        }
+        let idx_table = write_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
+
+        let mut count = 0usize;
+        for bucket in idx_table.iter()? {
+            let (collective, values) = bucket.map_err(StorageError::from)?;
+            let collective = *collective.value();
+            for result in values {
+                let value = result.map_err(StorageError::from)?;
+                let entry = *value.value();
+                let mut ts_bytes = [0u8; 8];
+                ts_bytes.copy_from_slice(&entry[..8]);
+                let timestamp = Timestamp::from_millis(i64::from_be_bytes(ts_bytes));
+                day_table.insert(&encode_day_key(&collective, day_bucket(timestamp)), &entry)?;
+                count += 1;
+            }
+        }
 
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        if count > 0 {
+            info!(count, "Backfilled experience day index");
+        }
+        Ok(())
+    }
+
+    /// Rewrites every stored embedding from one encoding to another.
+    ///
+    /// Works through the table in key order a chunk at a time, so only
+    /// one chunk of vectors is held in memory.
+    fn reencode_embeddings(
+        write_txn: &::redb::WriteTransaction,
+        from: EmbeddingStorage,
+        to: EmbeddingStorage,
+    ) -> Result<()> {
+        const CHUNK: usize = 1024;
+        let mut table = write_txn.open_table(EMBEDDINGS_TABLE)?;
+
+        let mut count = 0usize;
+        let mut last: Option<[u8; 16]> = None;
+        loop {
+            let range = match &last {
+                Some(key) => table.range::<&[u8; 16]>((Bound::Excluded(key), Bound::Unbounded))?,
+                None => table.range::<&[u8; 16]>(..)?,
+            };
+            let mut chunk = Vec::with_capacity(CHUNK);
+            for entry in range.take(CHUNK) {
+                let (key, value) = entry.map_err(StorageError::from)?;
+                chunk.push((*key.value(), decode_embedding(from, value.value())));
+            }
+            let Some((key, _)) = chunk.last() else {
+                break;
+            };
+            last = Some(*key);
+            for (key, embedding) in &chunk {
+                table.insert(key, &*encode_embedding(to, embedding))?;
+            }
+            count += chunk.len();
+        }
+
+        if count > 0 {
+            info!(count, ?from, ?to, "Re-encoded stored embeddings");
+        }
+        Ok(())
+    }
+
+    /// Populates `EXPERIENCE_APPLICATIONS_TABLE` from `EXPERIENCES_TABLE`.
+    ///
+    /// No-op when the table already has entries.
+    fn backfill_experience_applications(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        let mut apps_table = write_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE)?;
+        if !apps_table.is_empty()? {
+            return Ok(());
+        }
+        let table = write_txn.open_table(EXPERIENCES_TABLE)?;
+
+        let mut count = 0usize;
+        for entry in table.iter()? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            let experience: Experience = codec::decode(value.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            apps_table.insert(experience.id.as_bytes(), experience.applications)?;
+            count += 1;
+        }
+
+        if count > 0 {
+            info!(count, "Backfilled experience applications sidecar");
+        }
+        Ok(())
+    }
+
+    /// Populates `EXPERIENCE_META_TABLE` from `EXPERIENCES_TABLE`.
+    ///
+    /// No-op when the sidecar already has entries.
+    fn backfill_experience_meta(write_txn: &::redb::WriteTransaction) -> Result<()> {
+        let mut meta_table = write_txn.open_table(EXPERIENCE_META_TABLE)?;
+        if !meta_table.is_empty()? {
+            return Ok(());
+        }
+        let table = write_txn.open_table(EXPERIENCES_TABLE)?;
+
+        let mut count = 0usize;
+        for entry in table.iter()? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            let experience: Experience = codec::decode(value.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            meta_table.insert(
+                experience.id.as_bytes(),
+                &ExperienceMeta::of(&experience).to_bytes(),
+            )?;
+            count += 1;
+        }
+
+        if count > 0 {
+            info!(count, "Backfilled experience metadata sidecar");
+        }
+        Ok(())
+    }
+
+    /// Returns the experience cache size for `config`.
+    ///
+    /// Read-only handles don't cache: another process may be writing.
+    fn experience_cache_capacity(config: &Config) -> usize {
+        if config.read_only {
+            0
+        } else {
+            config.experience_cache_capacity
+        }
+    }
+
+    /// Returns the embedding dimension configured for this database.
+    #[inline]
+    pub fn embedding_dimension(&self) -> EmbeddingDimension {
+        self.metadata.embedding_dimension
+    }
+}
+
+impl StorageEngine for RedbStorage {
+    // =========================================================================
+    // Lifecycle
+    // =========================================================================
+
+    fn metadata(&self) -> &DatabaseMetadata {
+        &self.metadata
+    }
+
+    fn write_waits(&self) -> LockWaitStats {
+        self.write_waits.stats()
+    }
+
+    #[instrument(skip(self))]
+    fn close(self: Box<Self>) -> Result<()> {
+        info!("Closing storage engine");
+
+        // redb flushes all data durably on drop. Since `Database::drop` is
+        // infallible, this method currently always returns Ok(()). The Result
+        // return type is retained for API forward-compatibility if a future
+        // storage backend can report flush errors.
+        drop(self.db);
+
+        info!("Storage engine closed");
+        Ok(())
+    }
+
+    fn path(&self) -> Option<&Path> {
+        Some(&self.path)
+    }
+
+    #[instrument(skip(self), fields(dest = %dest.display()))]
+    fn snapshot_to(&self, dest: &Path) -> Result<()> {
+        if dest.exists() {
+            return Err(ValidationError::invalid_field(
+                "path",
+                format!("{} already exists", dest.display()),
+            )
+            .into());
+        }
+
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let target = Database::create(dest).map_err(StorageError::from)?;
+        let write_txn = target.begin_write().map_err(StorageError::from)?;
+
+        let mut copied = Vec::new();
+        copy_table(&read_txn, &write_txn, METADATA_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, COLLECTIVES_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, COLLECTIVE_DETAILS_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, COLLECTIVE_PARENTS_TABLE, &mut copied)?;
+        copy_table(
+            &read_txn,
+            &write_txn,
+            COLLECTIVE_INDEX_KINDS_TABLE,
+            &mut copied,
+        )?;
+        copy_table(&read_txn, &write_txn, EXPERIENCES_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, EMBEDDINGS_TABLE, &mut copied)?;
+        copy_table(
+            &read_txn,
+            &write_txn,
+            EXPERIENCE_ATTRIBUTION_TABLE,
+            &mut copied,
+        )?;
+        copy_table(&read_txn, &write_txn, EXPERIENCE_META_TABLE, &mut copied)?;
+        copy_table(
+            &read_txn,
+            &write_txn,
+            EXPERIENCE_APPLICATIONS_TABLE,
+            &mut copied,
+        )?;
+        copy_table(
+            &read_txn,
+            &write_txn,
+            EXPERIENCE_NEIGHBORS_TABLE,
+            &mut copied,
+        )?;
+        copy_table(&read_txn, &write_txn, EXPERIENCE_USERS_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, EXPERIENCE_EXPIRY_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, EPISODE_SUMMARIES_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, RELATIONS_TABLE, &mut copied)?;
+        copy_table(
+            &read_txn,
+            &write_txn,
+            RELATION_SUGGESTIONS_TABLE,
+            &mut copied,
+        )?;
+        copy_table(&read_txn, &write_txn, INSIGHTS_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, DEGRADED_INSIGHTS_TABLE, &mut copied)?;
+        copy_table(
+            &read_txn,
+            &write_txn,
+            MODERATION_POLICIES_TABLE,
+            &mut copied,
+        )?;
+        copy_table(
+            &read_txn,
+            &write_txn,
+            PENDING_EXPERIENCES_TABLE,
+            &mut copied,
+        )?;
+        copy_table(&read_txn, &write_txn, CONTENT_POLICIES_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, COLLECTIVE_QUOTAS_TABLE, &mut copied)?;
+        copy_table(
+            &read_txn,
+            &write_txn,
+            COLLECTIVE_NORMALIZATION_TABLE,
+            &mut copied,
+        )?;
+        copy_table(&read_txn, &write_txn, INTERESTS_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, ACTIVITIES_TABLE, &mut copied)?;
+        copy_table(
+            &read_txn,
+            &write_txn,
+            ACTIVITY_CAPABILITIES_TABLE,
+            &mut copied,
+        )?;
+        copy_table(&read_txn, &write_txn, LOCKS_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, KNOWLEDGE_GAPS_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, SYNC_CURSORS_TABLE, &mut copied)?;
+        copy_table(&read_txn, &write_txn, WATCH_EVENTS_TABLE, &mut copied)?;
+
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            COLLECTIVES_BY_OWNER_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            COLLECTIVE_CHILDREN_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            EXPERIENCES_BY_COLLECTIVE_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            EXPERIENCES_BY_TYPE_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(&read_txn, &write_txn, EXPERIENCES_BY_DAY_TABLE, &mut copied)?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            EXPERIENCES_BY_USER_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            EXPERIENCES_BY_EXPIRY_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            EXPERIENCES_BY_TASK_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(&read_txn, &write_txn, TASKS_BY_AGENT_TABLE, &mut copied)?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            EXPERIENCES_BY_FILE_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            RELATIONS_BY_SOURCE_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            RELATIONS_BY_TARGET_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            RELATIONS_BY_COLLECTIVE_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            INSIGHTS_BY_COLLECTIVE_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(&read_txn, &write_txn, INSIGHTS_BY_TYPE_TABLE, &mut copied)?;
+        copy_multimap_table(&read_txn, &write_txn, INSIGHTS_BY_SOURCE_TABLE, &mut copied)?;
+        copy_multimap_table(
+            &read_txn,
+            &write_txn,
+            AGENTS_BY_CAPABILITY_TABLE,
+            &mut copied,
+        )?;
+        copy_multimap_table(&read_txn, &write_txn, BOOKMARKS_TABLE, &mut copied)?;
+        copy_multimap_table(&read_txn, &write_txn, BOOKMARKED_BY_TABLE, &mut copied)?;
+
+        // A table this list doesn't know about would be silently lost
+        let names = read_txn
+            .list_tables()
+            .map_err(StorageError::from)?
+            .map(|t| t.name().to_string())
+            .chain(
+                read_txn
+                    .list_multimap_tables()
+                    .map_err(StorageError::from)?
+                    .map(|t| t.name().to_string()),
+            );
+        for name in names {
+            if !copied.contains(&name) {
+                return Err(PulseDBError::internal(format!(
+                    "snapshot does not cover table '{}'",
+                    name
+                )));
+            }
+        }
+
+        write_txn.commit().map_err(StorageError::from)?;
+        info!(tables = copied.len(), "Snapshot written");
+        Ok(())
+    }
+
+    // =========================================================================
+    // Collective Storage Operations
+    // =========================================================================
+
+    fn save_collective(&self, collective: &Collective) -> Result<()> {
+        let bytes =
+            codec::encode(collective).map_err(|e| StorageError::serialization(e.to_string()))?;
+
+        let write_txn = self.begin_write()?;
+        let event_type;
         {
             let mut table = write_txn.open_table(COLLECTIVES_TABLE)?;
-            table.insert(collective.id.as_bytes(), bytes.as_slice())?;
+            let previous = match table.insert(collective.id.as_bytes(), bytes.as_slice())? {
+                Some(old) => Some(
+                    codec::decode::<Collective>(old.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?,
+                ),
+                None => None,
+            };
+            event_type = match previous {
+                Some(_) => WatchEventTypeTag::Updated,
+                None => WatchEventTypeTag::Created,
+            };
+            let previous_owner = previous.and_then(|old| old.owner_id);
+
+            let mut owners = write_txn.open_multimap_table(COLLECTIVES_BY_OWNER_TABLE)?;
+            if let Some(owner) = previous_owner.as_deref() {
+                owners.remove(owner, collective.id.as_bytes())?;
+            }
+            if let Some(owner) = collective.owner_id.as_deref() {
+                owners.insert(owner, collective.id.as_bytes())?;
+            }
+
+            let mut details = write_txn.open_table(COLLECTIVE_DETAILS_TABLE)?;
+            match CollectiveDetails::of(collective) {
+                Some(value) => {
+                    let bytes = codec::encode(&value)
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    details.insert(collective.id.as_bytes(), bytes.as_slice())?;
+                }
+                None => {
+                    details.remove(collective.id.as_bytes())?;
+                }
+            }
         }
         self.increment_wal_and_record(
             &write_txn,
             collective.id.as_bytes(),
             collective.id,
             EntityTypeTag::Collective,
-            WatchEventTypeTag::Created,
+            event_type,
             collective.created_at,
         )?;
-        write_txn.commit().map_err(StorageError::from)?;
+        self.commit_wal(write_txn)?;
 
         debug!(id = %collective.id, name = %collective.name, "Collective saved");
         Ok(())
@@ -510,13 +1686,10 @@ impl StorageEngine for RedbStorage {
     fn get_collective(&self, id: CollectiveId) -> Result<Option<Collective>> {
         let read_txn = self.db.begin_read().map_err(StorageError::from)?;
         let table = read_txn.open_table(COLLECTIVES_TABLE)?;
+        let details = read_txn.open_table(COLLECTIVE_DETAILS_TABLE)?;
 
         match table.get(id.as_bytes())? {
-            Some(value) => {
-                let collective: Collective = bincode::deserialize(value.value())
-                    .map_err(|e| StorageError::serialization(e.to_string()))?;
-                Ok(Some(collective))
-            }
+            Some(value) => Ok(Some(decode_collective(value.value(), &details)?)),
             None => Ok(None),
         }
     }
@@ -524,26 +1697,92 @@ impl StorageEngine for RedbStorage {
     fn list_collectives(&self) -> Result<Vec<Collective>> {
         let read_txn = self.db.begin_read().map_err(StorageError::from)?;
         let table = read_txn.open_table(COLLECTIVES_TABLE)?;
+        let details = read_txn.open_table(COLLECTIVE_DETAILS_TABLE)?;
 
         let mut collectives = Vec::new();
         for result in table.iter()? {
             let (_, value) = result.map_err(StorageError::from)?;
-            let collective: Collective = bincode::deserialize(value.value())
-                .map_err(|e| StorageError::serialization(e.to_string()))?;
-            collectives.push(collective);
+            collectives.push(decode_collective(value.value(), &details)?);
         }
 
         Ok(collectives)
     }
 
+    fn list_collective_ids_by_owner(&self, owner_id: &str) -> Result<Vec<CollectiveId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(COLLECTIVES_BY_OWNER_TABLE)?;
+
+        let mut ids = Vec::new();
+        for result in table.get(owner_id)? {
+            let value = result.map_err(StorageError::from)?;
+            ids.push(CollectiveId::from_bytes(*value.value()));
+        }
+        Ok(ids)
+    }
+
     fn delete_collective(&self, id: CollectiveId) -> Result<bool> {
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         let existed;
         {
             let mut table = write_txn.open_table(COLLECTIVES_TABLE)?;
-            existed = table.remove(id.as_bytes())?.is_some();
+            let removed = match table.remove(id.as_bytes())? {
+                Some(old) => Some(
+                    codec::decode::<Collective>(old.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?,
+                ),
+                None => None,
+            };
+            existed = removed.is_some();
+
+            if let Some(owner) = removed.as_ref().and_then(|c| c.owner_id.as_deref()) {
+                let mut owners = write_txn.open_multimap_table(COLLECTIVES_BY_OWNER_TABLE)?;
+                owners.remove(owner, id.as_bytes())?;
+            }
+            let mut details = write_txn.open_table(COLLECTIVE_DETAILS_TABLE)?;
+            details.remove(id.as_bytes())?;
+
+            // Drop hierarchy links in both directions
+            let mut parents = write_txn.open_table(COLLECTIVE_PARENTS_TABLE)?;
+            let mut children = write_txn.open_multimap_table(COLLECTIVE_CHILDREN_TABLE)?;
+            if let Some(parent) = parents.remove(id.as_bytes())? {
+                let parent = *parent.value();
+                children.remove(&parent, id.as_bytes())?;
+            }
+            let orphans: Vec<[u8; 16]> = children
+                .remove_all(id.as_bytes())?
+                .map(|r| r.map(|v| *v.value()))
+                .collect::<std::result::Result<_, _>>()
+                .map_err(StorageError::from)?;
+            for child in &orphans {
+                parents.remove(child)?;
+            }
+
+            let mut kinds = write_txn.open_table(COLLECTIVE_INDEX_KINDS_TABLE)?;
+            kinds.remove(id.as_bytes())?;
+
+            let mut policies = write_txn.open_table(MODERATION_POLICIES_TABLE)?;
+            policies.remove(id.as_bytes())?;
+            let mut content_policies = write_txn.open_table(CONTENT_POLICIES_TABLE)?;
+            content_policies.remove(id.as_bytes())?;
+            let mut quotas = write_txn.open_table(COLLECTIVE_QUOTAS_TABLE)?;
+            quotas.remove(id.as_bytes())?;
+            let mut normalization = write_txn.open_table(COLLECTIVE_NORMALIZATION_TABLE)?;
+            normalization.remove(id.as_bytes())?;
+            let mut interests = write_txn.open_table(INTERESTS_TABLE)?;
+            interests.remove(id.as_bytes())?;
         }
-        write_txn.commit().map_err(StorageError::from)?;
+        // One event covers the collective's cascaded contents
+        if existed {
+            self.increment_wal_and_record(
+                &write_txn,
+                id.as_bytes(),
+                id,
+                EntityTypeTag::Collective,
+                WatchEventTypeTag::Deleted,
+                Timestamp::now(),
+            )?;
+        }
+        self.commit_wal(write_txn)?;
 
         if existed {
             debug!(id = %id, "Collective deleted");
@@ -551,6 +1790,94 @@ impl StorageEngine for RedbStorage {
         Ok(existed)
     }
 
+    // =========================================================================
+    // Collective Hierarchy Operations
+    // =========================================================================
+
+    fn set_collective_parent(&self, id: CollectiveId, parent: Option<CollectiveId>) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut parents = write_txn.open_table(COLLECTIVE_PARENTS_TABLE)?;
+            let mut children = write_txn.open_multimap_table(COLLECTIVE_CHILDREN_TABLE)?;
+
+            if let Some(old) = parents.remove(id.as_bytes())? {
+                let old = *old.value();
+                children.remove(&old, id.as_bytes())?;
+            }
+            if let Some(parent) = parent {
+                parents.insert(id.as_bytes(), parent.as_bytes())?;
+                children.insert(parent.as_bytes(), id.as_bytes())?;
+            }
+        }
+        self.increment_wal_and_record(
+            &write_txn,
+            id.as_bytes(),
+            id,
+            EntityTypeTag::Collective,
+            WatchEventTypeTag::Updated,
+            Timestamp::now(),
+        )?;
+        self.commit_wal(write_txn)?;
+
+        debug!(id = %id, parent = ?parent, "Collective parent set");
+        Ok(())
+    }
+
+    fn get_collective_parent(&self, id: CollectiveId) -> Result<Option<CollectiveId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(COLLECTIVE_PARENTS_TABLE)?;
+
+        Ok(table
+            .get(id.as_bytes())?
+            .map(|v| CollectiveId::from_bytes(*v.value())))
+    }
+
+    fn list_child_collective_ids(&self, id: CollectiveId) -> Result<Vec<CollectiveId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(COLLECTIVE_CHILDREN_TABLE)?;
+
+        let mut ids = Vec::new();
+        for result in table.get(id.as_bytes())? {
+            let value = result.map_err(StorageError::from)?;
+            ids.push(CollectiveId::from_bytes(*value.value()));
+        }
+        Ok(ids)
+    }
+
+    fn set_collective_index_kind(&self, id: CollectiveId, kind: VectorIndexKind) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut kinds = write_txn.open_table(COLLECTIVE_INDEX_KINDS_TABLE)?;
+            match kind {
+                VectorIndexKind::Hnsw => {
+                    kinds.remove(id.as_bytes())?;
+                }
+                VectorIndexKind::Ivf => {
+                    kinds.insert(id.as_bytes(), INDEX_KIND_IVF)?;
+                }
+            }
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+
+        debug!(id = %id, kind = ?kind, "Collective index kind set");
+        Ok(())
+    }
+
+    fn get_collective_index_kind(&self, id: CollectiveId) -> Result<VectorIndexKind> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(COLLECTIVE_INDEX_KINDS_TABLE)?;
+
+        match table.get(id.as_bytes())?.map(|v| v.value()) {
+            None => Ok(VectorIndexKind::Hnsw),
+            Some(INDEX_KIND_IVF) => Ok(VectorIndexKind::Ivf),
+            Some(tag) => Err(StorageError::corrupted(format!(
+                "unknown index kind tag {} for collective {}",
+                tag, id
+            ))
+            .into()),
+        }
+    }
+
     // =========================================================================
     // Experience Index Operations
     // =========================================================================
@@ -564,6 +1891,37 @@ impl StorageEngine for RedbStorage {
         Ok(count)
     }
 
+    fn collective_storage_bytes(&self, id: CollectiveId) -> Result<u64> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let by_collective = read_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
+        let experiences = read_txn.open_table(EXPERIENCES_TABLE)?;
+        let embeddings = read_txn.open_table(EMBEDDINGS_TABLE)?;
+
+        let mut bytes = 0u64;
+        for entry in by_collective.get(id.as_bytes())? {
+            let entry = entry.map_err(StorageError::from)?;
+            // Entry is [timestamp: 8 bytes][experience_id: 16 bytes]
+            let mut exp_id = [0u8; 16];
+            exp_id.copy_from_slice(&entry.value()[8..24]);
+            if let Some(record) = experiences.get(&exp_id)? {
+                bytes += record.value().len() as u64;
+            }
+            if let Some(embedding) = embeddings.get(&exp_id)? {
+                bytes += embedding.value().len() as u64;
+            }
+        }
+
+        let by_collective = read_txn.open_multimap_table(INSIGHTS_BY_COLLECTIVE_TABLE)?;
+        let insights = read_txn.open_table(INSIGHTS_TABLE)?;
+        for entry in by_collective.get(id.as_bytes())? {
+            let insight_id = *entry.map_err(StorageError::from)?.value();
+            if let Some(record) = insights.get(&insight_id)? {
+                bytes += record.value().len() as u64;
+            }
+        }
+        Ok(bytes)
+    }
+
     fn delete_experiences_by_collective(&self, id: CollectiveId) -> Result<u64> {
         // Phase 1: Read — collect experience IDs and relation IDs to delete
         let (exp_ids, relation_ids): (Vec<[u8; 16]>, Vec<[u8; 16]>) = {
@@ -604,12 +1962,22 @@ impl StorageEngine for RedbStorage {
         }
 
         // Phase 2: Write — delete from all tables in a single transaction
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
+        for exp_id in &exp_ids {
+            // Reads the experience record, so runs before it is removed
+            remove_subject_links_for(&write_txn, exp_id)?;
+        }
         {
             // Delete experience records
             let mut exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
+            let mut meta_table = write_txn.open_table(EXPERIENCE_META_TABLE)?;
+            let mut apps_table = write_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE)?;
+            let mut neighbors_table = write_txn.open_table(EXPERIENCE_NEIGHBORS_TABLE)?;
             for exp_id in &exp_ids {
                 exp_table.remove(exp_id)?;
+                meta_table.remove(exp_id)?;
+                apps_table.remove(exp_id)?;
+                neighbors_table.remove(exp_id)?;
             }
         }
         {
@@ -619,11 +1987,32 @@ impl StorageEngine for RedbStorage {
                 emb_table.remove(exp_id)?;
             }
         }
+        {
+            // Delete model attributions
+            let mut attr_table = write_txn.open_table(EXPERIENCE_ATTRIBUTION_TABLE)?;
+            for exp_id in &exp_ids {
+                attr_table.remove(exp_id)?;
+            }
+        }
         {
             // Clear the by-collective index for this collective
             let mut idx_table = write_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
             idx_table.remove_all(id.as_bytes())?;
         }
+        {
+            // Clear this collective's day buckets
+            let mut day_table = write_txn.open_multimap_table(EXPERIENCES_BY_DAY_TABLE)?;
+            let start = encode_day_key(id.as_bytes(), 0);
+            let end = encode_day_key(id.as_bytes(), u32::MAX);
+            let mut days = Vec::new();
+            for bucket in day_table.range::<&[u8; 20]>(&start..=&end)? {
+                let (key, _) = bucket.map_err(StorageError::from)?;
+                days.push(*key.value());
+            }
+            for day in &days {
+                day_table.remove_all(day)?;
+            }
+        }
         {
             // Clear the by-type index for all type variants of this collective
             let mut type_table = write_txn.open_multimap_table(EXPERIENCES_BY_TYPE_TABLE)?;
@@ -654,8 +2043,24 @@ impl StorageEngine for RedbStorage {
                     "Cascade-deleted relations for collective"
                 );
             }
+            let mut coll_idx = write_txn.open_multimap_table(RELATIONS_BY_COLLECTIVE_TABLE)?;
+            coll_idx.remove_all(id.as_bytes())?;
+        }
+        {
+            let mut pending = write_txn.open_table(PENDING_EXPERIENCES_TABLE)?;
+            for exp_id in &exp_ids {
+                pending.remove(&encode_pending_key(id.as_bytes(), exp_id))?;
+            }
+        }
+        for exp_id in &exp_ids {
+            remove_bookmarks_for(&write_txn, exp_id)?;
         }
         write_txn.commit().map_err(StorageError::from)?;
+        let erased: Vec<ExperienceId> = exp_ids
+            .iter()
+            .map(|id| ExperienceId::from_bytes(*id))
+            .collect();
+        self.experience_cache.invalidate(&erased);
 
         debug!(id = %id, count = count, "Cascade-deleted experiences for collective");
         Ok(count)
@@ -678,37 +2083,100 @@ impl StorageEngine for RedbStorage {
         Ok(ids)
     }
 
-    fn get_recent_experience_ids(
+    fn list_experience_ids_by_type(
         &self,
         collective_id: CollectiveId,
-        limit: usize,
-    ) -> Result<Vec<(ExperienceId, Timestamp)>> {
+        type_tag: ExperienceTypeTag,
+    ) -> Result<Vec<ExperienceId>> {
         let read_txn = self.db.begin_read().map_err(StorageError::from)?;
-        let table = read_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
+        let table = read_txn.open_multimap_table(EXPERIENCES_BY_TYPE_TABLE)?;
+        let key = encode_type_index_key(collective_id.as_bytes(), type_tag);
 
-        // Collect all (ExperienceId, Timestamp) pairs for this collective.
-        // Multimap values are sorted ascending by [timestamp_be][exp_id],
-        // so we collect all and then take from the end for newest-first.
-        let mut entries = Vec::new();
+        let mut ids = Vec::new();
+        for result in table.get(&key)? {
+            let value = result.map_err(StorageError::from)?;
+            ids.push(ExperienceId::from_bytes(*value.value()));
+        }
+
+        Ok(ids)
+    }
+
+    fn list_expired_experience_ids(
+        &self,
+        collective_id: CollectiveId,
+        now: Timestamp,
+    ) -> Result<Vec<ExperienceId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(EXPERIENCES_BY_EXPIRY_TABLE)?;
+
+        let mut ids = Vec::new();
         for result in table.get(collective_id.as_bytes())? {
             let value = result.map_err(StorageError::from)?;
             let entry = value.value();
-            // Entry layout: [timestamp_be: 8 bytes][experience_id: 16 bytes]
-            let mut ts_bytes = [0u8; 8];
-            ts_bytes.copy_from_slice(&entry[..8]);
-            let timestamp = Timestamp::from_millis(i64::from_be_bytes(ts_bytes));
-
+            // Entry is [expires_at: 8 bytes][experience_id: 16 bytes]
+            let mut expires_at = [0u8; 8];
+            expires_at.copy_from_slice(&entry[..8]);
+            if i64::from_be_bytes(expires_at) > now.as_millis() {
+                break;
+            }
             let mut exp_bytes = [0u8; 16];
             exp_bytes.copy_from_slice(&entry[8..24]);
-            entries.push((ExperienceId::from_bytes(exp_bytes), timestamp));
+            ids.push(ExperienceId::from_bytes(exp_bytes));
         }
 
-        // Take the last `limit` entries (newest) and reverse to get descending order
-        let start = entries.len().saturating_sub(limit);
-        let mut recent = entries.split_off(start);
-        recent.reverse();
+        Ok(ids)
+    }
+
+    fn get_recent_experience_ids(
+        &self,
+        collective_id: CollectiveId,
+        limit: usize,
+    ) -> Result<Vec<(ExperienceId, Timestamp)>> {
+        self.get_experience_ids_in_range(collective_id, None, None, limit)
+    }
+
+    fn get_experience_ids_in_range(
+        &self,
+        collective_id: CollectiveId,
+        since: Option<Timestamp>,
+        until: Option<Timestamp>,
+        limit: usize,
+    ) -> Result<Vec<(ExperienceId, Timestamp)>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(EXPERIENCES_BY_DAY_TABLE)?;
+
+        let start = encode_day_key(collective_id.as_bytes(), since.map_or(0, day_bucket));
+        let end = encode_day_key(collective_id.as_bytes(), until.map_or(u32::MAX, day_bucket));
+
+        // Buckets and the entries within them are chronological, so
+        // walking both backwards yields newest first
+        let mut entries = Vec::new();
+        for bucket in table.range::<&[u8; 20]>(&start..=&end)?.rev() {
+            let (_, values) = bucket.map_err(StorageError::from)?;
+            for result in values.rev() {
+                let value = result.map_err(StorageError::from)?;
+                let entry = value.value();
+                // Entry layout: [timestamp_be: 8 bytes][experience_id: 16 bytes]
+                let mut ts_bytes = [0u8; 8];
+                ts_bytes.copy_from_slice(&entry[..8]);
+                let timestamp = Timestamp::from_millis(i64::from_be_bytes(ts_bytes));
+                if until.is_some_and(|until| timestamp >= until) {
+                    continue;
+                }
+                if since.is_some_and(|since| timestamp < since) {
+                    return Ok(entries);
+                }
+
+                let mut exp_bytes = [0u8; 16];
+                exp_bytes.copy_from_slice(&entry[8..24]);
+                entries.push((ExperienceId::from_bytes(exp_bytes), timestamp));
+                if entries.len() >= limit {
+                    return Ok(entries);
+                }
+            }
+        }
 
-        Ok(recent)
+        Ok(entries)
     }
 
     // =========================================================================
@@ -716,65 +2184,36 @@ impl StorageEngine for RedbStorage {
     // =========================================================================
 
     fn save_experience(&self, experience: &Experience) -> Result<()> {
-        // Serialize experience (embedding is #[serde(skip)], excluded automatically)
-        let exp_bytes = bincode::serialize(experience)
-            .map_err(|e| StorageError::serialization(e.to_string()))?;
-
-        // Convert embedding to raw little-endian bytes
-        let emb_bytes = f32_slice_to_bytes(&experience.embedding);
+        self.write_experience(experience, false).map(|_| ())
+    }
 
-        // Build index keys
-        let type_key = encode_type_index_key(
-            experience.collective_id.as_bytes(),
-            experience.experience_type.type_tag(),
-        );
+    fn insert_experience(&self, experience: &Experience) -> Result<bool> {
+        self.write_experience(experience, true)
+    }
 
-        // Write to all 4 tables in a single atomic transaction
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
-        {
-            // Main experience record
-            let mut exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
-            exp_table.insert(experience.id.as_bytes(), exp_bytes.as_slice())?;
-        }
-        {
-            // Embedding vector (stored separately for compactness)
-            let mut emb_table = write_txn.open_table(EMBEDDINGS_TABLE)?;
-            emb_table.insert(experience.id.as_bytes(), emb_bytes.as_slice())?;
-        }
-        {
-            // By-collective index: key=collective_id, value=timestamp+experience_id
-            let mut idx_table = write_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
-            // Value is [timestamp_be: 8 bytes][experience_id: 16 bytes] = 24 bytes
-            let mut value = [0u8; 24];
-            value[..8].copy_from_slice(&experience.timestamp.to_be_bytes());
-            value[8..24].copy_from_slice(experience.id.as_bytes());
-            idx_table.insert(experience.collective_id.as_bytes(), &value)?;
+    fn insert_experiences(&self, experiences: &[Experience]) -> Result<Vec<bool>> {
+        let write_txn = self.begin_write()?;
+        let mut written = Vec::with_capacity(experiences.len());
+        for experience in experiences {
+            written.push(self.stage_experience(&write_txn, experience, true)?);
         }
-        {
-            // By-type index: key=collective_id+type_tag, value=experience_id
-            let mut type_table = write_txn.open_multimap_table(EXPERIENCES_BY_TYPE_TABLE)?;
-            type_table.insert(&type_key, experience.id.as_bytes())?;
-        }
-        // Record WAL event for cross-process change detection
-        self.increment_wal_and_record(
-            &write_txn,
-            experience.id.as_bytes(),
-            experience.collective_id,
-            EntityTypeTag::Experience,
-            WatchEventTypeTag::Created,
-            experience.timestamp,
-        )?;
-        write_txn.commit().map_err(StorageError::from)?;
+        self.commit_wal(write_txn)?;
+        let ids: Vec<ExperienceId> = experiences.iter().map(|e| e.id).collect();
+        self.experience_cache.invalidate(&ids);
 
         debug!(
-            id = %experience.id,
-            collective_id = %experience.collective_id,
-            "Experience saved"
+            count = written.iter().filter(|&&w| w).count(),
+            "Experience batch saved"
         );
-        Ok(())
+        Ok(written)
     }
 
     fn get_experience(&self, id: ExperienceId) -> Result<Option<Experience>> {
+        if let Some(experience) = self.experience_cache.get(id) {
+            return Ok(Some(experience));
+        }
+        // Snapshot before reading so a concurrent write can't be undone by our fill
+        let generation = self.experience_cache.generation();
         let read_txn = self.db.begin_read().map_err(StorageError::from)?;
 
         // Read main experience record
@@ -784,64 +2223,145 @@ impl StorageEngine for RedbStorage {
             None => return Ok(None),
         };
 
-        let mut experience: Experience = bincode::deserialize(exp_entry.value())
+        let mut experience: Experience = codec::decode(exp_entry.value())
             .map_err(|e| StorageError::serialization(e.to_string()))?;
 
+        // Mutable fields are kept current in the sidecars, not the record
+        let meta_table = read_txn.open_table(EXPERIENCE_META_TABLE)?;
+        if let Some(meta) = meta_table
+            .get(id.as_bytes())?
+            .and_then(|m| ExperienceMeta::from_bytes(m.value()))
+        {
+            meta.apply_to(&mut experience);
+        }
+        let apps_table = read_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE)?;
+        if let Some(applications) = apps_table.get(id.as_bytes())? {
+            experience.applications = applications.value();
+        }
+
         // Read embedding from separate table and reconstitute
         let emb_table = read_txn.open_table(EMBEDDINGS_TABLE)?;
         if let Some(emb_entry) = emb_table.get(id.as_bytes())? {
-            experience.embedding = bytes_to_f32_vec(emb_entry.value());
+            experience.embedding = decode_embedding(self.embedding_storage, emb_entry.value());
         }
 
+        let attr_table = read_txn.open_table(EXPERIENCE_ATTRIBUTION_TABLE)?;
+        if let Some(attr_entry) = attr_table.get(id.as_bytes())? {
+            experience.attribution = Some(
+                codec::decode(attr_entry.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+            );
+        }
+
+        let users = read_txn.open_table(EXPERIENCE_USERS_TABLE)?;
+        if let Some(user) = users.get(id.as_bytes())? {
+            experience.user_id = Some(UserId::new(user.value()));
+        }
+
+        let expiry = read_txn.open_table(EXPERIENCE_EXPIRY_TABLE)?;
+        if let Some(expires_at) = expiry.get(id.as_bytes())? {
+            experience.expires_at = Some(Timestamp::from_millis(expires_at.value()));
+        }
+
+        self.experience_cache.insert(&experience, generation);
         Ok(Some(experience))
     }
 
     fn update_experience(&self, id: ExperienceId, update: &ExperienceUpdate) -> Result<bool> {
-        // Read-modify-write: read the current record, apply updates, write back
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         let collective_id;
         let timestamp;
-        let is_archive;
+        let is_archive = update.archived == Some(true);
         {
             let mut exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
+            let mut meta_table = write_txn.open_table(EXPERIENCE_META_TABLE)?;
 
             let entry = match exp_table.get(id.as_bytes())? {
                 Some(v) => v,
                 None => return Ok(false),
             };
+            let stored_meta = meta_table
+                .get(id.as_bytes())?
+                .and_then(|m| ExperienceMeta::from_bytes(m.value()));
+
+            match stored_meta {
+                // Only sidecar fields change: leave the record untouched
+                Some(mut meta) if update.domain.is_none() && update.related_files.is_none() => {
+                    let head: ExperienceHead = codec::decode(entry.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    drop(entry);
 
-            let mut experience: Experience = bincode::deserialize(entry.value())
-                .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    if let Some(importance) = update.importance {
+                        meta.importance = importance;
+                    }
+                    if let Some(confidence) = update.confidence {
+                        meta.confidence = confidence;
+                    }
+                    if let Some(archived) = update.archived {
+                        meta.archived = archived;
+                    }
+                    meta_table.insert(id.as_bytes(), &meta.to_bytes())?;
 
-            // Drop the borrow on entry before mutating the table
-            drop(entry);
+                    collective_id = head.collective_id;
+                    timestamp = meta.timestamp;
+                }
+                // Read-modify-write: read the current record, apply updates, write back
+                stored_meta => {
+                    let mut experience: Experience = codec::decode(entry.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
 
-            // Capture metadata for WAL event before applying updates
-            collective_id = experience.collective_id;
-            timestamp = experience.timestamp;
-            is_archive = update.archived == Some(true);
+                    // Drop the borrow on entry before mutating the table
+                    drop(entry);
 
-            // Apply updates (only Some fields)
-            if let Some(importance) = update.importance {
-                experience.importance = importance;
-            }
-            if let Some(confidence) = update.confidence {
-                experience.confidence = confidence;
-            }
-            if let Some(ref domain) = update.domain {
-                experience.domain = domain.clone();
-            }
-            if let Some(ref related_files) = update.related_files {
-                experience.related_files = related_files.clone();
-            }
-            if let Some(archived) = update.archived {
-                experience.archived = archived;
-            }
+                    // The sidecars hold the current mutable fields
+                    if let Some(meta) = stored_meta {
+                        meta.apply_to(&mut experience);
+                    }
+                    let apps_table = write_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE)?;
+                    if let Some(applications) = apps_table.get(id.as_bytes())? {
+                        experience.applications = applications.value();
+                    }
 
-            // Re-serialize and write back
-            let bytes = bincode::serialize(&experience)
-                .map_err(|e| StorageError::serialization(e.to_string()))?;
-            exp_table.insert(id.as_bytes(), bytes.as_slice())?;
+                    // Capture metadata for WAL event before applying updates
+                    collective_id = experience.collective_id;
+                    timestamp = experience.timestamp;
+
+                    // Apply updates (only Some fields)
+                    if let Some(importance) = update.importance {
+                        experience.importance = importance;
+                    }
+                    if let Some(confidence) = update.confidence {
+                        experience.confidence = confidence;
+                    }
+                    if let Some(ref domain) = update.domain {
+                        experience.domain = domain.clone();
+                    }
+                    if let Some(ref related_files) = update.related_files {
+                        // Re-point the file index at the new list
+                        let mut by_file =
+                            write_txn.open_multimap_table(EXPERIENCES_BY_FILE_TABLE)?;
+                        for path in &experience.related_files {
+                            let key = encode_file_key(experience.collective_id.as_bytes(), path);
+                            by_file.remove(key.as_slice(), id.as_bytes())?;
+                        }
+                        for path in related_files {
+                            let key = encode_file_key(experience.collective_id.as_bytes(), path);
+                            by_file.insert(key.as_slice(), id.as_bytes())?;
+                        }
+                        experience.related_files = related_files.clone();
+                    }
+                    if let Some(archived) = update.archived {
+                        experience.archived = archived;
+                    }
+
+                    // Re-serialize and write back
+                    let bytes = codec::encode(&experience)
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    exp_table.insert(id.as_bytes(), bytes.as_slice())?;
+                    meta_table
+                        .insert(id.as_bytes(), &ExperienceMeta::of(&experience).to_bytes())?;
+                }
+            }
         }
         // Record WAL event for cross-process change detection
         let event_type = if is_archive {
@@ -857,95 +2377,66 @@ impl StorageEngine for RedbStorage {
             event_type,
             timestamp,
         )?;
-        write_txn.commit().map_err(StorageError::from)?;
+        self.commit_wal(write_txn)?;
+        self.experience_cache.invalidate(&[id]);
 
         debug!(id = %id, "Experience updated");
         Ok(true)
     }
 
     fn delete_experience(&self, id: ExperienceId) -> Result<bool> {
-        // First read the experience to get collective_id, timestamp, and type_tag
-        // (needed for cleaning up secondary indices and WAL event)
-        let (collective_id, timestamp, type_tag) = {
-            let read_txn = self.db.begin_read().map_err(StorageError::from)?;
-            let exp_table = read_txn.open_table(EXPERIENCES_TABLE)?;
-
-            match exp_table.get(id.as_bytes())? {
-                Some(entry) => {
-                    let exp: Experience = bincode::deserialize(entry.value())
-                        .map_err(|e| StorageError::serialization(e.to_string()))?;
-                    (
-                        exp.collective_id,
-                        exp.timestamp,
-                        exp.experience_type.type_tag(),
-                    )
-                }
-                None => return Ok(false),
-            }
-        };
-
-        // Delete from all 4 tables in a single transaction
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
-        {
-            let mut exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
-            exp_table.remove(id.as_bytes())?;
-        }
-        {
-            let mut emb_table = write_txn.open_table(EMBEDDINGS_TABLE)?;
-            emb_table.remove(id.as_bytes())?;
-        }
-        {
-            // Remove specific entry from by-collective multimap
-            let mut idx_table = write_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
-            let mut value = [0u8; 24];
-            value[..8].copy_from_slice(&timestamp.to_be_bytes());
-            value[8..24].copy_from_slice(id.as_bytes());
-            idx_table.remove(collective_id.as_bytes(), &value)?;
-        }
-        {
-            // Remove specific entry from by-type multimap
-            let mut type_table = write_txn.open_multimap_table(EXPERIENCES_BY_TYPE_TABLE)?;
-            let type_key = encode_type_index_key(collective_id.as_bytes(), type_tag);
-            type_table.remove(&type_key, id.as_bytes())?;
-        }
-        // Record WAL event for cross-process change detection
-        self.increment_wal_and_record(
-            &write_txn,
-            id.as_bytes(),
-            collective_id,
-            EntityTypeTag::Experience,
-            WatchEventTypeTag::Deleted,
-            timestamp,
-        )?;
-        write_txn.commit().map_err(StorageError::from)?;
+        Ok(matches!(
+            self.delete_experience_with(id, None)?,
+            ExperienceDeletion::Deleted { .. }
+        ))
+    }
 
-        debug!(id = %id, "Experience deleted");
-        Ok(true)
+    fn delete_experience_cascading(
+        &self,
+        id: ExperienceId,
+        cascade: InsightSourceCascade,
+    ) -> Result<ExperienceDeletion> {
+        self.delete_experience_with(id, Some(cascade))
     }
 
     fn reinforce_experience(&self, id: ExperienceId) -> Result<Option<u32>> {
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         let (new_count, collective_id, timestamp) = {
-            let mut exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
+            let exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
+            let meta_table = write_txn.open_table(EXPERIENCE_META_TABLE)?;
+            let mut apps_table = write_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE)?;
 
             let entry = match exp_table.get(id.as_bytes())? {
                 Some(v) => v,
                 None => return Ok(None),
             };
-
-            let mut experience: Experience = bincode::deserialize(entry.value())
+            let head: ExperienceHead = codec::decode(entry.value())
                 .map_err(|e| StorageError::serialization(e.to_string()))?;
+            let applications = apps_table.get(id.as_bytes())?.map(|a| a.value());
+            let meta_timestamp = meta_table
+                .get(id.as_bytes())?
+                .and_then(|m| ExperienceMeta::from_bytes(m.value()))
+                .map(|meta| meta.timestamp);
+
+            // Sidecars written before the experience existed are missing
+            // only if the database was damaged; fall back to the record
+            let (applications, timestamp) = match (applications, meta_timestamp) {
+                (Some(applications), Some(timestamp)) => (applications, timestamp),
+                (applications, _) => {
+                    let experience: Experience = codec::decode(entry.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    (
+                        applications.unwrap_or(experience.applications),
+                        experience.timestamp,
+                    )
+                }
+            };
             drop(entry);
 
-            experience.applications = experience.applications.saturating_add(1);
-            let new_count = experience.applications;
-            let collective_id = experience.collective_id;
-            let timestamp = experience.timestamp;
-
-            let bytes = bincode::serialize(&experience)
-                .map_err(|e| StorageError::serialization(e.to_string()))?;
-            exp_table.insert(id.as_bytes(), bytes.as_slice())?;
-            (new_count, collective_id, timestamp)
+            // Only the counter is written; the record stays as it is
+            let new_count = applications.saturating_add(1);
+            apps_table.insert(id.as_bytes(), new_count)?;
+            (new_count, head.collective_id, timestamp)
         };
         // Record WAL event for cross-process change detection
         self.increment_wal_and_record(
@@ -956,21 +2447,23 @@ impl StorageEngine for RedbStorage {
             WatchEventTypeTag::Updated,
             timestamp,
         )?;
-        write_txn.commit().map_err(StorageError::from)?;
+        self.commit_wal(write_txn)?;
+        self.experience_cache.invalidate(&[id]);
 
         debug!(id = %id, applications = new_count, "Experience reinforced");
         Ok(Some(new_count))
     }
 
     fn save_embedding(&self, id: ExperienceId, embedding: &[f32]) -> Result<()> {
-        let bytes = f32_slice_to_bytes(embedding);
+        let bytes = encode_embedding(self.embedding_storage, embedding);
 
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         {
             let mut table = write_txn.open_table(EMBEDDINGS_TABLE)?;
             table.insert(id.as_bytes(), bytes.as_slice())?;
         }
         write_txn.commit().map_err(StorageError::from)?;
+        self.experience_cache.invalidate(&[id]);
 
         debug!(id = %id, dim = embedding.len(), "Embedding saved");
         Ok(())
@@ -981,7 +2474,87 @@ impl StorageEngine for RedbStorage {
         let table = read_txn.open_table(EMBEDDINGS_TABLE)?;
 
         match table.get(id.as_bytes())? {
-            Some(entry) => Ok(Some(bytes_to_f32_vec(entry.value()))),
+            Some(entry) => Ok(Some(decode_embedding(
+                self.embedding_storage,
+                entry.value(),
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    fn get_experience_collectives(
+        &self,
+        ids: &[ExperienceId],
+    ) -> Result<Vec<Option<CollectiveId>>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(EXPERIENCES_TABLE)?;
+
+        let mut collectives = Vec::with_capacity(ids.len());
+        for id in ids {
+            let collective_id = match table.get(id.as_bytes())? {
+                Some(entry) => {
+                    let experience: Experience = codec::decode(entry.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    Some(experience.collective_id)
+                }
+                None => None,
+            };
+            collectives.push(collective_id);
+        }
+
+        Ok(collectives)
+    }
+
+    fn get_experience_meta(&self, ids: &[ExperienceId]) -> Result<Vec<Option<ExperienceMeta>>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(EXPERIENCE_META_TABLE)?;
+
+        let mut metas = Vec::with_capacity(ids.len());
+        for id in ids {
+            let meta = match table.get(id.as_bytes())? {
+                Some(entry) => Some(
+                    ExperienceMeta::from_bytes(entry.value())
+                        .ok_or_else(|| StorageError::corrupted("invalid experience metadata"))?,
+                ),
+                None => None,
+            };
+            metas.push(meta);
+        }
+
+        Ok(metas)
+    }
+
+    fn save_experience_neighbors(
+        &self,
+        lists: &[(ExperienceId, Vec<ExperienceNeighbor>)],
+    ) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(EXPERIENCE_NEIGHBORS_TABLE)?;
+            for (id, neighbors) in lists {
+                let bytes = codec::encode(neighbors)
+                    .map_err(|e| StorageError::serialization(e.to_string()))?;
+                table.insert(id.as_bytes(), bytes.as_slice())?;
+            }
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+
+        debug!(count = lists.len(), "Experience neighbor lists saved");
+        Ok(())
+    }
+
+    fn get_experience_neighbors(
+        &self,
+        id: ExperienceId,
+    ) -> Result<Option<Vec<ExperienceNeighbor>>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(EXPERIENCE_NEIGHBORS_TABLE)?;
+
+        match table.get(id.as_bytes())? {
+            Some(value) => Ok(Some(
+                codec::decode(value.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+            )),
             None => Ok(None),
         }
     }
@@ -992,9 +2565,9 @@ impl StorageEngine for RedbStorage {
 
     fn save_relation(&self, relation: &ExperienceRelation) -> Result<()> {
         let bytes =
-            bincode::serialize(relation).map_err(|e| StorageError::serialization(e.to_string()))?;
+            codec::encode(relation).map_err(|e| StorageError::serialization(e.to_string()))?;
 
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         {
             let mut table = write_txn.open_table(RELATIONS_TABLE)?;
             table.insert(relation.id.as_bytes(), bytes.as_slice())?;
@@ -1007,7 +2580,8 @@ impl StorageEngine for RedbStorage {
             let mut table = write_txn.open_multimap_table(RELATIONS_BY_TARGET_TABLE)?;
             table.insert(relation.target_id.as_bytes(), relation.id.as_bytes())?;
         }
-        // Look up collective_id from source experience for WAL record
+        // Look up collective_id from source experience for the collective
+        // index and WAL record
         let collective_id = {
             let exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
             let entry = exp_table
@@ -1015,10 +2589,17 @@ impl StorageEngine for RedbStorage {
                 .ok_or_else(|| {
                     StorageError::corrupted("relation source experience not found for WAL record")
                 })?;
-            let exp: Experience = bincode::deserialize(entry.value())
+            let exp: Experience = codec::decode(entry.value())
                 .map_err(|e| StorageError::serialization(e.to_string()))?;
             exp.collective_id
         };
+        {
+            let mut table = write_txn.open_multimap_table(RELATIONS_BY_COLLECTIVE_TABLE)?;
+            table.insert(
+                collective_id.as_bytes(),
+                &relation_collective_entry(relation),
+            )?;
+        }
         self.increment_wal_and_record(
             &write_txn,
             relation.id.as_bytes(),
@@ -1027,7 +2608,7 @@ impl StorageEngine for RedbStorage {
             WatchEventTypeTag::Created,
             relation.created_at,
         )?;
-        write_txn.commit().map_err(StorageError::from)?;
+        self.commit_wal(write_txn)?;
 
         debug!(id = %relation.id, "Relation saved");
         Ok(())
@@ -1039,7 +2620,7 @@ impl StorageEngine for RedbStorage {
 
         match table.get(id.as_bytes())? {
             Some(value) => {
-                let relation: ExperienceRelation = bincode::deserialize(value.value())
+                let relation: ExperienceRelation = codec::decode(value.value())
                     .map_err(|e| StorageError::serialization(e.to_string()))?;
                 Ok(Some(relation))
             }
@@ -1050,44 +2631,51 @@ impl StorageEngine for RedbStorage {
     fn delete_relation(&self, id: RelationId) -> Result<bool> {
         // Read the relation first to get source/target IDs for index cleanup
         // and source experience's collective_id for WAL record
-        let (source_id, target_id, collective_id) = {
+        let (relation, collective_id) = {
             let read_txn = self.db.begin_read().map_err(StorageError::from)?;
             let rel_table = read_txn.open_table(RELATIONS_TABLE)?;
 
             match rel_table.get(id.as_bytes())? {
                 Some(entry) => {
-                    let rel: ExperienceRelation = bincode::deserialize(entry.value())
+                    let rel: ExperienceRelation = codec::decode(entry.value())
                         .map_err(|e| StorageError::serialization(e.to_string()))?;
                     // Look up collective_id from source experience
                     let exp_table = read_txn.open_table(EXPERIENCES_TABLE)?;
                     let cid = match exp_table.get(rel.source_id.as_bytes())? {
                         Some(exp_entry) => {
-                            let exp: Experience = bincode::deserialize(exp_entry.value())
+                            let exp: Experience = codec::decode(exp_entry.value())
                                 .map_err(|e| StorageError::serialization(e.to_string()))?;
                             exp.collective_id
                         }
                         // Source experience may have been deleted; use nil collective
                         None => CollectiveId::nil(),
                     };
-                    (rel.source_id, rel.target_id, cid)
+                    (rel, cid)
                 }
                 None => return Ok(false),
             }
         };
 
-        // Delete from all 3 tables atomically
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        // Delete from the relation table and its indexes atomically
+        let write_txn = self.begin_write()?;
         {
             let mut table = write_txn.open_table(RELATIONS_TABLE)?;
             table.remove(id.as_bytes())?;
         }
         {
             let mut table = write_txn.open_multimap_table(RELATIONS_BY_SOURCE_TABLE)?;
-            table.remove(source_id.as_bytes(), id.as_bytes())?;
+            table.remove(relation.source_id.as_bytes(), id.as_bytes())?;
         }
         {
             let mut table = write_txn.open_multimap_table(RELATIONS_BY_TARGET_TABLE)?;
-            table.remove(target_id.as_bytes(), id.as_bytes())?;
+            table.remove(relation.target_id.as_bytes(), id.as_bytes())?;
+        }
+        if collective_id != CollectiveId::nil() {
+            let mut table = write_txn.open_multimap_table(RELATIONS_BY_COLLECTIVE_TABLE)?;
+            table.remove(
+                collective_id.as_bytes(),
+                &relation_collective_entry(&relation),
+            )?;
         }
         self.increment_wal_and_record(
             &write_txn,
@@ -1097,7 +2685,7 @@ impl StorageEngine for RedbStorage {
             WatchEventTypeTag::Deleted,
             Timestamp::now(),
         )?;
-        write_txn.commit().map_err(StorageError::from)?;
+        self.commit_wal(write_txn)?;
 
         debug!(id = %id, "Relation deleted");
         Ok(true)
@@ -1160,24 +2748,34 @@ impl StorageEngine for RedbStorage {
             return Ok(0);
         }
 
-        // Phase 2: Read each relation to get source/target IDs for index cleanup
-        let relations: Vec<ExperienceRelation> = {
+        // Phase 2: Read each relation to get source/target IDs for index cleanup,
+        // plus the experience's collective (relations never cross collectives)
+        let (relations, collective_id): (Vec<ExperienceRelation>, Option<CollectiveId>) = {
             let read_txn = self.db.begin_read().map_err(StorageError::from)?;
             let table = read_txn.open_table(RELATIONS_TABLE)?;
+            let exp_table = read_txn.open_table(EXPERIENCES_TABLE)?;
+            let collective_id = match exp_table.get(experience_id.as_bytes())? {
+                Some(entry) => {
+                    let exp: Experience = codec::decode(entry.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    Some(exp.collective_id)
+                }
+                None => None,
+            };
 
             let mut rels = Vec::with_capacity(relation_ids.len());
             for rel_id in &relation_ids {
                 if let Some(entry) = table.get(rel_id.as_bytes())? {
-                    let rel: ExperienceRelation = bincode::deserialize(entry.value())
+                    let rel: ExperienceRelation = codec::decode(entry.value())
                         .map_err(|e| StorageError::serialization(e.to_string()))?;
                     rels.push(rel);
                 }
             }
-            rels
+            (rels, collective_id)
         };
 
-        // Phase 3: Write — delete from all 3 tables atomically
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        // Phase 3: Write — delete from the relation table and its indexes atomically
+        let write_txn = self.begin_write()?;
         {
             let mut rel_table = write_txn.open_table(RELATIONS_TABLE)?;
             for rel in &relations {
@@ -1196,7 +2794,26 @@ impl StorageEngine for RedbStorage {
                 target_table.remove(rel.target_id.as_bytes(), rel.id.as_bytes())?;
             }
         }
-        write_txn.commit().map_err(StorageError::from)?;
+        if let Some(collective_id) = collective_id {
+            let mut idx_table = write_txn.open_multimap_table(RELATIONS_BY_COLLECTIVE_TABLE)?;
+            for rel in &relations {
+                idx_table.remove(collective_id.as_bytes(), &relation_collective_entry(rel))?;
+            }
+        }
+        if let Some(collective_id) = collective_id {
+            let timestamp = Timestamp::now();
+            for rel in &relations {
+                self.increment_wal_and_record(
+                    &write_txn,
+                    rel.id.as_bytes(),
+                    collective_id,
+                    EntityTypeTag::Relation,
+                    WatchEventTypeTag::Deleted,
+                    timestamp,
+                )?;
+            }
+        }
+        self.commit_wal(write_txn)?;
 
         debug!(
             experience_id = %experience_id,
@@ -1222,7 +2839,7 @@ impl StorageEngine for RedbStorage {
             let rel_id = RelationId::from_bytes(*value.value());
 
             if let Some(entry) = rel_table.get(rel_id.as_bytes())? {
-                let rel: ExperienceRelation = bincode::deserialize(entry.value())
+                let rel: ExperienceRelation = codec::decode(entry.value())
                     .map_err(|e| StorageError::serialization(e.to_string()))?;
                 if rel.target_id == target_id && rel.relation_type == relation_type {
                     return Ok(true);
@@ -1233,23 +2850,148 @@ impl StorageEngine for RedbStorage {
         Ok(false)
     }
 
+    fn save_relation_suggestions(&self, suggestions: &[RelationSuggestion]) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(RELATION_SUGGESTIONS_TABLE)?;
+            for suggestion in suggestions {
+                let bytes = codec::encode(suggestion)
+                    .map_err(|e| StorageError::serialization(e.to_string()))?;
+                let key = encode_suggestion_key(
+                    suggestion.collective_id.as_bytes(),
+                    suggestion.source_id.as_bytes(),
+                    suggestion.target_id.as_bytes(),
+                );
+                table.insert(&key, bytes.as_slice())?;
+            }
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+
+        debug!(count = suggestions.len(), "Relation suggestions queued");
+        Ok(())
+    }
+
+    fn list_relation_suggestions(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Vec<RelationSuggestion>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(RELATION_SUGGESTIONS_TABLE)?;
+        let start = encode_suggestion_key(collective_id.as_bytes(), &[0u8; 16], &[0u8; 16]);
+        let end = encode_suggestion_key(collective_id.as_bytes(), &[0xFF; 16], &[0xFF; 16]);
+
+        let mut suggestions = Vec::new();
+        for entry in table.range::<&[u8; 48]>(&start..=&end)? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            suggestions.push(
+                codec::decode(value.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+            );
+        }
+        Ok(suggestions)
+    }
+
+    fn get_relation_suggestion(
+        &self,
+        collective_id: CollectiveId,
+        source_id: ExperienceId,
+        target_id: ExperienceId,
+    ) -> Result<Option<RelationSuggestion>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(RELATION_SUGGESTIONS_TABLE)?;
+        let key = encode_suggestion_key(
+            collective_id.as_bytes(),
+            source_id.as_bytes(),
+            target_id.as_bytes(),
+        );
+        match table.get(&key)? {
+            Some(entry) => Ok(Some(
+                codec::decode(entry.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn delete_relation_suggestion(
+        &self,
+        collective_id: CollectiveId,
+        source_id: ExperienceId,
+        target_id: ExperienceId,
+    ) -> Result<bool> {
+        let write_txn = self.begin_write()?;
+        let existed = {
+            let mut table = write_txn.open_table(RELATION_SUGGESTIONS_TABLE)?;
+            let key = encode_suggestion_key(
+                collective_id.as_bytes(),
+                source_id.as_bytes(),
+                target_id.as_bytes(),
+            );
+            let removed = table.remove(&key)?;
+            removed.is_some()
+        };
+        write_txn.commit().map_err(StorageError::from)?;
+        Ok(existed)
+    }
+
+    fn delete_relation_suggestions_by_collective(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<u64> {
+        let start = encode_suggestion_key(collective_id.as_bytes(), &[0u8; 16], &[0u8; 16]);
+        let end = encode_suggestion_key(collective_id.as_bytes(), &[0xFF; 16], &[0xFF; 16]);
+
+        let write_txn = self.begin_write()?;
+        let count = {
+            let mut table = write_txn.open_table(RELATION_SUGGESTIONS_TABLE)?;
+            let mut keys = Vec::new();
+            for result in table.range::<&[u8; 48]>(&start..=&end)? {
+                let (key, _) = result.map_err(StorageError::from)?;
+                keys.push(*key.value());
+            }
+            for key in &keys {
+                table.remove(key)?;
+            }
+            keys.len() as u64
+        };
+        write_txn.commit().map_err(StorageError::from)?;
+
+        if count > 0 {
+            debug!(collective_id = %collective_id, count, "Cascade-deleted relation suggestions for collective");
+        }
+        Ok(count)
+    }
+
     // =========================================================================
     // Insight Storage Operations (E3-S02)
     // =========================================================================
 
     fn save_insight(&self, insight: &DerivedInsight) -> Result<()> {
         let bytes =
-            bincode::serialize(insight).map_err(|e| StorageError::serialization(e.to_string()))?;
+            codec::encode(insight).map_err(|e| StorageError::serialization(e.to_string()))?;
 
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
-        {
+        let write_txn = self.begin_write()?;
+        let replaced = {
             let mut table = write_txn.open_table(INSIGHTS_TABLE)?;
-            table.insert(insight.id.as_bytes(), bytes.as_slice())?;
+            let old = table.insert(insight.id.as_bytes(), bytes.as_slice())?;
+            old.map(|old| codec::decode::<DerivedInsight>(old.value()))
+                .transpose()
+                .map_err(|e| StorageError::serialization(e.to_string()))?
+        };
+        if let Some(replaced) = &replaced {
+            unindex_insight_sources(&write_txn, replaced)?;
         }
+        index_insight_sources(&write_txn, insight)?;
         {
             let mut table = write_txn.open_multimap_table(INSIGHTS_BY_COLLECTIVE_TABLE)?;
             table.insert(insight.collective_id.as_bytes(), insight.id.as_bytes())?;
         }
+        {
+            let mut table = write_txn.open_multimap_table(INSIGHTS_BY_TYPE_TABLE)?;
+            let key =
+                encode_insight_type_key(insight.collective_id.as_bytes(), insight.insight_type);
+            table.insert(&key, insight.id.as_bytes())?;
+        }
         self.increment_wal_and_record(
             &write_txn,
             insight.id.as_bytes(),
@@ -1258,7 +3000,7 @@ impl StorageEngine for RedbStorage {
             WatchEventTypeTag::Created,
             insight.created_at,
         )?;
-        write_txn.commit().map_err(StorageError::from)?;
+        self.commit_wal(write_txn)?;
 
         debug!(id = %insight.id, collective_id = %insight.collective_id, "Insight saved");
         Ok(())
@@ -1270,7 +3012,7 @@ impl StorageEngine for RedbStorage {
 
         match table.get(id.as_bytes())? {
             Some(value) => {
-                let insight: DerivedInsight = bincode::deserialize(value.value())
+                let insight: DerivedInsight = codec::decode(value.value())
                     .map_err(|e| StorageError::serialization(e.to_string()))?;
                 Ok(Some(insight))
             }
@@ -1279,43 +3021,358 @@ impl StorageEngine for RedbStorage {
     }
 
     fn delete_insight(&self, id: InsightId) -> Result<bool> {
-        // Read the insight first to get collective_id for index cleanup
-        let collective_id = {
-            let read_txn = self.db.begin_read().map_err(StorageError::from)?;
-            let table = read_txn.open_table(INSIGHTS_TABLE)?;
-
-            match table.get(id.as_bytes())? {
-                Some(entry) => {
-                    let insight: DerivedInsight = bincode::deserialize(entry.value())
-                        .map_err(|e| StorageError::serialization(e.to_string()))?;
-                    insight.collective_id
-                }
-                None => return Ok(false),
-            }
+        // Delete from all insight tables atomically
+        let write_txn = self.begin_write()?;
+        let Some(insight) = remove_insight_in(&write_txn, id)? else {
+            return Ok(false);
         };
+        self.increment_wal_and_record(
+            &write_txn,
+            id.as_bytes(),
+            insight.collective_id,
+            EntityTypeTag::Insight,
+            WatchEventTypeTag::Deleted,
+            Timestamp::now(),
+        )?;
+        self.commit_wal(write_txn)?;
+
+        debug!(id = %id, "Insight deleted");
+        Ok(true)
+    }
 
-        // Delete from both tables atomically
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+    fn update_insight_sources(&self, id: InsightId, sources: &[ExperienceId]) -> Result<bool> {
+        let write_txn = self.begin_write()?;
+        let collective_id;
+        let updated_at;
         {
             let mut table = write_txn.open_table(INSIGHTS_TABLE)?;
-            table.remove(id.as_bytes())?;
-        }
-        {
-            let mut table = write_txn.open_multimap_table(INSIGHTS_BY_COLLECTIVE_TABLE)?;
-            table.remove(collective_id.as_bytes(), id.as_bytes())?;
+
+            let entry = match table.get(id.as_bytes())? {
+                Some(v) => v,
+                None => return Ok(false),
+            };
+            let mut insight: DerivedInsight = codec::decode(entry.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            drop(entry);
+
+            unindex_insight_sources(&write_txn, &insight)?;
+            insight.source_experience_ids = sources.to_vec();
+            insight.updated_at = Timestamp::now();
+            collective_id = insight.collective_id;
+            updated_at = insight.updated_at;
+            index_insight_sources(&write_txn, &insight)?;
+
+            let bytes =
+                codec::encode(&insight).map_err(|e| StorageError::serialization(e.to_string()))?;
+            table.insert(id.as_bytes(), bytes.as_slice())?;
         }
         self.increment_wal_and_record(
             &write_txn,
             id.as_bytes(),
             collective_id,
             EntityTypeTag::Insight,
-            WatchEventTypeTag::Deleted,
-            Timestamp::now(),
+            WatchEventTypeTag::Updated,
+            updated_at,
         )?;
+        self.commit_wal(write_txn)?;
+
+        debug!(id = %id, sources = sources.len(), "Insight sources updated");
+        Ok(true)
+    }
+
+    fn list_insight_ids_by_type(
+        &self,
+        collective_id: CollectiveId,
+        insight_type: InsightType,
+    ) -> Result<Vec<InsightId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(INSIGHTS_BY_TYPE_TABLE)?;
+        let key = encode_insight_type_key(collective_id.as_bytes(), insight_type);
+
+        let mut ids = Vec::new();
+        for result in table.get(&key)? {
+            let value = result.map_err(StorageError::from)?;
+            ids.push(InsightId::from_bytes(*value.value()));
+        }
+
+        Ok(ids)
+    }
+
+    fn mark_insight_degraded(&self, id: InsightId, at: Timestamp) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(DEGRADED_INSIGHTS_TABLE)?;
+            table.insert(id.as_bytes(), at.as_millis())?;
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+
+        debug!(id = %id, "Insight marked degraded");
+        Ok(())
+    }
+
+    fn get_insight_degraded_at(&self, id: InsightId) -> Result<Option<Timestamp>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(DEGRADED_INSIGHTS_TABLE)?;
+        Ok(table
+            .get(id.as_bytes())?
+            .map(|entry| Timestamp::from_millis(entry.value())))
+    }
+
+    fn clear_insight_degraded(&self, id: InsightId) -> Result<bool> {
+        let write_txn = self.begin_write()?;
+        let existed = {
+            let mut table = write_txn.open_table(DEGRADED_INSIGHTS_TABLE)?;
+            let removed = table.remove(id.as_bytes())?;
+            removed.is_some()
+        };
+        write_txn.commit().map_err(StorageError::from)?;
+        Ok(existed)
+    }
+
+    fn save_moderation_policy(
+        &self,
+        collective_id: CollectiveId,
+        policy: &ModerationPolicy,
+    ) -> Result<()> {
+        let bytes =
+            codec::encode(policy).map_err(|e| StorageError::serialization(e.to_string()))?;
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(MODERATION_POLICIES_TABLE)?;
+            table.insert(collective_id.as_bytes(), bytes.as_slice())?;
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+        Ok(())
+    }
+
+    fn get_moderation_policy(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Option<ModerationPolicy>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(MODERATION_POLICIES_TABLE)?;
+        match table.get(collective_id.as_bytes())? {
+            Some(entry) => Ok(Some(
+                codec::decode(entry.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn save_content_policy(
+        &self,
+        collective_id: CollectiveId,
+        policy: &ContentPolicy,
+    ) -> Result<()> {
+        let bytes =
+            codec::encode(policy).map_err(|e| StorageError::serialization(e.to_string()))?;
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(CONTENT_POLICIES_TABLE)?;
+            table.insert(collective_id.as_bytes(), bytes.as_slice())?;
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+        Ok(())
+    }
+
+    fn get_content_policy(&self, collective_id: CollectiveId) -> Result<Option<ContentPolicy>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(CONTENT_POLICIES_TABLE)?;
+        match table.get(collective_id.as_bytes())? {
+            Some(entry) => Ok(Some(
+                codec::decode(entry.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn save_collective_quota(
+        &self,
+        collective_id: CollectiveId,
+        quota: &CollectiveQuota,
+    ) -> Result<()> {
+        let bytes = codec::encode(quota).map_err(|e| StorageError::serialization(e.to_string()))?;
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(COLLECTIVE_QUOTAS_TABLE)?;
+            table.insert(collective_id.as_bytes(), bytes.as_slice())?;
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+        Ok(())
+    }
+
+    fn get_collective_quota(&self, collective_id: CollectiveId) -> Result<Option<CollectiveQuota>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(COLLECTIVE_QUOTAS_TABLE)?;
+        match table.get(collective_id.as_bytes())? {
+            Some(entry) => Ok(Some(
+                codec::decode(entry.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn save_text_normalization(
+        &self,
+        collective_id: CollectiveId,
+        normalization: Option<&TextNormalization>,
+    ) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(COLLECTIVE_NORMALIZATION_TABLE)?;
+            match normalization {
+                Some(normalization) => {
+                    let bytes = codec::encode(normalization)
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    table.insert(collective_id.as_bytes(), bytes.as_slice())?;
+                }
+                None => {
+                    table.remove(collective_id.as_bytes())?;
+                }
+            }
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+        Ok(())
+    }
+
+    fn get_text_normalization(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Option<TextNormalization>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(COLLECTIVE_NORMALIZATION_TABLE)?;
+        match table.get(collective_id.as_bytes())? {
+            Some(entry) => Ok(Some(
+                codec::decode(entry.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn upsert_interest(&self, collective_id: CollectiveId, interest: &Interest) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(INTERESTS_TABLE)?;
+            let mut interests: Vec<Interest> = match table.get(collective_id.as_bytes())? {
+                Some(entry) => codec::decode(entry.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+                None => Vec::new(),
+            };
+            interests.retain(|i| i.agent_id != interest.agent_id || i.name != interest.name);
+            interests.push(interest.clone());
+            let bytes = codec::encode(&interests)
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            table.insert(collective_id.as_bytes(), bytes.as_slice())?;
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+        Ok(())
+    }
+
+    fn remove_interest(
+        &self,
+        collective_id: CollectiveId,
+        agent_id: &str,
+        name: &str,
+    ) -> Result<bool> {
+        let write_txn = self.begin_write()?;
+        let removed = {
+            let mut table = write_txn.open_table(INTERESTS_TABLE)?;
+            let mut interests: Vec<Interest> = match table.get(collective_id.as_bytes())? {
+                Some(entry) => codec::decode(entry.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+                None => Vec::new(),
+            };
+            let before = interests.len();
+            interests.retain(|i| i.agent_id.as_str() != agent_id || i.name != name);
+            let removed = interests.len() < before;
+            if interests.is_empty() {
+                table.remove(collective_id.as_bytes())?;
+            } else if removed {
+                let bytes = codec::encode(&interests)
+                    .map_err(|e| StorageError::serialization(e.to_string()))?;
+                table.insert(collective_id.as_bytes(), bytes.as_slice())?;
+            }
+            removed
+        };
         write_txn.commit().map_err(StorageError::from)?;
+        Ok(removed)
+    }
+
+    fn get_interests(&self, collective_id: CollectiveId) -> Result<Vec<Interest>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(INTERESTS_TABLE)?;
+        match table.get(collective_id.as_bytes())? {
+            Some(entry) => Ok(codec::decode(entry.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn mark_experience_pending(
+        &self,
+        collective_id: CollectiveId,
+        id: ExperienceId,
+        at: Timestamp,
+    ) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(PENDING_EXPERIENCES_TABLE)?;
+            let key = encode_pending_key(collective_id.as_bytes(), id.as_bytes());
+            table.insert(&key, at.as_millis())?;
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+
+        debug!(id = %id, "Experience queued for review");
+        Ok(())
+    }
+
+    fn is_experience_pending(&self, collective_id: CollectiveId, id: ExperienceId) -> Result<bool> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(PENDING_EXPERIENCES_TABLE)?;
+        let key = encode_pending_key(collective_id.as_bytes(), id.as_bytes());
+        Ok(table.get(&key)?.is_some())
+    }
+
+    fn clear_experience_pending(
+        &self,
+        collective_id: CollectiveId,
+        id: ExperienceId,
+    ) -> Result<bool> {
+        let write_txn = self.begin_write()?;
+        let existed = {
+            let mut table = write_txn.open_table(PENDING_EXPERIENCES_TABLE)?;
+            let key = encode_pending_key(collective_id.as_bytes(), id.as_bytes());
+            let removed = table.remove(&key)?;
+            removed.is_some()
+        };
+        write_txn.commit().map_err(StorageError::from)?;
+        Ok(existed)
+    }
+
+    fn list_pending_experience_ids(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Vec<(ExperienceId, Timestamp)>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(PENDING_EXPERIENCES_TABLE)?;
+        let start = encode_pending_key(collective_id.as_bytes(), &[0u8; 16]);
+        let end = encode_pending_key(collective_id.as_bytes(), &[0xFF; 16]);
 
-        debug!(id = %id, "Insight deleted");
-        Ok(true)
+        let mut ids = Vec::new();
+        for entry in table.range::<&[u8; 32]>(&start..=&end)? {
+            let (key, queued) = entry.map_err(StorageError::from)?;
+            let mut id = [0u8; 16];
+            id.copy_from_slice(&key.value()[16..]);
+            ids.push((
+                ExperienceId::from_bytes(id),
+                Timestamp::from_millis(queued.value()),
+            ));
+        }
+        Ok(ids)
     }
 
     fn list_insight_ids_in_collective(&self, id: CollectiveId) -> Result<Vec<InsightId>> {
@@ -1331,6 +3388,19 @@ impl StorageEngine for RedbStorage {
         Ok(ids)
     }
 
+    fn list_insight_ids_citing(&self, experience_id: ExperienceId) -> Result<Vec<InsightId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(INSIGHTS_BY_SOURCE_TABLE)?;
+
+        let mut ids = Vec::new();
+        for result in table.get(experience_id.as_bytes())? {
+            let value = result.map_err(StorageError::from)?;
+            ids.push(InsightId::from_bytes(*value.value()));
+        }
+
+        Ok(ids)
+    }
+
     fn delete_insights_by_collective(&self, id: CollectiveId) -> Result<u64> {
         // Phase 1: Read — collect insight IDs
         let insight_ids: Vec<[u8; 16]> = {
@@ -1351,9 +3421,21 @@ impl StorageEngine for RedbStorage {
         }
 
         // Phase 2: Write — delete from both tables atomically
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         {
             let mut table = write_txn.open_table(INSIGHTS_TABLE)?;
+            for insight_id in &insight_ids {
+                let removed = table.remove(insight_id)?;
+                if let Some(removed) = removed {
+                    let insight: DerivedInsight = codec::decode(removed.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    drop(removed);
+                    unindex_insight_sources(&write_txn, &insight)?;
+                }
+            }
+        }
+        {
+            let mut table = write_txn.open_table(DEGRADED_INSIGHTS_TABLE)?;
             for insight_id in &insight_ids {
                 table.remove(insight_id)?;
             }
@@ -1362,6 +3444,12 @@ impl StorageEngine for RedbStorage {
             let mut table = write_txn.open_multimap_table(INSIGHTS_BY_COLLECTIVE_TABLE)?;
             table.remove_all(id.as_bytes())?;
         }
+        {
+            let mut table = write_txn.open_multimap_table(INSIGHTS_BY_TYPE_TABLE)?;
+            for insight_type in InsightType::all() {
+                table.remove_all(&encode_insight_type_key(id.as_bytes(), *insight_type))?;
+            }
+        }
         write_txn.commit().map_err(StorageError::from)?;
 
         debug!(id = %id, count = count, "Cascade-deleted insights for collective");
@@ -1374,14 +3462,32 @@ impl StorageEngine for RedbStorage {
 
     fn save_activity(&self, activity: &Activity) -> Result<()> {
         let key = encode_activity_key(activity.collective_id.as_bytes(), &activity.agent_id);
-        let bytes =
-            bincode::serialize(activity).map_err(|e| StorageError::serialization(e.to_string()))?;
+        let bytes = codec::encode(&ActivityRecord::from(activity))
+            .map_err(|e| StorageError::serialization(e.to_string()))?;
 
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         {
             let mut table = write_txn.open_table(ACTIVITIES_TABLE)?;
             table.insert(key.as_slice(), bytes.as_slice())?;
         }
+        // Replace capabilities and their index entries wholesale (upsert)
+        remove_activity_capabilities(
+            &write_txn,
+            activity.collective_id.as_bytes(),
+            &activity.agent_id,
+        )?;
+        if !activity.capabilities.is_empty() {
+            let caps_bytes = codec::encode(&activity.capabilities)
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            let mut caps_table = write_txn.open_table(ACTIVITY_CAPABILITIES_TABLE)?;
+            caps_table.insert(key.as_slice(), caps_bytes.as_slice())?;
+
+            let mut idx_table = write_txn.open_multimap_table(AGENTS_BY_CAPABILITY_TABLE)?;
+            for capability in &activity.capabilities {
+                let cap_key = encode_capability_key(activity.collective_id.as_bytes(), capability);
+                idx_table.insert(cap_key.as_slice(), activity.agent_id.as_str())?;
+            }
+        }
         write_txn.commit().map_err(StorageError::from)?;
 
         debug!(
@@ -1401,13 +3507,14 @@ impl StorageEngine for RedbStorage {
 
         let read_txn = self.db.begin_read().map_err(StorageError::from)?;
         let table = read_txn.open_table(ACTIVITIES_TABLE)?;
+        let caps_table = read_txn.open_table(ACTIVITY_CAPABILITIES_TABLE)?;
 
         match table.get(key.as_slice())? {
-            Some(value) => {
-                let activity: Activity = bincode::deserialize(value.value())
-                    .map_err(|e| StorageError::serialization(e.to_string()))?;
-                Ok(Some(activity))
-            }
+            Some(value) => Ok(Some(decode_activity(
+                value.value(),
+                &caps_table,
+                key.as_slice(),
+            )?)),
             None => Ok(None),
         }
     }
@@ -1415,12 +3522,13 @@ impl StorageEngine for RedbStorage {
     fn delete_activity(&self, agent_id: &str, collective_id: CollectiveId) -> Result<bool> {
         let key = encode_activity_key(collective_id.as_bytes(), agent_id);
 
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         let existed = {
             let mut table = write_txn.open_table(ACTIVITIES_TABLE)?;
             let removed = table.remove(key.as_slice())?;
             removed.is_some()
         };
+        remove_activity_capabilities(&write_txn, collective_id.as_bytes(), agent_id)?;
         write_txn.commit().map_err(StorageError::from)?;
 
         if existed {
@@ -1434,6 +3542,7 @@ impl StorageEngine for RedbStorage {
 
         let read_txn = self.db.begin_read().map_err(StorageError::from)?;
         let table = read_txn.open_table(ACTIVITIES_TABLE)?;
+        let caps_table = read_txn.open_table(ACTIVITY_CAPABILITIES_TABLE)?;
 
         let mut activities = Vec::new();
         for result in table.iter()? {
@@ -1442,57 +3551,469 @@ impl StorageEngine for RedbStorage {
 
             // Check if this key belongs to the requested collective (16-byte prefix)
             if key_bytes.len() >= 16 && decode_collective_from_activity_key(key_bytes) == *prefix {
-                let activity: Activity = bincode::deserialize(value.value())
+                activities.push(decode_activity(value.value(), &caps_table, key_bytes)?);
+            }
+        }
+
+        Ok(activities)
+    }
+
+    fn list_agent_ids_with_capability(
+        &self,
+        collective_id: CollectiveId,
+        capability: &str,
+    ) -> Result<Vec<String>> {
+        let key = encode_capability_key(collective_id.as_bytes(), capability);
+
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(AGENTS_BY_CAPABILITY_TABLE)?;
+
+        let mut agent_ids = Vec::new();
+        for result in table.get(key.as_slice())? {
+            let value = result.map_err(StorageError::from)?;
+            agent_ids.push(value.value().to_string());
+        }
+
+        Ok(agent_ids)
+    }
+
+    fn delete_activities_by_collective(&self, collective_id: CollectiveId) -> Result<u64> {
+        let prefix = collective_id.as_bytes();
+
+        // Phase 1: Read — collect matching keys
+        let keys_to_delete: Vec<Vec<u8>> = {
+            let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+            let table = read_txn.open_table(ACTIVITIES_TABLE)?;
+
+            let mut keys = Vec::new();
+            for result in table.iter()? {
+                let (key, _) = result.map_err(StorageError::from)?;
+                let key_bytes = key.value();
+
+                if key_bytes.len() >= 16
+                    && decode_collective_from_activity_key(key_bytes) == *prefix
+                {
+                    keys.push(key_bytes.to_vec());
+                }
+            }
+            keys
+        };
+
+        let count = keys_to_delete.len() as u64;
+        if count == 0 {
+            return Ok(0);
+        }
+
+        // Phase 2: Write — delete all collected keys and their capabilities
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(ACTIVITIES_TABLE)?;
+            for key in &keys_to_delete {
+                table.remove(key.as_slice())?;
+            }
+        }
+        for key in &keys_to_delete {
+            remove_activity_capabilities(
+                &write_txn,
+                prefix,
+                decode_agent_id_from_activity_key(key),
+            )?;
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+
+        debug!(
+            collective_id = %collective_id,
+            count = count,
+            "Cascade-deleted activities for collective"
+        );
+        Ok(count)
+    }
+
+    // =========================================================================
+    // Bookmark Operations
+    // =========================================================================
+
+    fn add_bookmark(&self, agent_id: &str, experience_id: ExperienceId) -> Result<bool> {
+        let write_txn = self.begin_write()?;
+        let existed = {
+            let mut bookmarks = write_txn.open_multimap_table(BOOKMARKS_TABLE)?;
+            let mut by_exp = write_txn.open_multimap_table(BOOKMARKED_BY_TABLE)?;
+            by_exp.insert(experience_id.as_bytes(), agent_id)?;
+            bookmarks.insert(agent_id, experience_id.as_bytes())?
+        };
+        write_txn.commit().map_err(StorageError::from)?;
+
+        debug!(agent_id = %agent_id, experience_id = %experience_id, "Bookmark saved");
+        Ok(!existed)
+    }
+
+    fn remove_bookmark(&self, agent_id: &str, experience_id: ExperienceId) -> Result<bool> {
+        let write_txn = self.begin_write()?;
+        let existed = {
+            let mut bookmarks = write_txn.open_multimap_table(BOOKMARKS_TABLE)?;
+            let mut by_exp = write_txn.open_multimap_table(BOOKMARKED_BY_TABLE)?;
+            by_exp.remove(experience_id.as_bytes(), agent_id)?;
+            bookmarks.remove(agent_id, experience_id.as_bytes())?
+        };
+        write_txn.commit().map_err(StorageError::from)?;
+
+        if existed {
+            debug!(agent_id = %agent_id, experience_id = %experience_id, "Bookmark removed");
+        }
+        Ok(existed)
+    }
+
+    fn list_bookmark_ids(&self, agent_id: &str) -> Result<Vec<ExperienceId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(BOOKMARKS_TABLE)?;
+
+        let mut ids = Vec::new();
+        for result in table.get(agent_id)? {
+            let value = result.map_err(StorageError::from)?;
+            ids.push(ExperienceId::from_bytes(*value.value()));
+        }
+        Ok(ids)
+    }
+
+    fn list_bookmarking_agent_ids(&self, experience_id: ExperienceId) -> Result<Vec<String>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(BOOKMARKED_BY_TABLE)?;
+
+        let mut agents = Vec::new();
+        for result in table.get(experience_id.as_bytes())? {
+            let value = result.map_err(StorageError::from)?;
+            agents.push(value.value().to_string());
+        }
+        Ok(agents)
+    }
+
+    // =========================================================================
+    // User and Task Links
+    // =========================================================================
+
+    fn list_experience_ids_by_user(&self, user_id: &str) -> Result<Vec<ExperienceId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(EXPERIENCES_BY_USER_TABLE)?;
+
+        let mut ids = Vec::new();
+        for result in table.get(user_id)? {
+            let value = result.map_err(StorageError::from)?;
+            ids.push(ExperienceId::from_bytes(*value.value()));
+        }
+        Ok(ids)
+    }
+
+    fn list_experience_ids_by_task(&self, task_id: &str) -> Result<Vec<ExperienceId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(EXPERIENCES_BY_TASK_TABLE)?;
+
+        let mut ids = Vec::new();
+        for result in table.get(task_id)? {
+            let value = result.map_err(StorageError::from)?;
+            ids.push(ExperienceId::from_bytes(*value.value()));
+        }
+        Ok(ids)
+    }
+
+    fn list_experience_files(
+        &self,
+        collective_id: CollectiveId,
+        prefix: &str,
+    ) -> Result<Vec<(String, ExperienceId)>> {
+        let start = encode_file_key(collective_id.as_bytes(), prefix);
+
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(EXPERIENCES_BY_FILE_TABLE)?;
+
+        let mut entries = Vec::new();
+        for bucket in table.range::<&[u8]>(start.as_slice()..)? {
+            let (key, values) = bucket.map_err(StorageError::from)?;
+            let key = key.value();
+            if !key.starts_with(&start) {
+                break;
+            }
+            // Keys are built from &str, so the path is valid UTF-8
+            let path = String::from_utf8_lossy(&key[16..]).into_owned();
+            for result in values {
+                let value = result.map_err(StorageError::from)?;
+                entries.push((path.clone(), ExperienceId::from_bytes(*value.value())));
+            }
+        }
+        Ok(entries)
+    }
+
+    fn list_tasks_by_agent(&self, agent_id: &str) -> Result<Vec<TaskId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(TASKS_BY_AGENT_TABLE)?;
+
+        // One entry per experience, so the same task can appear many times
+        let mut tasks = std::collections::BTreeSet::new();
+        for result in table.get(agent_id)? {
+            let value = result.map_err(StorageError::from)?;
+            let entry = value.value();
+            tasks.insert(String::from_utf8_lossy(&entry[..entry.len() - 16]).into_owned());
+        }
+        Ok(tasks.into_iter().map(TaskId::new).collect())
+    }
+
+    fn set_episode_summary(&self, task_id: &str, insight_id: Option<InsightId>) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(EPISODE_SUMMARIES_TABLE)?;
+            match insight_id {
+                Some(id) => {
+                    table.insert(task_id, id.as_bytes())?;
+                }
+                None => {
+                    table.remove(task_id)?;
+                }
+            }
+        }
+        write_txn.commit().map_err(StorageError::from)?;
+        Ok(())
+    }
+
+    fn get_episode_summary(&self, task_id: &str) -> Result<Option<InsightId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(EPISODE_SUMMARIES_TABLE)?;
+        Ok(table
+            .get(task_id)?
+            .map(|entry| InsightId::from_bytes(*entry.value())))
+    }
+
+    // =========================================================================
+    // Lock Operations
+    // =========================================================================
+
+    fn acquire_lock(
+        &self,
+        collective_id: CollectiveId,
+        name: &str,
+        owner: &str,
+        now: Timestamp,
+        expires_at: Timestamp,
+    ) -> Result<Lease> {
+        let key = encode_lock_key(collective_id.as_bytes(), name);
+
+        let write_txn = self.begin_write()?;
+        let lease = {
+            let mut table = write_txn.open_table(LOCKS_TABLE)?;
+            let current: Option<Lease> = match table.get(key.as_slice())? {
+                Some(value) => Some(
+                    codec::decode(value.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?,
+                ),
+                None => None,
+            };
+
+            let lease = match current {
+                // Held elsewhere: leave it untouched (txn drops uncommitted)
+                Some(held) if !held.is_expired_at(now) && held.owner != owner => {
+                    return Ok(held);
+                }
+                // Renewal keeps the token and original acquisition time
+                Some(held) if !held.is_expired_at(now) => Lease { expires_at, ..held },
+                // Free or expired: grant with the next fencing token
+                _ => {
+                    let mut meta_table = write_txn.open_table(METADATA_TABLE)?;
+                    let last_token = match meta_table.get(LOCK_FENCING_TOKEN_KEY)? {
+                        Some(entry) => {
+                            let bytes: [u8; 8] = entry.value().try_into().map_err(|_| {
+                                StorageError::corrupted("invalid lock_fencing_token bytes")
+                            })?;
+                            u64::from_be_bytes(bytes)
+                        }
+                        None => 0,
+                    };
+                    let token = last_token + 1;
+                    meta_table.insert(LOCK_FENCING_TOKEN_KEY, token.to_be_bytes().as_slice())?;
+
+                    Lease {
+                        collective_id,
+                        name: name.to_string(),
+                        owner: owner.to_string(),
+                        fencing_token: token,
+                        acquired_at: now,
+                        expires_at,
+                    }
+                }
+            };
+
+            let bytes =
+                codec::encode(&lease).map_err(|e| StorageError::serialization(e.to_string()))?;
+            table.insert(key.as_slice(), bytes.as_slice())?;
+            lease
+        };
+        write_txn.commit().map_err(StorageError::from)?;
+
+        debug!(
+            name = %name,
+            owner = %owner,
+            fencing_token = lease.fencing_token,
+            "Lock acquired"
+        );
+        Ok(lease)
+    }
+
+    fn release_lock(
+        &self,
+        collective_id: CollectiveId,
+        name: &str,
+        fencing_token: u64,
+    ) -> Result<bool> {
+        let key = encode_lock_key(collective_id.as_bytes(), name);
+
+        let write_txn = self.begin_write()?;
+        let released = {
+            let mut table = write_txn.open_table(LOCKS_TABLE)?;
+            let matches = match table.get(key.as_slice())? {
+                Some(value) => {
+                    let lease: Lease = codec::decode(value.value())
+                        .map_err(|e| StorageError::serialization(e.to_string()))?;
+                    lease.fencing_token == fencing_token
+                }
+                None => false,
+            };
+            if matches {
+                table.remove(key.as_slice())?;
+            }
+            matches
+        };
+        write_txn.commit().map_err(StorageError::from)?;
+
+        if released {
+            debug!(name = %name, fencing_token, "Lock released");
+        }
+        Ok(released)
+    }
+
+    fn get_lock(&self, collective_id: CollectiveId, name: &str) -> Result<Option<Lease>> {
+        let key = encode_lock_key(collective_id.as_bytes(), name);
+
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(LOCKS_TABLE)?;
+
+        match table.get(key.as_slice())? {
+            Some(value) => {
+                let lease: Lease = codec::decode(value.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?;
+                Ok(Some(lease))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn list_locks_in_collective(&self, collective_id: CollectiveId) -> Result<Vec<Lease>> {
+        let prefix: &[u8] = collective_id.as_bytes();
+
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(LOCKS_TABLE)?;
+
+        let mut leases = Vec::new();
+        for result in table.range(prefix..)? {
+            let (key, value) = result.map_err(StorageError::from)?;
+            if !key.value().starts_with(prefix) {
+                break;
+            }
+            let lease: Lease = codec::decode(value.value())
+                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            leases.push(lease);
+        }
+        Ok(leases)
+    }
+
+    fn delete_locks_by_collective(&self, collective_id: CollectiveId) -> Result<u64> {
+        let prefix: &[u8] = collective_id.as_bytes();
+
+        let write_txn = self.begin_write()?;
+        let count = {
+            let mut table = write_txn.open_table(LOCKS_TABLE)?;
+            // Keys start with the 16-byte collective ID, so this collective's
+            // locks form one contiguous range beginning at the prefix
+            let mut keys = Vec::new();
+            for result in table.range(prefix..)? {
+                let (key, _) = result.map_err(StorageError::from)?;
+                if !key.value().starts_with(prefix) {
+                    break;
+                }
+                keys.push(key.value().to_vec());
+            }
+            for key in &keys {
+                table.remove(key.as_slice())?;
+            }
+            keys.len() as u64
+        };
+        write_txn.commit().map_err(StorageError::from)?;
+
+        if count > 0 {
+            debug!(collective_id = %collective_id, count, "Cascade-deleted locks for collective");
+        }
+        Ok(count)
+    }
+
+    // =========================================================================
+    // Knowledge Gap Operations
+    // =========================================================================
+
+    fn save_knowledge_gaps(&self, records: &[KnowledgeGapRecord]) -> Result<()> {
+        let write_txn = self.begin_write()?;
+        {
+            let mut table = write_txn.open_table(KNOWLEDGE_GAPS_TABLE)?;
+            for record in records {
+                let bytes = codec::encode(record)
                     .map_err(|e| StorageError::serialization(e.to_string()))?;
-                activities.push(activity);
+                let key = encode_gap_key(
+                    record.collective_id.as_bytes(),
+                    uuid::Uuid::now_v7().as_bytes(),
+                );
+                table.insert(&key, bytes.as_slice())?;
             }
         }
+        write_txn.commit().map_err(StorageError::from)?;
 
-        Ok(activities)
+        debug!(count = records.len(), "Knowledge gaps recorded");
+        Ok(())
     }
 
-    fn delete_activities_by_collective(&self, collective_id: CollectiveId) -> Result<u64> {
-        let prefix = collective_id.as_bytes();
+    fn list_knowledge_gaps(&self, collective_id: CollectiveId) -> Result<Vec<KnowledgeGapRecord>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_table(KNOWLEDGE_GAPS_TABLE)?;
+        let start = encode_gap_key(collective_id.as_bytes(), &[0u8; 16]);
+        let end = encode_gap_key(collective_id.as_bytes(), &[0xFF; 16]);
 
-        // Phase 1: Read — collect matching keys
-        let keys_to_delete: Vec<Vec<u8>> = {
-            let read_txn = self.db.begin_read().map_err(StorageError::from)?;
-            let table = read_txn.open_table(ACTIVITIES_TABLE)?;
+        let mut records = Vec::new();
+        for entry in table.range::<&[u8; 32]>(&start..=&end)? {
+            let (_, value) = entry.map_err(StorageError::from)?;
+            records.push(
+                codec::decode(value.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?,
+            );
+        }
+        Ok(records)
+    }
+
+    fn delete_knowledge_gaps_by_collective(&self, collective_id: CollectiveId) -> Result<u64> {
+        let start = encode_gap_key(collective_id.as_bytes(), &[0u8; 16]);
+        let end = encode_gap_key(collective_id.as_bytes(), &[0xFF; 16]);
 
+        let write_txn = self.begin_write()?;
+        let count = {
+            let mut table = write_txn.open_table(KNOWLEDGE_GAPS_TABLE)?;
             let mut keys = Vec::new();
-            for result in table.iter()? {
+            for result in table.range::<&[u8; 32]>(&start..=&end)? {
                 let (key, _) = result.map_err(StorageError::from)?;
-                let key_bytes = key.value();
-
-                if key_bytes.len() >= 16
-                    && decode_collective_from_activity_key(key_bytes) == *prefix
-                {
-                    keys.push(key_bytes.to_vec());
-                }
+                keys.push(*key.value());
             }
-            keys
-        };
-
-        let count = keys_to_delete.len() as u64;
-        if count == 0 {
-            return Ok(0);
-        }
-
-        // Phase 2: Write — delete all collected keys
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
-        {
-            let mut table = write_txn.open_table(ACTIVITIES_TABLE)?;
-            for key in &keys_to_delete {
-                table.remove(key.as_slice())?;
+            for key in &keys {
+                table.remove(key)?;
             }
-        }
+            keys.len() as u64
+        };
         write_txn.commit().map_err(StorageError::from)?;
 
-        debug!(
-            collective_id = %collective_id,
-            count = count,
-            "Cascade-deleted activities for collective"
-        );
+        if count > 0 {
+            debug!(collective_id = %collective_id, count, "Deleted knowledge gaps for collective");
+        }
         Ok(count)
     }
 
@@ -1537,34 +4058,23 @@ impl StorageEngine for RedbStorage {
         limit: usize,
         offset: usize,
     ) -> Result<Vec<crate::relation::ExperienceRelation>> {
-        // Get all experience IDs in this collective first
-        let exp_ids = self.list_experience_ids_in_collective(collective_id)?;
-
         let read_txn = self.db.begin_read().map_err(StorageError::from)?;
-        let source_table = read_txn.open_multimap_table(RELATIONS_BY_SOURCE_TABLE)?;
+        let idx_table = read_txn.open_multimap_table(RELATIONS_BY_COLLECTIVE_TABLE)?;
         let rel_table = read_txn.open_table(RELATIONS_TABLE)?;
 
         let mut relations = Vec::new();
-        let mut skipped = 0usize;
-
-        for exp_id in &exp_ids {
-            for result in source_table.get(exp_id.as_bytes())? {
-                let rel_id_value = result.map_err(StorageError::from)?;
-                let rel_id = RelationId::from_bytes(*rel_id_value.value());
-
-                if skipped < offset {
-                    skipped += 1;
-                    continue;
-                }
+        for result in idx_table.get(collective_id.as_bytes())?.skip(offset) {
+            let value = result.map_err(StorageError::from)?;
+            // Entry is [created_at: 8 bytes][relation_id: 16 bytes]
+            let mut rel_bytes = [0u8; 16];
+            rel_bytes.copy_from_slice(&value.value()[8..24]);
 
-                if let Some(entry) = rel_table.get(rel_id.as_bytes())? {
-                    let relation: crate::relation::ExperienceRelation =
-                        bincode::deserialize(entry.value())
-                            .map_err(|e| StorageError::serialization(e.to_string()))?;
-                    relations.push(relation);
-                    if relations.len() >= limit {
-                        return Ok(relations);
-                    }
+            if let Some(entry) = rel_table.get(&rel_bytes)? {
+                let relation: crate::relation::ExperienceRelation = codec::decode(entry.value())
+                    .map_err(|e| StorageError::serialization(e.to_string()))?;
+                relations.push(relation);
+                if relations.len() >= limit {
+                    break;
                 }
             }
         }
@@ -1572,6 +4082,25 @@ impl StorageEngine for RedbStorage {
         Ok(relations)
     }
 
+    fn list_relation_ids_in_collective(
+        &self,
+        collective_id: CollectiveId,
+    ) -> Result<Vec<RelationId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(RELATIONS_BY_COLLECTIVE_TABLE)?;
+
+        let mut ids = Vec::new();
+        for result in table.get(collective_id.as_bytes())? {
+            let value = result.map_err(StorageError::from)?;
+            // Entry is [created_at: 8 bytes][relation_id: 16 bytes]
+            let mut rel_bytes = [0u8; 16];
+            rel_bytes.copy_from_slice(&value.value()[8..24]);
+            ids.push(RelationId::from_bytes(rel_bytes));
+        }
+
+        Ok(ids)
+    }
+
     fn list_insight_ids_paginated(
         &self,
         collective_id: CollectiveId,
@@ -1599,6 +4128,67 @@ impl StorageEngine for RedbStorage {
         Ok(ids)
     }
 
+    fn list_experience_keys_after(
+        &self,
+        collective_id: CollectiveId,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<Vec<(Timestamp, ExperienceId)>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(EXPERIENCES_BY_COLLECTIVE_TABLE)?;
+        let keys = timestamped_keys_after(table.get(collective_id.as_bytes())?, cursor, limit)?;
+        Ok(keys
+            .into_iter()
+            .map(|(timestamp, id)| (timestamp, ExperienceId::from_bytes(id)))
+            .collect())
+    }
+
+    fn list_relation_keys_after(
+        &self,
+        collective_id: CollectiveId,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<Vec<(Timestamp, RelationId)>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(RELATIONS_BY_COLLECTIVE_TABLE)?;
+        let keys = timestamped_keys_after(table.get(collective_id.as_bytes())?, cursor, limit)?;
+        Ok(keys
+            .into_iter()
+            .map(|(timestamp, id)| (timestamp, RelationId::from_bytes(id)))
+            .collect())
+    }
+
+    fn list_insight_ids_after(
+        &self,
+        collective_id: CollectiveId,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<Vec<InsightId>> {
+        let read_txn = self.db.begin_read().map_err(StorageError::from)?;
+        let table = read_txn.open_multimap_table(INSIGHTS_BY_COLLECTIVE_TABLE)?;
+        let values = table.get(collective_id.as_bytes())?;
+        let position = cursor.position().map(|(_, id)| id);
+        let newest_first = cursor.newest_first();
+        let values: Box<dyn Iterator<Item = _>> = if newest_first {
+            Box::new(values.rev())
+        } else {
+            Box::new(values)
+        };
+
+        let mut ids = Vec::new();
+        for result in values {
+            let id = *result.map_err(StorageError::from)?.value();
+            if position.is_some_and(|p| if newest_first { id >= p } else { id <= p }) {
+                continue;
+            }
+            ids.push(InsightId::from_bytes(id));
+            if ids.len() >= limit {
+                break;
+            }
+        }
+        Ok(ids)
+    }
+
     // =========================================================================
     // Watch Event Operations (E4-S02)
     // =========================================================================
@@ -1634,7 +4224,7 @@ impl StorageEngine for RedbStorage {
         for entry in events_table.range::<&[u8; 8]>(&start_key..=&end_key)? {
             let (key, value) = entry.map_err(StorageError::from)?;
             let seq = u64::from_be_bytes(*key.value());
-            let record: WatchEventRecord = bincode::deserialize(value.value())
+            let record: WatchEventRecord = codec::decode(value.value())
                 .map_err(|e| StorageError::serialization(e.to_string()))?;
             events.push(record);
             max_seq = seq;
@@ -1646,11 +4236,12 @@ impl StorageEngine for RedbStorage {
         Ok((events, max_seq))
     }
 
-    // =========================================================================
-    // Sync Operations (feature: sync)
-    // =========================================================================
+    fn set_commit_listener(&self, listener: CommitListener) {
+        if let Ok(mut slot) = self.commit_listener.write() {
+            *slot = Some(listener);
+        }
+    }
 
-    #[cfg(feature = "sync")]
     fn poll_sync_events(
         &self,
         since_seq: u64,
@@ -1666,7 +4257,7 @@ impl StorageEngine for RedbStorage {
         for entry in events_table.range::<&[u8; 8]>(&start_key..=&end_key)? {
             let (key, value) = entry.map_err(StorageError::from)?;
             let seq = u64::from_be_bytes(*key.value());
-            let record: WatchEventRecord = bincode::deserialize(value.value())
+            let record: WatchEventRecord = codec::decode(value.value())
                 .map_err(|e| StorageError::serialization(e.to_string()))?;
             events.push((seq, record));
             if events.len() >= limit {
@@ -1677,6 +4268,10 @@ impl StorageEngine for RedbStorage {
         Ok(events)
     }
 
+    // =========================================================================
+    // Sync Operations (feature: sync)
+    // =========================================================================
+
     #[cfg(feature = "sync")]
     fn instance_id(&self) -> crate::sync::InstanceId {
         self.instance_id
@@ -1684,11 +4279,11 @@ impl StorageEngine for RedbStorage {
 
     #[cfg(feature = "sync")]
     fn save_sync_cursor(&self, cursor: &crate::sync::SyncCursor) -> Result<()> {
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         {
             let mut table = write_txn.open_table(SYNC_CURSORS_TABLE)?;
-            let bytes = bincode::serialize(cursor)
-                .map_err(|e| StorageError::serialization(e.to_string()))?;
+            let bytes =
+                codec::encode(cursor).map_err(|e| StorageError::serialization(e.to_string()))?;
             table.insert(cursor.instance_id.as_bytes(), bytes.as_slice())?;
         }
         write_txn.commit().map_err(StorageError::from)?;
@@ -1709,7 +4304,7 @@ impl StorageEngine for RedbStorage {
         let table = read_txn.open_table(SYNC_CURSORS_TABLE)?;
         match table.get(instance_id.as_bytes())? {
             Some(entry) => {
-                let cursor: crate::sync::SyncCursor = bincode::deserialize(entry.value())
+                let cursor: crate::sync::SyncCursor = codec::decode(entry.value())
                     .map_err(|e| StorageError::serialization(e.to_string()))?;
                 Ok(Some(cursor))
             }
@@ -1724,7 +4319,7 @@ impl StorageEngine for RedbStorage {
         let mut cursors = Vec::new();
         for entry in table.iter()? {
             let (_, value) = entry.map_err(StorageError::from)?;
-            let cursor: crate::sync::SyncCursor = bincode::deserialize(value.value())
+            let cursor: crate::sync::SyncCursor = codec::decode(value.value())
                 .map_err(|e| StorageError::serialization(e.to_string()))?;
             cursors.push(cursor);
         }
@@ -1760,7 +4355,7 @@ impl StorageEngine for RedbStorage {
         let count = keys_to_delete.len() as u64;
 
         // Delete in a write transaction
-        let write_txn = self.db.begin_write().map_err(StorageError::from)?;
+        let write_txn = self.begin_write()?;
         {
             let mut events_table = write_txn.open_table(WATCH_EVENTS_TABLE)?;
             for key in &keys_to_delete {
@@ -1774,14 +4369,499 @@ impl StorageEngine for RedbStorage {
     }
 }
 
+// ============================================================================
+// Collective record helpers
+// ============================================================================
+
+/// Decodes a collective record and attaches its stored description and
+/// settings.
+fn decode_collective(
+    bytes: &[u8],
+    details: &impl ReadableTable<&'static [u8; 16], &'static [u8]>,
+) -> Result<Collective> {
+    let mut collective: Collective =
+        codec::decode(bytes).map_err(|e| StorageError::serialization(e.to_string()))?;
+    if let Some(value) = details.get(collective.id.as_bytes())? {
+        let stored: CollectiveDetails =
+            codec::decode(value.value()).map_err(|e| StorageError::serialization(e.to_string()))?;
+        stored.apply_to(&mut collective);
+    }
+    Ok(collective)
+}
+
+// ============================================================================
+// Activity record helpers
+// ============================================================================
+
+/// On-disk layout of `ACTIVITIES_TABLE` values.
+///
+/// Mirrors [`Activity`] minus `capabilities`, which live in
+/// `ACTIVITY_CAPABILITIES_TABLE` so records written before capabilities
+/// existed still decode.
+#[derive(Serialize, Deserialize)]
+struct ActivityRecord {
+    agent_id: String,
+    collective_id: CollectiveId,
+    current_task: Option<String>,
+    context_summary: Option<String>,
+    started_at: Timestamp,
+    last_heartbeat: Timestamp,
+}
+
+impl Record for ActivityRecord {}
+
+impl From<&Activity> for ActivityRecord {
+    fn from(activity: &Activity) -> Self {
+        Self {
+            agent_id: activity.agent_id.clone(),
+            collective_id: activity.collective_id,
+            current_task: activity.current_task.clone(),
+            context_summary: activity.context_summary.clone(),
+            started_at: activity.started_at,
+            last_heartbeat: activity.last_heartbeat,
+        }
+    }
+}
+
+/// Decodes an activity record and attaches its stored capabilities.
+fn decode_activity(
+    bytes: &[u8],
+    caps_table: &impl ReadableTable<&'static [u8], &'static [u8]>,
+    key: &[u8],
+) -> Result<Activity> {
+    let record: ActivityRecord =
+        codec::decode(bytes).map_err(|e| StorageError::serialization(e.to_string()))?;
+    let capabilities = match caps_table.get(key)? {
+        Some(value) => {
+            codec::decode(value.value()).map_err(|e| StorageError::serialization(e.to_string()))?
+        }
+        None => Vec::new(),
+    };
+    Ok(Activity {
+        agent_id: record.agent_id,
+        collective_id: record.collective_id,
+        current_task: record.current_task,
+        context_summary: record.context_summary,
+        capabilities,
+        started_at: record.started_at,
+        last_heartbeat: record.last_heartbeat,
+    })
+}
+
+/// Removes an activity's stored capabilities and their index entries.
+fn remove_activity_capabilities(
+    write_txn: &::redb::WriteTransaction,
+    collective_id: &[u8; 16],
+    agent_id: &str,
+) -> Result<()> {
+    let key = encode_activity_key(collective_id, agent_id);
+    let mut caps_table = write_txn.open_table(ACTIVITY_CAPABILITIES_TABLE)?;
+    let previous: Vec<String> = match caps_table.remove(key.as_slice())? {
+        Some(value) => {
+            codec::decode(value.value()).map_err(|e| StorageError::serialization(e.to_string()))?
+        }
+        None => return Ok(()),
+    };
+
+    let mut idx_table = write_txn.open_multimap_table(AGENTS_BY_CAPABILITY_TABLE)?;
+    for capability in &previous {
+        let cap_key = encode_capability_key(collective_id, capability);
+        idx_table.remove(cap_key.as_slice(), agent_id)?;
+    }
+    Ok(())
+}
+
+/// Prefixes every value in a record table with the bincode codec tag.
+fn prefix_codec_headers<K: Key + 'static>(
+    write_txn: &::redb::WriteTransaction,
+    definition: TableDefinition<K, &'static [u8]>,
+) -> Result<usize> {
+    let mut table = write_txn.open_table(definition)?;
+    let mut entries = Vec::new();
+    for entry in table.iter()? {
+        let (key, value) = entry.map_err(StorageError::from)?;
+        entries.push((
+            K::as_bytes(&key.value()).as_ref().to_vec(),
+            value.value().to_vec(),
+        ));
+    }
+    for (key, payload) in &entries {
+        let mut bytes = Vec::with_capacity(payload.len() + 1);
+        bytes.push(CodecId::Bincode.tag());
+        bytes.extend_from_slice(payload);
+        table.insert(K::from_bytes(key), bytes.as_slice())?;
+    }
+    Ok(entries.len())
+}
+
+/// Copies every entry of a table into the same table of another database.
+///
+/// Tables that were never created in the source are skipped.
+fn copy_table<K: Key + 'static, V: Value + 'static>(
+    read_txn: &ReadTransaction,
+    write_txn: &WriteTransaction,
+    definition: TableDefinition<K, V>,
+    copied: &mut Vec<String>,
+) -> Result<()> {
+    let source = match read_txn.open_table(definition) {
+        Ok(table) => table,
+        Err(::redb::TableError::TableDoesNotExist(_)) => return Ok(()),
+        Err(e) => return Err(StorageError::from(e).into()),
+    };
+    let mut target = write_txn.open_table(definition)?;
+    for entry in source.iter()? {
+        let (key, value) = entry.map_err(StorageError::from)?;
+        target.insert(key.value(), value.value())?;
+    }
+    copied.push(definition.name().to_string());
+    Ok(())
+}
+
+/// Multimap counterpart of [`copy_table`].
+fn copy_multimap_table<K: Key + 'static, V: Key + 'static>(
+    read_txn: &ReadTransaction,
+    write_txn: &WriteTransaction,
+    definition: MultimapTableDefinition<K, V>,
+    copied: &mut Vec<String>,
+) -> Result<()> {
+    let source = match read_txn.open_multimap_table(definition) {
+        Ok(table) => table,
+        Err(::redb::TableError::TableDoesNotExist(_)) => return Ok(()),
+        Err(e) => return Err(StorageError::from(e).into()),
+    };
+    let mut target = write_txn.open_multimap_table(definition)?;
+    for entry in source.iter()? {
+        let (key, values) = entry.map_err(StorageError::from)?;
+        for value in values {
+            let value = value.map_err(StorageError::from)?;
+            target.insert(key.value(), value.value())?;
+        }
+    }
+    copied.push(definition.name().to_string());
+    Ok(())
+}
+
+// ============================================================================
+// Write lock retries
+// ============================================================================
+
+/// Upper bound on one round of waiting for the write lock.
+const MAX_WRITE_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Waits for the write gate in rounds that double in length.
+///
+/// Returns [`PulseDBError::Busy`] once `policy.max_retries` retries have
+/// passed with the gate still held.
+fn acquire_write_gate<'a>(
+    gate: &'a WriteGate,
+    policy: &WriteRetryConfig,
+    deadline: &Deadline,
+) -> Result<WriteGateGuard<'a>> {
+    let mut patience = policy.retry_backoff;
+    for attempt in 0..=policy.max_retries {
+        if let Some(guard) = gate.acquire_within(deadline, patience)? {
+            return Ok(guard);
+        }
+        debug!(attempt = attempt + 1, "Write lock still held; retrying");
+        patience = (patience * 2).min(MAX_WRITE_RETRY_BACKOFF);
+    }
+    Err(PulseDBError::busy(format!(
+        "write lock still held after {} attempts",
+        policy.max_retries + 1
+    )))
+}
+
+// ============================================================================
+// Index entry helpers
+// ============================================================================
+
+/// Adds an insight's entries to `INSIGHTS_BY_SOURCE_TABLE`.
+fn index_insight_sources(
+    write_txn: &::redb::WriteTransaction,
+    insight: &DerivedInsight,
+) -> Result<()> {
+    let mut by_source = write_txn.open_multimap_table(INSIGHTS_BY_SOURCE_TABLE)?;
+    for source in &insight.source_experience_ids {
+        by_source.insert(source.as_bytes(), insight.id.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Drops an insight's entries from `INSIGHTS_BY_SOURCE_TABLE`.
+fn unindex_insight_sources(
+    write_txn: &::redb::WriteTransaction,
+    insight: &DerivedInsight,
+) -> Result<()> {
+    let mut by_source = write_txn.open_multimap_table(INSIGHTS_BY_SOURCE_TABLE)?;
+    for source in &insight.source_experience_ids {
+        by_source.remove(source.as_bytes(), insight.id.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Removes an insight and its index entries, returning the removed record.
+///
+/// Does not record a WAL event; the caller does, once per insight.
+fn remove_insight_in(
+    write_txn: &::redb::WriteTransaction,
+    id: InsightId,
+) -> Result<Option<DerivedInsight>> {
+    let insight: DerivedInsight = {
+        let mut table = write_txn.open_table(INSIGHTS_TABLE)?;
+        let Some(removed) = table.remove(id.as_bytes())? else {
+            return Ok(None);
+        };
+        codec::decode(removed.value()).map_err(|e| StorageError::serialization(e.to_string()))?
+    };
+    {
+        let mut table = write_txn.open_multimap_table(INSIGHTS_BY_COLLECTIVE_TABLE)?;
+        table.remove(insight.collective_id.as_bytes(), id.as_bytes())?;
+    }
+    {
+        let mut table = write_txn.open_multimap_table(INSIGHTS_BY_TYPE_TABLE)?;
+        let key = encode_insight_type_key(insight.collective_id.as_bytes(), insight.insight_type);
+        table.remove(&key, id.as_bytes())?;
+    }
+    {
+        let mut table = write_txn.open_table(DEGRADED_INSIGHTS_TABLE)?;
+        table.remove(id.as_bytes())?;
+    }
+    unindex_insight_sources(write_txn, &insight)?;
+    Ok(Some(insight))
+}
+
+/// Drops every agent's bookmark of an experience (cascade on delete).
+fn remove_bookmarks_for(
+    write_txn: &::redb::WriteTransaction,
+    experience_id: &[u8; 16],
+) -> Result<()> {
+    let mut by_exp = write_txn.open_multimap_table(BOOKMARKED_BY_TABLE)?;
+    let agents: Vec<String> = by_exp
+        .remove_all(experience_id)?
+        .map(|r| r.map(|v| v.value().to_string()))
+        .collect::<std::result::Result<_, _>>()
+        .map_err(StorageError::from)?;
+    if agents.is_empty() {
+        return Ok(());
+    }
+    let mut bookmarks = write_txn.open_multimap_table(BOOKMARKS_TABLE)?;
+    for agent_id in &agents {
+        bookmarks.remove(agent_id.as_str(), experience_id)?;
+    }
+    Ok(())
+}
+
+/// Drops an experience's user, task, file, and expiry index entries
+/// (cascade on delete).
+///
+/// Task, file, and expiry index entries are keyed by fields of the
+/// experience record, so this must run before the record itself is
+/// removed.
+fn remove_subject_links_for(
+    write_txn: &::redb::WriteTransaction,
+    experience_id: &[u8; 16],
+) -> Result<()> {
+    let mut users = write_txn.open_table(EXPERIENCE_USERS_TABLE)?;
+    let user = users.remove(experience_id)?.map(|v| v.value().to_string());
+    if let Some(user) = user {
+        let mut by_user = write_txn.open_multimap_table(EXPERIENCES_BY_USER_TABLE)?;
+        by_user.remove(user.as_str(), experience_id)?;
+    }
+
+    let exp_table = write_txn.open_table(EXPERIENCES_TABLE)?;
+    let Some(entry) = exp_table.get(experience_id)? else {
+        return Ok(());
+    };
+    let experience: Experience =
+        codec::decode(entry.value()).map_err(|e| StorageError::serialization(e.to_string()))?;
+    let mut expiry = write_txn.open_table(EXPERIENCE_EXPIRY_TABLE)?;
+    let expires_at = expiry.remove(experience_id)?.map(|v| v.value());
+    if let Some(expires_at) = expires_at {
+        let mut by_expiry = write_txn.open_multimap_table(EXPERIENCES_BY_EXPIRY_TABLE)?;
+        by_expiry.remove(
+            experience.collective_id.as_bytes(),
+            &expiry_entry(Timestamp::from_millis(expires_at), experience_id),
+        )?;
+    }
+    if let Some(task) = experience.source_task.as_ref() {
+        let mut by_task = write_txn.open_multimap_table(EXPERIENCES_BY_TASK_TABLE)?;
+        by_task.remove(task.as_str(), experience_id)?;
+        let mut by_agent = write_txn.open_multimap_table(TASKS_BY_AGENT_TABLE)?;
+        by_agent.remove(
+            experience.source_agent.as_str(),
+            task_agent_entry(task.as_str(), experience_id).as_slice(),
+        )?;
+    }
+    if !experience.related_files.is_empty() {
+        let mut by_file = write_txn.open_multimap_table(EXPERIENCES_BY_FILE_TABLE)?;
+        for path in &experience.related_files {
+            let key = encode_file_key(experience.collective_id.as_bytes(), path);
+            by_file.remove(key.as_slice(), experience_id)?;
+        }
+    }
+    Ok(())
+}
+
+/// Builds the `TASKS_BY_AGENT_TABLE` value for an experience:
+/// `[task_id bytes][experience_id: 16 bytes]`.
+#[inline]
+fn task_agent_entry(task_id: &str, experience_id: &[u8; 16]) -> Vec<u8> {
+    let mut entry = Vec::with_capacity(task_id.len() + 16);
+    entry.extend_from_slice(task_id.as_bytes());
+    entry.extend_from_slice(experience_id);
+    entry
+}
+
+/// Builds the `EXPERIENCES_BY_EXPIRY_TABLE` value for an experience:
+/// `[expires_at_be: 8 bytes][experience_id: 16 bytes]`.
+#[inline]
+fn expiry_entry(expires_at: Timestamp, experience_id: &[u8; 16]) -> [u8; 24] {
+    let mut value = [0u8; 24];
+    value[..8].copy_from_slice(&expires_at.to_be_bytes());
+    value[8..24].copy_from_slice(experience_id);
+    value
+}
+
+/// Builds the `RELATIONS_BY_COLLECTIVE_TABLE` value for a relation:
+/// `[created_at_be: 8 bytes][relation_id: 16 bytes]`.
+#[inline]
+fn relation_collective_entry(relation: &ExperienceRelation) -> [u8; 24] {
+    let mut value = [0u8; 24];
+    value[..8].copy_from_slice(&relation.created_at.to_be_bytes());
+    value[8..24].copy_from_slice(relation.id.as_bytes());
+    value
+}
+
+/// Collects up to `limit` `[timestamp_be: 8][id: 16]` index values that
+/// come strictly after `cursor`'s position in its direction.
+///
+/// Comparing the raw 24-byte values keeps the walk in exact index order,
+/// so records inserted or deleted elsewhere never shift where it resumes.
+fn timestamped_keys_after(
+    values: ::redb::MultimapValue<'_, &'static [u8; 24]>,
+    cursor: &Cursor,
+    limit: usize,
+) -> Result<Vec<(Timestamp, [u8; 16])>> {
+    let position = cursor.position().map(|(timestamp, id)| {
+        let mut key = [0u8; 24];
+        key[..8].copy_from_slice(&timestamp.to_be_bytes());
+        key[8..].copy_from_slice(&id);
+        key
+    });
+    let newest_first = cursor.newest_first();
+    let values: Box<dyn Iterator<Item = _>> = if newest_first {
+        Box::new(values.rev())
+    } else {
+        Box::new(values)
+    };
+
+    let mut keys = Vec::new();
+    for result in values {
+        let value = *result.map_err(StorageError::from)?.value();
+        if position.is_some_and(|p| if newest_first { value >= p } else { value <= p }) {
+            continue;
+        }
+        let mut timestamp = [0u8; 8];
+        timestamp.copy_from_slice(&value[..8]);
+        let mut id = [0u8; 16];
+        id.copy_from_slice(&value[8..]);
+        keys.push((Timestamp::from_millis(i64::from_be_bytes(timestamp)), id));
+        if keys.len() >= limit {
+            break;
+        }
+    }
+    Ok(keys)
+}
+
 // ============================================================================
 // Embedding byte conversion helpers
 // ============================================================================
 
+/// Returns the `EMBEDDING_STORAGE_KEY` tag for an encoding.
+fn embedding_storage_tag(storage: EmbeddingStorage) -> u8 {
+    match storage {
+        EmbeddingStorage::F32 => EMBEDDING_STORAGE_F32,
+        EmbeddingStorage::F16 => EMBEDDING_STORAGE_F16,
+        EmbeddingStorage::Int8 => EMBEDDING_STORAGE_INT8,
+    }
+}
+
+/// Looks up an encoding by `EMBEDDING_STORAGE_KEY` tag.
+fn embedding_storage_from_tag(tag: u8) -> Option<EmbeddingStorage> {
+    match tag {
+        EMBEDDING_STORAGE_F32 => Some(EmbeddingStorage::F32),
+        EMBEDDING_STORAGE_F16 => Some(EmbeddingStorage::F16),
+        EMBEDDING_STORAGE_INT8 => Some(EmbeddingStorage::Int8),
+        _ => None,
+    }
+}
+
+/// Returns the `CONTENT_STORAGE_KEY` tag for a content storage mode.
+fn content_storage_tag(storage: ContentStorage) -> u8 {
+    match storage {
+        ContentStorage::Inline => CONTENT_STORAGE_INLINE,
+        ContentStorage::External => CONTENT_STORAGE_EXTERNAL,
+    }
+}
+
+/// Looks up a content storage mode by `CONTENT_STORAGE_KEY` tag.
+fn content_storage_from_tag(tag: u8) -> Option<ContentStorage> {
+    match tag {
+        CONTENT_STORAGE_INLINE => Some(ContentStorage::Inline),
+        CONTENT_STORAGE_EXTERNAL => Some(ContentStorage::External),
+        _ => None,
+    }
+}
+
+/// Encodes an embedding for `EMBEDDINGS_TABLE`.
+fn encode_embedding(storage: EmbeddingStorage, data: &[f32]) -> Vec<u8> {
+    match storage {
+        EmbeddingStorage::F32 => f32_slice_to_bytes(data),
+        EmbeddingStorage::F16 => data
+            .iter()
+            .flat_map(|val| f16::from_f32(*val).to_le_bytes())
+            .collect(),
+        EmbeddingStorage::Int8 => {
+            // Symmetric per-vector scale: the largest magnitude maps to 127
+            let max = data.iter().fold(0.0f32, |max, val| max.max(val.abs()));
+            let scale = max / 127.0;
+            let mut bytes = Vec::with_capacity(4 + data.len());
+            bytes.extend_from_slice(&scale.to_le_bytes());
+            bytes.extend(data.iter().map(|val| {
+                let q = if scale > 0.0 {
+                    (val / scale).round().clamp(-127.0, 127.0)
+                } else {
+                    0.0
+                };
+                q as i8 as u8
+            }));
+            bytes
+        }
+    }
+}
+
+/// Decodes an `EMBEDDINGS_TABLE` value back to f32, dequantizing if needed.
+fn decode_embedding(storage: EmbeddingStorage, data: &[u8]) -> Vec<f32> {
+    match storage {
+        EmbeddingStorage::F32 => bytes_to_f32_vec(data),
+        EmbeddingStorage::F16 => data
+            .chunks_exact(2)
+            .map(|b| f16::from_le_bytes([b[0], b[1]]).to_f32())
+            .collect(),
+        EmbeddingStorage::Int8 => {
+            let Some((scale, values)) = data.split_first_chunk::<4>() else {
+                return Vec::new();
+            };
+            let scale = f32::from_le_bytes(*scale);
+            values.iter().map(|q| f32::from(*q as i8) * scale).collect()
+        }
+    }
+}
+
 /// Converts a slice of f32 values to raw little-endian bytes.
 #[inline]
 fn f32_slice_to_bytes(data: &[f32]) -> Vec<u8> {
-    let mut bytes = Vec::with_capacity(data.len() * 4);
+    let mut bytes = Vec::with_capacity(std::mem::size_of_val(data));
     for &val in data {
         bytes.extend_from_slice(&val.to_le_bytes());
     }
@@ -1789,6 +4869,10 @@ fn f32_slice_to_bytes(data: &[f32]) -> Vec<u8> {
 }
 
 /// Converts raw little-endian bytes back to a Vec<f32>.
+///
+/// Stored values may sit at any offset in a redb page, so they are decoded
+/// value by value rather than reinterpreted in place. Trailing bytes that
+/// don't form a whole value are ignored.
 #[inline]
 fn bytes_to_f32_vec(data: &[u8]) -> Vec<f32> {
     data.chunks_exact(4)
@@ -1913,10 +4997,60 @@ mod tests {
         // Reopen
         let storage = RedbStorage::open(&path, &config).unwrap();
         assert_eq!(
-            storage.metadata().embedding_dimension,
-            EmbeddingDimension::Custom(512)
+            storage.metadata().embedding_dimension,
+            EmbeddingDimension::Custom(512)
+        );
+        Box::new(storage).close().unwrap();
+    }
+
+    #[test]
+    fn test_v2_records_migrated_to_codec_headers() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+        let collective = Collective::new("legacy", 384);
+        storage.save_collective(&collective).unwrap();
+        let exp = test_experience(collective.id, 384);
+        storage.save_experience(&exp).unwrap();
+
+        // Rewrite as a v2 database: headerless bincode values
+        {
+            let write_txn = storage.database().begin_write().unwrap();
+            {
+                let mut table = write_txn.open_table(COLLECTIVES_TABLE).unwrap();
+                let raw = bincode::serialize(&collective).unwrap();
+                table
+                    .insert(collective.id.as_bytes(), raw.as_slice())
+                    .unwrap();
+                let mut table = write_txn.open_table(EXPERIENCES_TABLE).unwrap();
+                let raw = bincode::serialize(&exp).unwrap();
+                table.insert(exp.id.as_bytes(), raw.as_slice()).unwrap();
+                let mut meta = write_txn.open_table(METADATA_TABLE).unwrap();
+                let mut metadata = storage.metadata().clone();
+                metadata.schema_version = 2;
+                let raw = bincode::serialize(&metadata).unwrap();
+                meta.insert(METADATA_KEY, raw.as_slice()).unwrap();
+            }
+            write_txn.commit().unwrap();
+        }
+        Box::new(storage).close().unwrap();
+
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+        assert_eq!(storage.metadata().schema_version, SCHEMA_VERSION);
+        assert_eq!(
+            storage.get_collective(collective.id).unwrap().unwrap().name,
+            "legacy"
+        );
+        assert_eq!(
+            storage.get_experience(exp.id).unwrap().unwrap().content,
+            exp.content
         );
         Box::new(storage).close().unwrap();
+
+        // Migration runs once
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+        assert!(storage.get_experience(exp.id).unwrap().is_some());
+        Box::new(storage).close().unwrap();
     }
 
     #[test]
@@ -2101,7 +5235,7 @@ mod tests {
 
         let collective = Collective::new("phantom", 384);
         let id = collective.id;
-        let bytes = bincode::serialize(&collective).unwrap();
+        let bytes = codec::encode(&collective).unwrap();
 
         // Open a write transaction, insert data, but DON'T commit -- just drop
         {
@@ -2150,7 +5284,7 @@ mod tests {
 
         let collective = Collective::new("multi-table", 384);
         let id = collective.id;
-        let collective_bytes = bincode::serialize(&collective).unwrap();
+        let collective_bytes = codec::encode(&collective).unwrap();
 
         // Write to TWO tables in a single transaction
         let write_txn = storage.database().begin_write().unwrap();
@@ -2373,8 +5507,11 @@ mod tests {
             related_files: vec!["src/storage/redb.rs".into()],
             source_agent: AgentId::new("test-agent"),
             source_task: None,
+            user_id: None,
             timestamp: Timestamp::now(),
+            expires_at: None,
             archived: false,
+            attribution: None,
         }
     }
 
@@ -2559,6 +5696,266 @@ mod tests {
         Box::new(storage).close().unwrap();
     }
 
+    #[test]
+    fn test_day_index_range_reads() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+
+        let collective = Collective::new("test", 384);
+        storage.save_collective(&collective).unwrap();
+
+        // One experience per day, days 10..15, plus one at noon on day 12
+        const DAY: i64 = 86_400_000;
+        let mut ids = Vec::new();
+        for day in 10..15 {
+            let mut exp = test_experience(collective.id, 384);
+            exp.timestamp = Timestamp::from_millis(day * DAY);
+            ids.push(exp.id);
+            storage.save_experience(&exp).unwrap();
+        }
+        let mut noon = test_experience(collective.id, 384);
+        noon.timestamp = Timestamp::from_millis(12 * DAY + DAY / 2);
+        storage.save_experience(&noon).unwrap();
+
+        // Newest first, limited
+        let recent = storage.get_recent_experience_ids(collective.id, 2).unwrap();
+        assert_eq!(
+            recent.iter().map(|e| e.0).collect::<Vec<_>>(),
+            vec![ids[4], ids[3]]
+        );
+
+        // [day 12, noon day 12) — boundary bucket is filtered exactly
+        let range = storage
+            .get_experience_ids_in_range(
+                collective.id,
+                Some(Timestamp::from_millis(12 * DAY)),
+                Some(noon.timestamp),
+                usize::MAX,
+            )
+            .unwrap();
+        assert_eq!(range.iter().map(|e| e.0).collect::<Vec<_>>(), vec![ids[2]]);
+
+        // Open-ended lower bound
+        let range = storage
+            .get_experience_ids_in_range(
+                collective.id,
+                None,
+                Some(Timestamp::from_millis(12 * DAY)),
+                usize::MAX,
+            )
+            .unwrap();
+        assert_eq!(
+            range.iter().map(|e| e.0).collect::<Vec<_>>(),
+            vec![ids[1], ids[0]]
+        );
+
+        // Deletes remove the bucket entries
+        storage.delete_experience(ids[4]).unwrap();
+        let recent = storage.get_recent_experience_ids(collective.id, 1).unwrap();
+        assert_eq!(recent[0].0, ids[3]);
+        storage
+            .delete_experiences_by_collective(collective.id)
+            .unwrap();
+        assert!(storage
+            .get_recent_experience_ids(collective.id, 10)
+            .unwrap()
+            .is_empty());
+
+        Box::new(storage).close().unwrap();
+    }
+
+    #[test]
+    fn test_insert_experiences_batch() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+
+        let collective = Collective::new("test", 384);
+        storage.save_collective(&collective).unwrap();
+
+        let existing = test_experience(collective.id, 384);
+        storage.save_experience(&existing).unwrap();
+        let seq_before = storage.get_wal_sequence().unwrap();
+
+        let fresh = test_experience(collective.id, 384);
+        let written = storage
+            .insert_experiences(&[existing.clone(), fresh.clone(), fresh.clone()])
+            .unwrap();
+        assert_eq!(written, vec![false, true, false]);
+        assert!(storage.get_experience(fresh.id).unwrap().is_some());
+        // Only the written experience leaves a WAL event
+        assert_eq!(storage.get_wal_sequence().unwrap(), seq_before + 1);
+
+        Box::new(storage).close().unwrap();
+    }
+
+    #[test]
+    fn test_experience_file_index() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+
+        let collective = Collective::new("test", 384);
+        storage.save_collective(&collective).unwrap();
+
+        let exp = test_experience(collective.id, 384);
+        let exp_id = exp.id;
+        storage.save_experience(&exp).unwrap();
+        assert_eq!(
+            storage
+                .list_experience_files(collective.id, "src/")
+                .unwrap(),
+            vec![("src/storage/redb.rs".to_string(), exp_id)]
+        );
+        // Other collectives see nothing
+        assert!(storage
+            .list_experience_files(CollectiveId::new(), "")
+            .unwrap()
+            .is_empty());
+
+        let update = ExperienceUpdate {
+            related_files: Some(vec!["src/db.rs".into(), "README.md".into()]),
+            ..Default::default()
+        };
+        storage.update_experience(exp_id, &update).unwrap();
+        let files: Vec<String> = storage
+            .list_experience_files(collective.id, "")
+            .unwrap()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(files, vec!["README.md", "src/db.rs"]);
+
+        storage.delete_experience(exp_id).unwrap();
+        assert!(storage
+            .list_experience_files(collective.id, "")
+            .unwrap()
+            .is_empty());
+
+        Box::new(storage).close().unwrap();
+    }
+
+    #[test]
+    fn test_experience_meta_sidecar() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+
+        let collective = Collective::new("test", 384);
+        storage.save_collective(&collective).unwrap();
+        let exp = test_experience(collective.id, 384);
+        let other = test_experience(collective.id, 384);
+        storage.save_experience(&exp).unwrap();
+        storage.save_experience(&other).unwrap();
+
+        let metas = storage
+            .get_experience_meta(&[exp.id, ExperienceId::new()])
+            .unwrap();
+        assert_eq!(metas, vec![Some(ExperienceMeta::of(&exp)), None]);
+
+        // Updates keep the sidecar in step. The domain change rewrites the
+        // record as well, as every update did in older databases.
+        let update = ExperienceUpdate {
+            importance: Some(0.1),
+            domain: Some(vec!["ops".to_string()]),
+            archived: Some(true),
+            ..Default::default()
+        };
+        storage.update_experience(exp.id, &update).unwrap();
+        let meta = storage.get_experience_meta(&[exp.id]).unwrap()[0].unwrap();
+        assert!(meta.archived);
+        assert_eq!(meta.importance, 0.1);
+
+        // Reopening an older database backfills the sidecar
+        {
+            let write_txn = storage.database().begin_write().unwrap();
+            {
+                let mut table = write_txn.open_table(EXPERIENCE_META_TABLE).unwrap();
+                table.retain(|_, _| false).unwrap();
+            }
+            write_txn.commit().unwrap();
+        }
+        Box::new(storage).close().unwrap();
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+        let metas = storage.get_experience_meta(&[exp.id, other.id]).unwrap();
+        assert!(metas[0].unwrap().archived);
+        assert_eq!(metas[1], Some(ExperienceMeta::of(&other)));
+
+        // Deletes remove the entry
+        storage.delete_experience(other.id).unwrap();
+        assert_eq!(
+            storage.get_experience_meta(&[other.id]).unwrap(),
+            vec![None]
+        );
+        storage
+            .delete_experiences_by_collective(collective.id)
+            .unwrap();
+        assert_eq!(storage.get_experience_meta(&[exp.id]).unwrap(), vec![None]);
+
+        Box::new(storage).close().unwrap();
+    }
+
+    #[test]
+    fn test_experience_cache_sees_every_write() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+
+        let collective = Collective::new("test", 384);
+        storage.save_collective(&collective).unwrap();
+        let exp = test_experience(collective.id, 384);
+        storage.save_experience(&exp).unwrap();
+
+        // Second read is served from the cache
+        storage.get_experience(exp.id).unwrap().unwrap();
+        assert_eq!(storage.experience_cache.len(), 1);
+        assert_eq!(
+            storage.get_experience(exp.id).unwrap().unwrap().content,
+            exp.content
+        );
+
+        let update = ExperienceUpdate {
+            importance: Some(0.2),
+            ..Default::default()
+        };
+        storage.update_experience(exp.id, &update).unwrap();
+        assert_eq!(
+            storage.get_experience(exp.id).unwrap().unwrap().importance,
+            0.2
+        );
+
+        storage.reinforce_experience(exp.id).unwrap();
+        assert_eq!(
+            storage
+                .get_experience(exp.id)
+                .unwrap()
+                .unwrap()
+                .applications,
+            1
+        );
+
+        storage.save_embedding(exp.id, &[0.5; 384]).unwrap();
+        assert_eq!(
+            storage.get_experience(exp.id).unwrap().unwrap().embedding,
+            vec![0.5; 384]
+        );
+
+        storage.delete_experience(exp.id).unwrap();
+        assert!(storage.get_experience(exp.id).unwrap().is_none());
+
+        let again = test_experience(collective.id, 384);
+        storage.save_experience(&again).unwrap();
+        storage.get_experience(again.id).unwrap().unwrap();
+        storage
+            .delete_experiences_by_collective(collective.id)
+            .unwrap();
+        assert!(storage.get_experience(again.id).unwrap().is_none());
+        assert!(storage.experience_cache.is_empty());
+
+        Box::new(storage).close().unwrap();
+    }
+
     #[test]
     fn test_cascade_delete_includes_experiences() {
         let dir = tempdir().unwrap();
@@ -2636,6 +6033,100 @@ mod tests {
         assert_eq!(original, restored);
     }
 
+    #[test]
+    fn test_f32_byte_conversion_layout() {
+        let original = vec![1.5f32, -2.25, 3072.0];
+        let expected: Vec<u8> = original.iter().flat_map(|v| v.to_le_bytes()).collect();
+        assert_eq!(f32_slice_to_bytes(&original), expected);
+
+        // Stored values may sit at any offset in a page
+        let mut unaligned = vec![0u8];
+        unaligned.extend_from_slice(&expected);
+        unaligned.push(0xFF);
+        assert_eq!(bytes_to_f32_vec(&unaligned[1..]), original);
+        assert!(bytes_to_f32_vec(&[]).is_empty());
+    }
+
+    /// Cosine similarity between two vectors.
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        dot / (norm(a) * norm(b))
+    }
+
+    #[test]
+    fn test_quantized_embedding_roundtrip() {
+        let original: Vec<f32> = (0..1536)
+            .map(|i| ((i * 37 % 101) as f32 - 50.0) / 73.0)
+            .collect();
+
+        let f16 = encode_embedding(EmbeddingStorage::F16, &original);
+        assert_eq!(f16.len(), 1536 * 2);
+        let restored = decode_embedding(EmbeddingStorage::F16, &f16);
+        assert_eq!(restored.len(), original.len());
+        assert!(cosine(&original, &restored) > 0.99999);
+
+        let int8 = encode_embedding(EmbeddingStorage::Int8, &original);
+        assert_eq!(int8.len(), 1536 + 4);
+        let restored = decode_embedding(EmbeddingStorage::Int8, &int8);
+        assert_eq!(restored.len(), original.len());
+        assert!(cosine(&original, &restored) > 0.999);
+
+        // The largest magnitude survives exactly; zero vectors stay zero
+        let extremes = decode_embedding(
+            EmbeddingStorage::Int8,
+            &encode_embedding(EmbeddingStorage::Int8, &[-2.0, 0.5, 2.0]),
+        );
+        assert_eq!(extremes[0], -2.0);
+        assert_eq!(extremes[2], 2.0);
+        let zeros = encode_embedding(EmbeddingStorage::Int8, &[0.0; 4]);
+        assert_eq!(
+            decode_embedding(EmbeddingStorage::Int8, &zeros),
+            vec![0.0; 4]
+        );
+        assert!(decode_embedding(EmbeddingStorage::Int8, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_reopen_reencodes_embeddings() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let embedding = vec![0.25, -0.5, 0.75, 1.0];
+
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+        let ids: Vec<ExperienceId> = (0..3000).map(|_| ExperienceId::new()).collect();
+        for id in &ids {
+            storage.save_embedding(*id, &embedding).unwrap();
+        }
+        Box::new(storage).close().unwrap();
+
+        // F32 → Int8 rewrites every vector, across several chunks
+        let config = Config {
+            embedding_storage: EmbeddingStorage::Int8,
+            ..default_config()
+        };
+        let storage = RedbStorage::open(&path, &config).unwrap();
+        {
+            let read_txn = storage.db.begin_read().unwrap();
+            let table = read_txn.open_table(EMBEDDINGS_TABLE).unwrap();
+            for entry in table.iter().unwrap() {
+                assert_eq!(entry.unwrap().1.value().len(), 4 + 4);
+            }
+        }
+        for id in [ids[0], ids[1500], ids[2999]] {
+            let restored = storage.get_embedding(id).unwrap().unwrap();
+            assert!(cosine(&embedding, &restored) > 0.999);
+        }
+        Box::new(storage).close().unwrap();
+
+        // And back to F32, reading the stored encoding from metadata
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+        let restored = storage.get_embedding(ids[42]).unwrap().unwrap();
+        assert_eq!(restored.len(), 4);
+        assert!(cosine(&embedding, &restored) > 0.999);
+        Box::new(storage).close().unwrap();
+    }
+
     #[test]
     fn test_experience_with_all_type_variants() {
         let dir = tempdir().unwrap();
@@ -2750,6 +6241,83 @@ mod tests {
         Box::new(storage).close().unwrap();
     }
 
+    #[test]
+    fn test_metadata_updates_leave_record_untouched() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+
+        let collective = Collective::new("test", 384);
+        storage.save_collective(&collective).unwrap();
+        let exp = test_experience(collective.id, 384);
+        storage.save_experience(&exp).unwrap();
+        let raw_record = |storage: &RedbStorage| {
+            let read_txn = storage.database().begin_read().unwrap();
+            let table = read_txn.open_table(EXPERIENCES_TABLE).unwrap();
+            let bytes = table
+                .get(exp.id.as_bytes())
+                .unwrap()
+                .unwrap()
+                .value()
+                .to_vec();
+            bytes
+        };
+        let before = raw_record(&storage);
+
+        // Reinforcement and score/archive updates only touch the sidecars
+        storage.reinforce_experience(exp.id).unwrap();
+        storage.reinforce_experience(exp.id).unwrap();
+        let update = ExperienceUpdate {
+            importance: Some(0.9),
+            confidence: Some(0.2),
+            archived: Some(true),
+            ..Default::default()
+        };
+        storage.update_experience(exp.id, &update).unwrap();
+        assert_eq!(raw_record(&storage), before);
+
+        let retrieved = storage.get_experience(exp.id).unwrap().unwrap();
+        assert_eq!(retrieved.applications, 2);
+        assert_eq!(retrieved.importance, 0.9);
+        assert_eq!(retrieved.confidence, 0.2);
+        assert!(retrieved.archived);
+
+        // A domain update rewrites the record with the current values
+        let update = ExperienceUpdate {
+            domain: Some(vec!["ops".to_string()]),
+            ..Default::default()
+        };
+        storage.update_experience(exp.id, &update).unwrap();
+        assert_ne!(raw_record(&storage), before);
+        let retrieved = storage.get_experience(exp.id).unwrap().unwrap();
+        assert_eq!(retrieved.domain, vec!["ops".to_string()]);
+        assert_eq!(retrieved.applications, 2);
+        assert_eq!(retrieved.importance, 0.9);
+        assert!(retrieved.archived);
+
+        // Reopening an older database backfills the applications sidecar
+        {
+            let write_txn = storage.database().begin_write().unwrap();
+            {
+                let mut table = write_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE).unwrap();
+                table.retain(|_, _| false).unwrap();
+            }
+            write_txn.commit().unwrap();
+        }
+        Box::new(storage).close().unwrap();
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+        assert_eq!(storage.reinforce_experience(exp.id).unwrap(), Some(3));
+
+        // Deletes remove the entry
+        storage.delete_experience(exp.id).unwrap();
+        {
+            let read_txn = storage.database().begin_read().unwrap();
+            let table = read_txn.open_table(EXPERIENCE_APPLICATIONS_TABLE).unwrap();
+            assert!(table.get(exp.id.as_bytes()).unwrap().is_none());
+        }
+        Box::new(storage).close().unwrap();
+    }
+
     // ====================================================================
     // WAL Sequence Tracking Tests (E4-S02)
     // ====================================================================
@@ -2995,4 +6563,110 @@ mod tests {
 
         Box::new(storage).close().unwrap();
     }
+
+    #[test]
+    fn test_begin_write_times_out_behind_held_transaction() {
+        let dir = tempdir().unwrap();
+        let config = Config {
+            timeouts: crate::config::TimeoutConfig {
+                write_transaction: Some(Duration::from_millis(30)),
+                ..Default::default()
+            },
+            ..default_config()
+        };
+        let storage = RedbStorage::open(dir.path().join("test.db"), &config).unwrap();
+
+        let held = storage.begin_write().unwrap();
+        let err = storage.begin_write().err().unwrap();
+        assert!(matches!(
+            err,
+            PulseDBError::Timeout {
+                operation: TimedOperation::WriteTransaction,
+                ..
+            }
+        ));
+
+        // The next writer gets in once the first commits
+        held.commit().unwrap();
+        storage.begin_write().unwrap().commit().unwrap();
+    }
+
+    #[test]
+    fn test_begin_write_busy_after_retries() {
+        let dir = tempdir().unwrap();
+        let config = Config {
+            write_retry: WriteRetryConfig {
+                max_retries: 2,
+                retry_backoff: Duration::from_millis(5),
+            },
+            ..default_config()
+        };
+        let storage = RedbStorage::open(dir.path().join("test.db"), &config).unwrap();
+
+        let held = storage.begin_write().unwrap();
+        let err = storage.begin_write().err().unwrap();
+        assert!(err.is_busy());
+        assert!(err.to_string().contains("after 3 attempts"));
+
+        // A writer waiting through its retries gets the lock once released
+        std::thread::scope(|scope| {
+            let waiter = scope.spawn(|| storage.begin_write().map(|txn| txn.commit().unwrap()));
+            std::thread::sleep(Duration::from_millis(5));
+            held.commit().unwrap();
+            waiter.join().unwrap().unwrap();
+        });
+    }
+
+    #[test]
+    fn test_activity_stored_before_capabilities_decodes() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let storage = RedbStorage::open(&path, &default_config()).unwrap();
+        let collective = Collective::new("test", 384);
+        storage.save_collective(&collective).unwrap();
+
+        // The `Activity` layout before capabilities were added
+        #[derive(Serialize, Deserialize)]
+        struct LegacyActivity {
+            agent_id: String,
+            collective_id: CollectiveId,
+            current_task: Option<String>,
+            context_summary: Option<String>,
+            started_at: Timestamp,
+            last_heartbeat: Timestamp,
+        }
+        impl Record for LegacyActivity {}
+
+        let legacy = LegacyActivity {
+            agent_id: "agent-1".to_string(),
+            collective_id: collective.id,
+            current_task: Some("triage".to_string()),
+            context_summary: None,
+            started_at: Timestamp::from_millis(1000),
+            last_heartbeat: Timestamp::from_millis(2000),
+        };
+        let key = encode_activity_key(collective.id.as_bytes(), "agent-1");
+        let write_txn = storage.database().begin_write().unwrap();
+        {
+            let mut table = write_txn.open_table(ACTIVITIES_TABLE).unwrap();
+            let bytes = codec::encode(&legacy).unwrap();
+            table.insert(key.as_slice(), bytes.as_slice()).unwrap();
+        }
+        write_txn.commit().unwrap();
+
+        let activity = storage
+            .get_activity("agent-1", collective.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(activity.current_task.as_deref(), Some("triage"));
+        assert!(activity.capabilities.is_empty());
+        assert_eq!(activity.last_heartbeat, Timestamp::from_millis(2000));
+        assert_eq!(
+            storage
+                .list_activities_in_collective(collective.id)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
 }
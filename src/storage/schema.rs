@@ -22,27 +22,33 @@
 //! ┌─────────────────────────────────────────────────────────────┐
 //! │ COLLECTIVES_TABLE                                            │
 //! │   Key: &[u8; 16] (CollectiveId as UUID bytes)               │
-//! │   Value: &[u8] (bincode-serialized Collective)              │
+//! │   Value: &[u8] (codec-encoded Collective)                   │
 //! └─────────────────────────────────────────────────────────────┘
 //!
 //! ┌─────────────────────────────────────────────────────────────┐
 //! │ EXPERIENCES_TABLE                                            │
 //! │   Key: &[u8; 16] (ExperienceId as UUID bytes)               │
-//! │   Value: &[u8] (bincode-serialized Experience)              │
+//! │   Value: &[u8] (codec-encoded Experience)                   │
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
+//!
+//! Record values carry a one-byte codec header; see [`codec`](super::codec).
 
 use redb::{MultimapTableDefinition, TableDefinition};
 use serde::{Deserialize, Serialize};
 
 use crate::config::EmbeddingDimension;
+use crate::experience::Experience;
+use crate::insight::InsightType;
 use crate::types::Timestamp;
 
 /// Current schema version.
 ///
 /// Increment this when making breaking changes to the schema.
 /// Version 2 adds `entity_type` to `WatchEventRecord` for sync protocol support.
-pub const SCHEMA_VERSION: u32 = 2;
+/// Version 3 prefixes every record value with a codec header byte
+/// (see [`codec`](super::codec)).
+pub const SCHEMA_VERSION: u32 = 3;
 
 /// Maximum content size in bytes (100 KB).
 pub const MAX_CONTENT_SIZE: usize = 100 * 1024;
@@ -62,6 +68,13 @@ pub const MAX_FILE_PATH_LENGTH: usize = 500;
 /// Maximum length of a source agent identifier.
 pub const MAX_SOURCE_AGENT_LENGTH: usize = 256;
 
+/// Maximum length of a user or task identifier linked to an experience.
+pub const MAX_SUBJECT_ID_LENGTH: usize = 256;
+
+/// Maximum length of a model name, model version, or tool in
+/// [`ModelAttribution`](crate::ModelAttribution).
+pub const MAX_ATTRIBUTION_FIELD_LENGTH: usize = 255;
+
 /// Maximum relation metadata size in bytes (10 KB).
 pub const MAX_RELATION_METADATA_SIZE: usize = 10 * 1024;
 
@@ -83,6 +96,18 @@ pub const MAX_ACTIVITY_AGENT_ID_LENGTH: usize = 255;
 /// for a task name or brief context summary.
 pub const MAX_ACTIVITY_FIELD_SIZE: usize = 1024;
 
+/// Maximum number of capabilities an agent can advertise per activity.
+pub const MAX_ACTIVITY_CAPABILITIES: usize = 32;
+
+/// Maximum capability name length in bytes.
+///
+/// Capabilities are short routing labels like "rust", "code-review", or
+/// "deploy:staging", not descriptions.
+pub const MAX_CAPABILITY_LENGTH: usize = 64;
+
+/// Maximum lock name and lock owner length in bytes.
+pub const MAX_LOCK_FIELD_LENGTH: usize = 255;
+
 // ============================================================================
 // Table Definitions
 // ============================================================================
@@ -96,14 +121,67 @@ pub const METADATA_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("m
 /// Collectives table.
 ///
 /// Key: CollectiveId as 16-byte UUID
-/// Value: bincode-serialized Collective struct
+/// Value: codec-encoded Collective struct
 pub const COLLECTIVES_TABLE: TableDefinition<&[u8; 16], &[u8]> =
     TableDefinition::new("collectives");
 
+/// Index: Collectives by owner.
+///
+/// Key: owner_id string
+/// Value (multimap): CollectiveId as 16-byte UUID
+///
+/// Collectives without an owner have no entry. Backfilled from
+/// `COLLECTIVES_TABLE` the first time an older database is opened.
+pub const COLLECTIVES_BY_OWNER_TABLE: MultimapTableDefinition<&str, &[u8; 16]> =
+    MultimapTableDefinition::new("collectives_by_owner");
+
+/// Collective description and settings.
+///
+/// Key: CollectiveId as 16-byte UUID
+/// Value: codec-encoded `CollectiveDetails`
+///
+/// Collectives without a description or settings have no entry. Kept
+/// outside `COLLECTIVES_TABLE` so the persisted `Collective` layout is
+/// unchanged.
+pub const COLLECTIVE_DETAILS_TABLE: TableDefinition<&[u8; 16], &[u8]> =
+    TableDefinition::new("collective_details");
+
+/// Collective parent links.
+///
+/// Key: child CollectiveId as 16-byte UUID
+/// Value: parent CollectiveId as 16-byte UUID
+///
+/// Root collectives have no entry. Kept outside `COLLECTIVES_TABLE` so the
+/// persisted `Collective` layout is unchanged.
+pub const COLLECTIVE_PARENTS_TABLE: TableDefinition<&[u8; 16], &[u8; 16]> =
+    TableDefinition::new("collective_parents");
+
+/// Index: Collective children by parent.
+///
+/// Key: parent CollectiveId as 16-byte UUID
+/// Value (multimap): child CollectiveId as 16-byte UUID
+///
+/// Mirror of `COLLECTIVE_PARENTS_TABLE`, maintained in the same transaction.
+pub const COLLECTIVE_CHILDREN_TABLE: MultimapTableDefinition<&[u8; 16], &[u8; 16]> =
+    MultimapTableDefinition::new("collective_children");
+
+/// Vector index kind per collective.
+///
+/// Key: CollectiveId as 16-byte UUID
+/// Value: index kind tag (`INDEX_KIND_IVF`)
+///
+/// Collectives without an entry use HNSW. Kept outside `COLLECTIVES_TABLE`
+/// so the persisted `Collective` layout is unchanged.
+pub const COLLECTIVE_INDEX_KINDS_TABLE: TableDefinition<&[u8; 16], u8> =
+    TableDefinition::new("collective_index_kinds");
+
+/// `COLLECTIVE_INDEX_KINDS_TABLE` tag for [`VectorIndexKind::Ivf`](crate::VectorIndexKind::Ivf).
+pub const INDEX_KIND_IVF: u8 = 1;
+
 /// Experiences table.
 ///
 /// Key: ExperienceId as 16-byte UUID
-/// Value: bincode-serialized Experience struct (without embedding)
+/// Value: codec-encoded Experience struct (without embedding)
 pub const EXPERIENCES_TABLE: TableDefinition<&[u8; 16], &[u8]> =
     TableDefinition::new("experiences");
 
@@ -132,9 +210,184 @@ pub const EXPERIENCES_BY_TYPE_TABLE: MultimapTableDefinition<&[u8; 17], &[u8; 16
 ///
 /// Stored separately from experiences to keep the main table compact.
 /// Key: ExperienceId as 16-byte UUID
-/// Value: raw f32 bytes (dimension * 4 bytes)
+/// Value: the vector in the encoding named by `EMBEDDING_STORAGE_KEY`
+/// (raw little-endian f32 bytes, dimension * 4, unless quantized)
 pub const EMBEDDINGS_TABLE: TableDefinition<&[u8; 16], &[u8]> = TableDefinition::new("embeddings");
 
+/// Metadata key for the encoding of every value in `EMBEDDINGS_TABLE`.
+///
+/// Stored in `METADATA_TABLE` as one tag byte (`EMBEDDING_STORAGE_F32`,
+/// `EMBEDDING_STORAGE_F16`, or `EMBEDDING_STORAGE_INT8`). Databases
+/// without the key store raw f32.
+pub const EMBEDDING_STORAGE_KEY: &str = "embedding_storage";
+
+/// `EMBEDDING_STORAGE_KEY` tag for [`EmbeddingStorage::F32`](crate::EmbeddingStorage::F32).
+pub const EMBEDDING_STORAGE_F32: u8 = 0;
+
+/// `EMBEDDING_STORAGE_KEY` tag for [`EmbeddingStorage::F16`](crate::EmbeddingStorage::F16):
+/// little-endian IEEE 754 half floats, dimension * 2 bytes.
+pub const EMBEDDING_STORAGE_F16: u8 = 1;
+
+/// `EMBEDDING_STORAGE_KEY` tag for [`EmbeddingStorage::Int8`](crate::EmbeddingStorage::Int8):
+/// a little-endian f32 scale followed by one i8 per dimension.
+pub const EMBEDDING_STORAGE_INT8: u8 = 2;
+
+/// Metadata key for the [`ContentStorage`](crate::ContentStorage) mode the
+/// database was created with.
+///
+/// Stored in `METADATA_TABLE` as one tag byte (`CONTENT_STORAGE_INLINE` or
+/// `CONTENT_STORAGE_EXTERNAL`). Databases without the key take the mode
+/// of the first open that finds it missing.
+pub const CONTENT_STORAGE_KEY: &str = "content_storage";
+
+/// `CONTENT_STORAGE_KEY` tag for [`ContentStorage::Inline`](crate::ContentStorage::Inline).
+pub const CONTENT_STORAGE_INLINE: u8 = 0;
+
+/// `CONTENT_STORAGE_KEY` tag for [`ContentStorage::External`](crate::ContentStorage::External).
+pub const CONTENT_STORAGE_EXTERNAL: u8 = 1;
+
+/// Experience model attribution.
+///
+/// Kept beside `EXPERIENCES_TABLE` so experience records written before
+/// attribution existed decode unchanged. Experiences without attribution
+/// have no entry.
+///
+/// Key: ExperienceId as 16-byte UUID
+/// Value: codec-encoded `ModelAttribution`
+pub const EXPERIENCE_ATTRIBUTION_TABLE: TableDefinition<&[u8; 16], &[u8]> =
+    TableDefinition::new("experience_attribution");
+
+/// Index: Experiences by collective and day.
+///
+/// Coarse recency index for time-range reads: a range over one collective's
+/// day buckets touches only the days in question instead of every entry in
+/// `EXPERIENCES_BY_COLLECTIVE_TABLE`.
+///
+/// Key: (CollectiveId 16 bytes, UTC day number big-endian 4 bytes) = 20 bytes
+/// Value (multimap): (timestamp big-endian 8 bytes, ExperienceId 16 bytes) = 24 bytes
+///
+/// Keys and values both sort chronologically. Backfilled from
+/// `EXPERIENCES_BY_COLLECTIVE_TABLE` the first time an older database is opened.
+pub const EXPERIENCES_BY_DAY_TABLE: MultimapTableDefinition<&[u8; 20], &[u8; 24]> =
+    MultimapTableDefinition::new("experiences_by_day");
+
+/// Experience hot metadata — the fields filters and ranking read.
+///
+/// Key: ExperienceId as 16-byte UUID
+/// Value: fixed-size [`ExperienceMeta`] encoding ([`EXPERIENCE_META_SIZE`] bytes)
+///
+/// Kept in step with `EXPERIENCES_TABLE` so search candidates can be
+/// screened without deserializing full records. Backfilled the first time
+/// an older database is opened.
+///
+/// Authoritative for `importance`, `confidence`, and `archived`: updates
+/// that only touch those rewrite this entry and leave the record alone,
+/// and reads overlay it onto the decoded record.
+pub const EXPERIENCE_META_TABLE: TableDefinition<&[u8; 16], &[u8; EXPERIENCE_META_SIZE]> =
+    TableDefinition::new("experience_meta");
+
+/// Application count per experience.
+///
+/// Key: ExperienceId as 16-byte UUID
+/// Value: `applications` as `u32`
+///
+/// Authoritative for `applications`, so reinforcement writes four bytes
+/// instead of the whole record. Backfilled the first time an older
+/// database is opened.
+pub const EXPERIENCE_APPLICATIONS_TABLE: TableDefinition<&[u8; 16], u32> =
+    TableDefinition::new("experience_applications");
+
+/// Precomputed nearest neighbors per experience.
+///
+/// Key: ExperienceId as 16-byte UUID
+/// Value: codec-encoded `Vec<ExperienceNeighbor>`, most similar first
+///
+/// Written by `PulseDB::compute_neighbor_graph`; experiences recorded since
+/// the last run have no entry. A row is removed with its experience, but
+/// other rows may still name it until the graph is recomputed.
+pub const EXPERIENCE_NEIGHBORS_TABLE: TableDefinition<&[u8; 16], &[u8]> =
+    TableDefinition::new("experience_neighbors");
+
+/// Experience user links.
+///
+/// Key: ExperienceId as 16-byte UUID
+/// Value: user_id string
+///
+/// Reverse of `EXPERIENCES_BY_USER_TABLE`, used to hydrate
+/// `Experience::user_id` and to drop the index entry on delete.
+pub const EXPERIENCE_USERS_TABLE: TableDefinition<&[u8; 16], &str> =
+    TableDefinition::new("experience_users");
+
+/// Index: Experiences linked to a user.
+///
+/// Key: user_id string
+/// Value (multimap): ExperienceId as 16-byte UUID
+///
+/// UUID v7 values sort by time, so iteration yields experiences oldest first.
+pub const EXPERIENCES_BY_USER_TABLE: MultimapTableDefinition<&str, &[u8; 16]> =
+    MultimapTableDefinition::new("experiences_by_user");
+
+/// Experience expiry times.
+///
+/// Key: ExperienceId as 16-byte UUID
+/// Value: `expires_at` as Unix milliseconds
+///
+/// Only experiences recorded with a TTL have a row. Used to hydrate
+/// `Experience::expires_at` and to drop the index entry on delete.
+pub const EXPERIENCE_EXPIRY_TABLE: TableDefinition<&[u8; 16], i64> =
+    TableDefinition::new("experience_expiry");
+
+/// Index: Experiences by collective and expiry time.
+///
+/// Key: CollectiveId as 16-byte UUID
+/// Value (multimap): `[expires_at_be: 8 bytes][experience_id: 16 bytes]`
+///
+/// Values sort by expiry, so the expired experiences of a collective are
+/// a prefix of its entries.
+pub const EXPERIENCES_BY_EXPIRY_TABLE: MultimapTableDefinition<&[u8; 16], &[u8; 24]> =
+    MultimapTableDefinition::new("experiences_by_expiry");
+
+/// Index: Experiences recorded under a task.
+///
+/// Key: task_id string
+/// Value (multimap): ExperienceId as 16-byte UUID
+///
+/// Backfilled from `EXPERIENCES_TABLE` the first time an older database is opened.
+pub const EXPERIENCES_BY_TASK_TABLE: MultimapTableDefinition<&str, &[u8; 16]> =
+    MultimapTableDefinition::new("experiences_by_task");
+
+/// Index: Tasks an agent recorded experiences under.
+///
+/// Key: agent_id string
+/// Value (multimap): task_id bytes followed by the 16-byte ExperienceId
+///
+/// One entry per experience so deleting an experience removes exactly its
+/// own entry; readers dedup tasks.
+/// Backfilled together with `EXPERIENCES_BY_TASK_TABLE`.
+pub const TASKS_BY_AGENT_TABLE: MultimapTableDefinition<&str, &[u8]> =
+    MultimapTableDefinition::new("tasks_by_agent");
+
+/// Index: Experiences by collective and related file.
+///
+/// Key: `[collective_id: 16B][file path: NB]` (see [`encode_file_key`])
+/// Value (multimap): ExperienceId as 16-byte UUID
+///
+/// Keys sort by path within a collective, so a prefix range finds every
+/// file under a directory. Backfilled from `EXPERIENCES_TABLE` the first
+/// time an older database is opened.
+pub const EXPERIENCES_BY_FILE_TABLE: MultimapTableDefinition<&[u8], &[u8; 16]> =
+    MultimapTableDefinition::new("experiences_by_file");
+
+/// Summary insight attached to an episode.
+///
+/// Key: task_id string
+/// Value: InsightId as 16-byte UUID
+///
+/// Not cleaned up when the insight is deleted; readers treat a dangling
+/// entry as no summary.
+pub const EPISODE_SUMMARIES_TABLE: TableDefinition<&str, &[u8; 16]> =
+    TableDefinition::new("episode_summaries");
+
 // ============================================================================
 // Relation Tables (E3-S01)
 // ============================================================================
@@ -143,7 +396,7 @@ pub const EMBEDDINGS_TABLE: TableDefinition<&[u8; 16], &[u8]> = TableDefinition:
 ///
 /// Primary storage for experience relations.
 /// Key: RelationId as 16-byte UUID
-/// Value: bincode-serialized ExperienceRelation struct
+/// Value: codec-encoded ExperienceRelation struct
 pub const RELATIONS_TABLE: TableDefinition<&[u8; 16], &[u8]> = TableDefinition::new("relations");
 
 /// Index: Relations by source experience.
@@ -165,6 +418,27 @@ pub const RELATIONS_BY_SOURCE_TABLE: MultimapTableDefinition<&[u8; 16], &[u8; 16
 pub const RELATIONS_BY_TARGET_TABLE: MultimapTableDefinition<&[u8; 16], &[u8; 16]> =
     MultimapTableDefinition::new("relations_by_target");
 
+/// Index: Relations by collective and creation time.
+///
+/// Enables collective-scoped edge enumeration without walking every experience.
+/// Key: CollectiveId (of the source experience) as 16-byte UUID
+/// Value (multimap): (created_at big-endian 8 bytes, RelationId 16 bytes) = 24 bytes
+///
+/// Values sort lexicographically, so iteration yields relations oldest first.
+/// Backfilled from `RELATIONS_TABLE` the first time an older database is opened.
+pub const RELATIONS_BY_COLLECTIVE_TABLE: MultimapTableDefinition<&[u8; 16], &[u8; 24]> =
+    MultimapTableDefinition::new("relations_by_collective");
+
+/// Relations suggested for newly recorded experiences, awaiting confirmation.
+///
+/// Keyed by collective, then source, so a collective's queue is one range
+/// scan and a source's suggestions are a sub-range of it. Removed with the
+/// source experience; suggestions whose target is gone are skipped on read.
+/// Key: [collective_id: 16 bytes][source_id: 16 bytes][target_id: 16 bytes]
+/// Value: codec-encoded `RelationSuggestion`
+pub const RELATION_SUGGESTIONS_TABLE: TableDefinition<&[u8; 48], &[u8]> =
+    TableDefinition::new("relation_suggestions");
+
 // ============================================================================
 // Insight Tables (E3-S02)
 // ============================================================================
@@ -173,7 +447,7 @@ pub const RELATIONS_BY_TARGET_TABLE: MultimapTableDefinition<&[u8; 16], &[u8; 16
 ///
 /// Primary storage for derived insights.
 /// Key: InsightId as 16-byte UUID
-/// Value: bincode-serialized DerivedInsight struct (with inline embedding)
+/// Value: codec-encoded DerivedInsight struct (with inline embedding)
 pub const INSIGHTS_TABLE: TableDefinition<&[u8; 16], &[u8]> = TableDefinition::new("insights");
 
 /// Index: Insights by collective.
@@ -184,6 +458,90 @@ pub const INSIGHTS_TABLE: TableDefinition<&[u8; 16], &[u8]> = TableDefinition::n
 pub const INSIGHTS_BY_COLLECTIVE_TABLE: MultimapTableDefinition<&[u8; 16], &[u8; 16]> =
     MultimapTableDefinition::new("insights_by_collective");
 
+/// Index: Insights by collective and type.
+///
+/// Enables efficient queries like "all Pattern insights in collective X".
+/// Key: (CollectiveId bytes, insight type tag byte) = 17 bytes
+/// Value (multimap): InsightId as 16-byte UUID
+///
+/// See [`encode_insight_type_key`] for the tag assignment. Backfilled from
+/// `INSIGHTS_TABLE` the first time an older database is opened.
+pub const INSIGHTS_BY_TYPE_TABLE: MultimapTableDefinition<&[u8; 17], &[u8; 16]> =
+    MultimapTableDefinition::new("insights_by_type");
+
+/// Index: Insights by source experience.
+///
+/// Lets deleting an experience find the insights that cite it without
+/// scanning its collective.
+/// Key: ExperienceId as 16-byte UUID
+/// Value (multimap): InsightId as 16-byte UUID
+///
+/// Backfilled from `INSIGHTS_TABLE` the first time an older database is
+/// opened.
+pub const INSIGHTS_BY_SOURCE_TABLE: MultimapTableDefinition<&[u8; 16], &[u8; 16]> =
+    MultimapTableDefinition::new("insights_by_source");
+
+/// Degraded insights.
+///
+/// Marks insights that still cite a deleted source experience (see
+/// `InsightSourceCascade::MarkDegraded`). Kept out of `INSIGHTS_TABLE` so
+/// the bincode layout of `DerivedInsight` is unchanged.
+/// Key: InsightId as 16-byte UUID
+/// Value: degradation time as Unix milliseconds
+pub const DEGRADED_INSIGHTS_TABLE: TableDefinition<&[u8; 16], i64> =
+    TableDefinition::new("degraded_insights");
+
+// ============================================================================
+// Moderation Tables
+// ============================================================================
+
+/// Moderation policy per collective.
+///
+/// Collectives without an entry publish every experience immediately.
+/// Key: CollectiveId as 16-byte UUID
+/// Value: codec-encoded `ModerationPolicy`
+pub const MODERATION_POLICIES_TABLE: TableDefinition<&[u8; 16], &[u8]> =
+    TableDefinition::new("moderation_policies");
+
+/// Experiences waiting for review.
+///
+/// Keyed by collective first so a collective's queue is one range scan.
+/// Key: [collective_id: 16 bytes][experience_id: 16 bytes]
+/// Value: time queued as Unix milliseconds
+pub const PENDING_EXPERIENCES_TABLE: TableDefinition<&[u8; 32], i64> =
+    TableDefinition::new("pending_experiences");
+
+/// Content policy per collective.
+///
+/// Collectives without an entry accept any content that passes the
+/// built-in validation.
+/// Key: CollectiveId as 16-byte UUID
+/// Value: codec-encoded `ContentPolicy`
+pub const CONTENT_POLICIES_TABLE: TableDefinition<&[u8; 16], &[u8]> =
+    TableDefinition::new("content_policies");
+
+/// Quota per collective.
+///
+/// Collectives without an entry are unlimited.
+/// Key: CollectiveId as 16-byte UUID
+/// Value: codec-encoded `CollectiveQuota`
+pub const COLLECTIVE_QUOTAS_TABLE: TableDefinition<&[u8; 16], &[u8]> =
+    TableDefinition::new("collective_quotas");
+
+/// Text normalization per collective.
+///
+/// Collectives without an entry use [`Config::text_normalization`](crate::Config::text_normalization).
+/// Key: CollectiveId as 16-byte UUID
+/// Value: codec-encoded `TextNormalization`
+pub const COLLECTIVE_NORMALIZATION_TABLE: TableDefinition<&[u8; 16], &[u8]> =
+    TableDefinition::new("collective_normalization");
+
+/// Standing interests per collective.
+///
+/// Key: CollectiveId as 16-byte UUID
+/// Value: codec-encoded `Vec<Interest>`
+pub const INTERESTS_TABLE: TableDefinition<&[u8; 16], &[u8]> = TableDefinition::new("interests");
+
 // ============================================================================
 // Activity Tables (E3-S03)
 // ============================================================================
@@ -195,9 +553,84 @@ pub const INSIGHTS_BY_COLLECTIVE_TABLE: MultimapTableDefinition<&[u8; 16], &[u8;
 /// agent can have at most one active session per collective.
 ///
 /// Key: `[collective_id: 16B][agent_id_len: 2B BE][agent_id: NB]`
-/// Value: bincode-serialized Activity struct
+/// Value: codec-encoded Activity struct
 pub const ACTIVITIES_TABLE: TableDefinition<&[u8], &[u8]> = TableDefinition::new("activities");
 
+/// Activity capabilities.
+///
+/// Kept beside `ACTIVITIES_TABLE` so activity records written before
+/// capabilities existed decode unchanged.
+///
+/// Key: activity key (see [`encode_activity_key`])
+/// Value: codec-encoded `Vec<String>` of capability names
+pub const ACTIVITY_CAPABILITIES_TABLE: TableDefinition<&[u8], &[u8]> =
+    TableDefinition::new("activity_capabilities");
+
+/// Index: Agents by collective and capability.
+///
+/// Enables queries like "which agents in collective X can do `rust`".
+/// Key: `[collective_id: 16B][capability: NB]` (see [`encode_capability_key`])
+/// Value (multimap): agent_id
+pub const AGENTS_BY_CAPABILITY_TABLE: MultimapTableDefinition<&[u8], &str> =
+    MultimapTableDefinition::new("agents_by_capability");
+
+// ============================================================================
+// Bookmark Tables
+// ============================================================================
+
+/// Bookmarks table — per-agent reading lists.
+///
+/// Key: agent_id string
+/// Value (multimap): ExperienceId as 16-byte UUID
+///
+/// UUID v7 values sort by time, so iteration yields bookmarks in the order
+/// the experiences were recorded.
+pub const BOOKMARKS_TABLE: MultimapTableDefinition<&str, &[u8; 16]> =
+    MultimapTableDefinition::new("bookmarks");
+
+/// Index: Agents that bookmarked an experience.
+///
+/// Key: ExperienceId as 16-byte UUID
+/// Value (multimap): agent_id string
+///
+/// Reverse of `BOOKMARKS_TABLE`, used to drop bookmarks when an
+/// experience is deleted.
+pub const BOOKMARKED_BY_TABLE: MultimapTableDefinition<&[u8; 16], &str> =
+    MultimapTableDefinition::new("bookmarked_by");
+
+// ============================================================================
+// Lock Tables
+// ============================================================================
+
+/// Locks table — named leases scoped to a collective.
+///
+/// Key: `[collective_id: 16B][name: NB]` (see [`encode_lock_key`])
+/// Value: codec-encoded `Lease` struct
+///
+/// Released leases are removed; expired leases stay until the next
+/// acquisition overwrites them or the collective is deleted.
+pub const LOCKS_TABLE: TableDefinition<&[u8], &[u8]> = TableDefinition::new("locks");
+
+/// Metadata key for the last issued lock fencing token.
+///
+/// Stored in `METADATA_TABLE` as 8-byte big-endian `u64`. Incremented in
+/// the same write transaction that grants a lease, so tokens are strictly
+/// increasing across all locks for the lifetime of the database.
+pub const LOCK_FENCING_TOKEN_KEY: &str = "lock_fencing_token";
+
+// ============================================================================
+// Knowledge Gap Tables
+// ============================================================================
+
+/// Searches that scored below the knowledge gap threshold.
+///
+/// Keyed by collective first so a collective's gaps are one range scan, in
+/// the order they were recorded.
+/// Key: [collective_id: 16 bytes][gap_id: 16-byte UUID v7]
+/// Value: codec-encoded `KnowledgeGapRecord`
+pub const KNOWLEDGE_GAPS_TABLE: TableDefinition<&[u8; 32], &[u8]> =
+    TableDefinition::new("knowledge_gaps");
+
 // ============================================================================
 // Watch Events Tables (E4-S02)
 // ============================================================================
@@ -218,8 +651,8 @@ pub const INSTANCE_ID_KEY: &str = "instance_id";
 ///
 /// Each entry records the last WAL sequence number successfully synced
 /// with a specific peer instance. Key is the peer's InstanceId (16 bytes),
-/// value is bincode-serialized `SyncCursor`.
-#[cfg(feature = "sync")]
+/// value is codec-encoded `SyncCursor`. Defined without the `sync` feature
+/// so snapshots carry cursors written by sync-enabled builds.
 pub const SYNC_CURSORS_TABLE: TableDefinition<&[u8; 16], &[u8]> =
     TableDefinition::new("sync_cursors");
 
@@ -241,7 +674,7 @@ pub const WAL_SEQUENCE_KEY: &str = "wal_sequence";
 /// Reader processes poll this table to discover changes made by the writer.
 ///
 /// Key: u64 sequence number as 8-byte big-endian (lexicographic = numeric order)
-/// Value: bincode-serialized `WatchEventRecord`
+/// Value: codec-encoded `WatchEventRecord`
 ///
 /// The table grows unboundedly; a future compaction feature will allow
 /// trimming old entries.
@@ -384,6 +817,7 @@ impl EntityTypeTag {
 /// - `TechInsight` — Technical knowledge about a technology
 /// - `Fact` — Verified factual statement with source
 /// - `Generic` — Catch-all for uncategorized experiences
+/// - `OpenQuestion` — Question the collective can't answer yet
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum ExperienceTypeTag {
@@ -405,6 +839,8 @@ pub enum ExperienceTypeTag {
     Fact = 7,
     /// Catch-all for uncategorized experiences.
     Generic = 8,
+    /// Question the collective can't answer yet.
+    OpenQuestion = 9,
 }
 
 impl ExperienceTypeTag {
@@ -422,6 +858,7 @@ impl ExperienceTypeTag {
             6 => Some(Self::TechInsight),
             7 => Some(Self::Fact),
             8 => Some(Self::Generic),
+            9 => Some(Self::OpenQuestion),
             _ => None,
         }
     }
@@ -438,6 +875,7 @@ impl ExperienceTypeTag {
             Self::TechInsight,
             Self::Fact,
             Self::Generic,
+            Self::OpenQuestion,
         ]
     }
 }
@@ -551,6 +989,90 @@ pub fn encode_type_index_key(collective_id: &[u8; 16], type_tag: ExperienceTypeT
     key
 }
 
+/// Milliseconds per day bucket in `EXPERIENCES_BY_DAY_TABLE`.
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Returns the UTC day bucket of a timestamp.
+///
+/// Timestamps before the Unix epoch share bucket 0.
+#[inline]
+pub fn day_bucket(timestamp: Timestamp) -> u32 {
+    timestamp
+        .as_millis()
+        .div_euclid(MILLIS_PER_DAY)
+        .clamp(0, u32::MAX as i64) as u32
+}
+
+/// Encodes a (CollectiveId, day) key for the day bucket index.
+///
+/// Format: [collective_id: 16 bytes][day_be: 4 bytes] = 20 bytes
+#[inline]
+pub fn encode_day_key(collective_id: &[u8; 16], day: u32) -> [u8; 20] {
+    let mut key = [0u8; 20];
+    key[..16].copy_from_slice(collective_id);
+    key[16..].copy_from_slice(&day.to_be_bytes());
+    key
+}
+
+/// Encodes a (CollectiveId, ExperienceId) key for the pending experience queue.
+///
+/// Format: [collective_id: 16 bytes][experience_id: 16 bytes] = 32 bytes
+#[inline]
+pub fn encode_pending_key(collective_id: &[u8; 16], experience_id: &[u8; 16]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[..16].copy_from_slice(collective_id);
+    key[16..].copy_from_slice(experience_id);
+    key
+}
+
+/// Encodes a (CollectiveId, source, target) key for the relation
+/// suggestions table.
+///
+/// Format: [collective_id: 16 bytes][source_id: 16 bytes][target_id: 16 bytes] = 48 bytes
+#[inline]
+pub fn encode_suggestion_key(
+    collective_id: &[u8; 16],
+    source_id: &[u8; 16],
+    target_id: &[u8; 16],
+) -> [u8; 48] {
+    let mut key = [0u8; 48];
+    key[..16].copy_from_slice(collective_id);
+    key[16..32].copy_from_slice(source_id);
+    key[32..].copy_from_slice(target_id);
+    key
+}
+
+/// Encodes a (CollectiveId, gap ID) key for the knowledge gaps table.
+///
+/// Format: [collective_id: 16 bytes][gap_id: 16 bytes] = 32 bytes
+#[inline]
+pub fn encode_gap_key(collective_id: &[u8; 16], gap_id: &[u8; 16]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[..16].copy_from_slice(collective_id);
+    key[16..].copy_from_slice(gap_id);
+    key
+}
+
+/// Encodes a (CollectiveId, InsightType) key for the insight type index.
+///
+/// Format: [collective_id: 16 bytes][type_tag: 1 byte] = 17 bytes
+///
+/// Tags are fixed on disk: Pattern = 0, Synthesis = 1, Abstraction = 2,
+/// Correlation = 3.
+#[inline]
+pub fn encode_insight_type_key(collective_id: &[u8; 16], insight_type: InsightType) -> [u8; 17] {
+    let tag = match insight_type {
+        InsightType::Pattern => 0,
+        InsightType::Synthesis => 1,
+        InsightType::Abstraction => 2,
+        InsightType::Correlation => 3,
+    };
+    let mut key = [0u8; 17];
+    key[..16].copy_from_slice(collective_id);
+    key[16] = tag;
+    key
+}
+
 /// Decodes the ExperienceTypeTag from a type index key.
 ///
 /// Returns `None` if the tag byte doesn't correspond to a known variant.
@@ -567,6 +1089,97 @@ pub fn decode_collective_from_type_key(key: &[u8; 17]) -> [u8; 16] {
     id
 }
 
+// ============================================================================
+// Experience Metadata Sidecar
+// ============================================================================
+
+/// Size of an encoded [`ExperienceMeta`].
+pub const EXPERIENCE_META_SIZE: usize = 26;
+
+/// Projection of an experience's frequently filtered fields.
+///
+/// Format: `[type_tag: 1][flags: 1][importance: 4 LE][confidence: 4 LE]`
+/// `[timestamp: 8 BE][agent_hash: 8 BE]` = 26 bytes. Flag bit 0 is
+/// `archived`.
+///
+/// The agent is stored as a hash so the record stays fixed-size; a match
+/// must be confirmed against the full record.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExperienceMeta {
+    /// Experience type discriminant.
+    pub type_tag: ExperienceTypeTag,
+    /// Whether the experience is archived.
+    pub archived: bool,
+    /// Importance score.
+    pub importance: f32,
+    /// Confidence score.
+    pub confidence: f32,
+    /// Creation time.
+    pub timestamp: Timestamp,
+    /// [`agent_hash`] of the source agent.
+    pub agent_hash: u64,
+}
+
+impl ExperienceMeta {
+    /// Projects an experience's hot fields.
+    pub fn of(experience: &Experience) -> Self {
+        Self {
+            type_tag: experience.experience_type.type_tag(),
+            archived: experience.archived,
+            importance: experience.importance,
+            confidence: experience.confidence,
+            timestamp: experience.timestamp,
+            agent_hash: agent_hash(experience.source_agent.as_str()),
+        }
+    }
+
+    /// Encodes to the fixed-size on-disk format.
+    pub fn to_bytes(&self) -> [u8; EXPERIENCE_META_SIZE] {
+        let mut bytes = [0u8; EXPERIENCE_META_SIZE];
+        bytes[0] = self.type_tag as u8;
+        bytes[1] = u8::from(self.archived);
+        bytes[2..6].copy_from_slice(&self.importance.to_le_bytes());
+        bytes[6..10].copy_from_slice(&self.confidence.to_le_bytes());
+        bytes[10..18].copy_from_slice(&self.timestamp.to_be_bytes());
+        bytes[18..26].copy_from_slice(&self.agent_hash.to_be_bytes());
+        bytes
+    }
+
+    /// Copies the fields this sidecar is authoritative for onto a record.
+    pub fn apply_to(&self, experience: &mut Experience) {
+        experience.importance = self.importance;
+        experience.confidence = self.confidence;
+        experience.archived = self.archived;
+    }
+
+    /// Decodes the on-disk format.
+    ///
+    /// Returns `None` if the type tag is unknown.
+    pub fn from_bytes(bytes: &[u8; EXPERIENCE_META_SIZE]) -> Option<Self> {
+        let f32_at =
+            |i: usize| f32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+        let mut millis = [0u8; 8];
+        millis.copy_from_slice(&bytes[10..18]);
+        let mut hash = [0u8; 8];
+        hash.copy_from_slice(&bytes[18..26]);
+        Some(Self {
+            type_tag: ExperienceTypeTag::from_u8(bytes[0])?,
+            archived: bytes[1] & 1 != 0,
+            importance: f32_at(2),
+            confidence: f32_at(6),
+            timestamp: Timestamp::from_millis(i64::from_be_bytes(millis)),
+            agent_hash: u64::from_be_bytes(hash),
+        })
+    }
+}
+
+/// Stable 64-bit FNV-1a hash of an agent ID, as stored in [`ExperienceMeta`].
+pub fn agent_hash(agent_id: &str) -> u64 {
+    agent_id.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
 // ============================================================================
 // Activity Key Encoding (E3-S03)
 // ============================================================================
@@ -588,6 +1201,39 @@ pub fn encode_activity_key(collective_id: &[u8; 16], agent_id: &str) -> Vec<u8>
     key
 }
 
+/// Encodes a `(collective_id, capability)` key for the capability index.
+///
+/// Format: `[collective_id: 16 bytes][capability: N bytes]`
+#[inline]
+pub fn encode_capability_key(collective_id: &[u8; 16], capability: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(16 + capability.len());
+    key.extend_from_slice(collective_id);
+    key.extend_from_slice(capability.as_bytes());
+    key
+}
+
+/// Encodes a `(collective_id, path)` key for the related-file index.
+///
+/// Format: `[collective_id: 16 bytes][path: N bytes]`
+#[inline]
+pub fn encode_file_key(collective_id: &[u8; 16], path: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(16 + path.len());
+    key.extend_from_slice(collective_id);
+    key.extend_from_slice(path.as_bytes());
+    key
+}
+
+/// Encodes a `(collective_id, name)` key for the locks table.
+///
+/// Format: `[collective_id: 16 bytes][name: N bytes]`
+#[inline]
+pub fn encode_lock_key(collective_id: &[u8; 16], name: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(16 + name.len());
+    key.extend_from_slice(collective_id);
+    key.extend_from_slice(name.as_bytes());
+    key
+}
+
 /// Extracts the 16-byte CollectiveId from an activity key.
 ///
 /// # Panics
@@ -621,7 +1267,7 @@ mod tests {
 
     #[test]
     fn test_schema_version() {
-        assert_eq!(SCHEMA_VERSION, 2);
+        assert_eq!(SCHEMA_VERSION, 3);
     }
 
     #[test]
@@ -702,16 +1348,17 @@ mod tests {
     #[test]
     fn test_experience_type_tag_from_u8_invalid() {
         assert!(ExperienceTypeTag::from_u8(255).is_none());
-        assert!(ExperienceTypeTag::from_u8(9).is_none());
+        assert!(ExperienceTypeTag::from_u8(10).is_none());
     }
 
     #[test]
     fn test_experience_type_tag_all_variants() {
         let all = ExperienceTypeTag::all();
-        assert_eq!(all.len(), 9);
+        assert_eq!(all.len(), 10);
         assert_eq!(all[0], ExperienceTypeTag::Difficulty);
         assert_eq!(all[5], ExperienceTypeTag::ArchitecturalDecision);
         assert_eq!(all[8], ExperienceTypeTag::Generic);
+        assert_eq!(all[9], ExperienceTypeTag::OpenQuestion);
     }
 
     #[test]
@@ -723,6 +1370,47 @@ mod tests {
         }
     }
 
+    // ====================================================================
+    // Day bucket key encoding tests
+    // ====================================================================
+
+    #[test]
+    fn test_day_bucket_boundaries() {
+        assert_eq!(day_bucket(Timestamp::from_millis(0)), 0);
+        assert_eq!(day_bucket(Timestamp::from_millis(MILLIS_PER_DAY - 1)), 0);
+        assert_eq!(day_bucket(Timestamp::from_millis(MILLIS_PER_DAY)), 1);
+        assert_eq!(day_bucket(Timestamp::from_millis(-1)), 0);
+    }
+
+    #[test]
+    fn test_experience_meta_roundtrip() {
+        let meta = ExperienceMeta {
+            type_tag: ExperienceTypeTag::Solution,
+            archived: true,
+            importance: 0.75,
+            confidence: 0.5,
+            timestamp: Timestamp::from_millis(1_700_000_000_000),
+            agent_hash: agent_hash("agent-1"),
+        };
+        let bytes = meta.to_bytes();
+        assert_eq!(ExperienceMeta::from_bytes(&bytes), Some(meta));
+
+        let mut bad = bytes;
+        bad[0] = 0xff;
+        assert_eq!(ExperienceMeta::from_bytes(&bad), None);
+
+        assert_ne!(agent_hash("agent-1"), agent_hash("agent-2"));
+    }
+
+    #[test]
+    fn test_day_keys_sort_chronologically_within_collective() {
+        let collective_id = [3u8; 16];
+        let early = encode_day_key(&collective_id, 255);
+        let late = encode_day_key(&collective_id, 256);
+        assert!(early < late);
+        assert_eq!(&early[..16], &collective_id);
+    }
+
     // ====================================================================
     // Type index key encoding tests
     // ====================================================================
@@ -752,6 +1440,25 @@ mod tests {
         assert_ne!(key_obs[16], key_les[16]);
     }
 
+    #[test]
+    fn test_insight_type_key_tags_are_distinct() {
+        let collective_id = [3u8; 16];
+        let keys: Vec<[u8; 17]> = [
+            InsightType::Pattern,
+            InsightType::Synthesis,
+            InsightType::Abstraction,
+            InsightType::Correlation,
+        ]
+        .into_iter()
+        .map(|t| encode_insight_type_key(&collective_id, t))
+        .collect();
+
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(&key[..16], &collective_id);
+            assert_eq!(key[16], i as u8);
+        }
+    }
+
     #[test]
     fn test_type_index_key_different_collectives_produce_different_keys() {
         let id_a = [1u8; 16];
@@ -0,0 +1,159 @@
+//! Timed admission to write transactions.
+//!
+//! redb's `begin_write()` blocks until the write in progress commits, with
+//! no way to give up. [`WriteGate`] queues writers in front of it instead,
+//! so waiting for the write lock can honor
+//! [`Config::write_retry`](crate::Config::write_retry) and
+//! [`TimeoutConfig::write_transaction`](crate::TimeoutConfig::write_transaction).
+//! A [`GatedWrite`] holds the gate for the life of its transaction.
+
+use std::ops::Deref;
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use ::redb::{CommitError, WriteTransaction};
+
+use crate::deadline::Deadline;
+use crate::error::{PulseDBError, Result};
+
+/// One-writer-at-a-time gate with timed waits.
+#[derive(Debug, Default)]
+pub(crate) struct WriteGate {
+    held: Mutex<bool>,
+    released: Condvar,
+}
+
+impl WriteGate {
+    /// Waits for the gate for at most `patience`, or until `deadline` if
+    /// that comes first.
+    ///
+    /// Returns `Ok(None)` if `patience` ran out with the gate still held,
+    /// and a timeout error if `deadline` did.
+    pub(crate) fn acquire_within(
+        &self,
+        deadline: &Deadline,
+        patience: Duration,
+    ) -> Result<Option<WriteGateGuard<'_>>> {
+        let give_up = Instant::now() + patience;
+        let mut held = self.held.lock().map_err(poisoned)?;
+        while *held {
+            let left = give_up.saturating_duration_since(Instant::now());
+            let wait = match deadline.remaining() {
+                Some(remaining) if remaining.is_zero() => return Err(deadline.expired()),
+                Some(remaining) => remaining.min(left),
+                None => left,
+            };
+            if left.is_zero() {
+                return Ok(None);
+            }
+            held = self.released.wait_timeout(held, wait).map_err(poisoned)?.0;
+        }
+        *held = true;
+        Ok(Some(WriteGateGuard(self)))
+    }
+}
+
+fn poisoned<T>(_: PoisonError<T>) -> PulseDBError {
+    PulseDBError::internal("Write gate lock poisoned")
+}
+
+/// Holds a [`WriteGate`]; dropping it lets the next writer in.
+#[derive(Debug)]
+pub(crate) struct WriteGateGuard<'a>(&'a WriteGate);
+
+impl Drop for WriteGateGuard<'_> {
+    fn drop(&mut self) {
+        // A poisoned flag is still a flag
+        let mut held = self.0.held.lock().unwrap_or_else(|e| e.into_inner());
+        *held = false;
+        drop(held);
+        self.0.released.notify_one();
+    }
+}
+
+/// A write transaction that holds the write gate until it commits or is
+/// dropped (which aborts it).
+pub(crate) struct GatedWrite<'a> {
+    // Declared first so the transaction ends before the gate opens
+    txn: WriteTransaction,
+    _gate: WriteGateGuard<'a>,
+}
+
+impl<'a> GatedWrite<'a> {
+    pub(crate) fn new(txn: WriteTransaction, gate: WriteGateGuard<'a>) -> Self {
+        Self { txn, _gate: gate }
+    }
+
+    /// Commits the transaction, then releases the gate.
+    pub(crate) fn commit(self) -> std::result::Result<(), CommitError> {
+        self.txn.commit()
+    }
+}
+
+impl Deref for GatedWrite<'_> {
+    type Target = WriteTransaction;
+
+    fn deref(&self) -> &WriteTransaction {
+        &self.txn
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TimedOperation;
+    use std::time::Duration;
+
+    const PATIENT: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_gate_wait_times_out() {
+        let gate = WriteGate::default();
+        let unbounded = Deadline::unbounded(TimedOperation::WriteTransaction);
+        let guard = gate.acquire_within(&unbounded, PATIENT).unwrap().unwrap();
+
+        let short = Deadline::start(
+            TimedOperation::WriteTransaction,
+            Some(Duration::from_millis(20)),
+        );
+        let err = gate.acquire_within(&short, PATIENT).unwrap_err();
+        assert!(err.is_timeout());
+
+        // Released to a waiter
+        std::thread::scope(|scope| {
+            let waiter =
+                scope.spawn(|| gate.acquire_within(&unbounded, PATIENT).unwrap().map(drop));
+            std::thread::sleep(Duration::from_millis(10));
+            drop(guard);
+            waiter.join().unwrap().unwrap();
+        });
+    }
+
+    #[test]
+    fn test_gate_acquire_within_gives_up_after_patience() {
+        let gate = WriteGate::default();
+        let unbounded = Deadline::unbounded(TimedOperation::WriteTransaction);
+        let guard = gate.acquire_within(&unbounded, PATIENT).unwrap().unwrap();
+
+        let waited = gate
+            .acquire_within(&unbounded, Duration::from_millis(10))
+            .unwrap();
+        assert!(waited.is_none());
+
+        // The deadline wins when it is shorter
+        let short = Deadline::start(
+            TimedOperation::WriteTransaction,
+            Some(Duration::from_millis(10)),
+        );
+        let err = gate
+            .acquire_within(&short, Duration::from_secs(5))
+            .unwrap_err();
+        assert!(err.is_timeout());
+
+        drop(guard);
+        assert!(gate
+            .acquire_within(&unbounded, Duration::ZERO)
+            .unwrap()
+            .is_some());
+    }
+}
@@ -219,7 +219,8 @@ impl LocalChangePusher {
             }
 
             // Insight events
-            (EntityTypeTag::Insight, WatchEventTypeTag::Created) => {
+            // Updated insights are re-sent in full; applying is an upsert
+            (EntityTypeTag::Insight, WatchEventTypeTag::Created | WatchEventTypeTag::Updated) => {
                 let id = InsightId::from_bytes(record.entity_id);
                 match self.db.get_insight(id).map_err(map_err)? {
                     Some(insight) => Ok(Some(SyncPayload::InsightCreated(insight))),
@@ -235,13 +236,18 @@ impl LocalChangePusher {
             }
 
             // Collective events
-            (EntityTypeTag::Collective, WatchEventTypeTag::Created) => {
+            (
+                EntityTypeTag::Collective,
+                WatchEventTypeTag::Created | WatchEventTypeTag::Updated,
+            ) => {
                 let id = CollectiveId::from_bytes(record.entity_id);
                 match self.db.get_collective(id).map_err(map_err)? {
                     Some(collective) => Ok(Some(SyncPayload::CollectiveCreated(collective))),
                     None => Ok(None),
                 }
             }
+            // Collective deletion is local to each instance
+            (EntityTypeTag::Collective, WatchEventTypeTag::Deleted) => Ok(None),
 
             // Unexpected combinations (e.g., Collective + Deleted) — skip
             (entity_type, event_type) => {
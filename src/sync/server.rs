@@ -270,7 +270,7 @@ fn build_change_from_record(
             let id = RelationId::from_bytes(record.entity_id);
             Some(SyncPayload::RelationDeleted { id, timestamp })
         }
-        (EntityTypeTag::Insight, WatchEventTypeTag::Created) => {
+        (EntityTypeTag::Insight, WatchEventTypeTag::Created | WatchEventTypeTag::Updated) => {
             let id = InsightId::from_bytes(record.entity_id);
             db.get_insight(id)
                 .map_err(map_err)?
@@ -280,7 +280,7 @@ fn build_change_from_record(
             let id = InsightId::from_bytes(record.entity_id);
             Some(SyncPayload::InsightDeleted { id, timestamp })
         }
-        (EntityTypeTag::Collective, WatchEventTypeTag::Created) => {
+        (EntityTypeTag::Collective, WatchEventTypeTag::Created | WatchEventTypeTag::Updated) => {
             let id = CollectiveId::from_bytes(record.entity_id);
             db.get_collective(id)
                 .map_err(map_err)?
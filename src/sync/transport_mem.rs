@@ -166,6 +166,8 @@ mod tests {
                 id: collective_id,
                 name: format!("test-{}", seq),
                 owner_id: None,
+                description: None,
+                settings: Default::default(),
                 embedding_dimension: 384,
                 created_at: Timestamp::now(),
                 updated_at: Timestamp::now(),
@@ -184,6 +184,9 @@ impl From<SerializableExperienceUpdate> for crate::experience::ExperienceUpdate
 ///
 /// Uses full payloads (not deltas) so the receiver has everything needed
 /// including embeddings for HNSW insertion.
+// Payloads are built once per change and moved straight into the wire
+// format, so boxing the record variants would only add an allocation.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum SyncPayload {
     /// A new experience was created.
@@ -0,0 +1,514 @@
+//! Realistic synthetic collectives for demos, benchmarks, and bug reports.
+//!
+//! Requires the `testdata` feature flag. [`generate()`] turns a
+//! [`TestDataConfig`] into [`NewExperience`]s that look like an agent
+//! team's memory: every experience belongs to a [`Topic`], carries tags
+//! from the topic's vocabulary in `domain`, follows a weighted mix of
+//! experience types, and has an embedding near its topic's center, pulled
+//! toward its tags — so similarity search, filtering, and clustering
+//! behave as they would on real data. [`populate()`] writes one straight
+//! into a database.
+//!
+//! The same config and seed always produce the same contents, embeddings,
+//! and (content-derived) IDs, so a bug report can name a config instead of
+//! attaching a private database:
+//!
+//! ```rust
+//! # fn main() -> pulsedb::Result<()> {
+//! # let dir = tempfile::tempdir().unwrap();
+//! use pulsedb::testdata::{self, TestDataConfig};
+//! use pulsedb::{Config, PulseDB};
+//!
+//! let db = PulseDB::open(dir.path().join("repro.db"), Config::default())?;
+//! let config = TestDataConfig {
+//!     experiences: 50,
+//!     seed: 7,
+//!     ..Default::default()
+//! };
+//! let collective_id = testdata::populate(&db, "repro", &config)?;
+//! assert_eq!(db.get_collective_stats(collective_id)?.experience_count, 50);
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::bench::{normalize, SplitMix64};
+use crate::db::PulseDB;
+use crate::error::{Result, ValidationError};
+use crate::experience::{ExperienceType, NewExperience, Severity};
+use crate::storage::schema::ExperienceTypeTag;
+use crate::types::{AgentId, CollectiveId, ExperienceId};
+
+/// Weight of the tag vectors relative to the topic center.
+const TAG_PULL: f32 = 0.5;
+
+/// A subject area and the tags experiences about it use.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Topic {
+    /// Topic name; also the first `domain` entry of its experiences.
+    pub name: String,
+
+    /// Tag vocabulary (at least one).
+    pub tags: Vec<String>,
+}
+
+impl Topic {
+    /// Creates a topic from a name and its tags.
+    pub fn new(name: impl Into<String>, tags: &[&str]) -> Self {
+        Self {
+            name: name.into(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    /// A software team's usual topics.
+    pub fn defaults() -> Vec<Topic> {
+        vec![
+            Topic::new(
+                "database",
+                &["postgres", "migrations", "indexes", "pooling"],
+            ),
+            Topic::new("testing", &["flaky-tests", "fixtures", "mocks", "coverage"]),
+            Topic::new("deployment", &["docker", "kubernetes", "rollback", "ci"]),
+            Topic::new("security", &["auth", "tokens", "secrets", "tls"]),
+            Topic::new("frontend", &["react", "css", "bundling", "accessibility"]),
+            Topic::new(
+                "performance",
+                &["caching", "profiling", "latency", "memory"],
+            ),
+        ]
+    }
+}
+
+/// Shape of a synthetic collective.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TestDataConfig {
+    /// Number of experiences to generate (at least 1).
+    pub experiences: usize,
+
+    /// Embedding dimension; must match the database's.
+    pub dimension: usize,
+
+    /// Subject areas; each experience picks one uniformly (at least one).
+    pub topics: Vec<Topic>,
+
+    /// Relative weights of experience types. Types left out are never
+    /// generated; the total must be positive.
+    pub type_mix: Vec<(ExperienceTypeTag, u32)>,
+
+    /// Most tags per experience, drawn from its topic (at least 1).
+    pub max_tags: usize,
+
+    /// Number of distinct source agents (at least 1).
+    pub agents: usize,
+
+    /// Per-component noise around each experience's topic and tags;
+    /// smaller values make topics tighter.
+    pub spread: f32,
+
+    /// Seed for every random choice.
+    pub seed: u64,
+}
+
+impl Default for TestDataConfig {
+    fn default() -> Self {
+        Self {
+            experiences: 200,
+            dimension: 384,
+            topics: Topic::defaults(),
+            type_mix: vec![
+                (ExperienceTypeTag::Difficulty, 4),
+                (ExperienceTypeTag::Solution, 4),
+                (ExperienceTypeTag::ErrorPattern, 2),
+                (ExperienceTypeTag::SuccessPattern, 2),
+                (ExperienceTypeTag::TechInsight, 3),
+                (ExperienceTypeTag::ArchitecturalDecision, 1),
+                (ExperienceTypeTag::UserPreference, 1),
+                (ExperienceTypeTag::Fact, 2),
+                (ExperienceTypeTag::Generic, 1),
+                (ExperienceTypeTag::OpenQuestion, 1),
+            ],
+            max_tags: 2,
+            agents: 4,
+            spread: 0.05,
+            seed: 42,
+        }
+    }
+}
+
+impl TestDataConfig {
+    fn validate(&self) -> Result<()> {
+        if self.experiences == 0 {
+            return Err(ValidationError::invalid_field("experiences", "must be at least 1").into());
+        }
+        if self.dimension == 0 {
+            return Err(ValidationError::invalid_field("dimension", "must be at least 1").into());
+        }
+        if self.topics.is_empty() || self.topics.iter().any(|t| t.tags.is_empty()) {
+            return Err(ValidationError::invalid_field(
+                "topics",
+                "must be non-empty, each with at least one tag",
+            )
+            .into());
+        }
+        if self
+            .type_mix
+            .iter()
+            .map(|(_, w)| u64::from(*w))
+            .sum::<u64>()
+            == 0
+        {
+            return Err(ValidationError::invalid_field(
+                "type_mix",
+                "total weight must be positive",
+            )
+            .into());
+        }
+        if self.max_tags == 0 {
+            return Err(ValidationError::invalid_field("max_tags", "must be at least 1").into());
+        }
+        if self.agents == 0 {
+            return Err(ValidationError::invalid_field("agents", "must be at least 1").into());
+        }
+        if !self.spread.is_finite() || self.spread < 0.0 {
+            return Err(ValidationError::invalid_field(
+                "spread",
+                "must be a finite non-negative number",
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Generates the experiences of a synthetic collective.
+///
+/// Experiences are returned in recording order, each with an ID derived
+/// from `collective_id` and its content. Solutions point `problem_ref` at
+/// the latest earlier difficulty in their topic, when there is one.
+///
+/// # Errors
+///
+/// - [`ValidationError::InvalidField`] if the config is out of range
+pub fn generate(
+    config: &TestDataConfig,
+    collective_id: CollectiveId,
+) -> Result<Vec<NewExperience>> {
+    config.validate()?;
+    let mut rng = SplitMix64::new(config.seed);
+
+    let random_unit = |rng: &mut SplitMix64| {
+        let mut v: Vec<f32> = (0..config.dimension)
+            .map(|_| rng.next_f32() * 2.0 - 1.0)
+            .collect();
+        normalize(&mut v);
+        v
+    };
+    let centers: Vec<Vec<f32>> = config
+        .topics
+        .iter()
+        .map(|_| random_unit(&mut rng))
+        .collect();
+    let tag_vectors: Vec<Vec<Vec<f32>>> = config
+        .topics
+        .iter()
+        .map(|topic| topic.tags.iter().map(|_| random_unit(&mut rng)).collect())
+        .collect();
+    let total_weight: u64 = config.type_mix.iter().map(|(_, w)| u64::from(*w)).sum();
+
+    let mut last_difficulty: Vec<Option<ExperienceId>> = vec![None; config.topics.len()];
+    let mut experiences = Vec::with_capacity(config.experiences);
+    for i in 0..config.experiences {
+        let t = rng.below(config.topics.len());
+        let topic = &config.topics[t];
+
+        // Distinct tags, in vocabulary order
+        let wanted = 1 + rng.below(config.max_tags.min(topic.tags.len()));
+        let mut picked: Vec<usize> = Vec::with_capacity(wanted);
+        while picked.len() < wanted {
+            let tag = rng.below(topic.tags.len());
+            if !picked.contains(&tag) {
+                picked.push(tag);
+            }
+        }
+        picked.sort_unstable();
+
+        let mut embedding = centers[t].clone();
+        for &tag in &picked {
+            for (x, v) in embedding.iter_mut().zip(&tag_vectors[t][tag]) {
+                *x += v * TAG_PULL / picked.len() as f32;
+            }
+        }
+        for x in &mut embedding {
+            *x += rng.next_gaussian() * config.spread;
+        }
+        normalize(&mut embedding);
+
+        let mut roll = rng.next_u64() % total_weight;
+        let tag_type = config
+            .type_mix
+            .iter()
+            .find(|(_, w)| {
+                let hit = roll < u64::from(*w);
+                roll = roll.saturating_sub(u64::from(*w));
+                hit
+            })
+            .map(|(tag_type, _)| *tag_type)
+            .unwrap_or(ExperienceTypeTag::Generic);
+
+        let tag = &topic.tags[picked[0]];
+        let (summary, experience_type) =
+            synthetic_type(tag_type, &topic.name, tag, last_difficulty[t], &mut rng);
+        let content = format!("{} (case {})", summary, i + 1);
+        let id = ExperienceId::derive(collective_id, &content);
+        if tag_type == ExperienceTypeTag::Difficulty {
+            last_difficulty[t] = Some(id);
+        }
+
+        let mut domain = vec![topic.name.clone()];
+        domain.extend(picked.iter().map(|&p| topic.tags[p].clone()));
+        experiences.push(NewExperience {
+            collective_id,
+            id: Some(id),
+            content,
+            experience_type,
+            embedding: Some(embedding),
+            importance: 0.3 + 0.7 * rng.next_f32(),
+            confidence: 0.5 + 0.5 * rng.next_f32(),
+            domain,
+            source_agent: AgentId::new(format!("agent-{}", rng.below(config.agents) + 1)),
+            ..Default::default()
+        });
+    }
+    Ok(experiences)
+}
+
+/// Creates a collective named `name` and records a generated collective
+/// into it, returning its ID.
+///
+/// # Errors
+///
+/// - [`ValidationError::InvalidField`] if the config is out of range
+/// - [`ValidationError::DimensionMismatch`] if `config.dimension` differs
+///   from the database's
+/// - Any error from [`PulseDB::create_collective()`] or
+///   [`PulseDB::record_experience()`]
+pub fn populate(db: &PulseDB, name: &str, config: &TestDataConfig) -> Result<CollectiveId> {
+    config.validate()?;
+    let expected = db.config().embedding_dimension.size();
+    if config.dimension != expected {
+        return Err(ValidationError::dimension_mismatch(expected, config.dimension).into());
+    }
+
+    let collective_id = db.create_collective(name)?;
+    for experience in generate(config, collective_id)? {
+        db.record_experience(experience)?;
+    }
+    Ok(collective_id)
+}
+
+/// Builds a summary sentence and typed payload for one experience.
+fn synthetic_type(
+    tag_type: ExperienceTypeTag,
+    topic: &str,
+    tag: &str,
+    problem_ref: Option<ExperienceId>,
+    rng: &mut SplitMix64,
+) -> (String, ExperienceType) {
+    match tag_type {
+        ExperienceTypeTag::Difficulty => {
+            let description = format!("{} keeps breaking the {} workflow", tag, topic);
+            let severity = [
+                Severity::Low,
+                Severity::Medium,
+                Severity::High,
+                Severity::Critical,
+            ][rng.below(4)];
+            (
+                description.clone(),
+                ExperienceType::Difficulty {
+                    description,
+                    severity,
+                },
+            )
+        }
+        ExperienceTypeTag::Solution => {
+            let approach = format!("Reconfigured {} and reran the {} checks", tag, topic);
+            (
+                approach.clone(),
+                ExperienceType::Solution {
+                    problem_ref,
+                    approach,
+                    worked: rng.next_f32() < 0.8,
+                },
+            )
+        }
+        ExperienceTypeTag::ErrorPattern => {
+            let signature = format!("{}: {} error", topic, tag);
+            (
+                format!("Recurring {}", signature),
+                ExperienceType::ErrorPattern {
+                    signature,
+                    fix: format!("Reset the {} state", tag),
+                    prevention: format!("Validate {} settings before {} changes", tag, topic),
+                },
+            )
+        }
+        ExperienceTypeTag::SuccessPattern => {
+            let approach = format!("Isolate {} changes in their own {} step", tag, topic);
+            (
+                approach.clone(),
+                ExperienceType::SuccessPattern {
+                    task_type: topic.to_string(),
+                    approach,
+                    quality: 0.5 + 0.5 * rng.next_f32(),
+                },
+            )
+        }
+        ExperienceTypeTag::UserPreference => {
+            let preference = format!("Keep {} configuration explicit", tag);
+            (
+                preference.clone(),
+                ExperienceType::UserPreference {
+                    category: topic.to_string(),
+                    preference,
+                    strength: rng.next_f32(),
+                },
+            )
+        }
+        ExperienceTypeTag::ArchitecturalDecision => {
+            let decision = format!("Standardize on {} for {}", tag, topic);
+            (
+                decision.clone(),
+                ExperienceType::ArchitecturalDecision {
+                    decision,
+                    rationale: format!("The team already knows {} well", tag),
+                },
+            )
+        }
+        ExperienceTypeTag::TechInsight => {
+            let insight = format!("{} behaves differently under {} load", tag, topic);
+            (
+                insight.clone(),
+                ExperienceType::TechInsight {
+                    technology: tag.to_string(),
+                    insight,
+                },
+            )
+        }
+        ExperienceTypeTag::Fact => {
+            let statement = format!("The {} setup depends on {}", topic, tag);
+            (
+                statement.clone(),
+                ExperienceType::Fact {
+                    statement,
+                    source: format!("docs/{}.md", topic),
+                },
+            )
+        }
+        ExperienceTypeTag::OpenQuestion => {
+            let question = format!("How should {} be handled in {}?", tag, topic);
+            (question.clone(), ExperienceType::OpenQuestion { question })
+        }
+        ExperienceTypeTag::Generic => (
+            format!("Note about {} in {}", tag, topic),
+            ExperienceType::Generic {
+                category: Some(topic.to_string()),
+            },
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small() -> TestDataConfig {
+        TestDataConfig {
+            experiences: 60,
+            dimension: 32,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_collective() {
+        let cid = CollectiveId::nil();
+        let a = generate(&small(), cid).unwrap();
+        let b = generate(&small(), cid).unwrap();
+        let c = generate(
+            &TestDataConfig {
+                seed: 43,
+                ..small()
+            },
+            cid,
+        )
+        .unwrap();
+
+        let key = |e: &NewExperience| (e.id, e.content.clone(), e.embedding.clone());
+        assert_eq!(
+            a.iter().map(key).collect::<Vec<_>>(),
+            b.iter().map(key).collect::<Vec<_>>()
+        );
+        assert_ne!(
+            a.iter().map(key).collect::<Vec<_>>(),
+            c.iter().map(key).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_type_mix_and_tags_respected() {
+        let config = TestDataConfig {
+            type_mix: vec![
+                (ExperienceTypeTag::Fact, 1),
+                (ExperienceTypeTag::Difficulty, 0),
+            ],
+            topics: vec![Topic::new("db", &["redb", "hnsw", "wal"])],
+            max_tags: 3,
+            ..small()
+        };
+        for experience in generate(&config, CollectiveId::nil()).unwrap() {
+            assert_eq!(
+                experience.experience_type.type_tag(),
+                ExperienceTypeTag::Fact
+            );
+            assert_eq!(experience.domain[0], "db");
+            assert!((2..=4).contains(&experience.domain.len()));
+            let norm = experience
+                .embedding
+                .unwrap()
+                .iter()
+                .map(|x| x * x)
+                .sum::<f32>()
+                .sqrt();
+            assert!((norm - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_invalid_config_rejected() {
+        for config in [
+            TestDataConfig {
+                experiences: 0,
+                ..small()
+            },
+            TestDataConfig {
+                topics: vec![Topic::new("empty", &[])],
+                ..small()
+            },
+            TestDataConfig {
+                type_mix: vec![(ExperienceTypeTag::Fact, 0)],
+                ..small()
+            },
+            TestDataConfig {
+                spread: f32::NAN,
+                ..small()
+            },
+        ] {
+            assert!(generate(&config, CollectiveId::nil())
+                .unwrap_err()
+                .is_validation());
+        }
+    }
+}
@@ -3,10 +3,17 @@
 //! This module defines the fundamental ID types used throughout PulseDB.
 //! All ID types use UUID v7 for time-ordered unique identification.
 
-use serde::{Deserialize, Serialize};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
+use std::str::FromStr;
 use uuid::Uuid;
 
+use crate::error::ValidationError;
+
+/// Crockford base32 alphabet used for ULID renderings.
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
 /// Collective identifier (UUID v7 for time-ordering).
 ///
 /// Collectives are isolated namespaces for agent experiences, typically one per project.
@@ -68,7 +75,25 @@ impl fmt::Display for CollectiveId {
 ///
 /// Experiences are the core unit of learned knowledge in PulseDB.
 /// Each experience belongs to exactly one collective.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// # String forms
+///
+/// `Display` and `Serialize` render the hyphenated UUID.
+/// [`to_ulid()`](Self::to_ulid) renders the same 128 bits as a 26-character
+/// ULID, which is shorter and friendlier in logs and agent tool calls.
+/// Parsing ([`FromStr`], and `Deserialize` in human-readable formats such
+/// as JSON) accepts either form:
+///
+/// ```rust
+/// use pulsedb::ExperienceId;
+///
+/// let id = ExperienceId::new();
+/// let ulid = id.to_ulid();
+/// assert_eq!(ulid.len(), 26);
+/// assert_eq!(ulid.parse::<ExperienceId>().unwrap(), id);
+/// assert_eq!(id.to_string().parse::<ExperienceId>().unwrap(), id);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
 pub struct ExperienceId(pub Uuid);
 
 impl ExperienceId {
@@ -84,6 +109,25 @@ impl ExperienceId {
         Self(Uuid::nil())
     }
 
+    /// Derives a deterministic ExperienceId (UUID v5) from a collective and
+    /// content.
+    ///
+    /// The collective ID is the v5 namespace, so the same content yields
+    /// the same ID within a collective and different IDs across collectives.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pulsedb::{CollectiveId, ExperienceId};
+    ///
+    /// let cid = CollectiveId::new();
+    /// assert_eq!(ExperienceId::derive(cid, "x"), ExperienceId::derive(cid, "x"));
+    /// assert_ne!(ExperienceId::derive(cid, "x"), ExperienceId::derive(cid, "y"));
+    /// ```
+    pub fn derive(collective_id: CollectiveId, content: &str) -> Self {
+        Self(Uuid::new_v5(&collective_id.0, content.as_bytes()))
+    }
+
     /// Returns the raw UUID bytes for storage.
     #[inline]
     pub fn as_bytes(&self) -> &[u8; 16] {
@@ -95,6 +139,75 @@ impl ExperienceId {
     pub fn from_bytes(bytes: [u8; 16]) -> Self {
         Self(Uuid::from_bytes(bytes))
     }
+
+    /// Renders the ID as a ULID: 26 characters of Crockford base32.
+    ///
+    /// ULIDs sort like the raw bytes, so IDs from [`new()`](Self::new)
+    /// still sort by creation time.
+    pub fn to_ulid(&self) -> String {
+        let mut value = u128::from_be_bytes(*self.as_bytes());
+        let mut chars = [0u8; 26];
+        for slot in chars.iter_mut().rev() {
+            *slot = CROCKFORD[(value & 0x1F) as usize];
+            value >>= 5;
+        }
+        chars.iter().map(|&c| c as char).collect()
+    }
+
+    /// Parses a ULID rendering.
+    ///
+    /// Case-insensitive; `I` and `L` read as `1` and `O` as `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::InvalidField`] if the string is not a
+    /// 26-character ULID.
+    pub fn from_ulid(ulid: &str) -> Result<Self, ValidationError> {
+        let invalid = || ValidationError::invalid_field("experience_id", "not a valid ULID");
+        if ulid.len() != 26 {
+            return Err(invalid());
+        }
+        let mut value: u128 = 0;
+        for (i, c) in ulid.bytes().enumerate() {
+            let c = match c.to_ascii_uppercase() {
+                b'O' => b'0',
+                b'I' | b'L' => b'1',
+                c => c,
+            };
+            let digit = CROCKFORD.iter().position(|&a| a == c).ok_or_else(invalid)?;
+            // 26 digits hold 130 bits; the first may only carry 3
+            if i == 0 && digit > 7 {
+                return Err(invalid());
+            }
+            value = (value << 5) | digit as u128;
+        }
+        Ok(Self::from_bytes(value.to_be_bytes()))
+    }
+}
+
+impl FromStr for ExperienceId {
+    type Err = ValidationError;
+
+    /// Parses a ULID or any UUID form (hyphenated, simple, braced, URN).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == 26 {
+            return Self::from_ulid(s);
+        }
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|_| ValidationError::invalid_field("experience_id", "expected a UUID or ULID"))
+    }
+}
+
+impl<'de> Deserialize<'de> for ExperienceId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            s.parse().map_err(serde::de::Error::custom)
+        } else {
+            Uuid::deserialize(deserializer).map(Self)
+        }
+    }
 }
 
 impl Default for ExperienceId {
@@ -116,9 +229,28 @@ impl fmt::Display for ExperienceId {
 ///
 /// Using i64 allows representing dates far into the future and past.
 /// Millisecond precision is sufficient for agent operations.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+///
+/// In human-readable formats (JSON) a timestamp serializes as an RFC 3339
+/// string in UTC with millisecond precision, e.g.
+/// `"2023-11-14T22:13:20.000Z"`; on input any UTC offset is accepted, as
+/// are plain Unix milliseconds. Binary formats store the raw `i64`.
+///
+/// # Example
+/// ```
+/// use pulsedb::{Timestamp, UtcOffset};
+///
+/// let ts: Timestamp = "2023-11-14T23:13:20.5+01:00".parse().unwrap();
+/// assert_eq!(ts.as_millis(), 1_700_000_000_500);
+/// assert_eq!(ts.to_rfc3339(), "2023-11-14T22:13:20.500Z");
+///
+/// let tokyo = UtcOffset::from_minutes(9 * 60).unwrap();
+/// assert_eq!(ts.to_rfc3339_with_offset(tokyo), "2023-11-15T07:13:20.500+09:00");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Timestamp(pub i64);
 
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
 impl Timestamp {
     /// Creates a timestamp for the current moment.
     ///
@@ -150,14 +282,312 @@ impl Timestamp {
     pub fn to_be_bytes(&self) -> [u8; 8] {
         self.0.to_be_bytes()
     }
+
+    /// Formats the timestamp as RFC 3339 in UTC, with milliseconds.
+    ///
+    /// Years outside 0000–9999 use the ISO 8601 expanded form
+    /// (`+12345-01-01T00:00:00.000Z`), which [`parse_rfc3339()`](Self::parse_rfc3339)
+    /// also accepts.
+    pub fn to_rfc3339(&self) -> String {
+        self.to_rfc3339_with_offset(UtcOffset::UTC)
+    }
+
+    /// Formats the timestamp as RFC 3339 in the local time of `offset`.
+    ///
+    /// A zero offset is written as `Z`.
+    pub fn to_rfc3339_with_offset(&self, offset: UtcOffset) -> String {
+        // i128 keeps extreme timestamps plus an offset from overflowing
+        let local = self.0 as i128 + offset.as_minutes() as i128 * 60_000;
+        let days = local.div_euclid(MILLIS_PER_DAY as i128) as i64;
+        let ms_of_day = local.rem_euclid(MILLIS_PER_DAY as i128) as i64;
+        let (year, month, day) = civil_from_days(days);
+
+        let year = if (0..=9999).contains(&year) {
+            format!("{:04}", year)
+        } else {
+            format!("{:+07}", year)
+        };
+        let zone = if offset == UtcOffset::UTC {
+            "Z".to_string()
+        } else {
+            offset.to_string()
+        };
+        format!(
+            "{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}{}",
+            year,
+            month,
+            day,
+            ms_of_day / 3_600_000,
+            ms_of_day / 60_000 % 60,
+            ms_of_day / 1_000 % 60,
+            ms_of_day % 1_000,
+            zone
+        )
+    }
+
+    /// Parses an RFC 3339 date-time with any UTC offset.
+    ///
+    /// Fractional seconds beyond milliseconds are truncated. A lowercase
+    /// `t`/`z` or a space separator are accepted, as RFC 3339 allows.
+    pub fn parse_rfc3339(s: &str) -> Result<Self, ValidationError> {
+        Self::parse_rfc3339_with_offset(s).map(|(ts, _)| ts)
+    }
+
+    /// Parses an RFC 3339 date-time, also returning the offset it was
+    /// written in, so it can be formatted back in the same time zone.
+    pub fn parse_rfc3339_with_offset(s: &str) -> Result<(Self, UtcOffset), ValidationError> {
+        parse_rfc3339(s).ok_or_else(|| {
+            ValidationError::invalid_field(
+                "timestamp",
+                format!("expected an RFC 3339 date-time, got {:?}", s),
+            )
+        })
+    }
 }
 
 impl fmt::Display for Timestamp {
+    /// Writes the RFC 3339 form; see [`Timestamp::to_rfc3339()`].
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        f.write_str(&self.to_rfc3339())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = ValidationError;
+
+    /// Parses an RFC 3339 date-time or integer Unix milliseconds.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(millis) = s.parse::<i64>() {
+            return Ok(Self(millis));
+        }
+        Self::parse_rfc3339(s).map_err(|_| {
+            ValidationError::invalid_field(
+                "timestamp",
+                "expected an RFC 3339 date-time or Unix milliseconds",
+            )
+        })
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_rfc3339())
+        } else {
+            serializer.serialize_i64(self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TimestampVisitor;
+
+        impl<'de> Visitor<'de> for TimestampVisitor {
+            type Value = Timestamp;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an RFC 3339 date-time or Unix milliseconds")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Timestamp, E> {
+                Ok(Timestamp(v))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Timestamp, E> {
+                i64::try_from(v)
+                    .map(Timestamp)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Timestamp, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            // Files written before the RFC 3339 form hold integers
+            deserializer.deserialize_any(TimestampVisitor)
+        } else {
+            i64::deserialize(deserializer).map(Self)
+        }
+    }
+}
+
+/// A fixed offset from UTC, for formatting and parsing [`Timestamp`]s in
+/// local time.
+///
+/// Ranges from `-23:59` to `+23:59`. PulseDB has no time zone database;
+/// callers resolve a zone (and its daylight saving rules) to the offset in
+/// effect themselves.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UtcOffset(i16);
+
+impl UtcOffset {
+    /// Coordinated Universal Time.
+    pub const UTC: Self = Self(0);
+
+    /// Largest offset magnitude in minutes (23:59).
+    const MAX_MINUTES: i32 = 23 * 60 + 59;
+
+    /// Creates an offset east of UTC in minutes (negative is west).
+    ///
+    /// Returns `None` outside ±23:59.
+    pub fn from_minutes(minutes: i32) -> Option<Self> {
+        (minutes.abs() <= Self::MAX_MINUTES).then_some(Self(minutes as i16))
+    }
+
+    /// Returns the offset east of UTC in minutes.
+    #[inline]
+    pub const fn as_minutes(&self) -> i32 {
+        self.0 as i32
+    }
+}
+
+impl fmt::Display for UtcOffset {
+    /// Writes `±HH:MM`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { '-' } else { '+' };
+        let minutes = self.0.unsigned_abs();
+        write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+impl FromStr for UtcOffset {
+    type Err = ValidationError;
+
+    /// Parses `Z` or `±HH:MM`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_offset(s.as_bytes())
+            .ok_or_else(|| ValidationError::invalid_field("utc_offset", "expected Z or ±HH:MM"))
     }
 }
 
+/// Days since 1970-01-01 to a proleptic Gregorian (year, month, day).
+///
+/// Howard Hinnant's `civil_from_days`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Proleptic Gregorian (year, month, day) to days since 1970-01-01.
+///
+/// Howard Hinnant's `days_from_civil`.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (i64::from(month) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses a non-empty run of ASCII digits.
+fn digits(bytes: &[u8]) -> Option<u32> {
+    if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    bytes.iter().try_fold(0u32, |n, &b| {
+        n.checked_mul(10)?.checked_add(u32::from(b - b'0'))
+    })
+}
+
+/// Parses `Z`/`z` or `±HH:MM`.
+fn parse_offset(bytes: &[u8]) -> Option<UtcOffset> {
+    match bytes {
+        [b'Z' | b'z'] => Some(UtcOffset::UTC),
+        [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => {
+            let hours = digits(&[*h1, *h2])?;
+            let minutes = digits(&[*m1, *m2])?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let total = (hours * 60 + minutes) as i32;
+            UtcOffset::from_minutes(if *sign == b'-' { -total } else { total })
+        }
+        _ => None,
+    }
+}
+
+/// Parses `[±]YYYY-MM-DDTHH:MM:SS[.fff]<offset>`.
+fn parse_rfc3339(s: &str) -> Option<(Timestamp, UtcOffset)> {
+    let bytes = s.as_bytes();
+
+    // Year: four digits, or the signed expanded form
+    let (year, rest) = match bytes.first()? {
+        sign @ (b'+' | b'-') => {
+            let end = bytes[1..].iter().position(|&b| b == b'-')? + 1;
+            if !(5..=10).contains(&end) {
+                return None;
+            }
+            let year = i64::from(digits(&bytes[1..end])?);
+            (if *sign == b'-' { -year } else { year }, &bytes[end..])
+        }
+        _ => (i64::from(digits(bytes.get(..4)?)?), &bytes[4..]),
+    };
+
+    let rest = rest.strip_prefix(b"-")?;
+    let month = digits(rest.get(..2)?)?;
+    let rest = rest[2..].strip_prefix(b"-")?;
+    let day = digits(rest.get(..2)?)?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let rest = match rest.get(2)? {
+        b'T' | b't' | b' ' => &rest[3..],
+        _ => return None,
+    };
+    let hour = digits(rest.get(..2)?)?;
+    let rest = rest[2..].strip_prefix(b":")?;
+    let minute = digits(rest.get(..2)?)?;
+    let rest = rest[2..].strip_prefix(b":")?;
+    // 60 is a leap second; it folds into the next minute
+    let second = digits(rest.get(..2)?)?;
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    let mut rest = &rest[2..];
+
+    let mut millis = 0;
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let len = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        for (i, &b) in fraction[..len.min(3)].iter().enumerate() {
+            millis += i64::from(b - b'0') * 10_i64.pow(2 - i as u32);
+        }
+        rest = &fraction[len..];
+    }
+    let offset = parse_offset(rest)?;
+
+    let seconds = i64::from(hour) * 3_600 + i64::from(minute) * 60 + i64::from(second)
+        - i64::from(offset.as_minutes()) * 60;
+    let millis = days_from_civil(year, month, day) as i128 * MILLIS_PER_DAY as i128
+        + (seconds * 1_000 + millis) as i128;
+    Some((Timestamp(i64::try_from(millis).ok()?), offset))
+}
+
 /// Relation identifier (UUID v7 for time-ordering).
 ///
 /// Relations connect two experiences within the same collective,
@@ -337,11 +767,65 @@ impl fmt::Display for TaskId {
     }
 }
 
+/// Episode identifier.
+///
+/// An episode is the run of experiences an agent recorded under one task,
+/// so it is keyed by the same ID set in
+/// [`NewExperience::source_task`](crate::NewExperience::source_task).
+pub type EpisodeId = TaskId;
+
 /// Embedding vector type alias.
 ///
 /// Embeddings are f32 vectors of fixed dimension (typically 384 or 768).
 pub type Embedding = Vec<f32>;
 
+/// Offset/limit pagination window for list APIs.
+///
+/// Offsets count items, so a page can skip or repeat items when others are
+/// inserted or deleted between calls. The cursor APIs (see
+/// [`Cursor`](crate::Cursor)) resume after the last item instead.
+///
+/// # Example
+/// ```
+/// use pulsedb::Page;
+///
+/// let first = Page::new(0, 50);
+/// let second = first.next();
+/// assert_eq!(second.offset, 50);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Page {
+    /// Number of matching items to skip.
+    pub offset: usize,
+    /// Maximum number of items to return.
+    pub limit: usize,
+}
+
+impl Page {
+    /// Creates a page window.
+    pub fn new(offset: usize, limit: usize) -> Self {
+        Self { offset, limit }
+    }
+
+    /// Returns the window immediately following this one.
+    pub fn next(&self) -> Self {
+        Self {
+            offset: self.offset + self.limit,
+            limit: self.limit,
+        }
+    }
+}
+
+impl Default for Page {
+    /// First page of 100 items.
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            limit: 100,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,6 +837,15 @@ mod tests {
         assert_ne!(id1, id2);
     }
 
+    #[test]
+    fn test_experience_id_derive_is_deterministic_per_collective() {
+        let (a, b) = (CollectiveId::new(), CollectiveId::new());
+        let id = ExperienceId::derive(a, "content");
+        assert_eq!(id, ExperienceId::derive(a, "content"));
+        assert_ne!(id, ExperienceId::derive(b, "content"));
+        assert_eq!(id.0.get_version_num(), 5);
+    }
+
     #[test]
     fn test_collective_id_nil() {
         let id = CollectiveId::nil();
@@ -390,6 +883,68 @@ mod tests {
         assert_eq!(id, restored);
     }
 
+    #[test]
+    fn test_experience_id_ulid_roundtrip() {
+        assert_eq!(ExperienceId::nil().to_ulid(), "0".repeat(26));
+        let max = ExperienceId::from_bytes([0xFF; 16]);
+        assert_eq!(max.to_ulid(), format!("7{}", "Z".repeat(25)));
+
+        let id = ExperienceId::new();
+        let ulid = id.to_ulid();
+        assert_eq!(ExperienceId::from_ulid(&ulid).unwrap(), id);
+        assert_eq!(
+            ExperienceId::from_ulid(&ulid.to_lowercase()).unwrap(),
+            id,
+            "ULIDs are case-insensitive"
+        );
+        assert_eq!(
+            ExperienceId::from_ulid("OIL00000000000000000000000").unwrap(),
+            ExperienceId::from_ulid("01100000000000000000000000").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_experience_id_ulid_preserves_order() {
+        let ids: Vec<ExperienceId> = (0..50).map(|_| ExperienceId::new()).collect();
+        let mut by_ulid = ids.clone();
+        by_ulid.sort_by_key(|id| id.to_ulid());
+        let mut by_bytes = ids;
+        by_bytes.sort_by_key(|id| *id.as_bytes());
+        assert_eq!(by_ulid, by_bytes);
+    }
+
+    #[test]
+    fn test_experience_id_parses_uuid_and_ulid() {
+        let id = ExperienceId::new();
+        assert_eq!(id.to_string().parse::<ExperienceId>().unwrap(), id);
+        assert_eq!(
+            id.0.simple().to_string().parse::<ExperienceId>().unwrap(),
+            id
+        );
+        assert_eq!(id.to_ulid().parse::<ExperienceId>().unwrap(), id);
+
+        for bad in [
+            "",
+            "not-an-id",
+            "8ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+            "0000000000000000000000000U",
+        ] {
+            assert!(bad.parse::<ExperienceId>().is_err(), "accepted {:?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_experience_id_json_accepts_ulid() {
+        let id = ExperienceId::new();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{}\"", id));
+        assert_eq!(serde_json::from_str::<ExperienceId>(&json).unwrap(), id);
+
+        let ulid = format!("\"{}\"", id.to_ulid());
+        assert_eq!(serde_json::from_str::<ExperienceId>(&ulid).unwrap(), id);
+        assert!(serde_json::from_str::<ExperienceId>("\"nope\"").is_err());
+    }
+
     #[test]
     fn test_relation_id_new_is_unique() {
         let id1 = RelationId::new();
@@ -471,6 +1026,113 @@ mod tests {
         assert!(t1.to_be_bytes() < t2.to_be_bytes());
     }
 
+    #[test]
+    fn test_timestamp_rfc3339_format() {
+        assert_eq!(
+            Timestamp::from_millis(0).to_rfc3339(),
+            "1970-01-01T00:00:00.000Z"
+        );
+        assert_eq!(
+            Timestamp::from_millis(1_700_000_000_123).to_rfc3339(),
+            "2023-11-14T22:13:20.123Z"
+        );
+        assert_eq!(
+            Timestamp::from_millis(-1).to_rfc3339(),
+            "1969-12-31T23:59:59.999Z"
+        );
+        // Leap day
+        assert_eq!(
+            Timestamp::from_millis(951_782_400_000).to_rfc3339(),
+            "2000-02-29T00:00:00.000Z"
+        );
+
+        let west = UtcOffset::from_minutes(-(5 * 60 + 30)).unwrap();
+        assert_eq!(
+            Timestamp::from_millis(0).to_rfc3339_with_offset(west),
+            "1969-12-31T18:30:00.000-05:30"
+        );
+        assert_eq!(
+            format!("{}", Timestamp::from_millis(0)),
+            "1970-01-01T00:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_rfc3339_parse() {
+        let expected = Timestamp::from_millis(1_700_000_000_123);
+        for s in [
+            "2023-11-14T22:13:20.123Z",
+            "2023-11-14t22:13:20.123z",
+            "2023-11-14 22:13:20.123456789Z",
+            "2023-11-15T03:43:20.123+05:30",
+            "2023-11-14T17:13:20.123-05:00",
+        ] {
+            assert_eq!(Timestamp::parse_rfc3339(s).unwrap(), expected, "{}", s);
+        }
+
+        let (ts, offset) = Timestamp::parse_rfc3339_with_offset("2023-11-15T03:43:20Z").unwrap();
+        assert_eq!(ts.as_millis(), 1_700_019_800_000);
+        assert_eq!(offset, UtcOffset::UTC);
+
+        for bad in [
+            "",
+            "2023-11-14",
+            "2023-11-14T22:13:20",
+            "2024-13-01T00:00:00Z",
+            "2023-02-29T00:00:00Z",
+            "2023-11-14T24:00:00Z",
+            "2023-11-14T22:13:20.Z",
+            "2023-11-14T22:13:20+24:00",
+            "2023-11-14T22:13:20Z trailing",
+        ] {
+            assert!(Timestamp::parse_rfc3339(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_timestamp_rfc3339_roundtrip_extremes() {
+        for millis in [i64::MIN, -62_167_219_200_001, 253_402_300_800_000, i64::MAX] {
+            let ts = Timestamp::from_millis(millis);
+            assert_eq!(Timestamp::parse_rfc3339(&ts.to_rfc3339()).unwrap(), ts);
+        }
+        assert_eq!(
+            Timestamp::from_millis(253_402_300_800_000).to_rfc3339(),
+            "+010000-01-01T00:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_serde_forms() {
+        let ts = Timestamp::from_millis(1_700_000_000_000);
+        let json = serde_json::to_string(&ts).unwrap();
+        assert_eq!(json, "\"2023-11-14T22:13:20.000Z\"");
+        assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), ts);
+
+        // Integers from earlier versions still read
+        assert_eq!(
+            serde_json::from_str::<Timestamp>("1700000000000").unwrap(),
+            ts
+        );
+        assert!(serde_json::from_str::<Timestamp>("\"yesterday\"").is_err());
+
+        // Binary formats keep the raw i64
+        let bytes = bincode::serialize(&ts).unwrap();
+        assert_eq!(bytes, bincode::serialize(&1_700_000_000_000_i64).unwrap());
+        assert_eq!(bincode::deserialize::<Timestamp>(&bytes).unwrap(), ts);
+
+        assert_eq!("1700000000000".parse::<Timestamp>().unwrap(), ts);
+    }
+
+    #[test]
+    fn test_utc_offset() {
+        assert!(UtcOffset::from_minutes(24 * 60).is_none());
+        let offset: UtcOffset = "-09:30".parse().unwrap();
+        assert_eq!(offset.as_minutes(), -570);
+        assert_eq!(offset.to_string(), "-09:30");
+        assert_eq!("Z".parse::<UtcOffset>().unwrap(), UtcOffset::UTC);
+        assert!("+9:00".parse::<UtcOffset>().is_err());
+    }
+
     #[test]
     fn test_user_id() {
         let id = UserId::new("user-123");
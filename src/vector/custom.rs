@@ -0,0 +1,365 @@
+//! User-supplied vector index backends.
+//!
+//! A [`VectorBackend`] set in [`Config::vector_backend`](crate::Config::vector_backend)
+//! builds a [`VectorIndex`] for every collective's experiences and insights
+//! in place of the built-in HNSW and IVF indexes. The backend only sees
+//! dense `usize` IDs; [`CustomIndex`] maps them to experience IDs.
+//!
+//! ```text
+//! PulseDB ──ExperienceId──→ CustomIndex ──usize──→ Box<dyn VectorIndex>
+//! ```
+//!
+//! Like the built-in indexes, custom indexes are derived state: they are
+//! rebuilt from the embeddings in redb whenever a collective is loaded.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use crate::error::{PulseDBError, Result};
+use crate::types::{CollectiveId, ExperienceId};
+
+use super::VectorIndex;
+
+/// Which records an index built by a [`VectorBackend`] holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndexRole {
+    /// The collective's experiences.
+    Experiences,
+
+    /// The collective's derived insights.
+    Insights,
+}
+
+/// Describes the index a [`VectorBackend`] is asked to build.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IndexSpec {
+    /// The collective the index serves.
+    pub collective_id: CollectiveId,
+
+    /// Whether the index holds experiences or insights.
+    pub role: IndexRole,
+
+    /// Length of every vector inserted or searched for.
+    pub dimension: usize,
+}
+
+/// Factory type wrapped by [`VectorBackend`].
+type IndexFactory = dyn Fn(&IndexSpec) -> Result<Box<dyn VectorIndex>> + Send + Sync;
+
+/// Builds a custom [`VectorIndex`] for each collective.
+///
+/// The factory is called whenever an empty index is needed — on open,
+/// when a collective is created, and when an evicted collective is
+/// reloaded. PulseDB then fills it from stored embeddings, so the
+/// factory should return an empty index.
+///
+/// # Example
+///
+/// ```rust
+/// use pulsedb::vector::HnswIndex;
+/// use pulsedb::{Config, HnswConfig, VectorBackend, VectorIndex};
+///
+/// let hnsw = HnswConfig::default();
+/// let config = Config {
+///     vector_backend: Some(VectorBackend::new(move |spec| {
+///         Ok(Box::new(HnswIndex::new(spec.dimension, &hnsw)) as Box<dyn VectorIndex>)
+///     })),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone)]
+pub struct VectorBackend {
+    factory: Arc<IndexFactory>,
+}
+
+impl VectorBackend {
+    /// Wraps a factory returning an empty index for the given spec.
+    pub fn new<F>(factory: F) -> Self
+    where
+        F: Fn(&IndexSpec) -> Result<Box<dyn VectorIndex>> + Send + Sync + 'static,
+    {
+        Self {
+            factory: Arc::new(factory),
+        }
+    }
+
+    /// Builds an empty index for one collective.
+    pub(crate) fn build(
+        &self,
+        collective_id: CollectiveId,
+        role: IndexRole,
+        dimension: usize,
+    ) -> Result<CustomIndex> {
+        let spec = IndexSpec {
+            collective_id,
+            role,
+            dimension,
+        };
+        Ok(CustomIndex::new((self.factory)(&spec)?, dimension))
+    }
+}
+
+impl std::fmt::Debug for VectorBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VectorBackend").finish_non_exhaustive()
+    }
+}
+
+/// Bidirectional mapping between experience IDs and backend IDs.
+#[derive(Default)]
+struct IdMap {
+    /// ExperienceId → backend ID.
+    to_internal: HashMap<ExperienceId, usize>,
+
+    /// Backend ID → ExperienceId (index = backend ID).
+    to_id: Vec<ExperienceId>,
+}
+
+/// A [`VectorIndex`] from a [`VectorBackend`], addressed by experience ID.
+///
+/// Backend IDs are assigned sequentially from 0. Inserts hold the ID map's
+/// write lock while calling the backend, so a failed insert leaves no
+/// mapping behind.
+pub struct CustomIndex {
+    /// The user's index.
+    inner: Box<dyn VectorIndex>,
+
+    /// Experience ID mapping.
+    ids: RwLock<IdMap>,
+
+    /// Vector dimension, checked before calling the backend.
+    dimension: usize,
+}
+
+impl CustomIndex {
+    /// Wraps an empty backend index.
+    pub(crate) fn new(inner: Box<dyn VectorIndex>, dimension: usize) -> Self {
+        Self {
+            inner,
+            ids: RwLock::new(IdMap::default()),
+            dimension,
+        }
+    }
+
+    fn check_dimension(&self, what: &str, vector: &[f32]) -> Result<()> {
+        if vector.len() != self.dimension {
+            return Err(PulseDBError::vector(format!(
+                "{} dimension mismatch: expected {}, got {}",
+                what,
+                self.dimension,
+                vector.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Inserts an experience embedding (no-op if already present).
+    pub fn insert_experience(&self, exp_id: ExperienceId, embedding: &[f32]) -> Result<()> {
+        self.check_dimension("Embedding", embedding)?;
+        let mut ids = self
+            .ids
+            .write()
+            .map_err(|_| PulseDBError::vector("Index ID map lock poisoned"))?;
+        if ids.to_internal.contains_key(&exp_id) {
+            return Ok(());
+        }
+
+        let internal_id = ids.to_id.len();
+        self.inner.insert(internal_id, embedding)?;
+        ids.to_internal.insert(exp_id, internal_id);
+        ids.to_id.push(exp_id);
+        Ok(())
+    }
+
+    /// Inserts experience embeddings through one
+    /// [`insert_batch`](VectorIndex::insert_batch) call, skipping any
+    /// already present.
+    pub fn insert_experiences(&self, items: &[(ExperienceId, &Vec<f32>)]) -> Result<()> {
+        for (_, embedding) in items {
+            self.check_dimension("Embedding", embedding)?;
+        }
+        let mut ids = self
+            .ids
+            .write()
+            .map_err(|_| PulseDBError::vector("Index ID map lock poisoned"))?;
+
+        let mut fresh = Vec::with_capacity(items.len());
+        let mut batch = Vec::with_capacity(items.len());
+        let mut seen = HashSet::with_capacity(items.len());
+        for (exp_id, embedding) in items {
+            if ids.to_internal.contains_key(exp_id) || !seen.insert(*exp_id) {
+                continue;
+            }
+            batch.push((*embedding, ids.to_id.len() + fresh.len()));
+            fresh.push(*exp_id);
+        }
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        self.inner.insert_batch(&batch)?;
+        for exp_id in fresh {
+            let internal_id = ids.to_id.len();
+            ids.to_internal.insert(exp_id, internal_id);
+            ids.to_id.push(exp_id);
+        }
+        Ok(())
+    }
+
+    /// Marks an experience as deleted. Returns Ok even if the experience
+    /// is not in the index.
+    pub fn delete_experience(&self, exp_id: ExperienceId) -> Result<()> {
+        let ids = self
+            .ids
+            .read()
+            .map_err(|_| PulseDBError::vector("Index ID map lock poisoned"))?;
+        match ids.to_internal.get(&exp_id) {
+            Some(&internal_id) => self.inner.delete(internal_id),
+            None => Ok(()),
+        }
+    }
+
+    /// Searches for the k nearest experiences, closest first.
+    pub fn search_experiences(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+    ) -> Result<Vec<(ExperienceId, f32)>> {
+        self.check_dimension("Query", query)?;
+        if self.inner.is_empty() {
+            return Ok(vec![]);
+        }
+        let ids = self
+            .ids
+            .read()
+            .map_err(|_| PulseDBError::vector("Index ID map lock poisoned"))?;
+        let hits = self.inner.search(query, k, ef_search)?;
+        Ok(self.map_hits(&ids, hits))
+    }
+
+    /// Searches for the k nearest experiences among `allowed`, closest
+    /// first, through [`search_filtered`](VectorIndex::search_filtered).
+    pub fn search_experiences_within(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        allowed: &HashSet<ExperienceId>,
+    ) -> Result<Vec<(ExperienceId, f32)>> {
+        self.check_dimension("Query", query)?;
+        let ids = self
+            .ids
+            .read()
+            .map_err(|_| PulseDBError::vector("Index ID map lock poisoned"))?;
+        let internal: HashSet<usize> = allowed
+            .iter()
+            .filter_map(|id| ids.to_internal.get(id).copied())
+            .filter(|id| !self.inner.is_deleted(*id))
+            .collect();
+        if internal.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let effective_k = k.min(internal.len());
+        let hits =
+            self.inner
+                .search_filtered(query, effective_k, ef_search.max(effective_k), &|id| {
+                    internal.contains(id)
+                })?;
+        Ok(self.map_hits(&ids, hits))
+    }
+
+    /// Maps backend hits to experience IDs, dropping IDs PulseDB never
+    /// assigned and any the backend returned despite their deletion.
+    fn map_hits(&self, ids: &IdMap, hits: Vec<(usize, f32)>) -> Vec<(ExperienceId, f32)> {
+        hits.into_iter()
+            .filter(|(id, _)| !self.inner.is_deleted(*id))
+            .filter_map(|(id, distance)| ids.to_id.get(id).map(|&exp_id| (exp_id, distance)))
+            .collect()
+    }
+
+    /// Returns true if the experience is indexed and not deleted.
+    pub fn contains(&self, exp_id: ExperienceId) -> bool {
+        let ids = self.ids.read().ok();
+        ids.is_some_and(|ids| {
+            ids.to_internal
+                .get(&exp_id)
+                .is_some_and(|&id| !self.inner.is_deleted(id))
+        })
+    }
+
+    /// Returns the number of active (non-deleted) vectors.
+    pub fn active_count(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Persists the backend's state (see [`VectorIndex::save`]).
+    pub fn save_to_dir(&self, dir: &Path, name: &str) -> Result<()> {
+        self.inner.save(dir, name)
+    }
+}
+
+// ==========================================================================
+// Tests
+// ==========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::HnswConfig;
+    use crate::vector::HnswIndex;
+
+    /// Generates a deterministic embedding from a seed.
+    fn make_embedding(seed: u64, dim: usize) -> Vec<f32> {
+        (0..dim)
+            .map(|i| (seed as f32 * 0.1 + i as f32 * 0.01).sin())
+            .collect()
+    }
+
+    fn hnsw_backed(dim: usize) -> CustomIndex {
+        CustomIndex::new(Box::new(HnswIndex::new(dim, &HnswConfig::default())), dim)
+    }
+
+    #[test]
+    fn test_experience_ids_map_through_the_trait() {
+        let index = hnsw_backed(8);
+        let ids: Vec<ExperienceId> = (0..10).map(|_| ExperienceId::new()).collect();
+        let embeddings: Vec<Vec<f32>> = (0..10).map(|i| make_embedding(i, 8)).collect();
+        let mut items: Vec<(ExperienceId, &Vec<f32>)> =
+            ids.iter().copied().zip(&embeddings).collect();
+        items.push((ids[0], &embeddings[0]));
+        index.insert_experiences(&items).unwrap();
+        index.insert_experience(ids[3], &embeddings[3]).unwrap();
+        assert_eq!(index.active_count(), 10);
+
+        let results = index.search_experiences(&embeddings[4], 2, 50).unwrap();
+        assert_eq!(results[0].0, ids[4]);
+
+        index.delete_experience(ids[4]).unwrap();
+        assert!(!index.contains(ids[4]));
+        assert_eq!(index.active_count(), 9);
+        let results = index.search_experiences(&embeddings[4], 9, 50).unwrap();
+        assert_eq!(results.len(), 9);
+        assert!(results.iter().all(|(id, _)| *id != ids[4]));
+
+        let allowed: HashSet<ExperienceId> = [ids[4], ids[7]].into_iter().collect();
+        let results = index
+            .search_experiences_within(&embeddings[4], 5, 50, &allowed)
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, ids[7]);
+    }
+
+    #[test]
+    fn test_dimension_checked_before_backend() {
+        let index = hnsw_backed(8);
+        assert!(index
+            .insert_experience(ExperienceId::new(), &[0.1; 4])
+            .is_err());
+        assert!(index.search_experiences(&[0.1; 4], 1, 10).is_err());
+        assert_eq!(index.active_count(), 0);
+    }
+}
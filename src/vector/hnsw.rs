@@ -3,24 +3,60 @@
 //! Wraps `hnsw_rs::Hnsw<f32, DistCosine>` with:
 //! - Bidirectional `ExperienceId` ↔ `usize` ID mapping
 //! - Soft-delete via `HashSet` + filtered search
+//! - Segmentation: one active graph taking inserts plus sealed graphs,
+//!   searched in parallel and merged
 //! - JSON metadata persistence (`.hnsw.meta`)
 //!
+//! # Persistence
+//!
+//! Each [`HnswIndex::save_to_dir()`] is a new generation. Graph dumps are
+//! written under generation-tagged names (`{name}.g{generation}.seg{i}`),
+//! then the metadata listing them replaces `.hnsw.meta` by atomic rename,
+//! and only then are the previous generation's dumps removed. A crash at
+//! any point leaves the last complete generation in place.
+//!
+//! The metadata also records each dump file's size and CRC32.
+//! [`HnswIndex::load_from_dir()`] reloads the graphs only when every dump
+//! verifies, so a truncated or corrupted dump costs a rebuild from redb
+//! rather than a broken index.
+//!
+//! # Segments
+//!
+//! A single graph gets slow to rebuild and hard to compact past a few
+//! million vectors. Once the active segment holds
+//! [`HnswConfig::max_segment_size`] points it is sealed and a new one
+//! starts. [`HnswIndex::merge_segments()`] folds runs of sealed segments
+//! together and drops soft-deleted vectors, one run per call, so
+//! compaction proceeds in bounded steps alongside searches and inserts.
+//!
+//! ```text
+//! ┌──────────┬──────────┬──────────┐
+//! │ sealed 0 │ sealed 1 │  active  │ ← inserts
+//! └────┬─────┴────┬─────┴────┬─────┘
+//!      └──── search each, merge by distance ──→ top k
+//! ```
+//!
 //! # Thread Safety
 //!
 //! The `hnsw_rs::Hnsw` graph uses `parking_lot::RwLock` internally,
-//! so `insert()` takes `&self`. Our metadata (`IndexState`) is
-//! protected by `std::sync::RwLock`.
+//! so `insert()` takes `&self`. Our metadata (`IndexState`) and the
+//! segment list are protected by `std::sync::RwLock`s, timed for
+//! [`PulseDB::metrics()`](crate::PulseDB::metrics).
 
 use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::io::Write;
 use std::path::Path;
-use std::sync::RwLock;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use hnsw_rs::prelude::*;
 
 use crate::config::HnswConfig;
-use crate::error::{PulseDBError, Result};
-use crate::types::ExperienceId;
+use crate::deadline::Deadline;
+use crate::error::{PulseDBError, Result, TimedOperation};
+use crate::metrics::{LockWaitStats, TimedRwLock};
+use crate::types::{ExperienceId, Timestamp};
 
 use super::VectorIndex;
 
@@ -31,6 +67,9 @@ use super::VectorIndex;
 /// reliable (100% recall) and faster (no graph overhead) at this scale.
 const BRUTE_FORCE_THRESHOLD: usize = 128;
 
+/// Points bulk-inserted between deadline checks during a rebuild.
+const REBUILD_CHECK_BATCH: usize = 4096;
+
 /// Newtype wrapper that bridges `&dyn Fn(&usize) -> bool` to `FilterT`.
 ///
 /// Rust's blanket impl `impl<F: Fn(&DataId) -> bool> FilterT for F` only
@@ -53,25 +92,159 @@ impl FilterT for FilterBridge<'_> {
 ///
 /// # Persistence Strategy
 ///
-/// Metadata (ID mappings, deleted set) is persisted to a JSON `.hnsw.meta`
-/// file. The graph itself is rebuilt from redb embeddings on open, because
-/// `hnsw_rs::HnswIo::load_hnsw` has lifetime constraints that create
-/// self-referential struct issues. The graph dump files (via `file_dump`)
-/// are saved for future optimization but not currently loaded.
+/// Metadata (ID mappings, deleted set, dump checksums) is persisted to a
+/// JSON `.hnsw.meta` file next to the graph dumps written by `file_dump`.
+/// On open the graphs are reloaded with
+/// [`load_from_dir()`](Self::load_from_dir); if they are missing, fail
+/// verification, or were built with other parameters, the graph is
+/// rebuilt from redb embeddings instead.
 pub struct HnswIndex {
-    /// The underlying HNSW graph. Uses `'static` lifetime because
-    /// all data is heap-owned (not memory-mapped).
-    hnsw: Hnsw<'static, f32, DistCosine>,
+    /// Graph segments, oldest first. Inserts go to the last (active)
+    /// segment; the others are sealed and only change when merged.
+    segments: TimedRwLock<Vec<Segment>>,
+
+    /// Serializes segment merges.
+    merging: Mutex<()>,
 
     /// Mutable metadata protected by RwLock.
-    state: RwLock<IndexState>,
+    state: TimedRwLock<IndexState>,
 
-    /// Immutable configuration (used during save/rebuild lifecycle).
-    #[allow(dead_code)]
+    /// Immutable configuration (used during save/rebuild lifecycle and warm-up).
     config: HnswConfig,
 
     /// Embedding dimension (must match all inserted vectors).
     dimension: usize,
+
+    /// When this index was last rebuilt from embeddings, and how long the
+    /// rebuild took. `None` for indexes that were created empty or loaded.
+    last_rebuild: Option<(Timestamp, Duration)>,
+}
+
+/// Health snapshot of one HNSW index.
+///
+/// Returned in [`CollectiveStats::vector_index`](crate::CollectiveStats::vector_index).
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HnswStats {
+    /// Vectors that searches can return.
+    pub active_vectors: u64,
+
+    /// Soft-deleted vectors still occupying the graphs until their
+    /// segment is merged or the index is rebuilt.
+    pub deleted_vectors: u64,
+
+    /// Number of graph segments, including the active one.
+    pub segments: u64,
+
+    /// Layers in the tallest segment graph (0 when the index is empty).
+    pub graph_levels: u32,
+
+    /// Rough in-memory footprint in bytes: vectors, neighbor lists, and
+    /// ID mappings. Allocator overhead is not counted.
+    pub memory_bytes: u64,
+
+    /// When the index was last rebuilt from stored embeddings.
+    pub last_rebuild: Option<Timestamp>,
+
+    /// How long that rebuild took.
+    pub last_rebuild_duration: Option<Duration>,
+}
+
+/// One HNSW graph holding a slice of the index's vectors.
+///
+/// Points are inserted under the index-wide internal IDs, so a point keeps
+/// its ID when segments are merged.
+struct Segment {
+    /// The underlying HNSW graph. Uses `'static` lifetime because
+    /// all data is heap-owned (not memory-mapped).
+    graph: Hnsw<'static, f32, DistCosine>,
+}
+
+impl Segment {
+    fn new(config: &HnswConfig, capacity: usize) -> Self {
+        Self {
+            graph: Hnsw::new(
+                config.max_nb_connection,
+                capacity,
+                config.max_layer,
+                config.ef_construction,
+                DistCosine,
+            ),
+        }
+    }
+
+    /// Returns the number of points, including deleted ones.
+    fn len(&self) -> usize {
+        self.graph.get_nb_point()
+    }
+
+    /// Returns the internal IDs of every point.
+    fn ids(&self) -> Vec<usize> {
+        if self.len() == 0 {
+            return Vec::new();
+        }
+        self.graph
+            .get_point_indexation()
+            .into_iter()
+            .map(|point| point.get_origin_id())
+            .collect()
+    }
+
+    /// Returns every point as `(internal ID, vector)`.
+    fn points(&self) -> Vec<(usize, Vec<f32>)> {
+        // Point iteration requires an entry point, which empty graphs lack
+        if self.len() == 0 {
+            return Vec::new();
+        }
+        self.graph
+            .get_point_indexation()
+            .into_iter()
+            .map(|point| (point.get_origin_id(), point.get_v().to_vec()))
+            .collect()
+    }
+
+    /// Returns the k nearest non-deleted points as `(internal ID, distance)`.
+    ///
+    /// `exhaustive` selects a linear scan instead of graph traversal.
+    fn search(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        deleted: &HashSet<usize>,
+        exhaustive: bool,
+    ) -> Vec<(usize, f32)> {
+        if self.len() == 0 {
+            return Vec::new();
+        }
+
+        if exhaustive {
+            // Linear scan: iterate all stored vectors and compute exact distances.
+            // Guarantees 100% recall for small collections where HNSW's layer
+            // fragmentation causes missed results.
+            let dist_fn = DistCosine;
+            let mut all_distances: Vec<(usize, f32)> = Vec::with_capacity(self.len());
+            for point in self.graph.get_point_indexation().into_iter() {
+                let origin_id = point.get_origin_id();
+                if deleted.contains(&origin_id) {
+                    continue;
+                }
+                all_distances.push((origin_id, dist_fn.eval(query, point.get_v())));
+            }
+            all_distances
+                .sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            all_distances.truncate(k);
+            return all_distances;
+        }
+
+        let filter_fn = |id: &usize| -> bool { !deleted.contains(id) };
+        let results = if deleted.is_empty() {
+            self.graph.search(query, k, ef_search)
+        } else {
+            self.graph
+                .search_filter(query, k, ef_search, Some(&filter_fn))
+        };
+        results.into_iter().map(|n| (n.d_id, n.distance)).collect()
+    }
 }
 
 /// Internal mutable state for ID mapping and soft-deletion.
@@ -104,6 +277,72 @@ pub(crate) struct IndexMetadata {
     /// are reassigned sequentially on rebuild. Using UUIDs ensures the
     /// correct experiences are marked as deleted after rebuild.
     pub(crate) deleted: Vec<String>,
+    /// Save generation, incremented by every `save_to_dir()`. Zero for
+    /// metadata written before generations existed and for snapshots.
+    #[serde(default)]
+    pub(crate) generation: u64,
+    /// Basenames of the graph dumps written with this generation.
+    #[serde(default)]
+    pub(crate) graphs: Vec<String>,
+    /// Size and checksum of every file of the graph dumps. Dumps saved
+    /// without checksums are never loaded.
+    #[serde(default)]
+    pub(crate) files: Vec<DumpFile>,
+}
+
+/// A graph dump file as recorded in [`IndexMetadata`].
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct DumpFile {
+    /// File name within the index directory.
+    pub(crate) name: String,
+    /// File size in bytes.
+    pub(crate) bytes: u64,
+    /// CRC32 of the file contents.
+    pub(crate) checksum: u32,
+}
+
+impl DumpFile {
+    /// Reads `dir/name` and records its size and checksum.
+    fn compute(dir: &Path, name: String) -> Result<Self> {
+        let bytes = fs::read(dir.join(&name)).map_err(|e| {
+            PulseDBError::vector(format!("Failed to read HNSW dump {}: {}", name, e))
+        })?;
+        Ok(Self {
+            bytes: bytes.len() as u64,
+            checksum: crc32fast::hash(&bytes),
+            name,
+        })
+    }
+}
+
+impl IndexMetadata {
+    /// Writes the metadata as pretty JSON.
+    ///
+    /// Writes and syncs a sibling `.tmp` file, then renames it over
+    /// `path`, so a crash never leaves truncated metadata behind.
+    pub(crate) fn write(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_vec_pretty(self).map_err(|e| {
+            PulseDBError::vector(format!("Failed to serialize HNSW metadata: {}", e))
+        })?;
+        let mut partial = path.as_os_str().to_owned();
+        partial.push(".tmp");
+        let partial = Path::new(&partial);
+        let written = fs::File::create(partial).and_then(|mut file| {
+            file.write_all(&json)?;
+            file.sync_all()
+        });
+        written
+            .and_then(|()| fs::rename(partial, path))
+            .map_err(|e| PulseDBError::vector(format!("Failed to write HNSW metadata: {}", e)))
+    }
+
+    /// Reads metadata written by [`write()`](Self::write).
+    pub(crate) fn read(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| PulseDBError::vector(format!("Failed to read HNSW metadata: {}", e)))?;
+        serde_json::from_str(&json)
+            .map_err(|e| PulseDBError::vector(format!("Failed to parse HNSW metadata: {}", e)))
+    }
 }
 
 impl HnswIndex {
@@ -114,17 +353,10 @@ impl HnswIndex {
     /// * `dimension` - Expected embedding dimension (validated on insert)
     /// * `config` - HNSW tuning parameters
     pub fn new(dimension: usize, config: &HnswConfig) -> Self {
-        let hnsw = Hnsw::new(
-            config.max_nb_connection,
-            config.max_elements,
-            config.max_layer,
-            config.ef_construction,
-            DistCosine,
-        );
-
         Self {
-            hnsw,
-            state: RwLock::new(IndexState {
+            segments: TimedRwLock::new(vec![Segment::new(config, segment_capacity(config))]),
+            merging: Mutex::new(()),
+            state: TimedRwLock::new(IndexState {
                 id_to_internal: HashMap::new(),
                 internal_to_id: Vec::new(),
                 deleted: HashSet::new(),
@@ -132,6 +364,7 @@ impl HnswIndex {
             }),
             config: config.clone(),
             dimension,
+            last_rebuild: None,
         }
     }
 
@@ -169,9 +402,74 @@ impl HnswIndex {
         // Drop the lock before calling hnsw insert (which acquires its own lock)
         drop(state);
 
-        // Insert into HNSW graph (uses interior mutability via parking_lot::RwLock)
-        self.hnsw.insert((embedding, internal_id));
+        self.insert_point(embedding, internal_id)
+    }
 
+    /// Inserts experience embeddings through
+    /// [`insert_batch`](VectorIndex::insert_batch).
+    ///
+    /// IDs are assigned under one lock; experiences already present are
+    /// skipped. Every embedding is checked before any is inserted.
+    pub fn insert_experiences(&self, items: &[(ExperienceId, &Vec<f32>)]) -> Result<()> {
+        if let Some((_, embedding)) = items.iter().find(|(_, e)| e.len() != self.dimension) {
+            return Err(PulseDBError::vector(format!(
+                "Embedding dimension mismatch: expected {}, got {}",
+                self.dimension,
+                embedding.len()
+            )));
+        }
+
+        let mut state = self
+            .state
+            .write()
+            .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
+        let mut batch = Vec::with_capacity(items.len());
+        for (exp_id, embedding) in items {
+            if state.id_to_internal.contains_key(exp_id) {
+                continue;
+            }
+            let internal_id = state.next_id;
+            state.next_id += 1;
+            state.id_to_internal.insert(*exp_id, internal_id);
+            state.internal_to_id.push(*exp_id);
+            batch.push((*embedding, internal_id));
+        }
+        drop(state);
+
+        self.insert_batch(&batch)
+    }
+
+    /// Inserts a point into the active segment, sealing it first if full.
+    fn insert_point(&self, embedding: &[f32], internal_id: usize) -> Result<()> {
+        {
+            let segments = self
+                .segments
+                .read()
+                .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+            if let Some(active) = segments
+                .last()
+                .filter(|s| s.len() < self.config.max_segment_size)
+            {
+                // Insert into HNSW graph (uses interior mutability via parking_lot::RwLock)
+                active.graph.insert((embedding, internal_id));
+                return Ok(());
+            }
+        }
+
+        let mut segments = self
+            .segments
+            .write()
+            .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+        if segments
+            .last()
+            .is_none_or(|s| s.len() >= self.config.max_segment_size)
+        {
+            segments.push(Segment::new(&self.config, segment_capacity(&self.config)));
+            tracing::debug!(segments = segments.len(), "Sealed HNSW segment");
+        }
+        if let Some(active) = segments.last() {
+            active.graph.insert((embedding, internal_id));
+        }
         Ok(())
     }
 
@@ -217,61 +515,150 @@ impl HnswIndex {
             .read()
             .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
 
-        let active_count = state.next_id - state.deleted.len();
+        let active_count = state.id_to_internal.len() - state.deleted.len();
         if active_count == 0 {
             return Ok(vec![]);
         }
         let effective_k = k.min(active_count);
+        let effective_ef = ef_search.max(effective_k);
 
-        if active_count <= BRUTE_FORCE_THRESHOLD {
-            // Linear scan: iterate all stored vectors and compute exact distances.
-            // Guarantees 100% recall for small collections where HNSW's layer
-            // fragmentation causes missed results.
-            let dist_fn = DistCosine;
-            let mut all_distances: Vec<(ExperienceId, f32)> = Vec::with_capacity(active_count);
-
-            for point in self.hnsw.get_point_indexation().into_iter() {
-                let origin_id = point.get_origin_id();
-                if state.deleted.contains(&origin_id) {
-                    continue;
-                }
-                let distance = dist_fn.eval(query, point.get_v());
-                if let Some(&exp_id) = state.internal_to_id.get(origin_id) {
-                    all_distances.push((exp_id, distance));
-                }
-            }
-
-            all_distances
-                .sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
-            all_distances.truncate(effective_k);
-            return Ok(all_distances);
-        }
+        let segments = self
+            .segments
+            .read()
+            .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+        let deleted = &state.deleted;
+        // Small indexes (and small segments) use brute force, see BRUTE_FORCE_THRESHOLD
+        let small_index = active_count <= BRUTE_FORCE_THRESHOLD;
+        let search_segment = |segment: &Segment| {
+            let exhaustive = small_index || segment.len() <= BRUTE_FORCE_THRESHOLD;
+            segment.search(query, effective_k, effective_ef, deleted, exhaustive)
+        };
 
-        // HNSW graph search for larger collections
-        let effective_ef = ef_search.max(effective_k);
-        let deleted_ref = &state.deleted;
-        let filter_fn = |id: &usize| -> bool { !deleted_ref.contains(id) };
-        let results = if state.deleted.is_empty() {
-            self.hnsw.search(query, effective_k, effective_ef)
+        let mut hits = if segments.len() == 1 {
+            search_segment(&segments[0])
         } else {
-            self.hnsw
-                .search_filter(query, effective_k, effective_ef, Some(&filter_fn))
+            // Segments are independent graphs: search them in parallel
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = segments
+                    .iter()
+                    .map(|segment| scope.spawn(|| search_segment(segment)))
+                    .collect();
+                let mut hits = Vec::new();
+                for handle in handles {
+                    hits.extend(
+                        handle
+                            .join()
+                            .map_err(|_| PulseDBError::vector("Segment search panicked"))?,
+                    );
+                }
+                Ok::<_, PulseDBError>(hits)
+            })?
         };
+        hits.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        hits.truncate(effective_k);
 
         // Map internal IDs back to ExperienceIds
-        let mapped: Vec<(ExperienceId, f32)> = results
+        let mapped: Vec<(ExperienceId, f32)> = hits
             .into_iter()
-            .filter_map(|n| {
+            .filter_map(|(id, distance)| {
                 state
                     .internal_to_id
-                    .get(n.d_id)
-                    .map(|&exp_id| (exp_id, n.distance))
+                    .get(id)
+                    .map(|&exp_id| (exp_id, distance))
             })
             .collect();
 
         Ok(mapped)
     }
 
+    /// Searches for the k nearest experiences among `allowed`, excluding
+    /// deleted ones.
+    ///
+    /// Runs a filtered graph traversal that only admits `allowed` points
+    /// as results. A very selective filter can strand the traversal, so
+    /// fewer than `k` hits may come back even when more qualify.
+    pub fn search_experiences_within(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        allowed: &HashSet<ExperienceId>,
+    ) -> Result<Vec<(ExperienceId, f32)>> {
+        if query.len() != self.dimension {
+            return Err(PulseDBError::vector(format!(
+                "Query dimension mismatch: expected {}, got {}",
+                self.dimension,
+                query.len()
+            )));
+        }
+
+        let state = self
+            .state
+            .read()
+            .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
+        let internal: HashSet<usize> = allowed
+            .iter()
+            .filter_map(|id| state.id_to_internal.get(id).copied())
+            .filter(|id| !state.deleted.contains(id))
+            .collect();
+        if internal.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let effective_k = k.min(internal.len());
+        let hits = self.search_graphs(query, effective_k, ef_search.max(effective_k), &|id| {
+            internal.contains(id)
+        })?;
+        Ok(hits
+            .into_iter()
+            .filter_map(|(id, distance)| {
+                state
+                    .internal_to_id
+                    .get(id)
+                    .map(|&exp_id| (exp_id, distance))
+            })
+            .collect())
+    }
+
+    /// Touches every stored vector and runs one graph traversal so the
+    /// pages backing the index are resident before the first real search.
+    ///
+    /// Returns the number of active vectors touched.
+    pub fn warm(&self) -> Result<usize> {
+        // Point iteration requires an entry point, which empty graphs lack
+        if self.total_count() == 0 {
+            return Ok(0);
+        }
+
+        let mut probe: Option<Vec<f32>> = None;
+        {
+            let segments = self
+                .segments
+                .read()
+                .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+            for segment in segments.iter().filter(|s| s.len() > 0) {
+                for point in segment.graph.get_point_indexation().into_iter() {
+                    let v = point.get_v();
+                    std::hint::black_box(v.iter().sum::<f32>());
+                    if probe.is_none() {
+                        probe = Some(v.to_vec());
+                    }
+                }
+            }
+        }
+
+        // Small indexes are searched by linear scan, which the walk above
+        // already covers; larger ones also get their layer links touched.
+        if let Some(query) = probe {
+            if self.active_count() > BRUTE_FORCE_THRESHOLD {
+                let results = self.search_experiences(&query, 1, self.config.ef_search)?;
+                std::hint::black_box(results);
+            }
+        }
+
+        Ok(self.active_count())
+    }
+
     /// Returns true if the given experience is in the index (and not deleted).
     pub fn contains(&self, exp_id: ExperienceId) -> bool {
         let state = self.state.read().ok();
@@ -290,7 +677,155 @@ impl HnswIndex {
 
     /// Returns the total number of vectors (including deleted).
     pub fn total_count(&self) -> usize {
-        self.hnsw.get_nb_point()
+        self.segments
+            .read()
+            .map_or(0, |segments| segments.iter().map(Segment::len).sum())
+    }
+
+    /// Returns the number of graph segments, including the active one.
+    pub fn segment_count(&self) -> usize {
+        self.segments.read().map_or(0, |segments| segments.len())
+    }
+
+    /// Returns a health snapshot of the index.
+    pub fn stats(&self) -> HnswStats {
+        let (total, segments, graph_levels) = self.segments.read().map_or((0, 0, 0), |segments| {
+            let total: usize = segments.iter().map(Segment::len).sum();
+            let levels = segments
+                .iter()
+                .filter(|segment| segment.len() > 0)
+                .map(|segment| u32::from(segment.graph.get_max_level_observed()) + 1)
+                .max()
+                .unwrap_or(0);
+            (total, segments.len(), levels)
+        });
+        let (active, deleted) = self.state.read().map_or((0, 0), |s| {
+            (s.id_to_internal.len() - s.deleted.len(), s.deleted.len())
+        });
+
+        // Each point holds its vector and up to 2 * M layer-0 neighbors
+        // (upper layers add a small fraction); each mapping an ID pair
+        let per_point = self.dimension * std::mem::size_of::<f32>()
+            + 2 * self.config.max_nb_connection * std::mem::size_of::<(usize, f32)>()
+            + 2 * std::mem::size_of::<ExperienceId>()
+            + std::mem::size_of::<usize>();
+
+        HnswStats {
+            active_vectors: active as u64,
+            deleted_vectors: deleted as u64,
+            segments: segments as u64,
+            graph_levels,
+            memory_bytes: (total * per_point) as u64,
+            last_rebuild: self.last_rebuild.map(|(at, _)| at),
+            last_rebuild_duration: self.last_rebuild.map(|(_, took)| took),
+        }
+    }
+
+    /// Merges one run of sealed segments, dropping soft-deleted vectors.
+    ///
+    /// Picks the oldest run of adjacent sealed segments whose live vectors
+    /// fit in one segment — or a single sealed segment with deletions —
+    /// and rebuilds it from its live vectors. The graph is built from a
+    /// copy of the run without holding the index locks, so searches and
+    /// inserts continue meanwhile; the merged segment is swapped in at the
+    /// end.
+    /// The active segment is never merged.
+    ///
+    /// Each call does a bounded amount of work. Call repeatedly, e.g. from
+    /// a maintenance thread, until it returns `false`.
+    pub fn merge_segments(&self) -> Result<bool> {
+        let _merging = self
+            .merging
+            .lock()
+            .map_err(|_| PulseDBError::vector("Merge lock poisoned"))?;
+
+        // Snapshot the run to merge. Sealed segments only change here, and
+        // merges are serialized, so their positions stay valid.
+        let (run, points) = {
+            let state = self
+                .state
+                .read()
+                .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
+            let segments = self
+                .segments
+                .read()
+                .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+            let sealed = &segments[..segments.len().saturating_sub(1)];
+            let live: Vec<usize> = sealed
+                .iter()
+                .map(|segment| {
+                    segment
+                        .ids()
+                        .iter()
+                        .filter(|id| !state.deleted.contains(id))
+                        .count()
+                })
+                .collect();
+
+            let mut run = None;
+            for start in 0..sealed.len() {
+                let mut end = start + 1;
+                let mut total = live[start];
+                while end < sealed.len() && total + live[end] <= self.config.max_segment_size {
+                    total += live[end];
+                    end += 1;
+                }
+                if end - start > 1 || live[start] < sealed[start].len() {
+                    run = Some(start..end);
+                    break;
+                }
+            }
+            let Some(run) = run else {
+                return Ok(false);
+            };
+            let points: Vec<(usize, Vec<f32>)> = sealed[run.clone()]
+                .iter()
+                .flat_map(Segment::points)
+                .collect();
+            (run, points)
+        };
+
+        // Rebuild from the live points, outside the locks
+        let (live, dropped): (Vec<_>, Vec<_>) = {
+            let state = self
+                .state
+                .read()
+                .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
+            points
+                .into_iter()
+                .partition(|(id, _)| !state.deleted.contains(id))
+        };
+        let merged = Segment::new(&self.config, live.len().max(1));
+        let batch: Vec<(&Vec<f32>, usize)> = live.iter().map(|(id, v)| (v, *id)).collect();
+        merged.graph.parallel_insert(&batch);
+
+        let segments_merged = run.len();
+        self.segments
+            .write()
+            .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?
+            .splice(run, [merged]);
+
+        // Dropped vectors are gone from the graph; forget them
+        let mut state = self
+            .state
+            .write()
+            .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
+        for (id, _) in &dropped {
+            state.deleted.remove(id);
+            if let Some(exp_id) = state.internal_to_id.get(*id).copied() {
+                if state.id_to_internal.get(&exp_id) == Some(id) {
+                    state.id_to_internal.remove(&exp_id);
+                }
+            }
+        }
+
+        tracing::debug!(
+            segments = segments_merged,
+            live = live.len(),
+            dropped = dropped.len(),
+            "Merged HNSW segments"
+        );
+        Ok(true)
     }
 
     /// Restores the deleted set from persisted metadata.
@@ -320,21 +855,95 @@ impl HnswIndex {
 
     /// Saves index metadata to a JSON file.
     ///
-    /// Creates `{dir}/{name}.hnsw.meta` with ID mappings and deleted set.
-    /// Also attempts to save the HNSW graph via `file_dump` for future
-    /// optimization (graph loading is not yet implemented due to lifetime
-    /// constraints in hnsw_rs).
+    /// Writes a new generation (see the module docs): the HNSW graphs are
+    /// dumped via `file_dump` and checksummed, then
+    /// `{dir}/{name}.hnsw.meta` with ID mappings, deleted set, dump names
+    /// and checksums is atomically replaced, then stale dumps are removed.
     pub fn save_to_dir(&self, dir: &Path, name: &str) -> Result<()> {
         fs::create_dir_all(dir)
             .map_err(|e| PulseDBError::vector(format!("Failed to create HNSW directory: {}", e)))?;
 
+        let meta_path = dir.join(format!("{}.hnsw.meta", name));
+        let generation = IndexMetadata::read(&meta_path).map_or(0, |old| old.generation) + 1;
+
         let state = self
             .state
             .read()
             .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
+        let mut metadata = self.metadata(&state);
+        metadata.generation = generation;
+        drop(state);
+
+        // Dump the HNSW graphs so the next open can load instead of rebuild
+        let segments = self
+            .segments
+            .read()
+            .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+        for (i, segment) in segments.iter().enumerate() {
+            if segment.len() == 0 {
+                continue;
+            }
+            let basename = format!("{}.g{}.seg{}", name, generation, i);
+            let dumped = dump_graph(&segment.graph, dir, &basename).and_then(|basename| {
+                for ext in ["hnsw.graph", "hnsw.data"] {
+                    let file = DumpFile::compute(dir, format!("{}.{}", basename, ext))?;
+                    metadata.files.push(file);
+                }
+                Ok(basename)
+            });
+            match dumped {
+                Ok(basename) => metadata.graphs.push(basename),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to dump HNSW graph (non-fatal, will rebuild on next open)");
+                    metadata.graphs.clear();
+                    metadata.files.clear();
+                    break;
+                }
+            }
+        }
+        drop(segments);
 
-        // Build metadata
-        let metadata = IndexMetadata {
+        metadata.write(&meta_path)?;
+        remove_graph_dumps(dir, name, &metadata.graphs);
+        Ok(())
+    }
+
+    /// Loads index metadata from a JSON file.
+    ///
+    /// Returns the metadata needed to reload the graphs with
+    /// [`load_from_dir()`](Self::load_from_dir), or to restore the deleted
+    /// set after a rebuild.
+    ///
+    /// Graph dumps are validated against the metadata's generation; if any
+    /// listed dump is missing or belongs to another generation, `graphs`
+    /// is cleared so the dumps are never mistaken for the saved index.
+    pub(crate) fn load_metadata(dir: &Path, name: &str) -> Result<Option<IndexMetadata>> {
+        let meta_path = dir.join(format!("{}.hnsw.meta", name));
+        if !meta_path.exists() {
+            return Ok(None);
+        }
+        let mut metadata = IndexMetadata::read(&meta_path)?;
+        let prefix = format!("{}.g{}.", name, metadata.generation);
+        let valid = metadata.graphs.iter().all(|basename| {
+            basename.starts_with(&prefix)
+                && ["hnsw.graph", "hnsw.data"]
+                    .iter()
+                    .all(|ext| dir.join(format!("{}.{}", basename, ext)).is_file())
+        });
+        if !valid {
+            tracing::warn!(
+                index = name,
+                generation = metadata.generation,
+                "HNSW graph dumps do not match their metadata generation, ignoring them"
+            );
+            metadata.graphs.clear();
+        }
+        Ok(Some(metadata))
+    }
+
+    /// Builds the persistable metadata for the current state.
+    fn metadata(&self, state: &IndexState) -> IndexMetadata {
+        IndexMetadata {
             dimension: self.dimension,
             next_id: state.next_id,
             id_map: state
@@ -352,61 +961,260 @@ impl HnswIndex {
                         .map(|exp_id| exp_id.to_string())
                 })
                 .collect(),
-        };
-
-        // Write metadata as JSON
-        let meta_path = dir.join(format!("{}.hnsw.meta", name));
-        let json = serde_json::to_string_pretty(&metadata).map_err(|e| {
-            PulseDBError::vector(format!("Failed to serialize HNSW metadata: {}", e))
-        })?;
-        fs::write(&meta_path, json)
-            .map_err(|e| PulseDBError::vector(format!("Failed to write HNSW metadata: {}", e)))?;
+            generation: 0,
+            graphs: Vec::new(),
+            files: Vec::new(),
+        }
+    }
 
-        // Also dump the HNSW graph (for future direct-load optimization)
-        if state.id_to_internal.is_empty() {
-            return Ok(());
+    /// Loads the graphs saved by [`save_to_dir()`](Self::save_to_dir).
+    ///
+    /// `metadata` comes from [`load_metadata()`](Self::load_metadata).
+    /// Every dump file is checked against its recorded size and CRC32,
+    /// and the graphs must have been built with `config`'s connectivity
+    /// and construction parameters; otherwise an error is returned and the
+    /// caller should rebuild. The result reflects the index as of the
+    /// save, so callers reconcile it against stored embeddings.
+    ///
+    /// Unlike [`from_dumped_segments()`](Self::from_dumped_segments), a
+    /// last segment with room left stays active, so repeated open/close
+    /// cycles don't pile up small sealed segments.
+    pub(crate) fn load_from_dir(
+        config: &HnswConfig,
+        dir: &Path,
+        metadata: &IndexMetadata,
+    ) -> Result<Self> {
+        if metadata.graphs.is_empty() {
+            return Err(PulseDBError::vector("No HNSW graph dumps to load"));
+        }
+        for basename in &metadata.graphs {
+            for ext in ["hnsw.graph", "hnsw.data"] {
+                let name = format!("{}.{}", basename, ext);
+                let expected = metadata
+                    .files
+                    .iter()
+                    .find(|file| file.name == name)
+                    .ok_or_else(|| {
+                        PulseDBError::vector(format!("No checksum recorded for HNSW dump {}", name))
+                    })?;
+                if DumpFile::compute(dir, name.clone())? != *expected {
+                    return Err(PulseDBError::vector(format!(
+                        "HNSW dump {} failed checksum verification",
+                        name
+                    )));
+                }
+            }
         }
-        drop(state);
 
-        if let Err(e) = self.hnsw.file_dump(dir, name) {
-            tracing::warn!(error = %e, "Failed to dump HNSW graph (non-fatal, will rebuild on next open)");
+        let index = Self::from_dumped_segments(config, dir, metadata, &metadata.graphs)?;
+        let mut segments = index
+            .segments
+            .write()
+            .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+        let sealed = &segments[..segments.len() - 1];
+        if sealed.iter().any(|segment| {
+            usize::from(segment.graph.get_max_nb_connection()) != config.max_nb_connection
+                || segment.graph.get_ef_construction() != config.ef_construction
+        }) {
+            return Err(PulseDBError::vector(
+                "HNSW graph dumps were built with different parameters",
+            ));
+        }
+        if sealed
+            .last()
+            .is_some_and(|segment| segment.len() < config.max_segment_size)
+        {
+            segments.pop();
         }
+        drop(segments);
+        Ok(index)
+    }
 
-        Ok(())
+    /// Dumps every non-empty segment's graph into `dir`.
+    ///
+    /// Returns the metadata and the dump basenames (`seg{i}`), captured
+    /// under the same locks so they describe the same points. Used by
+    /// index snapshots, which reload the graphs with
+    /// [`from_dumped_segments()`](Self::from_dumped_segments) instead of
+    /// rebuilding them.
+    pub(crate) fn dump_segments(&self, dir: &Path) -> Result<(IndexMetadata, Vec<String>)> {
+        let state = self
+            .state
+            .read()
+            .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
+        let segments = self
+            .segments
+            .read()
+            .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+
+        let mut basenames = Vec::new();
+        for (i, segment) in segments.iter().enumerate() {
+            if segment.len() == 0 {
+                continue;
+            }
+            basenames.push(dump_graph(&segment.graph, dir, &format!("seg{}", i))?);
+        }
+        Ok((self.metadata(&state), basenames))
     }
 
-    /// Loads index metadata from a JSON file.
+    /// Loads an index from graphs written by [`dump_segments()`](Self::dump_segments).
     ///
-    /// Returns the metadata needed to rebuild the graph. The caller must
-    /// create a new `HnswIndex` and re-insert embeddings using the
-    /// stored ID mappings.
-    #[allow(dead_code)] // Used in Step 4 (db.rs open/close lifecycle)
-    pub(crate) fn load_metadata(dir: &Path, name: &str) -> Result<Option<IndexMetadata>> {
-        let meta_path = dir.join(format!("{}.hnsw.meta", name));
-        if !meta_path.exists() {
-            return Ok(None);
+    /// The loaded graphs become sealed segments behind a fresh active one.
+    /// Mappings whose point is in none of the graphs (an insert racing the
+    /// dump) are dropped, so callers should reconcile the result against
+    /// stored embeddings.
+    ///
+    /// hnsw_rs ties a reloaded graph's lifetime to its loader, so each
+    /// loader is leaked. Without mmap the loader owns no point data; the
+    /// leak is a few hundred bytes per segment per load.
+    pub(crate) fn from_dumped_segments(
+        config: &HnswConfig,
+        dir: &Path,
+        metadata: &IndexMetadata,
+        basenames: &[String],
+    ) -> Result<Self> {
+        let mut segments = Vec::with_capacity(basenames.len() + 1);
+        let mut present = HashSet::new();
+        for basename in basenames {
+            let loader: &'static mut HnswIo = Box::leak(Box::new(HnswIo::new(dir, basename)));
+            let graph = loader
+                .load_hnsw::<f32, DistCosine>()
+                .map_err(|e| PulseDBError::vector(format!("Failed to load HNSW graph: {}", e)))?;
+            let segment = Segment { graph };
+            present.extend(segment.ids());
+            segments.push(segment);
         }
+        segments.push(Segment::new(config, segment_capacity(config)));
 
-        let json = fs::read_to_string(&meta_path)
-            .map_err(|e| PulseDBError::vector(format!("Failed to read HNSW metadata: {}", e)))?;
-        let metadata: IndexMetadata = serde_json::from_str(&json)
-            .map_err(|e| PulseDBError::vector(format!("Failed to parse HNSW metadata: {}", e)))?;
+        let mut state = IndexState {
+            id_to_internal: HashMap::new(),
+            internal_to_id: vec![ExperienceId::nil(); metadata.next_id],
+            deleted: HashSet::new(),
+            next_id: metadata.next_id,
+        };
+        for (exp_id_str, internal_id) in &metadata.id_map {
+            if !present.contains(internal_id) {
+                continue;
+            }
+            let exp_id = parse_experience_id(exp_id_str)?;
+            let slot = state.internal_to_id.get_mut(*internal_id).ok_or_else(|| {
+                PulseDBError::vector(format!(
+                    "Internal ID {} out of range in HNSW metadata",
+                    internal_id
+                ))
+            })?;
+            *slot = exp_id;
+            state.id_to_internal.insert(exp_id, *internal_id);
+        }
+        for exp_id_str in &metadata.deleted {
+            let exp_id = parse_experience_id(exp_id_str)?;
+            if let Some(&internal_id) = state.id_to_internal.get(&exp_id) {
+                state.deleted.insert(internal_id);
+            }
+        }
 
-        Ok(Some(metadata))
+        Ok(Self {
+            segments: TimedRwLock::new(segments),
+            merging: Mutex::new(()),
+            state: TimedRwLock::new(state),
+            config: config.clone(),
+            dimension: metadata.dimension,
+            last_rebuild: None,
+        })
+    }
+
+    /// Returns the waits on the segment list and metadata locks.
+    pub(crate) fn lock_waits(&self) -> LockWaitStats {
+        let mut waits = self.segments.waits();
+        waits.merge(&self.state.waits());
+        waits
+    }
+
+    /// Returns the IDs of every active (non-deleted) experience.
+    pub(crate) fn experience_ids(&self) -> Vec<ExperienceId> {
+        self.state.read().map_or_else(
+            |_| Vec::new(),
+            |state| {
+                state
+                    .id_to_internal
+                    .iter()
+                    .filter(|(_, id)| !state.deleted.contains(id))
+                    .map(|(&exp_id, _)| exp_id)
+                    .collect()
+            },
+        )
+    }
+
+    /// Calls `f` with every active (non-deleted) experience and the vector
+    /// the graph holds for it.
+    pub(crate) fn for_each_vector(
+        &self,
+        mut f: impl FnMut(ExperienceId, &[f32]) -> Result<()>,
+    ) -> Result<()> {
+        let state = self
+            .state
+            .read()
+            .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
+        let segments = self
+            .segments
+            .read()
+            .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+        // Point iteration requires an entry point, which empty graphs lack
+        for segment in segments.iter().filter(|s| s.len() > 0) {
+            for point in segment.graph.get_point_indexation().into_iter() {
+                let internal_id = point.get_origin_id();
+                if state.deleted.contains(&internal_id) {
+                    continue;
+                }
+                if let Some(&exp_id) = state.internal_to_id.get(internal_id) {
+                    if state.id_to_internal.get(&exp_id) == Some(&internal_id) {
+                        f(exp_id, point.get_v())?;
+                    }
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Rebuilds an index from a set of embeddings.
     ///
     /// Used during `PulseDB::open()` to reconstruct the HNSW graph
     /// from embeddings stored in redb (the source of truth).
+    ///
+    /// Collectives that outgrew `config.max_elements` are allocated for
+    /// their actual size, so the rebuilt graph doesn't reallocate its
+    /// layer tables during the bulk insert.
     pub fn rebuild_from_embeddings(
         dimension: usize,
         config: &HnswConfig,
         embeddings: Vec<(ExperienceId, Vec<f32>)>,
     ) -> Result<Self> {
-        let index = Self::new(dimension, config);
+        Self::rebuild_within(
+            dimension,
+            config,
+            embeddings,
+            &Deadline::unbounded(TimedOperation::Rebuild),
+        )
+    }
+
+    /// Like [`rebuild_from_embeddings`](Self::rebuild_from_embeddings),
+    /// checking `deadline` between batches of inserts.
+    pub(crate) fn rebuild_within(
+        dimension: usize,
+        config: &HnswConfig,
+        embeddings: Vec<(ExperienceId, Vec<f32>)>,
+        deadline: &Deadline,
+    ) -> Result<Self> {
+        let started_at = Timestamp::now();
+        let start = Instant::now();
+        let sized = HnswConfig {
+            max_elements: config.max_elements.max(embeddings.len()),
+            ..config.clone()
+        };
+        let mut index = Self::new(dimension, &sized);
 
         if embeddings.is_empty() {
+            index.last_rebuild = Some((started_at, start.elapsed()));
             return Ok(index);
         }
 
@@ -428,35 +1236,133 @@ impl HnswIndex {
 
         drop(state);
 
-        // Parallel bulk insert (uses rayon internally)
-        index.hnsw.parallel_insert(&batch);
+        // Parallel bulk insert (uses rayon internally), one segment at a time
+        {
+            let mut segments = index
+                .segments
+                .write()
+                .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+            for (i, chunk) in batch.chunks(sized.max_segment_size).enumerate() {
+                if i > 0 {
+                    segments.push(Segment::new(&sized, chunk.len()));
+                }
+                if let Some(segment) = segments.last() {
+                    for batch in chunk.chunks(REBUILD_CHECK_BATCH) {
+                        deadline.check()?;
+                        segment.graph.parallel_insert(batch);
+                    }
+                }
+            }
+        }
 
+        index.last_rebuild = Some((started_at, start.elapsed()));
         Ok(index)
     }
 
+    /// Searches every segment's graph, admitting only points `filter`
+    /// accepts, and merges the hits closest first.
+    ///
+    /// Deleted points are not excluded here; callers fold the deleted set
+    /// into `filter`.
+    fn search_graphs(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        filter: &(dyn Fn(&usize) -> bool + Sync),
+    ) -> Result<Vec<(usize, f32)>> {
+        // Wrap the dyn Fn trait object in FilterBridge to satisfy hnsw_rs's
+        // FilterT requirement (trait objects can't auto-coerce between traits)
+        let bridge = FilterBridge(filter);
+        let segments = self
+            .segments
+            .read()
+            .map_err(|_| PulseDBError::vector("Segments lock poisoned"))?;
+        let mut hits: Vec<(usize, f32)> = segments
+            .iter()
+            .filter(|s| s.len() > 0)
+            .flat_map(|s| s.graph.search_filter(query, k, ef_search, Some(&bridge)))
+            .map(|n| (n.d_id, n.distance))
+            .collect();
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+        hits.truncate(k);
+        Ok(hits)
+    }
+
     /// Removes HNSW files for a collective from disk.
     pub fn remove_files(dir: &Path, name: &str) -> Result<()> {
-        // Remove metadata file
+        // Remove metadata file (and any half-written one)
         let meta_path = dir.join(format!("{}.hnsw.meta", name));
         if meta_path.exists() {
             fs::remove_file(&meta_path).map_err(|e| {
                 PulseDBError::vector(format!("Failed to remove HNSW metadata: {}", e))
             })?;
         }
+        let _ = fs::remove_file(dir.join(format!("{}.hnsw.meta.tmp", name)));
 
-        // Remove graph dump files (hnsw_rs creates files with the name as prefix)
-        if let Ok(entries) = fs::read_dir(dir) {
-            for entry in entries.flatten() {
-                let file_name = entry.file_name();
-                let file_str = file_name.to_string_lossy();
-                if file_str.starts_with(name) && file_str.contains("hnswdump") {
-                    let _ = fs::remove_file(entry.path());
-                }
-            }
+        remove_graph_dumps(dir, name, &[]);
+        Ok(())
+    }
+}
+
+/// Removes `name`'s graph dump files except those of the `keep` basenames.
+///
+/// Covers every generation, dumps left by an interrupted save, and the
+/// untagged names used before generations existed.
+fn remove_graph_dumps(dir: &Path, name: &str, keep: &[String]) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let prefix = format!("{}.", name);
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let file_str = file_name.to_string_lossy();
+        let Some(basename) = file_str
+            .strip_suffix(".hnsw.graph")
+            .or_else(|| file_str.strip_suffix(".hnsw.data"))
+        else {
+            continue;
+        };
+        let ours = basename == name || basename.starts_with(&prefix);
+        if ours && !keep.iter().any(|k| k == basename) {
+            let _ = fs::remove_file(entry.path());
         }
+    }
+}
 
-        Ok(())
+/// Dumps a graph as `{basename}.hnsw.{graph,data}`, replacing earlier
+/// files. Returns the basename written.
+///
+/// Reloaded graphs refuse to overwrite existing dumps and pick a random
+/// basename instead, so stale files are removed first.
+fn dump_graph(
+    graph: &Hnsw<'static, f32, DistCosine>,
+    dir: &Path,
+    basename: &str,
+) -> Result<String> {
+    for ext in ["hnsw.graph", "hnsw.data"] {
+        let path = dir.join(format!("{}.{}", basename, ext));
+        if path.exists() {
+            fs::remove_file(path).map_err(|e| {
+                PulseDBError::vector(format!("Failed to remove old HNSW dump: {}", e))
+            })?;
+        }
     }
+    graph
+        .file_dump(dir, basename)
+        .map_err(|e| PulseDBError::vector(format!("Failed to dump HNSW graph: {}", e)))
+}
+
+/// Parses an `ExperienceId` from its UUID string form.
+fn parse_experience_id(s: &str) -> Result<ExperienceId> {
+    let uuid = uuid::Uuid::parse_str(s)
+        .map_err(|e| PulseDBError::vector(format!("Invalid UUID in HNSW metadata: {}", e)))?;
+    Ok(ExperienceId::from_bytes(*uuid.as_bytes()))
+}
+
+/// Pre-allocated capacity of a new segment.
+fn segment_capacity(config: &HnswConfig) -> usize {
+    config.max_elements.min(config.max_segment_size)
 }
 
 // ==========================================================================
@@ -472,18 +1378,18 @@ impl VectorIndex for HnswIndex {
                 embedding.len()
             )));
         }
-        self.hnsw.insert((embedding, id));
-        Ok(())
+        self.insert_point(embedding, id)
     }
 
     fn insert_batch(&self, items: &[(&Vec<f32>, usize)]) -> Result<()> {
-        self.hnsw.parallel_insert(items);
+        for (embedding, id) in items {
+            self.insert_point(embedding, *id)?;
+        }
         Ok(())
     }
 
     fn search(&self, query: &[f32], k: usize, ef_search: usize) -> Result<Vec<(usize, f32)>> {
-        let results = self.hnsw.search(query, k, ef_search);
-        Ok(results.into_iter().map(|n| (n.d_id, n.distance)).collect())
+        self.search_filtered(query, k, ef_search, &|_| true)
     }
 
     fn search_filtered(
@@ -493,11 +1399,14 @@ impl VectorIndex for HnswIndex {
         ef_search: usize,
         filter: &(dyn Fn(&usize) -> bool + Sync),
     ) -> Result<Vec<(usize, f32)>> {
-        // Wrap the dyn Fn trait object in FilterBridge to satisfy hnsw_rs's
-        // FilterT requirement (trait objects can't auto-coerce between traits)
-        let bridge = FilterBridge(filter);
-        let results = self.hnsw.search_filter(query, k, ef_search, Some(&bridge));
-        Ok(results.into_iter().map(|n| (n.d_id, n.distance)).collect())
+        let state = self
+            .state
+            .read()
+            .map_err(|_| PulseDBError::vector("Index state lock poisoned"))?;
+        let deleted = &state.deleted;
+        self.search_graphs(query, k, ef_search, &|id| {
+            !deleted.contains(id) && filter(id)
+        })
     }
 
     fn delete(&self, id: usize) -> Result<()> {
@@ -516,8 +1425,11 @@ impl VectorIndex for HnswIndex {
             .is_some_and(|s| s.deleted.contains(&id))
     }
 
+    /// Counts graph points, so IDs inserted through this trait are
+    /// included alongside those from the experience API.
     fn len(&self) -> usize {
-        self.active_count()
+        let deleted = self.state.read().map_or(0, |s| s.deleted.len());
+        self.total_count().saturating_sub(deleted)
     }
 
     fn save(&self, dir: &Path, name: &str) -> Result<()> {
@@ -541,6 +1453,8 @@ mod tests {
             ef_search: 50,
             max_layer: 8,
             max_elements: 1000,
+            max_segment_size: 1_000_000,
+            warm_after_rebuild: false,
         }
     }
 
@@ -691,6 +1605,146 @@ mod tests {
         assert!(!results.is_empty());
     }
 
+    #[test]
+    fn test_warm_reports_active_vectors() {
+        let dim = 8;
+        let config = test_config();
+        assert_eq!(HnswIndex::new(dim, &config).warm().unwrap(), 0);
+
+        // Above the brute-force threshold so the graph traversal runs too
+        let embeddings: Vec<(ExperienceId, Vec<f32>)> = (0..200u64)
+            .map(|i| (ExperienceId::new(), make_embedding(i, dim)))
+            .collect();
+        let deleted = embeddings[0].0;
+        let index = HnswIndex::rebuild_from_embeddings(dim, &config, embeddings).unwrap();
+        index.delete_experience(deleted).unwrap();
+
+        assert_eq!(index.warm().unwrap(), 199);
+    }
+
+    #[test]
+    fn test_insert_beyond_max_elements() {
+        let dim = 8;
+        let config = HnswConfig {
+            max_elements: 16,
+            ..test_config()
+        };
+        let index = HnswIndex::new(dim, &config);
+
+        // Past capacity and past the brute-force threshold, so the graph is searched
+        let ids: Vec<ExperienceId> = (0..300u64)
+            .map(|i| {
+                let id = ExperienceId::new();
+                index
+                    .insert_experience(id, &make_embedding(i, dim))
+                    .unwrap();
+                id
+            })
+            .collect();
+        assert_eq!(index.active_count(), 300);
+
+        let results = index
+            .search_experiences(&make_embedding(250, dim), 5, 50)
+            .unwrap();
+        assert!(results.iter().any(|(id, _)| *id == ids[250]));
+
+        let embeddings = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (*id, make_embedding(i as u64, dim)))
+            .collect();
+        let rebuilt = HnswIndex::rebuild_from_embeddings(dim, &config, embeddings).unwrap();
+        assert_eq!(rebuilt.active_count(), 300);
+    }
+
+    #[test]
+    fn test_segments_seal_and_search_across() {
+        let dim = 8;
+        let config = HnswConfig {
+            max_segment_size: 100,
+            ..test_config()
+        };
+        let index = HnswIndex::new(dim, &config);
+        let ids: Vec<ExperienceId> = (0..250u64)
+            .map(|i| {
+                let id = ExperienceId::new();
+                index
+                    .insert_experience(id, &make_embedding(i, dim))
+                    .unwrap();
+                id
+            })
+            .collect();
+        assert_eq!(index.segment_count(), 3);
+        assert_eq!(index.total_count(), 250);
+
+        // Hits come from every segment, closest first
+        for seed in [10u64, 150, 240] {
+            let results = index
+                .search_experiences(&make_embedding(seed, dim), 5, 50)
+                .unwrap();
+            assert!(results.iter().any(|(id, _)| *id == ids[seed as usize]));
+            for w in results.windows(2) {
+                assert!(w[0].1 <= w[1].1);
+            }
+        }
+
+        // Rebuilds chunk into the same segment size
+        let embeddings = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (*id, make_embedding(i as u64, dim)))
+            .collect();
+        let rebuilt = HnswIndex::rebuild_from_embeddings(dim, &config, embeddings).unwrap();
+        assert_eq!(rebuilt.segment_count(), 3);
+        assert_eq!(rebuilt.active_count(), 250);
+    }
+
+    #[test]
+    fn test_merge_segments_drops_deleted() {
+        let dim = 8;
+        let config = HnswConfig {
+            max_segment_size: 100,
+            ..test_config()
+        };
+        let index = HnswIndex::new(dim, &config);
+        let ids: Vec<ExperienceId> = (0..250u64)
+            .map(|i| {
+                let id = ExperienceId::new();
+                index
+                    .insert_experience(id, &make_embedding(i, dim))
+                    .unwrap();
+                id
+            })
+            .collect();
+
+        // Nothing to merge while the sealed segments are full and clean
+        assert!(!index.merge_segments().unwrap());
+
+        // Half of each sealed segment deleted: one merge packs both into one
+        for id in ids.iter().take(200).step_by(2) {
+            index.delete_experience(*id).unwrap();
+        }
+        assert!(index.merge_segments().unwrap());
+        assert_eq!(index.segment_count(), 2);
+        assert_eq!(index.total_count(), 150);
+        assert_eq!(index.active_count(), 150);
+        assert!(!index.merge_segments().unwrap());
+
+        assert!(!index.contains(ids[0]));
+        assert!(index.contains(ids[1]));
+        let results = index
+            .search_experiences(&make_embedding(51, dim), 5, 50)
+            .unwrap();
+        assert!(results.iter().any(|(id, _)| *id == ids[51]));
+        assert!(results.iter().all(|(id, _)| *id != ids[50]));
+
+        // Inserts keep going to the active segment
+        index
+            .insert_experience(ExperienceId::new(), &make_embedding(7, dim))
+            .unwrap();
+        assert_eq!(index.active_count(), 151);
+    }
+
     #[test]
     fn test_rebuild_empty() {
         let dim = 384;
@@ -731,6 +1785,126 @@ mod tests {
         assert_eq!(metadata.deleted[0], exp_ids[2].to_string());
     }
 
+    #[test]
+    fn test_save_generations_replace_previous_dumps() {
+        let dim = 4;
+        // hnsw_rs only dumps graphs with the default layer count
+        let config = HnswConfig {
+            max_layer: HnswConfig::default().max_layer,
+            ..test_config()
+        };
+        let index = HnswIndex::new(dim, &config);
+        for i in 0..5u64 {
+            index
+                .insert_experience(ExperienceId::new(), &make_embedding(i, dim))
+                .unwrap();
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let files = || {
+            let mut names: Vec<String> = fs::read_dir(dir.path())
+                .unwrap()
+                .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+                .collect();
+            names.sort();
+            names
+        };
+
+        index.save_to_dir(dir.path(), "coll").unwrap();
+        index.save_to_dir(dir.path(), "coll").unwrap();
+        let metadata = HnswIndex::load_metadata(dir.path(), "coll")
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.generation, 2);
+        assert_eq!(metadata.graphs, vec!["coll.g2.seg0".to_string()]);
+        assert_eq!(
+            files(),
+            [
+                "coll.g2.seg0.hnsw.data",
+                "coll.g2.seg0.hnsw.graph",
+                "coll.hnsw.meta"
+            ]
+        );
+
+        // A crash mid-save leaves a partial metadata file and dumps of an
+        // uncommitted generation; neither is read
+        fs::write(dir.path().join("coll.hnsw.meta.tmp"), "{\"dimen").unwrap();
+        fs::write(dir.path().join("coll.g3.seg0.hnsw.graph"), b"partial").unwrap();
+        let metadata = HnswIndex::load_metadata(dir.path(), "coll")
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.generation, 2);
+        assert_eq!(metadata.graphs.len(), 1);
+
+        // Dumps missing for the committed generation are not trusted
+        fs::remove_file(dir.path().join("coll.g2.seg0.hnsw.data")).unwrap();
+        let metadata = HnswIndex::load_metadata(dir.path(), "coll")
+            .unwrap()
+            .unwrap();
+        assert!(metadata.graphs.is_empty());
+        assert_eq!(metadata.id_map.len(), 5);
+
+        HnswIndex::remove_files(dir.path(), "coll").unwrap();
+        assert!(files().is_empty());
+    }
+
+    #[test]
+    fn test_load_from_dir_verifies_dumps() {
+        let dim = 4;
+        // hnsw_rs only dumps graphs with the default layer count
+        let config = HnswConfig {
+            max_layer: HnswConfig::default().max_layer,
+            ..test_config()
+        };
+        let index = HnswIndex::new(dim, &config);
+        let ids: Vec<ExperienceId> = (0..20u64)
+            .map(|i| {
+                let id = ExperienceId::new();
+                index
+                    .insert_experience(id, &make_embedding(i, dim))
+                    .unwrap();
+                id
+            })
+            .collect();
+        index.delete_experience(ids[0]).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        index.save_to_dir(dir.path(), "coll").unwrap();
+        let metadata = HnswIndex::load_metadata(dir.path(), "coll")
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.files.len(), 2);
+
+        let loaded = HnswIndex::load_from_dir(&config, dir.path(), &metadata).unwrap();
+        assert_eq!(loaded.active_count(), 19);
+        assert!(!loaded.contains(ids[0]));
+        // The dumped segment had room, so it stays active
+        assert_eq!(loaded.segment_count(), 1);
+        let results = loaded
+            .search_experiences(&make_embedding(7, dim), 1, 50)
+            .unwrap();
+        assert_eq!(results[0].0, ids[7]);
+
+        // Graphs built with other parameters are not reused
+        let other = HnswConfig {
+            max_nb_connection: config.max_nb_connection + 8,
+            ..config.clone()
+        };
+        assert!(HnswIndex::load_from_dir(&other, dir.path(), &metadata).is_err());
+
+        // Neither are dumps without checksums, or with a mismatching one
+        let mut unchecked = HnswIndex::load_metadata(dir.path(), "coll")
+            .unwrap()
+            .unwrap();
+        unchecked.files.clear();
+        assert!(HnswIndex::load_from_dir(&config, dir.path(), &unchecked).is_err());
+        let graph = dir
+            .path()
+            .join(format!("{}.hnsw.graph", metadata.graphs[0]));
+        let mut bytes = fs::read(&graph).unwrap();
+        bytes[0] ^= 0xff;
+        fs::write(&graph, bytes).unwrap();
+        assert!(HnswIndex::load_from_dir(&config, dir.path(), &metadata).is_err());
+    }
+
     #[test]
     fn test_remove_files() {
         let dim = 4;
@@ -0,0 +1,685 @@
+//! Disk-backed IVF (inverted file) vector index.
+//!
+//! For collectives too large to hold as an in-memory HNSW graph. Vectors
+//! live in an append-only data file (`{name}.ivf`); memory holds only the
+//! router — one centroid per cluster and, per cluster, the positions of
+//! its records in the file.
+//!
+//! ```text
+//! query ──→ rank centroids ──→ nprobe closest clusters
+//!                                     │ read records from disk
+//!                                     ▼
+//!                         exact cosine distance ──→ top k
+//! ```
+//!
+//! Clusters are trained with spherical k-means once the index holds
+//! [`TRAIN_POINTS_PER_LIST`] vectors per cluster. Until then there is a
+//! single cluster and search is an exact scan. Later vectors join their
+//! nearest centroid without retraining; the index is rebuilt (and
+//! retrained) from redb embeddings on every open, like the HNSW graph.
+//!
+//! # File Format
+//!
+//! Fixed-size little-endian records; record `n` starts at byte
+//! `n * (8 + 4 * dimension)`:
+//!
+//! ```text
+//! ┌─────────────────┬──────────────────────────┐
+//! │ internal id u64 │ vector (dimension × f32) │
+//! └─────────────────┴──────────────────────────┘
+//! ```
+//!
+//! # Thread Safety
+//!
+//! The router is protected by a `std::sync::RwLock` and the data file by a
+//! `Mutex`, taken in that order. Searches run concurrently up to the point
+//! of reading records, which is serialized on the file handle.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use hnsw_rs::prelude::{DistCosine, Distance};
+
+use crate::config::IvfConfig;
+use crate::error::{PulseDBError, Result};
+use crate::metrics::{LockWaitStats, TimedMutex, TimedRwLock};
+use crate::types::ExperienceId;
+
+use super::VectorIndex;
+
+/// Vectors per cluster an index must hold before clusters are trained.
+///
+/// Fewer points per centroid leave k-means with too little to separate.
+pub const TRAIN_POINTS_PER_LIST: usize = 16;
+
+/// Lloyd iterations when training clusters.
+const KMEANS_ITERATIONS: usize = 10;
+
+/// Disk-backed inverted-file index.
+///
+/// Each collective using [`VectorIndexKind::Ivf`](crate::VectorIndexKind::Ivf)
+/// gets its own `IvfIndex` and data file.
+pub struct IvfIndex {
+    /// Append-only record file.
+    file: TimedMutex<File>,
+
+    /// Location of `file`, for diagnostics.
+    path: PathBuf,
+
+    /// Router and ID mappings.
+    state: TimedRwLock<IvfState>,
+
+    /// Immutable configuration.
+    config: IvfConfig,
+
+    /// Embedding dimension (must match all inserted vectors).
+    dimension: usize,
+}
+
+/// In-memory part of an [`IvfIndex`].
+struct IvfState {
+    /// Unit-length cluster centroids. Empty until trained.
+    centroids: Vec<Vec<f32>>,
+
+    /// Record numbers per cluster (a single list before training).
+    lists: Vec<Vec<u64>>,
+
+    /// ExperienceId → internal ID.
+    id_to_internal: HashMap<ExperienceId, usize>,
+
+    /// Internal ID → ExperienceId (index = internal ID).
+    internal_to_id: Vec<ExperienceId>,
+
+    /// Soft-deleted internal IDs.
+    deleted: HashSet<usize>,
+
+    /// Number of records in the data file.
+    records: u64,
+
+    /// Next internal ID to assign.
+    next_id: usize,
+}
+
+impl IvfState {
+    fn active_count(&self) -> usize {
+        (self.records as usize).saturating_sub(self.deleted.len())
+    }
+}
+
+impl IvfIndex {
+    /// Creates an empty index backed by `{dir}/{name}.ivf`.
+    ///
+    /// An existing data file is truncated: the index is always rebuilt
+    /// from redb embeddings rather than reloaded.
+    pub fn create(dimension: usize, config: &IvfConfig, dir: &Path, name: &str) -> Result<Self> {
+        fs::create_dir_all(dir).map_err(|e| {
+            PulseDBError::vector(format!("Failed to create index directory: {}", e))
+        })?;
+        let path = data_path(dir, name);
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .map_err(|e| PulseDBError::vector(format!("Failed to create IVF data file: {}", e)))?;
+
+        Ok(Self {
+            file: TimedMutex::new(file),
+            path,
+            state: TimedRwLock::new(IvfState {
+                centroids: Vec::new(),
+                lists: vec![Vec::new()],
+                id_to_internal: HashMap::new(),
+                internal_to_id: Vec::new(),
+                deleted: HashSet::new(),
+                records: 0,
+                next_id: 0,
+            }),
+            config: config.clone(),
+            dimension,
+        })
+    }
+
+    /// Inserts an experience embedding into the index.
+    ///
+    /// Appends the vector to the data file. If the ExperienceId is already
+    /// present, this is a no-op.
+    pub fn insert_experience(&self, exp_id: ExperienceId, embedding: &[f32]) -> Result<()> {
+        self.check_dimension("Embedding", embedding)?;
+
+        let mut state = self.write_state()?;
+        if state.id_to_internal.contains_key(&exp_id) {
+            return Ok(());
+        }
+
+        let internal_id = state.next_id;
+        state.next_id += 1;
+        state.id_to_internal.insert(exp_id, internal_id);
+        state.internal_to_id.push(exp_id);
+
+        self.add_point(&mut state, internal_id, embedding)
+    }
+
+    /// Inserts experience embeddings through
+    /// [`insert_batch`](VectorIndex::insert_batch).
+    ///
+    /// IDs are assigned under one lock; experiences already present are
+    /// skipped. Every embedding is checked before any is inserted.
+    pub fn insert_experiences(&self, items: &[(ExperienceId, &Vec<f32>)]) -> Result<()> {
+        for (_, embedding) in items {
+            self.check_dimension("Embedding", embedding)?;
+        }
+
+        let mut state = self.write_state()?;
+        let mut batch = Vec::with_capacity(items.len());
+        for (exp_id, embedding) in items {
+            if state.id_to_internal.contains_key(exp_id) {
+                continue;
+            }
+            let internal_id = state.next_id;
+            state.next_id += 1;
+            state.id_to_internal.insert(*exp_id, internal_id);
+            state.internal_to_id.push(*exp_id);
+            batch.push((*embedding, internal_id));
+        }
+        drop(state);
+
+        self.insert_batch(&batch)
+    }
+
+    /// Marks an experience as deleted in the index.
+    ///
+    /// The record stays in the data file until the next rebuild. Returns
+    /// Ok even if the experience is not in the index (idempotent).
+    pub fn delete_experience(&self, exp_id: ExperienceId) -> Result<()> {
+        let mut state = self.write_state()?;
+        if let Some(&internal_id) = state.id_to_internal.get(&exp_id) {
+            state.deleted.insert(internal_id);
+        }
+        Ok(())
+    }
+
+    /// Searches for the k nearest experiences, excluding deleted ones.
+    ///
+    /// Returns `(ExperienceId, distance)` pairs sorted by cosine distance
+    /// ascending. Reads the [`IvfConfig::nprobe`] closest clusters.
+    pub fn search_experiences(&self, query: &[f32], k: usize) -> Result<Vec<(ExperienceId, f32)>> {
+        self.check_dimension("Query", query)?;
+
+        let state = self.read_state()?;
+        let hits = self.search_points(&state, query, k, &|_| true)?;
+        Ok(hits
+            .into_iter()
+            .filter_map(|(id, distance)| {
+                state
+                    .internal_to_id
+                    .get(id)
+                    .map(|&exp_id| (exp_id, distance))
+            })
+            .collect())
+    }
+
+    /// Searches for the k nearest experiences among `allowed`, excluding
+    /// deleted ones. Only the [`IvfConfig::nprobe`] closest clusters are
+    /// read, so qualifying experiences in other clusters are missed.
+    pub fn search_experiences_within(
+        &self,
+        query: &[f32],
+        k: usize,
+        allowed: &HashSet<ExperienceId>,
+    ) -> Result<Vec<(ExperienceId, f32)>> {
+        self.check_dimension("Query", query)?;
+
+        let state = self.read_state()?;
+        let internal: HashSet<usize> = allowed
+            .iter()
+            .filter_map(|id| state.id_to_internal.get(id).copied())
+            .collect();
+        if internal.is_empty() {
+            return Ok(vec![]);
+        }
+        let hits = self.search_points(&state, query, k, &|id| internal.contains(id))?;
+        Ok(hits
+            .into_iter()
+            .filter_map(|(id, distance)| {
+                state
+                    .internal_to_id
+                    .get(id)
+                    .map(|&exp_id| (exp_id, distance))
+            })
+            .collect())
+    }
+
+    /// Reads the data file through once so the OS page cache holds as much
+    /// of it as fits.
+    ///
+    /// Returns the number of active vectors.
+    pub fn warm(&self) -> Result<usize> {
+        let state = self.read_state()?;
+        let mut file = self.lock_file()?;
+        file.seek(SeekFrom::Start(0))
+            .and_then(|_| std::io::copy(&mut *file, &mut std::io::sink()))
+            .map_err(|e| self.io_error("read", e))?;
+        Ok(state.active_count())
+    }
+
+    /// Returns true if the given experience is in the index (and not deleted).
+    pub fn contains(&self, exp_id: ExperienceId) -> bool {
+        let state = self.state.read().ok();
+        state.is_some_and(|s| {
+            s.id_to_internal
+                .get(&exp_id)
+                .is_some_and(|id| !s.deleted.contains(id))
+        })
+    }
+
+    /// Returns the number of active (non-deleted) vectors.
+    pub fn active_count(&self) -> usize {
+        self.state.read().map_or(0, |s| s.active_count())
+    }
+
+    /// Returns the number of vectors in the data file (including deleted).
+    pub fn total_count(&self) -> usize {
+        self.state.read().map_or(0, |s| s.records as usize)
+    }
+
+    /// Returns the number of trained clusters (0 before training).
+    pub fn cluster_count(&self) -> usize {
+        self.state.read().map_or(0, |s| s.centroids.len())
+    }
+
+    /// Flushes the data file to disk.
+    pub fn flush(&self) -> Result<()> {
+        self.lock_file()?
+            .sync_data()
+            .map_err(|e| self.io_error("sync", e))
+    }
+
+    /// Removes the IVF data file for a collective from disk.
+    pub fn remove_files(dir: &Path, name: &str) -> Result<()> {
+        let path = data_path(dir, name);
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| {
+                PulseDBError::vector(format!("Failed to remove IVF data file: {}", e))
+            })?;
+        }
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // Internals
+    // -------------------------------------------------------------------------
+
+    /// Appends a point and files it under its nearest cluster, training
+    /// clusters once enough points have arrived.
+    fn add_point(&self, state: &mut IvfState, internal_id: usize, embedding: &[f32]) -> Result<()> {
+        let record = state.records;
+        let mut bytes = Vec::with_capacity(self.record_size());
+        bytes.extend_from_slice(&(internal_id as u64).to_le_bytes());
+        for value in embedding {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        {
+            let mut file = self.lock_file()?;
+            file.seek(SeekFrom::Start(record * self.record_size() as u64))
+                .and_then(|_| file.write_all(&bytes))
+                .map_err(|e| self.io_error("write", e))?;
+        }
+        state.records += 1;
+
+        let list = nearest(&state.centroids, embedding).unwrap_or(0);
+        state.lists[list].push(record);
+
+        if state.centroids.is_empty()
+            && state.active_count() >= self.config.nlist * TRAIN_POINTS_PER_LIST
+        {
+            self.train(state)?;
+        }
+        Ok(())
+    }
+
+    /// Trains centroids on every live point and reassigns points to them.
+    fn train(&self, state: &mut IvfState) -> Result<()> {
+        let points = {
+            let mut file = self.lock_file()?;
+            let mut points = Vec::with_capacity(state.lists[0].len());
+            for &record in &state.lists[0] {
+                let (id, vector) = self.read_record(&mut file, record)?;
+                if !state.deleted.contains(&id) {
+                    points.push((record, vector));
+                }
+            }
+            points
+        };
+
+        let vectors: Vec<&[f32]> = points.iter().map(|(_, v)| v.as_slice()).collect();
+        let centroids = kmeans(&vectors, self.config.nlist);
+        let mut lists = vec![Vec::new(); centroids.len()];
+        for (record, vector) in &points {
+            lists[nearest(&centroids, vector).unwrap_or(0)].push(*record);
+        }
+
+        tracing::debug!(
+            clusters = centroids.len(),
+            vectors = points.len(),
+            "Trained IVF clusters"
+        );
+        state.centroids = centroids;
+        state.lists = lists;
+        Ok(())
+    }
+
+    /// Finds the k nearest live points accepted by `filter`.
+    fn search_points(
+        &self,
+        state: &IvfState,
+        query: &[f32],
+        k: usize,
+        filter: &dyn Fn(&usize) -> bool,
+    ) -> Result<Vec<(usize, f32)>> {
+        if k == 0 || state.active_count() == 0 {
+            return Ok(vec![]);
+        }
+
+        // Rank clusters by centroid distance and take the closest nprobe
+        let mut probed: Vec<usize> = (0..state.lists.len()).collect();
+        if !state.centroids.is_empty() {
+            probed.sort_by(|&a, &b| {
+                DistCosine
+                    .eval(query, &state.centroids[a])
+                    .total_cmp(&DistCosine.eval(query, &state.centroids[b]))
+            });
+            probed.truncate(self.config.nprobe);
+        }
+
+        // Read in file order to keep disk access sequential
+        let mut records: Vec<u64> = probed
+            .iter()
+            .flat_map(|&list| state.lists[list].iter().copied())
+            .collect();
+        records.sort_unstable();
+
+        let mut hits = Vec::with_capacity(records.len());
+        let mut file = self.lock_file()?;
+        for record in records {
+            let (id, vector) = self.read_record(&mut file, record)?;
+            if state.deleted.contains(&id) || !filter(&id) {
+                continue;
+            }
+            hits.push((id, DistCosine.eval(query, &vector)));
+        }
+        drop(file);
+
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+        hits.truncate(k);
+        Ok(hits)
+    }
+
+    fn read_record(&self, file: &mut File, record: u64) -> Result<(usize, Vec<f32>)> {
+        let mut bytes = vec![0u8; self.record_size()];
+        file.seek(SeekFrom::Start(record * self.record_size() as u64))
+            .and_then(|_| file.read_exact(&mut bytes))
+            .map_err(|e| self.io_error("read", e))?;
+
+        let (id, vector) = bytes.split_at(8);
+        let id = u64::from_le_bytes(id.try_into().expect("split at 8")) as usize;
+        let vector = vector
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes(c.try_into().expect("chunk of 4")))
+            .collect();
+        Ok((id, vector))
+    }
+
+    fn record_size(&self) -> usize {
+        8 + 4 * self.dimension
+    }
+
+    fn check_dimension(&self, what: &str, vector: &[f32]) -> Result<()> {
+        if vector.len() != self.dimension {
+            return Err(PulseDBError::vector(format!(
+                "{} dimension mismatch: expected {}, got {}",
+                what,
+                self.dimension,
+                vector.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the waits on the router and data file locks.
+    pub(crate) fn lock_waits(&self) -> LockWaitStats {
+        let mut waits = self.state.waits();
+        waits.merge(&self.file.waits());
+        waits
+    }
+
+    fn read_state(&self) -> Result<std::sync::RwLockReadGuard<'_, IvfState>> {
+        self.state
+            .read()
+            .map_err(|_| PulseDBError::vector("Index state lock poisoned"))
+    }
+
+    fn write_state(&self) -> Result<std::sync::RwLockWriteGuard<'_, IvfState>> {
+        self.state
+            .write()
+            .map_err(|_| PulseDBError::vector("Index state lock poisoned"))
+    }
+
+    fn lock_file(&self) -> Result<std::sync::MutexGuard<'_, File>> {
+        self.file
+            .lock()
+            .map_err(|_| PulseDBError::vector("IVF data file lock poisoned"))
+    }
+
+    fn io_error(&self, op: &str, e: std::io::Error) -> PulseDBError {
+        PulseDBError::vector(format!(
+            "Failed to {} IVF data file {}: {}",
+            op,
+            self.path.display(),
+            e
+        ))
+    }
+}
+
+/// Path of the data file for an index name.
+fn data_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{}.ivf", name))
+}
+
+/// Index of the centroid closest to `vector`, or `None` if there are none.
+fn nearest(centroids: &[Vec<f32>], vector: &[f32]) -> Option<usize> {
+    centroids
+        .iter()
+        .map(|c| DistCosine.eval(vector, c))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+/// Scales a vector to unit length (zero vectors are returned unchanged).
+fn normalized(vector: &[f32]) -> Vec<f32> {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return vector.to_vec();
+    }
+    vector.iter().map(|v| v / norm).collect()
+}
+
+/// Spherical k-means: up to `k` unit-length centroids for `vectors`.
+///
+/// Seeded with evenly spaced points so training is deterministic. A
+/// cluster that loses all its points keeps its previous centroid.
+fn kmeans(vectors: &[&[f32]], k: usize) -> Vec<Vec<f32>> {
+    let k = k.min(vectors.len());
+    if k == 0 {
+        return Vec::new();
+    }
+    let dimension = vectors[0].len();
+    let mut centroids: Vec<Vec<f32>> = (0..k)
+        .map(|i| normalized(vectors[i * vectors.len() / k]))
+        .collect();
+
+    for _ in 0..KMEANS_ITERATIONS {
+        let mut sums = vec![vec![0.0f32; dimension]; k];
+        let mut counts = vec![0usize; k];
+        for vector in vectors {
+            let c = nearest(&centroids, vector).unwrap_or(0);
+            counts[c] += 1;
+            for (sum, value) in sums[c].iter_mut().zip(normalized(vector)) {
+                *sum += value;
+            }
+        }
+        for (centroid, (sum, count)) in centroids.iter_mut().zip(sums.iter().zip(&counts)) {
+            if *count > 0 {
+                *centroid = normalized(sum);
+            }
+        }
+    }
+    centroids
+}
+
+// ==========================================================================
+// VectorIndex trait implementation
+// ==========================================================================
+
+impl VectorIndex for IvfIndex {
+    fn insert(&self, id: usize, embedding: &[f32]) -> Result<()> {
+        self.check_dimension("Embedding", embedding)?;
+        let mut state = self.write_state()?;
+        self.add_point(&mut state, id, embedding)
+    }
+
+    fn insert_batch(&self, items: &[(&Vec<f32>, usize)]) -> Result<()> {
+        for (embedding, id) in items {
+            self.insert(*id, embedding)?;
+        }
+        Ok(())
+    }
+
+    fn search(&self, query: &[f32], k: usize, ef_search: usize) -> Result<Vec<(usize, f32)>> {
+        self.search_filtered(query, k, ef_search, &|_| true)
+    }
+
+    /// `ef_search` is ignored; recall is set by [`IvfConfig::nprobe`].
+    fn search_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        _ef_search: usize,
+        filter: &(dyn Fn(&usize) -> bool + Sync),
+    ) -> Result<Vec<(usize, f32)>> {
+        self.check_dimension("Query", query)?;
+        let state = self.read_state()?;
+        self.search_points(&state, query, k, filter)
+    }
+
+    fn delete(&self, id: usize) -> Result<()> {
+        self.write_state()?.deleted.insert(id);
+        Ok(())
+    }
+
+    fn is_deleted(&self, id: usize) -> bool {
+        self.state
+            .read()
+            .ok()
+            .is_some_and(|s| s.deleted.contains(&id))
+    }
+
+    fn len(&self) -> usize {
+        self.active_count()
+    }
+
+    /// The data file is the only persisted state; this flushes it.
+    fn save(&self, _dir: &Path, _name: &str) -> Result<()> {
+        self.flush()
+    }
+}
+
+// ==========================================================================
+// Tests
+// ==========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn test_config(nlist: usize, nprobe: usize) -> IvfConfig {
+        IvfConfig { nlist, nprobe }
+    }
+
+    /// Generates a deterministic embedding from a seed.
+    /// Vectors with close seeds produce similar embeddings.
+    fn make_embedding(seed: u64, dim: usize) -> Vec<f32> {
+        (0..dim)
+            .map(|i| (seed as f32 * 0.1 + i as f32 * 0.01).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_untrained_search_is_exact() {
+        let dir = tempdir().unwrap();
+        let index = IvfIndex::create(8, &test_config(4, 1), dir.path(), "c").unwrap();
+        let ids: Vec<ExperienceId> = (0..20).map(|_| ExperienceId::new()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            index
+                .insert_experience(*id, &make_embedding(i as u64, 8))
+                .unwrap();
+        }
+        index
+            .insert_experience(ids[0], &make_embedding(0, 8))
+            .unwrap();
+
+        assert_eq!(index.cluster_count(), 0);
+        assert_eq!(index.active_count(), 20);
+        let results = index.search_experiences(&make_embedding(7, 8), 3).unwrap();
+        assert_eq!(results[0].0, ids[7]);
+        for w in results.windows(2) {
+            assert!(w[0].1 <= w[1].1, "Results not sorted by distance");
+        }
+        assert!(index.search_experiences(&[1.0; 4], 3).is_err());
+    }
+
+    #[test]
+    fn test_trains_clusters_and_honors_deletes() {
+        let dir = tempdir().unwrap();
+        let nlist = 4;
+        let index = IvfIndex::create(8, &test_config(nlist, nlist), dir.path(), "c").unwrap();
+        let ids: Vec<ExperienceId> = (0..100).map(|_| ExperienceId::new()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            index
+                .insert_experience(*id, &make_embedding(i as u64, 8))
+                .unwrap();
+        }
+        assert_eq!(index.cluster_count(), nlist);
+        assert_eq!(index.total_count(), 100);
+
+        // Probing every cluster is exact
+        let results = index.search_experiences(&make_embedding(90, 8), 1).unwrap();
+        assert_eq!(results[0].0, ids[90]);
+
+        index.delete_experience(ids[90]).unwrap();
+        assert!(!index.contains(ids[90]));
+        let results = index.search_experiences(&make_embedding(90, 8), 5).unwrap();
+        assert!(results.iter().all(|(id, _)| *id != ids[90]));
+        assert_eq!(index.warm().unwrap(), 99);
+    }
+
+    #[test]
+    fn test_remove_files() {
+        let dir = tempdir().unwrap();
+        let index = IvfIndex::create(4, &test_config(4, 1), dir.path(), "c").unwrap();
+        index
+            .insert_experience(ExperienceId::new(), &[1.0, 0.0, 0.0, 0.0])
+            .unwrap();
+        index.flush().unwrap();
+        drop(index);
+
+        assert!(dir.path().join("c.ivf").exists());
+        IvfIndex::remove_files(dir.path(), "c").unwrap();
+        assert!(!dir.path().join("c.ivf").exists());
+    }
+}
@@ -2,36 +2,60 @@
 //!
 //! This module provides a trait-based abstraction over vector indexes,
 //! allowing different ANN (Approximate Nearest Neighbor) backends.
-//! The primary implementation uses [`hnsw_rs`] (pure Rust, ADR-005).
+//! The primary implementation uses [`hnsw_rs`] (pure Rust, ADR-005);
+//! [`IvfIndex`](crate::vector::IvfIndex) keeps vectors on disk for
+//! collectives larger than RAM, and a [`VectorBackend`] plugs in any other
+//! ANN library.
 //!
 //! # Architecture
 //!
 //! ```text
-//! ┌──────────────────────────────────┐
-//! │         VectorIndex trait         │
-//! └──────────┬───────────────────────┘
+//! ┌──────────────────────────────────────────────────────┐
+//! │                   VectorIndex trait                   │
+//! └──────────┬───────────────────────────────────────────┘
 //!            │
-//!    ┌───────┴────────┐
-//!    │   HnswIndex    │  (hnsw_rs wrapper)
-//!    └────────────────┘
+//!    ┌───────┴────────┬────────────────┬─────────────────┐
+//!    │   HnswIndex    │    IvfIndex    │  VectorBackend  │
+//!    │ (hnsw_rs, RAM) │ (IVF, on disk) │ (user factory)  │
+//!    └────────────────┴────────────────┴─────────────────┘
 //! ```
 //!
-//! Embeddings stored in redb are the **source of truth**. The HNSW index
-//! is a derived, rebuildable structure — if files are missing or corrupt,
+//! PulseDB reaches every index through
+//! [`CollectiveIndex`](crate::vector::CollectiveIndex), which maps
+//! experience IDs to the trait's `usize` IDs for custom backends.
+//!
+//! Embeddings stored in redb are the **source of truth**. Both indexes
+//! are derived, rebuildable structures — if files are missing or corrupt,
 //! rebuild from stored embeddings.
 
+mod custom;
 mod hnsw;
+mod ivf;
+pub(crate) mod snapshot;
 
-pub use hnsw::HnswIndex;
+pub use custom::{CustomIndex, IndexRole, IndexSpec, VectorBackend};
+pub(crate) use hnsw::IndexMetadata;
+pub use hnsw::{HnswIndex, HnswStats};
+pub use ivf::IvfIndex;
+pub use snapshot::{IndexSnapshotFile, IndexSnapshotManifest};
 
+use std::collections::HashSet;
 use std::path::Path;
 
+use crate::config::VectorIndexKind;
 use crate::error::Result;
+use crate::metrics::LockWaitStats;
+use crate::types::ExperienceId;
 
 /// Vector index trait for approximate nearest neighbor search.
 ///
+/// This is the extension point for custom ANN backends: implement it and
+/// return instances from a [`VectorBackend`] factory set in
+/// [`Config::vector_backend`](crate::Config::vector_backend).
+///
 /// Implementations must be `Send + Sync` for use inside `PulseDB`.
-/// IDs are `usize` to align with hnsw_rs's `DataId` type.
+/// IDs are dense `usize` values assigned by PulseDB from 0 upward; each
+/// ID is inserted at most once.
 ///
 /// All mutating methods (`insert`, `delete`) take `&self` and use
 /// interior mutability. This enables concurrent reads during search
@@ -56,11 +80,9 @@ pub trait VectorIndex: Send + Sync {
     /// Searches with a filter predicate applied during traversal.
     ///
     /// Only points where `filter(id)` returns `true` are considered.
-    /// This is filter-during-traversal, NOT post-filtering — critical
-    /// for maintaining result count when many points are filtered.
-    ///
-    /// The filter must implement `hnsw_rs::FilterT` (closures do
-    /// automatically via blanket impl).
+    /// Filtering during traversal, rather than after it, keeps the result
+    /// count up when many points are filtered out. Deleted points are
+    /// never returned.
     fn search_filtered(
         &self,
         query: &[f32],
@@ -71,8 +93,8 @@ pub trait VectorIndex: Send + Sync {
 
     /// Marks an ID as deleted (soft-delete).
     ///
-    /// The vector remains in the graph but is excluded from search
-    /// results. HNSW graphs don't support point removal — removing
+    /// The ID must be excluded from later search results. Backends may
+    /// keep the vector: HNSW graphs don't support point removal — removing
     /// nodes breaks proximity edges that other nodes rely on.
     fn delete(&self, id: usize) -> Result<()>;
 
@@ -88,5 +110,156 @@ pub trait VectorIndex: Send + Sync {
     }
 
     /// Persists index metadata to disk.
+    ///
+    /// Called with the database's index directory when the database is
+    /// closed or the collective is evicted. `name` is unique per index;
+    /// files written should start with it. Backends that keep nothing on
+    /// disk can return `Ok(())`.
     fn save(&self, dir: &Path, name: &str) -> Result<()>;
 }
+
+/// A collective's experience or insight index: the kind its
+/// [`VectorIndexKind`] selects, or one built by a [`VectorBackend`].
+pub enum CollectiveIndex {
+    /// In-memory HNSW graph.
+    Hnsw(HnswIndex),
+    /// Disk-backed inverted file.
+    Ivf(IvfIndex),
+    /// Index from the configured [`VectorBackend`].
+    Custom(CustomIndex),
+}
+
+impl CollectiveIndex {
+    /// Returns which built-in kind of index this is, or `None` for a
+    /// custom backend.
+    pub fn kind(&self) -> Option<VectorIndexKind> {
+        match self {
+            Self::Hnsw(_) => Some(VectorIndexKind::Hnsw),
+            Self::Ivf(_) => Some(VectorIndexKind::Ivf),
+            Self::Custom(_) => None,
+        }
+    }
+
+    /// Inserts an experience embedding (no-op if already present).
+    pub fn insert_experience(&self, exp_id: ExperienceId, embedding: &[f32]) -> Result<()> {
+        match self {
+            Self::Hnsw(index) => index.insert_experience(exp_id, embedding),
+            Self::Ivf(index) => index.insert_experience(exp_id, embedding),
+            Self::Custom(index) => index.insert_experience(exp_id, embedding),
+        }
+    }
+
+    /// Inserts experience embeddings in one batch, skipping any already
+    /// present.
+    pub fn insert_experiences(&self, items: &[(ExperienceId, &Vec<f32>)]) -> Result<()> {
+        match self {
+            Self::Hnsw(index) => index.insert_experiences(items),
+            Self::Ivf(index) => index.insert_experiences(items),
+            Self::Custom(index) => index.insert_experiences(items),
+        }
+    }
+
+    /// Marks an experience as deleted.
+    pub fn delete_experience(&self, exp_id: ExperienceId) -> Result<()> {
+        match self {
+            Self::Hnsw(index) => index.delete_experience(exp_id),
+            Self::Ivf(index) => index.delete_experience(exp_id),
+            Self::Custom(index) => index.delete_experience(exp_id),
+        }
+    }
+
+    /// Searches for the k nearest experiences, closest first.
+    ///
+    /// `ef_search` is ignored by IVF and passed through to custom backends.
+    pub fn search_experiences(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+    ) -> Result<Vec<(ExperienceId, f32)>> {
+        match self {
+            Self::Hnsw(index) => index.search_experiences(query, k, ef_search),
+            Self::Ivf(index) => index.search_experiences(query, k),
+            Self::Custom(index) => index.search_experiences(query, k, ef_search),
+        }
+    }
+
+    /// Searches for the k nearest experiences among `allowed`, closest
+    /// first. May return fewer than `k` even when more qualify; see the
+    /// per-kind methods.
+    pub fn search_experiences_within(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        allowed: &HashSet<ExperienceId>,
+    ) -> Result<Vec<(ExperienceId, f32)>> {
+        match self {
+            Self::Hnsw(index) => index.search_experiences_within(query, k, ef_search, allowed),
+            Self::Ivf(index) => index.search_experiences_within(query, k, allowed),
+            Self::Custom(index) => index.search_experiences_within(query, k, ef_search, allowed),
+        }
+    }
+
+    /// Pulls the index into memory ahead of the first search. Custom
+    /// backends are left as they are.
+    pub fn warm(&self) -> Result<usize> {
+        match self {
+            Self::Hnsw(index) => index.warm(),
+            Self::Ivf(index) => index.warm(),
+            Self::Custom(index) => Ok(index.active_count()),
+        }
+    }
+
+    /// Returns true if the experience is indexed and not deleted.
+    pub fn contains(&self, exp_id: ExperienceId) -> bool {
+        match self {
+            Self::Hnsw(index) => index.contains(exp_id),
+            Self::Ivf(index) => index.contains(exp_id),
+            Self::Custom(index) => index.contains(exp_id),
+        }
+    }
+
+    /// Returns the number of active (non-deleted) vectors.
+    pub fn active_count(&self) -> usize {
+        match self {
+            Self::Hnsw(index) => index.active_count(),
+            Self::Ivf(index) => index.active_count(),
+            Self::Custom(index) => index.active_count(),
+        }
+    }
+
+    /// Returns the HNSW health snapshot, or `None` for other indexes.
+    pub fn hnsw_stats(&self) -> Option<HnswStats> {
+        match self {
+            Self::Hnsw(index) => Some(index.stats()),
+            Self::Ivf(_) | Self::Custom(_) => None,
+        }
+    }
+
+    /// Returns the waits on the index's internal locks.
+    pub(crate) fn lock_waits(&self) -> LockWaitStats {
+        match self {
+            Self::Hnsw(index) => index.lock_waits(),
+            Self::Ivf(index) => index.lock_waits(),
+            Self::Custom(_) => LockWaitStats::default(),
+        }
+    }
+
+    /// Merges one run of sealed HNSW segments. Other indexes have none.
+    pub fn merge_segments(&self) -> Result<bool> {
+        match self {
+            Self::Hnsw(index) => index.merge_segments(),
+            Self::Ivf(_) | Self::Custom(_) => Ok(false),
+        }
+    }
+
+    /// Persists the index under `dir` (see [`VectorIndex::save`]).
+    pub fn save_to_dir(&self, dir: &Path, name: &str) -> Result<()> {
+        match self {
+            Self::Hnsw(index) => index.save_to_dir(dir, name),
+            Self::Ivf(index) => index.flush(),
+            Self::Custom(index) => index.save_to_dir(dir, name),
+        }
+    }
+}
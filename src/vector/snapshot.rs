@@ -0,0 +1,246 @@
+//! Index snapshots: a collective's HNSW graphs shipped outside redb.
+//!
+//! Rebuilding a large collective's graph on import can take far longer
+//! than copying its records. A snapshot carries the graphs themselves so
+//! a replica can load them instead.
+//!
+//! # Layout
+//!
+//! ```text
+//! {dir}/snapshot.json         manifest (IndexSnapshotManifest)
+//! {dir}/index.hnsw.meta       ID mappings and deleted set
+//! {dir}/seg{i}.hnsw.graph     hnsw_rs dump, one pair per non-empty segment
+//! {dir}/seg{i}.hnsw.data
+//! ```
+//!
+//! The manifest records every file's size and CRC32 and is written last,
+//! so a directory whose manifest verifies holds a complete snapshot.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::HnswConfig;
+use crate::error::{PulseDBError, Result, StorageError};
+use crate::types::{CollectiveId, Timestamp};
+
+use super::hnsw::IndexMetadata;
+use super::HnswIndex;
+
+/// Current snapshot layout version.
+const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Manifest file name inside a snapshot directory.
+const MANIFEST_FILE: &str = "snapshot.json";
+
+/// Metadata file name inside a snapshot directory.
+const META_FILE: &str = "index.hnsw.meta";
+
+/// Describes an index snapshot directory.
+///
+/// Returned by [`PulseDB::export_index_snapshot()`](crate::PulseDB::export_index_snapshot)
+/// and [`PulseDB::restore_index_snapshot()`](crate::PulseDB::restore_index_snapshot).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexSnapshotManifest {
+    /// Version of the snapshot layout.
+    pub format_version: u32,
+
+    /// Collective whose experience index this is.
+    pub collective_id: CollectiveId,
+
+    /// Embedding dimension of every vector in the index.
+    pub embedding_dimension: usize,
+
+    /// When the snapshot was written.
+    pub created_at: Timestamp,
+
+    /// Number of active (non-deleted) vectors.
+    pub vector_count: u64,
+
+    /// Dump basenames of the graph segments, oldest first.
+    pub segments: Vec<String>,
+
+    /// Every file of the snapshot except the manifest.
+    pub files: Vec<IndexSnapshotFile>,
+}
+
+/// One file of an index snapshot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexSnapshotFile {
+    /// File name relative to the snapshot directory.
+    pub name: String,
+
+    /// File size in bytes.
+    pub bytes: u64,
+
+    /// CRC32 of the file contents.
+    pub checksum: u32,
+}
+
+/// Writes a snapshot of `index` into `dir`, replacing any earlier one.
+pub(crate) fn write_snapshot(
+    index: &HnswIndex,
+    collective_id: CollectiveId,
+    dir: &Path,
+) -> Result<IndexSnapshotManifest> {
+    fs::create_dir_all(dir)?;
+
+    // Invalidate the old snapshot before touching its files
+    let manifest_path = dir.join(MANIFEST_FILE);
+    if let Ok(old) = read_manifest(dir) {
+        fs::remove_file(&manifest_path)?;
+        for file in old.files {
+            let _ = fs::remove_file(dir.join(file.name));
+        }
+    }
+
+    let (metadata, segments) = index.dump_segments(dir)?;
+    metadata.write(&dir.join(META_FILE))?;
+
+    let mut names = vec![META_FILE.to_string()];
+    for segment in &segments {
+        names.push(format!("{}.hnsw.graph", segment));
+        names.push(format!("{}.hnsw.data", segment));
+    }
+    let files = names
+        .into_iter()
+        .map(|name| {
+            let bytes = fs::read(dir.join(&name))?;
+            Ok(IndexSnapshotFile {
+                name,
+                bytes: bytes.len() as u64,
+                checksum: crc32fast::hash(&bytes),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let manifest = IndexSnapshotManifest {
+        format_version: SNAPSHOT_FORMAT_VERSION,
+        collective_id,
+        embedding_dimension: metadata.dimension,
+        created_at: Timestamp::now(),
+        vector_count: (metadata.id_map.len() - metadata.deleted.len()) as u64,
+        segments,
+        files,
+    };
+    let json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| StorageError::serialization(e.to_string()))?;
+    let partial = dir.join(format!("{}.partial", MANIFEST_FILE));
+    fs::write(&partial, json)?;
+    fs::rename(partial, manifest_path)?;
+
+    Ok(manifest)
+}
+
+/// Reads a snapshot's manifest and verifies every file against it.
+pub(crate) fn verify_snapshot(dir: &Path) -> Result<IndexSnapshotManifest> {
+    let manifest = read_manifest(dir)?;
+    for file in &manifest.files {
+        let bytes = fs::read(dir.join(&file.name)).map_err(|e| {
+            StorageError::corrupted(format!("snapshot file {} unreadable: {}", file.name, e))
+        })?;
+        if bytes.len() as u64 != file.bytes || crc32fast::hash(&bytes) != file.checksum {
+            return Err(StorageError::corrupted(format!(
+                "snapshot file {} fails its checksum",
+                file.name
+            ))
+            .into());
+        }
+    }
+    Ok(manifest)
+}
+
+/// Verifies a snapshot and loads its index.
+pub(crate) fn load_snapshot(
+    dir: &Path,
+    config: &HnswConfig,
+) -> Result<(IndexSnapshotManifest, HnswIndex)> {
+    let manifest = verify_snapshot(dir)?;
+    let metadata = IndexMetadata::read(&dir.join(META_FILE))?;
+    if metadata.dimension != manifest.embedding_dimension {
+        return Err(
+            StorageError::corrupted("snapshot metadata disagrees with its manifest").into(),
+        );
+    }
+    let index = HnswIndex::from_dumped_segments(config, dir, &metadata, &manifest.segments)?;
+    Ok((manifest, index))
+}
+
+/// Returns `true` if `dir` holds a snapshot manifest.
+pub(crate) fn snapshot_exists(dir: &Path) -> bool {
+    dir.join(MANIFEST_FILE).is_file()
+}
+
+/// Reads and checks the manifest, without verifying the files.
+fn read_manifest(dir: &Path) -> Result<IndexSnapshotManifest> {
+    let bytes = fs::read(dir.join(MANIFEST_FILE))?;
+    let manifest: IndexSnapshotManifest = serde_json::from_slice(&bytes)
+        .map_err(|e| StorageError::corrupted(format!("invalid snapshot manifest: {}", e)))?;
+    if manifest.format_version != SNAPSHOT_FORMAT_VERSION {
+        return Err(PulseDBError::from(StorageError::corrupted(format!(
+            "unsupported snapshot format version {} (expected {})",
+            manifest.format_version, SNAPSHOT_FORMAT_VERSION
+        ))));
+    }
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ExperienceId;
+
+    fn embedding(seed: usize) -> Vec<f32> {
+        (0..8)
+            .map(|i| ((seed * 31 + i * 7) % 17) as f32 + 1.0)
+            .collect()
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip_keeps_ids_and_deletions() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = HnswConfig::default();
+        let index = HnswIndex::new(8, &config);
+        let ids: Vec<ExperienceId> = (0..20).map(|_| ExperienceId::new()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            index.insert_experience(*id, &embedding(i)).unwrap();
+        }
+        index.delete_experience(ids[3]).unwrap();
+
+        let collective_id = CollectiveId::new();
+        let written = write_snapshot(&index, collective_id, dir.path()).unwrap();
+        assert_eq!(written.vector_count, 19);
+
+        let (manifest, loaded) = load_snapshot(dir.path(), &config).unwrap();
+        assert_eq!(manifest, written);
+        assert_eq!(loaded.active_count(), 19);
+        assert!(!loaded.contains(ids[3]));
+        let hits = loaded.search_experiences(&embedding(7), 1, 50).unwrap();
+        assert_eq!(hits[0].0, ids[7]);
+
+        // The loaded index still takes inserts
+        let extra = ExperienceId::new();
+        loaded.insert_experience(extra, &embedding(100)).unwrap();
+        assert!(loaded.contains(extra));
+    }
+
+    #[test]
+    fn test_corrupted_snapshot_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let index = HnswIndex::new(8, &HnswConfig::default());
+        index
+            .insert_experience(ExperienceId::new(), &embedding(1))
+            .unwrap();
+        let manifest = write_snapshot(&index, CollectiveId::new(), dir.path()).unwrap();
+
+        let graph = dir.path().join(&manifest.files[1].name);
+        let mut bytes = fs::read(&graph).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        fs::write(&graph, bytes).unwrap();
+
+        let err = verify_snapshot(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("checksum"));
+    }
+}
@@ -0,0 +1,133 @@
+//! The database-wide change log.
+//!
+//! Every mutation of an experience, relation, insight, or collective is
+//! appended to the WAL with a monotonically increasing sequence number.
+//! [`PulseDB::changes_since()`](crate::PulseDB::changes_since) reads it
+//! from any sequence, across processes; [`PulseDB::subscribe()`](crate::PulseDB::subscribe)
+//! pushes each new [`Change`] to a [`ChangeSubscription`] as soon as its
+//! write commits in this process.
+
+use std::time::Duration;
+
+use crossbeam_channel::Receiver;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::schema::{EntityTypeTag, WatchEventRecord};
+use crate::types::{CollectiveId, ExperienceId, InsightId, RelationId, Timestamp};
+use crate::watch::WatchEventType;
+
+/// The entity a [`Change`] applies to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChangedEntity {
+    /// An experience.
+    Experience(ExperienceId),
+
+    /// A relation between experiences.
+    Relation(RelationId),
+
+    /// A derived insight.
+    Insight(InsightId),
+
+    /// A collective. A `Deleted` change also stands for everything the
+    /// collective contained.
+    Collective(CollectiveId),
+}
+
+/// One entry of the change log.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Change {
+    /// Position in the log; pass the last one seen to
+    /// [`changes_since()`](crate::PulseDB::changes_since) to resume.
+    pub sequence: u64,
+
+    /// The collective the entity belongs to.
+    pub collective_id: CollectiveId,
+
+    /// What changed.
+    pub entity: ChangedEntity,
+
+    /// What kind of change occurred.
+    pub change_type: WatchEventType,
+
+    /// When the change occurred.
+    pub timestamp: Timestamp,
+}
+
+impl Change {
+    /// Builds a change from a WAL record and its sequence number.
+    pub(crate) fn from_record(sequence: u64, record: &WatchEventRecord) -> Self {
+        let id = record.entity_id;
+        let entity = match record.entity_type {
+            EntityTypeTag::Experience => ChangedEntity::Experience(ExperienceId::from_bytes(id)),
+            EntityTypeTag::Relation => ChangedEntity::Relation(RelationId::from_bytes(id)),
+            EntityTypeTag::Insight => ChangedEntity::Insight(InsightId::from_bytes(id)),
+            EntityTypeTag::Collective => ChangedEntity::Collective(CollectiveId::from_bytes(id)),
+        };
+        Self {
+            sequence,
+            collective_id: CollectiveId::from_bytes(record.collective_id),
+            entity,
+            change_type: record.event_type.into(),
+            timestamp: Timestamp::from_millis(record.timestamp_ms),
+        }
+    }
+}
+
+/// Receives every [`Change`] committed in this process after
+/// [`subscribe()`](crate::PulseDB::subscribe) was called, in sequence order.
+///
+/// Changes arrive over a bounded channel of
+/// [`WatchConfig::buffer_size`](crate::WatchConfig::buffer_size); if the
+/// subscriber falls behind, changes are dropped (logged as a warning) and
+/// can be recovered with [`changes_since()`](crate::PulseDB::changes_since).
+/// Iterating blocks until the next change, ending when the database is
+/// closed. Dropping the subscription unregisters it.
+pub struct ChangeSubscription {
+    /// The receiving end of the bounded channel.
+    pub(crate) receiver: Receiver<Change>,
+
+    /// Cleanup function called on drop to remove the subscriber.
+    pub(crate) cleanup: Option<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl ChangeSubscription {
+    /// Blocks until the next change, or returns `None` once the database
+    /// is closed.
+    pub fn recv(&self) -> Option<Change> {
+        self.receiver.recv().ok()
+    }
+
+    /// Returns the next change if one is waiting.
+    pub fn try_recv(&self) -> Option<Change> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Waits up to `timeout` for the next change.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Change> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+}
+
+impl Iterator for ChangeSubscription {
+    type Item = Change;
+
+    fn next(&mut self) -> Option<Change> {
+        self.recv()
+    }
+}
+
+impl Drop for ChangeSubscription {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+}
+
+impl std::fmt::Debug for ChangeSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChangeSubscription")
+            .field("pending_changes", &self.receiver.len())
+            .finish_non_exhaustive()
+    }
+}
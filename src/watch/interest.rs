@@ -0,0 +1,71 @@
+//! Standing interest vectors.
+//!
+//! An [`Interest`] registered with
+//! [`PulseDB::register_interest()`](crate::PulseDB::register_interest)
+//! routes newly recorded experiences to the agent that declared it: each
+//! experience within `min_similarity` of the interest's embedding is sent
+//! to the agent's [`watch_interests()`](crate::PulseDB::watch_interests)
+//! streams.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::AgentId;
+
+/// A standing interest an agent holds in a collective.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Interest {
+    /// The agent notified of matches.
+    pub agent_id: AgentId,
+
+    /// Name distinguishing the agent's interests in the collective.
+    pub name: String,
+
+    /// Embedding new experiences are compared against.
+    pub embedding: Vec<f32>,
+
+    /// Cosine similarity at or above which an experience matches.
+    pub min_similarity: f32,
+}
+
+impl Interest {
+    /// Creates an interest.
+    pub fn new(
+        agent_id: AgentId,
+        name: impl Into<String>,
+        embedding: Vec<f32>,
+        min_similarity: f32,
+    ) -> Self {
+        Self {
+            agent_id,
+            name: name.into(),
+            embedding,
+            min_similarity,
+        }
+    }
+
+    /// Returns `true` if an experience with this embedding matches.
+    pub fn matches(&self, embedding: &[f32]) -> bool {
+        let dot: f32 = self
+            .embedding
+            .iter()
+            .zip(embedding)
+            .map(|(a, b)| a * b)
+            .sum();
+        let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let denominator = norm(&self.embedding) * norm(embedding);
+        denominator > 0.0 && dot / denominator >= self.min_similarity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_uses_cosine_similarity() {
+        let interest = Interest::new(AgentId::new("a"), "x-axis", vec![1.0, 0.0], 0.9);
+        assert!(interest.matches(&[5.0, 0.1]));
+        assert!(!interest.matches(&[1.0, 1.0]));
+        assert!(!interest.matches(&[0.0, 0.0]));
+    }
+}
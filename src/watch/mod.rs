@@ -18,17 +18,21 @@
 //! Filters are applied on the sender side before channel delivery, so
 //! subscribers only receive events they care about.
 
+pub mod changes;
+pub mod interest;
 pub mod lock;
 pub mod poll;
 pub mod types;
 
+pub use changes::{Change, ChangeSubscription, ChangedEntity};
+pub use interest::Interest;
 pub use lock::WatchLock;
 pub use poll::ChangePoller;
 pub use types::{WatchEvent, WatchEventType, WatchFilter, WatchStream};
 
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 
 use atomic_waker::AtomicWaker;
 use crossbeam_channel::{bounded, Sender, TrySendError};
@@ -36,6 +40,7 @@ use tracing::{info, warn};
 
 use crate::error::PulseDBError;
 use crate::experience::Experience;
+use crate::storage::StorageEngine;
 use crate::types::CollectiveId;
 
 /// A subscriber's channel sender and optional filter.
@@ -45,6 +50,33 @@ struct Subscriber {
     filter: Option<WatchFilter>,
 }
 
+impl Subscriber {
+    /// Sends an event without blocking and wakes the consumer.
+    ///
+    /// A full channel drops the event (logged as a warning). Returns
+    /// `false` if the stream has been dropped.
+    fn send(&self, subscriber_id: u64, event: &WatchEvent) -> bool {
+        match self.sender.try_send(event.clone()) {
+            Ok(()) => {
+                // Wake the async poller so it picks up the event
+                self.waker.wake();
+                true
+            }
+            Err(TrySendError::Full(_)) => {
+                warn!(
+                    subscriber_id,
+                    collective_id = %event.collective_id,
+                    "Watch buffer full, dropping event"
+                );
+                // Still wake in case consumer is stuck
+                self.waker.wake();
+                true
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        }
+    }
+}
+
 /// Internal service managing watch subscriptions and event dispatch.
 ///
 /// Held as `Arc<WatchService>` in PulseDB so that [`WatchStream`] drop
@@ -55,6 +87,16 @@ pub(crate) struct WatchService {
     /// Read lock for emit (common path), write lock for subscribe/unsubscribe (rare).
     subscribers: RwLock<HashMap<CollectiveId, Vec<(u64, Subscriber)>>>,
 
+    /// Interest subscribers grouped by agent ID.
+    agent_subscribers: RwLock<HashMap<String, Vec<(u64, Subscriber)>>>,
+
+    /// Change log subscribers.
+    change_subscribers: RwLock<Vec<(u64, Sender<Change>)>>,
+
+    /// Last WAL sequence pushed to change subscribers. The lock also keeps
+    /// concurrent publishers from delivering out of order.
+    published_seq: Mutex<u64>,
+
     /// Monotonic counter for subscriber IDs.
     next_id: AtomicU64,
 
@@ -73,6 +115,9 @@ impl WatchService {
     pub(crate) fn new(buffer_size: usize, in_process: bool) -> Self {
         Self {
             subscribers: RwLock::new(HashMap::new()),
+            agent_subscribers: RwLock::new(HashMap::new()),
+            change_subscribers: RwLock::new(Vec::new()),
+            published_seq: Mutex::new(0),
             next_id: AtomicU64::new(0),
             buffer_size,
             in_process,
@@ -158,23 +203,8 @@ impl WatchService {
                         continue;
                     }
 
-                    match sub.sender.try_send(event.clone()) {
-                        Ok(()) => {
-                            // Wake the async poller so it picks up the event
-                            sub.waker.wake();
-                        }
-                        Err(TrySendError::Full(_)) => {
-                            warn!(
-                                subscriber_id = id,
-                                collective_id = %collective_id,
-                                "Watch buffer full, dropping event"
-                            );
-                            // Still wake in case consumer is stuck
-                            sub.waker.wake();
-                        }
-                        Err(TrySendError::Disconnected(_)) => {
-                            disconnected.push(*id);
-                        }
+                    if !sub.send(*id, &event) {
+                        disconnected.push(*id);
                     }
                 }
             }
@@ -197,6 +227,191 @@ impl WatchService {
         Ok(())
     }
 
+    /// Registers a subscriber for events targeted at an agent.
+    ///
+    /// Returns a [`WatchStream`] that yields what
+    /// [`emit_to_agent()`](Self::emit_to_agent) sends to `agent_id`. The
+    /// stream automatically unregisters on drop.
+    pub(crate) fn subscribe_agent(self: &Arc<Self>, agent_id: &str) -> crate::Result<WatchStream> {
+        if !self.in_process {
+            info!("in-process watch disabled, stream will not receive events");
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = bounded(self.buffer_size);
+        let waker = Arc::new(AtomicWaker::new());
+        {
+            let mut subs = self
+                .agent_subscribers
+                .write()
+                .map_err(|_| PulseDBError::watch("Watch subscribers lock poisoned"))?;
+            subs.entry(agent_id.to_string()).or_default().push((
+                id,
+                Subscriber {
+                    sender,
+                    waker: Arc::clone(&waker),
+                    filter: None,
+                },
+            ));
+        }
+
+        let service = Arc::downgrade(self);
+        let cleanup_agent = agent_id.to_string();
+        Ok(WatchStream {
+            receiver,
+            waker,
+            cleanup: Some(Box::new(move || {
+                if let Some(service) = service.upgrade() {
+                    service.remove_agent_subscriber(&cleanup_agent, id);
+                }
+            })),
+        })
+    }
+
+    /// Emits an event to every stream an agent opened with
+    /// [`subscribe_agent()`](Self::subscribe_agent).
+    pub(crate) fn emit_to_agent(&self, agent_id: &str, event: &WatchEvent) -> crate::Result<()> {
+        if !self.in_process {
+            return Ok(());
+        }
+
+        let mut disconnected = Vec::new();
+        {
+            let subs = self
+                .agent_subscribers
+                .read()
+                .map_err(|_| PulseDBError::watch("Watch subscribers lock poisoned"))?;
+            if let Some(subscribers) = subs.get(agent_id) {
+                for (id, sub) in subscribers {
+                    if !sub.send(*id, event) {
+                        disconnected.push(*id);
+                    }
+                }
+            }
+        }
+        for id in disconnected {
+            self.remove_agent_subscriber(agent_id, id);
+        }
+        Ok(())
+    }
+
+    /// Registers a change log subscriber.
+    ///
+    /// `current_seq` is the WAL sequence at the time of the call; the
+    /// subscription receives changes after it.
+    pub(crate) fn subscribe_changes(
+        self: &Arc<Self>,
+        current_seq: u64,
+    ) -> crate::Result<ChangeSubscription> {
+        if !self.in_process {
+            info!("in-process watch disabled, subscription will not receive changes");
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = bounded(self.buffer_size);
+        {
+            // Publishing holds this lock, so nothing is pushed between
+            // reading the baseline and registering
+            let mut published = self
+                .published_seq
+                .lock()
+                .map_err(|_| PulseDBError::watch("Change log cursor lock poisoned"))?;
+            let mut subs = self
+                .change_subscribers
+                .write()
+                .map_err(|_| PulseDBError::watch("Watch subscribers lock poisoned"))?;
+            if subs.is_empty() {
+                *published = current_seq;
+            }
+            subs.push((id, sender));
+        }
+
+        let service = Arc::downgrade(self);
+        Ok(ChangeSubscription {
+            receiver,
+            cleanup: Some(Box::new(move || {
+                if let Some(service) = service.upgrade() {
+                    service.remove_change_subscriber(id);
+                }
+            })),
+        })
+    }
+
+    /// Pushes changes committed since the last publish to change log
+    /// subscribers. Installed as the storage engine's commit listener.
+    ///
+    /// A full channel drops the change for that subscriber (logged as a
+    /// warning); errors reading the log are logged and retried on the next
+    /// commit.
+    pub(crate) fn publish_changes(&self, storage: &dyn StorageEngine) {
+        if !self.in_process || !self.has_change_subscribers() {
+            return;
+        }
+        let mut published = match self.published_seq.lock() {
+            Ok(guard) => guard,
+            Err(_) => {
+                warn!("Change log cursor lock poisoned");
+                return;
+            }
+        };
+
+        loop {
+            let events = match storage.poll_sync_events(*published, 1000) {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!(error = %e, "Failed to read change log for subscribers");
+                    return;
+                }
+            };
+            let Some((last_seq, _)) = events.last() else {
+                return;
+            };
+            *published = *last_seq;
+
+            let mut disconnected = Vec::new();
+            if let Ok(subs) = self.change_subscribers.read() {
+                for (seq, record) in &events {
+                    let change = Change::from_record(*seq, record);
+                    for (id, sender) in subs.iter() {
+                        match sender.try_send(change.clone()) {
+                            Ok(()) => {}
+                            Err(TrySendError::Full(_)) => {
+                                warn!(
+                                    subscriber_id = id,
+                                    sequence = seq,
+                                    "Change buffer full, dropping change"
+                                );
+                            }
+                            Err(TrySendError::Disconnected(_)) => disconnected.push(*id),
+                        }
+                    }
+                }
+            }
+            for id in disconnected {
+                self.remove_change_subscriber(id);
+            }
+        }
+    }
+
+    /// Returns `true` if any change log subscription is open.
+    fn has_change_subscribers(&self) -> bool {
+        self.change_subscribers
+            .read()
+            .map(|subs| !subs.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if any agent has an open interest stream.
+    pub(crate) fn has_agent_subscribers(&self) -> bool {
+        if !self.in_process {
+            return false;
+        }
+        self.agent_subscribers
+            .read()
+            .map(|subs| !subs.is_empty())
+            .unwrap_or(false)
+    }
+
     /// Returns `true` if any subscribers are registered.
     ///
     /// Cheap check used by mutation methods to skip the `get_experience`
@@ -230,6 +445,32 @@ impl WatchService {
             }
         }
     }
+
+    /// Removes a change log subscriber. Called from
+    /// [`ChangeSubscription::drop`].
+    fn remove_change_subscriber(&self, subscriber_id: u64) {
+        match self.change_subscribers.write() {
+            Ok(mut subs) => subs.retain(|(id, _)| *id != subscriber_id),
+            Err(_) => warn!("Watch subscribers lock poisoned during cleanup"),
+        }
+    }
+
+    /// Removes a specific agent subscriber. Called from [`WatchStream::drop`].
+    fn remove_agent_subscriber(&self, agent_id: &str, subscriber_id: u64) {
+        let mut subs = match self.agent_subscribers.write() {
+            Ok(guard) => guard,
+            Err(_) => {
+                warn!("Watch subscribers lock poisoned during cleanup");
+                return;
+            }
+        };
+        if let Some(subscribers) = subs.get_mut(agent_id) {
+            subscribers.retain(|(id, _)| *id != subscriber_id);
+            if subscribers.is_empty() {
+                subs.remove(agent_id);
+            }
+        }
+    }
 }
 
 /// Checks whether an experience matches a subscriber's filter.
@@ -299,8 +540,11 @@ mod tests {
             related_files: vec![],
             source_agent: AgentId::new("test-agent"),
             source_task: None,
+            user_id: None,
             timestamp: Timestamp::now(),
+            expires_at: None,
             archived: false,
+            attribution: None,
         }
     }
 
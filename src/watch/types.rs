@@ -11,6 +11,7 @@ use std::task::{Context, Poll};
 use atomic_waker::AtomicWaker;
 use crossbeam_channel::Receiver;
 use futures_core::Stream;
+use serde::{Deserialize, Serialize};
 
 use crate::experience::{Experience, ExperienceType};
 use crate::storage::schema::{WatchEventRecord, WatchEventTypeTag};
@@ -44,7 +45,7 @@ use crate::types::{CollectiveId, ExperienceId, Timestamp};
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WatchEvent {
     /// The experience that changed.
     pub experience_id: ExperienceId,
@@ -70,7 +71,7 @@ pub struct WatchEvent {
 }
 
 /// The kind of change that triggered a [`WatchEvent`].
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WatchEventType {
     /// A new experience was recorded.
     Created,
@@ -51,6 +51,7 @@ fn test_register_activity() {
         collective_id: cid,
         current_task: Some("Reviewing code".to_string()),
         context_summary: Some("Working on src/db.rs".to_string()),
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -76,6 +77,7 @@ fn test_register_activity_upsert() {
         collective_id: cid,
         current_task: Some("Task A".to_string()),
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -85,6 +87,7 @@ fn test_register_activity_upsert() {
         collective_id: cid,
         current_task: Some("Task B".to_string()),
         context_summary: Some("New context".to_string()),
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -107,6 +110,7 @@ fn test_heartbeat_updates_timestamp() {
         collective_id: cid,
         current_task: None,
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -148,6 +152,7 @@ fn test_end_activity() {
         collective_id: cid,
         current_task: None,
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -185,6 +190,7 @@ fn test_get_active_agents() {
             collective_id: cid,
             current_task: None,
             context_summary: None,
+            capabilities: vec![],
         })
         .unwrap();
         // Small sleep so heartbeats differ
@@ -210,6 +216,7 @@ fn test_stale_activity_excluded() {
         collective_id: cid,
         current_task: None,
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -235,6 +242,7 @@ fn test_one_activity_per_agent() {
         collective_id: cid,
         current_task: Some("First".to_string()),
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -243,6 +251,7 @@ fn test_one_activity_per_agent() {
         collective_id: cid,
         current_task: Some("Second".to_string()),
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -267,6 +276,7 @@ fn test_activity_collective_isolation() {
         collective_id: cid_a,
         current_task: Some("Working in A".to_string()),
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -275,6 +285,7 @@ fn test_activity_collective_isolation() {
         collective_id: cid_b,
         current_task: Some("Working in B".to_string()),
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -301,6 +312,7 @@ fn test_register_activity_invalid_collective() {
         collective_id: CollectiveId::new(), // Doesn't exist
         current_task: None,
         context_summary: None,
+        capabilities: vec![],
     });
 
     assert!(result.is_err());
@@ -316,6 +328,7 @@ fn test_register_activity_empty_agent_id() {
         collective_id: cid,
         current_task: None,
         context_summary: None,
+        capabilities: vec![],
     });
 
     assert!(result.is_err());
@@ -335,6 +348,7 @@ fn test_cascade_delete_collective_removes_activities() {
         collective_id: cid,
         current_task: Some("Working".to_string()),
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -343,6 +357,7 @@ fn test_cascade_delete_collective_removes_activities() {
         collective_id: cid,
         current_task: None,
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -357,3 +372,109 @@ fn test_cascade_delete_collective_removes_activities() {
     assert!(result.is_err());
     assert!(result.unwrap_err().is_not_found());
 }
+
+// ============================================================================
+// Capability Discovery
+// ============================================================================
+
+/// Helper: register an agent with the given capabilities.
+fn register_with_capabilities(db: &PulseDB, cid: CollectiveId, agent_id: &str, caps: &[&str]) {
+    db.register_activity(NewActivity {
+        agent_id: agent_id.to_string(),
+        collective_id: cid,
+        current_task: None,
+        context_summary: None,
+        capabilities: caps.iter().map(|c| c.to_string()).collect(),
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_find_agents_with_capability() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let other = db.create_collective("other").unwrap();
+
+    register_with_capabilities(&db, cid, "rustacean", &["rust", "code-review", "rust"]);
+    register_with_capabilities(&db, cid, "deployer", &["deploy:staging"]);
+    register_with_capabilities(&db, other, "elsewhere", &["rust"]);
+
+    let rust: Vec<_> = db
+        .find_agents_with_capability(cid, "rust")
+        .unwrap()
+        .into_iter()
+        .map(|a| a.agent_id)
+        .collect();
+    assert_eq!(rust, vec!["rustacean"]);
+
+    // Duplicates are collapsed and capabilities round-trip on reads
+    let agents = db.get_active_agents(cid).unwrap();
+    let rustacean = agents.iter().find(|a| a.agent_id == "rustacean").unwrap();
+    assert_eq!(rustacean.capabilities, vec!["rust", "code-review"]);
+
+    assert!(db
+        .find_agents_with_capability(cid, "Rust")
+        .unwrap()
+        .is_empty());
+
+    // Heartbeats keep capabilities; re-registering replaces them
+    db.update_heartbeat("rustacean", cid).unwrap();
+    assert_eq!(
+        db.find_agents_with_capability(cid, "rust").unwrap().len(),
+        1
+    );
+    register_with_capabilities(&db, cid, "rustacean", &["python"]);
+    assert!(db
+        .find_agents_with_capability(cid, "rust")
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        db.find_agents_with_capability(cid, "python").unwrap().len(),
+        1
+    );
+
+    // Ending the activity drops it from the index
+    db.end_activity("deployer", cid).unwrap();
+    assert!(db
+        .find_agents_with_capability(cid, "deploy:staging")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_find_agents_with_capability_excludes_stale() {
+    let (db, dir) = open_db_with_threshold(Duration::from_millis(50));
+    let cid = db.create_collective("test").unwrap();
+
+    register_with_capabilities(&db, cid, "sleepy", &["rust"]);
+    assert_eq!(
+        db.find_agents_with_capability(cid, "rust").unwrap().len(),
+        1
+    );
+
+    std::thread::sleep(Duration::from_millis(80));
+    assert!(db
+        .find_agents_with_capability(cid, "rust")
+        .unwrap()
+        .is_empty());
+
+    drop(dir);
+}
+
+#[test]
+fn test_register_activity_invalid_capability() {
+    let (db, cid, _dir) = open_db_with_collective();
+
+    let result = db.register_activity(NewActivity {
+        agent_id: "agent-1".to_string(),
+        collective_id: cid,
+        current_task: None,
+        context_summary: None,
+        capabilities: vec![String::new()],
+    });
+    assert!(result.unwrap_err().is_validation());
+
+    let err = db
+        .find_agents_with_capability(CollectiveId::new(), "rust")
+        .unwrap_err();
+    assert!(err.is_not_found());
+}
@@ -0,0 +1,201 @@
+//! Integration tests for answer support.
+//!
+//! Tests the full stack: PulseDB facade -> vector search -> relation index.
+//! Covers splitting hits into stances through relations, pulling in related
+//! experiences the search missed, aggregate confidence, rendering, and
+//! validation.
+
+use pulsedb::{
+    CollectiveId, Config, ExperienceId, NewExperience, NewExperienceRelation, PulseDB, RelationType,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: a unit embedding leaning toward one axis.
+fn embedding(axis: usize) -> Vec<f32> {
+    let mut v = vec![0.01; 384];
+    v[axis] = 1.0;
+    v
+}
+
+/// Helper: an embedding close to `embedding(0)` but never closer.
+fn near_axis_zero() -> Vec<f32> {
+    let mut v = embedding(0);
+    v[1] = 0.3;
+    v
+}
+
+/// Helper: record an experience with the given confidence and embedding.
+fn record(
+    db: &PulseDB,
+    cid: CollectiveId,
+    content: &str,
+    confidence: f32,
+    embedding: Vec<f32>,
+) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.to_string(),
+        confidence,
+        embedding: Some(embedding),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: relate two experiences.
+fn relate(db: &PulseDB, source: ExperienceId, target: ExperienceId, kind: RelationType) {
+    db.store_relation(NewExperienceRelation {
+        source_id: source,
+        target_id: target,
+        relation_type: kind,
+        strength: 0.5,
+        metadata: None,
+    })
+    .unwrap();
+}
+
+fn ids(stance: &pulsedb::Stance) -> Vec<ExperienceId> {
+    stance.evidence.iter().map(|e| e.experience.id).collect()
+}
+
+// ============================================================================
+// Stances
+// ============================================================================
+
+#[test]
+fn test_contradicts_splits_hits_into_stances() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let best = record(&db, cid, "pin the toolchain", 0.9, embedding(0));
+    let agrees = record(&db, cid, "pinning avoided breakage", 0.8, near_axis_zero());
+    let disagrees = record(
+        &db,
+        cid,
+        "pinning hid a security fix",
+        0.7,
+        near_axis_zero(),
+    );
+    relate(&db, agrees, best, RelationType::Supports);
+    relate(&db, disagrees, best, RelationType::Contradicts);
+
+    let support = db.answer_support(cid, &embedding(0), 10).unwrap();
+    let supporting = ids(&support.supporting);
+    assert_eq!(supporting.len(), 2);
+    assert!(supporting.contains(&best) && supporting.contains(&agrees));
+    assert_eq!(ids(&support.contradicting), vec![disagrees]);
+
+    let evidence = &support.contradicting.evidence[0];
+    assert_eq!(evidence.via, Some(RelationType::Contradicts));
+    assert!(evidence.similarity.is_some());
+}
+
+#[test]
+fn test_contradiction_of_a_contradiction_supports() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let a = record(&db, cid, "use a mutex", 0.9, embedding(0));
+    let b = record(&db, cid, "a mutex deadlocks here", 0.8, near_axis_zero());
+    let c = record(
+        &db,
+        cid,
+        "the deadlock was a lock-order bug",
+        0.8,
+        near_axis_zero(),
+    );
+    relate(&db, b, a, RelationType::Contradicts);
+    relate(&db, c, b, RelationType::Contradicts);
+
+    let support = db.answer_support(cid, &embedding(0), 10).unwrap();
+    assert!(ids(&support.supporting).contains(&a));
+    assert!(ids(&support.supporting).contains(&c));
+    assert_eq!(ids(&support.contradicting), vec![b]);
+}
+
+#[test]
+fn test_related_experiences_outside_the_hits_are_included() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let hit = record(&db, cid, "cache the index", 0.8, embedding(0));
+    let far = record(&db, cid, "the cache went stale", 0.5, embedding(1));
+    let beyond = record(&db, cid, "unrelated to the query", 0.9, embedding(1));
+    let archived = record(&db, cid, "old objection", 0.9, embedding(1));
+    relate(&db, far, hit, RelationType::Contradicts);
+    relate(&db, beyond, far, RelationType::Supports);
+    relate(&db, archived, hit, RelationType::Contradicts);
+    db.archive_experience(archived).unwrap();
+
+    let support = db.answer_support(cid, &embedding(0), 1).unwrap();
+    assert_eq!(ids(&support.supporting), vec![hit]);
+    // Neighbors are followed one hop only, and archived ones are skipped
+    assert_eq!(ids(&support.contradicting), vec![far]);
+
+    let evidence = &support.contradicting.evidence[0];
+    assert_eq!(evidence.similarity, None);
+    assert!((evidence.weight - 0.25).abs() < 1e-6);
+    assert!((support.contradicting.confidence - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn test_unrelated_relations_do_not_take_sides() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let hit = record(&db, cid, "cache the index", 0.8, embedding(0));
+    let newer = record(
+        &db,
+        cid,
+        "cache the index per collective",
+        0.8,
+        embedding(1),
+    );
+    relate(&db, newer, hit, RelationType::Supersedes);
+
+    let support = db.answer_support(cid, &embedding(0), 1).unwrap();
+    assert_eq!(ids(&support.supporting), vec![hit]);
+    assert!(support.contradicting.evidence.is_empty());
+    assert_eq!(support.contradicting.confidence, 0.0);
+}
+
+// ============================================================================
+// Rendering and Validation
+// ============================================================================
+
+#[test]
+fn test_render_lists_both_stances() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let best = record(&db, cid, "pin the toolchain", 0.9, embedding(0));
+    let disagrees = record(
+        &db,
+        cid,
+        "pinning hid a security fix",
+        0.7,
+        near_axis_zero(),
+    );
+    relate(&db, disagrees, best, RelationType::Contradicts);
+
+    let rendered = db.answer_support(cid, &embedding(0), 10).unwrap().render();
+    let contradicting = rendered.find("## Contradicting").unwrap();
+    assert!(rendered.starts_with("## Supporting (confidence "));
+    assert!(rendered[..contradicting].contains("pin the toolchain"));
+    assert!(rendered[contradicting..].contains("pinning hid a security fix"));
+}
+
+#[test]
+fn test_answer_support_validation() {
+    let (db, cid, _dir) = open_db_with_collective();
+    assert!(db
+        .answer_support(cid, &embedding(0), 0)
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .answer_support(CollectiveId::new(), &embedding(0), 5)
+        .unwrap_err()
+        .is_not_found());
+
+    let empty = db.answer_support(cid, &embedding(0), 5).unwrap();
+    assert!(empty.supporting.evidence.is_empty());
+    assert_eq!(empty.supporting.confidence, 0.0);
+}
@@ -0,0 +1,183 @@
+//! Integration tests for per-agent experience bookmarks.
+//!
+//! Tests the full stack: PulseDB facade -> validation -> StorageEngine -> redb.
+//! Covers add/remove, idempotence, per-agent isolation, recording order,
+//! cascade on delete, and persistence.
+
+use pulsedb::{CollectiveId, Config, ExperienceId, NewExperience, PulseDB};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record a minimal experience.
+fn record(db: &PulseDB, cid: CollectiveId, content: &str) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.to_string(),
+        embedding: Some(vec![0.1; 384]),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+// ============================================================================
+// Add + Remove
+// ============================================================================
+
+#[test]
+fn test_bookmark_and_list() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let first = record(&db, cid, "first");
+    let second = record(&db, cid, "second");
+
+    assert!(db.bookmark_experience("agent-1", second).unwrap());
+    assert!(db.bookmark_experience("agent-1", first).unwrap());
+    // Bookmarking twice is a no-op
+    assert!(!db.bookmark_experience("agent-1", first).unwrap());
+
+    let ids: Vec<_> = db
+        .list_bookmarks("agent-1")
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(ids, vec![first, second]);
+
+    // Other agents have their own lists
+    assert!(db.list_bookmarks("agent-2").unwrap().is_empty());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_bookmarks_listed_in_recording_order_for_any_id() {
+    let (db, cid, _dir) = open_db_with_collective();
+    // Custom IDs whose byte order is the reverse of recording order
+    let mut ids = Vec::new();
+    for (byte, content) in [(0xF0u8, "first"), (0x80, "second"), (0x10, "third")] {
+        let id = db
+            .record_experience(NewExperience {
+                collective_id: cid,
+                id: Some(ExperienceId::from_bytes([byte; 16])),
+                content: content.to_string(),
+                embedding: Some(vec![0.1; 384]),
+                ..Default::default()
+            })
+            .unwrap();
+        db.bookmark_experience("agent-1", id).unwrap();
+        ids.push(id);
+        std::thread::sleep(std::time::Duration::from_millis(2));
+    }
+
+    let listed: Vec<_> = db
+        .list_bookmarks("agent-1")
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(listed, ids);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_unbookmark() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let id = record(&db, cid, "pinned");
+    db.bookmark_experience("agent-1", id).unwrap();
+    db.bookmark_experience("agent-2", id).unwrap();
+
+    assert!(db.unbookmark_experience("agent-1", id).unwrap());
+    assert!(!db.unbookmark_experience("agent-1", id).unwrap());
+    assert!(db.list_bookmarks("agent-1").unwrap().is_empty());
+    assert_eq!(db.list_bookmarks("agent-2").unwrap().len(), 1);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_bookmark_validation() {
+    let (db, _cid, _dir) = open_db_with_collective();
+
+    assert!(db
+        .bookmark_experience("agent-1", ExperienceId::new())
+        .unwrap_err()
+        .is_not_found());
+    assert!(db
+        .bookmark_experience("", ExperienceId::new())
+        .unwrap_err()
+        .is_validation());
+    assert!(db.list_bookmarks("").unwrap_err().is_validation());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Cascade and Persistence
+// ============================================================================
+
+#[test]
+fn test_delete_experience_drops_bookmarks() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let gone = record(&db, cid, "gone");
+    let kept = record(&db, cid, "kept");
+    db.bookmark_experience("agent-1", gone).unwrap();
+    db.bookmark_experience("agent-1", kept).unwrap();
+    db.bookmark_experience("agent-2", gone).unwrap();
+
+    db.delete_experience(gone).unwrap();
+
+    let ids: Vec<_> = db
+        .list_bookmarks("agent-1")
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(ids, vec![kept]);
+    assert!(db.list_bookmarks("agent-2").unwrap().is_empty());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_delete_collective_drops_bookmarks() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let other = db.create_collective("other").unwrap();
+    let doomed = record(&db, cid, "doomed");
+    let survivor = record(&db, other, "survivor");
+    db.bookmark_experience("agent-1", doomed).unwrap();
+    db.bookmark_experience("agent-1", survivor).unwrap();
+
+    db.delete_collective(cid).unwrap();
+
+    let ids: Vec<_> = db
+        .list_bookmarks("agent-1")
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(ids, vec![survivor]);
+    // Re-bookmarking the deleted experience fails cleanly
+    assert!(db
+        .bookmark_experience("agent-1", doomed)
+        .unwrap_err()
+        .is_not_found());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_bookmarks_persist_across_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("test").unwrap();
+    let id = record(&db, cid, "durable");
+    db.bookmark_experience("agent-1", id).unwrap();
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert_eq!(db.list_bookmarks("agent-1").unwrap()[0].id, id);
+    db.close().unwrap();
+}
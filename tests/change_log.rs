@@ -0,0 +1,164 @@
+//! Integration tests for the change log.
+//!
+//! Tests the full stack: PulseDB facade -> WAL records in redb ->
+//! `changes_since` reads and commit-time pushes to subscriptions.
+//! Covers every entity type, resuming from a sequence, cascaded deletes,
+//! subscription ordering, and unsubscribing on drop.
+
+use std::time::Duration;
+
+use pulsedb::{
+    ChangedEntity, CollectiveId, Config, ExperienceId, NewExperience, NewExperienceRelation,
+    PulseDB, RelationType, WatchEventType,
+};
+use tempfile::tempdir;
+
+const DIM: usize = 384;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record an experience with a fixed embedding.
+fn record(db: &PulseDB, cid: CollectiveId) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: "content".into(),
+        embedding: Some(vec![0.1; DIM]),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+// ============================================================================
+// changes_since
+// ============================================================================
+
+#[test]
+fn test_changes_since_covers_every_entity_type() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let start = db.get_current_sequence().unwrap();
+
+    let a = record(&db, cid);
+    let b = record(&db, cid);
+    let relation = db
+        .store_relation(NewExperienceRelation {
+            source_id: a,
+            target_id: b,
+            relation_type: RelationType::Supports,
+            strength: 0.9,
+            metadata: None,
+        })
+        .unwrap();
+    db.delete_experience(b).unwrap();
+
+    let changes = db.changes_since(start).unwrap();
+    let summary: Vec<(ChangedEntity, WatchEventType)> =
+        changes.iter().map(|c| (c.entity, c.change_type)).collect();
+    assert_eq!(
+        summary,
+        [
+            (ChangedEntity::Experience(a), WatchEventType::Created),
+            (ChangedEntity::Experience(b), WatchEventType::Created),
+            (ChangedEntity::Relation(relation), WatchEventType::Created),
+            (ChangedEntity::Relation(relation), WatchEventType::Deleted),
+            (ChangedEntity::Experience(b), WatchEventType::Deleted),
+        ]
+    );
+    assert!(changes.iter().all(|c| c.collective_id == cid));
+    for pair in changes.windows(2) {
+        assert_eq!(pair[1].sequence, pair[0].sequence + 1);
+    }
+
+    // Resuming from the middle returns only the rest
+    let rest = db.changes_since(changes[2].sequence).unwrap();
+    assert_eq!(rest, changes[3..]);
+    assert!(db
+        .changes_since(changes.last().unwrap().sequence)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_collective_changes_are_logged() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let child = db.create_collective("child").unwrap();
+    let start = db.get_current_sequence().unwrap();
+
+    db.set_collective_parent(child, Some(cid)).unwrap();
+    record(&db, child);
+    db.delete_collective(child).unwrap();
+
+    let changes = db.changes_since(start).unwrap();
+    let first = &changes[0];
+    assert_eq!(first.entity, ChangedEntity::Collective(child));
+    assert_eq!(first.change_type, WatchEventType::Updated);
+
+    // The cascade is one event for the collective
+    let last = changes.last().unwrap();
+    assert_eq!(last.entity, ChangedEntity::Collective(child));
+    assert_eq!(last.change_type, WatchEventType::Deleted);
+    assert_eq!(changes.len(), 3);
+}
+
+// ============================================================================
+// subscribe
+// ============================================================================
+
+#[test]
+fn test_subscription_receives_new_changes_in_order() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let before = record(&db, cid);
+
+    let changes = db.subscribe().unwrap();
+    let a = record(&db, cid);
+    db.archive_experience(a).unwrap();
+
+    let first = changes.recv_timeout(Duration::from_secs(1)).unwrap();
+    let second = changes.recv_timeout(Duration::from_secs(1)).unwrap();
+    assert_eq!(first.entity, ChangedEntity::Experience(a));
+    assert_eq!(first.change_type, WatchEventType::Created);
+    assert_eq!(second.entity, ChangedEntity::Experience(a));
+    assert_eq!(second.change_type, WatchEventType::Archived);
+    assert_eq!(second.sequence, first.sequence + 1);
+    assert!(changes.try_recv().is_none());
+    assert_ne!(first.entity, ChangedEntity::Experience(before));
+
+    // Pushed changes match the log
+    assert_eq!(
+        db.changes_since(first.sequence - 1).unwrap(),
+        [first, second]
+    );
+}
+
+#[test]
+fn test_subscriptions_are_independent_and_end_with_the_database() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let first = db.subscribe().unwrap();
+    let second = db.subscribe().unwrap();
+
+    let id = record(&db, cid);
+    assert_eq!(
+        first.try_recv().unwrap().entity,
+        ChangedEntity::Experience(id)
+    );
+    drop(first);
+
+    record(&db, cid);
+    assert_eq!(
+        second.try_recv().unwrap().change_type,
+        WatchEventType::Created
+    );
+    assert_eq!(
+        second.try_recv().unwrap().change_type,
+        WatchEventType::Created
+    );
+    assert!(second.try_recv().is_none());
+
+    drop(db);
+    assert_eq!(second.count(), 0);
+}
@@ -0,0 +1,193 @@
+//! Integration tests for changeset knowledge lookup.
+//!
+//! Tests the full stack: PulseDB facade -> related-file index -> redb.
+//! Covers ranking by file match, relations and insights around the
+//! matches, exclusions, index maintenance on update, and validation.
+
+use pulsedb::{
+    CollectiveId, Config, ExperienceId, ExperienceUpdate, InsightType, NewDerivedInsight,
+    NewExperience, NewExperienceRelation, PulseDB, RelationType,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record an experience about some files.
+fn record(db: &PulseDB, cid: CollectiveId, content: &str, files: &[&str]) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.to_string(),
+        related_files: files.iter().map(|f| f.to_string()).collect(),
+        importance: 0.5,
+        confidence: 0.5,
+        embedding: Some(vec![0.1; 384]),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+// ============================================================================
+// Ranking
+// ============================================================================
+
+#[test]
+fn test_exact_matches_outrank_directory_matches() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let sibling = record(&db, cid, "lib.rs re-exports everything", &["src/lib.rs"]);
+    let exact = record(&db, cid, "db.rs is the facade", &["./src/db.rs"]);
+    let both = record(
+        &db,
+        cid,
+        "db.rs and its tests move together",
+        &["src/db.rs", "tests/lifecycle.rs"],
+    );
+    record(&db, cid, "unrelated", &["docs/guide.md"]);
+    record(&db, cid, "no files", &[]);
+
+    let knowledge = db
+        .knowledge_for_changeset(cid, &["src/db.rs", "tests/lifecycle.rs"])
+        .unwrap();
+    let ids: Vec<ExperienceId> = knowledge
+        .experiences
+        .iter()
+        .map(|found| found.experience.id)
+        .collect();
+    assert_eq!(ids, vec![both, exact, sibling]);
+
+    assert_eq!(
+        knowledge.experiences[0].matched_files,
+        vec!["src/db.rs", "tests/lifecycle.rs"]
+    );
+    assert!((knowledge.experiences[0].score - 1.0).abs() < 1e-6);
+    assert!((knowledge.experiences[1].score - 0.5).abs() < 1e-6);
+    assert!((knowledge.experiences[2].score - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn test_relations_and_insights_follow_matches() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let matched = record(&db, cid, "db.rs is the facade", &["src/db.rs"]);
+    let elsewhere = record(&db, cid, "guide explains the facade", &["docs/guide.md"]);
+    let other = record(&db, cid, "guide tone", &["docs/style.md"]);
+
+    db.store_relation(NewExperienceRelation {
+        source_id: elsewhere,
+        target_id: matched,
+        relation_type: RelationType::Elaborates,
+        strength: 0.7,
+        metadata: None,
+    })
+    .unwrap();
+    db.store_relation(NewExperienceRelation {
+        source_id: elsewhere,
+        target_id: other,
+        relation_type: RelationType::Supports,
+        strength: 0.9,
+        metadata: None,
+    })
+    .unwrap();
+    let store_insight = |content: &str, sources: Vec<ExperienceId>| {
+        db.store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: content.to_string(),
+            embedding: Some(vec![0.2; 384]),
+            source_experience_ids: sources,
+            insight_type: InsightType::Pattern,
+            confidence: 0.8,
+            domain: vec![],
+        })
+        .unwrap()
+    };
+    let citing = store_insight("the facade is documented", vec![matched, elsewhere]);
+    store_insight("docs are consistent", vec![elsewhere, other]);
+
+    let knowledge = db.knowledge_for_changeset(cid, &["src/db.rs"]).unwrap();
+    assert_eq!(knowledge.experiences.len(), 1);
+    assert_eq!(knowledge.relations.len(), 1);
+    assert_eq!(knowledge.relations[0].target_id, matched);
+    assert_eq!(knowledge.insights.len(), 1);
+    assert_eq!(knowledge.insights[0].insight.id, citing);
+    assert!((knowledge.insights[0].score - 0.4).abs() < 1e-6);
+}
+
+// ============================================================================
+// Exclusions and Index Maintenance
+// ============================================================================
+
+#[test]
+fn test_archived_and_other_collectives_are_excluded() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let other = db.create_collective("other").unwrap();
+    let archived = record(&db, cid, "old advice", &["src/db.rs"]);
+    db.archive_experience(archived).unwrap();
+    record(&db, other, "another team", &["src/db.rs"]);
+
+    let knowledge = db.knowledge_for_changeset(cid, &["src/db.rs"]).unwrap();
+    assert!(knowledge.experiences.is_empty());
+    assert!(knowledge.relations.is_empty());
+    assert!(knowledge.insights.is_empty());
+}
+
+#[test]
+fn test_updated_and_deleted_files_leave_the_index() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let id = record(&db, cid, "moved", &["src/old.rs"]);
+
+    db.update_experience(
+        id,
+        ExperienceUpdate {
+            related_files: Some(vec!["lib/new.rs".to_string()]),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert!(db
+        .knowledge_for_changeset(cid, &["src/old.rs"])
+        .unwrap()
+        .experiences
+        .is_empty());
+    assert_eq!(
+        db.knowledge_for_changeset(cid, &["lib/new.rs"])
+            .unwrap()
+            .experiences
+            .len(),
+        1
+    );
+
+    db.delete_experience(id).unwrap();
+    assert!(db
+        .knowledge_for_changeset(cid, &["lib/new.rs"])
+        .unwrap()
+        .experiences
+        .is_empty());
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn test_changeset_validation() {
+    let (db, cid, _dir) = open_db_with_collective();
+    assert!(db
+        .knowledge_for_changeset(cid, &["src/db.rs", "./"])
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .knowledge_for_changeset(CollectiveId::new(), &["src/db.rs"])
+        .unwrap_err()
+        .is_not_found());
+
+    let none: &[&str] = &[];
+    assert!(db
+        .knowledge_for_changeset(cid, none)
+        .unwrap()
+        .experiences
+        .is_empty());
+}
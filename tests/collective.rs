@@ -2,7 +2,16 @@
 //!
 //! Tests the full stack: PulseDB facade → StorageEngine → redb.
 
-use pulsedb::{CollectiveId, Config, EmbeddingDimension, PulseDB};
+use std::collections::HashMap;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use pulsedb::{
+    CollectiveId, CollectiveUpdate, CommittedWrite, Config, EmbeddingDimension, Hook,
+    NewExperience, PulseDB,
+};
 use tempfile::tempdir;
 
 /// Helper to open a fresh database with default config.
@@ -186,6 +195,112 @@ fn test_list_collectives_by_owner() {
     db.close().unwrap();
 }
 
+// ============================================================================
+// Update Collective
+// ============================================================================
+
+fn settings(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_update_collective_description_and_settings() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let id = db.create_collective("billing").unwrap();
+    let created = db.get_collective(id).unwrap().unwrap();
+    assert!(created.description.is_none());
+    assert!(created.settings.is_empty());
+
+    db.update_collective(
+        id,
+        CollectiveUpdate {
+            description: Some("Billing service".to_string()),
+            settings: Some(settings(&[
+                ("repo", "https://example.com/billing.git"),
+                ("team", "payments"),
+            ])),
+        },
+    )
+    .unwrap();
+    let updated = db.get_collective(id).unwrap().unwrap();
+    assert_eq!(updated.description.as_deref(), Some("Billing service"));
+    assert_eq!(updated.settings["team"], "payments");
+    assert!(updated.updated_at >= created.updated_at);
+
+    // Unset fields are left alone; settings are replaced wholesale
+    db.update_collective(
+        id,
+        CollectiveUpdate {
+            settings: Some(settings(&[("team", "platform")])),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let updated = db.get_collective(id).unwrap().unwrap();
+    assert_eq!(updated.description.as_deref(), Some("Billing service"));
+    assert_eq!(updated.settings, settings(&[("team", "platform")]));
+    db.close().unwrap();
+
+    // Details persist and show up in listings
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let listed = db.list_collectives().unwrap();
+    assert_eq!(listed[0].description.as_deref(), Some("Billing service"));
+    assert_eq!(listed[0].settings["team"], "platform");
+
+    // An empty description clears it
+    db.update_collective(
+        id,
+        CollectiveUpdate {
+            description: Some(String::new()),
+            settings: Some(HashMap::new()),
+        },
+    )
+    .unwrap();
+    let cleared = db.get_collective(id).unwrap().unwrap();
+    assert!(cleared.description.is_none());
+    assert!(cleared.settings.is_empty());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_update_collective_errors() {
+    let (db, _dir) = open_db();
+    let err = db
+        .update_collective(CollectiveId::new(), CollectiveUpdate::default())
+        .unwrap_err();
+    assert!(err.is_not_found());
+
+    let id = db.create_collective("c").unwrap();
+    let err = db
+        .update_collective(
+            id,
+            CollectiveUpdate {
+                settings: Some(settings(&[("", "v")])),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    db.freeze_collective(id).unwrap();
+    let err = db
+        .update_collective(
+            id,
+            CollectiveUpdate {
+                description: Some("frozen".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert!(err.is_busy());
+    db.close().unwrap();
+}
+
 // ============================================================================
 // Get Collective Stats
 // ============================================================================
@@ -205,6 +320,54 @@ fn test_get_collective_stats() {
     db.close().unwrap();
 }
 
+#[test]
+fn test_get_collective_stats_vector_index() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let id = db.create_collective("index-stats").unwrap();
+
+    let mut ids = Vec::new();
+    for i in 0..3 {
+        ids.push(
+            db.record_experience(NewExperience {
+                collective_id: id,
+                content: format!("experience {}", i),
+                embedding: Some(vec![0.1 + i as f32; 384]),
+                ..Default::default()
+            })
+            .unwrap(),
+        );
+    }
+    db.delete_experience(ids[0]).unwrap();
+
+    let index = db.get_collective_stats(id).unwrap().vector_index.unwrap();
+    assert_eq!(index.active_vectors, 2);
+    assert_eq!(index.deleted_vectors, 1);
+    assert_eq!(index.segments, 1);
+    assert!(index.graph_levels >= 1);
+    assert!(index.memory_bytes >= 3 * 384 * 4);
+    // Created empty, so never rebuilt yet
+    assert!(index.last_rebuild.is_none());
+    db.close().unwrap();
+
+    // Reopening loads the saved graph, which is not a rebuild
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let index = db.get_collective_stats(id).unwrap().vector_index.unwrap();
+    assert_eq!(index.active_vectors, 2);
+    assert!(index.last_rebuild.is_none());
+    db.close().unwrap();
+
+    // Without the saved graph, reopening rebuilds from the stored embeddings
+    std::fs::remove_dir_all(path.with_extension("db.hnsw")).unwrap();
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let index = db.get_collective_stats(id).unwrap().vector_index.unwrap();
+    assert_eq!(index.active_vectors, 2);
+    assert!(index.last_rebuild.is_some());
+    assert!(index.last_rebuild_duration.is_some());
+    db.close().unwrap();
+}
+
 #[test]
 fn test_get_collective_stats_nonexistent() {
     let (db, _dir) = open_db();
@@ -343,3 +506,486 @@ fn test_collectives_persist_across_reopen() {
 
     db.close().unwrap();
 }
+
+// ============================================================================
+// Write Fencing
+// ============================================================================
+
+fn experience_in(collective_id: CollectiveId) -> pulsedb::NewExperience {
+    pulsedb::NewExperience {
+        collective_id,
+        content: "fenced".to_string(),
+        embedding: Some(vec![0.1; 384]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_freeze_collective_blocks_writes_but_not_reads() {
+    let (db, _dir) = open_db();
+    let id = db.create_collective("fenced").unwrap();
+    let exp_id = db.record_experience(experience_in(id)).unwrap();
+
+    db.freeze_collective(id).unwrap();
+    assert!(db.is_collective_frozen(id));
+
+    let err = db.record_experience(experience_in(id)).unwrap_err();
+    assert!(err.is_busy());
+    assert!(db.reinforce_experience(exp_id).unwrap_err().is_busy());
+    assert!(db.delete_experience(exp_id).unwrap_err().is_busy());
+    assert!(db.delete_collective(id).unwrap_err().is_busy());
+
+    // Reads stay available
+    assert!(db.get_experience(exp_id).unwrap().is_some());
+    assert_eq!(db.search_similar(id, &[0.1; 384], 5).unwrap().len(), 1);
+
+    db.thaw_collective(id).unwrap();
+    assert!(!db.is_collective_frozen(id));
+    db.record_experience(experience_in(id)).unwrap();
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_freeze_collective_does_not_affect_other_collectives() {
+    let (db, _dir) = open_db();
+    let frozen = db.create_collective("frozen").unwrap();
+    let open = db.create_collective("open").unwrap();
+
+    db.freeze_collective(frozen).unwrap();
+    db.record_experience(experience_in(open)).unwrap();
+
+    db.close().unwrap();
+}
+
+/// Hook that parks the writing thread after commit until released.
+struct ParkAfterCommit {
+    entered: Mutex<Sender<()>>,
+    release: Mutex<Receiver<()>>,
+}
+
+impl Hook for ParkAfterCommit {
+    fn post_write(&self, _write: &CommittedWrite<'_>) -> pulsedb::Result<()> {
+        self.entered.lock().unwrap().send(()).unwrap();
+        self.release.lock().unwrap().recv().unwrap();
+        Ok(())
+    }
+}
+
+#[test]
+fn test_freeze_collective_waits_for_in_flight_writes() {
+    let (db, _dir) = open_db();
+    let id = db.create_collective("draining").unwrap();
+    let (entered_tx, entered_rx) = channel();
+    let (release_tx, release_rx) = channel();
+    db.add_hook(Arc::new(ParkAfterCommit {
+        entered: Mutex::new(entered_tx),
+        release: Mutex::new(release_rx),
+    }));
+
+    thread::scope(|scope| {
+        let writer = scope.spawn(|| db.record_experience(experience_in(id)).unwrap());
+        entered_rx.recv().unwrap();
+
+        let freezer = scope.spawn(|| db.freeze_collective(id).unwrap());
+        thread::sleep(Duration::from_millis(100));
+        assert!(!freezer.is_finished());
+
+        release_tx.send(()).unwrap();
+        writer.join().unwrap();
+        freezer.join().unwrap();
+    });
+    assert!(db.is_collective_frozen(id));
+    db.clear_hooks();
+    db.close().unwrap();
+}
+
+#[test]
+fn test_freeze_collective_twice_is_busy() {
+    let (db, _dir) = open_db();
+    let id = db.create_collective("double").unwrap();
+
+    db.freeze_collective(id).unwrap();
+    assert!(db.freeze_collective(id).unwrap_err().is_busy());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_freeze_nonexistent_collective() {
+    let (db, _dir) = open_db();
+
+    let err = db.freeze_collective(CollectiveId::new()).unwrap_err();
+    assert!(err.is_not_found());
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Hierarchy
+// ============================================================================
+
+fn embedded_experience_in(collective_id: CollectiveId, seed: f32) -> pulsedb::NewExperience {
+    let mut embedding = vec![0.0f32; 384];
+    embedding[0] = 1.0;
+    embedding[1] = seed;
+    pulsedb::NewExperience {
+        collective_id,
+        content: format!("experience {}", seed),
+        embedding: Some(embedding),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_create_sub_collective_links_parent_and_inherits_owner() {
+    let (db, _dir) = open_db();
+    let team = db.create_collective_with_owner("team", "tenant-1").unwrap();
+    let project = db.create_sub_collective(team, "project").unwrap();
+    let feature = db.create_sub_collective(project, "feature").unwrap();
+
+    assert_eq!(db.get_parent_collective(team).unwrap(), None);
+    assert_eq!(db.get_parent_collective(project).unwrap(), Some(team));
+    assert_eq!(
+        db.get_collective(feature)
+            .unwrap()
+            .unwrap()
+            .owner_id
+            .as_deref(),
+        Some("tenant-1")
+    );
+
+    let children = db.list_child_collectives(team).unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].id, project);
+    assert_eq!(
+        db.list_descendant_collectives(team).unwrap(),
+        vec![project, feature]
+    );
+
+    assert!(db
+        .create_sub_collective(CollectiveId::new(), "orphan")
+        .unwrap_err()
+        .is_not_found());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_set_collective_parent_rejects_cycles() {
+    let (db, _dir) = open_db();
+    let a = db.create_collective("a").unwrap();
+    let b = db.create_sub_collective(a, "b").unwrap();
+    let c = db.create_collective("c").unwrap();
+
+    assert!(db
+        .set_collective_parent(a, Some(b))
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .set_collective_parent(a, Some(a))
+        .unwrap_err()
+        .is_validation());
+
+    // Re-parent b under c, then detach it
+    db.set_collective_parent(b, Some(c)).unwrap();
+    assert!(db.list_child_collectives(a).unwrap().is_empty());
+    assert_eq!(db.get_parent_collective(b).unwrap(), Some(c));
+    db.set_collective_parent(b, None).unwrap();
+    assert_eq!(db.get_parent_collective(b).unwrap(), None);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_search_includes_descendants_when_requested() {
+    let (db, _dir) = open_db();
+    let team = db.create_collective("team").unwrap();
+    let project = db.create_sub_collective(team, "project").unwrap();
+    let other = db.create_collective("other").unwrap();
+
+    db.record_experience(embedded_experience_in(team, 0.1))
+        .unwrap();
+    // Keep timestamps distinct so the recency order is deterministic
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    let child_exp = db
+        .record_experience(embedded_experience_in(project, 0.2))
+        .unwrap();
+    db.record_experience(embedded_experience_in(other, 0.3))
+        .unwrap();
+
+    let query = embedded_experience_in(team, 0.2).embedding.unwrap();
+    let scoped = db.search_similar(team, &query, 10).unwrap();
+    assert_eq!(scoped.len(), 1);
+
+    let filter = pulsedb::SearchFilter {
+        include_descendants: true,
+        ..pulsedb::SearchFilter::default()
+    };
+    let tree = db
+        .search_similar_filtered(team, &query, 10, filter.clone())
+        .unwrap();
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree[0].experience.id, child_exp);
+
+    let recent = db
+        .get_recent_experiences_filtered(team, 10, filter)
+        .unwrap();
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent[0].id, child_exp);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_stats_rollup_and_delete_guard() {
+    let (db, _dir) = open_db();
+    let team = db.create_collective("team").unwrap();
+    let project = db.create_sub_collective(team, "project").unwrap();
+
+    db.record_experience(experience_in(team)).unwrap();
+    db.record_experience(experience_in(project)).unwrap();
+    db.record_experience(experience_in(project)).unwrap();
+
+    assert_eq!(db.get_collective_stats(team).unwrap().experience_count, 1);
+    assert_eq!(
+        db.get_collective_stats_rollup(team)
+            .unwrap()
+            .experience_count,
+        3
+    );
+
+    // Parents with children cannot be deleted
+    assert!(db.delete_collective(team).unwrap_err().is_validation());
+    db.delete_collective(project).unwrap();
+    assert!(db.list_child_collectives(team).unwrap().is_empty());
+    db.delete_collective(team).unwrap();
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_stats_rollup_sums_storage_bytes() {
+    let (db, _dir) = open_db();
+    let team = db.create_collective("team").unwrap();
+    let project = db.create_sub_collective(team, "project").unwrap();
+    assert_eq!(db.get_collective_stats(team).unwrap().storage_bytes, 0);
+
+    db.record_experience(experience_in(team)).unwrap();
+    db.record_experience(experience_in(project)).unwrap();
+
+    let own = db.get_collective_stats(team).unwrap().storage_bytes;
+    let child = db.get_collective_stats(project).unwrap().storage_bytes;
+    assert!(own > 0 && child > 0);
+    assert_eq!(
+        db.get_collective_stats_rollup(team).unwrap().storage_bytes,
+        own + child
+    );
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_quota_counts_sub_collective_writes() {
+    let (db, _dir) = open_db();
+    let team = db.create_collective("team").unwrap();
+    let project = db.create_sub_collective(team, "project").unwrap();
+    let quota = pulsedb::CollectiveQuota {
+        max_experiences: Some(2),
+    };
+    db.set_collective_quota(team, quota.clone()).unwrap();
+    assert_eq!(db.get_collective_quota(team).unwrap(), quota);
+    assert_eq!(
+        db.get_collective_quota(project).unwrap(),
+        pulsedb::CollectiveQuota::default()
+    );
+
+    db.record_experience(experience_in(project)).unwrap();
+    let report = db
+        .record_experiences_batch(vec![experience_in(project), experience_in(team)])
+        .unwrap();
+    assert!(matches!(
+        report.outcomes[0],
+        pulsedb::BatchOutcome::Accepted(_)
+    ));
+    assert!(matches!(
+        report.outcomes[1],
+        pulsedb::BatchOutcome::Rejected(_)
+    ));
+    assert!(db
+        .record_experience(experience_in(project))
+        .unwrap_err()
+        .is_validation());
+
+    // Removing the limit lets writes through again
+    db.set_collective_quota(team, pulsedb::CollectiveQuota::default())
+        .unwrap();
+    db.record_experience(experience_in(project)).unwrap();
+    assert_eq!(
+        db.get_collective_stats_rollup(team)
+            .unwrap()
+            .experience_count,
+        3
+    );
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_hierarchy_persists_across_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let team = db.create_collective("team").unwrap();
+    let project = db.create_sub_collective(team, "project").unwrap();
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert_eq!(db.get_parent_collective(project).unwrap(), Some(team));
+    assert_eq!(db.list_descendant_collectives(team).unwrap(), vec![project]);
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Owner Aggregates
+// ============================================================================
+
+#[test]
+fn test_stats_by_owner() {
+    let (db, _dir) = open_db();
+    let a = db.create_collective_with_owner("a", "tenant-1").unwrap();
+    let b = db.create_collective_with_owner("b", "tenant-1").unwrap();
+    let other = db.create_collective_with_owner("c", "tenant-2").unwrap();
+
+    db.record_experience(experience_in(a)).unwrap();
+    db.record_experience(experience_in(b)).unwrap();
+    db.record_experience(experience_in(b)).unwrap();
+    db.record_experience(experience_in(other)).unwrap();
+
+    let stats = db.stats_by_owner("tenant-1").unwrap();
+    assert_eq!(stats.collective_count, 2);
+    assert_eq!(stats.experience_count, 3);
+    assert_eq!(stats.insight_count, 0);
+
+    let empty = db.stats_by_owner("nobody").unwrap();
+    assert_eq!(empty.collective_count, 0);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_delete_collectives_by_owner() {
+    let (db, _dir) = open_db();
+    let team = db.create_collective_with_owner("team", "tenant-1").unwrap();
+    let project = db.create_sub_collective(team, "project").unwrap();
+    let keep = db.create_collective_with_owner("keep", "tenant-2").unwrap();
+    db.record_experience(experience_in(project)).unwrap();
+
+    assert_eq!(db.delete_collectives_by_owner("tenant-1").unwrap(), 2);
+    assert!(db.get_collective(team).unwrap().is_none());
+    assert!(db.get_collective(project).unwrap().is_none());
+    assert!(db.list_collectives_by_owner("tenant-1").unwrap().is_empty());
+    assert!(db.get_collective(keep).unwrap().is_some());
+
+    assert_eq!(db.delete_collectives_by_owner("tenant-1").unwrap(), 0);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_delete_collectives_by_owner_checks_before_deleting() {
+    let (db, _dir) = open_db();
+    let team = db.create_collective_with_owner("team", "tenant-1").unwrap();
+    let foreign = db
+        .create_collective_with_owner("foreign", "tenant-2")
+        .unwrap();
+    db.set_collective_parent(foreign, Some(team)).unwrap();
+
+    let err = db.delete_collectives_by_owner("tenant-1").unwrap_err();
+    assert!(err.is_validation());
+    assert!(db.get_collective(team).unwrap().is_some());
+
+    let solo = db.create_collective_with_owner("solo", "tenant-3").unwrap();
+    db.freeze_collective(solo).unwrap();
+    assert!(db
+        .delete_collectives_by_owner("tenant-3")
+        .unwrap_err()
+        .is_busy());
+    assert!(db.get_collective(solo).unwrap().is_some());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_owner_index_survives_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let id = db
+        .create_collective_with_owner("owned", "tenant-1")
+        .unwrap();
+    db.create_collective("unowned").unwrap();
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let owned = db.list_collectives_by_owner("tenant-1").unwrap();
+    assert_eq!(owned.len(), 1);
+    assert_eq!(owned[0].id, id);
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Embedding Stats
+// ============================================================================
+
+#[test]
+fn test_embedding_stats() {
+    let (db, _dir) = open_db();
+    let id = db.create_collective("stats").unwrap();
+
+    let empty = db.embedding_stats(id).unwrap();
+    assert_eq!(empty.embedding_count, 0);
+    assert!(empty.intrinsic_dimension.is_none());
+
+    // Points at pseudo-random angles on a circle in a 2D subspace:
+    // intrinsic dimension 1. Every other point is scaled by 2, so norms are
+    // 1 or 2.
+    let points = 200;
+    let mut seed = 42u64;
+    for i in 0..points {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let angle = (seed >> 40) as f32 / (1u64 << 24) as f32 * std::f32::consts::TAU;
+        let scale = if i % 2 == 0 { 1.0 } else { 2.0 };
+        let mut embedding = vec![0.0; 384];
+        embedding[0] = angle.cos() * scale;
+        embedding[1] = angle.sin() * scale;
+        db.record_experience(NewExperience {
+            collective_id: id,
+            content: format!("point {}", i),
+            embedding: Some(embedding),
+            ..Default::default()
+        })
+        .unwrap();
+    }
+
+    let stats = db.embedding_stats(id).unwrap();
+    assert_eq!(stats.embedding_count, points);
+    assert!((stats.norm_min - 1.0).abs() < 1e-4);
+    assert!((stats.norm_max - 2.0).abs() < 1e-4);
+    assert!((stats.norm_mean - 1.5).abs() < 1e-4);
+    assert!((stats.norm_std_dev - 0.5).abs() < 1e-4);
+    assert_eq!(stats.mean_vector.len(), 384);
+    assert!(stats.mean_vector[2..].iter().all(|&x| x == 0.0));
+    let dimension = stats.intrinsic_dimension.unwrap();
+    assert!((0.5..2.0).contains(&dimension), "estimate {}", dimension);
+    // 200 points on a circle sit far closer than the 0.98 cutoff (~11°)
+    assert!(stats.near_duplicate_rate > 0.9);
+
+    assert!(db.embedding_stats(CollectiveId::new()).is_err());
+
+    db.close().unwrap();
+}
@@ -0,0 +1,235 @@
+//! Integration tests for streaming collective archives.
+//!
+//! Tests the full stack: PulseDB facade -> archive stream -> PulseDB facade.
+//! Covers roundtrip to another database, ID remapping on collision,
+//! cleanup after a corrupt or truncated stream, dimension mismatch, and
+//! unknown collectives.
+
+use pulsedb::{
+    CollectiveExportReport, CollectiveId, Config, EmbeddingDimension, ExperienceId, InsightType,
+    ModelAttribution, NewDerivedInsight, NewExperience, NewExperienceRelation, PulseDB,
+    RelationType,
+};
+use tempfile::tempdir;
+
+/// Helper: populate a collective with two experiences (one
+/// model-attributed and bookmarked), a relation, and an insight. Returns
+/// the collective and the two experience IDs.
+fn populate(db: &PulseDB) -> (CollectiveId, ExperienceId, ExperienceId) {
+    let cid = db.create_collective("shared").unwrap();
+    let a = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "first".to_string(),
+            embedding: Some(vec![0.1; 384]),
+            ..Default::default()
+        })
+        .unwrap();
+    let b = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "second".to_string(),
+            embedding: Some(vec![0.2; 384]),
+            attribution: Some(ModelAttribution {
+                model_name: "gpt-4o".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .unwrap();
+    db.bookmark_experience("reviewer", b).unwrap();
+    db.store_relation(NewExperienceRelation {
+        source_id: a,
+        target_id: b,
+        relation_type: RelationType::Supports,
+        strength: 0.8,
+        metadata: None,
+    })
+    .unwrap();
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: "first supports second".to_string(),
+        embedding: Some(vec![0.15; 384]),
+        source_experience_ids: vec![a, b],
+        insight_type: InsightType::Pattern,
+        confidence: 0.9,
+        domain: vec![],
+    })
+    .unwrap();
+    (cid, a, b)
+}
+
+/// Helper: export a collective to bytes.
+fn archive(db: &PulseDB, cid: CollectiveId) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let report = db.export_collective(cid, &mut bytes).unwrap();
+    assert_eq!(
+        report,
+        CollectiveExportReport {
+            experiences: 2,
+            relations: 1,
+            insights: 1,
+            bookmarks: 1,
+        }
+    );
+    bytes
+}
+
+// ============================================================================
+// Roundtrip
+// ============================================================================
+
+#[test]
+fn test_archive_roundtrip_to_another_database() {
+    let dir = tempdir().unwrap();
+    let source = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let (cid, a, b) = populate(&source);
+    let bytes = archive(&source, cid);
+    assert!(bytes.starts_with(b"PULSEEXP"));
+
+    let target = PulseDB::open(dir.path().join("target.db"), Config::default()).unwrap();
+    let report = target.import_collective(bytes.as_slice()).unwrap();
+    assert_eq!(report.collective_id, cid);
+    assert_eq!(report.experiences, 2);
+    assert_eq!(report.relations, 1);
+    assert_eq!(report.insights, 1);
+    assert_eq!(report.bookmarks, 1);
+    assert_eq!(report.remapped, 0);
+
+    // Records keep their IDs, and the vector indexes are populated
+    assert_eq!(target.get_collective(cid).unwrap().unwrap().name, "shared");
+    let hits = target.search_similar(cid, &[0.2; 384], 5).unwrap();
+    assert_eq!(hits.len(), 2);
+    let second = target.get_experience(b).unwrap().unwrap();
+    assert_eq!(second.embedding, vec![0.2; 384]);
+    assert_eq!(second.attribution.unwrap().model_name, "gpt-4o");
+    let relations = target.list_relations(cid, 10, 0).unwrap();
+    assert_eq!((relations[0].source_id, relations[0].target_id), (a, b));
+    assert_eq!(target.get_insights(cid, &[0.15; 384], 5).unwrap().len(), 1);
+    let bookmarks = target.list_bookmarks("reviewer").unwrap();
+    assert_eq!(bookmarks.len(), 1);
+    assert_eq!(bookmarks[0].id, b);
+}
+
+// ============================================================================
+// ID Remapping
+// ============================================================================
+
+#[test]
+fn test_reimport_remaps_colliding_ids() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let (cid, a, b) = populate(&db);
+    let bytes = archive(&db, cid);
+
+    let report = db.import_collective(bytes.as_slice()).unwrap();
+    assert_ne!(report.collective_id, cid);
+    // The collective, two experiences, a relation, and an insight
+    assert_eq!(report.remapped, 5);
+
+    let copy = report.collective_id;
+    let experiences = db.list_experiences(copy, 10, 0).unwrap();
+    assert_eq!(experiences.len(), 2);
+    let copied: Vec<ExperienceId> = experiences.iter().map(|e| e.id).collect();
+    assert!(!copied.contains(&a) && !copied.contains(&b));
+    assert!(experiences.iter().all(|e| e.collective_id == copy));
+
+    // References follow the new IDs
+    let relation = &db.list_relations(copy, 10, 0).unwrap()[0];
+    assert!(copied.contains(&relation.source_id) && copied.contains(&relation.target_id));
+    let insight = &db.list_insights(copy, 10, 0).unwrap()[0];
+    assert!(insight
+        .source_experience_ids
+        .iter()
+        .all(|id| copied.contains(id)));
+    assert_eq!(db.search_similar(copy, &[0.2; 384], 5).unwrap().len(), 2);
+    let bookmarked: Vec<ExperienceId> = db
+        .list_bookmarks("reviewer")
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(bookmarked.len(), 2);
+    assert!(bookmarked.contains(&b));
+
+    // The original is untouched
+    assert_eq!(db.list_experiences(cid, 10, 0).unwrap().len(), 2);
+    assert_eq!(db.search_similar(cid, &[0.2; 384], 5).unwrap().len(), 2);
+}
+
+// ============================================================================
+// Failure Handling
+// ============================================================================
+
+#[test]
+fn test_truncated_archive_leaves_nothing_behind() {
+    let dir = tempdir().unwrap();
+    let source = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let (cid, _, _) = populate(&source);
+    let bytes = archive(&source, cid);
+
+    let target = PulseDB::open(dir.path().join("target.db"), Config::default()).unwrap();
+    let err = target
+        .import_collective(&bytes[..bytes.len() - 10])
+        .unwrap_err();
+    assert!(err.is_storage());
+    assert!(target.list_collectives().unwrap().is_empty());
+
+    let mut corrupt = bytes.clone();
+    let at = corrupt.len() - 30;
+    corrupt[at] ^= 0xFF;
+    assert!(target.import_collective(corrupt.as_slice()).is_err());
+    assert!(target.list_collectives().unwrap().is_empty());
+
+    // An intact archive still imports afterwards
+    target.import_collective(bytes.as_slice()).unwrap();
+    assert_eq!(target.list_experiences(cid, 10, 0).unwrap().len(), 2);
+}
+
+#[test]
+fn test_dimension_mismatch_rejected() {
+    let dir = tempdir().unwrap();
+    let source = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let (cid, _, _) = populate(&source);
+    let bytes = archive(&source, cid);
+
+    let config = Config {
+        embedding_dimension: EmbeddingDimension::D768,
+        ..Default::default()
+    };
+    let target = PulseDB::open(dir.path().join("target.db"), config).unwrap();
+    let err = target.import_collective(bytes.as_slice()).unwrap_err();
+    assert!(err.is_validation());
+    assert!(target.list_collectives().unwrap().is_empty());
+}
+
+#[test]
+fn test_full_exports_and_collective_exports_do_not_mix() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let (cid, _, _) = populate(&db);
+
+    let full = dir.path().join("full.pulse");
+    db.export(&full).unwrap();
+    let err = db
+        .import_collective(std::fs::File::open(&full).unwrap())
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    let single = dir.path().join("single.pulse");
+    db.export_collective(cid, std::fs::File::create(&single).unwrap())
+        .unwrap();
+    let err = db.import(&single).unwrap_err();
+    assert!(err.is_validation(), "{err:?}");
+    assert_eq!(db.list_collectives().unwrap().len(), 1);
+}
+
+#[test]
+fn test_export_unknown_collective_is_not_found() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let err = db
+        .export_collective(CollectiveId::new(), Vec::new())
+        .unwrap_err();
+    assert!(err.is_not_found());
+}
@@ -0,0 +1,220 @@
+//! Integration tests for memory consolidation.
+//!
+//! Tests the full stack: PulseDB facade -> vector index clustering ->
+//! insights, relations, and archiving in redb.
+//! Covers clustering by similarity and importance, the written insight and
+//! `Summarizes` relations, idempotence, and validation.
+
+use std::collections::HashSet;
+
+use pulsedb::{
+    CollectiveId, Config, ConsolidationPolicy, ExperienceId, InsightType, NewExperience, PulseDB,
+    RelationDirection, RelationType, ScoreKind,
+};
+use tempfile::tempdir;
+
+const DIM: usize = 384;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: an embedding along `axis`, tilted slightly toward `axis + 1`.
+fn near_axis(axis: usize, tilt: f32) -> Vec<f32> {
+    let mut embedding = vec![0.0; DIM];
+    embedding[axis] = 1.0;
+    embedding[axis + 1] = tilt;
+    embedding
+}
+
+/// Helper: record an experience with the given embedding and importance.
+fn record(
+    db: &PulseDB,
+    cid: CollectiveId,
+    content: &str,
+    embedding: Vec<f32>,
+    importance: f32,
+) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.into(),
+        embedding: Some(embedding),
+        importance,
+        domain: vec!["build".into()],
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+// ============================================================================
+// Clustering
+// ============================================================================
+
+#[test]
+fn test_consolidates_similar_low_importance_experiences() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let cache: Vec<ExperienceId> = [0.05, 0.0, 0.1]
+        .iter()
+        .enumerate()
+        .map(|(i, tilt)| {
+            record(
+                &db,
+                cid,
+                &format!("cache miss {}", i),
+                near_axis(0, *tilt),
+                0.1,
+            )
+        })
+        .collect();
+    let important = record(&db, cid, "cache design", near_axis(0, 0.02), 0.9);
+    let lonely = record(&db, cid, "unrelated", near_axis(10, 0.0), 0.1);
+    let pair: Vec<ExperienceId> = [0.0, 0.05]
+        .iter()
+        .map(|tilt| record(&db, cid, "flaky test", near_axis(20, *tilt), 0.1))
+        .collect();
+
+    let report = db.consolidate(cid, ConsolidationPolicy::default()).unwrap();
+    assert_eq!(report.candidates, 6);
+    assert_eq!(report.clusters.len(), 1);
+    assert_eq!(report.archived, 3);
+    assert_eq!(report.relations, 2);
+
+    let cluster = &report.clusters[0];
+    let members: HashSet<ExperienceId> = cluster.members.iter().copied().collect();
+    assert_eq!(members, cache.iter().copied().collect());
+    // The middle tilt sits between the other two
+    assert_eq!(cluster.representative, cache[0]);
+    assert!(cluster.cohesion > 0.99);
+
+    for id in &cache {
+        assert!(db.get_experience(*id).unwrap().unwrap().archived);
+    }
+    for id in [important, lonely, pair[0], pair[1]] {
+        assert!(!db.get_experience(id).unwrap().unwrap().archived);
+    }
+}
+
+#[test]
+fn test_cluster_insight_and_relations() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let ids: Vec<ExperienceId> = [0.05, 0.0, 0.1]
+        .iter()
+        .enumerate()
+        .map(|(i, tilt)| {
+            record(
+                &db,
+                cid,
+                &format!("cache miss {}", i),
+                near_axis(0, *tilt),
+                0.1,
+            )
+        })
+        .collect();
+
+    let report = db.consolidate(cid, ConsolidationPolicy::default()).unwrap();
+    let cluster = &report.clusters[0];
+
+    let insight = db.get_insight(cluster.insight_id).unwrap().unwrap();
+    assert_eq!(insight.content, "cache miss 0");
+    assert_eq!(insight.insight_type, InsightType::Synthesis);
+    assert_eq!(insight.domain, vec!["build".to_string()]);
+    assert_eq!(insight.source_experience_ids, cluster.members);
+    assert_eq!(insight.confidence, cluster.cohesion);
+
+    let related = db
+        .get_related_experiences(cluster.representative, RelationDirection::Outgoing)
+        .unwrap();
+    assert_eq!(related.len(), 2);
+    for (experience, relation) in &related {
+        assert_eq!(relation.relation_type, RelationType::Summarizes);
+        assert!(experience.id == ids[1] || experience.id == ids[2]);
+        assert!(relation.strength > 0.99);
+    }
+}
+
+#[test]
+fn test_consolidation_is_idempotent() {
+    let (db, cid, _dir) = open_db_with_collective();
+    for tilt in [0.0, 0.05, 0.1] {
+        record(&db, cid, "cache miss", near_axis(0, tilt), 0.1);
+    }
+    assert_eq!(
+        db.consolidate(cid, ConsolidationPolicy::default())
+            .unwrap()
+            .clusters
+            .len(),
+        1
+    );
+
+    let again = db.consolidate(cid, ConsolidationPolicy::default()).unwrap();
+    assert_eq!(again.candidates, 0);
+    assert!(again.clusters.is_empty());
+}
+
+#[test]
+fn test_similarity_threshold_controls_clusters() {
+    let (db, cid, _dir) = open_db_with_collective();
+    // ~0.89 similarity between neighbors
+    for tilt in [0.0, 0.5, 1.0] {
+        record(&db, cid, "loosely related", near_axis(0, tilt), 0.1);
+    }
+
+    let strict = ConsolidationPolicy {
+        min_similarity: 0.95,
+        ..Default::default()
+    };
+    assert!(db.consolidate(cid, strict).unwrap().clusters.is_empty());
+
+    let loose = ConsolidationPolicy {
+        min_similarity: 0.7,
+        ..Default::default()
+    };
+    assert_eq!(db.consolidate(cid, loose).unwrap().archived, 3);
+}
+
+#[test]
+fn test_similarity_threshold_uses_score_kind() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        score_kind: ScoreKind::NormalizedDot,
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    // Cosine ~0.89 and ~0.71 from the seed, normalized dot ~0.95 and ~0.85
+    for tilt in [0.0, 0.5, 1.0] {
+        record(&db, cid, "loosely related", near_axis(0, tilt), 0.1);
+    }
+
+    let policy = ConsolidationPolicy {
+        min_similarity: 0.84,
+        ..Default::default()
+    };
+    assert_eq!(db.consolidate(cid, policy).unwrap().archived, 3);
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn test_consolidation_validation() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let invalid = ConsolidationPolicy {
+        min_cluster_size: 1,
+        ..Default::default()
+    };
+    assert!(db.consolidate(cid, invalid).unwrap_err().is_validation());
+    assert!(db
+        .consolidate(CollectiveId::new(), ConsolidationPolicy::default())
+        .unwrap_err()
+        .is_not_found());
+
+    let empty = db.consolidate(cid, ConsolidationPolicy::default()).unwrap();
+    assert_eq!(empty.candidates, 0);
+    assert!(empty.clusters.is_empty());
+}
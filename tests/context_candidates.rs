@@ -5,8 +5,9 @@
 //! handling.
 
 use pulsedb::{
-    CollectiveId, Config, ContextRequest, ExperienceId, InsightType, NewActivity,
-    NewDerivedInsight, NewExperience, NewExperienceRelation, PulseDB, RelationType, SearchFilter,
+    ApproxTokenCounter, CollectiveId, Config, ContextItem, ContextRequest, ExperienceId,
+    InsightType, NewActivity, NewDerivedInsight, NewExperience, NewExperienceRelation, PulseDB,
+    RelationType, RenderStyle, SearchFilter,
 };
 use tempfile::tempdir;
 
@@ -155,6 +156,21 @@ fn test_context_includes_insights() {
         candidates.insights[0].content,
         "Error handling pattern detected"
     );
+
+    // A similarity cutoff applies to insights as well as experiences
+    let candidates = db
+        .get_context_candidates(ContextRequest {
+            collective_id: cid,
+            query_embedding: make_embedding(999),
+            include_insights: true,
+            include_relations: false,
+            include_active_agents: false,
+            filter: SearchFilter::default().min_similarity(0.99),
+            ..ContextRequest::default()
+        })
+        .unwrap();
+    assert!(candidates.insights.is_empty());
+    assert!(candidates.similar_experiences.is_empty());
 }
 
 #[test]
@@ -204,6 +220,7 @@ fn test_context_includes_active_agents() {
         collective_id: cid,
         current_task: Some("Reviewing code".to_string()),
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -258,6 +275,7 @@ fn test_context_respects_flags() {
         collective_id: cid,
         current_task: None,
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -551,3 +569,192 @@ fn test_context_relations_deduplicated() {
         );
     }
 }
+
+// ============================================================================
+// Insight Source Collapsing
+// ============================================================================
+
+#[test]
+fn test_context_collapses_insight_sources() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let exp_ids = record_experiences(&db, cid, &[10, 20, 30, 40]);
+
+    // The insight cites the first two experiences only
+    let insight_id = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "Retry with backoff".to_string(),
+            embedding: Some(make_embedding(10)),
+            source_experience_ids: vec![exp_ids[0], exp_ids[1]],
+            insight_type: InsightType::Pattern,
+            confidence: 0.9,
+            domain: vec![],
+        })
+        .unwrap();
+
+    let request = ContextRequest {
+        collective_id: cid,
+        query_embedding: make_embedding(10),
+        max_similar: 10,
+        max_recent: 10,
+        include_insights: true,
+        include_relations: false,
+        include_active_agents: false,
+        ..ContextRequest::default()
+    };
+
+    // Default: sources stay in the experience lists
+    let plain = db.get_context_candidates(request.clone()).unwrap();
+    assert!(plain.insight_sources.is_empty());
+    assert!(plain.recent_experiences.iter().any(|e| e.id == exp_ids[0]));
+
+    let collapsed = db
+        .get_context_candidates(ContextRequest {
+            collapse_insight_sources: true,
+            ..request
+        })
+        .unwrap();
+    assert_eq!(collapsed.insights[0].id, insight_id);
+
+    let returned: Vec<ExperienceId> = collapsed
+        .similar_experiences
+        .iter()
+        .map(|r| r.experience.id)
+        .chain(collapsed.recent_experiences.iter().map(|e| e.id))
+        .collect();
+    assert!(!returned.contains(&exp_ids[0]));
+    assert!(!returned.contains(&exp_ids[1]));
+    assert!(returned.contains(&exp_ids[2]));
+    assert!(returned.contains(&exp_ids[3]));
+
+    // Each source is attached exactly once even though it was both similar and recent
+    let children = &collapsed.insight_sources[&insight_id];
+    assert_eq!(children.len(), 2);
+    assert!(children.iter().any(|e| e.id == exp_ids[0]));
+    assert!(children.iter().any(|e| e.id == exp_ids[1]));
+}
+
+#[test]
+fn test_context_collapse_ignored_without_insights() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let exp_ids = record_experiences(&db, cid, &[10, 20]);
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: "Unused".to_string(),
+        embedding: Some(make_embedding(10)),
+        source_experience_ids: exp_ids.clone(),
+        insight_type: InsightType::Pattern,
+        confidence: 0.9,
+        domain: vec![],
+    })
+    .unwrap();
+
+    let candidates = db
+        .get_context_candidates(ContextRequest {
+            collective_id: cid,
+            query_embedding: make_embedding(10),
+            include_insights: false,
+            include_relations: false,
+            include_active_agents: false,
+            collapse_insight_sources: true,
+            ..ContextRequest::default()
+        })
+        .unwrap();
+
+    assert!(candidates.insight_sources.is_empty());
+    assert_eq!(candidates.recent_experiences.len(), 2);
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+#[test]
+fn test_context_render_dedups_and_includes_insights() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let exp_ids = record_experiences(&db, cid, &[10, 20]);
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: "Seeds cluster together".to_string(),
+        embedding: Some(make_embedding(10)),
+        source_experience_ids: exp_ids,
+        insight_type: InsightType::Pattern,
+        confidence: 0.9,
+        domain: vec![],
+    })
+    .unwrap();
+
+    let candidates = db
+        .get_context_candidates(ContextRequest {
+            collective_id: cid,
+            query_embedding: make_embedding(10),
+            include_relations: false,
+            include_active_agents: false,
+            ..ContextRequest::default()
+        })
+        .unwrap();
+
+    // Both experiences are similar and recent, but each renders once
+    let compact = candidates.render(RenderStyle::Compact);
+    assert_eq!(compact.lines().count(), 3);
+    assert_eq!(compact.matches("Experience seed=10").count(), 1);
+    assert!(compact.ends_with("[Insight] Seeds cluster together"));
+
+    let markdown = candidates.render(RenderStyle::Markdown);
+    assert_eq!(markdown.matches("### Note").count(), 2);
+    assert!(markdown.contains("### Insight\n\nSeeds cluster together\n\n- Confidence: 0.90"));
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Budget Accounting
+// ============================================================================
+
+#[test]
+fn test_estimate_context_cost() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let exp_ids = record_experiences(&db, cid, &[10, 200]);
+    let insight_id = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "Seeds cluster together".to_string(),
+            embedding: Some(make_embedding(10)),
+            source_experience_ids: exp_ids.clone(),
+            insight_type: InsightType::Pattern,
+            confidence: 0.9,
+            domain: vec![],
+        })
+        .unwrap();
+    let missing = ContextItem::Experience(ExperienceId::new());
+
+    let words = |text: &str| text.split_whitespace().count();
+    let cost = db
+        .estimate_context_cost(
+            &[
+                exp_ids[0].into(),
+                missing,
+                exp_ids[1].into(),
+                insight_id.into(),
+            ],
+            &words,
+        )
+        .unwrap();
+
+    // "Experience seed=10", "Experience seed=200", "Seeds cluster together"
+    let tokens: Vec<_> = cost.items.iter().map(|c| c.tokens).collect();
+    let bytes: Vec<_> = cost.items.iter().map(|c| c.bytes).collect();
+    assert_eq!(tokens, vec![2, 2, 3]);
+    assert_eq!(bytes, vec![18, 19, 22]);
+    assert_eq!(cost.items[2].item, ContextItem::Insight(insight_id));
+    assert_eq!(cost.total_tokens, 7);
+    assert_eq!(cost.total_bytes, 59);
+    assert_eq!(cost.missing, vec![missing]);
+
+    let approx = db
+        .estimate_context_cost(&[exp_ids[0].into()], &ApproxTokenCounter)
+        .unwrap();
+    assert_eq!(approx.total_tokens, 5);
+
+    db.close().unwrap();
+}
@@ -0,0 +1,215 @@
+//! Integration tests for cursor pagination.
+//!
+//! Tests the full stack: PulseDB facade -> by-collective indexes -> redb.
+//! Covers full traversals in both directions, stability under inserts and
+//! deletes between pages, relations and insights, the wire form, and
+//! validation.
+
+use std::collections::HashSet;
+
+use pulsedb::{
+    CollectiveId, Config, Cursor, ExperienceId, InsightType, NewDerivedInsight, NewExperience,
+    NewExperienceRelation, PageDirection, PulseDB, RelationType,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record `count` experiences, returning their IDs in order.
+fn record_many(db: &PulseDB, cid: CollectiveId, count: usize) -> Vec<ExperienceId> {
+    (0..count)
+        .map(|i| {
+            db.record_experience(NewExperience {
+                collective_id: cid,
+                content: format!("experience {}", i),
+                embedding: Some(vec![0.1; 384]),
+                ..Default::default()
+            })
+            .unwrap()
+        })
+        .collect()
+}
+
+/// Helper: walk every experience page, returning IDs and the page count.
+fn walk(db: &PulseDB, cid: CollectiveId, direction: PageDirection) -> (Vec<ExperienceId>, usize) {
+    let mut cursor = Cursor::first(direction);
+    let mut ids = Vec::new();
+    let mut pages = 0;
+    loop {
+        let page = db.list_experiences_page(cid, &cursor, 10).unwrap();
+        pages += 1;
+        ids.extend(page.items.iter().map(|e| e.id));
+        match page.next {
+            Some(next) => cursor = next,
+            None => return (ids, pages),
+        }
+    }
+}
+
+// ============================================================================
+// Traversal
+// ============================================================================
+
+#[test]
+fn test_walks_every_experience_once_in_both_directions() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let recorded = record_many(&db, cid, 25);
+
+    let (oldest_first, pages) = walk(&db, cid, PageDirection::OldestFirst);
+    assert_eq!(pages, 3);
+    assert_eq!(oldest_first, recorded);
+
+    let (newest_first, _) = walk(&db, cid, PageDirection::NewestFirst);
+    let mut reversed = recorded;
+    reversed.reverse();
+    assert_eq!(newest_first, reversed);
+}
+
+#[test]
+fn test_exact_multiple_ends_without_an_empty_page() {
+    let (db, cid, _dir) = open_db_with_collective();
+    record_many(&db, cid, 20);
+    let (ids, pages) = walk(&db, cid, PageDirection::OldestFirst);
+    assert_eq!(ids.len(), 20);
+    assert_eq!(pages, 2);
+}
+
+// ============================================================================
+// Stability Under Writes
+// ============================================================================
+
+#[test]
+fn test_writes_between_pages_do_not_skip_or_repeat() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let recorded = record_many(&db, cid, 30);
+
+    let first = db
+        .list_experiences_page(cid, &Cursor::default(), 10)
+        .unwrap();
+    let seen: Vec<ExperienceId> = first.items.iter().map(|e| e.id).collect();
+    assert_eq!(seen, recorded[..10]);
+
+    // Delete from the page already read and record new experiences; an
+    // offset of 10 would now skip recorded[10]
+    for id in &recorded[..3] {
+        db.delete_experience(*id).unwrap();
+    }
+    let added = record_many(&db, cid, 2);
+    let by_offset: Vec<ExperienceId> = db
+        .list_experiences(cid, 10, 10)
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_ne!(by_offset[0], recorded[10]);
+
+    let mut cursor = first.next.unwrap();
+    let mut rest = Vec::new();
+    loop {
+        let page = db.list_experiences_page(cid, &cursor, 10).unwrap();
+        rest.extend(page.items.iter().map(|e| e.id));
+        match page.next {
+            Some(next) => cursor = next,
+            None => break,
+        }
+    }
+    let mut expected = recorded[10..].to_vec();
+    expected.extend(added);
+    assert_eq!(rest, expected);
+}
+
+// ============================================================================
+// Relations and Insights
+// ============================================================================
+
+#[test]
+fn test_relations_and_insights_paginate() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let ids = record_many(&db, cid, 6);
+    for pair in ids.windows(2) {
+        db.store_relation(NewExperienceRelation {
+            source_id: pair[0],
+            target_id: pair[1],
+            relation_type: RelationType::Supports,
+            strength: 0.5,
+            metadata: None,
+        })
+        .unwrap();
+    }
+    for (i, source) in ids.iter().take(3).enumerate() {
+        db.store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: format!("insight {}", i),
+            embedding: Some(vec![0.2; 384]),
+            source_experience_ids: vec![*source],
+            insight_type: InsightType::Pattern,
+            confidence: 0.8,
+            domain: vec![],
+        })
+        .unwrap();
+    }
+
+    let first = db.list_relations_page(cid, &Cursor::default(), 3).unwrap();
+    let second = db
+        .list_relations_page(cid, &first.next.unwrap(), 3)
+        .unwrap();
+    assert!(second.next.is_none());
+    let relations: HashSet<_> = first
+        .items
+        .iter()
+        .chain(&second.items)
+        .map(|r| r.id)
+        .collect();
+    assert_eq!(relations.len(), 5);
+
+    let newest = Cursor::first(PageDirection::NewestFirst);
+    let first = db.list_insights_page(cid, &newest, 2).unwrap();
+    assert_eq!(first.items[0].content, "insight 2");
+    let second = db.list_insights_page(cid, &first.next.unwrap(), 2).unwrap();
+    assert_eq!(second.items.len(), 1);
+    assert_eq!(second.items[0].content, "insight 0");
+    assert!(second.next.is_none());
+}
+
+// ============================================================================
+// Wire Form and Validation
+// ============================================================================
+
+#[test]
+fn test_cursor_survives_a_string_roundtrip() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let recorded = record_many(&db, cid, 5);
+
+    let first = db
+        .list_experiences_page(cid, &Cursor::default(), 2)
+        .unwrap();
+    let wire = first.next.unwrap().to_string();
+    let cursor: Cursor = wire.parse().unwrap();
+    assert_eq!(cursor.direction(), PageDirection::OldestFirst);
+    let second = db.list_experiences_page(cid, &cursor, 2).unwrap();
+    assert_eq!(second.items[0].id, recorded[2]);
+}
+
+#[test]
+fn test_page_validation() {
+    let (db, cid, _dir) = open_db_with_collective();
+    assert!(db
+        .list_experiences_page(cid, &Cursor::default(), 0)
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .list_insights_page(CollectiveId::new(), &Cursor::default(), 10)
+        .unwrap_err()
+        .is_not_found());
+    assert!("not a cursor".parse::<Cursor>().is_err());
+
+    let empty = db.list_relations_page(cid, &Cursor::default(), 10).unwrap();
+    assert!(empty.items.is_empty());
+    assert!(empty.next.is_none());
+}
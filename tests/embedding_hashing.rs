@@ -0,0 +1,159 @@
+//! Integration tests for the hash-based embedding provider.
+//!
+//! `EmbeddingProvider::Hashing` takes the same paths as builtin ONNX
+//! embeddings — records without an embedding get one generated — but
+//! needs no feature or model download, so these run everywhere.
+
+use pulsedb::embedding::{EmbeddingService, HashingEmbedding};
+use pulsedb::{
+    Config, EmbeddingDimension, InsightType, NewDerivedInsight, NewExperience, PulseDB,
+    TextNormalization,
+};
+use tempfile::tempdir;
+
+fn open_db() -> (PulseDB, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let config = Config::with_hashing_embeddings(EmbeddingDimension::D384);
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    (db, dir)
+}
+
+// ============================================================================
+// Generated Embeddings
+// ============================================================================
+
+#[test]
+fn test_records_without_embeddings_get_generated_ones() {
+    let (db, _dir) = open_db();
+    let cid = db.create_collective("hashing").unwrap();
+    let service = HashingEmbedding::new(384);
+
+    let contents = [
+        "Borrow checker rejects mutable aliasing in the parser",
+        "Database migration failed on a locked table",
+        "Flaky network timeouts during model download",
+    ];
+    let ids: Vec<_> = contents
+        .iter()
+        .map(|content| {
+            db.record_experience(NewExperience {
+                collective_id: cid,
+                content: content.to_string(),
+                embedding: None,
+                ..Default::default()
+            })
+            .unwrap()
+        })
+        .collect();
+
+    let stored = db.get_experience(ids[1]).unwrap().unwrap();
+    assert_eq!(stored.embedding, service.embed(contents[1]).unwrap());
+
+    let query = service.embed("database migration locked").unwrap();
+    let results = db.search_similar(cid, &query, 3).unwrap();
+    assert_eq!(results[0].experience.id, ids[1]);
+
+    let insight_id = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "Migrations should not run while the table is locked".to_string(),
+            embedding: None,
+            source_experience_ids: vec![ids[1]],
+            insight_type: InsightType::Pattern,
+            confidence: 0.9,
+            domain: vec![],
+        })
+        .unwrap();
+    let insight = db.get_insight(insight_id).unwrap().unwrap();
+    assert_eq!(insight.embedding.len(), 384);
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Text Normalization
+// ============================================================================
+
+#[test]
+fn test_normalization_applies_at_record_and_query_time() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        text_normalization: TextNormalization::standard(),
+        ..Config::with_hashing_embeddings(EmbeddingDimension::D384)
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("normalized").unwrap();
+
+    let content = "Parser PANICS on\n```rust\nlet x = ;\n```";
+    let id = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: content.to_string(),
+            embedding: None,
+            ..Default::default()
+        })
+        .unwrap();
+
+    // Content is stored verbatim; only the embedding input is normalized
+    let stored = db.get_experience(id).unwrap().unwrap();
+    assert_eq!(stored.content, content);
+    assert_eq!(
+        stored.embedding,
+        db.embed_query(cid, "parser panics on let x = ;").unwrap()
+    );
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_embed_query_requires_generating_provider() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("external").unwrap();
+    assert!(db.embed_query(cid, "anything").is_err());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_per_collective_normalization() {
+    let (db, _dir) = open_db();
+    let code = db.create_collective("code").unwrap();
+    let prose = db.create_collective("prose").unwrap();
+    db.set_text_normalization(code, Some(TextNormalization::standard()))
+        .unwrap();
+
+    // Each collective embeds with its own settings
+    assert_eq!(
+        db.embed_query(code, "Parser  PANICS").unwrap(),
+        db.embed_query(code, "parser panics").unwrap()
+    );
+    assert_eq!(
+        db.get_text_normalization(prose).unwrap(),
+        TextNormalization::default()
+    );
+
+    db.record_experience(NewExperience {
+        collective_id: code,
+        content: "Parser panics on empty input".to_string(),
+        embedding: None,
+        ..Default::default()
+    })
+    .unwrap();
+
+    // Changing the settings under embedded records is rejected...
+    let err = db.set_text_normalization(code, None).unwrap_err();
+    assert!(err.is_validation());
+    assert_eq!(
+        db.get_text_normalization(code).unwrap(),
+        TextNormalization::standard()
+    );
+    // ...but re-applying the same settings is fine
+    db.set_text_normalization(code, Some(TextNormalization::standard()))
+        .unwrap();
+    // Empty collectives can change freely
+    db.set_text_normalization(prose, Some(TextNormalization::standard()))
+        .unwrap();
+    db.set_text_normalization(prose, None).unwrap();
+
+    db.close().unwrap();
+}
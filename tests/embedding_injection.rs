@@ -0,0 +1,103 @@
+//! Integration tests for injected embedding services.
+//!
+//! Tests the full stack: `PulseDB::open_with_embedding` -> caller-supplied
+//! `EmbeddingService` -> generated embeddings in redb and the vector index.
+//! Covers records and insights without embeddings, query embedding, and
+//! dimension checks at open.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use pulsedb::embedding::{EmbeddingService, HashingEmbedding};
+use pulsedb::{Config, Embedding, InsightType, NewDerivedInsight, NewExperience, PulseDB};
+use tempfile::tempdir;
+
+/// A service standing in for a model server: hashes text and counts calls.
+struct CountingService {
+    inner: HashingEmbedding,
+    calls: Arc<AtomicUsize>,
+}
+
+impl EmbeddingService for CountingService {
+    fn embed(&self, text: &str) -> pulsedb::Result<Embedding> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.embed(text)
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> pulsedb::Result<Vec<Embedding>> {
+        texts.iter().map(|text| self.embed(text)).collect()
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+}
+
+// ============================================================================
+// Injected Service
+// ============================================================================
+
+#[test]
+fn test_injected_service_embeds_records_insights_and_queries() {
+    let dir = tempdir().unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let service = CountingService {
+        inner: HashingEmbedding::new(384),
+        calls: Arc::clone(&calls),
+    };
+    // The default config expects external embeddings; the service overrides it
+    let db = PulseDB::open_with_embedding(
+        dir.path().join("test.db"),
+        Config::default(),
+        Box::new(service),
+    )
+    .unwrap();
+    let cid = db.create_collective("injected").unwrap();
+
+    let id = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "connection pool exhausted under load".into(),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    let expected = HashingEmbedding::new(384)
+        .embed("connection pool exhausted under load")
+        .unwrap();
+    assert_eq!(db.get_experience(id).unwrap().unwrap().embedding, expected);
+
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: "pools need headroom".into(),
+        embedding: None,
+        source_experience_ids: vec![id],
+        insight_type: InsightType::Pattern,
+        confidence: 0.8,
+        domain: vec![],
+    })
+    .unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let query = db
+        .embed_query(cid, "connection pool exhausted under load")
+        .unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    let results = db.search_similar(cid, &query, 1).unwrap();
+    assert_eq!(results[0].experience.id, id);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_injected_service_dimension_must_match_config() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let err = PulseDB::open_with_embedding(
+        &path,
+        Config::default(),
+        Box::new(HashingEmbedding::new(768)),
+    )
+    .unwrap_err();
+    assert!(err.is_validation());
+    assert!(!path.exists());
+}
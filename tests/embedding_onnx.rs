@@ -16,7 +16,7 @@
 
 #[cfg(feature = "builtin-embeddings")]
 mod onnx_tests {
-    use pulsedb::embedding::onnx::OnnxEmbedding;
+    use pulsedb::embedding::onnx::{OnnxEmbedding, OnnxOptions};
     use pulsedb::embedding::EmbeddingService;
 
     /// Check if the default model files are available.
@@ -220,6 +220,61 @@ mod onnx_tests {
         );
     }
 
+    // ---------------------------------------------------------------
+    // Worker pool tests
+    // ---------------------------------------------------------------
+
+    #[test]
+    #[ignore]
+    fn test_concurrent_embeds_match_serial() {
+        if !model_available() {
+            return;
+        }
+
+        let service = OnnxEmbedding::with_threads(None, 384, 3).unwrap();
+        assert_eq!(service.threads(), 3);
+
+        let texts = ["Hello world", "Rust is great", "Borrow checker errors"];
+        let serial: Vec<_> = texts.iter().map(|t| service.embed(t).unwrap()).collect();
+        let concurrent: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = texts
+                .iter()
+                .map(|t| scope.spawn(|| service.embed(t).unwrap()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        for (s, c) in serial.iter().zip(&concurrent) {
+            assert!(cosine_similarity(s, c) > 0.9999);
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn test_workers_share_fewer_sessions() {
+        if !model_available() {
+            return;
+        }
+
+        let options = OnnxOptions {
+            threads: 4,
+            sessions: 2,
+        };
+        let service = OnnxEmbedding::with_options(None, 384, &options).unwrap();
+        assert_eq!((service.threads(), service.sessions()), (4, 2));
+
+        let embeddings: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|i| {
+                    let service = &service;
+                    scope.spawn(move || service.embed(&format!("text {i}")).unwrap())
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert!(embeddings.iter().all(|e| e.len() == 384));
+    }
+
     // ---------------------------------------------------------------
     // Edge case tests
     // ---------------------------------------------------------------
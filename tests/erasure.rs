@@ -0,0 +1,234 @@
+//! Integration tests for data subject erasure.
+//!
+//! Tests the full stack: PulseDB facade -> cascade -> redb.
+//! Covers erasing an agent's records across collectives, insight handling,
+//! erasing a user's collectives, content handles for external storage, and
+//! validation.
+
+use pulsedb::{
+    AgentId, CollectiveId, Config, ContentStorage, ExperienceId, InsightSourceCascade, InsightType,
+    NewActivity, NewDerivedInsight, NewExperience, NewExperienceRelation, PulseDB, RelationType,
+    UserId,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB with the given config.
+fn open_db(config: Config) -> (PulseDB, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    (db, dir)
+}
+
+/// Helper: record an experience attributed to an agent.
+fn record(db: &PulseDB, cid: CollectiveId, agent: &str, content: &str) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.to_string(),
+        source_agent: AgentId::new(agent),
+        embedding: Some(vec![0.1; 384]),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: store an insight citing the given sources.
+fn insight(db: &PulseDB, cid: CollectiveId, sources: Vec<ExperienceId>) -> pulsedb::InsightId {
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: "derived".to_string(),
+        embedding: Some(vec![0.1; 384]),
+        source_experience_ids: sources,
+        insight_type: InsightType::Pattern,
+        confidence: 0.8,
+        domain: vec![],
+    })
+    .unwrap()
+}
+
+// ============================================================================
+// Erase by Agent
+// ============================================================================
+
+#[test]
+fn test_erase_by_agent_across_collectives() {
+    let (db, _dir) = open_db(Config::default());
+    let a = db.create_collective("a").unwrap();
+    let b = db.create_collective("b").unwrap();
+    let mine_a = record(&db, a, "bot", "one");
+    let mine_b = record(&db, b, "bot", "two");
+    let theirs = record(&db, a, "other", "three");
+    db.store_relation(NewExperienceRelation {
+        source_id: mine_a,
+        target_id: theirs,
+        relation_type: RelationType::Supports,
+        strength: 0.5,
+        metadata: None,
+    })
+    .unwrap();
+    for cid in [a, b] {
+        db.register_activity(NewActivity {
+            agent_id: "bot".to_string(),
+            collective_id: cid,
+            current_task: None,
+            context_summary: None,
+            capabilities: vec![],
+        })
+        .unwrap();
+    }
+    db.bookmark_experience("bot", theirs).unwrap();
+
+    let report = db.erase_by_agent(&AgentId::new("bot")).unwrap();
+    assert_eq!(report.experiences_erased, 2);
+    assert_eq!(report.relations_erased, 1);
+    assert_eq!(report.activities_erased, 2);
+    assert_eq!(report.bookmarks_erased, 1);
+    assert_eq!(report.collectives_erased, 0);
+    assert!(report.content_handles.is_empty());
+
+    assert!(db.get_experience(mine_a).unwrap().is_none());
+    assert!(db.get_experience(mine_b).unwrap().is_none());
+    assert!(db.get_experience(theirs).unwrap().is_some());
+    assert!(db.get_active_agents(a).unwrap().is_empty());
+    assert!(db.list_bookmarks("bot").unwrap().is_empty());
+
+    // Erasing again finds nothing
+    assert!(db.erase_by_agent(&AgentId::new("bot")).unwrap().is_empty());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_erase_by_agent_settles_insights_despite_block_policy() {
+    let (db, _dir) = open_db(Config {
+        insight_source_cascade: InsightSourceCascade::Block,
+        ..Default::default()
+    });
+    let cid = db.create_collective("c").unwrap();
+    let mine = record(&db, cid, "bot", "mine");
+    let theirs = record(&db, cid, "other", "theirs");
+    let only_mine = insight(&db, cid, vec![mine]);
+    let shared = insight(&db, cid, vec![mine, theirs]);
+
+    let report = db.erase_by_agent(&AgentId::new("bot")).unwrap();
+    assert_eq!(report.insights_erased, 1);
+    assert_eq!(report.insights_detached, 1);
+
+    assert!(db.get_insight(only_mine).unwrap().is_none());
+    let shared = db.get_insight(shared).unwrap().unwrap();
+    assert_eq!(shared.source_experience_ids, vec![theirs]);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_erase_by_agent_reports_external_content_handles() {
+    let (db, _dir) = open_db(Config {
+        content_storage: ContentStorage::External,
+        ..Default::default()
+    });
+    let cid = db.create_collective("c").unwrap();
+    record(&db, cid, "bot", "vault://doc-7");
+
+    let report = db.erase_by_agent(&AgentId::new("bot")).unwrap();
+    assert_eq!(report.content_handles, vec!["vault://doc-7".to_string()]);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_erase_by_agent_respects_frozen_collective() {
+    let (db, _dir) = open_db(Config::default());
+    let cid = db.create_collective("c").unwrap();
+    let id = record(&db, cid, "bot", "mine");
+    db.freeze_collective(cid).unwrap();
+
+    assert!(db
+        .erase_by_agent(&AgentId::new("bot"))
+        .unwrap_err()
+        .is_busy());
+    assert!(db.get_experience(id).unwrap().is_some());
+
+    db.thaw_collective(cid).unwrap();
+    assert_eq!(
+        db.erase_by_agent(&AgentId::new("bot"))
+            .unwrap()
+            .experiences_erased,
+        1
+    );
+    db.close().unwrap();
+}
+
+#[test]
+fn test_erase_by_agent_validation() {
+    let (db, _dir) = open_db(Config::default());
+    assert!(db
+        .erase_by_agent(&AgentId::new(""))
+        .unwrap_err()
+        .is_validation());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Erase by User
+// ============================================================================
+
+#[test]
+fn test_erase_by_user_deletes_owned_collectives() {
+    let (db, _dir) = open_db(Config::default());
+    let owned = db.create_collective_with_owner("mine", "alice").unwrap();
+    let kept = db.create_collective_with_owner("theirs", "bob").unwrap();
+    let first = record(&db, owned, "bot", "one");
+    let second = record(&db, owned, "bot", "two");
+    record(&db, kept, "bot", "three");
+    insight(&db, owned, vec![first, second]);
+    db.store_relation(NewExperienceRelation {
+        source_id: first,
+        target_id: second,
+        relation_type: RelationType::Elaborates,
+        strength: 0.5,
+        metadata: None,
+    })
+    .unwrap();
+
+    let report = db.erase_by_user(&UserId::new("alice")).unwrap();
+    assert_eq!(report.collectives_erased, 1);
+    assert_eq!(report.experiences_erased, 2);
+    assert_eq!(report.relations_erased, 1);
+    assert_eq!(report.insights_erased, 1);
+
+    assert!(db.get_collective(owned).unwrap().is_none());
+    assert!(db.get_collective(kept).unwrap().is_some());
+    assert!(db.erase_by_user(&UserId::new("nobody")).unwrap().is_empty());
+    assert!(db
+        .erase_by_user(&UserId::new(""))
+        .unwrap_err()
+        .is_validation());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_erase_by_user_includes_linked_experiences() {
+    let (db, _dir) = open_db(Config::default());
+    let shared = db.create_collective_with_owner("shared", "bob").unwrap();
+    let linked = db
+        .record_experience(NewExperience {
+            collective_id: shared,
+            content: "about alice".to_string(),
+            embedding: Some(vec![0.1; 384]),
+            user_id: Some(UserId::new("alice")),
+            ..Default::default()
+        })
+        .unwrap();
+    let unrelated = record(&db, shared, "bot", "general");
+    insight(&db, shared, vec![linked, unrelated]);
+
+    let report = db.erase_by_user(&UserId::new("alice")).unwrap();
+    assert_eq!(report.collectives_erased, 0);
+    assert_eq!(report.experiences_erased, 1);
+    assert_eq!(report.insights_detached, 1);
+
+    assert!(db.get_experience(linked).unwrap().is_none());
+    assert!(db.get_experience(unrelated).unwrap().is_some());
+    assert!(db
+        .experiences_for_user(&UserId::new("alice"))
+        .unwrap()
+        .is_empty());
+    db.close().unwrap();
+}
@@ -0,0 +1,195 @@
+//! Integration tests for retrieval evaluation.
+//!
+//! Tests the full stack: labeled set -> PulseDB::evaluate -> HNSW -> redb.
+//! Covers metric computation against live and scratch indexes, filters,
+//! loading sets from JSON, and validation.
+
+use std::collections::HashMap;
+
+use pulsedb::{
+    CollectiveId, Config, EvalQuery, EvalSet, ExperienceId, HnswConfig, NewExperience, PulseDB,
+    RetrievalConfig, SearchFilter,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB with default config.
+fn open_db() -> (PulseDB, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    (db, dir)
+}
+
+/// Helper: a unit embedding pointing mostly along `axis`.
+fn embedding(axis: usize) -> Vec<f32> {
+    let mut v = vec![0.01; 384];
+    v[axis] = 1.0;
+    v
+}
+
+/// Helper: record an experience near `axis` in the given domain.
+fn record(db: &PulseDB, cid: CollectiveId, axis: usize, domain: &str) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: format!("experience along axis {axis}"),
+        embedding: Some(embedding(axis)),
+        domain: vec![domain.to_string()],
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: a query along `axis` judging `relevant` as relevant.
+fn query(axis: usize, relevant: &[ExperienceId]) -> EvalQuery {
+    EvalQuery {
+        name: format!("axis {axis}"),
+        embedding: embedding(axis),
+        relevant: relevant.iter().map(|id| (*id, 1.0)).collect(),
+    }
+}
+
+// ============================================================================
+// Evaluation
+// ============================================================================
+
+#[test]
+fn test_evaluate_live_and_scratch_configs() {
+    let (db, _dir) = open_db();
+    let cid = db.create_collective("eval").unwrap();
+    let ids: Vec<_> = (0..8).map(|axis| record(&db, cid, axis, "rust")).collect();
+
+    let set = EvalSet {
+        queries: (0..8).map(|axis| query(axis, &[ids[axis]])).collect(),
+    };
+    let configs = [
+        RetrievalConfig {
+            name: "live".to_string(),
+            k: 1,
+            ..RetrievalConfig::default()
+        },
+        RetrievalConfig {
+            name: "scratch".to_string(),
+            k: 3,
+            hnsw: Some(HnswConfig {
+                ef_search: 16,
+                ..HnswConfig::default()
+            }),
+            ..RetrievalConfig::default()
+        },
+    ];
+
+    let reports = db.evaluate(cid, &set, &configs).unwrap();
+    assert_eq!(reports.len(), 2);
+    for (report, name) in reports.iter().zip(["live", "scratch"]) {
+        assert_eq!(report.config, name);
+        assert_eq!(report.queries, 8);
+        assert!((report.ndcg - 1.0).abs() < 1e-9);
+        assert!((report.recall - 1.0).abs() < 1e-9);
+    }
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_evaluate_applies_filter() {
+    let (db, _dir) = open_db();
+    let cid = db.create_collective("eval").unwrap();
+    let rust = record(&db, cid, 0, "rust");
+    let python = record(&db, cid, 1, "python");
+
+    let set = EvalSet {
+        queries: vec![query(0, &[rust, python])],
+    };
+    let configs = [RetrievalConfig {
+        name: "python-only".to_string(),
+        k: 2,
+        filter: SearchFilter {
+            domains: Some(vec!["python".to_string()]),
+            ..SearchFilter::default()
+        },
+        ..RetrievalConfig::default()
+    }];
+
+    let report = &db.evaluate(cid, &set, &configs).unwrap()[0];
+    assert!((report.recall - 0.5).abs() < 1e-9);
+    assert!(report.ndcg > 0.0 && report.ndcg < 1.0);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_eval_set_load_from_json() {
+    let dir = tempdir().unwrap();
+    let id = ExperienceId::new();
+    let set = EvalSet {
+        queries: vec![EvalQuery {
+            name: "q".to_string(),
+            embedding: vec![0.5; 4],
+            relevant: HashMap::from([(id, 2.0)]),
+        }],
+    };
+    let path = dir.path().join("set.json");
+    std::fs::write(&path, serde_json::to_string(&set).unwrap()).unwrap();
+
+    let loaded = EvalSet::load(&path).unwrap();
+    assert_eq!(loaded.queries.len(), 1);
+    assert_eq!(loaded.queries[0].relevant[&id], 2.0);
+
+    assert!(EvalSet::from_json("{ not json")
+        .unwrap_err()
+        .is_validation());
+    assert!(EvalSet::load(dir.path().join("missing.json")).is_err());
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn test_evaluate_validation() {
+    let (db, _dir) = open_db();
+    let cid = db.create_collective("eval").unwrap();
+    let id = record(&db, cid, 0, "rust");
+    let set = EvalSet {
+        queries: vec![query(0, &[id])],
+    };
+
+    // Unknown collective
+    let err = db
+        .evaluate(CollectiveId::new(), &set, &[RetrievalConfig::default()])
+        .unwrap_err();
+    assert!(err.is_not_found());
+
+    // Empty set
+    let err = db
+        .evaluate(cid, &EvalSet::default(), &[RetrievalConfig::default()])
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    // k out of range
+    let bad_k = RetrievalConfig {
+        k: 0,
+        ..RetrievalConfig::default()
+    };
+    assert!(db
+        .evaluate(cid, &set, &[bad_k])
+        .unwrap_err()
+        .is_validation());
+
+    // Wrong dimension
+    let mut wrong_dim = set.clone();
+    wrong_dim.queries[0].embedding = vec![0.1; 8];
+    let err = db
+        .evaluate(cid, &wrong_dim, &[RetrievalConfig::default()])
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    // No relevant judgments
+    let mut unjudged = set.clone();
+    unjudged.queries[0].relevant.clear();
+    let err = db
+        .evaluate(cid, &unjudged, &[RetrievalConfig::default()])
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    db.close().unwrap();
+}
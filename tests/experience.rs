@@ -4,9 +4,14 @@
 //! Uses External embedding provider (default), so all experiences must provide
 //! pre-computed embeddings of the correct dimension (384 for D384).
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use pulsedb::{
-    AgentId, CollectiveId, Config, ExperienceId, ExperienceType, ExperienceUpdate, NewExperience,
-    PulseDB, Severity,
+    AgentId, BatchOutcome, CollectiveId, Config, ContentPolicy, ContentRequirement,
+    ContentResolver, ContentStorage, ExperienceId, ExperienceType, ExperienceUpdate, IdStrategy,
+    ModelAttribution, ModerationPolicy, NewExperience, PulseDB, PulseDBError, SearchFilter,
+    Severity,
 };
 use tempfile::tempdir;
 
@@ -804,6 +809,60 @@ fn test_record_experience_success_pattern_valid_quality_accepted() {
     db.close().unwrap();
 }
 
+// ============================================================================
+// Content Policy
+// ============================================================================
+
+#[test]
+fn test_content_policy_enforced_at_record_time() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let error_pattern = ExperienceType::ErrorPattern {
+        signature: "E0382".into(),
+        fix: "clone before the move".into(),
+        prevention: "borrow instead".into(),
+    };
+    let record = |db: &PulseDB, cid, content: &str| {
+        db.record_experience(NewExperience {
+            content: content.to_string(),
+            experience_type: error_pattern.clone(),
+            ..minimal_experience(cid)
+        })
+    };
+
+    let cid = {
+        let db = PulseDB::open(&path, Config::default()).unwrap();
+        let cid = db.create_collective("policed").unwrap();
+        assert!(db.get_content_policy(cid).unwrap().rules.is_empty());
+        db.set_content_policy(
+            cid,
+            ContentPolicy::new().require(
+                [error_pattern.clone()],
+                ContentRequirement::IncludesField("signature".into()),
+            ),
+        )
+        .unwrap();
+        db.close().unwrap();
+        cid
+    };
+
+    // The policy survives reopen
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let err = record(&db, cid, "use after move").unwrap_err();
+    assert!(err.is_validation());
+    assert!(err
+        .to_string()
+        .contains("must include its signature `E0382`"));
+    record(&db, cid, "E0382: use after move").unwrap();
+
+    // Other types and other collectives are unaffected
+    db.record_experience(minimal_experience(cid)).unwrap();
+    let other = db.create_collective("free").unwrap();
+    record(&db, other, "use after move").unwrap();
+
+    db.close().unwrap();
+}
+
 // ============================================================================
 // Persistence Across Reopen
 // ============================================================================
@@ -1118,3 +1177,362 @@ fn test_delete_then_rerecord() {
 
     db.close().unwrap();
 }
+
+// ============================================================================
+// External Content Storage
+// ============================================================================
+
+/// Resolver backed by an in-memory "document store".
+struct MapResolver(HashMap<String, String>);
+
+impl ContentResolver for MapResolver {
+    fn resolve(&self, handle: &str) -> pulsedb::Result<String> {
+        self.0
+            .get(handle)
+            .cloned()
+            .ok_or_else(|| PulseDBError::internal(format!("unknown handle {}", handle)))
+    }
+}
+
+/// Helper: open a DB with external content storage and a collective.
+fn open_external_content_db() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        content_storage: ContentStorage::External,
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("private").unwrap();
+    (db, cid, dir)
+}
+
+#[test]
+fn test_external_content_resolved_on_get() {
+    let (db, cid, _dir) = open_external_content_db();
+    let id = db
+        .record_experience(NewExperience {
+            content: "doc-1".to_string(),
+            ..minimal_experience(cid)
+        })
+        .unwrap();
+
+    // Without a resolver the stored handle is returned
+    assert_eq!(db.get_experience(id).unwrap().unwrap().content, "doc-1");
+
+    let documents = HashMap::from([("doc-1".to_string(), "secret text".to_string())]);
+    db.set_content_resolver(Arc::new(MapResolver(documents)));
+    assert_eq!(
+        db.get_experience(id).unwrap().unwrap().content,
+        "secret text"
+    );
+
+    // Search stays local and returns handles
+    let hits = db.search_similar(cid, &dummy_embedding(), 5).unwrap();
+    assert_eq!(hits[0].experience.content, "doc-1");
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_external_content_resolver_error_fails_read() {
+    let (db, cid, _dir) = open_external_content_db();
+    let id = db.record_experience(minimal_experience(cid)).unwrap();
+    db.set_content_resolver(Arc::new(MapResolver(HashMap::new())));
+
+    let err = db.get_experience(id).unwrap_err();
+    assert!(err.to_string().contains("unknown handle"));
+    // Missing experiences never reach the resolver
+    assert!(db.get_experience(ExperienceId::new()).unwrap().is_none());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_external_content_requires_embedding() {
+    let (db, cid, _dir) = open_external_content_db();
+    let err = db
+        .record_experience(NewExperience {
+            embedding: None,
+            ..minimal_experience(cid)
+        })
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_reopen_with_other_content_storage_fails() {
+    let (db, _cid, dir) = open_external_content_db();
+    db.close().unwrap();
+    let path = dir.path().join("test.db");
+
+    // Handles and raw text must never end up side by side
+    let err = PulseDB::open(&path, Config::default()).unwrap_err();
+    assert!(err.is_config());
+
+    let config = Config {
+        content_storage: ContentStorage::External,
+        ..Default::default()
+    };
+    PulseDB::open(&path, config).unwrap().close().unwrap();
+}
+
+// ============================================================================
+// Model Attribution
+// ============================================================================
+
+#[test]
+fn test_attribution_roundtrip_and_persists() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let attribution = ModelAttribution {
+        model_name: "gpt-4o".to_string(),
+        model_version: Some("2024-08-06".to_string()),
+        temperature: Some(0.2),
+        tool: Some("code-review".to_string()),
+    };
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("test").unwrap();
+    let attributed = db
+        .record_experience(NewExperience {
+            attribution: Some(attribution.clone()),
+            ..minimal_experience(cid)
+        })
+        .unwrap();
+    let plain = db.record_experience(minimal_experience(cid)).unwrap();
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert_eq!(
+        db.get_experience(attributed).unwrap().unwrap().attribution,
+        Some(attribution)
+    );
+    assert!(db
+        .get_experience(plain)
+        .unwrap()
+        .unwrap()
+        .attribution
+        .is_none());
+
+    // Updates don't touch attribution
+    db.update_experience(
+        attributed,
+        ExperienceUpdate {
+            importance: Some(0.9),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert!(db
+        .get_experience(attributed)
+        .unwrap()
+        .unwrap()
+        .attribution
+        .is_some());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_search_filtered_by_model() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let by_model = |model: &str| NewExperience {
+        attribution: Some(ModelAttribution {
+            model_name: model.to_string(),
+            ..Default::default()
+        }),
+        ..minimal_experience(cid)
+    };
+    let gpt = db.record_experience(by_model("gpt-4o")).unwrap();
+    db.record_experience(by_model("claude-sonnet")).unwrap();
+    db.record_experience(minimal_experience(cid)).unwrap();
+
+    let filter = SearchFilter {
+        models: Some(vec!["gpt-4o".to_string()]),
+        ..Default::default()
+    };
+    let hits = db
+        .search_similar_filtered(cid, &dummy_embedding(), 10, filter.clone())
+        .unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].experience.id, gpt);
+
+    let recent = db.get_recent_experiences_filtered(cid, 10, filter).unwrap();
+    assert_eq!(recent.len(), 1);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_record_experience_invalid_attribution_rejected() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let err = db
+        .record_experience(NewExperience {
+            attribution: Some(ModelAttribution::default()),
+            ..minimal_experience(cid)
+        })
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// ID Strategy
+// ============================================================================
+
+#[test]
+fn test_record_experience_with_supplied_id() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let supplied = ExperienceId::new();
+    let id = db
+        .record_experience(NewExperience {
+            id: Some(supplied),
+            ..minimal_experience(cid)
+        })
+        .unwrap();
+    assert_eq!(id, supplied);
+    assert!(db.get_experience(supplied).unwrap().is_some());
+
+    // Reusing the ID is rejected and leaves the original untouched
+    let err = db
+        .record_experience(NewExperience {
+            id: Some(supplied),
+            content: "different".to_string(),
+            ..minimal_experience(cid)
+        })
+        .unwrap_err();
+    assert!(err.is_validation());
+    assert_eq!(db.get_collective_stats(cid).unwrap().experience_count, 1);
+
+    let err = db
+        .record_experience(NewExperience {
+            id: Some(ExperienceId::nil()),
+            ..minimal_experience(cid)
+        })
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_content_derived_id_strategy() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(
+        dir.path().join("test.db"),
+        Config {
+            id_strategy: IdStrategy::ContentDerived,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let a = db.create_collective("a").unwrap();
+    let b = db.create_collective("b").unwrap();
+
+    let id = db.record_experience(minimal_experience(a)).unwrap();
+    assert_eq!(
+        id,
+        ExperienceId::derive(a, "Always validate user input before processing")
+    );
+
+    // Same content in the same collective collides
+    let err = db.record_experience(minimal_experience(a)).unwrap_err();
+    assert!(err.is_validation());
+
+    // Same content in another collective gets its own ID
+    let other = db.record_experience(minimal_experience(b)).unwrap();
+    assert_ne!(other, id);
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Batch Ingestion
+// ============================================================================
+
+#[test]
+fn test_record_experiences_batch_reports_each_item() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(
+        dir.path().join("test.db"),
+        Config {
+            id_strategy: IdStrategy::ContentDerived,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let cid = db.create_collective("logs").unwrap();
+
+    let empty = NewExperience {
+        content: String::new(),
+        ..minimal_experience(cid)
+    };
+    let orphan = minimal_experience(CollectiveId::new());
+    let report = db
+        .record_experiences_batch(vec![
+            minimal_experience(cid),
+            empty,
+            minimal_experience(cid),
+            orphan,
+        ])
+        .unwrap();
+
+    let id = ExperienceId::derive(cid, "Always validate user input before processing");
+    assert_eq!(report.outcomes.len(), 4);
+    assert_eq!(report.outcomes[0], BatchOutcome::Accepted(id));
+    assert!(
+        matches!(&report.outcomes[1], BatchOutcome::Rejected(reason) if reason.contains("content"))
+    );
+    assert_eq!(report.outcomes[2], BatchOutcome::Deduped(id));
+    assert!(matches!(report.outcomes[3], BatchOutcome::Rejected(_)));
+    assert_eq!(
+        (report.accepted(), report.deduped(), report.rejected()),
+        (1, 1, 2)
+    );
+    assert_eq!(report.accepted_ids(), vec![id]);
+    assert!(db.get_experience(id).unwrap().is_some());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_record_experiences_batch_indexes_every_collective() {
+    let (db, a, _dir) = open_db_with_collective();
+    let b = db.create_collective("other").unwrap();
+    db.set_moderation_policy(
+        b,
+        ModerationPolicy::ReviewAgents(vec![AgentId::new("intern")]),
+    )
+    .unwrap();
+
+    let item = |collective_id: CollectiveId, i: usize| NewExperience {
+        collective_id,
+        content: format!("batch item {}", i),
+        embedding: Some((0..DIM).map(|d| ((d + i) % 7) as f32 + 0.1).collect()),
+        ..Default::default()
+    };
+    let mut batch: Vec<NewExperience> = (0..20).map(|i| item(a, i)).collect();
+    batch.extend((20..30).map(|i| item(b, i)));
+    batch.push(NewExperience {
+        source_agent: AgentId::new("intern"),
+        ..item(b, 30)
+    });
+
+    let report = db.record_experiences_batch(batch).unwrap();
+    assert_eq!(report.accepted(), 31);
+
+    let found = db.search_similar(a, &dummy_embedding(), 50).unwrap();
+    assert_eq!(found.len(), 20);
+    let found = db.search_similar(b, &dummy_embedding(), 50).unwrap();
+    assert_eq!(found.len(), 10);
+
+    // The reviewed agent's item is queued, not published
+    let pending = db.list_pending_experiences(b).unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].id, report.accepted_ids()[30]);
+
+    db.close().unwrap();
+}
@@ -0,0 +1,181 @@
+//! Integration tests for experience TTL and expiry.
+//!
+//! Tests the full stack: PulseDB facade -> expiry index -> redb.
+//! Covers recording with `expires_at`, `purge_expired()`, index cleanup on
+//! manual delete, the sweeps on open and on write, export round trips, and
+//! validation.
+
+use std::thread::sleep;
+use std::time::Duration;
+
+use pulsedb::{CollectiveId, Config, ExperienceId, NewExperience, PulseDB, Timestamp};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: a timestamp `millis` from now.
+fn in_millis(millis: i64) -> Timestamp {
+    Timestamp::from_millis(Timestamp::now().as_millis() + millis)
+}
+
+/// Helper: record an experience, optionally expiring.
+fn record(db: &PulseDB, cid: CollectiveId, expires_at: Option<Timestamp>) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: "scratch observation".into(),
+        embedding: Some(vec![0.1; 384]),
+        expires_at,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+// ============================================================================
+// Purge
+// ============================================================================
+
+#[test]
+fn test_purge_removes_only_expired_experiences() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let permanent = record(&db, cid, None);
+    let later = record(&db, cid, Some(in_millis(3_600_000)));
+    let soon = record(&db, cid, Some(in_millis(50)));
+
+    let stored = db.get_experience(soon).unwrap().unwrap();
+    assert!(stored.expires_at.is_some());
+    assert!(db
+        .get_experience(permanent)
+        .unwrap()
+        .unwrap()
+        .expires_at
+        .is_none());
+    assert_eq!(db.purge_expired().unwrap(), 0);
+
+    sleep(Duration::from_millis(100));
+    assert_eq!(db.purge_expired().unwrap(), 1);
+    assert!(db.get_experience(soon).unwrap().is_none());
+    assert!(db.get_experience(permanent).unwrap().is_some());
+    assert!(db.get_experience(later).unwrap().is_some());
+
+    let hits = db.search_similar(cid, &[0.1; 384], 10).unwrap();
+    assert!(hits.iter().all(|hit| hit.experience.id != soon));
+    assert_eq!(db.purge_expired().unwrap(), 0);
+}
+
+#[test]
+fn test_manual_delete_clears_expiry_entry() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let id = record(&db, cid, Some(in_millis(50)));
+    db.delete_experience(id).unwrap();
+
+    sleep(Duration::from_millis(100));
+    assert_eq!(db.purge_expired().unwrap(), 0);
+}
+
+// ============================================================================
+// Automatic Sweeps
+// ============================================================================
+
+#[test]
+fn test_sweep_on_open_purges_experiences_expired_while_closed() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    let id = record(&db, cid, Some(in_millis(50)));
+    db.close().unwrap();
+
+    sleep(Duration::from_millis(100));
+    let config = Config {
+        expiry_sweep: Some(Duration::from_secs(3600)),
+        ..Default::default()
+    };
+    let db = PulseDB::open(&path, config).unwrap();
+    assert!(db.get_experience(id).unwrap().is_none());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_sweep_runs_on_write_after_interval() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        expiry_sweep: Some(Duration::from_millis(10)),
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    let id = record(&db, cid, Some(in_millis(50)));
+
+    sleep(Duration::from_millis(100));
+    assert!(db.get_experience(id).unwrap().is_some());
+    record(&db, cid, None);
+    assert!(db.get_experience(id).unwrap().is_none());
+}
+
+// ============================================================================
+// Export and Import
+// ============================================================================
+
+#[test]
+fn test_export_import_preserves_expiry() {
+    let (db, cid, dir) = open_db_with_collective();
+    let expires_at = in_millis(3_600_000);
+    let expiring = record(&db, cid, Some(expires_at));
+    let permanent = record(&db, cid, None);
+
+    let path = dir.path().join("full.pulse");
+    db.export(&path).unwrap();
+    let restored = PulseDB::open(dir.path().join("restored.db"), Config::default()).unwrap();
+    restored.import(&path).unwrap();
+
+    let stored = restored.get_experience(expiring).unwrap().unwrap();
+    assert_eq!(stored.expires_at, Some(expires_at));
+    let stored = restored.get_experience(permanent).unwrap().unwrap();
+    assert_eq!(stored.expires_at, None);
+}
+
+#[test]
+fn test_collective_archive_preserves_expiry() {
+    let (db, cid, dir) = open_db_with_collective();
+    let expires_at = in_millis(3_600_000);
+    let expiring = record(&db, cid, Some(expires_at));
+
+    let mut archive = Vec::new();
+    db.export_collective(cid, &mut archive).unwrap();
+    let other = PulseDB::open(dir.path().join("other.db"), Config::default()).unwrap();
+    other.import_collective(archive.as_slice()).unwrap();
+
+    let stored = other.get_experience(expiring).unwrap().unwrap();
+    assert_eq!(stored.expires_at, Some(expires_at));
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn test_expiry_validation() {
+    let (db, cid, dir) = open_db_with_collective();
+    let err = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "already stale".into(),
+            embedding: Some(vec![0.1; 384]),
+            expires_at: Some(Timestamp::from_millis(1)),
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    let invalid = Config {
+        expiry_sweep: Some(Duration::ZERO),
+        ..Default::default()
+    };
+    assert!(PulseDB::open(dir.path().join("other.db"), invalid).is_err());
+}
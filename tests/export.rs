@@ -0,0 +1,694 @@
+//! Integration tests for the export format.
+//!
+//! Tests the full stack: PulseDB facade -> export file -> PulseDB facade.
+//! Covers roundtrip, manifest contents, corruption detection with nothing
+//! applied, dimension mismatch, skipping existing records, incremental
+//! backup chains, point-in-time restore, and file-level backups.
+
+use pulsedb::{
+    CollectiveId, CollectiveUpdate, Config, EmbeddingDimension, ExperienceId, ExperienceUpdate,
+    ExportKind, InsightSourceCascade, InsightType, ModelAttribution, NewDerivedInsight,
+    NewExperience, NewExperienceRelation, PulseDB, RelationType, Timestamp,
+};
+use tempfile::tempdir;
+
+/// Helper: record a minimal experience.
+fn record(db: &PulseDB, cid: CollectiveId, content: &str, seed: f32) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.to_string(),
+        embedding: Some(vec![seed; 384]),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: populate a database with a parent/child collective pair (the
+/// parent described), two experiences (one model-attributed), a relation,
+/// and an insight. Returns the parent collective.
+fn populate(db: &PulseDB) -> CollectiveId {
+    let cid = db.create_collective("exported").unwrap();
+    db.update_collective(
+        cid,
+        CollectiveUpdate {
+            description: Some("exported collective".to_string()),
+            settings: Some([("team".to_string(), "platform".to_string())].into()),
+        },
+    )
+    .unwrap();
+    let child = db.create_sub_collective(cid, "child").unwrap();
+    let a = record(db, cid, "first", 0.1);
+    let b = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "second".to_string(),
+            embedding: Some(vec![0.2; 384]),
+            attribution: Some(ModelAttribution {
+                model_name: "gpt-4o".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .unwrap();
+    record(db, child, "child record", 0.3);
+
+    db.store_relation(NewExperienceRelation {
+        source_id: a,
+        target_id: b,
+        relation_type: RelationType::Supports,
+        strength: 0.8,
+        metadata: None,
+    })
+    .unwrap();
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: "first supports second".to_string(),
+        embedding: Some(vec![0.15; 384]),
+        source_experience_ids: vec![a, b],
+        insight_type: InsightType::Pattern,
+        confidence: 0.9,
+        domain: vec![],
+    })
+    .unwrap();
+    cid
+}
+
+// ============================================================================
+// Roundtrip
+// ============================================================================
+
+#[test]
+fn test_export_import_roundtrip() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("out.pulse");
+
+    let source = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let cid = populate(&source);
+    let manifest = source.export(&file).unwrap();
+    source.close().unwrap();
+
+    assert_eq!(manifest.collective_count, 2);
+    assert_eq!(manifest.experience_count, 3);
+    assert_eq!(manifest.relation_count, 1);
+    assert_eq!(manifest.insight_count, 1);
+    assert_eq!(manifest.embedding_dimension, 384);
+    assert_eq!(manifest.embedding_model, None);
+    assert_eq!(manifest.format_version, 1);
+    assert_eq!(manifest.kind, ExportKind::Full);
+
+    let target = PulseDB::open(dir.path().join("target.db"), Config::default()).unwrap();
+    let report = target.import(&file).unwrap();
+    assert_eq!(report.collectives, 2);
+    assert_eq!(report.experiences, 3);
+    assert_eq!(report.relations, 1);
+    assert_eq!(report.insights, 1);
+    assert_eq!(report.skipped, 0);
+
+    // Records, collective details, hierarchy, and vector indexes all come across
+    let collective = target.get_collective(cid).unwrap().unwrap();
+    assert_eq!(collective.name, "exported");
+    assert_eq!(
+        collective.description.as_deref(),
+        Some("exported collective")
+    );
+    assert_eq!(collective.settings["team"], "platform");
+    assert_eq!(target.list_child_collectives(cid).unwrap().len(), 1);
+    let hits = target.search_similar(cid, &[0.2; 384], 5).unwrap();
+    assert_eq!(hits.len(), 2);
+    assert_eq!(
+        hits.iter()
+            .filter(|hit| hit.experience.attribution.is_some())
+            .count(),
+        1
+    );
+    assert_eq!(target.list_relations(cid, 10, 0).unwrap().len(), 1);
+    assert_eq!(target.list_insights(cid, 10, 0).unwrap().len(), 1);
+    target.close().unwrap();
+}
+
+#[test]
+fn test_export_carries_bookmarks_and_degraded_flags() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("out.pulse");
+    let config = Config {
+        insight_source_cascade: InsightSourceCascade::MarkDegraded,
+        ..Default::default()
+    };
+
+    let source = PulseDB::open(dir.path().join("source.db"), config.clone()).unwrap();
+    let cid = source.create_collective("flags").unwrap();
+    let a = record(&source, cid, "kept", 0.1);
+    let b = record(&source, cid, "deleted", 0.2);
+    let insight = source
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "cites both".to_string(),
+            embedding: Some(vec![0.15; 384]),
+            source_experience_ids: vec![a, b],
+            insight_type: InsightType::Pattern,
+            confidence: 0.9,
+            domain: vec![],
+        })
+        .unwrap();
+    source.delete_experience(b).unwrap();
+    source.bookmark_experience("reviewer", a).unwrap();
+    assert!(source.is_insight_degraded(insight).unwrap());
+
+    let manifest = source.export(&file).unwrap();
+    source.close().unwrap();
+    assert_eq!(manifest.bookmark_count, 1);
+
+    let target = PulseDB::open(dir.path().join("target.db"), config).unwrap();
+    let report = target.import(&file).unwrap();
+    assert_eq!(report.bookmarks, 1);
+    assert!(target.is_insight_degraded(insight).unwrap());
+    let bookmarks = target.list_bookmarks("reviewer").unwrap();
+    assert_eq!(bookmarks.len(), 1);
+    assert_eq!(bookmarks[0].id, a);
+    target.close().unwrap();
+}
+
+#[test]
+fn test_verify_export_returns_manifest() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("out.pulse");
+
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    populate(&db);
+    let written = db.export(&file).unwrap();
+    db.close().unwrap();
+
+    let verified = PulseDB::verify_export(&file).unwrap();
+    assert_eq!(verified, written);
+}
+
+// ============================================================================
+// Verification Before Apply
+// ============================================================================
+
+#[test]
+fn test_corrupted_export_applies_nothing() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("out.pulse");
+
+    let source = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let cid = populate(&source);
+    source.export(&file).unwrap();
+    source.close().unwrap();
+
+    // Flip one byte near the end of the file (inside a later record)
+    let mut bytes = std::fs::read(&file).unwrap();
+    let at = bytes.len() - 4;
+    bytes[at] ^= 0xFF;
+    std::fs::write(&file, &bytes).unwrap();
+
+    assert!(PulseDB::verify_export(&file).is_err());
+
+    let target = PulseDB::open(dir.path().join("target.db"), Config::default()).unwrap();
+    let err = target.import(&file).unwrap_err();
+    assert!(err.is_storage());
+    // The earlier, intact records were not applied either
+    assert!(target.get_collective(cid).unwrap().is_none());
+    assert!(target.list_collectives().unwrap().is_empty());
+    target.close().unwrap();
+}
+
+#[test]
+fn test_dimension_mismatch_rejected() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("out.pulse");
+
+    let source = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    populate(&source);
+    source.export(&file).unwrap();
+    source.close().unwrap();
+
+    let config = Config {
+        embedding_dimension: EmbeddingDimension::D768,
+        ..Default::default()
+    };
+    let target = PulseDB::open(dir.path().join("target.db"), config).unwrap();
+    let err = target.import(&file).unwrap_err();
+    assert!(err.is_validation());
+    assert!(target.list_collectives().unwrap().is_empty());
+    target.close().unwrap();
+}
+
+// ============================================================================
+// Existing Records
+// ============================================================================
+
+#[test]
+fn test_reimport_skips_existing_records() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("out.pulse");
+
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = populate(&db);
+    db.export(&file).unwrap();
+
+    let report = db.import(&file).unwrap();
+    assert_eq!(report.collectives, 0);
+    assert_eq!(report.experiences, 0);
+    assert_eq!(report.skipped, 7);
+    assert_eq!(db.list_experiences(cid, 10, 0).unwrap().len(), 2);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_import_into_frozen_collective_is_busy() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("out.pulse");
+
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = populate(&db);
+    db.export(&file).unwrap();
+
+    db.freeze_collective(cid).unwrap();
+    assert!(db.import(&file).unwrap_err().is_busy());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Incremental Backups
+// ============================================================================
+
+#[test]
+fn test_restore_chain_replays_deltas() {
+    let dir = tempdir().unwrap();
+    let base_file = dir.path().join("base.pulse");
+    let delta1_file = dir.path().join("delta-1.pulse");
+    let delta2_file = dir.path().join("delta-2.pulse");
+
+    let db = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let cid = populate(&db);
+    let base = db.export(&base_file).unwrap();
+
+    // Delta 1: add, update, and delete records; add a collective
+    let added = record(&db, cid, "added later", 0.4);
+    let existing = db.list_experiences(cid, 10, 0).unwrap();
+    let (first, second) = (existing[0].id, existing[1].id);
+    db.update_experience(
+        first,
+        ExperienceUpdate {
+            importance: Some(0.95),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    db.delete_experience(second).unwrap();
+    let extra = db.create_collective("extra").unwrap();
+    let delta1 = db.backup_incremental(&delta1_file, base.sequence).unwrap();
+    assert_eq!(delta1.kind, ExportKind::Incremental);
+    assert_eq!(delta1.base_sequence, base.sequence);
+    assert!(delta1.tombstone_count >= 1);
+
+    // Delta 2: drop the collective added in delta 1
+    db.delete_collective(extra).unwrap();
+    let delta2 = db
+        .backup_incremental(&delta2_file, delta1.sequence)
+        .unwrap();
+    assert_eq!(delta2.experience_count, 0);
+    db.close().unwrap();
+
+    let restored = PulseDB::restore_chain(
+        &[&base_file, &delta1_file, &delta2_file],
+        dir.path().join("restored.db"),
+        Config::default(),
+    )
+    .unwrap();
+
+    let ids: Vec<_> = restored
+        .list_experiences(cid, 10, 0)
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&first) && ids.contains(&added));
+    assert!(restored.get_experience(second).unwrap().is_none());
+    let updated = restored.get_experience(first).unwrap().unwrap();
+    assert!((updated.importance - 0.95).abs() < f32::EPSILON);
+    // Relation citing the deleted experience went with it
+    assert!(restored.list_relations(cid, 10, 0).unwrap().is_empty());
+    assert!(restored.get_collective(extra).unwrap().is_none());
+    assert_eq!(restored.list_child_collectives(cid).unwrap().len(), 1);
+    // Vector index rebuilt from restored records
+    let hits = restored.search_similar(cid, &[0.4; 384], 5).unwrap();
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().any(|hit| hit.experience.id == added));
+    restored.close().unwrap();
+}
+
+#[test]
+fn test_restore_chain_rejects_gap() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let cid = populate(&db);
+    let base = db.export(dir.path().join("base.pulse")).unwrap();
+    record(&db, cid, "one", 0.4);
+    let delta1 = db
+        .backup_incremental(dir.path().join("delta-1.pulse"), base.sequence)
+        .unwrap();
+    record(&db, cid, "two", 0.5);
+    db.backup_incremental(dir.path().join("delta-2.pulse"), delta1.sequence)
+        .unwrap();
+    db.close().unwrap();
+
+    let target = dir.path().join("restored.db");
+    let err = PulseDB::restore_chain(
+        &[
+            dir.path().join("base.pulse"),
+            dir.path().join("delta-2.pulse"),
+        ],
+        &target,
+        Config::default(),
+    )
+    .unwrap_err();
+    assert!(err.is_validation());
+    assert!(!target.exists());
+}
+
+#[test]
+fn test_backup_incremental_validation() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    populate(&db);
+    let sequence = db.get_current_sequence().unwrap();
+
+    let err = db
+        .backup_incremental(dir.path().join("ahead.pulse"), sequence + 1)
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    // An empty delta is valid, but only restore_chain may apply it
+    let delta_file = dir.path().join("empty.pulse");
+    let delta = db.backup_incremental(&delta_file, sequence).unwrap();
+    assert_eq!(delta.experience_count, 0);
+    assert_eq!(delta.tombstone_count, 0);
+    assert!(db.import(&delta_file).unwrap_err().is_validation());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Point-in-Time Restore
+// ============================================================================
+
+#[test]
+fn test_restore_rewinds_past_poisoning() {
+    let dir = tempdir().unwrap();
+    let backups = dir.path().join("backups");
+    std::fs::create_dir(&backups).unwrap();
+    // Stray files in the backup directory are ignored
+    std::fs::write(backups.join("README"), b"hourly backups").unwrap();
+
+    let db = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let cid = populate(&db);
+    let base = db.export(backups.join("0-base.pulse")).unwrap();
+    let good = record(&db, cid, "good", 0.4);
+    let delta1 = db
+        .backup_incremental(backups.join("1-delta.pulse"), base.sequence)
+        .unwrap();
+
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let before_incident = Timestamp::now();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+
+    let poison = record(&db, cid, "poison", 0.5);
+    db.backup_incremental(backups.join("2-delta.pulse"), delta1.sequence)
+        .unwrap();
+    db.close().unwrap();
+
+    let rewound = PulseDB::restore(
+        &backups,
+        dir.path().join("rewound.db"),
+        before_incident,
+        Config::default(),
+    )
+    .unwrap();
+    assert!(rewound.get_experience(good).unwrap().is_some());
+    assert!(rewound.get_experience(poison).unwrap().is_none());
+    rewound.close().unwrap();
+
+    let latest = PulseDB::restore(
+        &backups,
+        dir.path().join("latest.db"),
+        Timestamp::now(),
+        Config::default(),
+    )
+    .unwrap();
+    assert!(latest.get_experience(poison).unwrap().is_some());
+    latest.close().unwrap();
+}
+
+#[test]
+fn test_restore_between_backups_lands_on_the_earlier_one() {
+    let dir = tempdir().unwrap();
+    let backups = dir.path().join("backups");
+    std::fs::create_dir(&backups).unwrap();
+
+    let db = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let cid = populate(&db);
+    let base = db.export(backups.join("0-base.pulse")).unwrap();
+    let backed_up = record(&db, cid, "backed up", 0.4);
+    let delta1 = db
+        .backup_incremental(backups.join("1-delta.pulse"), base.sequence)
+        .unwrap();
+
+    // Recorded before `upto`, but only the next backup holds it
+    let unsaved = record(&db, cid, "unsaved", 0.5);
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let upto = Timestamp::now();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    db.backup_incremental(backups.join("2-delta.pulse"), delta1.sequence)
+        .unwrap();
+    db.close().unwrap();
+
+    let rewound = PulseDB::restore(
+        &backups,
+        dir.path().join("rewound.db"),
+        upto,
+        Config::default(),
+    )
+    .unwrap();
+    assert!(rewound.get_experience(backed_up).unwrap().is_some());
+    assert!(rewound.get_experience(unsaved).unwrap().is_none());
+    rewound.close().unwrap();
+}
+
+#[test]
+fn test_restore_before_first_backup_fails() {
+    let dir = tempdir().unwrap();
+    let backups = dir.path().join("backups");
+    std::fs::create_dir(&backups).unwrap();
+
+    let too_early = Timestamp::now();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let db = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    populate(&db);
+    db.export(backups.join("base.pulse")).unwrap();
+    db.close().unwrap();
+
+    let target = dir.path().join("rewound.db");
+    let err = PulseDB::restore(&backups, &target, too_early, Config::default()).unwrap_err();
+    assert!(err.is_validation());
+    assert!(!target.exists());
+}
+
+// ============================================================================
+// Index Snapshots
+// ============================================================================
+
+/// Helper: a deterministic, well-spread embedding.
+fn spread_embedding(seed: u64) -> Vec<f32> {
+    (0..384)
+        .map(|i| {
+            let h = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(i as u64)
+                .wrapping_mul(1442695040888963407);
+            (h >> 33) as f32 / (u32::MAX as f32) - 0.5
+        })
+        .collect()
+}
+
+#[test]
+fn test_import_with_index_snapshots_skips_rebuild() {
+    let dir = tempdir().unwrap();
+    let primary = PulseDB::open(dir.path().join("primary.db"), Config::default()).unwrap();
+    let cid = primary.create_collective("indexed").unwrap();
+    let ids: Vec<ExperienceId> = (0..300)
+        .map(|seed| {
+            primary
+                .record_experience(NewExperience {
+                    collective_id: cid,
+                    content: format!("seed {}", seed),
+                    embedding: Some(spread_embedding(seed)),
+                    ..Default::default()
+                })
+                .unwrap()
+        })
+        .collect();
+
+    let snapshots = dir.path().join("indexes");
+    let manifest = primary
+        .export_index_snapshot(cid, snapshots.join(cid.to_string()))
+        .unwrap();
+    assert_eq!(manifest.collective_id, cid);
+    assert_eq!(manifest.vector_count, 300);
+
+    // Changes after the snapshot are caught up on import
+    primary.delete_experience(ids[0]).unwrap();
+    let late = primary
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "late".to_string(),
+            embedding: Some(spread_embedding(1000)),
+            ..Default::default()
+        })
+        .unwrap();
+    let export_path = dir.path().join("data.pulse");
+    primary.export(&export_path).unwrap();
+
+    let replica = PulseDB::open(dir.path().join("replica.db"), Config::default()).unwrap();
+    let report = replica
+        .import_with_index_snapshots(&export_path, &snapshots)
+        .unwrap();
+    assert_eq!(report.experiences, 300);
+
+    let top = |db: &PulseDB, seed: u64| {
+        db.search_similar(cid, &spread_embedding(seed), 1).unwrap()[0]
+            .experience
+            .id
+    };
+    assert_eq!(top(&replica, 42), ids[42]);
+    assert_eq!(top(&replica, 1000), late);
+    assert_ne!(top(&replica, 0), ids[0]);
+
+    primary.close().unwrap();
+    replica.close().unwrap();
+}
+
+#[test]
+fn test_restore_index_snapshot_validation() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = populate(&db);
+    let other = db.create_collective("other").unwrap();
+
+    let snapshot = dir.path().join("snapshot");
+    db.export_index_snapshot(cid, &snapshot).unwrap();
+    let manifest = db.restore_index_snapshot(cid, &snapshot).unwrap();
+    assert_eq!(manifest.vector_count, 2);
+    assert_eq!(db.search_similar(cid, &[0.1; 384], 5).unwrap().len(), 2);
+
+    // A snapshot only restores onto the collective it was taken of
+    let err = db.restore_index_snapshot(other, &snapshot).unwrap_err();
+    assert!(err.to_string().contains("belongs to collective"));
+
+    // A damaged file is caught before anything is swapped in
+    let graph = snapshot.join(&manifest.files[1].name);
+    let mut bytes = std::fs::read(&graph).unwrap();
+    bytes[0] ^= 0xFF;
+    std::fs::write(&graph, bytes).unwrap();
+    assert!(db.restore_index_snapshot(cid, &snapshot).is_err());
+    assert_eq!(db.search_similar(cid, &[0.1; 384], 5).unwrap().len(), 2);
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// File Backups
+// ============================================================================
+
+#[test]
+fn test_backup_to_restore_from_roundtrip() {
+    let dir = tempdir().unwrap();
+    let backup = dir.path().join("nightly");
+
+    let db = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let cid = populate(&db);
+    let sequence = db.get_current_sequence().unwrap();
+    db.backup_to(&backup).unwrap();
+    let later = record(&db, cid, "after backup", 0.4);
+
+    let restored =
+        PulseDB::restore_from(&backup, dir.path().join("restored.db"), Config::default()).unwrap();
+    assert_eq!(restored.list_collectives().unwrap().len(), 2);
+    assert_eq!(restored.list_child_collectives(cid).unwrap().len(), 1);
+    assert!(restored.get_experience(later).unwrap().is_none());
+    assert_eq!(
+        restored.search_similar(cid, &[0.2; 384], 5).unwrap().len(),
+        2
+    );
+    assert_eq!(restored.list_relations(cid, 10, 0).unwrap().len(), 1);
+    assert_eq!(restored.list_insights(cid, 10, 0).unwrap().len(), 1);
+
+    // The change log comes along, so incremental backups can continue
+    assert_eq!(restored.get_current_sequence().unwrap(), sequence);
+    assert_eq!(
+        restored.changes_since(0).unwrap(),
+        db.changes_since(0).unwrap()[..sequence as usize]
+    );
+    restored.close().unwrap();
+
+    // The backup is left in place and can be restored again
+    let again =
+        PulseDB::restore_from(&backup, dir.path().join("again.db"), Config::default()).unwrap();
+    assert_eq!(again.list_collectives().unwrap().len(), 2);
+    again.close().unwrap();
+    db.close().unwrap();
+}
+
+#[test]
+fn test_backup_to_leaves_nothing_behind_on_failure() {
+    let dir = tempdir().unwrap();
+    let backup = dir.path().join("nightly");
+    let db = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    let cid = populate(&db);
+
+    // A file in the way fails the backup once the files are written
+    std::fs::write(&backup, b"not a directory").unwrap();
+    assert!(db.backup_to(&backup).is_err());
+    let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.ends_with(".partial"))
+        .collect();
+    assert!(leftovers.is_empty(), "staging left behind: {:?}", leftovers);
+
+    // Once the path is free the retry succeeds, into an existing directory
+    std::fs::remove_file(&backup).unwrap();
+    std::fs::create_dir(&backup).unwrap();
+    db.backup_to(&backup).unwrap();
+    let restored =
+        PulseDB::restore_from(&backup, dir.path().join("restored.db"), Config::default()).unwrap();
+    assert_eq!(
+        restored.search_similar(cid, &[0.2; 384], 5).unwrap().len(),
+        2
+    );
+    restored.close().unwrap();
+    db.close().unwrap();
+}
+
+#[test]
+fn test_backup_to_and_restore_from_validation() {
+    let dir = tempdir().unwrap();
+    let backup = dir.path().join("nightly");
+    let db = PulseDB::open(dir.path().join("source.db"), Config::default()).unwrap();
+    populate(&db);
+
+    db.backup_to(&backup).unwrap();
+    assert!(db.backup_to(&backup).unwrap_err().is_validation());
+
+    // No backup in the directory
+    let err =
+        PulseDB::restore_from(dir.path(), dir.path().join("a.db"), Config::default()).unwrap_err();
+    assert!(err.is_validation());
+
+    // Restoring over an existing database is refused
+    let err = PulseDB::restore_from(&backup, dir.path().join("source.db"), Config::default())
+        .unwrap_err();
+    assert!(err.is_validation());
+    db.close().unwrap();
+}
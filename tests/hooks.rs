@@ -0,0 +1,326 @@
+//! Integration tests for write and read hooks.
+//!
+//! Tests the full stack: PulseDB facade -> hook pipeline -> redb.
+//! Covers enrichment and redaction before write, rejection, registration
+//! order, post-write notification for every record kind, clearing, and
+//! per-collective read-time rewriting of get, list, and search results.
+
+use std::sync::{Arc, Mutex};
+
+use pulsedb::{
+    CollectiveId, CommittedWrite, Config, ExperienceId, Hook, InsightType, NewDerivedInsight,
+    NewExperience, NewExperienceRelation, PendingWrite, PulseDB, PulseDBError, ReadHook,
+    ReadRecord, RelationDirection, RelationType, Result, SearchFilter,
+};
+use tempfile::tempdir;
+
+/// Helper: open a DB with one collective.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record an experience with the given content.
+fn record(db: &PulseDB, cid: CollectiveId, content: &str) -> Result<ExperienceId> {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.to_string(),
+        embedding: Some(vec![0.1; 384]),
+        ..Default::default()
+    })
+}
+
+/// Adds a domain tag to experiences and insights.
+struct Tag(&'static str);
+
+impl Hook for Tag {
+    fn pre_write(&self, write: &mut PendingWrite<'_>) -> Result<()> {
+        match write {
+            PendingWrite::Experience(exp) => exp.domain.push(self.0.to_string()),
+            PendingWrite::Insight(insight) => insight.domain.push(self.0.to_string()),
+            PendingWrite::Relation(_) => {}
+        }
+        Ok(())
+    }
+}
+
+/// Rejects experiences whose content mentions a secret.
+struct RejectSecrets;
+
+impl Hook for RejectSecrets {
+    fn pre_write(&self, write: &mut PendingWrite<'_>) -> Result<()> {
+        if let PendingWrite::Experience(exp) = write {
+            if exp.content.contains("secret") {
+                return Err(PulseDBError::internal("secrets are not allowed"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Records a line per committed write.
+#[derive(Default)]
+struct Journal(Mutex<Vec<String>>);
+
+impl Hook for Journal {
+    fn post_write(&self, write: &CommittedWrite<'_>) -> Result<()> {
+        let line = match write {
+            CommittedWrite::Experience(exp) => format!("experience {}", exp.id),
+            CommittedWrite::Insight(insight) => format!("insight {}", insight.id),
+            CommittedWrite::Relation(relation) => format!("relation {}", relation.id),
+        };
+        self.0.lock().unwrap().push(line);
+        Ok(())
+    }
+}
+
+/// Always fails after the write.
+struct FailingNotifier;
+
+impl Hook for FailingNotifier {
+    fn post_write(&self, _write: &CommittedWrite<'_>) -> Result<()> {
+        Err(PulseDBError::internal("notifier down"))
+    }
+}
+
+// ============================================================================
+// Pre-write
+// ============================================================================
+
+#[test]
+fn test_pre_write_hooks_enrich_in_order() {
+    let (db, cid, _dir) = open_db_with_collective();
+    db.add_hook(Arc::new(Tag("first")));
+    db.add_hook(Arc::new(Tag("second")));
+
+    let id = record(&db, cid, "tagged").unwrap();
+    let exp = db.get_experience(id).unwrap().unwrap();
+    assert_eq!(exp.domain, vec!["first", "second"]);
+
+    let insight = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "derived".to_string(),
+            embedding: Some(vec![0.1; 384]),
+            source_experience_ids: vec![id],
+            insight_type: InsightType::Pattern,
+            confidence: 0.8,
+            domain: vec![],
+        })
+        .unwrap();
+    let insight = db.get_insight(insight).unwrap().unwrap();
+    assert_eq!(insight.domain, vec!["first", "second"]);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_pre_write_hook_rejects_write() {
+    let (db, cid, _dir) = open_db_with_collective();
+    db.add_hook(Arc::new(RejectSecrets));
+    let journal = Arc::new(Journal::default());
+    db.add_hook(journal.clone());
+
+    assert!(record(&db, cid, "the secret is 42").is_err());
+    assert_eq!(db.get_collective_stats(cid).unwrap().experience_count, 0);
+    assert!(journal.0.lock().unwrap().is_empty());
+
+    record(&db, cid, "nothing to hide").unwrap();
+    assert_eq!(journal.0.lock().unwrap().len(), 1);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_pre_write_output_is_validated() {
+    struct Blank;
+    impl Hook for Blank {
+        fn pre_write(&self, write: &mut PendingWrite<'_>) -> Result<()> {
+            if let PendingWrite::Experience(exp) = write {
+                exp.content.clear();
+            }
+            Ok(())
+        }
+    }
+
+    let (db, cid, _dir) = open_db_with_collective();
+    db.add_hook(Arc::new(Blank));
+    assert!(record(&db, cid, "content").unwrap_err().is_validation());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Post-write
+// ============================================================================
+
+#[test]
+fn test_post_write_hooks_see_every_record_kind() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let journal = Arc::new(Journal::default());
+    db.add_hook(Arc::new(FailingNotifier));
+    db.add_hook(journal.clone());
+
+    let a = record(&db, cid, "a").unwrap();
+    let b = record(&db, cid, "b").unwrap();
+    let relation = db
+        .store_relation(NewExperienceRelation {
+            source_id: a,
+            target_id: b,
+            relation_type: RelationType::Supports,
+            strength: 0.5,
+            metadata: None,
+        })
+        .unwrap();
+
+    // A failing hook does not fail the write or stop later hooks
+    let lines = journal.0.lock().unwrap().clone();
+    assert_eq!(
+        lines,
+        vec![
+            format!("experience {}", a),
+            format!("experience {}", b),
+            format!("relation {}", relation),
+        ]
+    );
+    db.close().unwrap();
+}
+
+#[test]
+fn test_clear_hooks() {
+    let (db, cid, _dir) = open_db_with_collective();
+    db.add_hook(Arc::new(RejectSecrets));
+    assert!(record(&db, cid, "secret").is_err());
+
+    db.clear_hooks();
+    record(&db, cid, "secret").unwrap();
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Read hooks
+// ============================================================================
+
+/// Appends a marker to the content of experiences and insights.
+struct Annotate(&'static str);
+
+impl ReadHook for Annotate {
+    fn on_read(&self, record: &mut ReadRecord<'_>) -> Result<()> {
+        match record {
+            ReadRecord::Experience(exp) => exp.content.push_str(self.0),
+            ReadRecord::Insight(insight) => insight.content.push_str(self.0),
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_read_hooks_rewrite_results() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let a = record(&db, cid, "a").unwrap();
+    let b = record(&db, cid, "b").unwrap();
+    db.store_relation(NewExperienceRelation {
+        source_id: a,
+        target_id: b,
+        relation_type: RelationType::Supports,
+        strength: 0.5,
+        metadata: None,
+    })
+    .unwrap();
+    let insight = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "derived".to_string(),
+            embedding: Some(vec![0.1; 384]),
+            source_experience_ids: vec![a],
+            insight_type: InsightType::Pattern,
+            confidence: 0.8,
+            domain: vec![],
+        })
+        .unwrap();
+    db.add_read_hook(cid, Arc::new(Annotate(" [1]"))).unwrap();
+    db.add_read_hook(cid, Arc::new(Annotate(" [2]"))).unwrap();
+
+    assert_eq!(db.get_experience(a).unwrap().unwrap().content, "a [1] [2]");
+    for exp in db.list_experiences(cid, 10, 0).unwrap() {
+        assert!(exp.content.ends_with(" [1] [2]"));
+    }
+    for exp in db.get_recent_experiences(cid, 10).unwrap() {
+        assert!(exp.content.ends_with(" [1] [2]"));
+    }
+    for hit in db.search_similar(cid, &[0.1; 384], 10).unwrap() {
+        assert!(hit.experience.content.ends_with(" [1] [2]"));
+    }
+    let related = db
+        .get_related_experiences(a, RelationDirection::Outgoing)
+        .unwrap();
+    assert_eq!(related[0].0.content, "b [1] [2]");
+    assert_eq!(
+        db.get_insight(insight).unwrap().unwrap().content,
+        "derived [1] [2]"
+    );
+    assert_eq!(
+        db.get_insights(cid, &[0.1; 384], 5).unwrap()[0].0.content,
+        "derived [1] [2]"
+    );
+
+    // Filters see the stored record, not the rewritten one
+    struct TagRead;
+    impl ReadHook for TagRead {
+        fn on_read(&self, record: &mut ReadRecord<'_>) -> Result<()> {
+            if let ReadRecord::Experience(exp) = record {
+                exp.domain.push("hooked".to_string());
+            }
+            Ok(())
+        }
+    }
+    db.add_read_hook(cid, Arc::new(TagRead)).unwrap();
+    let filter = SearchFilter {
+        domains: Some(vec!["hooked".to_string()]),
+        ..Default::default()
+    };
+    assert!(db
+        .get_recent_experiences_filtered(cid, 10, filter)
+        .unwrap()
+        .is_empty());
+
+    db.clear_read_hooks(cid);
+    assert_eq!(db.get_experience(a).unwrap().unwrap().content, "a");
+    db.close().unwrap();
+}
+
+#[test]
+fn test_read_hooks_are_per_collective() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let other = db.create_collective("other").unwrap();
+    let mine = record(&db, cid, "mine").unwrap();
+    let theirs = record(&db, other, "theirs").unwrap();
+    db.add_read_hook(cid, Arc::new(Annotate("!"))).unwrap();
+
+    assert_eq!(db.get_experience(mine).unwrap().unwrap().content, "mine!");
+    assert_eq!(
+        db.get_experience(theirs).unwrap().unwrap().content,
+        "theirs"
+    );
+    assert!(db
+        .add_read_hook(CollectiveId::new(), Arc::new(Annotate("!")))
+        .unwrap_err()
+        .is_not_found());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_read_hook_error_fails_read() {
+    struct Deny;
+    impl ReadHook for Deny {
+        fn on_read(&self, _record: &mut ReadRecord<'_>) -> Result<()> {
+            Err(PulseDBError::internal("access denied"))
+        }
+    }
+
+    let (db, cid, _dir) = open_db_with_collective();
+    let id = record(&db, cid, "guarded").unwrap();
+    db.add_read_hook(cid, Arc::new(Deny)).unwrap();
+    assert!(db.get_experience(id).is_err());
+    assert!(db.get_recent_experiences(cid, 10).is_err());
+    db.close().unwrap();
+}
@@ -4,8 +4,8 @@
 //! Covers insight CRUD, vector search, cascade deletes, and validation error paths.
 
 use pulsedb::{
-    CollectiveId, Config, ExperienceId, InsightId, InsightType, NewDerivedInsight, NewExperience,
-    PulseDB,
+    CollectiveId, Config, ExperienceId, InsightFilter, InsightId, InsightSourceCascade,
+    InsightType, NewDerivedInsight, NewExperience, PulseDB,
 };
 use tempfile::tempdir;
 
@@ -454,3 +454,323 @@ fn test_all_insight_types() {
         assert_eq!(insight.insight_type, *insight_type);
     }
 }
+
+// ============================================================================
+// Source Integrity (strict mode + repair)
+// ============================================================================
+
+#[test]
+fn test_insight_sources_unchecked_when_not_strict() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        strict_insight_sources: false,
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("import").unwrap();
+
+    // Source not yet imported — accepted in non-strict mode
+    let missing = ExperienceId::new();
+    let id = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "Imported ahead of its sources".to_string(),
+            embedding: Some(dummy_embedding()),
+            source_experience_ids: vec![missing],
+            insight_type: InsightType::Pattern,
+            confidence: 0.5,
+            domain: vec![],
+        })
+        .unwrap();
+
+    let insight = db.get_insight(id).unwrap().unwrap();
+    assert_eq!(insight.source_experience_ids, vec![missing]);
+
+    db.close().unwrap();
+}
+
+/// Helper: open DB with the given cascade policy and one collective.
+fn open_db_with_cascade(
+    policy: InsightSourceCascade,
+) -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        insight_source_cascade: policy,
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+fn store_insight_from(db: &PulseDB, cid: CollectiveId, sources: Vec<ExperienceId>) -> InsightId {
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: "Derived from sources".to_string(),
+        embedding: Some(dummy_embedding()),
+        source_experience_ids: sources,
+        insight_type: InsightType::Pattern,
+        confidence: 0.7,
+        domain: vec![],
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_delete_cited_experience_detaches_by_default() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let (exp_a, exp_b) = record_source_experiences(&db, cid);
+    let insight_id = store_insight_from(&db, cid, vec![exp_a, exp_b]);
+
+    db.delete_experience(exp_b).unwrap();
+
+    let insight = db.get_insight(insight_id).unwrap().unwrap();
+    assert_eq!(insight.source_experience_ids, vec![exp_a]);
+    assert!(!db.is_insight_degraded(insight_id).unwrap());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_delete_last_source_deletes_insight() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let (exp_a, exp_b) = record_source_experiences(&db, cid);
+    let orphaned = store_insight_from(&db, cid, vec![exp_a]);
+    let shared = store_insight_from(&db, cid, vec![exp_a, exp_b]);
+
+    db.delete_experience(exp_a).unwrap();
+
+    // An insight may not outlive all of its sources
+    assert!(db.get_insight(orphaned).unwrap().is_none());
+    let insight = db.get_insight(shared).unwrap().unwrap();
+    assert_eq!(insight.source_experience_ids, vec![exp_b]);
+
+    let results = db.get_insights(cid, &dummy_embedding(), 10).unwrap();
+    assert!(results.iter().all(|(i, _)| i.id != orphaned));
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_delete_cited_experience_blocked() {
+    let (db, cid, _dir) = open_db_with_cascade(InsightSourceCascade::Block);
+    let (exp_a, exp_b) = record_source_experiences(&db, cid);
+    let insight_id = store_insight_from(&db, cid, vec![exp_a]);
+
+    let err = db.delete_experience(exp_a).unwrap_err();
+    assert!(err.is_validation());
+    assert!(db.get_experience(exp_a).unwrap().is_some());
+
+    // Uncited experiences delete normally
+    db.delete_experience(exp_b).unwrap();
+
+    // Once the insight is gone, the source can be deleted
+    db.delete_insight(insight_id).unwrap();
+    db.delete_experience(exp_a).unwrap();
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_repair_insight_sources_removes_dangling() {
+    let (db, cid, _dir) = open_db_with_cascade(InsightSourceCascade::MarkDegraded);
+    let (exp_a, exp_b) = record_source_experiences(&db, cid);
+    let exp_c = db.record_experience(minimal_experience(cid)).unwrap();
+
+    let partial = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "Partially dangling".to_string(),
+            embedding: Some(dummy_embedding()),
+            source_experience_ids: vec![exp_a, exp_b],
+            insight_type: InsightType::Pattern,
+            confidence: 0.7,
+            domain: vec![],
+        })
+        .unwrap();
+    let orphan = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "Fully dangling".to_string(),
+            embedding: Some(dummy_embedding()),
+            source_experience_ids: vec![exp_c],
+            insight_type: InsightType::Synthesis,
+            confidence: 0.7,
+            domain: vec![],
+        })
+        .unwrap();
+
+    db.delete_experience(exp_b).unwrap();
+    db.delete_experience(exp_c).unwrap();
+
+    // MarkDegraded keeps the dangling IDs and flags the insights
+    let dangling = db.get_insight(partial).unwrap().unwrap();
+    assert_eq!(dangling.source_experience_ids, vec![exp_a, exp_b]);
+    let mut degraded = db.list_degraded_insights(cid).unwrap();
+    degraded.sort_by_key(|id| *id.as_bytes());
+    let mut expected = vec![partial, orphan];
+    expected.sort_by_key(|id| *id.as_bytes());
+    assert_eq!(degraded, expected);
+
+    let report = db.repair_insight_sources(cid).unwrap();
+    assert_eq!(report.insights_scanned, 2);
+    assert_eq!(report.insights_repaired, 2);
+    assert_eq!(report.sources_removed, 2);
+    assert_eq!(report.orphaned, vec![orphan]);
+
+    let repaired = db.get_insight(partial).unwrap().unwrap();
+    assert_eq!(repaired.source_experience_ids, vec![exp_a]);
+    assert!(repaired.updated_at >= repaired.created_at);
+    assert!(!db.is_insight_degraded(partial).unwrap());
+    assert!(db.list_degraded_insights(cid).unwrap().is_empty());
+
+    // Second pass is a no-op
+    let again = db.repair_insight_sources(cid).unwrap();
+    assert_eq!(again.insights_repaired, 0);
+    assert_eq!(again.sources_removed, 0);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_repair_insight_sources_nonexistent_collective() {
+    let (db, _dir) = open_db();
+    let err = db.repair_insight_sources(CollectiveId::new()).unwrap_err();
+    assert!(err.is_not_found());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Filtered listing (type / confidence / since)
+// ============================================================================
+
+fn store_typed_insight(
+    db: &PulseDB,
+    cid: CollectiveId,
+    source: ExperienceId,
+    insight_type: InsightType,
+    confidence: f32,
+) -> InsightId {
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: format!("{:?} at {}", insight_type, confidence),
+        embedding: Some(dummy_embedding()),
+        source_experience_ids: vec![source],
+        insight_type,
+        confidence,
+        domain: vec![],
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_list_insights_filtered_by_type_and_confidence() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let (exp_a, _) = record_source_experiences(&db, cid);
+
+    let strong = store_typed_insight(&db, cid, exp_a, InsightType::Pattern, 0.9);
+    let _weak = store_typed_insight(&db, cid, exp_a, InsightType::Pattern, 0.5);
+    let _other = store_typed_insight(&db, cid, exp_a, InsightType::Synthesis, 0.95);
+
+    let results = db
+        .list_insights_filtered(
+            cid,
+            InsightFilter {
+                types: Some(vec![InsightType::Pattern]),
+                min_confidence: Some(0.8),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let ids: Vec<InsightId> = results.iter().map(|i| i.id).collect();
+    assert_eq!(ids, vec![strong]);
+
+    // No filter → everything
+    let all = db
+        .list_insights_filtered(cid, InsightFilter::default())
+        .unwrap();
+    assert_eq!(all.len(), 3);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_list_insights_filtered_limit_newest_first() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let (exp_a, _) = record_source_experiences(&db, cid);
+
+    let mut ids = Vec::new();
+    for _ in 0..3 {
+        ids.push(store_typed_insight(
+            &db,
+            cid,
+            exp_a,
+            InsightType::Abstraction,
+            0.7,
+        ));
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+
+    let results = db
+        .list_insights_filtered(
+            cid,
+            InsightFilter {
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let got: Vec<InsightId> = results.iter().map(|i| i.id).collect();
+    assert_eq!(got, vec![ids[2], ids[1]]);
+
+    // `since` excludes older insights
+    let since = db.get_insight(ids[1]).unwrap().unwrap().created_at;
+    let recent = db
+        .list_insights_filtered(
+            cid,
+            InsightFilter {
+                since: Some(since),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(recent.len(), 2);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_list_insights_filtered_type_index_tracks_deletes_and_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let (cid, kept) = {
+        let db = PulseDB::open(&path, Config::default()).unwrap();
+        let cid = db.create_collective("typed").unwrap();
+        let (exp_a, _) = record_source_experiences(&db, cid);
+        let kept = store_typed_insight(&db, cid, exp_a, InsightType::Correlation, 0.6);
+        let gone = store_typed_insight(&db, cid, exp_a, InsightType::Correlation, 0.6);
+        db.delete_insight(gone).unwrap();
+        db.close().unwrap();
+        (cid, kept)
+    };
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let results = db
+        .list_insights_filtered(
+            cid,
+            InsightFilter {
+                types: Some(vec![InsightType::Correlation]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, kept);
+
+    let err = db
+        .list_insights_filtered(CollectiveId::new(), InsightFilter::default())
+        .unwrap_err();
+    assert!(err.is_not_found());
+
+    db.close().unwrap();
+}
@@ -0,0 +1,174 @@
+//! Integration tests for knowledge gap detection.
+//!
+//! Tests the full stack: PulseDB facade -> similarity search -> gap records
+//! in redb -> clustering.
+//! Covers the score threshold, clustering of repeated queries, query text
+//! from `search_text`, clearing, and the default of not recording.
+
+use pulsedb::{
+    CollectiveId, Config, EmbeddingDimension, KnowledgeGapConfig, NewExperience, PulseDB,
+    SearchFilter,
+};
+use tempfile::tempdir;
+
+const DIM: usize = 384;
+
+/// Helper: open DB with gap tracking, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        knowledge_gaps: Some(KnowledgeGapConfig::default()),
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: an embedding along `axis`, tilted slightly toward `axis + 1`.
+fn near_axis(axis: usize, tilt: f32) -> Vec<f32> {
+    let mut embedding = vec![0.0; DIM];
+    embedding[axis] = 1.0;
+    embedding[axis + 1] = tilt;
+    embedding
+}
+
+/// Helper: record an experience with the given embedding.
+fn record(db: &PulseDB, cid: CollectiveId, embedding: Vec<f32>) {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: "known".into(),
+        embedding: Some(embedding),
+        ..Default::default()
+    })
+    .unwrap();
+}
+
+// ============================================================================
+// Recording and Clustering
+// ============================================================================
+
+#[test]
+fn test_low_scoring_searches_become_gaps() {
+    let (db, cid, _dir) = open_db_with_collective();
+    record(&db, cid, near_axis(0, 0.0));
+
+    // Answered well: not a gap
+    db.search_similar(cid, &near_axis(0, 0.1), 5).unwrap();
+    assert!(db.list_knowledge_gaps(cid).unwrap().is_empty());
+
+    db.search_similar(cid, &near_axis(10, 0.0), 5).unwrap();
+    db.search_similar(cid, &near_axis(20, 0.0), 5).unwrap();
+    db.search_similar(cid, &near_axis(10, 0.1), 5).unwrap();
+
+    let gaps = db.list_knowledge_gaps(cid).unwrap();
+    assert_eq!(gaps.len(), 2);
+    assert_eq!(gaps[0].occurrences, 2);
+    assert!(gaps[0].embedding[10] > 0.99);
+    assert!(gaps[0].best_score.unwrap() < 0.5);
+    assert!(gaps[0].queries.is_empty());
+    assert_eq!(gaps[1].occurrences, 1);
+    assert!(gaps[1].embedding[20] > 0.99);
+}
+
+#[test]
+fn test_filters_and_cutoffs_do_not_make_gaps() {
+    let (db, cid, _dir) = open_db_with_collective();
+    record(&db, cid, near_axis(0, 0.0));
+
+    // Nothing passes, but the collective does know the answer
+    let cutoff = SearchFilter::default().min_similarity(0.9999);
+    assert!(db
+        .search_similar_filtered(cid, &near_axis(0, 0.1), 5, cutoff)
+        .unwrap()
+        .is_empty());
+    let other_domain = SearchFilter::default().domains(["elsewhere"]);
+    assert!(db
+        .search_similar_filtered(cid, &near_axis(0, 0.1), 5, other_domain)
+        .unwrap()
+        .is_empty());
+    assert!(db.list_knowledge_gaps(cid).unwrap().is_empty());
+
+    // A filtered search far from anything known still is one
+    let cutoff = SearchFilter::default().min_similarity(0.9);
+    db.search_similar_filtered(cid, &near_axis(10, 0.0), 5, cutoff)
+        .unwrap();
+    assert_eq!(db.list_knowledge_gaps(cid).unwrap().len(), 1);
+}
+
+#[test]
+fn test_queued_gaps_are_listed_and_survive_close() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let config = Config {
+        knowledge_gaps: Some(KnowledgeGapConfig::default()),
+        ..Default::default()
+    };
+    let db = PulseDB::open(&path, config.clone()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    db.search_similar(cid, &near_axis(10, 0.0), 5).unwrap();
+    db.search_similar(cid, &near_axis(10, 0.1), 5).unwrap();
+    assert_eq!(db.list_knowledge_gaps(cid).unwrap()[0].occurrences, 2);
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, config).unwrap();
+    let gaps = db.list_knowledge_gaps(cid).unwrap();
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].occurrences, 2);
+}
+
+#[test]
+fn test_search_text_records_query_text() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        knowledge_gaps: Some(KnowledgeGapConfig::default()),
+        ..Config::with_hashing_embeddings(EmbeddingDimension::D384)
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+
+    assert!(db
+        .search_text(cid, "who owns the deploy key?", 5)
+        .unwrap()
+        .is_empty());
+    db.search_text(cid, "who owns the deploy key?", 5).unwrap();
+
+    let gaps = db.list_knowledge_gaps(cid).unwrap();
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].occurrences, 2);
+    assert_eq!(gaps[0].queries, vec!["who owns the deploy key?"]);
+    assert_eq!(gaps[0].best_score, None);
+    assert!(gaps[0].first_seen <= gaps[0].last_seen);
+}
+
+#[test]
+fn test_gaps_not_recorded_by_default() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+
+    db.search_similar(cid, &near_axis(0, 0.0), 5).unwrap();
+    assert!(db.list_knowledge_gaps(cid).unwrap().is_empty());
+}
+
+// ============================================================================
+// Clearing
+// ============================================================================
+
+#[test]
+fn test_clear_and_cascade() {
+    let (db, cid, _dir) = open_db_with_collective();
+    db.search_similar(cid, &near_axis(0, 0.0), 5).unwrap();
+    db.search_similar(cid, &near_axis(0, 0.0), 5).unwrap();
+
+    assert_eq!(db.clear_knowledge_gaps(cid).unwrap(), 2);
+    assert!(db.list_knowledge_gaps(cid).unwrap().is_empty());
+
+    db.search_similar(cid, &near_axis(0, 0.0), 5).unwrap();
+    db.delete_collective(cid).unwrap();
+    assert!(db.list_knowledge_gaps(cid).unwrap_err().is_not_found());
+    assert!(db
+        .clear_knowledge_gaps(CollectiveId::new())
+        .unwrap_err()
+        .is_not_found());
+}
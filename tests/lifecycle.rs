@@ -6,8 +6,19 @@
 //! - Configuration validation
 //! - Dimension mismatch detection
 //! - Proper resource cleanup on close
-
-use pulsedb::{Config, EmbeddingDimension, PulseDB, PulseDBError, SyncMode, ValidationError};
+//! - Degraded open when one collective fails to load
+//! - Re-insertion of experiences whose vector index insert failed
+//! - Lock contention metrics
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use pulsedb::vector::HnswIndex;
+use pulsedb::{
+    Config, EmbeddingDimension, HnswConfig, NewExperience, PulseDB, PulseDBError, SyncMode,
+    ValidationError, VectorBackend, VectorIndex, VectorIndexKind,
+};
 use tempfile::tempdir;
 
 // ============================================================================
@@ -265,7 +276,7 @@ fn test_close_flushes_data() {
 
     // Reopen and verify metadata was persisted
     let db = PulseDB::open(&path, Config::default()).unwrap();
-    assert_eq!(db.metadata().schema_version, 2);
+    assert_eq!(db.metadata().schema_version, 3);
     db.close().unwrap();
 }
 
@@ -349,3 +360,273 @@ fn test_sync_mode_paranoid() {
     assert!(db.config().sync_mode.is_paranoid());
     db.close().unwrap();
 }
+
+// ============================================================================
+// Degraded Open Tests
+// ============================================================================
+
+#[test]
+fn test_damaged_collective_does_not_fail_open() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let config = Config {
+        embedding_dimension: EmbeddingDimension::Custom(4),
+        ..Default::default()
+    };
+
+    let db = PulseDB::open(&path, config.clone()).unwrap();
+    let healthy = db.create_collective("healthy").unwrap();
+    let damaged = db.create_collective("damaged").unwrap();
+    for cid in [healthy, damaged] {
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: "stored".to_string(),
+            embedding: Some(vec![1.0, 0.0, 0.0, 0.0]),
+            ..Default::default()
+        })
+        .unwrap();
+    }
+    db.set_vector_index_kind(damaged, VectorIndexKind::Ivf)
+        .unwrap();
+    db.close().unwrap();
+
+    // A directory where the IVF data file belongs makes the rebuild fail
+    let ivf = dir
+        .path()
+        .join("test.db.hnsw")
+        .join(format!("{}.ivf", damaged));
+    let _ = std::fs::remove_file(&ivf);
+    std::fs::create_dir_all(&ivf).unwrap();
+
+    let db = PulseDB::open(&path, config).unwrap();
+    let health = db.health().unwrap();
+    assert_eq!(health.collective_count, 2);
+    assert!(health.is_degraded());
+    assert_eq!(health.unavailable.len(), 1);
+    assert_eq!(health.unavailable[0].collective_id, damaged);
+    assert!(health.unavailable[0].reason.contains("IVF"));
+
+    // The healthy collective serves reads and writes
+    let results = db
+        .search_similar(healthy, &[1.0, 0.0, 0.0, 0.0], 5)
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    // The damaged one refuses both
+    let err = db
+        .search_similar(damaged, &[1.0, 0.0, 0.0, 0.0], 5)
+        .unwrap_err();
+    assert!(err.is_unavailable(), "got {:?}", err);
+    let err = db
+        .record_experience(NewExperience {
+            collective_id: damaged,
+            content: "rejected".to_string(),
+            embedding: Some(vec![0.0, 1.0, 0.0, 0.0]),
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert!(err.is_unavailable(), "got {:?}", err);
+
+    // Deleting the damaged collective restores full health
+    db.delete_collective(damaged).unwrap();
+    assert!(!db.health().unwrap().is_degraded());
+    db.close().unwrap();
+}
+
+/// HNSW index whose inserts fail while `failing` is set.
+struct FlakyIndex {
+    inner: HnswIndex,
+    failing: Arc<AtomicBool>,
+}
+
+impl FlakyIndex {
+    fn check(&self) -> pulsedb::Result<()> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(PulseDBError::vector("injected insert failure"));
+        }
+        Ok(())
+    }
+}
+
+impl VectorIndex for FlakyIndex {
+    fn insert(&self, id: usize, embedding: &[f32]) -> pulsedb::Result<()> {
+        self.check()?;
+        self.inner.insert(id, embedding)
+    }
+
+    fn insert_batch(&self, items: &[(&Vec<f32>, usize)]) -> pulsedb::Result<()> {
+        self.check()?;
+        self.inner.insert_batch(items)
+    }
+
+    fn search(&self, query: &[f32], k: usize, ef: usize) -> pulsedb::Result<Vec<(usize, f32)>> {
+        self.inner.search(query, k, ef)
+    }
+
+    fn search_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: usize,
+        filter: &(dyn Fn(&usize) -> bool + Sync),
+    ) -> pulsedb::Result<Vec<(usize, f32)>> {
+        self.inner.search_filtered(query, k, ef_search, filter)
+    }
+
+    fn delete(&self, id: usize) -> pulsedb::Result<()> {
+        self.inner.delete(id)
+    }
+
+    fn is_deleted(&self, id: usize) -> bool {
+        self.inner.is_deleted(id)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn save(&self, dir: &Path, name: &str) -> pulsedb::Result<()> {
+        self.inner.save(dir, name)
+    }
+}
+
+#[test]
+fn test_failed_index_insert_is_queued_for_reinsertion() {
+    let dir = tempdir().unwrap();
+    let failing = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&failing);
+    let config = Config {
+        embedding_dimension: EmbeddingDimension::Custom(4),
+        vector_backend: Some(VectorBackend::new(move |spec| {
+            Ok(Box::new(FlakyIndex {
+                inner: HnswIndex::new(spec.dimension, &HnswConfig::default()),
+                failing: Arc::clone(&flag),
+            }) as Box<dyn VectorIndex>)
+        })),
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("flaky").unwrap();
+    let record = |content: &str, embedding: Vec<f32>| {
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: content.to_string(),
+            embedding: Some(embedding),
+            ..Default::default()
+        })
+    };
+
+    // The insert fails after the redb commit: the write still succeeds
+    failing.store(true, Ordering::SeqCst);
+    let id = record("stored but unindexed", vec![1.0, 0.0, 0.0, 0.0]).unwrap();
+    assert!(db.get_experience(id).unwrap().is_some());
+    assert!(db
+        .search_similar(cid, &[1.0, 0.0, 0.0, 0.0], 5)
+        .unwrap()
+        .is_empty());
+    let health = db.health().unwrap();
+    assert!(health.is_degraded());
+    assert_eq!(health.unindexed_experiences, 1);
+
+    // The next write re-inserts it
+    failing.store(false, Ordering::SeqCst);
+    record("indexed", vec![0.0, 1.0, 0.0, 0.0]).unwrap();
+    let health = db.health().unwrap();
+    assert!(!health.is_degraded());
+    assert_eq!(health.unindexed_experiences, 0);
+    let results = db.search_similar(cid, &[1.0, 0.0, 0.0, 0.0], 1).unwrap();
+    assert_eq!(results[0].experience.id, id);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_failed_index_insert_is_reinserted_by_a_search() {
+    let dir = tempdir().unwrap();
+    let failing = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&failing);
+    let config = Config {
+        embedding_dimension: EmbeddingDimension::Custom(4),
+        vector_backend: Some(VectorBackend::new(move |spec| {
+            Ok(Box::new(FlakyIndex {
+                inner: HnswIndex::new(spec.dimension, &HnswConfig::default()),
+                failing: Arc::clone(&flag),
+            }) as Box<dyn VectorIndex>)
+        })),
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("flaky").unwrap();
+
+    failing.store(true, Ordering::SeqCst);
+    let id = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "stored but unindexed".to_string(),
+            embedding: Some(vec![1.0, 0.0, 0.0, 0.0]),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(db.health().unwrap().unindexed_experiences, 1);
+
+    // No further write: the search itself re-inserts it and finds it
+    failing.store(false, Ordering::SeqCst);
+    let results = db.search_similar(cid, &[1.0, 0.0, 0.0, 0.0], 1).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].experience.id, id);
+    assert_eq!(db.health().unwrap().unindexed_experiences, 0);
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Metrics Tests
+// ============================================================================
+
+#[test]
+fn test_metrics_count_lock_acquisitions() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        embedding_dimension: EmbeddingDimension::Custom(4),
+        ..Default::default()
+    };
+    let db = Arc::new(PulseDB::open(dir.path().join("test.db"), config).unwrap());
+    let cid = db.create_collective("metrics").unwrap();
+
+    let writers: Vec<_> = (0..4)
+        .map(|i| {
+            let db = Arc::clone(&db);
+            std::thread::spawn(move || {
+                for j in 0..5 {
+                    db.record_experience(NewExperience {
+                        collective_id: cid,
+                        content: format!("writer {} item {}", i, j),
+                        embedding: Some(vec![1.0, i as f32, j as f32, 0.0]),
+                        ..Default::default()
+                    })
+                    .unwrap();
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        writer.join().unwrap();
+    }
+    db.search_similar(cid, &[1.0, 0.0, 0.0, 0.0], 5).unwrap();
+
+    let metrics = db.metrics().unwrap();
+    assert!(metrics.write_transactions.acquisitions >= 20);
+    assert!(metrics.vectors_lock.acquisitions > 0);
+    assert_eq!(metrics.indexes.len(), 1);
+    assert_eq!(metrics.indexes[0].collective_id, cid);
+    assert_eq!(metrics.index_locks, metrics.indexes[0].waits);
+    assert!(metrics.index_locks.acquisitions > 0);
+    for waits in [
+        &metrics.vectors_lock,
+        &metrics.index_locks,
+        &metrics.write_transactions,
+    ] {
+        assert!(waits.contended <= waits.acquisitions);
+        assert_eq!(waits.histogram.iter().sum::<u64>(), waits.contended);
+    }
+
+    Arc::try_unwrap(db).ok().unwrap().close().unwrap();
+}
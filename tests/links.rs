@@ -0,0 +1,258 @@
+//! Integration tests for user and task links on experiences.
+//!
+//! Tests the full stack: PulseDB facade -> validation -> StorageEngine -> redb.
+//! Covers linking experiences to users and tasks, per-agent task lists,
+//! cleanup on delete, persistence, and validation.
+
+use pulsedb::{
+    AgentId, CollectiveId, Config, EpisodeId, ExperienceId, InsightType, NewDerivedInsight,
+    NewExperience, PulseDB, TaskId, UserId,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record an experience with the given agent, task, and user.
+fn record(
+    db: &PulseDB,
+    cid: CollectiveId,
+    agent: &str,
+    task: Option<&str>,
+    user: Option<&str>,
+) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: "linked".to_string(),
+        embedding: Some(vec![0.1; 384]),
+        source_agent: AgentId::new(agent),
+        source_task: task.map(TaskId::new),
+        user_id: user.map(UserId::new),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: IDs of a list of experiences.
+fn ids(experiences: Vec<pulsedb::Experience>) -> Vec<ExperienceId> {
+    experiences.into_iter().map(|e| e.id).collect()
+}
+
+// ============================================================================
+// Users
+// ============================================================================
+
+#[test]
+fn test_experiences_for_user() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let other = db.create_collective("other").unwrap();
+    let first = record(&db, cid, "bot", None, Some("alice"));
+    let second = record(&db, other, "bot", None, Some("alice"));
+    record(&db, cid, "bot", None, Some("bob"));
+    record(&db, cid, "bot", None, None);
+
+    let alice = UserId::new("alice");
+    assert_eq!(
+        ids(db.experiences_for_user(&alice).unwrap()),
+        vec![first, second]
+    );
+    assert_eq!(
+        db.get_experience(first).unwrap().unwrap().user_id,
+        Some(alice.clone())
+    );
+    assert!(db
+        .experiences_for_user(&UserId::new("nobody"))
+        .unwrap()
+        .is_empty());
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Tasks
+// ============================================================================
+
+#[test]
+fn test_experiences_for_task_and_tasks_touched_by() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let a = record(&db, cid, "planner", Some("task-b"), None);
+    let b = record(&db, cid, "planner", Some("task-a"), None);
+    record(&db, cid, "planner", Some("task-a"), None);
+    let c = record(&db, cid, "coder", Some("task-b"), None);
+
+    assert_eq!(
+        db.tasks_touched_by(&AgentId::new("planner")).unwrap(),
+        vec![TaskId::new("task-a"), TaskId::new("task-b")]
+    );
+    assert_eq!(
+        db.tasks_touched_by(&AgentId::new("coder")).unwrap(),
+        vec![TaskId::new("task-b")]
+    );
+    assert_eq!(
+        ids(db.experiences_for_task(&TaskId::new("task-b")).unwrap()),
+        vec![a, c]
+    );
+    assert_eq!(
+        db.experiences_for_task(&TaskId::new("task-a"))
+            .unwrap()
+            .first()
+            .map(|e| e.id),
+        Some(b)
+    );
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Episodes
+// ============================================================================
+
+#[test]
+fn test_get_episode_orders_steps_and_attaches_summary() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let first = record(&db, cid, "planner", Some("episode-1"), None);
+    record(&db, cid, "planner", Some("episode-2"), None);
+    let second = record(&db, cid, "coder", Some("episode-1"), None);
+
+    let episode_id = EpisodeId::new("episode-1");
+    let episode = db.get_episode(&episode_id).unwrap();
+    assert_eq!(episode.id, episode_id);
+    assert_eq!(ids(episode.experiences), vec![first, second]);
+    assert!(episode.summary.is_none());
+
+    let summary = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "Planner scoped, coder implemented".to_string(),
+            embedding: Some(vec![0.1; 384]),
+            source_experience_ids: vec![first, second],
+            insight_type: InsightType::Synthesis,
+            confidence: 0.9,
+            domain: vec![],
+        })
+        .unwrap();
+    db.set_episode_summary(&episode_id, Some(summary)).unwrap();
+    assert_eq!(
+        db.get_episode(&episode_id).unwrap().summary.map(|i| i.id),
+        Some(summary)
+    );
+
+    // A deleted summary insight reads as no summary
+    db.delete_insight(summary).unwrap();
+    assert!(db.get_episode(&episode_id).unwrap().summary.is_none());
+
+    // Unknown episodes are empty, unknown insights are rejected
+    assert!(db
+        .get_episode(&EpisodeId::new("never-ran"))
+        .unwrap()
+        .experiences
+        .is_empty());
+    let err = db
+        .set_episode_summary(&episode_id, Some(summary))
+        .unwrap_err();
+    assert!(err.is_not_found());
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Cascade + Persistence
+// ============================================================================
+
+#[test]
+fn test_links_removed_on_delete() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let only = record(&db, cid, "planner", Some("task-x"), Some("alice"));
+    let shared = record(&db, cid, "planner", Some("task-y"), None);
+    record(&db, cid, "planner", Some("task-y"), None);
+
+    db.delete_experience(only).unwrap();
+    db.delete_experience(shared).unwrap();
+
+    assert!(db
+        .experiences_for_user(&UserId::new("alice"))
+        .unwrap()
+        .is_empty());
+    assert!(db
+        .experiences_for_task(&TaskId::new("task-x"))
+        .unwrap()
+        .is_empty());
+    // task-y is still touched by the remaining experience
+    assert_eq!(
+        db.tasks_touched_by(&AgentId::new("planner")).unwrap(),
+        vec![TaskId::new("task-y")]
+    );
+
+    // Deleting the collective clears the rest
+    db.delete_collective(cid).unwrap();
+    assert!(db
+        .tasks_touched_by(&AgentId::new("planner"))
+        .unwrap()
+        .is_empty());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_links_persist_across_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let id = {
+        let db = PulseDB::open(&path, Config::default()).unwrap();
+        let cid = db.create_collective("c").unwrap();
+        let id = record(&db, cid, "planner", Some("task-1"), Some("alice"));
+        db.close().unwrap();
+        id
+    };
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert_eq!(
+        ids(db.experiences_for_user(&UserId::new("alice")).unwrap()),
+        vec![id]
+    );
+    assert_eq!(
+        db.tasks_touched_by(&AgentId::new("planner")).unwrap(),
+        vec![TaskId::new("task-1")]
+    );
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn test_link_validation() {
+    let (db, cid, _dir) = open_db_with_collective();
+
+    let err = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "x".to_string(),
+            embedding: Some(vec![0.1; 384]),
+            user_id: Some(UserId::new("")),
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    assert!(db
+        .experiences_for_user(&UserId::new(""))
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .experiences_for_task(&TaskId::new(""))
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .tasks_touched_by(&AgentId::new(""))
+        .unwrap_err()
+        .is_validation());
+
+    db.close().unwrap();
+}
@@ -0,0 +1,183 @@
+//! Integration tests for named locks and leases.
+//!
+//! Tests the full stack: PulseDB facade -> validation -> StorageEngine -> redb.
+//! Covers acquire/release, contention, renewal, expiry takeover, fencing
+//! token ordering, concurrent acquisition, and cascade deletes.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use pulsedb::{CollectiveId, Config, PulseDB};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+const TTL: Duration = Duration::from_secs(30);
+
+// ============================================================================
+// Acquire + Release
+// ============================================================================
+
+#[test]
+fn test_acquire_and_release_lock() {
+    let (db, cid, _dir) = open_db_with_collective();
+
+    let lease = db.acquire_lock(cid, "repo:main", "agent-1", TTL).unwrap();
+    assert_eq!(lease.name, "repo:main");
+    assert_eq!(lease.owner, "agent-1");
+    assert!(lease.expires_at > lease.acquired_at);
+
+    let held = db.get_lock(cid, "repo:main").unwrap().unwrap();
+    assert_eq!(held, lease);
+
+    assert!(db
+        .release_lock(cid, "repo:main", lease.fencing_token)
+        .unwrap());
+    assert!(db.get_lock(cid, "repo:main").unwrap().is_none());
+    // Second release is a no-op
+    assert!(!db
+        .release_lock(cid, "repo:main", lease.fencing_token)
+        .unwrap());
+}
+
+#[test]
+fn test_lock_contention_returns_busy() {
+    let (db, cid, _dir) = open_db_with_collective();
+
+    db.acquire_lock(cid, "repo:main", "agent-1", TTL).unwrap();
+    let err = db
+        .acquire_lock(cid, "repo:main", "agent-2", TTL)
+        .unwrap_err();
+    assert!(err.is_busy());
+    assert!(err.to_string().contains("agent-1"));
+
+    // Different names and collectives are independent
+    db.acquire_lock(cid, "repo:dev", "agent-2", TTL).unwrap();
+    let other = db.create_collective("other").unwrap();
+    db.acquire_lock(other, "repo:main", "agent-2", TTL).unwrap();
+}
+
+#[test]
+fn test_reacquire_renews_with_same_token() {
+    let (db, cid, _dir) = open_db_with_collective();
+
+    let first = db
+        .acquire_lock(cid, "repo:main", "agent-1", Duration::from_secs(1))
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(5));
+    let renewed = db.acquire_lock(cid, "repo:main", "agent-1", TTL).unwrap();
+
+    assert_eq!(renewed.fencing_token, first.fencing_token);
+    assert_eq!(renewed.acquired_at, first.acquired_at);
+    assert!(renewed.expires_at > first.expires_at);
+}
+
+// ============================================================================
+// Expiry and Fencing
+// ============================================================================
+
+#[test]
+fn test_expired_lease_taken_over_with_higher_token() {
+    let (db, cid, _dir) = open_db_with_collective();
+
+    let stale = db
+        .acquire_lock(cid, "deploy", "agent-1", Duration::from_millis(20))
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(40));
+    assert!(db.get_lock(cid, "deploy").unwrap().is_none());
+
+    let fresh = db.acquire_lock(cid, "deploy", "agent-2", TTL).unwrap();
+    assert!(fresh.fencing_token > stale.fencing_token);
+
+    // The stale holder can no longer release the new lease
+    assert!(!db.release_lock(cid, "deploy", stale.fencing_token).unwrap());
+    assert_eq!(
+        db.get_lock(cid, "deploy").unwrap().unwrap().owner,
+        "agent-2"
+    );
+}
+
+#[test]
+fn test_fencing_tokens_increase_across_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("test").unwrap();
+    let first = db.acquire_lock(cid, "a", "agent-1", TTL).unwrap();
+    db.release_lock(cid, "a", first.fencing_token).unwrap();
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let second = db.acquire_lock(cid, "a", "agent-1", TTL).unwrap();
+    assert!(second.fencing_token > first.fencing_token);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_concurrent_acquire_has_single_winner() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let db = Arc::new(db);
+
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let db = Arc::clone(&db);
+            std::thread::spawn(move || {
+                db.acquire_lock(cid, "shared", &format!("agent-{}", i), TTL)
+                    .is_ok()
+            })
+        })
+        .collect();
+
+    let winners = handles
+        .into_iter()
+        .map(|h| h.join().unwrap())
+        .filter(|won| *won)
+        .count();
+    assert_eq!(winners, 1);
+}
+
+// ============================================================================
+// Validation and Cascade
+// ============================================================================
+
+#[test]
+fn test_lock_validation_errors() {
+    let (db, cid, _dir) = open_db_with_collective();
+
+    assert!(db
+        .acquire_lock(cid, "", "agent-1", TTL)
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .acquire_lock(cid, "repo", "", TTL)
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .acquire_lock(cid, "repo", "agent-1", Duration::ZERO)
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .acquire_lock(CollectiveId::new(), "repo", "agent-1", TTL)
+        .unwrap_err()
+        .is_not_found());
+}
+
+#[test]
+fn test_delete_collective_removes_locks() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let other = db.create_collective("other").unwrap();
+
+    db.acquire_lock(cid, "repo", "agent-1", TTL).unwrap();
+    db.acquire_lock(other, "repo", "agent-1", TTL).unwrap();
+
+    db.delete_collective(cid).unwrap();
+    assert!(db.get_lock(cid, "repo").unwrap().is_none());
+    assert!(db.get_lock(other, "repo").unwrap().is_some());
+}
@@ -0,0 +1,347 @@
+//! Integration tests for planned maintenance.
+//!
+//! Tests the full stack: PulseDB facade -> plan -> apply -> redb.
+//! Covers dry-run planning, applying an approved plan, skipping items that
+//! changed after planning, and policy validation.
+
+use std::time::Duration;
+
+use pulsedb::{
+    ActivityConfig, CollectiveId, Config, ExperienceId, InsightType, MaintenancePolicy,
+    NewActivity, NewDerivedInsight, NewExperience, PulseDB,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB with a short activity stale threshold and a collective.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        activity: ActivityConfig {
+            stale_threshold: Duration::from_millis(20),
+        },
+        ..Config::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record an experience with the given content and importance.
+fn record(db: &PulseDB, cid: CollectiveId, content: &str, importance: f32) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.to_string(),
+        importance,
+        embedding: Some(vec![0.1; 384]),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: register an agent in a collective.
+fn register(db: &PulseDB, cid: CollectiveId, agent_id: &str) {
+    db.register_activity(NewActivity {
+        agent_id: agent_id.to_string(),
+        collective_id: cid,
+        current_task: None,
+        context_summary: None,
+        capabilities: vec![],
+    })
+    .unwrap();
+}
+
+// ============================================================================
+// Planning
+// ============================================================================
+
+#[test]
+fn test_plan_is_a_dry_run() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let original = record(&db, cid, "use exponential backoff", 0.5);
+    let copy = record(&db, cid, "use exponential backoff ", 0.5);
+    let unique = record(&db, cid, "pin the toolchain", 0.9);
+    register(&db, cid, "agent-1");
+    db.acquire_lock(cid, "deploy", "agent-1", Duration::from_millis(10))
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(40));
+
+    let plan = db
+        .plan_maintenance(
+            cid,
+            MaintenancePolicy {
+                merge_duplicates: true,
+                archive_older_than: Some(Duration::ZERO),
+                archive_below_importance: Some(0.8),
+                ..MaintenancePolicy::default()
+            },
+        )
+        .unwrap();
+
+    assert_eq!(plan.merge.len(), 1);
+    assert_eq!(plan.merge[0].keep, original);
+    assert_eq!(plan.merge[0].duplicates, vec![copy]);
+    // The duplicate is merged, not archived; the important one is kept
+    assert_eq!(plan.archive, vec![original]);
+    assert!(!plan.archive.contains(&unique));
+    assert_eq!(plan.expire_activities, vec!["agent-1".to_string()]);
+    assert_eq!(plan.expire_locks.len(), 1);
+    assert!(plan.bytes_reclaimed > 0);
+
+    // Nothing changed yet
+    assert!(db.get_experience(copy).unwrap().is_some());
+    assert!(!db.get_experience(original).unwrap().unwrap().archived);
+    assert!(db
+        .storage_for_test()
+        .get_activity("agent-1", cid)
+        .unwrap()
+        .is_some());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_default_policy_plans_nothing_lossy() {
+    let (db, cid, _dir) = open_db_with_collective();
+    record(&db, cid, "same", 0.1);
+    record(&db, cid, "same", 0.1);
+
+    let plan = db
+        .plan_maintenance(cid, MaintenancePolicy::default())
+        .unwrap();
+    assert!(plan.is_empty());
+    assert_eq!(plan.bytes_reclaimed, 0);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_plan_and_run_repair_insight_sources() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        strict_insight_sources: false,
+        ..Config::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test").unwrap();
+    let source = record(&db, cid, "source", 0.5);
+    let insight = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "insight".to_string(),
+            embedding: Some(vec![0.1; 384]),
+            source_experience_ids: vec![source, ExperienceId::new()],
+            insight_type: InsightType::Pattern,
+            confidence: 0.9,
+            domain: vec![],
+        })
+        .unwrap();
+
+    let plan = db
+        .plan_maintenance(cid, MaintenancePolicy::default())
+        .unwrap();
+    assert_eq!(plan.repair_insights, vec![insight]);
+    assert_eq!(
+        db.get_insight(insight)
+            .unwrap()
+            .unwrap()
+            .source_experience_ids
+            .len(),
+        2
+    );
+
+    let report = db.run_maintenance(&plan).unwrap();
+    assert_eq!(report.insights_repaired, 1);
+    assert_eq!(
+        db.get_insight(insight)
+            .unwrap()
+            .unwrap()
+            .source_experience_ids,
+        vec![source]
+    );
+    db.close().unwrap();
+}
+
+#[test]
+fn test_run_repairs_only_planned_insights() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        strict_insight_sources: false,
+        ..Config::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test").unwrap();
+    let source = record(&db, cid, "source", 0.5);
+    let store = |content: &str| {
+        db.store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: content.to_string(),
+            embedding: Some(vec![0.1; 384]),
+            source_experience_ids: vec![source, ExperienceId::new()],
+            insight_type: InsightType::Pattern,
+            confidence: 0.9,
+            domain: vec![],
+        })
+        .unwrap()
+    };
+    let planned = store("planned");
+    let plan = db
+        .plan_maintenance(cid, MaintenancePolicy::default())
+        .unwrap();
+    assert_eq!(plan.repair_insights, vec![planned]);
+
+    // Stored after planning: not part of the reviewed plan
+    let unplanned = store("unplanned");
+    let report = db.run_maintenance(&plan).unwrap();
+    assert_eq!(report.insights_repaired, 1);
+    let sources = |id| db.get_insight(id).unwrap().unwrap().source_experience_ids;
+    assert_eq!(sources(planned), vec![source]);
+    assert_eq!(sources(unplanned).len(), 2);
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Applying
+// ============================================================================
+
+#[test]
+fn test_run_applies_plan() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let original = record(&db, cid, "duplicate", 0.5);
+    let copy = record(&db, cid, "duplicate", 0.5);
+    register(&db, cid, "agent-1");
+    std::thread::sleep(Duration::from_millis(40));
+
+    let plan = db
+        .plan_maintenance(
+            cid,
+            MaintenancePolicy {
+                merge_duplicates: true,
+                ..MaintenancePolicy::default()
+            },
+        )
+        .unwrap();
+    let report = db.run_maintenance(&plan).unwrap();
+
+    assert_eq!(report.experiences_merged, 1);
+    assert_eq!(report.activities_expired, 1);
+    assert!(report.bytes_reclaimed > 0);
+    assert!(db.get_experience(copy).unwrap().is_none());
+    assert!(db.get_experience(original).unwrap().is_some());
+    assert!(db
+        .storage_for_test()
+        .get_activity("agent-1", cid)
+        .unwrap()
+        .is_none());
+
+    // Re-running the same plan is a no-op
+    assert_eq!(db.run_maintenance(&plan).unwrap().experiences_merged, 0);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_run_skips_items_changed_since_planning() {
+    let (db, cid, _dir) = open_db_with_collective();
+    register(&db, cid, "agent-1");
+    db.acquire_lock(cid, "deploy", "agent-1", Duration::from_millis(10))
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(40));
+
+    let plan = db
+        .plan_maintenance(cid, MaintenancePolicy::default())
+        .unwrap();
+    assert_eq!(plan.expire_activities.len(), 1);
+    assert_eq!(plan.expire_locks.len(), 1);
+
+    // The agent comes back and someone takes the lock before approval
+    db.update_heartbeat("agent-1", cid).unwrap();
+    let fresh = db
+        .acquire_lock(cid, "deploy", "agent-2", Duration::from_secs(30))
+        .unwrap();
+
+    let report = db.run_maintenance(&plan).unwrap();
+    assert_eq!(report.activities_expired, 0);
+    assert_eq!(report.locks_expired, 0);
+    assert!(db
+        .storage_for_test()
+        .get_activity("agent-1", cid)
+        .unwrap()
+        .is_some());
+    assert_eq!(db.get_lock(cid, "deploy").unwrap().unwrap(), fresh);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_run_skips_merge_when_kept_experience_is_gone() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let original = record(&db, cid, "duplicate", 0.5);
+    let copy = record(&db, cid, "duplicate", 0.5);
+    let plan = db
+        .plan_maintenance(
+            cid,
+            MaintenancePolicy {
+                merge_duplicates: true,
+                ..MaintenancePolicy::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(plan.merge[0].keep, original);
+
+    // The kept copy is deleted before approval
+    db.delete_experience(original).unwrap();
+    let report = db.run_maintenance(&plan).unwrap();
+    assert_eq!(report.experiences_merged, 0);
+    assert!(db.get_experience(copy).unwrap().is_some());
+
+    // An archived keeper doesn't stand for its duplicates either
+    let again = record(&db, cid, "duplicate", 0.5);
+    let plan = db
+        .plan_maintenance(
+            cid,
+            MaintenancePolicy {
+                merge_duplicates: true,
+                ..MaintenancePolicy::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(plan.merge[0].duplicates, vec![again]);
+    db.archive_experience(copy).unwrap();
+    assert_eq!(db.run_maintenance(&plan).unwrap().experiences_merged, 0);
+    assert!(db.get_experience(again).unwrap().is_some());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_run_respects_freeze() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let plan = db
+        .plan_maintenance(cid, MaintenancePolicy::default())
+        .unwrap();
+
+    db.freeze_collective(cid).unwrap();
+    assert!(db.run_maintenance(&plan).unwrap_err().is_busy());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn test_plan_validation_errors() {
+    let (db, cid, _dir) = open_db_with_collective();
+
+    let err = db
+        .plan_maintenance(
+            cid,
+            MaintenancePolicy {
+                archive_below_importance: Some(-0.1),
+                ..MaintenancePolicy::default()
+            },
+        )
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    assert!(db
+        .plan_maintenance(CollectiveId::new(), MaintenancePolicy::default())
+        .unwrap_err()
+        .is_not_found());
+    db.close().unwrap();
+}
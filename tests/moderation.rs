@@ -0,0 +1,144 @@
+//! Integration tests for the experience review queue.
+//!
+//! Tests the full stack: PulseDB facade -> StorageEngine -> redb.
+//! Covers policy persistence, hiding pending experiences from search,
+//! approval, rejection, and the guard against unarchiving around review.
+
+use pulsedb::{
+    AgentId, CollectiveId, Config, ExperienceId, ModerationPolicy, NewExperience, PulseDB,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record a minimal experience from an agent.
+fn record(db: &PulseDB, cid: CollectiveId, agent: &str) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: format!("learned by {}", agent),
+        embedding: Some(vec![0.1; 384]),
+        source_agent: AgentId::new(agent),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: IDs returned by a similarity search.
+fn search_ids(db: &PulseDB, cid: CollectiveId) -> Vec<ExperienceId> {
+    db.search_similar(cid, &[0.1; 384], 10)
+        .unwrap()
+        .into_iter()
+        .map(|r| r.experience.id)
+        .collect()
+}
+
+// ============================================================================
+// Pending Experiences
+// ============================================================================
+
+#[test]
+fn test_reviewed_agent_experience_is_hidden_until_approved() {
+    let (db, cid, _dir) = open_db_with_collective();
+    db.set_moderation_policy(
+        cid,
+        ModerationPolicy::ReviewAgents(vec![AgentId::new("intern")]),
+    )
+    .unwrap();
+
+    let trusted = record(&db, cid, "senior");
+    let pending = record(&db, cid, "intern");
+
+    assert!(!db.is_experience_pending(trusted).unwrap());
+    assert!(db.is_experience_pending(pending).unwrap());
+    assert_eq!(search_ids(&db, cid), vec![trusted]);
+    let recent = db.get_recent_experiences(cid, 10).unwrap();
+    assert_eq!(recent.len(), 1);
+
+    let queue = db.list_pending_experiences(cid).unwrap();
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue[0].id, pending);
+
+    db.approve_experience(pending).unwrap();
+    assert!(!db.is_experience_pending(pending).unwrap());
+    assert!(db.list_pending_experiences(cid).unwrap().is_empty());
+    assert_eq!(search_ids(&db, cid).len(), 2);
+
+    // Approving twice is an error
+    assert!(db.approve_experience(pending).unwrap_err().is_validation());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_reject_deletes_pending_experience() {
+    let (db, cid, _dir) = open_db_with_collective();
+    db.set_moderation_policy(cid, ModerationPolicy::ReviewAllExcept(vec![]))
+        .unwrap();
+    let pending = record(&db, cid, "newcomer");
+
+    db.reject_experience(pending).unwrap();
+    assert!(db.get_experience(pending).unwrap().is_none());
+    assert!(db.list_pending_experiences(cid).unwrap().is_empty());
+
+    // Published experiences can't be rejected
+    db.set_moderation_policy(cid, ModerationPolicy::Open)
+        .unwrap();
+    let published = record(&db, cid, "newcomer");
+    assert!(db.reject_experience(published).unwrap_err().is_validation());
+    assert!(db.get_experience(published).unwrap().is_some());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_unarchive_cannot_bypass_review() {
+    let (db, cid, _dir) = open_db_with_collective();
+    db.set_moderation_policy(cid, ModerationPolicy::ReviewAllExcept(vec![]))
+        .unwrap();
+    let pending = record(&db, cid, "newcomer");
+
+    let err = db.unarchive_experience(pending).unwrap_err();
+    assert!(err.is_validation());
+    assert!(db.is_experience_pending(pending).unwrap());
+    assert!(search_ids(&db, cid).is_empty());
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Persistence
+// ============================================================================
+
+#[test]
+fn test_policy_and_queue_survive_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let policy = ModerationPolicy::ReviewAllExcept(vec![AgentId::new("curator")]);
+
+    let (cid, pending) = {
+        let db = PulseDB::open(&path, Config::default()).unwrap();
+        let cid = db.create_collective("test-collective").unwrap();
+        assert_eq!(
+            db.get_moderation_policy(cid).unwrap(),
+            ModerationPolicy::Open
+        );
+        db.set_moderation_policy(cid, policy.clone()).unwrap();
+        let pending = record(&db, cid, "newcomer");
+        db.close().unwrap();
+        (cid, pending)
+    };
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert_eq!(db.get_moderation_policy(cid).unwrap(), policy);
+    assert!(db.is_experience_pending(pending).unwrap());
+    let curated = record(&db, cid, "curator");
+    assert_eq!(search_ids(&db, cid), vec![curated]);
+
+    db.close().unwrap();
+}
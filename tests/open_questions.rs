@@ -0,0 +1,132 @@
+//! Integration tests for open questions and answer linking.
+//!
+//! Tests the full stack: PulseDB facade -> type index and relations -> redb.
+//! Covers listing unanswered questions, linking answers, reopening when an
+//! answer is deleted, and validation of `Answers` relations.
+
+use pulsedb::{
+    CollectiveId, Config, ExperienceId, ExperienceType, NewExperience, NewExperienceRelation,
+    PulseDB, RelationType,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record an open question.
+fn ask(db: &PulseDB, cid: CollectiveId, question: &str) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: question.into(),
+        experience_type: ExperienceType::OpenQuestion {
+            question: question.into(),
+        },
+        embedding: Some(vec![0.1; 384]),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: record a generic experience.
+fn note(db: &PulseDB, cid: CollectiveId, content: &str) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.into(),
+        embedding: Some(vec![0.2; 384]),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: IDs of the collective's open questions.
+fn open_ids(db: &PulseDB, cid: CollectiveId) -> Vec<ExperienceId> {
+    db.list_open_questions(cid)
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect()
+}
+
+// ============================================================================
+// Listing and Answering
+// ============================================================================
+
+#[test]
+fn test_answered_questions_leave_the_open_list() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let first = ask(&db, cid, "Why is CI slow?");
+    let second = ask(&db, cid, "Who owns the deploy key?");
+    note(&db, cid, "Not a question");
+    assert_eq!(open_ids(&db, cid), vec![first, second]);
+
+    let answer = note(&db, cid, "CI waits on an unreachable mirror");
+    db.answer_question(first, answer).unwrap();
+    assert_eq!(open_ids(&db, cid), vec![second]);
+
+    let answers = db.get_answers(first).unwrap();
+    assert_eq!(answers.len(), 1);
+    assert_eq!(answers[0].id, answer);
+    assert!(db.get_answers(second).unwrap().is_empty());
+}
+
+#[test]
+fn test_deleting_the_answer_reopens_the_question() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let question = ask(&db, cid, "Why is CI slow?");
+    let answer = note(&db, cid, "CI waits on an unreachable mirror");
+    db.answer_question(question, answer).unwrap();
+    assert!(open_ids(&db, cid).is_empty());
+
+    db.delete_experience(answer).unwrap();
+    assert_eq!(open_ids(&db, cid), vec![question]);
+}
+
+#[test]
+fn test_archived_questions_are_not_listed() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let question = ask(&db, cid, "Why is CI slow?");
+    db.archive_experience(question).unwrap();
+    assert!(open_ids(&db, cid).is_empty());
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn test_answers_must_target_a_question() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let statement = note(&db, cid, "A plain note");
+    let answer = note(&db, cid, "An answer");
+    assert!(db
+        .answer_question(statement, answer)
+        .unwrap_err()
+        .is_validation());
+
+    let err = db
+        .store_relation(NewExperienceRelation {
+            source_id: answer,
+            target_id: statement,
+            relation_type: RelationType::Answers,
+            strength: 1.0,
+            metadata: None,
+        })
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    let question = ask(&db, cid, "Why is CI slow?");
+    db.answer_question(question, answer).unwrap();
+    assert!(db
+        .answer_question(question, answer)
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .list_open_questions(CollectiveId::new())
+        .unwrap_err()
+        .is_not_found());
+}
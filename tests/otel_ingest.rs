@@ -0,0 +1,48 @@
+//! Integration tests for OpenTelemetry trace ingestion.
+//!
+//! Feeds OTLP/JSON traces through `PulseDB::ingest_otel()` with the
+//! hashing embedding provider, so no model download is needed.
+
+#![cfg(feature = "otel")]
+
+use pulsedb::otel::OtelMapping;
+use pulsedb::{Config, EmbeddingDimension, ExperienceType, PulseDB};
+use tempfile::tempdir;
+
+const TRACES: &str = r#"{"resourceSpans":[{"resource":{"attributes":[{"key":"service.name","value":{"stringValue":"coder"}}]},"scopeSpans":[{"spans":[{"traceId":"0af7651916cd43dd8448eb211c80319c","spanId":"b7ad6b7169203331","name":"cargo_build","status":{"code":2,"message":"linker not found"}},{"traceId":"0af7651916cd43dd8448eb211c80319c","spanId":"00f067aa0ba902b7","name":"plan","status":{"code":1}}]}]}]}
+{"resourceSpans":[{"scopeSpans":[{"spans":[{"traceId":"4bf92f3577b34da6a3ce929d0e0e4736","spanId":"53995c3f42cd8ad8","name":"reflect","attributes":[{"key":"pulsedb.content","value":{"stringValue":"Install lld before building on CI images"}}]}]}]}]}
+"#;
+
+#[test]
+fn test_ingest_otel_records_failures_and_marked_spans() {
+    let dir = tempdir().unwrap();
+    let config = Config::with_hashing_embeddings(EmbeddingDimension::D384);
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("agents").unwrap();
+
+    let report = db
+        .ingest_otel(cid, TRACES.as_bytes(), &OtelMapping::default())
+        .unwrap();
+    assert_eq!(report.accepted(), 2);
+
+    let ids = report.accepted_ids();
+    let failure = db.get_experience(ids[0]).unwrap().unwrap();
+    assert_eq!(failure.content, "cargo_build failed: linker not found");
+    assert!(matches!(
+        failure.experience_type,
+        ExperienceType::Difficulty { .. }
+    ));
+    assert_eq!(failure.source_agent.as_str(), "coder");
+    let lesson = db.get_experience(ids[1]).unwrap().unwrap();
+    assert_eq!(lesson.content, "Install lld before building on CI images");
+    assert_eq!(lesson.source_agent.as_str(), "otel");
+
+    // Re-ingesting the same traces finds the spans already recorded
+    let again = db
+        .ingest_otel(cid, TRACES.as_bytes(), &OtelMapping::default())
+        .unwrap();
+    assert_eq!(again.deduped(), 2);
+    assert_eq!(again.accepted(), 0);
+
+    db.close().unwrap();
+}
@@ -3,8 +3,8 @@
 //! Tests read-only mode, paginated list methods, and enriched watch events.
 
 use pulsedb::{
-    Config, ExperienceUpdate, InsightType, NewDerivedInsight, NewExperience, NewExperienceRelation,
-    PulseDB, RelationType, WatchEventType,
+    Config, InsightType, NewDerivedInsight, NewExperience, NewExperienceRelation, PulseDB,
+    RelationType, WatchEventType,
 };
 use tempfile::tempdir;
 
@@ -218,7 +218,6 @@ fn test_watch_event_includes_experience_on_create() {
         .unwrap();
 
     // Get the event from the stream
-    use futures::StreamExt;
     let rt = tokio::runtime::Runtime::new().unwrap();
     let event = rt.block_on(async {
         tokio::time::timeout(
@@ -253,7 +252,6 @@ fn test_watch_event_none_on_delete() {
     let stream = db.watch_experiences(cid).unwrap();
     db.delete_experience(exp_id).unwrap();
 
-    use futures::StreamExt;
     let rt = tokio::runtime::Runtime::new().unwrap();
     let event = rt.block_on(async {
         tokio::time::timeout(
@@ -370,6 +370,34 @@ fn test_recent_with_importance_filter() {
     assert_eq!(recent[0].content, "High importance");
 }
 
+#[test]
+fn test_recent_with_time_range_filter() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let ids = record_n_experiences(&db, cid, 4);
+    let stamps: Vec<_> = ids
+        .iter()
+        .map(|id| db.get_experience(*id).unwrap().unwrap().timestamp)
+        .collect();
+
+    // [second, fourth) keeps the middle two, newest first
+    let filter = SearchFilter {
+        since: Some(stamps[1]),
+        until: Some(stamps[3]),
+        ..SearchFilter::default()
+    };
+    let recent = db.get_recent_experiences_filtered(cid, 10, filter).unwrap();
+    let got: Vec<_> = recent.iter().map(|e| e.id).collect();
+    assert_eq!(got, vec![ids[2], ids[1]]);
+
+    // A limit smaller than the range returns the newest matches
+    let filter = SearchFilter {
+        until: Some(stamps[3]),
+        ..SearchFilter::default()
+    };
+    let recent = db.get_recent_experiences_filtered(cid, 1, filter).unwrap();
+    assert_eq!(recent[0].id, ids[2]);
+}
+
 // ============================================================================
 // Collective Isolation
 // ============================================================================
@@ -5,8 +5,8 @@
 //! validation error paths.
 
 use pulsedb::{
-    CollectiveId, Config, NewExperience, NewExperienceRelation, PulseDB, RelationDirection,
-    RelationType,
+    CollectiveId, Config, NewExperience, NewExperienceRelation, Page, PulseDB, RelationDirection,
+    RelationFilter, RelationSort, RelationType,
 };
 use tempfile::tempdir;
 
@@ -563,3 +563,220 @@ fn test_relation_nonexistent_target() {
     assert!(result.is_err());
     assert!(result.unwrap_err().is_not_found());
 }
+
+// ============================================================================
+// Collective-scoped listing
+// ============================================================================
+
+/// Records `n` experiences and chains relations between consecutive ones
+/// with the given (type, strength) pairs. Sleeps between relations so each
+/// gets a distinct `created_at`.
+fn store_relation_chain(
+    db: &PulseDB,
+    cid: CollectiveId,
+    specs: &[(RelationType, f32)],
+) -> Vec<pulsedb::RelationId> {
+    let exps: Vec<_> = (0..=specs.len())
+        .map(|i| {
+            db.record_experience(NewExperience {
+                content: format!("experience {}", i),
+                ..minimal_experience(cid)
+            })
+            .unwrap()
+        })
+        .collect();
+
+    specs
+        .iter()
+        .enumerate()
+        .map(|(i, (relation_type, strength))| {
+            std::thread::sleep(std::time::Duration::from_millis(2));
+            db.store_relation(NewExperienceRelation {
+                source_id: exps[i],
+                target_id: exps[i + 1],
+                relation_type: *relation_type,
+                strength: *strength,
+                metadata: None,
+            })
+            .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_list_relations_filtered_sort_filter_and_page() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let ids = store_relation_chain(
+        &db,
+        cid,
+        &[
+            (RelationType::Supports, 0.3),
+            (RelationType::Contradicts, 0.9),
+            (RelationType::Supports, 0.6),
+            (RelationType::Elaborates, 0.1),
+        ],
+    );
+
+    let list = |filter: RelationFilter, page: Page| -> Vec<pulsedb::RelationId> {
+        db.list_relations_filtered(cid, filter, page)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.id)
+            .collect()
+    };
+
+    assert_eq!(list(RelationFilter::default(), Page::default()), ids);
+
+    let newest = RelationFilter {
+        sort: RelationSort::NewestFirst,
+        ..Default::default()
+    };
+    assert_eq!(
+        list(newest.clone(), Page::default()),
+        ids.iter().rev().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(list(newest, Page::new(1, 2)), vec![ids[2], ids[1]]);
+
+    let strongest = RelationFilter {
+        sort: RelationSort::StrongestFirst,
+        ..Default::default()
+    };
+    assert_eq!(
+        list(strongest, Page::default()),
+        vec![ids[1], ids[2], ids[0], ids[3]]
+    );
+
+    let supports = RelationFilter {
+        relation_types: Some(vec![RelationType::Supports]),
+        ..Default::default()
+    };
+    assert_eq!(list(supports, Page::default()), vec![ids[0], ids[2]]);
+
+    let strong = RelationFilter {
+        min_strength: Some(0.5),
+        ..Default::default()
+    };
+    assert_eq!(list(strong.clone(), Page::new(0, 1)), vec![ids[1]]);
+    assert_eq!(list(strong, Page::new(0, 1).next()), vec![ids[2]]);
+
+    // Legacy pagination shares the index and its creation order
+    let legacy: Vec<_> = db
+        .list_relations(cid, 2, 1)
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    assert_eq!(legacy, vec![ids[1], ids[2]]);
+}
+
+#[test]
+fn test_list_relations_filtered_tracks_deletes_and_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("graph").unwrap();
+    let other = db.create_collective("other").unwrap();
+
+    let ids = store_relation_chain(
+        &db,
+        cid,
+        &[
+            (RelationType::Supports, 0.5),
+            (RelationType::Implies, 0.5),
+            (RelationType::RelatedTo, 0.5),
+        ],
+    );
+    store_relation_chain(&db, other, &[(RelationType::Supports, 0.5)]);
+
+    db.delete_relation(ids[0]).unwrap();
+    // Deleting the shared endpoint cascades the remaining relation it touches
+    let last = db.get_relation(ids[2]).unwrap().unwrap();
+    db.delete_experience(last.source_id).unwrap();
+
+    let listed = db
+        .list_relations_filtered(cid, RelationFilter::default(), Page::default())
+        .unwrap();
+    assert!(listed.is_empty());
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert!(db
+        .list_relations_filtered(cid, RelationFilter::default(), Page::default())
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        db.list_relations_filtered(other, RelationFilter::default(), Page::default())
+            .unwrap()
+            .len(),
+        1
+    );
+
+    db.delete_collective(other).unwrap();
+    let err = db
+        .list_relations_filtered(other, RelationFilter::default(), Page::default())
+        .unwrap_err();
+    assert!(err.is_not_found());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Neighborhood Search
+// ============================================================================
+
+/// Helper: an embedding pointing mostly along axis `i`.
+fn axis_embedding(i: usize) -> Vec<f32> {
+    let mut v = vec![0.01; DIM];
+    v[i] = 1.0;
+    v
+}
+
+#[test]
+fn test_search_within_neighborhood_respects_hops() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let record = |i: usize| {
+        db.record_experience(NewExperience {
+            embedding: Some(axis_embedding(i)),
+            ..minimal_experience(cid)
+        })
+        .unwrap()
+    };
+    let relate = |source_id, target_id| {
+        db.store_relation(NewExperienceRelation {
+            source_id,
+            target_id,
+            relation_type: RelationType::Elaborates,
+            strength: 1.0,
+            metadata: None,
+        })
+        .unwrap();
+    };
+
+    // anchor -> near -> far (far points back at near); outsider unrelated
+    let anchor = record(0);
+    let near = record(1);
+    let far = record(2);
+    let outsider = record(2);
+    relate(anchor, near);
+    relate(far, near);
+
+    let one_hop = db
+        .search_within_neighborhood(anchor, &axis_embedding(2), 10, 1)
+        .unwrap();
+    let ids: Vec<_> = one_hop.iter().map(|r| r.experience.id).collect();
+    assert_eq!(ids, vec![near]);
+
+    let two_hops = db
+        .search_within_neighborhood(anchor, &axis_embedding(2), 10, 2)
+        .unwrap();
+    let ids: Vec<_> = two_hops.iter().map(|r| r.experience.id).collect();
+    assert_eq!(ids, vec![far, near]);
+    assert!(!ids.contains(&outsider));
+    assert!(!ids.contains(&anchor));
+
+    let err = db
+        .search_within_neighborhood(anchor, &axis_embedding(2), 10, 0)
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    db.close().unwrap();
+}
@@ -0,0 +1,187 @@
+//! Integration tests for relation suggestions at record time.
+//!
+//! Tests the full stack: PulseDB facade -> similarity pass on record ->
+//! suggestion queue in redb -> relations on acceptance.
+//! Covers the similarity threshold, type heuristics, accepting and
+//! dismissing, cleanup on delete, and the default of not suggesting.
+
+use pulsedb::{
+    CollectiveId, Config, ExperienceId, ExperienceType, NewExperience, PulseDB, RelationDirection,
+    RelationSuggestionConfig, RelationType, ScoreKind, Severity,
+};
+use tempfile::tempdir;
+
+const DIM: usize = 384;
+
+/// Helper: open DB with suggestions on, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        relation_suggestions: Some(RelationSuggestionConfig::default()),
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: an embedding along `axis`, tilted slightly toward `axis + 1`.
+fn near_axis(axis: usize, tilt: f32) -> Vec<f32> {
+    let mut embedding = vec![0.0; DIM];
+    embedding[axis] = 1.0;
+    embedding[axis + 1] = tilt;
+    embedding
+}
+
+/// Helper: record an experience of the given type and embedding.
+fn record(
+    db: &PulseDB,
+    cid: CollectiveId,
+    experience_type: ExperienceType,
+    embedding: Vec<f32>,
+) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: "content".into(),
+        experience_type,
+        embedding: Some(embedding),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+fn difficulty() -> ExperienceType {
+    ExperienceType::Difficulty {
+        description: "CI is slow".into(),
+        severity: Severity::Medium,
+    }
+}
+
+fn solution() -> ExperienceType {
+    ExperienceType::Solution {
+        problem_ref: None,
+        approach: "cache dependencies".into(),
+        worked: true,
+    }
+}
+
+// ============================================================================
+// Suggesting
+// ============================================================================
+
+#[test]
+fn test_similar_experiences_are_suggested_with_typed_relations() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let problem = record(&db, cid, difficulty(), near_axis(0, 0.0));
+    let unrelated = record(&db, cid, difficulty(), near_axis(10, 0.0));
+    let fix = record(&db, cid, solution(), near_axis(0, 0.1));
+
+    let suggestions = db.list_relation_suggestions(cid).unwrap();
+    assert_eq!(suggestions.len(), 1);
+    let suggestion = &suggestions[0];
+    assert_eq!(suggestion.source_id, fix);
+    assert_eq!(suggestion.target_id, problem);
+    assert_eq!(suggestion.relation_type, RelationType::Elaborates);
+    assert!(suggestion.similarity > 0.99);
+    assert_ne!(suggestion.target_id, unrelated);
+}
+
+#[test]
+fn test_suggestion_threshold_follows_score_kind() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        relation_suggestions: Some(RelationSuggestionConfig {
+            min_similarity: 0.4,
+            ..Default::default()
+        }),
+        score_kind: ScoreKind::NormalizedDot,
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    // Orthogonal: 0.0 as cosine, 0.5 normalized
+    let problem = record(&db, cid, difficulty(), near_axis(0, 0.0));
+    let fix = record(&db, cid, solution(), near_axis(10, 0.0));
+
+    let suggestions = db.list_relation_suggestions(cid).unwrap();
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].source_id, fix);
+    assert_eq!(suggestions[0].target_id, problem);
+    assert!((suggestions[0].similarity - 0.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_suggestions_not_made_by_default() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    record(&db, cid, difficulty(), near_axis(0, 0.0));
+    record(&db, cid, solution(), near_axis(0, 0.1));
+
+    assert!(db.list_relation_suggestions(cid).unwrap().is_empty());
+}
+
+// ============================================================================
+// Confirmation
+// ============================================================================
+
+#[test]
+fn test_accept_and_dismiss() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let problem = record(&db, cid, difficulty(), near_axis(0, 0.0));
+    let fix = record(&db, cid, solution(), near_axis(0, 0.1));
+    let other_fix = record(&db, cid, solution(), near_axis(0, 0.05));
+
+    db.accept_relation_suggestion(fix, problem).unwrap();
+    let related = db
+        .get_related_experiences(fix, RelationDirection::Outgoing)
+        .unwrap();
+    assert_eq!(related.len(), 1);
+    assert_eq!(related[0].0.id, problem);
+    assert_eq!(related[0].1.relation_type, RelationType::Elaborates);
+    assert!(related[0].1.strength > 0.99);
+
+    // The accepted suggestion is gone; accepting again fails
+    assert!(db
+        .accept_relation_suggestion(fix, problem)
+        .unwrap_err()
+        .is_validation());
+
+    let remaining = db.list_relation_suggestions(cid).unwrap();
+    assert!(remaining.iter().all(|s| s.source_id == other_fix));
+    for suggestion in &remaining {
+        assert!(db
+            .dismiss_relation_suggestion(suggestion.source_id, suggestion.target_id)
+            .unwrap());
+    }
+    assert!(db.list_relation_suggestions(cid).unwrap().is_empty());
+    assert!(!db.dismiss_relation_suggestion(other_fix, problem).unwrap());
+}
+
+#[test]
+fn test_deleted_experiences_drop_their_suggestions() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let problem = record(&db, cid, difficulty(), near_axis(0, 0.0));
+    let fix = record(&db, cid, solution(), near_axis(0, 0.1));
+    let other = record(&db, cid, difficulty(), near_axis(20, 0.0));
+    let other_fix = record(&db, cid, solution(), near_axis(20, 0.1));
+    assert_eq!(db.list_relation_suggestions(cid).unwrap().len(), 2);
+
+    // Deleted target
+    db.delete_experience(problem).unwrap();
+    let suggestions = db.list_relation_suggestions(cid).unwrap();
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].target_id, other);
+
+    // Deleted source
+    db.delete_experience(other_fix).unwrap();
+    assert!(db.list_relation_suggestions(cid).unwrap().is_empty());
+    assert!(db
+        .accept_relation_suggestion(other_fix, other)
+        .unwrap_err()
+        .is_not_found());
+    assert!(db
+        .accept_relation_suggestion(fix, problem)
+        .unwrap_err()
+        .is_not_found());
+}
@@ -0,0 +1,140 @@
+//! Integration tests for reranked similarity search.
+//!
+//! Tests the full stack: PulseDB facade -> over-fetching vector search ->
+//! caller-supplied `Reranker`.
+//! Covers the candidate count, reranker ordering and truncation, and error
+//! propagation.
+
+use std::sync::Mutex;
+
+use pulsedb::{
+    CollectiveId, Config, NewExperience, PulseDB, PulseDBError, Reranker, SearchResult,
+    RERANK_OVER_FETCH,
+};
+use tempfile::tempdir;
+
+const DIM: usize = 384;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: an embedding along axis 0, tilted toward axis 1.
+fn tilted(tilt: f32) -> Vec<f32> {
+    let mut embedding = vec![0.0; DIM];
+    embedding[0] = 1.0;
+    embedding[1] = tilt;
+    embedding
+}
+
+/// Reverses the candidates and remembers what it was given.
+#[derive(Default)]
+struct Reverse {
+    seen: Mutex<Vec<(String, usize)>>,
+}
+
+impl Reranker for Reverse {
+    fn rerank(
+        &self,
+        query: &str,
+        mut candidates: Vec<SearchResult>,
+    ) -> pulsedb::Result<Vec<SearchResult>> {
+        self.seen
+            .lock()
+            .unwrap()
+            .push((query.to_string(), candidates.len()));
+        candidates.reverse();
+        Ok(candidates)
+    }
+}
+
+// ============================================================================
+// Reranking
+// ============================================================================
+
+#[test]
+fn test_reranker_reorders_over_fetched_candidates() {
+    let (db, cid, _dir) = open_db_with_collective();
+    for i in 0..20 {
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: format!("tilt {}", i),
+            embedding: Some(tilted(i as f32 * 0.1)),
+            ..Default::default()
+        })
+        .unwrap();
+    }
+
+    let reranker = Reverse::default();
+    let results = db
+        .search_similar_reranked(cid, &tilted(0.0), "flaky test", 3, &reranker)
+        .unwrap();
+
+    let seen = reranker.seen.lock().unwrap();
+    assert_eq!(
+        seen.as_slice(),
+        [("flaky test".to_string(), 3 * RERANK_OVER_FETCH)]
+    );
+    // The least similar of the 12 candidates now comes first
+    let contents: Vec<&str> = results
+        .iter()
+        .map(|r| r.experience.content.as_str())
+        .collect();
+    assert_eq!(contents, ["tilt 11", "tilt 10", "tilt 9"]);
+}
+
+#[test]
+fn test_closure_reranker_may_drop_candidates() {
+    let (db, cid, _dir) = open_db_with_collective();
+    for content in ["keep", "drop", "keep too"] {
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: content.into(),
+            embedding: Some(tilted(0.0)),
+            ..Default::default()
+        })
+        .unwrap();
+    }
+
+    let only_keep = |_query: &str, candidates: Vec<SearchResult>| {
+        Ok(candidates
+            .into_iter()
+            .filter(|r| r.experience.content.starts_with("keep"))
+            .collect())
+    };
+    let results = db
+        .search_similar_reranked(cid, &tilted(0.0), "q", 10, &only_keep)
+        .unwrap();
+    assert_eq!(results.len(), 2);
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[test]
+fn test_reranker_errors_and_validation() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let failing = |_query: &str, _candidates: Vec<SearchResult>| {
+        Err(PulseDBError::internal("cross-encoder unavailable"))
+    };
+    let err = db
+        .search_similar_reranked(cid, &tilted(0.0), "q", 5, &failing)
+        .unwrap_err();
+    assert!(err.to_string().contains("cross-encoder unavailable"));
+
+    let reranker = Reverse::default();
+    assert!(db
+        .search_similar_reranked(cid, &tilted(0.0), "q", 0, &reranker)
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .search_similar_reranked(cid, &[0.1; 8], "q", 5, &reranker)
+        .unwrap_err()
+        .is_validation());
+    assert!(reranker.seen.lock().unwrap().is_empty());
+}
@@ -0,0 +1,139 @@
+//! Integration tests for scoped database handles.
+//!
+//! Tests the full stack: ScopedDb -> PulseDB facade -> redb.
+//! Covers collective confinement and capability checks.
+
+use pulsedb::{
+    Capabilities, CollectiveId, Config, ExperienceId, NewExperience, NewExperienceRelation,
+    PulseDB, RelationType,
+};
+use tempfile::tempdir;
+
+/// Helper: open DB with two collectives.
+fn open_db_with_collectives() -> (PulseDB, CollectiveId, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let mine = db.create_collective("mine").unwrap();
+    let other = db.create_collective("other").unwrap();
+    (db, mine, other, dir)
+}
+
+/// Helper: record a minimal experience.
+fn record(db: &PulseDB, cid: CollectiveId) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: "scoped content".to_string(),
+        embedding: Some(vec![0.1; 384]),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+// ============================================================================
+// Confinement
+// ============================================================================
+
+#[test]
+fn test_scoped_handle_hides_other_collectives() {
+    let (db, mine, other, _dir) = open_db_with_collectives();
+    let own = record(&db, mine);
+    let foreign = record(&db, other);
+    let scoped = db.scoped(mine, Capabilities::all()).unwrap();
+
+    assert!(scoped.get_experience(own).unwrap().is_some());
+    assert!(scoped.get_experience(foreign).unwrap().is_none());
+    let hits = scoped.search_similar(&[0.1; 384], 10).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].experience.id, own);
+
+    // Foreign records can't be touched, and new records land in scope
+    assert!(scoped
+        .delete_experience(foreign)
+        .unwrap_err()
+        .is_not_found());
+    assert!(scoped
+        .reinforce_experience(foreign)
+        .unwrap_err()
+        .is_not_found());
+    let created = scoped
+        .record_experience(NewExperience {
+            collective_id: other,
+            content: "redirected".to_string(),
+            embedding: Some(vec![0.2; 384]),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(
+        db.get_experience(created).unwrap().unwrap().collective_id,
+        mine
+    );
+
+    assert!(db
+        .scoped(CollectiveId::new(), Capabilities::all())
+        .unwrap_err()
+        .is_not_found());
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Capabilities
+// ============================================================================
+
+#[test]
+fn test_read_only_handle_rejects_writes() {
+    let (db, mine, _other, _dir) = open_db_with_collectives();
+    let own = record(&db, mine);
+    let scoped = db.scoped(mine, Capabilities::read_only()).unwrap();
+
+    assert!(scoped.get_recent_experiences(10).unwrap().len() == 1);
+    let err = scoped
+        .record_experience(NewExperience {
+            content: "denied".to_string(),
+            embedding: Some(vec![0.1; 384]),
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert!(err.is_permission_denied());
+    assert!(scoped
+        .archive_experience(own)
+        .unwrap_err()
+        .is_permission_denied());
+    assert!(scoped
+        .delete_experience(own)
+        .unwrap_err()
+        .is_permission_denied());
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_no_delete_handle_writes_but_cannot_delete() {
+    let (db, mine, _other, _dir) = open_db_with_collectives();
+    let first = record(&db, mine);
+    let second = record(&db, mine);
+    let scoped = db.scoped(mine, Capabilities::no_delete()).unwrap();
+
+    let relation = scoped
+        .store_relation(NewExperienceRelation {
+            source_id: first,
+            target_id: second,
+            relation_type: RelationType::Supports,
+            strength: 0.5,
+            metadata: None,
+        })
+        .unwrap();
+    assert_eq!(scoped.reinforce_experience(first).unwrap(), 1);
+
+    assert!(scoped
+        .delete_relation(relation)
+        .unwrap_err()
+        .is_permission_denied());
+    assert!(scoped
+        .delete_experience(first)
+        .unwrap_err()
+        .is_permission_denied());
+    assert!(db.get_experience(first).unwrap().is_some());
+
+    db.close().unwrap();
+}
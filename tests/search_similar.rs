@@ -5,7 +5,8 @@
 //! and collective isolation.
 
 use pulsedb::{
-    CollectiveId, Config, ExperienceType, NewExperience, PulseDB, SearchFilter, Severity, Timestamp,
+    CollectiveId, Config, ExperienceType, NewExperience, PulseDB, ScoreKind, SearchFilter,
+    Severity, Timestamp,
 };
 use tempfile::tempdir;
 
@@ -101,6 +102,52 @@ fn test_search_similar_returns_sorted() {
     );
 }
 
+#[test]
+fn test_results_carry_distance_and_score_kind() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("scores").unwrap();
+    let ids = record_experiences_with_embeddings(&db, cid, &[1, 2, 3]);
+    let query = make_embedding(2);
+
+    let cosine = db.search_similar(cid, &query, 3).unwrap();
+    for r in &cosine {
+        assert_eq!(r.score_kind, ScoreKind::Cosine);
+        assert_eq!(r.similarity, 1.0 - r.distance);
+    }
+    assert!(cosine[0].distance.abs() < 1e-4);
+    db.close().unwrap();
+
+    let config = Config {
+        score_kind: ScoreKind::NormalizedDot,
+        ..Default::default()
+    };
+    let db = PulseDB::open(&path, config).unwrap();
+    let normalized = db.search_similar(cid, &query, 3).unwrap();
+    for (n, c) in normalized.iter().zip(&cosine) {
+        // Same ranking, different scale
+        assert_eq!(n.experience.id, c.experience.id);
+        assert_eq!(n.score_kind, ScoreKind::NormalizedDot);
+        assert_eq!(n.similarity, 1.0 - n.distance / 2.0);
+        assert!((0.0..=1.0).contains(&n.similarity));
+    }
+
+    // Stored neighbor lists use the same scale
+    db.compute_neighbor_graph(cid, 2).unwrap();
+    let neighbors = db.get_experience_neighbors(ids[1]).unwrap().unwrap();
+    assert_eq!(neighbors.len(), 2);
+    for neighbor in &neighbors {
+        let hit = normalized
+            .iter()
+            .find(|r| r.experience.id == neighbor.experience_id)
+            .unwrap();
+        assert!((neighbor.similarity - hit.similarity).abs() < 1e-4);
+    }
+
+    db.close().unwrap();
+}
+
 // ============================================================================
 // Archival Exclusion
 // ============================================================================
@@ -404,6 +451,88 @@ fn test_search_respects_since_filter() {
     assert_eq!(results.len(), 5, "Should find all 5 new experiences");
 }
 
+// ============================================================================
+// Similarity Cutoff
+// ============================================================================
+
+#[test]
+fn test_min_similarity_cuts_before_hydration() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let blend = |x: f32, y: f32| {
+        let mut embedding = vec![0.0; DIM];
+        embedding[0] = x;
+        embedding[1] = y;
+        embedding
+    };
+    for (content, embedding) in [
+        ("exact", blend(1.0, 0.0)),
+        ("close", blend(0.8, 0.6)),
+        ("orthogonal", blend(0.0, 1.0)),
+    ] {
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: content.to_string(),
+            embedding: Some(embedding),
+            ..Default::default()
+        })
+        .unwrap();
+    }
+
+    let output = db
+        .query(cid)
+        .similar_to(blend(1.0, 0.0))
+        .filter(|f| f.min_similarity(0.75))
+        .limit(10)
+        .with_explain()
+        .run()
+        .unwrap();
+    let contents: Vec<_> = output
+        .results
+        .iter()
+        .map(|r| r.experience.content.as_str())
+        .collect();
+    assert_eq!(contents, ["exact", "close"]);
+    let explain = output.explain.unwrap();
+    assert_eq!(explain.below_min_similarity, 1);
+    assert_eq!(explain.filtered_out, 0);
+    // The index ran dry below the over-fetch, so nothing more was asked for
+    assert_eq!(explain.over_fetch, 10);
+
+    // The cutoff falls inside the first `limit` candidates: no over-fetch
+    let output = db
+        .query(cid)
+        .similar_to(blend(1.0, 0.0))
+        .filter(|f| f.min_similarity(0.9))
+        .limit(2)
+        .with_explain()
+        .run()
+        .unwrap();
+    assert_eq!(output.results.len(), 1);
+    let explain = output.explain.unwrap();
+    assert_eq!(explain.over_fetch, 2);
+    assert_eq!(explain.candidates, 2);
+
+    // Every candidate passes: over-fetch as usual
+    let output = db
+        .query(cid)
+        .similar_to(blend(1.0, 0.0))
+        .filter(|f| f.min_similarity(-1.0))
+        .limit(1)
+        .with_explain()
+        .run()
+        .unwrap();
+    assert_eq!(output.results.len(), 1);
+    assert_eq!(output.explain.unwrap().over_fetch, 2);
+
+    let filter = SearchFilter::default().min_similarity(f32::NAN);
+    assert!(db
+        .search_similar_filtered(cid, &blend(1.0, 0.0), 10, filter)
+        .unwrap_err()
+        .is_validation());
+
+    db.close().unwrap();
+}
+
 // ============================================================================
 // Collective Isolation
 // ============================================================================
@@ -502,3 +631,334 @@ fn test_search_collective_not_found() {
     assert!(result.is_err());
     assert!(result.unwrap_err().is_not_found());
 }
+
+#[test]
+fn test_parallel_hydration_matches_serial_order() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let parallel = Config {
+        hydration_threads: 4,
+        ..Default::default()
+    };
+    let db = PulseDB::open(&path, parallel).unwrap();
+    let cid = db.create_collective("wide").unwrap();
+    let seeds: Vec<u64> = (1..=120).collect();
+    let ids = record_experiences_with_embeddings(&db, cid, &seeds);
+    for id in ids.iter().step_by(3) {
+        db.archive_experience(*id).unwrap();
+    }
+
+    let query = make_embedding(7);
+    let hits: Vec<_> = db
+        .search_similar(cid, &query, 100)
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.experience.id, r.experience.archived, r.similarity))
+        .collect();
+    assert_eq!(hits.len(), 80);
+    assert!(hits.iter().all(|&(_, archived, _)| !archived));
+    assert!(hits.windows(2).all(|w| w[0].2 >= w[1].2));
+    db.close().unwrap();
+
+    let serial = Config {
+        hydration_threads: 1,
+        ..Default::default()
+    };
+    let db = PulseDB::open(&path, serial).unwrap();
+    let serial_ids: Vec<_> = db
+        .search_similar(cid, &query, 100)
+        .unwrap()
+        .into_iter()
+        .map(|r| r.experience.id)
+        .collect();
+    let parallel_ids: Vec<_> = hits.into_iter().map(|(id, _, _)| id).collect();
+    assert_eq!(serial_ids, parallel_ids);
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Similarity Graph
+// ============================================================================
+
+#[test]
+fn test_neighbor_graph_compute_and_read() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let ids = record_experiences_with_embeddings(&db, cid, &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+    // A near-copy of seed 3 should list it as its closest neighbor
+    let mut near = make_embedding(3);
+    near[0] += 0.01;
+    let copy = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "near copy".to_string(),
+            embedding: Some(near),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert!(db.get_experience_neighbors(copy).unwrap().is_none());
+    assert_eq!(db.compute_neighbor_graph(cid, 3).unwrap(), 9);
+
+    let neighbors = db.get_experience_neighbors(copy).unwrap().unwrap();
+    assert_eq!(neighbors.len(), 3);
+    assert_eq!(neighbors[0].experience_id, ids[3]);
+    assert!(neighbors[0].similarity > 0.99);
+    assert!(neighbors.iter().all(|n| n.experience_id != copy));
+    for w in neighbors.windows(2) {
+        assert!(w[0].similarity >= w[1].similarity);
+    }
+
+    // Deleted neighbors drop out; deleted experiences lose their list
+    db.delete_experience(ids[3]).unwrap();
+    let neighbors = db.get_experience_neighbors(copy).unwrap().unwrap();
+    assert!(neighbors.iter().all(|n| n.experience_id != ids[3]));
+    assert!(db.get_experience_neighbors(ids[3]).unwrap().is_none());
+
+    assert!(db
+        .compute_neighbor_graph(cid, 0)
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .compute_neighbor_graph(CollectiveId::new(), 3)
+        .unwrap_err()
+        .is_not_found());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_representative_queries_rank_the_experience() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let ids = record_experiences_with_embeddings(&db, cid, &[1, 2, 3, 4, 5, 6]);
+    db.archive_experience(ids[5]).unwrap();
+
+    let queries = db.representative_queries(ids[0], 3).unwrap();
+    assert_eq!(queries.len(), 3);
+
+    // The experience's own embedding comes first and finds it on top
+    assert_eq!(queries[0].exemplar, None);
+    assert_eq!(queries[0].rank, Some(1));
+    assert!((queries[0].similarity - 1.0).abs() < 1e-4);
+
+    // The rest lean toward distinct active neighbors
+    let exemplars: Vec<_> = queries[1..].iter().map(|q| q.exemplar.unwrap()).collect();
+    assert_ne!(exemplars[0], exemplars[1]);
+    assert!(!exemplars.contains(&ids[0]) && !exemplars.contains(&ids[5]));
+    for query in &queries {
+        assert_eq!(query.embedding.len(), DIM);
+        assert!(query.rank.is_some());
+    }
+
+    assert!(db.representative_queries(ids[0], 0).is_err());
+    assert!(db
+        .representative_queries(pulsedb::ExperienceId::new(), 3)
+        .unwrap_err()
+        .is_not_found());
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Query Builder
+// ============================================================================
+
+#[test]
+fn test_query_builder_matches_filtered_search() {
+    let (db, cid, _dir) = open_db_with_collective();
+    for seed in 0..20u64 {
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: format!("Experience seed={}", seed),
+            embedding: Some(make_embedding(seed)),
+            importance: if seed % 2 == 0 { 0.9 } else { 0.1 },
+            ..Default::default()
+        })
+        .unwrap();
+    }
+
+    let query = make_embedding(4);
+    let built = db
+        .query(cid)
+        .similar_to(query.clone())
+        .filter(|f| f.types([ExperienceType::default()]).min_importance(0.5))
+        .limit(5)
+        .with_explain()
+        .run()
+        .unwrap();
+    let direct = db
+        .search_similar_filtered(
+            cid,
+            &query,
+            5,
+            SearchFilter::default()
+                .types([ExperienceType::default()])
+                .min_importance(0.5),
+        )
+        .unwrap();
+
+    let ids = |results: &[pulsedb::SearchResult]| -> Vec<_> {
+        results.iter().map(|r| r.experience.id).collect()
+    };
+    assert_eq!(ids(&built.results), ids(&direct));
+    assert_eq!(built.results.len(), 5);
+    assert!(built.results.iter().all(|r| r.experience.importance > 0.5));
+
+    // The odd seeds fail the importance prescreen before any record is read
+    let explain = built.explain.unwrap();
+    assert_eq!(explain.collectives_searched, 1);
+    assert_eq!(explain.over_fetch, 10);
+    assert_eq!(explain.candidates, 10);
+    assert!(explain.prescreened_out > 0);
+    assert_eq!(explain.filtered_out, 0);
+
+    // Explain is opt-in, and the query vector is required
+    let plain = db.query(cid).similar_to(query).run().unwrap();
+    assert!(plain.explain.is_none());
+    assert!(db.query(cid).limit(3).run().unwrap_err().is_validation());
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Owner-Scoped Search
+// ============================================================================
+
+#[test]
+fn test_search_similar_scoped_stays_within_owner() {
+    let (db, _dir) = open_db();
+    let alice_a = db.create_collective_with_owner("a", "alice").unwrap();
+    let alice_b = db.create_collective_with_owner("b", "alice").unwrap();
+    let bob = db.create_collective_with_owner("c", "bob").unwrap();
+    let unowned = db.create_collective("d").unwrap();
+
+    let in_a = record_experiences_with_embeddings(&db, alice_a, &[1, 2]);
+    let in_b = record_experiences_with_embeddings(&db, alice_b, &[3]);
+    // Bob and the unowned collective hold the exact query vector
+    record_experiences_with_embeddings(&db, bob, &[3]);
+    record_experiences_with_embeddings(&db, unowned, &[3]);
+
+    let results = db
+        .search_similar_scoped("alice", &make_embedding(3), 10, SearchFilter::default())
+        .unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].experience.id, in_b[0]);
+    assert!(results
+        .iter()
+        .all(|r| r.experience.collective_id == alice_a || r.experience.collective_id == alice_b));
+    assert!(results
+        .windows(2)
+        .all(|w| w[0].similarity >= w[1].similarity));
+    assert!(in_a
+        .iter()
+        .all(|id| results.iter().any(|r| r.experience.id == *id)));
+
+    // k caps the merged list; unknown owners see nothing
+    assert_eq!(
+        db.search_similar_scoped("alice", &make_embedding(3), 2, SearchFilter::default())
+            .unwrap()
+            .len(),
+        2
+    );
+    assert!(db
+        .search_similar_scoped("mallory", &make_embedding(3), 10, SearchFilter::default())
+        .unwrap()
+        .is_empty());
+    assert!(db
+        .search_similar_scoped("alice", &[0.1; 8], 10, SearchFilter::default())
+        .unwrap_err()
+        .is_validation());
+
+    // The filter's cutoff applies in every collective
+    let exact_only = SearchFilter::default().min_similarity(0.99);
+    let results = db
+        .search_similar_scoped("alice", &make_embedding(3), 10, exact_only)
+        .unwrap();
+    assert_eq!(
+        results.iter().map(|r| r.experience.id).collect::<Vec<_>>(),
+        in_b
+    );
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Cross-Collective Search
+// ============================================================================
+
+#[test]
+fn test_search_across_collectives_merges_with_provenance() {
+    let (db, _dir) = open_db();
+    let api = db.create_collective("api").unwrap();
+    let web = db.create_collective("web").unwrap();
+    let other = db.create_collective("other").unwrap();
+
+    let in_api = record_experiences_with_embeddings(&db, api, &[1, 2]);
+    let in_web = record_experiences_with_embeddings(&db, web, &[3]);
+    // Outside the searched set, and the exact query vector
+    record_experiences_with_embeddings(&db, other, &[3]);
+
+    let results = db
+        .search_across_collectives(
+            &[api, web, api],
+            &make_embedding(3),
+            10,
+            SearchFilter::default(),
+        )
+        .unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].experience.id, in_web[0]);
+    assert_eq!(results[0].experience.collective_id, web);
+    assert!(results
+        .windows(2)
+        .all(|w| w[0].similarity >= w[1].similarity));
+    for id in &in_api {
+        let hit = results.iter().find(|r| r.experience.id == *id).unwrap();
+        assert_eq!(hit.experience.collective_id, api);
+    }
+
+    // Parallel fan-out returns the same as searching one by one
+    let mut serial: Vec<_> = [api, web]
+        .iter()
+        .flat_map(|&cid| db.search_similar(cid, &make_embedding(3), 2).unwrap())
+        .collect();
+    serial.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    serial.truncate(2);
+    let merged = db
+        .search_across_collectives(&[api, web], &make_embedding(3), 2, SearchFilter::default())
+        .unwrap();
+    assert_eq!(
+        merged.iter().map(|r| r.experience.id).collect::<Vec<_>>(),
+        serial.iter().map(|r| r.experience.id).collect::<Vec<_>>()
+    );
+
+    assert!(db
+        .search_across_collectives(&[], &make_embedding(3), 10, SearchFilter::default())
+        .unwrap_err()
+        .is_validation());
+    assert!(db
+        .search_across_collectives(
+            &[api, CollectiveId::new()],
+            &make_embedding(3),
+            10,
+            SearchFilter::default()
+        )
+        .unwrap_err()
+        .is_not_found());
+    assert!(db
+        .search_across_collectives(&[api, web], &[0.1; 8], 10, SearchFilter::default())
+        .unwrap_err()
+        .is_validation());
+
+    // The filter's cutoff applies in every collective
+    let exact_only = SearchFilter::default().min_similarity(0.99);
+    let results = db
+        .search_across_collectives(&[api, web], &make_embedding(3), 10, exact_only)
+        .unwrap();
+    assert_eq!(
+        results.iter().map(|r| r.experience.id).collect::<Vec<_>>(),
+        in_web
+    );
+
+    db.close().unwrap();
+}
@@ -0,0 +1,87 @@
+//! Integration tests for the multi-agent simulation harness.
+//!
+//! Runs `pulsedb::sim` workloads against a real database and checks the
+//! reported counts and invariants.
+
+#![cfg(feature = "test-util")]
+
+use pulsedb::bench::VectorDistribution;
+use pulsedb::sim::{self, SimConfig, WorkloadMix};
+use pulsedb::{CollectiveId, Config, PulseDB};
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+#[test]
+fn test_mixed_workload_keeps_invariants() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let config = SimConfig {
+        agents: 6,
+        operations_per_agent: 40,
+        ..SimConfig::default()
+    };
+    let report = sim::run(&db, cid, &config).unwrap();
+    report.assert_invariants();
+
+    let attempted = report.record.operations
+        + report.search.operations
+        + report.relate.operations
+        + report.skipped;
+    assert_eq!(attempted, 240);
+    assert!(report.record.operations > 0 && report.search.operations > 0);
+    assert_eq!(
+        db.get_collective_stats(cid).unwrap().experience_count,
+        report.record.operations as u64
+    );
+    assert!(report.per_second() > 0.0);
+    assert!(report.to_string().contains("violations 0"));
+}
+
+#[test]
+fn test_mix_and_distribution_are_honored() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let config = SimConfig {
+        agents: 3,
+        operations_per_agent: 20,
+        mix: WorkloadMix {
+            record: 1,
+            search: 0,
+            relate: 0,
+        },
+        distribution: VectorDistribution::Uniform,
+        ..SimConfig::default()
+    };
+    let report = sim::run(&db, cid, &config).unwrap();
+    report.assert_invariants();
+    assert_eq!(report.record.operations, 60);
+    assert_eq!(report.search.operations, 0);
+    assert_eq!(report.relate.operations, 0);
+}
+
+#[test]
+fn test_invalid_configs_are_rejected() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let no_ops = SimConfig {
+        mix: WorkloadMix {
+            record: 0,
+            search: 0,
+            relate: 0,
+        },
+        ..SimConfig::default()
+    };
+    assert!(sim::run(&db, cid, &no_ops).unwrap_err().is_validation());
+    let no_agents = SimConfig {
+        agents: 0,
+        ..SimConfig::default()
+    };
+    assert!(sim::run(&db, cid, &no_agents).unwrap_err().is_validation());
+    assert!(sim::run(&db, CollectiveId::new(), &SimConfig::default())
+        .unwrap_err()
+        .is_not_found());
+}
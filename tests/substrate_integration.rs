@@ -313,6 +313,7 @@ async fn test_get_activities() {
         collective_id: cid,
         current_task: Some("Testing".to_string()),
         context_summary: None,
+        capabilities: vec![],
     })
     .unwrap();
 
@@ -353,6 +354,7 @@ async fn test_get_context_candidates() {
             include_relations: false,
             include_active_agents: false,
             filter: SearchFilter::default(),
+            collapse_insight_sources: false,
         })
         .await
         .unwrap();
@@ -242,6 +242,8 @@ fn make_change(seq: u64, cid: CollectiveId) -> SyncChange {
             id: cid,
             name: format!("collective-{}", seq),
             owner_id: None,
+            description: None,
+            settings: Default::default(),
             embedding_dimension: 384,
             created_at: Timestamp::now(),
             updated_at: Timestamp::now(),
@@ -605,8 +607,11 @@ fn test_echo_prevention_suppresses_wal() {
         related_files: vec![],
         source_agent: pulsedb::AgentId::new("sync-test"),
         source_task: None,
+        user_id: None,
         timestamp: Timestamp::now(),
+        expires_at: None,
         archived: false,
+        attribution: None,
     };
     db.apply_synced_experience(exp).unwrap();
 
@@ -641,8 +646,11 @@ fn test_apply_synced_experience_writes_data() {
         related_files: vec![],
         source_agent: pulsedb::AgentId::new("sync-test"),
         source_task: None,
+        user_id: None,
         timestamp: Timestamp::now(),
+        expires_at: None,
         archived: false,
+        attribution: None,
     };
 
     let _guard = SyncApplyGuard::enter();
@@ -665,6 +673,8 @@ fn test_apply_synced_collective_creates_indexes() {
         id: CollectiveId::new(),
         name: "synced-collective".to_string(),
         owner_id: None,
+        description: None,
+        settings: Default::default(),
         embedding_dimension: 384,
         created_at: Timestamp::now(),
         updated_at: Timestamp::now(),
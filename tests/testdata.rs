@@ -0,0 +1,100 @@
+//! Integration tests for synthetic collective generation.
+//!
+//! Populates real databases from `pulsedb::testdata` configs and checks
+//! that the data is reproducible and behaves like topical memory.
+
+#![cfg(feature = "testdata")]
+
+use pulsedb::testdata::{self, TestDataConfig, Topic};
+use pulsedb::{Config, EmbeddingDimension, ExperienceType, PulseDB};
+use tempfile::tempdir;
+
+/// Helper: open an empty DB.
+fn open_db() -> (PulseDB, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    (db, dir)
+}
+
+// ============================================================================
+// Populating
+// ============================================================================
+
+#[test]
+fn test_populate_is_reproducible() {
+    let config = TestDataConfig {
+        experiences: 80,
+        ..Default::default()
+    };
+    let (first, _dir1) = open_db();
+    let (second, _dir2) = open_db();
+    let a = testdata::populate(&first, "repro", &config).unwrap();
+    let b = testdata::populate(&second, "repro", &config).unwrap();
+
+    let contents = |db: &PulseDB, cid| {
+        let mut contents: Vec<String> = db
+            .list_experiences(cid, 1000, 0)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.content)
+            .collect();
+        contents.sort();
+        contents
+    };
+    assert_eq!(contents(&first, a).len(), 80);
+    assert_eq!(contents(&first, a), contents(&second, b));
+}
+
+#[test]
+fn test_topics_cluster_in_search() {
+    let (db, _dir) = open_db();
+    let config = TestDataConfig {
+        experiences: 120,
+        topics: vec![
+            Topic::new("database", &["postgres", "indexes"]),
+            Topic::new("frontend", &["react", "css"]),
+        ],
+        ..Default::default()
+    };
+    let cid = testdata::populate(&db, "topical", &config).unwrap();
+
+    let probe = testdata::generate(&config, cid).unwrap().remove(0);
+    let topic = probe.domain[0].clone();
+    let results = db
+        .search_similar(cid, probe.embedding.as_ref().unwrap(), 20)
+        .unwrap();
+    assert_eq!(results[0].experience.content, probe.content);
+    assert!(results.iter().all(|r| r.experience.domain[0] == topic));
+
+    // Solutions link back to a difficulty in their topic
+    let linked = testdata::generate(&config, cid)
+        .unwrap()
+        .into_iter()
+        .filter_map(|e| match e.experience_type {
+            ExperienceType::Solution { problem_ref, .. } => problem_ref,
+            _ => None,
+        })
+        .next()
+        .unwrap();
+    let problem = db.get_experience(linked).unwrap().unwrap();
+    assert!(matches!(
+        problem.experience_type,
+        ExperienceType::Difficulty { .. }
+    ));
+}
+
+#[test]
+fn test_populate_rejects_mismatched_dimension() {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(
+        dir.path().join("test.db"),
+        Config {
+            embedding_dimension: EmbeddingDimension::D768,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let err = testdata::populate(&db, "wrong", &TestDataConfig::default()).unwrap_err();
+    assert!(err.is_validation());
+    assert!(db.list_collectives().unwrap().is_empty());
+}
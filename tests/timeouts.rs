@@ -0,0 +1,138 @@
+//! Integration tests for per-operation timeouts.
+//!
+//! Covers search deadlines checked while hydrating results, index
+//! rebuilds at open that run out of time, and validation of the limits.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use pulsedb::{
+    CollectiveId, Config, NewExperience, PulseDB, PulseDBError, ReadHook, ReadRecord, Result,
+    TimedOperation, TimeoutConfig,
+};
+use tempfile::tempdir;
+
+/// Helper: record `count` experiences with distinct embeddings.
+fn record_many(db: &PulseDB, cid: CollectiveId, count: usize) {
+    for i in 0..count {
+        let mut embedding = vec![0.1; 384];
+        embedding[i % 384] = 1.0;
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: format!("experience {}", i),
+            embedding: Some(embedding),
+            ..Default::default()
+        })
+        .unwrap();
+    }
+}
+
+/// Stalls every read, like a slow enrichment service.
+struct Slow(Duration);
+
+impl ReadHook for Slow {
+    fn on_read(&self, _record: &mut ReadRecord<'_>) -> Result<()> {
+        std::thread::sleep(self.0);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Search
+// ============================================================================
+
+#[test]
+fn test_search_times_out_during_hydration() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        timeouts: TimeoutConfig {
+            search: Some(Duration::from_millis(100)),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let db = PulseDB::open(dir.path().join("test.db"), config).unwrap();
+    let cid = db.create_collective("slow").unwrap();
+    record_many(&db, cid, 5);
+    db.add_read_hook(cid, Arc::new(Slow(Duration::from_millis(60))))
+        .unwrap();
+
+    // One hit fits in the budget
+    let results = db.search_similar(cid, &[0.1; 384], 1).unwrap();
+    assert_eq!(results.len(), 1);
+
+    // Five don't
+    let err = db.search_similar(cid, &[0.1; 384], 5).unwrap_err();
+    assert!(err.is_timeout(), "expected timeout, got {err}");
+    assert!(matches!(
+        err,
+        PulseDBError::Timeout {
+            operation: TimedOperation::Search,
+            limit,
+        } if limit == Duration::from_millis(100)
+    ));
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Rebuild
+// ============================================================================
+
+#[test]
+fn test_rebuild_timeout_at_open_marks_collective_unavailable() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let big = db.create_collective("big").unwrap();
+    let empty = db.create_collective("empty").unwrap();
+    record_many(&db, big, 20);
+    db.close().unwrap();
+    // Force a rebuild instead of loading the saved graph
+    std::fs::remove_dir_all(path.with_extension("db.hnsw")).unwrap();
+
+    let config = Config {
+        timeouts: TimeoutConfig {
+            rebuild: Some(Duration::from_nanos(1)),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let db = PulseDB::open(&path, config).unwrap();
+    let health = db.health().unwrap();
+    assert_eq!(health.unavailable.len(), 1);
+    assert_eq!(health.unavailable[0].collective_id, big);
+    assert!(health.unavailable[0].reason.contains("timed out"));
+
+    assert!(db
+        .search_similar(big, &[0.1; 384], 5)
+        .unwrap_err()
+        .is_unavailable());
+    // Nothing to rebuild, nothing to time out
+    assert!(db.search_similar(empty, &[0.1; 384], 5).unwrap().is_empty());
+    db.close().unwrap();
+
+    // Without the limit the rebuild completes
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert!(!db.health().unwrap().is_degraded());
+    assert_eq!(db.search_similar(big, &[0.1; 384], 5).unwrap().len(), 5);
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn test_zero_timeout_rejected() {
+    let dir = tempdir().unwrap();
+    let config = Config {
+        timeouts: TimeoutConfig {
+            write_transaction: Some(Duration::ZERO),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let err = PulseDB::open(dir.path().join("test.db"), config).unwrap_err();
+    assert!(err.is_validation());
+}
@@ -0,0 +1,265 @@
+//! Integration tests for fine-tuning dataset export.
+//!
+//! Tests the full stack: PulseDB facade -> StorageEngine -> JSONL output.
+//! Covers the record mapping, filtering of experiences and insights,
+//! sub-collectives, review and read-hook handling, and unknown collectives.
+
+use std::sync::Arc;
+
+use pulsedb::{
+    AgentId, CollectiveId, Config, ExperienceId, ExperienceType, InsightType, ModerationPolicy,
+    NewDerivedInsight, NewExperience, PulseDB, ReadHook, ReadRecord, Result, SearchFilter,
+    TrainingExportReport, TrainingFormat, TrainingTemplate,
+};
+use serde_json::Value;
+use tempfile::tempdir;
+
+/// Helper: open DB, create a collective, return both.
+fn open_db_with_collective() -> (PulseDB, CollectiveId, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let db = PulseDB::open(dir.path().join("test.db"), Config::default()).unwrap();
+    let cid = db.create_collective("test-collective").unwrap();
+    (db, cid, dir)
+}
+
+/// Helper: record an experience of the given type.
+fn record(
+    db: &PulseDB,
+    cid: CollectiveId,
+    content: &str,
+    experience_type: ExperienceType,
+) -> ExperienceId {
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: content.to_string(),
+        experience_type,
+        embedding: Some(vec![0.1; 384]),
+        domain: vec!["rust".to_string()],
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+/// Helper: store an insight drawn from one experience.
+fn insight(db: &PulseDB, cid: CollectiveId, source: ExperienceId, content: &str, confidence: f32) {
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: content.to_string(),
+        embedding: Some(vec![0.2; 384]),
+        source_experience_ids: vec![source],
+        insight_type: InsightType::Synthesis,
+        confidence,
+        domain: vec!["rust".to_string()],
+    })
+    .unwrap();
+}
+
+/// Helper: export with a template and filter, returning parsed lines.
+fn export(
+    db: &PulseDB,
+    cid: CollectiveId,
+    template: TrainingTemplate,
+    filter: &SearchFilter,
+) -> (TrainingExportReport, Vec<Value>) {
+    let mut out = Vec::new();
+    let report = db
+        .export_training_data(cid, TrainingFormat::Jsonl { template }, filter, &mut out)
+        .unwrap();
+    let lines = String::from_utf8(out)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    (report, lines)
+}
+
+fn error_pattern() -> ExperienceType {
+    ExperienceType::ErrorPattern {
+        signature: "E0502".to_string(),
+        fix: "clone before the loop".to_string(),
+        prevention: "borrow narrowly".to_string(),
+    }
+}
+
+// ============================================================================
+// Record Mapping
+// ============================================================================
+
+#[test]
+fn test_experiences_and_insights_become_examples() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let source = record(&db, cid, "cargo build fails in the loop", error_pattern());
+    record(&db, cid, "prefer iterators", ExperienceType::default());
+    insight(&db, cid, source, "borrow errors cluster in loops", 0.8);
+
+    let (report, lines) = export(&db, cid, TrainingTemplate::Alpaca, &SearchFilter::default());
+    assert_eq!(
+        report,
+        TrainingExportReport {
+            experiences: 2,
+            insights: 1
+        }
+    );
+    assert_eq!(lines.len(), 3);
+
+    let fix = lines
+        .iter()
+        .find(|l| l["input"] == "cargo build fails in the loop")
+        .unwrap();
+    assert_eq!(fix["instruction"], "How do I fix and prevent this error?");
+    assert_eq!(
+        fix["output"],
+        "On `E0502`: fix by clone before the loop; prevent by borrow narrowly"
+    );
+
+    let note = lines
+        .iter()
+        .find(|l| l["output"] == "prefer iterators")
+        .unwrap();
+    assert_eq!(note["instruction"], "What was learned about rust?");
+    assert_eq!(note["input"], "");
+
+    assert!(lines
+        .iter()
+        .any(|l| l["output"] == "borrow errors cluster in loops"));
+}
+
+#[test]
+fn test_chat_template_carries_system_prompt() {
+    let (db, cid, _dir) = open_db_with_collective();
+    record(&db, cid, "cargo build fails in the loop", error_pattern());
+
+    let template = TrainingTemplate::Chat {
+        system: Some("You are the team's Rust expert.".to_string()),
+    };
+    let (_, lines) = export(&db, cid, template, &SearchFilter::default());
+    let messages = lines[0]["messages"].as_array().unwrap();
+    let roles: Vec<&str> = messages
+        .iter()
+        .map(|m| m["role"].as_str().unwrap())
+        .collect();
+    assert_eq!(roles, ["system", "user", "assistant"]);
+    assert_eq!(
+        messages[1]["content"],
+        "How do I fix and prevent this error?\n\ncargo build fails in the loop"
+    );
+}
+
+// ============================================================================
+// Filtering
+// ============================================================================
+
+#[test]
+fn test_filter_selects_experiences_and_insights() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let source = record(&db, cid, "cargo build fails in the loop", error_pattern());
+    record(&db, cid, "prefer iterators", ExperienceType::default());
+    insight(&db, cid, source, "confident", 0.9);
+    insight(&db, cid, source, "hunch", 0.2);
+
+    let by_confidence = SearchFilter {
+        min_confidence: Some(0.5),
+        ..SearchFilter::default()
+    };
+    let (report, _) = export(&db, cid, TrainingTemplate::Alpaca, &by_confidence);
+    assert_eq!(report.insights, 1);
+
+    // Insights have no experience type, so a type filter leaves them out
+    let by_type = SearchFilter {
+        experience_types: Some(vec![error_pattern()]),
+        ..SearchFilter::default()
+    };
+    let (report, lines) = export(&db, cid, TrainingTemplate::Alpaca, &by_type);
+    assert_eq!(
+        report,
+        TrainingExportReport {
+            experiences: 1,
+            insights: 0
+        }
+    );
+    assert_eq!(lines[0]["input"], "cargo build fails in the loop");
+}
+
+#[test]
+fn test_include_descendants_covers_sub_collectives() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let child = db.create_sub_collective(cid, "child").unwrap();
+    record(&db, cid, "parent note", ExperienceType::default());
+    record(&db, child, "child note", ExperienceType::default());
+
+    let (report, _) = export(&db, cid, TrainingTemplate::Alpaca, &SearchFilter::default());
+    assert_eq!(report.experiences, 1);
+
+    let subtree = SearchFilter {
+        include_descendants: true,
+        ..SearchFilter::default()
+    };
+    let (report, _) = export(&db, cid, TrainingTemplate::Alpaca, &subtree);
+    assert_eq!(report.experiences, 2);
+}
+
+// ============================================================================
+// Review and Read Hooks
+// ============================================================================
+
+/// Masks a secret in experience and insight content.
+struct Redact;
+
+impl ReadHook for Redact {
+    fn on_read(&self, record: &mut ReadRecord<'_>) -> Result<()> {
+        match record {
+            ReadRecord::Experience(exp) => exp.content = exp.content.replace("hunter2", "***"),
+            ReadRecord::Insight(insight) => {
+                insight.content = insight.content.replace("hunter2", "***")
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_pending_experiences_are_skipped_and_hooks_applied() {
+    let (db, cid, _dir) = open_db_with_collective();
+    db.set_moderation_policy(
+        cid,
+        ModerationPolicy::ReviewAgents(vec![AgentId::new("intern")]),
+    )
+    .unwrap();
+    db.add_read_hook(cid, Arc::new(Redact)).unwrap();
+
+    record(&db, cid, "password is hunter2", ExperienceType::default());
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: "unreviewed".to_string(),
+        embedding: Some(vec![0.1; 384]),
+        source_agent: AgentId::new("intern"),
+        ..Default::default()
+    })
+    .unwrap();
+
+    // Pending experiences are archived, so keep archived ones in scope to
+    // prove the review check excludes them on its own
+    let filter = SearchFilter {
+        exclude_archived: false,
+        ..SearchFilter::default()
+    };
+    let (report, lines) = export(&db, cid, TrainingTemplate::PromptCompletion, &filter);
+    assert_eq!(report.experiences, 1);
+    assert_eq!(lines[0]["completion"], "password is ***");
+}
+
+#[test]
+fn test_unknown_collective_is_not_found() {
+    let (db, _cid, _dir) = open_db_with_collective();
+    let err = db
+        .export_training_data(
+            CollectiveId::new(),
+            TrainingFormat::Jsonl {
+                template: TrainingTemplate::Alpaca,
+            },
+            &SearchFilter::default(),
+            Vec::new(),
+        )
+        .unwrap_err();
+    assert!(err.is_not_found());
+}
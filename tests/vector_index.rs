@@ -2,9 +2,18 @@
 //!
 //! Tests the full stack: PulseDB → HnswIndex lifecycle, including
 //! creation, population via record_experience, soft-delete, persistence
-//! across reopen, and rebuild from redb embeddings.
-
-use pulsedb::{CollectiveId, Config, NewExperience, PulseDB};
+//! across reopen, saved graph loading, rebuild from redb embeddings, and
+//! custom backends.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+
+use pulsedb::{
+    CollectiveId, Config, EmbeddingStorage, ExperienceId, IndexRole, IndexSpec, InsightType,
+    IvfConfig, NewDerivedInsight, NewExperience, PulseDB, Timestamp, VectorBackend, VectorIndex,
+    VectorIndexKind,
+};
 use tempfile::tempdir;
 
 /// Default embedding dimension for tests (D384).
@@ -246,6 +255,229 @@ fn test_hnsw_rebuild_when_files_missing() {
     }
 }
 
+// ============================================================================
+// Saved Graph Loading
+// ============================================================================
+
+/// Records one experience per seed, returning their IDs.
+fn record_embeddings(
+    db: &PulseDB,
+    cid: CollectiveId,
+    seeds: std::ops::Range<u64>,
+) -> Vec<ExperienceId> {
+    seeds
+        .map(|i| {
+            db.record_experience(NewExperience {
+                collective_id: cid,
+                content: format!("Saved graph experience {}", i),
+                embedding: Some(make_embedding(i)),
+                ..Default::default()
+            })
+            .unwrap()
+        })
+        .collect()
+}
+
+/// Returns when the collective's index was last rebuilt (`None` if loaded).
+fn last_rebuild(db: &PulseDB, cid: CollectiveId) -> Option<Timestamp> {
+    db.get_collective_stats(cid)
+        .unwrap()
+        .vector_index
+        .unwrap()
+        .last_rebuild
+}
+
+/// Copies the files of a flat directory.
+fn copy_dir(from: &Path, to: &Path) {
+    std::fs::create_dir_all(to).unwrap();
+    for entry in std::fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        std::fs::copy(entry.path(), to.join(entry.file_name())).unwrap();
+    }
+}
+
+#[test]
+fn test_hnsw_graph_loaded_on_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("load-test").unwrap();
+    let ids = record_embeddings(&db, cid, 0..10);
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert!(last_rebuild(&db, cid).is_none(), "graph should be loaded");
+    let results = db
+        .with_vector_index(cid, |idx| idx.search_experiences(&make_embedding(5), 1, 50))
+        .unwrap()
+        .unwrap();
+    assert_eq!(results[0].0, ids[5]);
+
+    // The loaded graph keeps taking inserts, in the same segment
+    let more = record_embeddings(&db, cid, 10..12);
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert!(last_rebuild(&db, cid).is_none());
+    let stats = db.get_collective_stats(cid).unwrap().vector_index.unwrap();
+    assert_eq!(stats.active_vectors, 12);
+    assert_eq!(stats.segments, 1);
+    let results = db
+        .with_vector_index(cid, |idx| {
+            idx.search_experiences(&make_embedding(11), 1, 50)
+        })
+        .unwrap()
+        .unwrap();
+    assert_eq!(results[0].0, more[1]);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_hnsw_corrupted_graph_dump_rebuilds() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("corrupt-test").unwrap();
+    record_embeddings(&db, cid, 0..10);
+    db.close().unwrap();
+
+    // Flip a byte in the graph data; the size stays right, the CRC doesn't
+    let data = std::fs::read_dir(path.with_extension("db.hnsw"))
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .find(|p| {
+            let name = p.file_name().unwrap().to_string_lossy();
+            name.starts_with(&cid.to_string()) && name.ends_with(".hnsw.data")
+        })
+        .expect("graph dump should be saved");
+    let mut bytes = std::fs::read(&data).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xff;
+    std::fs::write(&data, bytes).unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert!(
+        last_rebuild(&db, cid).is_some(),
+        "corrupted dump should rebuild"
+    );
+    let count = db
+        .with_vector_index(cid, |idx| Ok(idx.active_count()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(count, 10);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_hnsw_graph_with_changed_embedding_rebuilds() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("changed-test").unwrap();
+    let ids = record_embeddings(&db, cid, 0..10);
+    // Same IDs, different vector: only the embedding comparison sees it
+    db.storage_for_test()
+        .save_embedding(ids[2], &make_embedding(99))
+        .unwrap();
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert!(
+        last_rebuild(&db, cid).is_some(),
+        "changed embedding should rebuild"
+    );
+    let results = db
+        .with_vector_index(cid, |idx| {
+            idx.search_experiences(&make_embedding(99), 1, 50)
+        })
+        .unwrap()
+        .unwrap();
+    assert_eq!(results[0].0, ids[2]);
+    db.close().unwrap();
+}
+
+#[test]
+fn test_hnsw_graph_loaded_with_quantized_storage() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let config = || Config {
+        embedding_storage: EmbeddingStorage::Int8,
+        ..Default::default()
+    };
+
+    let db = PulseDB::open(&path, config()).unwrap();
+    let cid = db.create_collective("quantized-test").unwrap();
+    record_embeddings(&db, cid, 0..10);
+    db.close().unwrap();
+
+    // Quantization error alone doesn't count as a changed embedding
+    let db = PulseDB::open(&path, config()).unwrap();
+    assert!(last_rebuild(&db, cid).is_none(), "graph should be loaded");
+    db.close().unwrap();
+}
+
+#[test]
+fn test_hnsw_stale_graph_reconciled_with_redb() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let hnsw_dir = path.with_extension("db.hnsw");
+    let saved = dir.path().join("saved.hnsw");
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("stale-test").unwrap();
+    let ids = record_embeddings(&db, cid, 0..10);
+    db.close().unwrap();
+    copy_dir(&hnsw_dir, &saved);
+
+    // Changes after the save, then the saved graph is put back, as after
+    // a crash that skipped the save on close
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let added = record_embeddings(&db, cid, 50..52);
+    db.delete_experience(ids[3]).unwrap();
+    db.close().unwrap();
+    std::fs::remove_dir_all(&hnsw_dir).unwrap();
+    copy_dir(&saved, &hnsw_dir);
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert!(
+        last_rebuild(&db, cid).is_none(),
+        "small drift is reconciled"
+    );
+    db.with_vector_index(cid, |idx| {
+        assert_eq!(idx.active_count(), 11);
+        assert!(idx.contains(added[0]) && idx.contains(added[1]));
+        assert!(!idx.contains(ids[3]));
+        Ok(())
+    })
+    .unwrap();
+
+    // Most of the collective replaced: the saved graph is stale
+    for &id in &ids {
+        if id != ids[3] {
+            db.delete_experience(id).unwrap();
+        }
+    }
+    record_embeddings(&db, cid, 100..110);
+    db.close().unwrap();
+    std::fs::remove_dir_all(&hnsw_dir).unwrap();
+    copy_dir(&saved, &hnsw_dir);
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    assert!(
+        last_rebuild(&db, cid).is_some(),
+        "stale graph should rebuild"
+    );
+    let count = db
+        .with_vector_index(cid, |idx| Ok(idx.active_count()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(count, 12);
+    db.close().unwrap();
+}
+
 // ============================================================================
 // Removal on Collective Delete
 // ============================================================================
@@ -390,3 +622,480 @@ fn test_hnsw_search_returns_nearest_neighbors() {
 
     db.close().unwrap();
 }
+
+// ============================================================================
+// Idle Eviction
+// ============================================================================
+
+/// Helper: open DB with a short idle-eviction window.
+fn open_db_with_idle_eviction(window: std::time::Duration) -> (PulseDB, tempfile::TempDir) {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let config = Config {
+        idle_eviction: Some(window),
+        ..Default::default()
+    };
+    let db = PulseDB::open(&path, config).unwrap();
+    (db, dir)
+}
+
+fn record_seeds(db: &PulseDB, cid: CollectiveId, seeds: std::ops::Range<u64>) {
+    for i in seeds {
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: format!("Experience {}", i),
+            embedding: Some(make_embedding(i)),
+            importance: 0.5,
+            ..Default::default()
+        })
+        .unwrap();
+    }
+}
+
+#[test]
+fn test_idle_collective_evicted_and_reloaded() {
+    let window = std::time::Duration::from_millis(50);
+    let (db, dir) = open_db_with_idle_eviction(window);
+    let cid = db.create_collective("idle").unwrap();
+    record_seeds(&db, cid, 0..3);
+
+    std::thread::sleep(window * 2);
+    assert_eq!(db.evict_idle_collectives().unwrap(), 1);
+    assert!(!db.is_collective_loaded(cid));
+
+    // Eviction persisted the index metadata
+    let meta = dir
+        .path()
+        .join("test.db.hnsw")
+        .join(format!("{}.hnsw.meta", cid));
+    assert!(meta.exists(), "Evicted index should be saved to disk");
+
+    // Next search reloads transparently
+    let results = db.search_similar(cid, &make_embedding(1), 10).unwrap();
+    assert_eq!(results.len(), 3);
+    assert!(db.is_collective_loaded(cid));
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_idle_eviction_sweeps_automatically_on_use() {
+    let window = std::time::Duration::from_millis(50);
+    let (db, _dir) = open_db_with_idle_eviction(window);
+    let busy = db.create_collective("busy").unwrap();
+    let idle = db.create_collective("idle").unwrap();
+    record_seeds(&db, busy, 0..2);
+    record_seeds(&db, idle, 0..2);
+
+    std::thread::sleep(window * 2);
+
+    // Using one collective sweeps the other, which has gone idle
+    db.search_similar(busy, &make_embedding(0), 5).unwrap();
+    assert!(db.is_collective_loaded(busy));
+    assert!(!db.is_collective_loaded(idle));
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_writes_to_evicted_collective_visible_after_reload() {
+    let window = std::time::Duration::from_millis(50);
+    let (db, _dir) = open_db_with_idle_eviction(window);
+    let cid = db.create_collective("idle").unwrap();
+    record_seeds(&db, cid, 0..2);
+
+    std::thread::sleep(window * 2);
+    assert_eq!(db.evict_idle_collectives().unwrap(), 1);
+
+    // Recorded while evicted: lands in redb, picked up by the rebuild
+    record_seeds(&db, cid, 2..4);
+    let results = db.search_similar(cid, &make_embedding(3), 10).unwrap();
+    assert_eq!(results.len(), 4);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_searches_survive_concurrent_idle_sweeps() {
+    let window = std::time::Duration::from_millis(1);
+    let (db, _dir) = open_db_with_idle_eviction(window);
+    let cid = db.create_collective("churn").unwrap();
+    record_seeds(&db, cid, 0..3);
+
+    // Every search can trigger a sweep, and a sweeper runs alongside;
+    // none may drop the index out from under a search using it
+    std::thread::scope(|s| {
+        for seed in 0..4 {
+            let db = &db;
+            s.spawn(move || {
+                for _ in 0..50 {
+                    let results = db.search_similar(cid, &make_embedding(seed), 10).unwrap();
+                    assert_eq!(results.len(), 3);
+                }
+            });
+        }
+        s.spawn(|| {
+            for _ in 0..50 {
+                db.evict_idle_collectives().unwrap();
+                std::thread::sleep(window);
+            }
+        });
+    });
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_idle_eviction_disabled_by_default() {
+    let (db, cid, _dir) = open_db_with_collective();
+
+    assert_eq!(db.evict_idle_collectives().unwrap(), 0);
+    assert!(db.is_collective_loaded(cid));
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Warm-up
+// ============================================================================
+
+#[test]
+fn test_warm_collective_reloads_evicted_indexes() {
+    let window = std::time::Duration::from_millis(50);
+    let (db, _dir) = open_db_with_idle_eviction(window);
+    let cid = db.create_collective("warm").unwrap();
+    record_seeds(&db, cid, 0..4);
+
+    assert_eq!(db.warm_collective(cid).unwrap(), 4);
+
+    std::thread::sleep(window * 2);
+    db.evict_idle_collectives().unwrap();
+    assert!(!db.is_collective_loaded(cid));
+
+    assert_eq!(db.warm_collective(cid).unwrap(), 4);
+    assert!(db.is_collective_loaded(cid));
+
+    let err = db.warm_collective(CollectiveId::new()).unwrap_err();
+    assert!(err.is_not_found());
+    db.close().unwrap();
+}
+
+#[test]
+fn test_warm_after_rebuild_on_open() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let config = Config {
+        hnsw: pulsedb::HnswConfig {
+            warm_after_rebuild: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let db = PulseDB::open(&path, config.clone()).unwrap();
+    let cid = db.create_collective("warm").unwrap();
+    record_seeds(&db, cid, 0..3);
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, config).unwrap();
+    let results = db.search_similar(cid, &make_embedding(1), 10).unwrap();
+    assert_eq!(results.len(), 3);
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Segments
+// ============================================================================
+
+#[test]
+fn test_segmented_index_search_and_merge() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let config = Config {
+        hnsw: pulsedb::HnswConfig {
+            max_segment_size: 50,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let db = PulseDB::open(&path, config.clone()).unwrap();
+    let cid = db.create_collective("segments").unwrap();
+    record_seeds(&db, cid, 0..120);
+
+    // Nearest neighbors found across all three segments
+    let results = db.search_similar(cid, &make_embedding(110), 1).unwrap();
+    assert_eq!(results[0].experience.content, "Experience 110");
+    assert!(!db.merge_index_segments(cid).unwrap());
+
+    // Deleting from sealed segments makes them mergeable
+    let ids: Vec<_> = db
+        .list_experiences(cid, 100, 0)
+        .unwrap()
+        .into_iter()
+        .filter(|exp| exp.content.ends_with('0'))
+        .map(|exp| exp.id)
+        .collect();
+    for id in &ids {
+        db.delete_experience(*id).unwrap();
+    }
+    assert!(db.merge_index_segments(cid).unwrap());
+    while db.merge_index_segments(cid).unwrap() {}
+    let results = db.search_similar(cid, &make_embedding(20), 3).unwrap();
+    assert!(results.iter().all(|r| !ids.contains(&r.experience.id)));
+    db.close().unwrap();
+
+    // Survives reopen with the same segment size
+    let db = PulseDB::open(&path, config).unwrap();
+    let results = db.search_similar(cid, &make_embedding(111), 1).unwrap();
+    assert_eq!(results[0].experience.content, "Experience 111");
+    assert!(db
+        .merge_index_segments(CollectiveId::new())
+        .unwrap_err()
+        .is_not_found());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Index Kinds
+// ============================================================================
+
+#[test]
+fn test_ivf_collective_search_and_reopen() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let config = Config {
+        ivf: IvfConfig {
+            nlist: 4,
+            nprobe: 4,
+        },
+        ..Default::default()
+    };
+    let db = PulseDB::open(&path, config.clone()).unwrap();
+    let cid = db.create_collective("ivf").unwrap();
+    record_seeds(&db, cid, 0..50);
+    assert_eq!(
+        db.get_vector_index_kind(cid).unwrap(),
+        VectorIndexKind::Hnsw
+    );
+
+    // Switching rebuilds from redb; later records go to the new index
+    db.set_vector_index_kind(cid, VectorIndexKind::Ivf).unwrap();
+    record_seeds(&db, cid, 50..120);
+    let ivf_file = dir.path().join("test.db.hnsw").join(format!("{}.ivf", cid));
+    assert!(ivf_file.exists());
+    let active = db
+        .with_vector_index(cid, |idx| Ok(idx.active_count()))
+        .unwrap();
+    assert_eq!(active, Some(120));
+    let results = db.search_similar(cid, &make_embedding(110), 1).unwrap();
+    assert_eq!(results[0].experience.content, "Experience 110");
+    db.close().unwrap();
+
+    // The kind is persisted and the index rebuilt on open
+    let db = PulseDB::open(&path, config).unwrap();
+    assert_eq!(db.get_vector_index_kind(cid).unwrap(), VectorIndexKind::Ivf);
+    let results = db.search_similar(cid, &make_embedding(30), 1).unwrap();
+    assert_eq!(results[0].experience.content, "Experience 30");
+
+    db.set_vector_index_kind(cid, VectorIndexKind::Hnsw)
+        .unwrap();
+    assert!(!ivf_file.exists());
+    let results = db.search_similar(cid, &make_embedding(30), 1).unwrap();
+    assert_eq!(results[0].experience.content, "Experience 30");
+    assert!(db
+        .set_vector_index_kind(CollectiveId::new(), VectorIndexKind::Ivf)
+        .unwrap_err()
+        .is_not_found());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Custom Backends
+// ============================================================================
+
+/// Exact-scan index standing in for a third-party ANN library.
+#[derive(Default)]
+struct FlatIndex {
+    points: RwLock<Vec<(usize, Vec<f32>)>>,
+    deleted: RwLock<HashSet<usize>>,
+}
+
+impl VectorIndex for FlatIndex {
+    fn insert(&self, id: usize, embedding: &[f32]) -> pulsedb::Result<()> {
+        self.points.write().unwrap().push((id, embedding.to_vec()));
+        Ok(())
+    }
+
+    fn insert_batch(&self, items: &[(&Vec<f32>, usize)]) -> pulsedb::Result<()> {
+        for (embedding, id) in items {
+            self.insert(*id, embedding)?;
+        }
+        Ok(())
+    }
+
+    fn search(&self, query: &[f32], k: usize, ef: usize) -> pulsedb::Result<Vec<(usize, f32)>> {
+        self.search_filtered(query, k, ef, &|_| true)
+    }
+
+    fn search_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        _ef_search: usize,
+        filter: &(dyn Fn(&usize) -> bool + Sync),
+    ) -> pulsedb::Result<Vec<(usize, f32)>> {
+        let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let deleted = self.deleted.read().unwrap();
+        let mut hits: Vec<(usize, f32)> = self
+            .points
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(id, _)| !deleted.contains(id) && filter(id))
+            .map(|(id, v)| {
+                let dot: f32 = v.iter().zip(query).map(|(a, b)| a * b).sum();
+                (*id, 1.0 - dot / (norm(v) * norm(query)))
+            })
+            .collect();
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+        hits.truncate(k);
+        Ok(hits)
+    }
+
+    fn delete(&self, id: usize) -> pulsedb::Result<()> {
+        self.deleted.write().unwrap().insert(id);
+        Ok(())
+    }
+
+    fn is_deleted(&self, id: usize) -> bool {
+        self.deleted.read().unwrap().contains(&id)
+    }
+
+    fn len(&self) -> usize {
+        self.points.read().unwrap().len() - self.deleted.read().unwrap().len()
+    }
+
+    fn save(&self, _dir: &Path, _name: &str) -> pulsedb::Result<()> {
+        Ok(())
+    }
+}
+
+/// Helper: a config whose backend builds `FlatIndex`es, recording each spec.
+fn flat_backend_config() -> (Config, Arc<Mutex<Vec<IndexSpec>>>) {
+    let specs = Arc::new(Mutex::new(Vec::new()));
+    let seen = Arc::clone(&specs);
+    let config = Config {
+        vector_backend: Some(VectorBackend::new(move |spec| {
+            seen.lock().unwrap().push(*spec);
+            Ok(Box::new(FlatIndex::default()) as Box<dyn VectorIndex>)
+        })),
+        ..Default::default()
+    };
+    (config, specs)
+}
+
+#[test]
+fn test_custom_backend_serves_search_and_rebuilds_on_open() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let (config, specs) = flat_backend_config();
+
+    let db = PulseDB::open(&path, config.clone()).unwrap();
+    let cid = db.create_collective("custom").unwrap();
+    let roles: Vec<IndexRole> = specs.lock().unwrap().iter().map(|s| s.role).collect();
+    assert_eq!(roles, [IndexRole::Experiences, IndexRole::Insights]);
+    assert!(specs
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|s| s.collective_id == cid && s.dimension == DIM));
+
+    record_seeds(&db, cid, 0..30);
+    let results = db.search_similar(cid, &make_embedding(12), 3).unwrap();
+    assert_eq!(results[0].experience.content, "Experience 12");
+    db.delete_experience(results[0].experience.id).unwrap();
+    let results = db.search_similar(cid, &make_embedding(12), 3).unwrap();
+    assert!(results
+        .iter()
+        .all(|r| r.experience.content != "Experience 12"));
+    assert_eq!(
+        db.with_vector_index(cid, |idx| Ok(idx.hnsw_stats().is_none()))
+            .unwrap(),
+        Some(true)
+    );
+
+    // Insights go through the backend too
+    db.store_insight(NewDerivedInsight {
+        collective_id: cid,
+        content: "pattern".to_string(),
+        embedding: Some(make_embedding(5)),
+        source_experience_ids: vec![results[0].experience.id],
+        insight_type: InsightType::Pattern,
+        confidence: 0.8,
+        domain: vec![],
+    })
+    .unwrap();
+    assert_eq!(
+        db.get_insights(cid, &make_embedding(5), 1).unwrap().len(),
+        1
+    );
+    db.close().unwrap();
+
+    // Reopening rebuilds the backend's indexes from redb
+    specs.lock().unwrap().clear();
+    let db = PulseDB::open(&path, config).unwrap();
+    assert_eq!(specs.lock().unwrap().len(), 2);
+    let results = db.search_similar(cid, &make_embedding(20), 1).unwrap();
+    assert_eq!(results[0].experience.content, "Experience 20");
+    assert_eq!(
+        db.with_vector_index(cid, |idx| Ok(idx.active_count()))
+            .unwrap(),
+        Some(29)
+    );
+    assert_eq!(
+        db.get_insights(cid, &make_embedding(5), 1).unwrap().len(),
+        1
+    );
+
+    // Index snapshots are HNSW-only
+    let err = db
+        .export_index_snapshot(cid, dir.path().join("snap"))
+        .unwrap_err();
+    assert!(err.is_validation());
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Quantized Embedding Storage
+// ============================================================================
+
+#[test]
+fn test_quantized_embeddings_rebuild_and_search() {
+    for storage in [EmbeddingStorage::F16, EmbeddingStorage::Int8] {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let config = Config {
+            embedding_storage: storage,
+            ..Default::default()
+        };
+
+        let db = PulseDB::open(&path, config.clone()).unwrap();
+        let cid = db.create_collective("quantized").unwrap();
+        record_seeds(&db, cid, 0..40);
+        db.close().unwrap();
+
+        // The reopened index is rebuilt from dequantized vectors
+        let db = PulseDB::open(&path, config).unwrap();
+        for seed in [3, 17, 32] {
+            let results = db.search_similar(cid, &make_embedding(seed), 1).unwrap();
+            assert_eq!(
+                results[0].experience.content,
+                format!("Experience {}", seed),
+                "{:?}",
+                storage
+            );
+            assert!(results[0].similarity > 0.999, "{:?}", storage);
+        }
+        db.close().unwrap();
+    }
+}
@@ -8,7 +8,8 @@ use futures::executor::block_on;
 use futures::StreamExt;
 
 use pulsedb::{
-    CollectiveId, Config, ExperienceUpdate, NewExperience, PulseDB, WatchEventType, WatchFilter,
+    AgentId, CollectiveId, Config, ExperienceUpdate, Interest, NewExperience, PulseDB,
+    WatchEventType, WatchFilter,
 };
 use tempfile::tempdir;
 
@@ -370,3 +371,95 @@ fn test_events_arrive_in_order() {
     assert_eq!(e2.experience_id, id2);
     assert_eq!(e3.experience_id, id3);
 }
+
+// ============================================================================
+// Interests
+// ============================================================================
+
+/// Helper: unit embedding along one axis.
+fn axis_embedding(axis: usize) -> Vec<f32> {
+    let mut embedding = vec![0.0; DIM];
+    embedding[axis] = 1.0;
+    embedding
+}
+
+#[test]
+fn test_interest_routes_matching_experiences_to_agent() {
+    let (db, cid, _dir) = open_db_with_collective();
+    let reviewer = AgentId::new("reviewer");
+    db.register_interest(
+        cid,
+        Interest::new(reviewer.clone(), "axis-0", axis_embedding(0), 0.9),
+    )
+    .unwrap();
+    let mut alerts = db.watch_interests(&reviewer).unwrap();
+
+    let record = |agent: &str, axis: usize| {
+        db.record_experience(NewExperience {
+            collective_id: cid,
+            content: format!("{} on axis {}", agent, axis),
+            embedding: Some(axis_embedding(axis)),
+            source_agent: AgentId::new(agent),
+            ..Default::default()
+        })
+        .unwrap()
+    };
+    record("writer", 1);
+    record("reviewer", 0);
+    let matching = record("writer", 0);
+
+    // Unrelated and self-recorded experiences are not routed
+    let event = block_on(alerts.next()).expect("should receive matching event");
+    assert_eq!(event.event_type, WatchEventType::Created);
+    assert_eq!(event.experience_id, matching);
+    assert!(format!("{:?}", alerts).contains("pending_events: 0"));
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_interests_persist_and_validate() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.db");
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let cid = db.create_collective("hive").unwrap();
+    let agent = AgentId::new("reviewer");
+
+    let err = db
+        .register_interest(
+            cid,
+            Interest::new(agent.clone(), "short", vec![0.1; 3], 0.5),
+        )
+        .unwrap_err();
+    assert!(err.is_validation());
+    let err = db
+        .register_interest(
+            cid,
+            Interest::new(agent.clone(), "loose", axis_embedding(0), 1.5),
+        )
+        .unwrap_err();
+    assert!(err.is_validation());
+
+    db.register_interest(
+        cid,
+        Interest::new(agent.clone(), "a", axis_embedding(0), 0.5),
+    )
+    .unwrap();
+    db.register_interest(
+        cid,
+        Interest::new(agent.clone(), "a", axis_embedding(1), 0.7),
+    )
+    .unwrap();
+    db.close().unwrap();
+
+    let db = PulseDB::open(&path, Config::default()).unwrap();
+    let interests = db.list_interests(cid).unwrap();
+    assert_eq!(interests.len(), 1);
+    assert_eq!(interests[0].min_similarity, 0.7);
+
+    assert!(db.remove_interest(cid, &agent, "a").unwrap());
+    assert!(!db.remove_interest(cid, &agent, "a").unwrap());
+    assert!(db.list_interests(cid).unwrap().is_empty());
+
+    db.close().unwrap();
+}
@@ -0,0 +1,222 @@
+//! Integration tests for the JSON wire format of public types.
+//!
+//! Pins the field names of the types that cross service boundaries, and
+//! checks roundtrips through the database, tolerance of unknown fields,
+//! and defaults for omitted optional fields.
+
+use pulsedb::{
+    AgentId, Config, EmbeddingDimension, Experience, ExperienceType, InsightType,
+    NewDerivedInsight, NewExperience, PulseDB, SearchResult, Severity, UserId,
+};
+use serde_json::{json, Value};
+use tempfile::tempdir;
+
+/// Helper: open a database with a small embedding dimension.
+fn open_db(dir: &std::path::Path) -> PulseDB {
+    let config = Config {
+        embedding_dimension: EmbeddingDimension::Custom(4),
+        ..Default::default()
+    };
+    PulseDB::open(dir.join("test.db"), config).unwrap()
+}
+
+/// Helper: the sorted keys of a JSON object.
+fn keys(value: &Value) -> Vec<&str> {
+    let mut keys: Vec<&str> = value
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    keys.sort_unstable();
+    keys
+}
+
+// ============================================================================
+// Field names
+// ============================================================================
+
+#[test]
+fn test_experience_and_search_result_field_names() {
+    let dir = tempdir().unwrap();
+    let db = open_db(dir.path());
+    let cid = db.create_collective("wire").unwrap();
+    db.record_experience(NewExperience {
+        collective_id: cid,
+        content: "timeouts under load".to_string(),
+        experience_type: ExperienceType::Difficulty {
+            description: "pool exhausted".to_string(),
+            severity: Severity::High,
+        },
+        embedding: Some(vec![1.0, 0.0, 0.0, 0.0]),
+        user_id: Some(UserId::new("user-1")),
+        ..Default::default()
+    })
+    .unwrap();
+
+    let results = db.search_similar(cid, &[1.0, 0.0, 0.0, 0.0], 1).unwrap();
+    let value = serde_json::to_value(&results[0]).unwrap();
+    assert_eq!(
+        keys(&value),
+        ["distance", "experience", "score_kind", "similarity"]
+    );
+
+    let experience = &value["experience"];
+    assert_eq!(
+        keys(experience),
+        [
+            "applications",
+            "archived",
+            "attribution",
+            "collective_id",
+            "confidence",
+            "content",
+            "domain",
+            "embedding",
+            "experience_type",
+            "expires_at",
+            "id",
+            "importance",
+            "related_files",
+            "source_agent",
+            "source_task",
+            "timestamp",
+            "user_id",
+        ]
+    );
+    assert_eq!(experience["collective_id"], json!(cid.to_string()));
+    assert_eq!(experience["user_id"], json!("user-1"));
+    let recorded = experience["timestamp"].as_str().unwrap();
+    assert_eq!(
+        recorded,
+        results[0].experience.timestamp.to_rfc3339(),
+        "timestamps are RFC 3339 strings"
+    );
+    assert_eq!(experience["embedding"], json!([1.0, 0.0, 0.0, 0.0]));
+    assert_eq!(
+        experience["experience_type"],
+        json!({"Difficulty": {"description": "pool exhausted", "severity": "High"}})
+    );
+
+    let restored: SearchResult = serde_json::from_value(value).unwrap();
+    assert_eq!(restored.experience.id, results[0].experience.id);
+    assert_eq!(
+        restored.experience.timestamp,
+        results[0].experience.timestamp
+    );
+    assert_eq!(restored.experience.user_id, Some(UserId::new("user-1")));
+    assert_eq!(restored.experience.embedding, vec![1.0, 0.0, 0.0, 0.0]);
+
+    db.close().unwrap();
+}
+
+#[test]
+fn test_collective_and_insight_field_names() {
+    let dir = tempdir().unwrap();
+    let db = open_db(dir.path());
+    let cid = db.create_collective("wire").unwrap();
+    let source = db
+        .record_experience(NewExperience {
+            collective_id: cid,
+            content: "source".to_string(),
+            embedding: Some(vec![0.0, 1.0, 0.0, 0.0]),
+            ..Default::default()
+        })
+        .unwrap();
+    let insight_id = db
+        .store_insight(NewDerivedInsight {
+            collective_id: cid,
+            content: "pools need headroom".to_string(),
+            embedding: Some(vec![0.0, 1.0, 0.0, 0.0]),
+            source_experience_ids: vec![source],
+            insight_type: InsightType::Pattern,
+            confidence: 0.8,
+            domain: vec![],
+        })
+        .unwrap();
+
+    let collective = serde_json::to_value(db.get_collective(cid).unwrap().unwrap()).unwrap();
+    assert_eq!(
+        keys(&collective),
+        [
+            "created_at",
+            "description",
+            "embedding_dimension",
+            "id",
+            "name",
+            "owner_id",
+            "settings",
+            "updated_at"
+        ]
+    );
+
+    let insight = serde_json::to_value(db.get_insight(insight_id).unwrap().unwrap()).unwrap();
+    assert_eq!(
+        keys(&insight),
+        [
+            "collective_id",
+            "confidence",
+            "content",
+            "created_at",
+            "domain",
+            "embedding",
+            "id",
+            "insight_type",
+            "source_experience_ids",
+            "updated_at",
+        ]
+    );
+    assert_eq!(insight["insight_type"], json!("Pattern"));
+    assert_eq!(
+        insight["source_experience_ids"],
+        json!([source.to_string()])
+    );
+
+    db.close().unwrap();
+}
+
+// ============================================================================
+// Forward and backward compatibility
+// ============================================================================
+
+#[test]
+fn test_unknown_fields_ignored_and_optional_fields_defaulted() {
+    let value = json!({
+        "id": "0190a8e0-0000-7000-8000-000000000001",
+        "collective_id": "0190a8e0-0000-7000-8000-000000000002",
+        "content": "minimal",
+        "experience_type": {"Generic": {"category": null}},
+        "importance": 0.5,
+        "confidence": 0.5,
+        "source_agent": "agent",
+        "timestamp": 1_700_000_000_000_i64,
+        "field_from_a_newer_version": true,
+    });
+    let experience: Experience = serde_json::from_value(value).unwrap();
+    assert_eq!(experience.content, "minimal");
+    assert_eq!(experience.source_agent, AgentId::new("agent"));
+    assert!(experience.embedding.is_empty());
+    assert!(experience.domain.is_empty());
+    assert!(experience.user_id.is_none());
+    assert!(experience.attribution.is_none());
+    assert!(!experience.archived);
+    assert_eq!(experience.timestamp.as_millis(), 1_700_000_000_000);
+    assert!(experience.expires_at.is_none());
+
+    // Timestamps may carry any UTC offset
+    let mut value = serde_json::to_value(&experience).unwrap();
+    value["timestamp"] = json!("2023-11-15T03:43:20+05:30");
+    let experience: Experience = serde_json::from_value(value).unwrap();
+    assert_eq!(experience.timestamp.as_millis(), 1_700_000_000_000);
+
+    // Inputs default every omitted field
+    let new: NewExperience = serde_json::from_value(json!({
+        "collective_id": "0190a8e0-0000-7000-8000-000000000002",
+        "content": "from a client",
+    }))
+    .unwrap();
+    assert_eq!(new.content, "from a client");
+    assert_eq!(new.importance, 0.5);
+    assert_eq!(new.source_agent, AgentId::new("anonymous"));
+    assert!(new.embedding.is_none());
+}